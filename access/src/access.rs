@@ -0,0 +1,133 @@
+use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+use crate::errors::AccessError;
+use crate::role::Role;
+use crate::storage;
+
+/********** Roles **********/
+
+/// Fetch the Address holding a role, if any
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn get_role(e: &Env, role: Role) -> Option<Address> {
+    storage::get_role(e, role)
+}
+
+/// Set the Address holding a role. The contract checks who may set it.
+///
+/// ### Arguments
+/// * `role` - The role
+/// * `holder` - The Address for the role
+pub fn set_role(e: &Env, role: Role, holder: &Address) {
+    storage::set_role(e, role, holder);
+}
+
+/// Check if an address holds a role
+///
+/// ### Arguments
+/// * `role` - The role
+/// * `address` - The Address to check
+pub fn has_role(e: &Env, role: Role, address: &Address) -> bool {
+    storage::get_role(e, role).as_ref() == Some(address)
+}
+
+/// Require that the holder of a role authorized the invocation. Returns the holder.
+///
+/// ### Arguments
+/// * `role` - The role
+///
+/// ### Panics
+/// If no address holds the role
+pub fn require_role(e: &Env, role: Role) -> Address {
+    let holder = storage::get_role(e, role)
+        .unwrap_or_else(|| panic_with_error!(e, AccessError::UnauthorizedError));
+    holder.require_auth();
+    holder
+}
+
+/// Require that `caller` authorized the invocation and either holds a role or was granted
+/// access to `function`
+///
+/// ### Arguments
+/// * `caller` - The Address calling the function
+/// * `role` - The role guarding the function
+/// * `function` - The name of the function
+///
+/// ### Panics
+/// If the caller does not hold the role and was not granted access
+pub fn require_role_or_grant(e: &Env, caller: &Address, role: Role, function: &Symbol) {
+    caller.require_auth();
+    if !has_role(e, role, caller) && !storage::has_grant(e, caller, function) {
+        panic_with_error!(e, AccessError::UnauthorizedError);
+    }
+}
+
+/********** Grants **********/
+
+/// Grant an address access to a single function. The contract checks who may grant.
+///
+/// ### Arguments
+/// * `grantee` - The Address being granted access
+/// * `function` - The name of the function
+pub fn grant(e: &Env, grantee: &Address, function: &Symbol) {
+    storage::set_grant(e, grantee, function);
+}
+
+/// Revoke an address's access to a single function. The contract checks who may revoke.
+///
+/// ### Arguments
+/// * `grantee` - The Address losing access
+/// * `function` - The name of the function
+pub fn revoke(e: &Env, grantee: &Address, function: &Symbol) {
+    storage::del_grant(e, grantee, function);
+}
+
+/// Check if an address was granted access to a function
+///
+/// ### Arguments
+/// * `grantee` - The Address to check
+/// * `function` - The name of the function
+pub fn has_grant(e: &Env, grantee: &Address, function: &Symbol) -> bool {
+    storage::has_grant(e, grantee, function)
+}
+
+/********** Transfers **********/
+
+/// Propose a new holder for a role, who takes the role once they accept it. The holder of the
+/// role must authorize the invocation. Returns the holder.
+///
+/// ### Arguments
+/// * `role` - The role
+/// * `new_holder` - The Address proposed as the next holder
+///
+/// ### Panics
+/// If no address holds the role
+pub fn propose_transfer(e: &Env, role: Role, new_holder: &Address) -> Address {
+    let holder = require_role(e, role);
+    storage::set_pending(e, role, new_holder);
+    holder
+}
+
+/// Fetch the Address proposed for a role, if any
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn get_pending(e: &Env, role: Role) -> Option<Address> {
+    storage::get_pending(e, role)
+}
+
+/// Accept a proposed transfer of a role. The proposed holder must authorize the invocation.
+/// Returns the old and the new holder, or None if no transfer is proposed.
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn accept_transfer(e: &Env, role: Role) -> Option<(Address, Address)> {
+    let new_holder = storage::get_pending(e, role)?;
+    new_holder.require_auth();
+
+    let old_holder = storage::get_role(e, role).unwrap_optimized();
+    storage::set_role(e, role, &new_holder);
+    storage::del_pending(e, role);
+    Some((old_holder, new_holder))
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for access control. The unauthorized code matches the common error every Orbit
+/// contract reports for an unauthorized caller.
+pub enum AccessError {
+    UnauthorizedError = 4,
+    ReentrantCallError = 14,
+}
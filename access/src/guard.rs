@@ -0,0 +1,57 @@
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+use crate::errors::AccessError;
+use crate::storage;
+
+/// Hold a lock for the rest of the enclosing scope, so the contract can't be entered again
+/// through any function taking the same lock until the scope is left
+///
+/// ### Arguments
+/// * `e` - The Env
+/// * `name` - The name of the lock, "Locked" by default
+///
+/// ### Panics
+/// If the lock is already held
+#[macro_export]
+macro_rules! nonreentrant {
+    ($e:expr) => {
+        let _guard = $crate::ReentrancyGuard::new($e, "Locked");
+    };
+    ($e:expr, $name:expr) => {
+        let _guard = $crate::ReentrancyGuard::new($e, $name);
+    };
+}
+
+/// A lock kept in temporary storage, released when the guard is dropped. A panic reverts the
+/// invocation, which releases the lock along with everything else it wrote.
+pub struct ReentrancyGuard {
+    e: Env,
+    name: Symbol,
+}
+
+impl ReentrancyGuard {
+    /// Take a lock until the guard is dropped
+    ///
+    /// ### Arguments
+    /// * `name` - The name of the lock
+    ///
+    /// ### Panics
+    /// If the lock is already held
+    pub fn new(e: &Env, name: &str) -> Self {
+        let name = Symbol::new(e, name);
+        if storage::has_lock(e, &name) {
+            panic_with_error!(e, AccessError::ReentrantCallError);
+        }
+        storage::set_lock(e, &name);
+        ReentrancyGuard {
+            e: e.clone(),
+            name,
+        }
+    }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        storage::del_lock(&self.e, &self.name);
+    }
+}
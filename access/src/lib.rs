@@ -0,0 +1,18 @@
+#![no_std]
+//! Access control shared by the Orbit contracts. Each role is held by a single address, kept in
+//! the instance of the contract under the role's name, so contracts that kept their roles by
+//! hand read the same storage. Roles are handed over in two steps, and access to a single
+//! function can be granted to other addresses. Functions that call out can hold a lock with
+//! `nonreentrant!` while they do.
+
+mod access;
+mod errors;
+mod guard;
+mod role;
+mod storage;
+mod test;
+
+pub use access::*;
+pub use errors::AccessError;
+pub use guard::ReentrancyGuard;
+pub use role::Role;
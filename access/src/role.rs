@@ -0,0 +1,51 @@
+use soroban_sdk::{contracttype, Env, Symbol};
+
+/// A role held by a single address
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Role {
+    // Administers a contract
+    Admin,
+    // Owns the admin contract
+    Owner,
+    // Pauses and cancels, but can't change parameters
+    Guardian,
+    // Tunes interest rate parameters
+    IrManager,
+    // Executes approved governance proposals
+    Governor,
+    // Mints flash loans from the treasury
+    FlashMinter,
+    // Triggers the emergency shutdown
+    ShutdownModule,
+}
+
+impl Role {
+    /// The instance key the holder of the role is kept under
+    pub(crate) fn key(&self, e: &Env) -> Symbol {
+        let name = match self {
+            Role::Admin => "Admin",
+            Role::Owner => "Owner",
+            Role::Guardian => "Guardian",
+            Role::IrManager => "IRManager",
+            Role::Governor => "Governor",
+            Role::FlashMinter => "FlashMinter",
+            Role::ShutdownModule => "ShutdownModule",
+        };
+        Symbol::new(e, name)
+    }
+
+    /// The instance key the address proposed for the role is kept under
+    pub(crate) fn pending_key(&self, e: &Env) -> Symbol {
+        let name = match self {
+            Role::Admin => "PendingAdmin",
+            Role::Owner => "PendingOwner",
+            Role::Guardian => "PendingGuardian",
+            Role::IrManager => "PendingIRManager",
+            Role::Governor => "PendingGovernor",
+            Role::FlashMinter => "PendingFlashMinter",
+            Role::ShutdownModule => "PendingShutdownModule",
+        };
+        Symbol::new(e, name)
+    }
+}
@@ -0,0 +1,135 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::role::Role;
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+#[derive(Clone)]
+#[contracttype]
+pub enum AccessDataKey {
+    // Whether an address was granted access to a single function
+    Grant(Address, Symbol),
+    // Whether a reentrancy lock is held
+    Lock(Symbol),
+}
+
+/********** Roles **********/
+
+/// Fetch the Address holding a role, if any
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn get_role(e: &Env, role: Role) -> Option<Address> {
+    e.storage().instance().get(&role.key(e))
+}
+
+/// Set the Address holding a role
+///
+/// ### Arguments
+/// * `role` - The role
+/// * `holder` - The Address for the role
+pub fn set_role(e: &Env, role: Role, holder: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&role.key(e), holder);
+}
+
+/// Fetch the Address proposed for a role, if any
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn get_pending(e: &Env, role: Role) -> Option<Address> {
+    e.storage().instance().get(&role.pending_key(e))
+}
+
+/// Set the Address proposed for a role
+///
+/// ### Arguments
+/// * `role` - The role
+/// * `pending` - The Address proposed as the next holder
+pub fn set_pending(e: &Env, role: Role, pending: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&role.pending_key(e), pending);
+}
+
+/// Remove the Address proposed for a role
+///
+/// ### Arguments
+/// * `role` - The role
+pub fn del_pending(e: &Env, role: Role) {
+    e.storage().instance().remove(&role.pending_key(e));
+}
+
+/********** Grants **********/
+
+/// Check if an address was granted access to a function
+///
+/// ### Arguments
+/// * `grantee` - The Address to check
+/// * `function` - The name of the function
+pub fn has_grant(e: &Env, grantee: &Address, function: &Symbol) -> bool {
+    let key = AccessDataKey::Grant(grantee.clone(), function.clone());
+    if let Some(result) = e.storage().persistent().get::<AccessDataKey, bool>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        result
+    } else {
+        false
+    }
+}
+
+/// Grant an address access to a function
+///
+/// ### Arguments
+/// * `grantee` - The Address being granted access
+/// * `function` - The name of the function
+pub fn set_grant(e: &Env, grantee: &Address, function: &Symbol) {
+    let key = AccessDataKey::Grant(grantee.clone(), function.clone());
+    e.storage()
+        .persistent()
+        .set::<AccessDataKey, bool>(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove an address's access to a function
+///
+/// ### Arguments
+/// * `grantee` - The Address losing access
+/// * `function` - The name of the function
+pub fn del_grant(e: &Env, grantee: &Address, function: &Symbol) {
+    let key = AccessDataKey::Grant(grantee.clone(), function.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/********** Reentrancy Locks **********/
+
+/// Check if a lock is held
+///
+/// ### Arguments
+/// * `name` - The name of the lock
+pub fn has_lock(e: &Env, name: &Symbol) -> bool {
+    e.storage().temporary().has(&AccessDataKey::Lock(name.clone()))
+}
+
+/// Take a lock
+///
+/// ### Arguments
+/// * `name` - The name of the lock
+pub fn set_lock(e: &Env, name: &Symbol) {
+    e.storage()
+        .temporary()
+        .set::<AccessDataKey, bool>(&AccessDataKey::Lock(name.clone()), &true);
+}
+
+/// Release a lock
+///
+/// ### Arguments
+/// * `name` - The name of the lock
+pub fn del_lock(e: &Env, name: &Symbol) {
+    e.storage().temporary().remove(&AccessDataKey::Lock(name.clone()));
+}
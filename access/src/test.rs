@@ -0,0 +1,141 @@
+#![cfg(test)]
+use crate::{AccessError, Role};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+/// Guards its functions with the access control helpers
+#[contract]
+pub struct Guarded;
+
+#[contractimpl]
+impl Guarded {
+    pub fn initialize(e: Env, admin: Address, guardian: Address) {
+        crate::set_role(&e, Role::Admin, &admin);
+        crate::set_role(&e, Role::Guardian, &guardian);
+    }
+
+    pub fn admin_only(e: Env) -> Address {
+        crate::require_role(&e, Role::Admin)
+    }
+
+    pub fn flash_minter_only(e: Env) -> Address {
+        crate::require_role(&e, Role::FlashMinter)
+    }
+
+    pub fn guardian_or_grant(e: Env, caller: Address) {
+        crate::require_role_or_grant(&e, &caller, Role::Guardian, &Symbol::new(&e, "pause"));
+    }
+
+    pub fn grant(e: Env, grantee: Address) {
+        crate::require_role(&e, Role::Admin);
+        crate::grant(&e, &grantee, &Symbol::new(&e, "pause"));
+    }
+
+    pub fn revoke(e: Env, grantee: Address) {
+        crate::require_role(&e, Role::Admin);
+        crate::revoke(&e, &grantee, &Symbol::new(&e, "pause"));
+    }
+
+    pub fn propose(e: Env, new_admin: Address) -> Address {
+        crate::propose_transfer(&e, Role::Admin, &new_admin)
+    }
+
+    pub fn accept(e: Env) -> Option<(Address, Address)> {
+        crate::accept_transfer(&e, Role::Admin)
+    }
+
+    pub fn get(e: Env, role: Role) -> Option<Address> {
+        crate::get_role(&e, role)
+    }
+
+    pub fn pending(e: Env) -> Option<Address> {
+        crate::get_pending(&e, Role::Admin)
+    }
+
+    pub fn locked(e: Env, nested: bool) -> u32 {
+        crate::nonreentrant!(&e);
+        if nested {
+            Self::locked(e.clone(), false);
+        }
+        Self::lock_other(e.clone()) + 1
+    }
+
+    pub fn lock_other(e: Env) -> u32 {
+        crate::nonreentrant!(&e, "Other");
+        1
+    }
+}
+
+#[test]
+fn test_roles_and_grants() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let guarded = GuardedClient::new(&e, &e.register_contract(None, Guarded));
+    let admin = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    guarded.initialize(&admin, &guardian);
+
+    // roles are kept under their names, where contracts kept them by hand
+    e.as_contract(&guarded.address, || {
+        let stored: Address = e.storage().instance().get(&Symbol::new(&e, "Admin")).unwrap();
+        assert_eq!(stored, admin);
+    });
+    assert_eq!(guarded.admin_only(), admin);
+    assert_eq!(e.auths()[0].0, admin);
+    let result = guarded.try_flash_minter_only();
+    assert_eq!(result.err(), Some(Ok(AccessError::UnauthorizedError.into())));
+
+    // the guardian or a grantee can call the guarded function, and no one else
+    guarded.guardian_or_grant(&guardian);
+    assert_eq!(e.auths()[0].0, guardian);
+    let result = guarded.try_guardian_or_grant(&samwise);
+    assert_eq!(result.err(), Some(Ok(AccessError::UnauthorizedError.into())));
+    guarded.grant(&samwise);
+    guarded.guardian_or_grant(&samwise);
+    assert_eq!(e.auths()[0].0, samwise);
+    guarded.revoke(&samwise);
+    let result = guarded.try_guardian_or_grant(&samwise);
+    assert_eq!(result.err(), Some(Ok(AccessError::UnauthorizedError.into())));
+}
+
+#[test]
+fn test_two_step_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let guarded = GuardedClient::new(&e, &e.register_contract(None, Guarded));
+    let admin = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    guarded.initialize(&admin, &Address::generate(&e));
+
+    assert_eq!(guarded.accept(), None);
+    assert_eq!(guarded.propose(&samwise), admin);
+    assert_eq!(e.auths()[0].0, admin);
+    assert_eq!(guarded.pending(), Some(samwise.clone()));
+    // the role only moves once it is accepted
+    assert_eq!(guarded.get(&Role::Admin), Some(admin.clone()));
+
+    assert_eq!(guarded.accept(), Some((admin, samwise.clone())));
+    assert_eq!(e.auths()[0].0, samwise);
+    assert_eq!(guarded.get(&Role::Admin), Some(samwise));
+    assert_eq!(guarded.pending(), None);
+}
+
+#[test]
+fn test_nonreentrant() {
+    let e = Env::default();
+    let guarded = GuardedClient::new(&e, &e.register_contract(None, Guarded));
+
+    // the lock is released when the scope is left, and other locks are held apart
+    assert_eq!(guarded.locked(&false), 2);
+    assert_eq!(guarded.lock_other(), 1);
+    assert_eq!(guarded.locked(&false), 2);
+
+    // taking a held lock reverts, without leaving it held
+    let result = guarded.try_locked(&true);
+    assert_eq!(result.err(), Some(Ok(AccessError::ReentrantCallError.into())));
+    assert_eq!(guarded.locked(&false), 2);
+    e.as_contract(&guarded.address, || {
+        assert!(!crate::storage::has_lock(&e, &Symbol::new(&e, "Locked")));
+    });
+}
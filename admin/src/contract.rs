@@ -0,0 +1,636 @@
+use crate::dependencies::pool::{Client as PoolClient, ReserveConfig};
+use crate::errors::AdminError;
+use crate::events::AdminEvents;
+use crate::storage::{self, QueuedPoolAdmin};
+use orbit_access::Role;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, unwrap::UnwrapOptimized, Address, BytesN, Env, Symbol, TryFromVal, Val, Vec};
+
+/// The delay between queueing and executing a pool admin transfer, in seconds
+const POOL_ADMIN_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+
+#[contract]
+pub struct AdminContract;
+
+#[contractclient(name = "AdminClient")]
+pub trait Admin {
+
+    /// Initialize the admin contract. The contract must be set as the admin of every
+    /// Blend pool it manages.
+    ///
+    /// ### Arguments
+    /// * `owner` - The Address for the owner
+    /// * `ir_manager` - The Address allowed to tune interest rate parameters
+    /// * `guardian` - The Address allowed to freeze pools and cancel queued reserve changes
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, owner: Address, ir_manager: Address, guardian: Address);
+
+    /// (Owner only) Propose a new owner. The proposed owner must call `accept_ownership`
+    /// before the transfer takes effect.
+    ///
+    /// ### Arguments
+    /// * `new_owner` - The proposed owner address
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn propose_ownership(e: Env, new_owner: Address);
+
+    /// (Proposed owner only) Accept a pending ownership transfer
+    ///
+    /// ### Panics
+    /// If there is no pending ownership transfer
+    /// If the caller is not the proposed owner
+    fn accept_ownership(e: Env);
+
+    /// Get the current owner address
+    fn get_owner(e: Env) -> Address;
+
+    /// (Owner only) Upgrade the contract to a new WASM, keeping its address and storage
+    ///
+    /// ### Arguments
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to upgrade to
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>);
+
+    /// (Owner only) Set a new interest rate manager
+    ///
+    /// ### Arguments
+    /// * `ir_manager` - The new interest rate manager address
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn set_ir_manager(e: Env, ir_manager: Address);
+
+    /// (Owner only) Set a new guardian. The guardian can freeze pools and cancel queued
+    /// reserve changes, but cannot modify any parameters.
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn set_guardian(e: Env, guardian: Address);
+
+    /// (Owner only) Set the governor allowed to execute calls through the admin contract
+    ///
+    /// ### Arguments
+    /// * `governor` - The new governor address
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn set_governor(e: Env, governor: Address);
+
+    /// (Governor only) Execute a list of calls to the admin functions of this contract, so an
+    /// approved governance proposal can update the pools this contract manages through the same
+    /// validation as the owner. The calls can be `queue_set_reserve`, `batch_update_reserves`,
+    /// `set_pool_status` and `rollback_reserve`, with the arguments of the function without
+    /// `caller`. Pools can't be called directly, so pool admin transfers must go through the
+    /// timelock.
+    ///
+    /// ### Arguments
+    /// * `calls` - The (function, args) calls to execute, in order
+    ///
+    /// ### Panics
+    /// If the caller is not the governor
+    /// If any call is to another function, or its arguments don't match the function
+    /// If any call fails
+    fn execute(e: Env, calls: Vec<(Symbol, Vec<Val>)>);
+
+    /// (Owner only) Queue a transfer of a pool's admin away from this contract. The transfer
+    /// can be executed with `set_pool_admin` once the timelock expires.
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `new_admin` - The Address of the new pool admin
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn queue_set_pool_admin(e: Env, pool: Address, new_admin: Address);
+
+    /// (Owner or guardian only) Cancel a queued transfer of a pool's admin
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the owner or guardian
+    /// If no transfer is queued for the pool
+    fn cancel_set_pool_admin(e: Env, caller: Address, pool: Address);
+
+    /// (Owner only) Execute a queued transfer of a pool's admin. The new admin must also
+    /// authorize the pool's `set_admin` invocation.
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `new_admin` - The Address of the new pool admin, which must match the queued transfer
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    /// If no matching transfer is queued for the pool
+    /// If the timelock has not expired
+    fn set_pool_admin(e: Env, pool: Address, new_admin: Address);
+
+    /// (Owner only) Grant an address access to a single admin function. Only `set_pool_status`,
+    /// `freeze_pool`, `cancel_set_reserve`, `set_ir_params` and `rollback_reserve` honor grants.
+    ///
+    /// ### Arguments
+    /// * `grantee` - The address being granted access
+    /// * `function` - The name of the admin function
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn grant(e: Env, grantee: Address, function: Symbol);
+
+    /// (Owner only) Revoke an address's access to a single admin function
+    ///
+    /// ### Arguments
+    /// * `grantee` - The address losing access
+    /// * `function` - The name of the admin function
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn revoke(e: Env, grantee: Address, function: Symbol);
+
+    /// Check if an address was granted access to an admin function
+    ///
+    /// ### Arguments
+    /// * `grantee` - The address to check
+    /// * `function` - The name of the admin function
+    fn has_grant(e: Env, grantee: Address, function: Symbol) -> bool;
+
+    /// (Owner or grantee only) Set the status of a pool
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    /// * `pool_status` - The new pool status
+    ///
+    /// ### Panics
+    /// If the caller is not the owner and was not granted access
+    fn set_pool_status(e: Env, caller: Address, pool: Address, pool_status: u32);
+
+    /// (Guardian or grantee only) Freeze a pool
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian and was not granted access
+    fn freeze_pool(e: Env, caller: Address, pool: Address);
+
+    /// (Guardian or grantee only) Cancel a queued reserve configuration update on a pool. The
+    /// reserve configs recorded before the update was queued are restored.
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the reserve asset
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian and was not granted access
+    fn cancel_set_reserve(e: Env, caller: Address, pool: Address, asset: Address);
+
+    /// (Owner only) Queue a full reserve configuration update on a pool
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the reserve asset
+    /// * `config` - The new reserve config
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn queue_set_reserve(e: Env, pool: Address, asset: Address, config: ReserveConfig);
+
+    /// (Owner only) Queue reserve configuration updates for several assets of a pool in a
+    /// single transaction, so they all unlock together
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `updates` - The (asset, config) pairs to queue
+    ///
+    /// ### Panics
+    /// If the caller is not the owner
+    fn batch_update_reserves(e: Env, pool: Address, updates: Vec<(Address, ReserveConfig)>);
+
+    /// (IR manager or grantee only) Queue an update to the interest rate curve of a reserve,
+    /// leaving the rest of the reserve config untouched
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the reserve asset
+    /// * `r_one` - The R1 value in the interest rate formula
+    /// * `r_two` - The R2 value in the interest rate formula
+    /// * `r_three` - The R3 value in the interest rate formula
+    /// * `util` - The target utilization rate
+    ///
+    /// ### Panics
+    /// If the caller is not the interest rate manager and was not granted access
+    /// If the reserve was not configured through this contract
+    #[allow(clippy::too_many_arguments)]
+    fn set_ir_params(
+        e: Env,
+        caller: Address,
+        pool: Address,
+        asset: Address,
+        r_one: u32,
+        r_two: u32,
+        r_three: u32,
+        util: u32,
+    );
+
+    /// (Owner or grantee only) Queue the reserve config that was replaced by the last update
+    /// of a reserve. A rollback can't be rolled back itself.
+    ///
+    /// ### Arguments
+    /// * `caller` - The address calling the function
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the reserve asset
+    ///
+    /// ### Panics
+    /// If the caller is not the owner and was not granted access
+    /// If there is no previous reserve config to restore
+    fn rollback_reserve(e: Env, caller: Address, pool: Address, asset: Address);
+
+    /// Get the last reserve config queued through this contract
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the reserve asset
+    ///
+    /// ### Panics
+    /// If the reserve was not configured through this contract
+    fn get_reserve_config(e: Env, pool: Address, asset: Address) -> ReserveConfig;
+}
+
+#[contractimpl]
+impl Admin for AdminContract {
+
+    fn initialize(e: Env, owner: Address, ir_manager: Address, guardian: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, AdminError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Owner, &owner);
+        orbit_access::set_role(&e, Role::IrManager, &ir_manager);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+        storage::set_is_init(&e);
+
+        AdminEvents::initialize(&e, owner, ir_manager, guardian);
+    }
+
+    fn propose_ownership(e: Env, new_owner: Address) {
+        storage::extend_instance(&e);
+        let owner = orbit_access::propose_transfer(&e, Role::Owner, &new_owner);
+
+        AdminEvents::propose_ownership(&e, owner, new_owner);
+    }
+
+    fn accept_ownership(e: Env) {
+        storage::extend_instance(&e);
+        let (old_owner, new_owner) = orbit_access::accept_transfer(&e, Role::Owner)
+            .unwrap_or_else(|| panic_with_error!(&e, AdminError::NoPendingOwner));
+
+        AdminEvents::accept_ownership(&e, old_owner, new_owner);
+    }
+
+    fn get_owner(e: Env) -> Address {
+        storage::extend_instance(&e);
+        orbit_access::get_role(&e, Role::Owner).unwrap_optimized()
+    }
+
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
+        storage::extend_instance(&e);
+        let owner = orbit_access::require_role(&e, Role::Owner);
+
+        e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        AdminEvents::upgrade(&e, owner, new_wasm_hash);
+    }
+
+    fn set_ir_manager(e: Env, ir_manager: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let old_ir_manager = orbit_access::get_role(&e, Role::IrManager).unwrap_optimized();
+        orbit_access::set_role(&e, Role::IrManager, &ir_manager);
+
+        AdminEvents::set_ir_manager(&e, old_ir_manager, ir_manager);
+    }
+
+    fn set_guardian(e: Env, guardian: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let old_guardian = orbit_access::get_role(&e, Role::Guardian).unwrap_optimized();
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+
+        AdminEvents::set_guardian(&e, old_guardian, guardian);
+    }
+
+    fn set_governor(e: Env, governor: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let old_governor = orbit_access::get_role(&e, Role::Governor);
+        orbit_access::set_role(&e, Role::Governor, &governor);
+
+        AdminEvents::set_governor(&e, old_governor, governor);
+    }
+
+    fn execute(e: Env, calls: Vec<(Symbol, Vec<Val>)>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Governor);
+
+        for (function, args) in calls.iter() {
+            execute_call(&e, &function, &args);
+
+            AdminEvents::execute(&e, function, args);
+        }
+    }
+
+    fn queue_set_pool_admin(e: Env, pool: Address, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let unlock_time = e.ledger().timestamp() + POOL_ADMIN_TIMELOCK;
+        storage::set_queued_pool_admin(
+            &e,
+            &pool,
+            &QueuedPoolAdmin {
+                new_admin: new_admin.clone(),
+                unlock_time,
+            },
+        );
+
+        AdminEvents::queue_set_pool_admin(&e, pool, new_admin, unlock_time);
+    }
+
+    fn cancel_set_pool_admin(e: Env, caller: Address, pool: Address) {
+        storage::extend_instance(&e);
+        caller.require_auth();
+        if !orbit_access::has_role(&e, Role::Owner, &caller)
+            && !orbit_access::has_role(&e, Role::Guardian, &caller)
+        {
+            panic_with_error!(&e, AdminError::UnauthorizedError);
+        }
+
+        let queued = storage::get_queued_pool_admin(&e, &pool)
+            .unwrap_or_else(|| panic_with_error!(&e, AdminError::NoQueuedPoolAdmin));
+        storage::del_queued_pool_admin(&e, &pool);
+
+        AdminEvents::cancel_set_pool_admin(&e, pool, queued.new_admin);
+    }
+
+    fn set_pool_admin(e: Env, pool: Address, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let queued = storage::get_queued_pool_admin(&e, &pool)
+            .unwrap_or_else(|| panic_with_error!(&e, AdminError::NoQueuedPoolAdmin));
+        if queued.new_admin != new_admin {
+            panic_with_error!(&e, AdminError::NoQueuedPoolAdmin);
+        }
+        if queued.unlock_time > e.ledger().timestamp() {
+            panic_with_error!(&e, AdminError::TimelockNotExpired);
+        }
+
+        PoolClient::new(&e, &pool).set_admin(&new_admin);
+        storage::del_queued_pool_admin(&e, &pool);
+
+        AdminEvents::set_pool_admin(&e, pool, new_admin);
+    }
+
+    fn grant(e: Env, grantee: Address, function: Symbol) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        orbit_access::grant(&e, &grantee, &function);
+
+        AdminEvents::grant(&e, grantee, function);
+    }
+
+    fn revoke(e: Env, grantee: Address, function: Symbol) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        orbit_access::revoke(&e, &grantee, &function);
+
+        AdminEvents::revoke(&e, grantee, function);
+    }
+
+    fn has_grant(e: Env, grantee: Address, function: Symbol) -> bool {
+        storage::extend_instance(&e);
+        orbit_access::has_grant(&e, &grantee, &function)
+    }
+
+    fn set_pool_status(e: Env, caller: Address, pool: Address, pool_status: u32) {
+        storage::extend_instance(&e);
+        require_role_or_grant(&e, &caller, Role::Owner, "set_pool_status");
+
+        set_pool_status(&e, pool, pool_status);
+    }
+
+    fn freeze_pool(e: Env, caller: Address, pool: Address) {
+        storage::extend_instance(&e);
+        require_role_or_grant(&e, &caller, Role::Guardian, "freeze_pool");
+
+        PoolClient::new(&e, &pool).set_status(&4_u32); // Admin Frozen PoolStatus
+        storage::set_pool_status(&e, &pool, 4);
+
+        AdminEvents::freeze_pool(&e, pool, caller);
+    }
+
+    fn cancel_set_reserve(e: Env, caller: Address, pool: Address, asset: Address) {
+        storage::extend_instance(&e);
+        require_role_or_grant(&e, &caller, Role::Guardian, "cancel_set_reserve");
+
+        PoolClient::new(&e, &pool).cancel_set_reserve(&asset);
+
+        let cancelled = storage::get_reserve_config(&e, &pool, &asset);
+        if let Some((config, prev_config)) = storage::get_reserve_snapshot(&e, &pool, &asset) {
+            match config {
+                Some(config) => storage::set_reserve_config(&e, &pool, &asset, &config),
+                None => storage::del_reserve_config(&e, &pool, &asset),
+            }
+            match prev_config {
+                Some(prev_config) => {
+                    storage::set_prev_reserve_config(&e, &pool, &asset, &prev_config)
+                }
+                None => storage::del_prev_reserve_config(&e, &pool, &asset),
+            }
+            storage::del_reserve_snapshot(&e, &pool, &asset);
+        }
+
+        AdminEvents::cancel_set_reserve(&e, pool, asset, cancelled);
+    }
+
+    fn queue_set_reserve(e: Env, pool: Address, asset: Address, config: ReserveConfig) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        queue_reserve_config(&e, &PoolClient::new(&e, &pool), &asset, &config);
+    }
+
+    fn batch_update_reserves(e: Env, pool: Address, updates: Vec<(Address, ReserveConfig)>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Owner);
+
+        let pool_client = PoolClient::new(&e, &pool);
+        for (asset, config) in updates.iter() {
+            queue_reserve_config(&e, &pool_client, &asset, &config);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn set_ir_params(
+        e: Env,
+        caller: Address,
+        pool: Address,
+        asset: Address,
+        r_one: u32,
+        r_two: u32,
+        r_three: u32,
+        util: u32,
+    ) {
+        storage::extend_instance(&e);
+        require_role_or_grant(&e, &caller, Role::IrManager, "set_ir_params");
+
+        let mut config = storage::get_reserve_config(&e, &pool, &asset)
+            .unwrap_or_else(|| panic_with_error!(&e, AdminError::ReserveNotConfigured));
+        config.r_one = r_one;
+        config.r_two = r_two;
+        config.r_three = r_three;
+        config.util = util;
+
+        queue_reserve_config(&e, &PoolClient::new(&e, &pool), &asset, &config);
+    }
+
+    fn rollback_reserve(e: Env, caller: Address, pool: Address, asset: Address) {
+        storage::extend_instance(&e);
+        require_role_or_grant(&e, &caller, Role::Owner, "rollback_reserve");
+
+        rollback_reserve_config(&e, pool, asset);
+    }
+
+    fn get_reserve_config(e: Env, pool: Address, asset: Address) -> ReserveConfig {
+        storage::extend_instance(&e);
+        storage::get_reserve_config(&e, &pool, &asset)
+            .unwrap_or_else(|| panic_with_error!(&e, AdminError::ReserveNotConfigured))
+    }
+}
+
+/// Require that `caller` authorized the invocation and either holds the role guarding
+/// `function` or was granted access to it
+fn require_role_or_grant(e: &Env, caller: &Address, role: Role, function: &str) {
+    orbit_access::require_role_or_grant(e, caller, role, &Symbol::new(e, function));
+}
+
+/// Execute a governance call through the admin function it names
+///
+/// ### Panics
+/// If the function can't be executed by the governor, or the arguments don't match it
+fn execute_call(e: &Env, function: &Symbol, args: &Vec<Val>) {
+    if *function == Symbol::new(e, "queue_set_reserve") {
+        require_arg_count(e, args, 3);
+        let pool: Address = load_arg(e, args, 0);
+        let asset: Address = load_arg(e, args, 1);
+        let config: ReserveConfig = load_arg(e, args, 2);
+        queue_reserve_config(e, &PoolClient::new(e, &pool), &asset, &config);
+    } else if *function == Symbol::new(e, "batch_update_reserves") {
+        require_arg_count(e, args, 2);
+        let pool: Address = load_arg(e, args, 0);
+        let updates: Vec<(Address, ReserveConfig)> = load_arg(e, args, 1);
+        let pool_client = PoolClient::new(e, &pool);
+        for (asset, config) in updates.iter() {
+            queue_reserve_config(e, &pool_client, &asset, &config);
+        }
+    } else if *function == Symbol::new(e, "set_pool_status") {
+        require_arg_count(e, args, 2);
+        set_pool_status(e, load_arg(e, args, 0), load_arg(e, args, 1));
+    } else if *function == Symbol::new(e, "rollback_reserve") {
+        require_arg_count(e, args, 2);
+        rollback_reserve_config(e, load_arg(e, args, 0), load_arg(e, args, 1));
+    } else {
+        panic_with_error!(e, AdminError::InvalidCall);
+    }
+}
+
+/// Require that a governance call has the number of arguments of the function it names
+///
+/// ### Panics
+/// If the call has another number of arguments
+fn require_arg_count(e: &Env, args: &Vec<Val>, count: u32) {
+    if args.len() != count {
+        panic_with_error!(e, AdminError::InvalidCall);
+    }
+}
+
+/// Load an argument of a governance call
+///
+/// ### Panics
+/// If the argument is not of the type the function takes
+fn load_arg<T: TryFromVal<Env, Val>>(e: &Env, args: &Vec<Val>, index: u32) -> T {
+    T::try_from_val(e, &args.get_unchecked(index))
+        .unwrap_or_else(|_| panic_with_error!(e, AdminError::InvalidCall))
+}
+
+/// Set the status of a pool and emit the change
+fn set_pool_status(e: &Env, pool: Address, pool_status: u32) {
+    let old_status = storage::get_pool_status(e, &pool);
+    PoolClient::new(e, &pool).set_status(&pool_status);
+    storage::set_pool_status(e, &pool, pool_status);
+
+    AdminEvents::set_pool_status(e, pool, old_status, pool_status);
+}
+
+/// Queue the reserve config that was replaced by the last update of a reserve
+///
+/// ### Panics
+/// If there is no previous reserve config to restore
+fn rollback_reserve_config(e: &Env, pool: Address, asset: Address) {
+    let prev_config = storage::get_prev_reserve_config(e, &pool, &asset)
+        .unwrap_or_else(|| panic_with_error!(e, AdminError::NoPreviousReserveConfig));
+    let config = storage::get_reserve_config(e, &pool, &asset).unwrap_optimized();
+
+    snapshot_reserve(e, &pool, &asset);
+    PoolClient::new(e, &pool).queue_set_reserve(&asset, &prev_config);
+    storage::set_reserve_config(e, &pool, &asset, &prev_config);
+    storage::del_prev_reserve_config(e, &pool, &asset);
+
+    AdminEvents::rollback_reserve(e, pool, asset, config, prev_config);
+}
+
+/// Record the reserve configs of a reserve before an update is queued, so cancelling the update
+/// restores them
+fn snapshot_reserve(e: &Env, pool: &Address, asset: &Address) {
+    let snapshot = (
+        storage::get_reserve_config(e, pool, asset),
+        storage::get_prev_reserve_config(e, pool, asset),
+    );
+    storage::set_reserve_snapshot(e, pool, asset, &snapshot);
+}
+
+/// Queue a reserve config on a pool, record it as the latest config for the reserve while
+/// keeping the one it replaces, and emit the change
+fn queue_reserve_config(e: &Env, pool_client: &PoolClient, asset: &Address, config: &ReserveConfig) {
+    let pool = pool_client.address.clone();
+    let old_config = storage::get_reserve_config(e, &pool, asset);
+    snapshot_reserve(e, &pool, asset);
+    pool_client.queue_set_reserve(asset, config);
+    storage::set_reserve_config(e, &pool, asset, config);
+
+    match old_config {
+        Some(old_config) => {
+            storage::set_prev_reserve_config(e, &pool, asset, &old_config);
+            AdminEvents::queue_set_reserve(e, pool, asset.clone(), old_config, config.clone())
+        }
+        None => AdminEvents::queue_init_reserve(e, pool, asset.clone(), config.clone()),
+    }
+}
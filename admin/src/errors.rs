@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the admin contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Admin specific errors start at 2100.
+pub enum AdminError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Admin
+    ReserveNotConfigured = 2100,
+    NoPendingOwner = 2101,
+    NoPreviousReserveConfig = 2102,
+    NoQueuedPoolAdmin = 2103,
+    TimelockNotExpired = 2104,
+    InvalidCall = 2105,
+}
@@ -0,0 +1,201 @@
+use orbit_events::{topics, ReserveConfigUpdate, RoleUpdate};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Val, Vec};
+
+use crate::dependencies::pool::ReserveConfig;
+
+pub struct AdminEvents {}
+
+impl AdminEvents {
+    /// Emitted when the admin contract is initialized
+    ///
+    /// - topics - `["initialize"]`
+    /// - data - `[owner: Address, ir_manager: Address, guardian: Address]`
+    pub fn initialize(e: &Env, owner: Address, ir_manager: Address, guardian: Address) {
+        let topics = (Symbol::new(e, topics::INITIALIZE),);
+        e.events().publish(topics, (owner, ir_manager, guardian));
+    }
+
+    /// Emitted when the owner proposes a new owner
+    ///
+    /// - topics - `["propose_ownership", owner: Address]`
+    /// - data - `new_owner: Address`
+    pub fn propose_ownership(e: &Env, owner: Address, new_owner: Address) {
+        let topics = (Symbol::new(e, topics::PROPOSE_OWNERSHIP), owner);
+        e.events().publish(topics, new_owner);
+    }
+
+    /// Emitted when a proposed owner accepts ownership
+    ///
+    /// - topics - `["accept_ownership"]`
+    /// - data - `RoleUpdate`
+    pub fn accept_ownership(e: &Env, old_owner: Address, new_owner: Address) {
+        let topics = (Symbol::new(e, topics::ACCEPT_OWNERSHIP),);
+        let data = RoleUpdate {
+            old: old_owner,
+            new: new_owner,
+        };
+        e.events().publish(topics, data);
+    }
+
+    /// Emitted when the contract is upgraded
+    ///
+    /// - topics - `["upgrade", owner: Address]`
+    /// - data - `new_wasm_hash: BytesN<32>`
+    pub fn upgrade(e: &Env, owner: Address, new_wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, topics::UPGRADE), owner);
+        e.events().publish(topics, new_wasm_hash);
+    }
+
+    /// Emitted when the interest rate manager is changed
+    ///
+    /// - topics - `["set_ir_manager"]`
+    /// - data - `RoleUpdate`
+    pub fn set_ir_manager(e: &Env, old: Address, new: Address) {
+        let topics = (Symbol::new(e, topics::SET_IR_MANAGER),);
+        e.events().publish(topics, RoleUpdate { old, new });
+    }
+
+    /// Emitted when the guardian is changed
+    ///
+    /// - topics - `["set_guardian"]`
+    /// - data - `RoleUpdate`
+    pub fn set_guardian(e: &Env, old: Address, new: Address) {
+        let topics = (Symbol::new(e, topics::SET_GUARDIAN),);
+        e.events().publish(topics, RoleUpdate { old, new });
+    }
+
+    /// Emitted when the governor is changed
+    ///
+    /// - topics - `["set_governor"]`
+    /// - data - `[old: Option<Address>, new: Address]`
+    pub fn set_governor(e: &Env, old: Option<Address>, new: Address) {
+        let topics = (Symbol::new(e, topics::SET_GOVERNOR),);
+        e.events().publish(topics, (old, new));
+    }
+
+    /// Emitted for each call the governor executes through the admin contract
+    ///
+    /// - topics - `["execute", function: Symbol]`
+    /// - data - `args: Vec<Val>`
+    pub fn execute(e: &Env, function: Symbol, args: Vec<Val>) {
+        let topics = (Symbol::new(e, topics::EXECUTE), function);
+        e.events().publish(topics, args);
+    }
+
+    /// Emitted when the owner grants an address access to an admin function
+    ///
+    /// - topics - `["grant", grantee: Address]`
+    /// - data - `function: Symbol`
+    pub fn grant(e: &Env, grantee: Address, function: Symbol) {
+        let topics = (Symbol::new(e, topics::GRANT), grantee);
+        e.events().publish(topics, function);
+    }
+
+    /// Emitted when the owner revokes an address's access to an admin function
+    ///
+    /// - topics - `["revoke", grantee: Address]`
+    /// - data - `function: Symbol`
+    pub fn revoke(e: &Env, grantee: Address, function: Symbol) {
+        let topics = (Symbol::new(e, topics::REVOKE), grantee);
+        e.events().publish(topics, function);
+    }
+
+    /// Emitted when the owner queues a transfer of a pool's admin
+    ///
+    /// - topics - `["queue_set_pool_admin", pool: Address]`
+    /// - data - `[new_admin: Address, unlock_time: u64]`
+    pub fn queue_set_pool_admin(e: &Env, pool: Address, new_admin: Address, unlock_time: u64) {
+        let topics = (Symbol::new(e, topics::QUEUE_SET_POOL_ADMIN), pool);
+        e.events().publish(topics, (new_admin, unlock_time));
+    }
+
+    /// Emitted when a queued transfer of a pool's admin is cancelled
+    ///
+    /// - topics - `["cancel_set_pool_admin", pool: Address]`
+    /// - data - `new_admin: Address`
+    pub fn cancel_set_pool_admin(e: &Env, pool: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, topics::CANCEL_SET_POOL_ADMIN), pool);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a pool's admin is transferred away from this contract
+    ///
+    /// - topics - `["set_pool_admin", pool: Address]`
+    /// - data - `new_admin: Address`
+    pub fn set_pool_admin(e: &Env, pool: Address, new_admin: Address) {
+        let topics = (Symbol::new(e, topics::SET_POOL_ADMIN), pool);
+        e.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when the owner or a grantee sets the status of a pool. The old status is the one
+    /// last set through this contract, if any.
+    ///
+    /// - topics - `["set_pool_status", pool: Address]`
+    /// - data - `[old: Option<u32>, new: u32]`
+    pub fn set_pool_status(e: &Env, pool: Address, old: Option<u32>, new: u32) {
+        let topics = (Symbol::new(e, topics::SET_POOL_STATUS), pool);
+        e.events().publish(topics, (old, new));
+    }
+
+    /// Emitted when the guardian or a grantee freezes a pool
+    ///
+    /// - topics - `["freeze_pool", pool: Address]`
+    /// - data - `caller: Address`
+    pub fn freeze_pool(e: &Env, pool: Address, caller: Address) {
+        let topics = (Symbol::new(e, topics::FREEZE_POOL), pool);
+        e.events().publish(topics, caller);
+    }
+
+    /// Emitted when the first reserve config for an asset is queued on a pool
+    ///
+    /// - topics - `["queue_init_reserve", pool: Address, asset: Address]`
+    /// - data - `config: ReserveConfig`
+    pub fn queue_init_reserve(e: &Env, pool: Address, asset: Address, config: ReserveConfig) {
+        let topics = (Symbol::new(e, topics::QUEUE_INIT_RESERVE), pool, asset);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when an update to an existing reserve config is queued on a pool
+    ///
+    /// - topics - `["queue_set_reserve", pool: Address, asset: Address]`
+    /// - data - `ReserveConfigUpdate`
+    pub fn queue_set_reserve(
+        e: &Env,
+        pool: Address,
+        asset: Address,
+        old: ReserveConfig,
+        new: ReserveConfig,
+    ) {
+        let topics = (Symbol::new(e, topics::QUEUE_SET_RESERVE), pool, asset);
+        e.events().publish(topics, ReserveConfigUpdate { old, new });
+    }
+
+    /// Emitted when a reserve config is rolled back to the config it replaced
+    ///
+    /// - topics - `["rollback_reserve", pool: Address, asset: Address]`
+    /// - data - `ReserveConfigUpdate`
+    pub fn rollback_reserve(
+        e: &Env,
+        pool: Address,
+        asset: Address,
+        old: ReserveConfig,
+        new: ReserveConfig,
+    ) {
+        let topics = (Symbol::new(e, topics::ROLLBACK_RESERVE), pool, asset);
+        e.events().publish(topics, ReserveConfigUpdate { old, new });
+    }
+
+    /// Emitted when the guardian or a grantee cancels a queued reserve config update
+    ///
+    /// - topics - `["cancel_set_reserve", pool: Address, asset: Address]`
+    /// - data - `cancelled: Option<ReserveConfig>`
+    pub fn cancel_set_reserve(
+        e: &Env,
+        pool: Address,
+        asset: Address,
+        cancelled: Option<ReserveConfig>,
+    ) {
+        let topics = (Symbol::new(e, topics::CANCEL_SET_RESERVE), pool, asset);
+        e.events().publish(topics, cancelled);
+    }
+}
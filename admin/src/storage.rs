@@ -0,0 +1,281 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::dependencies::pool::ReserveConfig;
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+
+/// A pool admin transfer waiting for its timelock to expire
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedPoolAdmin {
+    pub new_admin: Address,
+    pub unlock_time: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum AdminDataKey {
+    // The last reserve config queued by this contract for a (pool, asset) pair
+    ResConfig(Address, Address),
+    // The reserve config replaced by the last update for a (pool, asset) pair
+    PrevResConfig(Address, Address),
+    // The (config, previous config) recorded before the queued update for a (pool, asset) pair
+    ResSnapshot(Address, Address),
+    // The queued admin transfer for a pool
+    PoolAdmin(Address),
+    // The last status this contract set on a pool
+    PoolStatus(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Pool Admin Transfers **********/
+
+/// Fetch the queued admin transfer for a pool, if any
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn get_queued_pool_admin(e: &Env, pool: &Address) -> Option<QueuedPoolAdmin> {
+    let key = AdminDataKey::PoolAdmin(pool.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<AdminDataKey, QueuedPoolAdmin>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the queued admin transfer for a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `queued` - The queued admin transfer
+pub fn set_queued_pool_admin(e: &Env, pool: &Address, queued: &QueuedPoolAdmin) {
+    let key = AdminDataKey::PoolAdmin(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<AdminDataKey, QueuedPoolAdmin>(&key, queued);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the queued admin transfer for a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn del_queued_pool_admin(e: &Env, pool: &Address) {
+    let key = AdminDataKey::PoolAdmin(pool.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/********** Pool Status **********/
+
+/// Fetch the last status this contract set on a pool, if it set one
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn get_pool_status(e: &Env, pool: &Address) -> Option<u32> {
+    let key = AdminDataKey::PoolStatus(pool.clone());
+    let result = e.storage().persistent().get::<AdminDataKey, u32>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the last status this contract set on a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `pool_status` - The status set on the pool
+pub fn set_pool_status(e: &Env, pool: &Address, pool_status: u32) {
+    let key = AdminDataKey::PoolStatus(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<AdminDataKey, u32>(&key, &pool_status);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/********** Reserve Configs **********/
+
+/// Fetch the last reserve config queued for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn get_reserve_config(e: &Env, pool: &Address, asset: &Address) -> Option<ReserveConfig> {
+    let key = AdminDataKey::ResConfig(pool.clone(), asset.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<AdminDataKey, ReserveConfig>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the reserve config queued for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+/// * `config` - The reserve config
+pub fn set_reserve_config(e: &Env, pool: &Address, asset: &Address, config: &ReserveConfig) {
+    let key = AdminDataKey::ResConfig(pool.clone(), asset.clone());
+    e.storage()
+        .persistent()
+        .set::<AdminDataKey, ReserveConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the reserve config queued for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn del_reserve_config(e: &Env, pool: &Address, asset: &Address) {
+    let key = AdminDataKey::ResConfig(pool.clone(), asset.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/// Fetch the reserve config replaced by the last update for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn get_prev_reserve_config(
+    e: &Env,
+    pool: &Address,
+    asset: &Address,
+) -> Option<ReserveConfig> {
+    let key = AdminDataKey::PrevResConfig(pool.clone(), asset.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<AdminDataKey, ReserveConfig>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the reserve config replaced by the last update for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+/// * `config` - The replaced reserve config
+pub fn set_prev_reserve_config(
+    e: &Env,
+    pool: &Address,
+    asset: &Address,
+    config: &ReserveConfig,
+) {
+    let key = AdminDataKey::PrevResConfig(pool.clone(), asset.clone());
+    e.storage()
+        .persistent()
+        .set::<AdminDataKey, ReserveConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the replaced reserve config for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn del_prev_reserve_config(e: &Env, pool: &Address, asset: &Address) {
+    let key = AdminDataKey::PrevResConfig(pool.clone(), asset.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/// Fetch the (config, previous config) recorded before the queued update for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn get_reserve_snapshot(
+    e: &Env,
+    pool: &Address,
+    asset: &Address,
+) -> Option<(Option<ReserveConfig>, Option<ReserveConfig>)> {
+    let key = AdminDataKey::ResSnapshot(pool.clone(), asset.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<AdminDataKey, (Option<ReserveConfig>, Option<ReserveConfig>)>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the (config, previous config) recorded before the queued update for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+/// * `snapshot` - The (config, previous config) before the update
+pub fn set_reserve_snapshot(
+    e: &Env,
+    pool: &Address,
+    asset: &Address,
+    snapshot: &(Option<ReserveConfig>, Option<ReserveConfig>),
+) {
+    let key = AdminDataKey::ResSnapshot(pool.clone(), asset.clone());
+    e.storage()
+        .persistent()
+        .set::<AdminDataKey, (Option<ReserveConfig>, Option<ReserveConfig>)>(&key, snapshot);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the (config, previous config) recorded before the queued update for an asset in a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `asset` - The Address of the reserve asset
+pub fn del_reserve_snapshot(e: &Env, pool: &Address, asset: &Address) {
+    let key = AdminDataKey::ResSnapshot(pool.clone(), asset.clone());
+    e.storage().persistent().remove(&key);
+}
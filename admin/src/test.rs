@@ -0,0 +1,604 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{AdminClient, AdminContract};
+use crate::errors::AdminError;
+use orbit_events::{ReserveConfigUpdate, RoleUpdate};
+use crate::dependencies::pool::{Client as PoolClient, PoolDataKey, ReserveConfig, WASM as POOL_WASM};
+use soroban_sdk::testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Val, Vec};
+
+/// Accepts any status, like a pool whose backstop allows every status
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_status(_e: Env, _pool_status: u32) {}
+}
+
+fn default_reserve_config() -> ReserveConfig {
+    ReserveConfig {
+        decimals: 7,
+        c_factor: 0_7500000,
+        l_factor: 0_7500000,
+        util: 0_7500000,
+        max_util: 0_9500000,
+        r_one: 0_0500000,
+        r_two: 0_5000000,
+        r_three: 1_5000000,
+        reactivity: 0_0000020,
+        index: 0,
+    }
+}
+
+fn create_admin_with_pool<'a>(e: &Env) -> (AdminClient<'a>, PoolClient<'a>) {
+    let admin_id = e.register_contract(None, AdminContract);
+    let admin_client = AdminClient::new(e, &admin_id);
+
+    let pool_id = e.register_contract_wasm(None, POOL_WASM);
+    let pool_client = PoolClient::new(e, &pool_id);
+    pool_client.initialize(
+        &admin_id,
+        &Symbol::new(e, "Teapot"),
+        &Address::generate(e),
+        &0_1000000,
+        &4,
+        &Address::generate(e),
+        &Address::generate(e),
+        &Address::generate(e),
+    );
+    (admin_client, pool_client)
+}
+
+fn read_pool_reserve_config(e: &Env, pool: &Address, asset: &Address) -> ReserveConfig {
+    e.as_contract(pool, || {
+        e.storage()
+            .persistent()
+            .get(&PoolDataKey::ResConfig(asset.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_set_ir_params() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let ir_manager = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &ir_manager, &Address::generate(&e));
+
+    let config = default_reserve_config();
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &config);
+    pool_client.set_reserve(&asset);
+
+    admin_client.set_ir_params(
+        &ir_manager,
+        &pool_client.address,
+        &asset,
+        &0_0400000,
+        &0_4000000,
+        &2_0000000,
+        &0_8000000,
+    );
+    assert_eq!(e.auths()[0].0, ir_manager);
+    let events = e.events().all();
+    let mut expected_config = config.clone();
+    expected_config.r_one = 0_0400000;
+    expected_config.r_two = 0_4000000;
+    expected_config.r_three = 2_0000000;
+    expected_config.util = 0_8000000;
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (
+                    Symbol::new(&e, "queue_set_reserve"),
+                    pool_client.address.clone(),
+                    asset.clone()
+                )
+                    .into_val(&e),
+                ReserveConfigUpdate {
+                    old: config.clone(),
+                    new: expected_config,
+                }
+                .into_val(&e)
+            )
+        ]
+    );
+    pool_client.set_reserve(&asset);
+
+    let new_config = read_pool_reserve_config(&e, &pool_client.address, &asset);
+    assert_eq!(new_config.r_one, 0_0400000);
+    assert_eq!(new_config.r_two, 0_4000000);
+    assert_eq!(new_config.r_three, 2_0000000);
+    assert_eq!(new_config.util, 0_8000000);
+    assert_eq!(new_config.c_factor, config.c_factor);
+    assert_eq!(new_config.max_util, config.max_util);
+    assert_eq!(admin_client.get_reserve_config(&pool_client.address, &asset).r_one, 0_0400000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2100)")]
+fn test_set_ir_params_requires_configured_reserve() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let ir_manager = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&Address::generate(&e), &ir_manager, &Address::generate(&e));
+
+    admin_client.set_ir_params(
+        &ir_manager,
+        &pool_client.address,
+        &Address::generate(&e),
+        &0_0400000,
+        &0_4000000,
+        &2_0000000,
+        &0_8000000,
+    );
+}
+
+#[test]
+fn test_batch_update_reserves() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let asset_0 = Address::generate(&e);
+    let asset_1 = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+
+    let config_0 = default_reserve_config();
+    let mut config_1 = default_reserve_config();
+    config_1.c_factor = 0_9000000;
+    config_1.index = 1;
+    admin_client.batch_update_reserves(
+        &pool_client.address,
+        &vec![
+            &e,
+            (asset_0.clone(), config_0.clone()),
+            (asset_1.clone(), config_1.clone()),
+        ],
+    );
+    assert_eq!(e.auths()[0].0, owner);
+    pool_client.set_reserve(&asset_0);
+    pool_client.set_reserve(&asset_1);
+
+    assert_eq!(
+        read_pool_reserve_config(&e, &pool_client.address, &asset_0).c_factor,
+        config_0.c_factor
+    );
+    assert_eq!(
+        read_pool_reserve_config(&e, &pool_client.address, &asset_1).c_factor,
+        config_1.c_factor
+    );
+    assert_eq!(
+        admin_client.get_reserve_config(&pool_client.address, &asset_1).c_factor,
+        config_1.c_factor
+    );
+}
+
+#[test]
+fn test_ownership_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let (admin_client, _) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+
+    admin_client.propose_ownership(&new_owner);
+    assert_eq!(e.auths()[0].0, owner);
+    assert_eq!(admin_client.get_owner(), owner);
+
+    admin_client.accept_ownership();
+    assert_eq!(e.auths()[0].0, new_owner);
+    assert_eq!(admin_client.get_owner(), new_owner);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "accept_ownership"),).into_val(&e),
+                RoleUpdate {
+                    old: owner.clone(),
+                    new: new_owner.clone(),
+                }
+                .into_val(&e)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2101)")]
+fn test_accept_ownership_requires_proposal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let (admin_client, _) = create_admin_with_pool(&e);
+    admin_client.initialize(
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &Address::generate(&e),
+    );
+
+    admin_client.accept_ownership();
+}
+
+#[test]
+fn test_guardian_cancel_set_reserve() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+    admin_client.set_guardian(&guardian);
+    assert_eq!(e.auths()[0].0, owner);
+
+    let config = default_reserve_config();
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &config);
+    admin_client.cancel_set_reserve(&guardian, &pool_client.address, &asset);
+    assert_eq!(e.auths()[0].0, guardian);
+    assert!(pool_client.try_set_reserve(&asset).is_err());
+    let result = admin_client.try_get_reserve_config(&pool_client.address, &asset);
+    assert_eq!(result.err(), Some(Ok(AdminError::ReserveNotConfigured.into())));
+
+    // a cancelled update restores the configs recorded before it
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &config);
+    pool_client.set_reserve(&asset);
+    let mut bad_config = config.clone();
+    bad_config.c_factor = 0_9900000;
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &bad_config);
+    admin_client.cancel_set_reserve(&guardian, &pool_client.address, &asset);
+    assert_eq!(
+        admin_client.get_reserve_config(&pool_client.address, &asset).c_factor,
+        config.c_factor
+    );
+    let result = admin_client.try_rollback_reserve(&owner, &pool_client.address, &asset);
+    assert_eq!(result.err(), Some(Ok(AdminError::NoPreviousReserveConfig.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_cancel_set_reserve_requires_guardian() {
+    let e = Env::default();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+
+    admin_client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &admin_client.address,
+                fn_name: "cancel_set_reserve",
+                args: (owner.clone(), pool_client.address.clone(), asset.clone()).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_set_reserve(&owner, &pool_client.address, &asset);
+}
+
+#[test]
+fn test_rollback_reserve() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+
+    let config = default_reserve_config();
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &config);
+    pool_client.set_reserve(&asset);
+    let mut bad_config = config.clone();
+    bad_config.c_factor = 0_9900000;
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &bad_config);
+    pool_client.set_reserve(&asset);
+
+    admin_client.rollback_reserve(&owner, &pool_client.address, &asset);
+    assert_eq!(e.auths()[0].0, owner);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (
+                    Symbol::new(&e, "rollback_reserve"),
+                    pool_client.address.clone(),
+                    asset.clone()
+                )
+                    .into_val(&e),
+                ReserveConfigUpdate {
+                    old: bad_config.clone(),
+                    new: config.clone(),
+                }
+                .into_val(&e)
+            )
+        ]
+    );
+    pool_client.set_reserve(&asset);
+    assert_eq!(
+        read_pool_reserve_config(&e, &pool_client.address, &asset).c_factor,
+        config.c_factor
+    );
+    assert_eq!(
+        admin_client.get_reserve_config(&pool_client.address, &asset).c_factor,
+        config.c_factor
+    );
+
+    let result = admin_client.try_rollback_reserve(&owner, &pool_client.address, &asset);
+    assert_eq!(result.err(), Some(Ok(AdminError::NoPreviousReserveConfig.into())));
+}
+
+#[test]
+fn test_set_pool_admin() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+
+    admin_client.queue_set_pool_admin(&pool_client.address, &new_admin);
+    assert_eq!(e.auths()[0].0, owner);
+
+    let result = admin_client.try_set_pool_admin(&pool_client.address, &new_admin);
+    assert_eq!(result.err(), Some(Ok(AdminError::TimelockNotExpired.into())));
+
+    e.ledger().with_mut(|li| li.timestamp += 7 * 24 * 60 * 60);
+    let result = admin_client.try_set_pool_admin(&pool_client.address, &Address::generate(&e));
+    assert_eq!(result.err(), Some(Ok(AdminError::NoQueuedPoolAdmin.into())));
+
+    admin_client.set_pool_admin(&pool_client.address, &new_admin);
+    assert_eq!(e.auths()[0].0, owner);
+    let pool_admin: Address = e.as_contract(&pool_client.address, || {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "Admin"))
+            .unwrap()
+    });
+    assert_eq!(pool_admin, new_admin);
+
+    let result = admin_client.try_set_pool_admin(&pool_client.address, &new_admin);
+    assert_eq!(result.err(), Some(Ok(AdminError::NoQueuedPoolAdmin.into())));
+}
+
+#[test]
+fn test_guardian_cancel_set_pool_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &guardian);
+
+    admin_client.queue_set_pool_admin(&pool_client.address, &new_admin);
+    admin_client.cancel_set_pool_admin(&guardian, &pool_client.address);
+    assert_eq!(e.auths()[0].0, guardian);
+
+    e.ledger().with_mut(|li| li.timestamp += 7 * 24 * 60 * 60);
+    let result = admin_client.try_set_pool_admin(&pool_client.address, &new_admin);
+    assert_eq!(result.err(), Some(Ok(AdminError::NoQueuedPoolAdmin.into())));
+}
+
+#[test]
+fn test_set_pool_status() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let (admin_client, _) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &guardian);
+    let pool = e.register_contract(None, MockPool);
+
+    admin_client.set_pool_status(&owner, &pool, &2);
+    assert_eq!(e.auths()[0].0, owner);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "set_pool_status"), pool.clone()).into_val(&e),
+                (None::<u32>, 2_u32).into_val(&e)
+            )
+        ]
+    );
+
+    // the status a freeze set is the old status of the next change
+    admin_client.freeze_pool(&guardian, &pool);
+    admin_client.set_pool_status(&owner, &pool, &3);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "set_pool_status"), pool.clone()).into_val(&e),
+                (Some(4_u32), 3_u32).into_val(&e)
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_governor_execute() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let governor = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+    admin_client.set_governor(&governor);
+    assert_eq!(e.auths()[0].0, owner);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "set_governor"),).into_val(&e),
+                (None::<Address>, governor.clone()).into_val(&e)
+            )
+        ]
+    );
+
+    let config = default_reserve_config();
+    let args: Vec<Val> = (pool_client.address.clone(), asset.clone(), config.clone()).into_val(&e);
+    admin_client.execute(&vec![&e, (Symbol::new(&e, "queue_set_reserve"), args.clone())]);
+    assert_eq!(e.auths()[0].0, governor);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "execute"), Symbol::new(&e, "queue_set_reserve")).into_val(&e),
+                args.into_val(&e)
+            )
+        ]
+    );
+    pool_client.set_reserve(&asset);
+    assert_eq!(
+        read_pool_reserve_config(&e, &pool_client.address, &asset).c_factor,
+        config.c_factor
+    );
+
+    assert_eq!(
+        admin_client.get_reserve_config(&pool_client.address, &asset).c_factor,
+        config.c_factor
+    );
+
+    // only the admin functions can be called, with their own arguments
+    let args: Vec<Val> = (Address::generate(&e),).into_val(&e);
+    let result = admin_client.try_execute(&vec![&e, (Symbol::new(&e, "set_admin"), args)]);
+    assert_eq!(result.err(), Some(Ok(AdminError::InvalidCall.into())));
+    let args: Vec<Val> = (pool_client.address.clone(), asset.clone()).into_val(&e);
+    let result = admin_client.try_execute(&vec![&e, (Symbol::new(&e, "set_pool_status"), args)]);
+    assert_eq!(result.err(), Some(Ok(AdminError::InvalidCall.into())));
+    let mut new_config = config.clone();
+    new_config.c_factor = 0_8000000;
+    let updates = vec![&e, (asset.clone(), new_config)];
+    let args: Vec<Val> = (pool_client.address.clone(), updates).into_val(&e);
+    admin_client.execute(&vec![&e, (Symbol::new(&e, "batch_update_reserves"), args)]);
+    assert_eq!(
+        admin_client.get_reserve_config(&pool_client.address, &asset).c_factor,
+        0_8000000
+    );
+
+    // replacing the governor reports the one it replaced
+    let new_governor = Address::generate(&e);
+    admin_client.set_governor(&new_governor);
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "set_governor"),).into_val(&e),
+                (Some(governor), new_governor).into_val(&e)
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_grant_and_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let asset = Address::generate(&e);
+    let (admin_client, pool_client) = create_admin_with_pool(&e);
+    admin_client.initialize(&owner, &Address::generate(&e), &Address::generate(&e));
+    admin_client.queue_set_reserve(&pool_client.address, &asset, &default_reserve_config());
+    pool_client.set_reserve(&asset);
+
+    let function = Symbol::new(&e, "set_ir_params");
+    admin_client.grant(&operator, &function);
+    assert_eq!(e.auths()[0].0, owner);
+    assert!(admin_client.has_grant(&operator, &function));
+    assert!(!admin_client.has_grant(&operator, &Symbol::new(&e, "freeze_pool")));
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                admin_client.address.clone(),
+                (Symbol::new(&e, "grant"), operator.clone()).into_val(&e),
+                function.into_val(&e)
+            )
+        ]
+    );
+
+    admin_client.set_ir_params(
+        &operator,
+        &pool_client.address,
+        &asset,
+        &0_0400000,
+        &0_4000000,
+        &2_0000000,
+        &0_8000000,
+    );
+    assert_eq!(e.auths()[0].0, operator);
+    assert_eq!(admin_client.get_reserve_config(&pool_client.address, &asset).r_one, 0_0400000);
+
+    admin_client.revoke(&operator, &function);
+    assert_eq!(e.auths()[0].0, owner);
+    assert!(!admin_client.has_grant(&operator, &function));
+    let result = admin_client.try_set_ir_params(
+        &operator,
+        &pool_client.address,
+        &asset,
+        &0_0300000,
+        &0_4000000,
+        &2_0000000,
+        &0_8000000,
+    );
+    assert_eq!(result.err(), Some(Ok(AdminError::UnauthorizedError.into())));
+}
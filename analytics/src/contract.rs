@@ -0,0 +1,196 @@
+use crate::dependencies::pool::Client as PoolClient;
+use crate::dependencies::treasury::TreasuryClient;
+use crate::errors::AnalyticsError;
+use crate::events::AnalyticsEvents;
+use crate::storage::{self, ProtocolStats, TokenConfig, TokenStats};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_40_oracle::{Asset, PriceFeedClient};
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+#[contract]
+pub struct AnalyticsContract;
+
+#[contractclient(name = "AnalyticsClient")]
+pub trait Analytics {
+    /// Initialize the analytics contract
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `oracle` - The Address of the oracle pricing collateral in USD
+    /// * `insurance` - The Address of the insurance fund
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, oracle: Address, insurance: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Start tracking a stablecoin
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the stablecoin
+    /// * `config` - The contracts backing the stablecoin
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the stablecoin is already tracked
+    fn add_token(e: Env, token: Address, config: TokenConfig);
+
+    /// (Admin only) Stop tracking a stablecoin
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the stablecoin
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the stablecoin is not tracked
+    fn remove_token(e: Env, token: Address);
+
+    /// Get the tracked stablecoins
+    fn get_tokens(e: Env) -> Vec<Address>;
+
+    /// Get a snapshot of a stablecoin
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the stablecoin
+    ///
+    /// ### Panics
+    /// If the stablecoin is not tracked
+    fn get_token_stats(e: Env, token: Address) -> TokenStats;
+
+    /// Get a snapshot of every tracked stablecoin
+    fn get_protocol_stats(e: Env) -> ProtocolStats;
+}
+
+#[contractimpl]
+impl Analytics for AnalyticsContract {
+    fn initialize(e: Env, admin: Address, oracle: Address, insurance: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, AnalyticsError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_oracle(&e, &oracle);
+        storage::set_insurance(&e, &insurance);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn add_token(e: Env, token: Address, config: TokenConfig) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let mut tokens = storage::get_tokens(&e);
+        if tokens.contains(&token) {
+            panic_with_error!(&e, AnalyticsError::TokenExists);
+        }
+        tokens.push_back(token.clone());
+        storage::set_tokens(&e, &tokens);
+        storage::set_token_config(&e, &token, &config);
+
+        AnalyticsEvents::add_token(&e, token, config);
+    }
+
+    fn remove_token(e: Env, token: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let mut tokens = storage::get_tokens(&e);
+        match tokens.first_index_of(&token) {
+            Some(index) => tokens.remove(index),
+            None => panic_with_error!(&e, AnalyticsError::TokenNotFound),
+        };
+        storage::set_tokens(&e, &tokens);
+        storage::del_token_config(&e, &token);
+
+        AnalyticsEvents::remove_token(&e, token);
+    }
+
+    fn get_tokens(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_tokens(&e)
+    }
+
+    fn get_token_stats(e: Env, token: Address) -> TokenStats {
+        storage::extend_instance(&e);
+        load_token_stats(&e, &token)
+    }
+
+    fn get_protocol_stats(e: Env) -> ProtocolStats {
+        storage::extend_instance(&e);
+        let mut tokens = vec![&e];
+        for token in storage::get_tokens(&e).iter() {
+            tokens.push_back(load_token_stats(&e, &token));
+        }
+        ProtocolStats {
+            tokens,
+            timestamp: e.ledger().timestamp(),
+        }
+    }
+}
+
+/// Load a snapshot of a stablecoin. The amount borrowed is approximated as the supply minted by
+/// the treasury that is not held as liquidity by the pool, and collateral is valued assuming the
+/// stablecoin trades at its peg.
+///
+/// ### Panics
+/// If the stablecoin is not tracked
+fn load_token_stats(e: &Env, token: &Address) -> TokenStats {
+    let config = storage::get_token_config(e, token)
+        .unwrap_or_else(|| panic_with_error!(e, AnalyticsError::TokenNotFound));
+
+    let supply = TreasuryClient::new(e, &config.treasury).get_token_supply();
+    let treasury_position = PoolClient::new(e, &config.pool)
+        .get_positions(&config.treasury)
+        .supply
+        .values()
+        .iter()
+        .sum();
+    let token_client = TokenClient::new(e, token);
+    let borrowed = (supply - token_client.balance(&config.pool)).max(0);
+
+    let oracle = PriceFeedClient::new(e, &storage::get_oracle(e));
+    let scalar = 10i128.pow(oracle.decimals());
+    let mut collateral_value = 0;
+    for asset in config.collateral.iter() {
+        // collateral without a price is left out rather than failing the whole snapshot
+        if let Some(price) = oracle.lastprice(&Asset::Stellar(asset.clone())) {
+            let balance = TokenClient::new(e, &asset).balance(&config.pool);
+            collateral_value += balance * price.price / scalar;
+        }
+    }
+    let collateral_ratio = if borrowed > 0 {
+        math::pct_of(e, collateral_value, borrowed)
+    } else {
+        0
+    };
+
+    TokenStats {
+        token: token.clone(),
+        treasury: config.treasury,
+        pool: config.pool,
+        supply,
+        treasury_position,
+        borrowed,
+        collateral_value,
+        collateral_ratio,
+        insurance_balance: token_client.balance(&storage::get_insurance(e)),
+    }
+}
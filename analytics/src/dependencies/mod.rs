@@ -0,0 +1,2 @@
+pub use orbit_common::pool;
+pub mod treasury;
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Env};
+
+/// The part of the treasury the analytics contract reads
+#[allow(dead_code)]
+#[contractclient(name = "TreasuryClient")]
+pub trait Treasury {
+    fn get_token_supply(e: Env) -> i128;
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the analytics contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Analytics specific errors start at 3700.
+pub enum AnalyticsError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Analytics
+    TokenNotFound = 3700,
+    TokenExists = 3701,
+}
@@ -0,0 +1,26 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::TokenConfig;
+
+pub struct AnalyticsEvents {}
+
+impl AnalyticsEvents {
+    /// Emitted when the admin starts tracking a stablecoin
+    ///
+    /// - topics - `["add_token", token: Address]`
+    /// - data - `config: TokenConfig`
+    pub fn add_token(e: &Env, token: Address, config: TokenConfig) {
+        let topics = (Symbol::new(e, topics::ADD_TOKEN), token);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin stops tracking a stablecoin
+    ///
+    /// - topics - `["remove_token", token: Address]`
+    /// - data - `()`
+    pub fn remove_token(e: &Env, token: Address) {
+        let topics = (Symbol::new(e, topics::REMOVE_TOKEN), token);
+        e.events().publish(topics, ());
+    }
+}
@@ -0,0 +1,173 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, vec, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ORACLE_KEY: &str = "Oracle";
+const INSURANCE_KEY: &str = "Insurance";
+const TOKENS_KEY: &str = "Tokens";
+
+/// The contracts backing a stablecoin
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TokenConfig {
+    pub treasury: Address,        // the treasury minting the token
+    pub pool: Address,            // the pool the treasury supplies the token to
+    pub collateral: Vec<Address>, // the collateral assets of the pool
+}
+
+/// A snapshot of a stablecoin
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TokenStats {
+    pub token: Address,
+    pub treasury: Address,
+    pub pool: Address,
+    pub supply: i128,            // the supply minted by the treasury
+    pub treasury_position: i128, // the b-tokens the treasury holds in the pool
+    pub borrowed: i128,          // the supply not held as liquidity by the pool
+    pub collateral_value: i128,  // the value of the collateral held by the pool, in the token
+    pub collateral_ratio: i128,  // the collateral value over the amount borrowed, with 7 decimals
+    pub insurance_balance: i128, // the balance of the insurance fund in the token
+}
+
+/// A snapshot of the protocol
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ProtocolStats {
+    pub tokens: Vec<TokenStats>,
+    pub timestamp: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum AnalyticsDataKey {
+    // The contracts backing a stablecoin
+    Token(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** External Contracts **********/
+
+/// Fetch the oracle Address
+///
+/// ### Panics
+/// If the oracle does not exist
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the oracle Address
+///
+/// ### Arguments
+/// * `oracle` - The Address for the oracle
+pub fn set_oracle(e: &Env, oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ORACLE_KEY), oracle);
+}
+
+/// Fetch the insurance fund Address
+///
+/// ### Panics
+/// If the insurance fund does not exist
+pub fn get_insurance(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, INSURANCE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the insurance fund Address
+///
+/// ### Arguments
+/// * `insurance` - The Address for the insurance fund
+pub fn set_insurance(e: &Env, insurance: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, INSURANCE_KEY), insurance);
+}
+
+/********** Tokens **********/
+
+/// Fetch the tracked stablecoins
+pub fn get_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKENS_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the tracked stablecoins
+///
+/// ### Arguments
+/// * `tokens` - The Addresses of the stablecoins
+pub fn set_tokens(e: &Env, tokens: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, TOKENS_KEY), tokens);
+}
+
+/// Fetch the contracts backing a stablecoin, if it is tracked
+///
+/// ### Arguments
+/// * `token` - The Address of the stablecoin
+pub fn get_token_config(e: &Env, token: &Address) -> Option<TokenConfig> {
+    let key = AnalyticsDataKey::Token(token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<AnalyticsDataKey, TokenConfig>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the contracts backing a stablecoin
+///
+/// ### Arguments
+/// * `token` - The Address of the stablecoin
+/// * `config` - The contracts backing the stablecoin
+pub fn set_token_config(e: &Env, token: &Address, config: &TokenConfig) {
+    let key = AnalyticsDataKey::Token(token.clone());
+    e.storage()
+        .persistent()
+        .set::<AnalyticsDataKey, TokenConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the contracts backing a stablecoin
+///
+/// ### Arguments
+/// * `token` - The Address of the stablecoin
+pub fn del_token_config(e: &Env, token: &Address) {
+    let key = AnalyticsDataKey::Token(token.clone());
+    e.storage().persistent().remove(&key);
+}
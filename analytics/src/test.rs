@@ -0,0 +1,97 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{AnalyticsClient, AnalyticsContract};
+use crate::dependencies::pool::Positions;
+use crate::errors::AnalyticsError;
+use crate::storage::TokenConfig;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient, MockPriceOracleWASM};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{contract, contractimpl, map, vec, Address, Env, Symbol};
+
+/// Reports a fixed minted supply, like the treasury
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn get_token_supply(_e: Env) -> i128 {
+        10_000_000_000_000
+    }
+}
+
+/// Reports the treasury as supplying to reserve 0, like the pool
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn get_positions(e: Env, _address: Address) -> Positions {
+        Positions {
+            collateral: map![&e],
+            liabilities: map![&e],
+            supply: map![&e, (0, 9_900_000_000_000)],
+        }
+    }
+}
+
+#[test]
+fn test_protocol_stats() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token_admin = Address::generate(&e);
+    let ousd = e.register_stellar_asset_contract(token_admin.clone());
+    let xlm = e.register_stellar_asset_contract(token_admin.clone());
+    let usdc = e.register_stellar_asset_contract(token_admin);
+    let treasury = e.register_contract(None, MockTreasury);
+    let pool = e.register_contract(None, MockPool);
+    let insurance = Address::generate(&e);
+
+    let oracle_id = e.register_contract_wasm(None, MockPriceOracleWASM);
+    let oracle_client = MockPriceOracleClient::new(&e, &oracle_id);
+    oracle_client.set_data(
+        &Address::generate(&e),
+        &Asset::Other(Symbol::new(&e, "USD")),
+        &vec![&e, Asset::Stellar(xlm.clone()), Asset::Stellar(usdc.clone())],
+        &7,
+        &300,
+    );
+    oracle_client.set_price_stable(&vec![&e, 0_1000000, 1_0000000]);
+
+    // 400k oUSD is borrowed against 2M XLM and 400k USDC
+    StellarAssetClient::new(&e, &ousd).mint(&pool, &6_000_000_000_000);
+    StellarAssetClient::new(&e, &ousd).mint(&insurance, &250_000_000_000);
+    StellarAssetClient::new(&e, &xlm).mint(&pool, &20_000_000_000_000);
+    StellarAssetClient::new(&e, &usdc).mint(&pool, &4_000_000_000_000);
+
+    let analytics_id = e.register_contract(None, AnalyticsContract);
+    let analytics_client = AnalyticsClient::new(&e, &analytics_id);
+    analytics_client.initialize(&Address::generate(&e), &oracle_id, &insurance);
+    let config = TokenConfig {
+        treasury: treasury.clone(),
+        pool: pool.clone(),
+        collateral: vec![&e, xlm, usdc],
+    };
+    analytics_client.add_token(&ousd, &config);
+    let result = analytics_client.try_add_token(&ousd, &config);
+    assert_eq!(result.err(), Some(Ok(AnalyticsError::TokenExists.into())));
+
+    let stats = analytics_client.get_protocol_stats();
+    assert_eq!(stats.tokens.len(), 1);
+    let ousd_stats = stats.tokens.get_unchecked(0);
+    assert_eq!(ousd_stats.token, ousd);
+    assert_eq!(ousd_stats.treasury, treasury);
+    assert_eq!(ousd_stats.pool, pool);
+    assert_eq!(ousd_stats.supply, 10_000_000_000_000);
+    assert_eq!(ousd_stats.treasury_position, 9_900_000_000_000);
+    assert_eq!(ousd_stats.borrowed, 4_000_000_000_000);
+    assert_eq!(ousd_stats.collateral_value, 6_000_000_000_000);
+    assert_eq!(ousd_stats.collateral_ratio, 1_5000000);
+    assert_eq!(ousd_stats.insurance_balance, 250_000_000_000);
+    assert_eq!(analytics_client.get_token_stats(&ousd), ousd_stats);
+
+    analytics_client.remove_token(&ousd);
+    assert_eq!(analytics_client.get_protocol_stats().tokens.len(), 0);
+    let result = analytics_client.try_get_token_stats(&ousd);
+    assert_eq!(result.err(), Some(Ok(AnalyticsError::TokenNotFound.into())));
+}
@@ -0,0 +1,273 @@
+use crate::dependencies::route::ArbRouteClient;
+use crate::errors::ArbVaultError;
+use crate::events::ArbVaultEvents;
+use crate::storage;
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct ArbVaultContract;
+
+#[contractclient(name = "ArbVaultClient")]
+pub trait ArbVault {
+    /// Initialize the arbitrage vault. Depositors pool an asset like USDC that registered keepers
+    /// send through allowlisted peg arbitrage routes, and the profits accrue to the vault shares.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `asset` - The Address of the asset deposited
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, asset: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Register or remove a keeper
+    ///
+    /// ### Arguments
+    /// * `keeper` - The Address of the keeper
+    /// * `allowed` - Whether the address is a keeper
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_keeper(e: Env, keeper: Address, allowed: bool);
+
+    /// Check if an address is a registered keeper
+    ///
+    /// ### Arguments
+    /// * `keeper` - The Address of the keeper
+    fn is_keeper(e: Env, keeper: Address) -> bool;
+
+    /// (Admin only) Allowlist a route, update its limit or remove it
+    ///
+    /// ### Arguments
+    /// * `route` - The Address of the route contract
+    /// * `limit` - The most a keeper can send through the route at once, or zero to remove it
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the limit is negative
+    fn set_route(e: Env, route: Address, limit: i128);
+
+    /// Get the most a keeper can send through a route at once, or zero if the route is not
+    /// allowlisted
+    ///
+    /// ### Arguments
+    /// * `route` - The Address of the route contract
+    fn get_route_limit(e: Env, route: Address) -> i128;
+
+    /// Deposit assets for vault shares
+    ///
+    /// Returns the amount of shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `amount` - The amount of assets to deposit
+    ///
+    /// ### Panics
+    /// If the deposit is too small to mint a share
+    fn deposit(e: Env, from: Address, amount: i128) -> i128;
+
+    /// Withdraw assets by burning vault shares
+    ///
+    /// Returns the amount of assets withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The Address withdrawing
+    /// * `shares` - The amount of shares to burn
+    ///
+    /// ### Panics
+    /// If `from` does not own enough shares
+    fn withdraw(e: Env, from: Address, shares: i128) -> i128;
+
+    /// Get the vault shares owned by an address
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the depositor
+    fn get_shares(e: Env, user: Address) -> i128;
+
+    /// Get the total vault shares
+    fn get_total_shares(e: Env) -> i128;
+
+    /// Get the assets held by the vault
+    fn get_total_assets(e: Env) -> i128;
+
+    /// (Keeper only) Send vault assets through an allowlisted route. The route must return the
+    /// assets with at least `min_profit` on top before it returns, or the whole call reverts.
+    ///
+    /// Returns the profit
+    ///
+    /// ### Arguments
+    /// * `keeper` - The Address of the keeper
+    /// * `route` - The Address of the route contract
+    /// * `amount` - The amount of assets to send through the route
+    /// * `min_profit` - The least profit the arbitrage must make
+    ///
+    /// ### Panics
+    /// If the caller is not a registered keeper
+    /// If the route is not allowlisted or `amount` exceeds its limit
+    /// If `min_profit` is negative or the arbitrage makes less
+    fn execute(e: Env, keeper: Address, route: Address, amount: i128, min_profit: i128) -> i128;
+}
+
+#[contractimpl]
+impl ArbVault for ArbVaultContract {
+    fn initialize(e: Env, admin: Address, asset: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, ArbVaultError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_asset(&e, &asset);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_keeper(e: Env, keeper: Address, allowed: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_keeper(&e, &keeper, allowed);
+
+        ArbVaultEvents::set_keeper(&e, keeper, allowed);
+    }
+
+    fn is_keeper(e: Env, keeper: Address) -> bool {
+        storage::extend_instance(&e);
+        storage::is_keeper(&e, &keeper)
+    }
+
+    fn set_route(e: Env, route: Address, limit: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if limit < 0 {
+            panic_with_error!(&e, ArbVaultError::NegativeAmountError);
+        }
+
+        storage::set_route_limit(&e, &route, limit);
+
+        ArbVaultEvents::set_route(&e, route, limit);
+    }
+
+    fn get_route_limit(e: Env, route: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_route_limit(&e, &route)
+    }
+
+    fn deposit(e: Env, from: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        let token_client = TokenClient::new(&e, &storage::get_asset(&e));
+        let total_shares = storage::get_total_shares(&e);
+        let shares = if total_shares == 0 {
+            amount
+        } else {
+            amount * total_shares / token_client.balance(&e.current_contract_address())
+        };
+        if shares <= 0 {
+            panic_with_error!(&e, ArbVaultError::NegativeAmountError);
+        }
+        token_client.transfer(&from, &e.current_contract_address(), &amount);
+        storage::set_shares(&e, &from, storage::get_shares(&e, &from) + shares);
+        storage::set_total_shares(&e, total_shares + shares);
+
+        ArbVaultEvents::deposit(&e, from, amount, shares);
+        shares
+    }
+
+    fn withdraw(e: Env, from: Address, shares: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, shares);
+
+        let user_shares = storage::get_shares(&e, &from);
+        if user_shares < shares {
+            panic_with_error!(&e, ArbVaultError::InsufficientShares);
+        }
+        let token_client = TokenClient::new(&e, &storage::get_asset(&e));
+        let total_shares = storage::get_total_shares(&e);
+        let amount = shares * token_client.balance(&e.current_contract_address()) / total_shares;
+        storage::set_shares(&e, &from, user_shares - shares);
+        storage::set_total_shares(&e, total_shares - shares);
+        token_client.transfer(&e.current_contract_address(), &from, &amount);
+
+        ArbVaultEvents::withdraw(&e, from, amount, shares);
+        amount
+    }
+
+    fn get_shares(e: Env, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_shares(&e, &user)
+    }
+
+    fn get_total_shares(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_total_shares(&e)
+    }
+
+    fn get_total_assets(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        TokenClient::new(&e, &storage::get_asset(&e)).balance(&e.current_contract_address())
+    }
+
+    fn execute(e: Env, keeper: Address, route: Address, amount: i128, min_profit: i128) -> i128 {
+        storage::extend_instance(&e);
+        keeper.require_auth();
+        if !storage::is_keeper(&e, &keeper) {
+            panic_with_error!(&e, ArbVaultError::UnauthorizedError);
+        }
+        require_positive(&e, amount);
+        if min_profit < 0 {
+            panic_with_error!(&e, ArbVaultError::InsufficientProfit);
+        }
+        let limit = storage::get_route_limit(&e, &route);
+        if limit == 0 {
+            panic_with_error!(&e, ArbVaultError::RouteNotAllowed);
+        }
+        if amount > limit {
+            panic_with_error!(&e, ArbVaultError::RouteLimitExceeded);
+        }
+
+        let asset = storage::get_asset(&e);
+        let token_client = TokenClient::new(&e, &asset);
+        let balance_before = token_client.balance(&e.current_contract_address());
+        token_client.transfer(&e.current_contract_address(), &route, &amount);
+        ArbRouteClient::new(&e, &route).execute(&e.current_contract_address(), &asset, &amount);
+        let profit = token_client.balance(&e.current_contract_address()) - balance_before;
+        if profit < min_profit {
+            panic_with_error!(&e, ArbVaultError::InsufficientProfit);
+        }
+
+        ArbVaultEvents::execute(&e, route, keeper, amount, profit);
+        profit
+    }
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, ArbVaultError::NegativeAmountError);
+    }
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The interface of a peg arbitrage route, like buying oUSD below the peg on a DEX and redeeming
+/// it for $1 of collateral. The route receives the vault's funds before it is called, and must
+/// send them back with the profit before it returns.
+#[contractclient(name = "ArbRouteClient")]
+pub trait ArbRoute {
+    /// Run the arbitrage
+    ///
+    /// ### Arguments
+    /// * `vault` - The Address of the vault to return the funds to
+    /// * `asset` - The Address of the asset lent
+    /// * `amount` - The amount lent
+    fn execute(e: Env, vault: Address, asset: Address, amount: i128);
+}
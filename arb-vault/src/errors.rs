@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the arbitrage vault. Common errors are codes that match up with the built-in
+/// contracts error reporting. Arbitrage vault specific errors start at 4600.
+pub enum ArbVaultError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Arbitrage Vault
+    RouteNotAllowed = 4600,
+    RouteLimitExceeded = 4601,
+    InsufficientProfit = 4602,
+    InsufficientShares = 4603,
+}
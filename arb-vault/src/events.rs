@@ -0,0 +1,51 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct ArbVaultEvents {}
+
+impl ArbVaultEvents {
+    /// Emitted when the admin registers or removes a keeper
+    ///
+    /// - topics - `["set_keeper", keeper: Address]`
+    /// - data - `allowed: bool`
+    pub fn set_keeper(e: &Env, keeper: Address, allowed: bool) {
+        let topics = (Symbol::new(e, topics::SET_KEEPER), keeper);
+        e.events().publish(topics, allowed);
+    }
+
+    /// Emitted when the admin allowlists a route or updates its limit
+    ///
+    /// - topics - `["set_route", route: Address]`
+    /// - data - `limit: i128`
+    pub fn set_route(e: &Env, route: Address, limit: i128) {
+        let topics = (Symbol::new(e, topics::SET_ROUTE), route);
+        e.events().publish(topics, limit);
+    }
+
+    /// Emitted when assets are deposited
+    ///
+    /// - topics - `["deposit", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn deposit(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), from);
+        e.events().publish(topics, (amount, shares));
+    }
+
+    /// Emitted when assets are withdrawn
+    ///
+    /// - topics - `["withdraw", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn withdraw(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), from);
+        e.events().publish(topics, (amount, shares));
+    }
+
+    /// Emitted when a keeper runs an arbitrage
+    ///
+    /// - topics - `["execute", route: Address, keeper: Address]`
+    /// - data - `[amount: i128, profit: i128]`
+    pub fn execute(e: &Env, route: Address, keeper: Address, amount: i128, profit: i128) {
+        let topics = (Symbol::new(e, topics::EXECUTE), route, keeper);
+        e.events().publish(topics, (amount, profit));
+    }
+}
@@ -0,0 +1,194 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ASSET_KEY: &str = "Asset";
+const TOTAL_SHARES_KEY: &str = "TotalShares";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ArbVaultDataKey {
+    // Whether an address is a registered keeper
+    Keeper(Address),
+    // The most a keeper can send through an allowlisted route at once
+    Route(Address),
+    // The vault shares owned by an address
+    Shares(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Asset **********/
+
+/// Fetch the Address of the asset deposited
+///
+/// ### Panics
+/// If the asset does not exist
+pub fn get_asset(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ASSET_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the asset deposited
+///
+/// ### Arguments
+/// * `asset` - The Address for the asset
+pub fn set_asset(e: &Env, asset: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ASSET_KEY), asset);
+}
+
+/********** Keepers and Routes **********/
+
+/// Check if an address is a registered keeper
+///
+/// ### Arguments
+/// * `keeper` - The Address of the keeper
+pub fn is_keeper(e: &Env, keeper: &Address) -> bool {
+    let key = ArbVaultDataKey::Keeper(keeper.clone());
+    let result = e.storage().persistent().get::<ArbVaultDataKey, bool>(&key);
+    match result {
+        Some(allowed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            allowed
+        }
+        None => false,
+    }
+}
+
+/// Register or remove a keeper
+///
+/// ### Arguments
+/// * `keeper` - The Address of the keeper
+/// * `allowed` - Whether the address is a keeper
+pub fn set_keeper(e: &Env, keeper: &Address, allowed: bool) {
+    let key = ArbVaultDataKey::Keeper(keeper.clone());
+    if allowed {
+        e.storage()
+            .persistent()
+            .set::<ArbVaultDataKey, bool>(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/// Fetch the most a keeper can send through a route at once. Routes that are not
+/// allowlisted have a limit of zero.
+///
+/// ### Arguments
+/// * `route` - The Address of the route
+pub fn get_route_limit(e: &Env, route: &Address) -> i128 {
+    let key = ArbVaultDataKey::Route(route.clone());
+    let result = e.storage().persistent().get::<ArbVaultDataKey, i128>(&key);
+    match result {
+        Some(limit) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            limit
+        }
+        None => 0,
+    }
+}
+
+/// Set the most a keeper can send through a route at once. A limit of zero removes the route.
+///
+/// ### Arguments
+/// * `route` - The Address of the route
+/// * `limit` - The route limit
+pub fn set_route_limit(e: &Env, route: &Address, limit: i128) {
+    let key = ArbVaultDataKey::Route(route.clone());
+    if limit > 0 {
+        e.storage()
+            .persistent()
+            .set::<ArbVaultDataKey, i128>(&key, &limit);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/********** Shares **********/
+
+/// Fetch the total vault shares
+pub fn get_total_shares(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_SHARES_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the total vault shares
+///
+/// ### Arguments
+/// * `shares` - The total vault shares
+pub fn set_total_shares(e: &Env, shares: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, TOTAL_SHARES_KEY), &shares);
+}
+
+/// Fetch the vault shares owned by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the depositor
+pub fn get_shares(e: &Env, user: &Address) -> i128 {
+    let key = ArbVaultDataKey::Shares(user.clone());
+    let result = e.storage().persistent().get::<ArbVaultDataKey, i128>(&key);
+    match result {
+        Some(shares) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            shares
+        }
+        None => 0,
+    }
+}
+
+/// Set the vault shares owned by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the depositor
+/// * `shares` - The vault shares owned
+pub fn set_shares(e: &Env, user: &Address, shares: i128) {
+    let key = ArbVaultDataKey::Shares(user.clone());
+    e.storage()
+        .persistent()
+        .set::<ArbVaultDataKey, i128>(&key, &shares);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+use crate::contract::{ArbVaultClient, ArbVaultContract};
+use crate::errors::ArbVaultError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+/// Returns each loan with a fixed profit, like buying oUSD below the peg and redeeming it
+#[contract]
+pub struct MockRoute;
+
+#[contractimpl]
+impl MockRoute {
+    pub fn set_profit(e: Env, profit: i128) {
+        e.storage().instance().set(&Symbol::new(&e, "profit"), &profit);
+    }
+
+    pub fn execute(e: Env, vault: Address, asset: Address, amount: i128) {
+        let profit: i128 = e.storage().instance().get(&Symbol::new(&e, "profit")).unwrap();
+        let repayment = amount + profit;
+        TokenClient::new(&e, &asset).transfer(&e.current_contract_address(), &vault, &repayment);
+    }
+}
+
+#[test]
+fn test_arbitrage_profits_accrue_to_shares() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let usdc_client = TokenClient::new(&e, &usdc);
+    let vault_id = e.register_contract(None, ArbVaultContract);
+    let vault_client = ArbVaultClient::new(&e, &vault_id);
+    vault_client.initialize(&Address::generate(&e), &usdc);
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &usdc).mint(&alice, &1000_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&bob, &1010_0000000);
+    assert_eq!(vault_client.deposit(&alice, &1000_0000000), 1000_0000000);
+
+    let route = e.register_contract(None, MockRoute);
+    let route_client = MockRouteClient::new(&e, &route);
+    route_client.set_profit(&10_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&route, &100_0000000);
+    let keeper = Address::generate(&e);
+
+    let result = vault_client.try_execute(&keeper, &route, &500_0000000, &0);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::UnauthorizedError.into())));
+    vault_client.set_keeper(&keeper, &true);
+    let result = vault_client.try_execute(&keeper, &route, &500_0000000, &0);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::RouteNotAllowed.into())));
+    vault_client.set_route(&route, &500_0000000);
+    let result = vault_client.try_execute(&keeper, &route, &500_0000001, &0);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::RouteLimitExceeded.into())));
+    let result = vault_client.try_execute(&keeper, &route, &500_0000000, &20_0000000);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::InsufficientProfit.into())));
+
+    assert_eq!(vault_client.execute(&keeper, &route, &500_0000000, &10_0000000), 10_0000000);
+    assert_eq!(vault_client.get_total_assets(), 1010_0000000);
+
+    // a route that loses funds reverts
+    route_client.set_profit(&-1);
+    let result = vault_client.try_execute(&keeper, &route, &500_0000000, &0);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::InsufficientProfit.into())));
+
+    // later depositors buy in at the higher share price
+    assert_eq!(vault_client.deposit(&bob, &1010_0000000), 1000_0000000);
+    assert_eq!(vault_client.withdraw(&alice, &1000_0000000), 1010_0000000);
+    assert_eq!(usdc_client.balance(&alice), 1010_0000000);
+    let result = vault_client.try_withdraw(&alice, &1);
+    assert_eq!(result.err(), Some(Ok(ArbVaultError::InsufficientShares.into())));
+}
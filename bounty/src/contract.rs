@@ -0,0 +1,217 @@
+use crate::errors::BountyError;
+use crate::events::BountyEvents;
+use crate::storage::{self, Bounty, Task};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, Symbol, Val, Vec,
+};
+
+const SCALAR_7: i128 = 1_0000000;
+
+#[contract]
+pub struct BountyContract;
+
+#[contractclient(name = "BountyClient")]
+pub trait BountyTrait {
+    /// Initialize the bounty contract. The contract is funded by sending it tokens, usually
+    /// as a transfer destination of the fee distributor.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the token bounties are paid in
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Add a task keepers are paid to trigger
+    ///
+    /// Returns the id of the task
+    ///
+    /// ### Arguments
+    /// * `target` - The Address of the contract to call
+    /// * `function` - The function to call
+    /// * `bounty` - The bounty paid each time the task is triggered
+    /// * `interval` - The minimum time between triggers, in seconds
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the bounty is negative, or a percentage above 100%
+    fn add_task(e: Env, target: Address, function: Symbol, bounty: Bounty, interval: u64) -> u32;
+
+    /// (Admin only) Remove a task
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the task
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the task does not exist
+    fn remove_task(e: Env, id: u32);
+
+    /// Get a task
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the task
+    ///
+    /// ### Panics
+    /// If the task does not exist
+    fn get_task(e: Env, id: u32) -> Task;
+
+    /// Get the bounty currently paid for triggering a task
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the task
+    ///
+    /// ### Panics
+    /// If the task does not exist
+    fn get_bounty(e: Env, id: u32) -> i128;
+
+    /// Trigger a task and get paid its bounty. The bounty is only paid if the call succeeds.
+    ///
+    /// Returns the bounty paid
+    ///
+    /// ### Arguments
+    /// * `keeper` - The Address triggering the task
+    /// * `id` - The id of the task
+    /// * `nonce` - The nonce of the task, which prevents a trigger from being replayed
+    /// * `args` - The arguments of the call
+    ///
+    /// ### Panics
+    /// If the task does not exist
+    /// If the nonce does not match the task
+    /// If the interval since the last trigger has not passed
+    /// If the bounty funds are too low
+    fn trigger(e: Env, keeper: Address, id: u32, nonce: u64, args: Vec<Val>) -> i128;
+}
+
+#[contractimpl]
+impl BountyTrait for BountyContract {
+    fn initialize(e: Env, admin: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, BountyError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn add_task(e: Env, target: Address, function: Symbol, bounty: Bounty, interval: u64) -> u32 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        let valid = match bounty {
+            Bounty::Fixed(amount) => amount >= 0,
+            Bounty::Percentage(pct) => (0..=SCALAR_7).contains(&pct),
+        };
+        if !valid {
+            panic_with_error!(&e, BountyError::InvalidBounty);
+        }
+
+        let id = storage::get_task_count(&e);
+        let task = Task {
+            target,
+            function,
+            bounty,
+            interval,
+            last_run: 0,
+            nonce: 0,
+        };
+        storage::set_task(&e, id, &task);
+        storage::set_task_count(&e, id + 1);
+
+        BountyEvents::add_task(&e, id, task);
+        id
+    }
+
+    fn remove_task(e: Env, id: u32) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        load_task(&e, id);
+
+        storage::del_task(&e, id);
+
+        BountyEvents::remove_task(&e, id);
+    }
+
+    fn get_task(e: Env, id: u32) -> Task {
+        storage::extend_instance(&e);
+        load_task(&e, id)
+    }
+
+    fn get_bounty(e: Env, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        calc_bounty(&e, &load_task(&e, id).bounty)
+    }
+
+    fn trigger(e: Env, keeper: Address, id: u32, nonce: u64, args: Vec<Val>) -> i128 {
+        storage::extend_instance(&e);
+        keeper.require_auth();
+
+        let mut task = load_task(&e, id);
+        if nonce != task.nonce {
+            panic_with_error!(&e, BountyError::InvalidNonce);
+        }
+        let now = e.ledger().timestamp();
+        if task.nonce > 0 && now < task.last_run + task.interval {
+            panic_with_error!(&e, BountyError::TaskNotReady);
+        }
+        let bounty = calc_bounty(&e, &task.bounty);
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        if token_client.balance(&e.current_contract_address()) < bounty {
+            panic_with_error!(&e, BountyError::InsufficientFunds);
+        }
+
+        task.nonce += 1;
+        task.last_run = now;
+        storage::set_task(&e, id, &task);
+
+        e.invoke_contract::<Val>(&task.target, &task.function, args);
+        if bounty > 0 {
+            token_client.transfer(&e.current_contract_address(), &keeper, &bounty);
+        }
+
+        BountyEvents::trigger(&e, id, keeper, nonce, bounty);
+        bounty
+    }
+}
+
+/// Load a task
+///
+/// ### Panics
+/// If the task does not exist
+fn load_task(e: &Env, id: u32) -> Task {
+    storage::get_task(e, id).unwrap_or_else(|| panic_with_error!(e, BountyError::TaskNotFound))
+}
+
+/// Calculate the bounty currently paid for a task
+fn calc_bounty(e: &Env, bounty: &Bounty) -> i128 {
+    match bounty {
+        Bounty::Fixed(amount) => *amount,
+        Bounty::Percentage(pct) => {
+            let balance = TokenClient::new(e, &storage::get_token(e))
+                .balance(&e.current_contract_address());
+            math::apply_pct(e, balance, *pct)
+        }
+    }
+}
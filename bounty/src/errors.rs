@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the bounty contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Bounty specific errors start at 3600.
+pub enum BountyError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Bounty
+    TaskNotFound = 3600,
+    InvalidNonce = 3601,
+    TaskNotReady = 3602,
+    InsufficientFunds = 3603,
+    InvalidBounty = 3604,
+}
@@ -0,0 +1,35 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::Task;
+
+pub struct BountyEvents {}
+
+impl BountyEvents {
+    /// Emitted when the admin adds a task
+    ///
+    /// - topics - `["add_task", id: u32]`
+    /// - data - `task: Task`
+    pub fn add_task(e: &Env, id: u32, task: Task) {
+        let topics = (Symbol::new(e, topics::ADD_TASK), id);
+        e.events().publish(topics, task);
+    }
+
+    /// Emitted when the admin removes a task
+    ///
+    /// - topics - `["remove_task", id: u32]`
+    /// - data - `()`
+    pub fn remove_task(e: &Env, id: u32) {
+        let topics = (Symbol::new(e, topics::REMOVE_TASK), id);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a keeper triggers a task and is paid its bounty
+    ///
+    /// - topics - `["trigger", id: u32, keeper: Address]`
+    /// - data - `[nonce: u64, bounty: i128]`
+    pub fn trigger(e: &Env, id: u32, keeper: Address, nonce: u64, bounty: i128) {
+        let topics = (Symbol::new(e, topics::TRIGGER), id, keeper);
+        e.events().publish(topics, (nonce, bounty));
+    }
+}
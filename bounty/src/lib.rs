@@ -0,0 +1,12 @@
+#![no_std]
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+mod storage;
+mod contract;
+mod errors;
+mod events;
+mod test;
+
+pub use contract::*;
+pub use errors::BountyError;
+pub use storage::{Bounty, Task};
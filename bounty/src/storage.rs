@@ -0,0 +1,138 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const TASK_COUNT_KEY: &str = "TaskCount";
+
+/// The bounty paid for a task
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Bounty {
+    // a fixed amount of tokens
+    Fixed(i128),
+    // a share of the bounty funds, with 7 decimals
+    Percentage(i128),
+}
+
+/// An operation keepers are paid to trigger
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Task {
+    pub target: Address,  // the contract to call
+    pub function: Symbol, // the function to call
+    pub bounty: Bounty,
+    pub interval: u64, // the minimum time between triggers, in seconds
+    pub last_run: u64, // the time the task was last triggered
+    pub nonce: u64,    // the number of times the task was triggered
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum BountyDataKey {
+    // A task by id
+    Task(u32),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the token bounties are paid in
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token bounties are paid in
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Tasks **********/
+
+/// Fetch the number of tasks added, which is also the id of the next task
+pub fn get_task_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TASK_COUNT_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of tasks added
+///
+/// ### Arguments
+/// * `count` - The number of tasks
+pub fn set_task_count(e: &Env, count: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, TASK_COUNT_KEY), &count);
+}
+
+/// Fetch a task, if it exists
+///
+/// ### Arguments
+/// * `id` - The id of the task
+pub fn get_task(e: &Env, id: u32) -> Option<Task> {
+    let key = BountyDataKey::Task(id);
+    let result = e.storage().persistent().get::<BountyDataKey, Task>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set a task
+///
+/// ### Arguments
+/// * `id` - The id of the task
+/// * `task` - The task
+pub fn set_task(e: &Env, id: u32, task: &Task) {
+    let key = BountyDataKey::Task(id);
+    e.storage()
+        .persistent()
+        .set::<BountyDataKey, Task>(&key, task);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove a task
+///
+/// ### Arguments
+/// * `id` - The id of the task
+pub fn del_task(e: &Env, id: u32) {
+    let key = BountyDataKey::Task(id);
+    e.storage().persistent().remove(&key);
+}
@@ -0,0 +1,208 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{BountyClient, BountyContract};
+use crate::errors::BountyError;
+use crate::storage::Bounty;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+/// Counts heartbeats, like an oracle or rebalancing contract with a time-sensitive update
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn heartbeat(e: Env, count: u32) {
+        let total: u32 = Self::beats(e.clone()) + count;
+        e.storage().instance().set(&Symbol::new(&e, "beats"), &total);
+    }
+
+    pub fn fail(_e: Env) {
+        panic!("heartbeat failed");
+    }
+
+    pub fn beats(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "beats"))
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_trigger_pays_bounty() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let usdc_client = TokenClient::new(&e, &usdc);
+    let target = e.register_contract(None, MockTarget);
+    let target_client = MockTargetClient::new(&e, &target);
+    let keeper = Address::generate(&e);
+
+    let bounty_id = e.register_contract(None, BountyContract);
+    let bounty_client = BountyClient::new(&e, &bounty_id);
+    bounty_client.initialize(&Address::generate(&e), &usdc);
+    StellarAssetClient::new(&e, &usdc).mint(&bounty_id, &1000_0000000);
+
+    let heartbeat = Symbol::new(&e, "heartbeat");
+    let fixed = bounty_client.add_task(&target, &heartbeat, &Bounty::Fixed(5_0000000), &3600);
+    let share = bounty_client.add_task(&target, &heartbeat, &Bounty::Percentage(0_0100000), &0);
+
+    let args = vec![&e, 1_u32.into_val(&e)];
+    assert_eq!(bounty_client.trigger(&keeper, &fixed, &0, &args), 5_0000000);
+    assert_eq!(target_client.beats(), 1);
+    assert_eq!(bounty_client.get_task(&fixed).nonce, 1);
+
+    // the nonce prevents replaying a trigger, and the interval spaces out triggers
+    let result = bounty_client.try_trigger(&keeper, &fixed, &0, &args);
+    assert_eq!(result.err(), Some(Ok(BountyError::InvalidNonce.into())));
+    let result = bounty_client.try_trigger(&keeper, &fixed, &1, &args);
+    assert_eq!(result.err(), Some(Ok(BountyError::TaskNotReady.into())));
+    e.ledger().with_mut(|li| li.timestamp += 3600);
+    bounty_client.trigger(&keeper, &fixed, &1, &args);
+
+    // 1% of the remaining 990
+    assert_eq!(bounty_client.get_bounty(&share), 9_9000000);
+    assert_eq!(bounty_client.trigger(&keeper, &share, &0, &args), 9_9000000);
+    assert_eq!(usdc_client.balance(&keeper), 19_9000000);
+    assert_eq!(usdc_client.balance(&bounty_id), 980_1000000);
+    assert_eq!(target_client.beats(), 3);
+}
+
+#[test]
+fn test_trigger_requires_funds() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let target = e.register_contract(None, MockTarget);
+    let keeper = Address::generate(&e);
+
+    let bounty_id = e.register_contract(None, BountyContract);
+    let bounty_client = BountyClient::new(&e, &bounty_id);
+    bounty_client.initialize(&Address::generate(&e), &usdc);
+    StellarAssetClient::new(&e, &usdc).mint(&bounty_id, &1_0000000);
+
+    let heartbeat = Symbol::new(&e, "heartbeat");
+    let too_high = Bounty::Percentage(1_5000000);
+    let result = bounty_client.try_add_task(&target, &heartbeat, &too_high, &0);
+    assert_eq!(result.err(), Some(Ok(BountyError::InvalidBounty.into())));
+    let id = bounty_client.add_task(&target, &heartbeat, &Bounty::Fixed(5_0000000), &0);
+
+    let args = vec![&e, 1_u32.into_val(&e)];
+    let result = bounty_client.try_trigger(&keeper, &id, &0, &args);
+    assert_eq!(result.err(), Some(Ok(BountyError::InsufficientFunds.into())));
+
+    bounty_client.remove_task(&id);
+    let result = bounty_client.try_trigger(&keeper, &id, &0, &args);
+    assert_eq!(result.err(), Some(Ok(BountyError::TaskNotFound.into())));
+}
+
+#[test]
+fn test_add_task_validation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let target = e.register_contract(None, MockTarget);
+    let admin = Address::generate(&e);
+
+    let bounty_id = e.register_contract(None, BountyContract);
+    let bounty_client = BountyClient::new(&e, &bounty_id);
+    bounty_client.initialize(&admin, &usdc);
+    let result = bounty_client.try_initialize(&admin, &usdc);
+    assert_eq!(result.err(), Some(Ok(BountyError::AlreadyInitializedError.into())));
+
+    let heartbeat = Symbol::new(&e, "heartbeat");
+    let id = bounty_client.add_task(&target, &heartbeat, &Bounty::Percentage(1_0000000), &0);
+    assert_eq!(
+        e.auths()[0],
+        (
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    bounty_id.clone(),
+                    Symbol::new(&e, "add_task"),
+                    vec![
+                        &e,
+                        target.into_val(&e),
+                        heartbeat.into_val(&e),
+                        Bounty::Percentage(1_0000000).into_val(&e),
+                        0_u64.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(bounty_client.add_task(&target, &heartbeat, &Bounty::Fixed(0), &0), id + 1);
+
+    for bounty in [Bounty::Fixed(-1), Bounty::Percentage(-1), Bounty::Percentage(1_0000001)] {
+        let result = bounty_client.try_add_task(&target, &heartbeat, &bounty, &0);
+        assert_eq!(result.err(), Some(Ok(BountyError::InvalidBounty.into())));
+    }
+
+    let result = bounty_client.try_get_task(&(id + 2));
+    assert_eq!(result.err(), Some(Ok(BountyError::TaskNotFound.into())));
+    let result = bounty_client.try_get_bounty(&(id + 2));
+    assert_eq!(result.err(), Some(Ok(BountyError::TaskNotFound.into())));
+    let result = bounty_client.try_remove_task(&(id + 2));
+    assert_eq!(result.err(), Some(Ok(BountyError::TaskNotFound.into())));
+}
+
+#[test]
+fn test_trigger_pays_only_on_success() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let usdc_client = TokenClient::new(&e, &usdc);
+    let target = e.register_contract(None, MockTarget);
+    let target_client = MockTargetClient::new(&e, &target);
+    let keeper = Address::generate(&e);
+
+    let bounty_id = e.register_contract(None, BountyContract);
+    let bounty_client = BountyClient::new(&e, &bounty_id);
+    bounty_client.initialize(&Address::generate(&e), &usdc);
+    StellarAssetClient::new(&e, &usdc).mint(&bounty_id, &10_0000000);
+
+    // a failed call reverts the trigger, so the keeper is not paid and the nonce is not used
+    let fail = Symbol::new(&e, "fail");
+    let failing = bounty_client.add_task(&target, &fail, &Bounty::Fixed(5_0000000), &0);
+    let result = bounty_client.try_trigger(&keeper, &failing, &0, &vec![&e]);
+    assert!(result.is_err());
+    assert_eq!(bounty_client.get_task(&failing).nonce, 0);
+    assert_eq!(usdc_client.balance(&keeper), 0);
+
+    // the bounty can use up the whole balance
+    let heartbeat = Symbol::new(&e, "heartbeat");
+    let args = vec![&e, 1_u32.into_val(&e)];
+    let id = bounty_client.add_task(&target, &heartbeat, &Bounty::Fixed(10_0000000), &0);
+    assert_eq!(bounty_client.trigger(&keeper, &id, &0, &args), 10_0000000);
+    assert_eq!(
+        e.auths()[0],
+        (
+            keeper.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    bounty_id.clone(),
+                    Symbol::new(&e, "trigger"),
+                    vec![
+                        &e,
+                        keeper.into_val(&e),
+                        id.into_val(&e),
+                        0_u64.into_val(&e),
+                        args.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(usdc_client.balance(&bounty_id), 0);
+
+    // tasks without a bounty can still be triggered once the funds run out
+    let free = bounty_client.add_task(&target, &heartbeat, &Bounty::Fixed(0), &0);
+    assert_eq!(bounty_client.trigger(&keeper, &free, &0, &args), 0);
+    assert_eq!(usdc_client.balance(&keeper), 10_0000000);
+    assert_eq!(target_client.beats(), 2);
+}
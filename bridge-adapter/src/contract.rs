@@ -0,0 +1,238 @@
+use crate::errors::BridgeAdapterError;
+use crate::events::BridgeAdapterEvents;
+use crate::storage;
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Bytes, Env, Vec,
+};
+
+#[contract]
+pub struct BridgeAdapterContract;
+
+#[contractclient(name = "BridgeAdapterClient")]
+pub trait BridgeAdapter {
+    /// Initialize the bridge adapter
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the bridged token
+    /// * `relayers` - The relayers that attest to transfers from other chains
+    /// * `threshold` - The number of relayers that must sign a release
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the threshold is zero or above the number of relayers
+    fn initialize(e: Env, admin: Address, token: Address, relayers: Vec<Address>, threshold: u32);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the relayers and the number that must sign a release
+    ///
+    /// ### Arguments
+    /// * `relayers` - The relayers that attest to transfers from other chains
+    /// * `threshold` - The number of relayers that must sign a release
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the relayers contain duplicates
+    /// If the threshold is zero or above the number of relayers
+    fn set_relayers(e: Env, relayers: Vec<Address>, threshold: u32);
+
+    /// Get the relayers
+    fn get_relayers(e: Env) -> Vec<Address>;
+
+    /// Get the number of relayers that must sign a release
+    fn get_threshold(e: Env) -> u32;
+
+    /// Get the amount of tokens locked for other chains
+    fn get_locked(e: Env) -> i128;
+
+    /// Check if a transfer from another chain was released
+    ///
+    /// ### Arguments
+    /// * `src_chain` - The id of the source chain
+    /// * `nonce` - The nonce of the transfer on the source chain
+    fn is_processed(e: Env, src_chain: u32, nonce: u64) -> bool;
+
+    /// Lock tokens to be minted on another chain. The tokens stay in circulation on Stellar, so
+    /// the supply accounted for by the treasury is unchanged.
+    ///
+    /// Returns the nonce of the lock, which relayers attest to on the destination chain
+    ///
+    /// ### Arguments
+    /// * `from` - The Address locking tokens
+    /// * `amount` - The amount of tokens to lock
+    /// * `dest_chain` - The id of the destination chain
+    /// * `recipient` - The encoded address of the recipient on the destination chain
+    ///
+    /// ### Panics
+    /// If the amount is not positive
+    fn lock(e: Env, from: Address, amount: i128, dest_chain: u32, recipient: Bytes) -> u64;
+
+    /// (Relayers only) Release locked tokens for a transfer burned on another chain
+    ///
+    /// ### Arguments
+    /// * `signers` - The relayers signing the release
+    /// * `src_chain` - The id of the source chain
+    /// * `nonce` - The nonce of the transfer on the source chain
+    /// * `to` - The Address receiving the tokens
+    /// * `amount` - The amount of tokens to release
+    ///
+    /// ### Panics
+    /// If fewer distinct relayers than the threshold sign the release
+    /// If the transfer was already released
+    /// If the amount is not positive or exceeds the tokens locked
+    fn release(
+        e: Env,
+        signers: Vec<Address>,
+        src_chain: u32,
+        nonce: u64,
+        to: Address,
+        amount: i128,
+    );
+}
+
+#[contractimpl]
+impl BridgeAdapter for BridgeAdapterContract {
+    fn initialize(e: Env, admin: Address, token: Address, relayers: Vec<Address>, threshold: u32) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, BridgeAdapterError::AlreadyInitializedError);
+        }
+        require_valid_relayers(&e, &relayers, threshold);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_relayers(&e, &relayers);
+        storage::set_threshold(&e, threshold);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_relayers(e: Env, relayers: Vec<Address>, threshold: u32) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_relayers(&e, &relayers, threshold);
+
+        storage::set_relayers(&e, &relayers);
+        storage::set_threshold(&e, threshold);
+
+        BridgeAdapterEvents::set_relayers(&e, relayers, threshold);
+    }
+
+    fn get_relayers(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_relayers(&e)
+    }
+
+    fn get_threshold(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_threshold(&e)
+    }
+
+    fn get_locked(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_locked(&e)
+    }
+
+    fn is_processed(e: Env, src_chain: u32, nonce: u64) -> bool {
+        storage::extend_instance(&e);
+        storage::get_processed(&e, src_chain, nonce)
+    }
+
+    fn lock(e: Env, from: Address, amount: i128, dest_chain: u32, recipient: Bytes) -> u64 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&e, BridgeAdapterError::NegativeAmountError);
+        }
+
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &from,
+            &e.current_contract_address(),
+            &amount,
+        );
+        storage::set_locked(&e, storage::get_locked(&e) + amount);
+        let nonce = storage::get_nonce(&e);
+        storage::set_nonce(&e, nonce + 1);
+
+        BridgeAdapterEvents::lock(&e, dest_chain, nonce, from, recipient, amount);
+        nonce
+    }
+
+    fn release(
+        e: Env,
+        signers: Vec<Address>,
+        src_chain: u32,
+        nonce: u64,
+        to: Address,
+        amount: i128,
+    ) {
+        storage::extend_instance(&e);
+        let relayers = storage::get_relayers(&e);
+        let mut signed: Vec<Address> = Vec::new(&e);
+        for signer in signers.iter() {
+            if !relayers.contains(&signer) {
+                panic_with_error!(&e, BridgeAdapterError::UnauthorizedError);
+            }
+            if !signed.contains(&signer) {
+                signer.require_auth();
+                signed.push_back(signer);
+            }
+        }
+        if signed.len() < storage::get_threshold(&e) {
+            panic_with_error!(&e, BridgeAdapterError::InsufficientSignatures);
+        }
+        if storage::get_processed(&e, src_chain, nonce) {
+            panic_with_error!(&e, BridgeAdapterError::AlreadyProcessed);
+        }
+        if amount <= 0 {
+            panic_with_error!(&e, BridgeAdapterError::NegativeAmountError);
+        }
+        let locked = storage::get_locked(&e);
+        if amount > locked {
+            panic_with_error!(&e, BridgeAdapterError::InsufficientLocked);
+        }
+
+        storage::set_processed(&e, src_chain, nonce);
+        storage::set_locked(&e, locked - amount);
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &e.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        BridgeAdapterEvents::release(&e, src_chain, nonce, to, amount);
+    }
+}
+
+/// Require that the relayers are distinct and the threshold can be met
+///
+/// ### Panics
+/// If the relayers contain duplicates
+/// If the threshold is zero or above the number of relayers
+fn require_valid_relayers(e: &Env, relayers: &Vec<Address>, threshold: u32) {
+    if threshold == 0 || threshold > relayers.len() {
+        panic_with_error!(e, BridgeAdapterError::InvalidRelayers);
+    }
+    for (i, relayer) in relayers.iter().enumerate() {
+        if relayers.first_index_of(&relayer) != Some(i as u32) {
+            panic_with_error!(e, BridgeAdapterError::InvalidRelayers);
+        }
+    }
+}
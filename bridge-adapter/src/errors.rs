@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the bridge adapter contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Bridge adapter specific errors start at 3500.
+pub enum BridgeAdapterError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Bridge Adapter
+    InvalidRelayers = 3500,
+    InsufficientSignatures = 3501,
+    AlreadyProcessed = 3502,
+    InsufficientLocked = 3503,
+}
@@ -0,0 +1,40 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Bytes, Env, Symbol, Vec};
+
+pub struct BridgeAdapterEvents {}
+
+impl BridgeAdapterEvents {
+    /// Emitted when the admin updates the relayers
+    ///
+    /// - topics - `["set_relayers"]`
+    /// - data - `[relayers: Vec<Address>, threshold: u32]`
+    pub fn set_relayers(e: &Env, relayers: Vec<Address>, threshold: u32) {
+        let topics = (Symbol::new(e, topics::SET_RELAYERS),);
+        e.events().publish(topics, (relayers, threshold));
+    }
+
+    /// Emitted when tokens are locked to be minted on another chain. Relayers attest to this event.
+    ///
+    /// - topics - `["lock", dest_chain: u32, nonce: u64]`
+    /// - data - `[from: Address, recipient: Bytes, amount: i128]`
+    pub fn lock(
+        e: &Env,
+        dest_chain: u32,
+        nonce: u64,
+        from: Address,
+        recipient: Bytes,
+        amount: i128,
+    ) {
+        let topics = (Symbol::new(e, topics::LOCK), dest_chain, nonce);
+        e.events().publish(topics, (from, recipient, amount));
+    }
+
+    /// Emitted when locked tokens are released for a transfer from another chain
+    ///
+    /// - topics - `["release", src_chain: u32, nonce: u64]`
+    /// - data - `[to: Address, amount: i128]`
+    pub fn release(e: &Env, src_chain: u32, nonce: u64, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::RELEASE), src_chain, nonce);
+        e.events().publish(topics, (to, amount));
+    }
+}
@@ -0,0 +1,178 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const RELAYERS_KEY: &str = "Relayers";
+const THRESHOLD_KEY: &str = "Threshold";
+const NONCE_KEY: &str = "Nonce";
+const LOCKED_KEY: &str = "Locked";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum BridgeAdapterDataKey {
+    // If a transfer from another chain was released, by source chain and nonce
+    Processed(u32, u64),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the bridged token Address
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the bridged token Address
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Relayers **********/
+
+/// Fetch the relayers
+///
+/// ### Panics
+/// If the relayers do not exist
+pub fn get_relayers(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, RELAYERS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the relayers
+///
+/// ### Arguments
+/// * `relayers` - The relayer Addresses
+pub fn set_relayers(e: &Env, relayers: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, RELAYERS_KEY), relayers);
+}
+
+/// Fetch the number of relayers that must sign a release
+///
+/// ### Panics
+/// If the threshold does not exist
+pub fn get_threshold(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, THRESHOLD_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the number of relayers that must sign a release
+///
+/// ### Arguments
+/// * `threshold` - The number of relayers
+pub fn set_threshold(e: &Env, threshold: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, THRESHOLD_KEY), &threshold);
+}
+
+/********** Locks **********/
+
+/// Fetch the number of locks made, which is also the nonce of the next lock
+pub fn get_nonce(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, NONCE_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of locks made
+///
+/// ### Arguments
+/// * `nonce` - The number of locks
+pub fn set_nonce(e: &Env, nonce: u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, NONCE_KEY), &nonce);
+}
+
+/// Fetch the amount of tokens locked for other chains
+pub fn get_locked(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, LOCKED_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the amount of tokens locked for other chains
+///
+/// ### Arguments
+/// * `locked` - The amount of tokens locked
+pub fn set_locked(e: &Env, locked: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, LOCKED_KEY), &locked);
+}
+
+/********** Releases **********/
+
+/// Check if a transfer from another chain was released
+///
+/// ### Arguments
+/// * `src_chain` - The id of the source chain
+/// * `nonce` - The nonce of the transfer on the source chain
+pub fn get_processed(e: &Env, src_chain: u32, nonce: u64) -> bool {
+    let key = BridgeAdapterDataKey::Processed(src_chain, nonce);
+    let result = e.storage().persistent().has(&key);
+    if result {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set a transfer from another chain as released
+///
+/// ### Arguments
+/// * `src_chain` - The id of the source chain
+/// * `nonce` - The nonce of the transfer on the source chain
+pub fn set_processed(e: &Env, src_chain: u32, nonce: u64) {
+    let key = BridgeAdapterDataKey::Processed(src_chain, nonce);
+    e.storage()
+        .persistent()
+        .set::<BridgeAdapterDataKey, bool>(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+use crate::contract::{BridgeAdapterClient, BridgeAdapterContract};
+use crate::errors::BridgeAdapterError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, Env};
+
+#[test]
+fn test_lock_and_release() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let relayers = vec![
+        &e,
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    ];
+    let frodo = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&frodo, &1000_0000000);
+
+    let bridge_id = e.register_contract(None, BridgeAdapterContract);
+    let bridge_client = BridgeAdapterClient::new(&e, &bridge_id);
+    bridge_client.initialize(&Address::generate(&e), &ousd, &relayers, &2);
+
+    let recipient = Bytes::from_array(&e, &[0xab; 20]);
+    assert_eq!(bridge_client.lock(&frodo, &600_0000000, &1, &recipient), 0);
+    assert_eq!(bridge_client.lock(&frodo, &100_0000000, &1, &recipient), 1);
+    assert_eq!(bridge_client.get_locked(), 700_0000000);
+    assert_eq!(ousd_client.balance(&bridge_id), 700_0000000);
+    assert_eq!(ousd_client.balance(&frodo), 300_0000000);
+
+    let signers = vec![&e, relayers.get_unchecked(0), relayers.get_unchecked(2)];
+    bridge_client.release(&signers, &1, &5, &samwise, &250_0000000);
+    assert!(bridge_client.is_processed(&1, &5));
+    assert!(!bridge_client.is_processed(&2, &5));
+    assert_eq!(bridge_client.get_locked(), 450_0000000);
+    assert_eq!(ousd_client.balance(&samwise), 250_0000000);
+
+    // the same transfer can't be released twice
+    let result = bridge_client.try_release(&signers, &1, &5, &samwise, &250_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::AlreadyProcessed.into()))
+    );
+
+    let result = bridge_client.try_release(&signers, &1, &6, &samwise, &500_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::InsufficientLocked.into()))
+    );
+}
+
+#[test]
+fn test_release_requires_threshold() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let relayers = vec![&e, Address::generate(&e), Address::generate(&e)];
+    let frodo = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&frodo, &1000_0000000);
+
+    let bridge_id = e.register_contract(None, BridgeAdapterContract);
+    let bridge_client = BridgeAdapterClient::new(&e, &bridge_id);
+    let result = bridge_client.try_initialize(&Address::generate(&e), &ousd, &relayers, &3);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::InvalidRelayers.into()))
+    );
+    bridge_client.initialize(&Address::generate(&e), &ousd, &relayers, &2);
+    bridge_client.lock(&frodo, &100_0000000, &1, &Bytes::from_array(&e, &[0xab; 20]));
+
+    // a relayer signing twice only counts once
+    let relayer = relayers.get_unchecked(0);
+    let signers = vec![&e, relayer.clone(), relayer.clone()];
+    let result = bridge_client.try_release(&signers, &1, &0, &frodo, &100_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::InsufficientSignatures.into()))
+    );
+
+    let signers = vec![&e, relayer, Address::generate(&e)];
+    let result = bridge_client.try_release(&signers, &1, &0, &frodo, &100_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::UnauthorizedError.into()))
+    );
+
+    let duplicates = vec![&e, relayers.get_unchecked(1), relayers.get_unchecked(1)];
+    let result = bridge_client.try_set_relayers(&duplicates, &1);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BridgeAdapterError::InvalidRelayers.into()))
+    );
+}
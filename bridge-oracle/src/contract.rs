@@ -1,6 +1,20 @@
 use sep_40_oracle::{Asset, PriceData};
-use soroban_sdk::{contract, contractclient, contractimpl, vec, Address, Env, Symbol, Vec, Val, IntoVal};
-use crate::storage;
+use orbit_access::Role;
+use orbit_common::math;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, BytesN, Env, Symbol, Vec, Val, IntoVal};
+use crate::dependencies::reflector;
+use crate::errors::BridgeOracleError;
+use crate::events::BridgeOracleEvents;
+use crate::storage::{self, AssetConfig, CrossRate, Fallback, PriceSource, Source, SourceKind, SourcePrice};
+
+/// The number of slots in the ring buffer of recent observations kept per asset
+const MAX_OBSERVATIONS: u32 = 24;
+
+const SCALAR_7: i128 = 1_0000000;
+
+/// The version of the storage layout used by this contract. Bump this and add a step to
+/// `migrate` whenever stored data changes shape.
+const STORAGE_VERSION: u32 = 2;
 
 #[contract]
 pub struct BridgeOracleContract;
@@ -11,55 +25,1158 @@ pub trait BridgeOracle {
     /// Initializes the bridge oracle
     ///
     /// # Arguments
+    /// * `admin` - The admin address
+    /// * `guardian` - The address allowed to accept prices held by a circuit breaker and to pause
+    ///   pricing for an asset
     /// * `from_asset` - The asset to convert from
     /// * `to_asset` - The asset to convert to
     /// * `oracle` - The oracle contract address
-    fn initialize(e: Env, from_asset: Address, to_asset: Address, oracle: Address);
+    ///
+    /// # Panics
+    /// If the contract is already initialized
+    fn initialize(
+        e: Env,
+        admin: Address,
+        guardian: Address,
+        from_asset: Address,
+        to_asset: Address,
+        oracle: Address,
+    );
+
+    /// (Admin only) Propose a new admin. The proposed admin must call `accept_admin` before the
+    /// transfer takes effect.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The proposed admin address
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn propose_admin(env: Env, new_admin: Address);
+
+    /// (Proposed admin only) Accept a pending admin transfer
+    ///
+    /// # Panics
+    /// If there is no pending admin transfer
+    /// If the caller is not the proposed admin
+    fn accept_admin(env: Env);
+
+    /// Fetch the admin address
+    fn admin(env: Env) -> Address;
+
+    /// (Admin only) Upgrade the contract to a new WASM, keeping its address and storage. Call
+    /// `migrate` afterwards to bring the storage up to the layout of the new WASM.
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to upgrade to
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
+
+    /// (Admin only) Migrate the stored configuration to the storage layout of the current WASM.
+    /// Does nothing if the storage is already up to date.
+    ///
+    /// # Arguments
+    /// * `assets` - The assets the previous layout kept settings for. Its keys can't be listed,
+    ///   so settings of assets left out are not migrated.
+    ///
+    /// # Panics
+    /// If the storage predates versioning or was written by a newer version of the contract
+    /// If the caller is not the admin
+    fn migrate(env: Env, assets: Vec<Asset>);
+
+    /// Fetch the version of the storage layout
+    fn version(env: Env) -> u32;
+
+    /// (Admin only) Set a new guardian
+    ///
+    /// # Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_guardian(env: Env, guardian: Address);
+
+    /// (Admin only) Set the asset `lastprice` quotes the price of an asset in, instead of the
+    /// default oracle's base, and add it to the list of configured assets. Prices are converted
+    /// as for `lastprice_in`. Pools only call `lastprice`, so every pool reading the asset from
+    /// this deployment gets it in this base, while other assets can be quoted in other bases,
+    /// e.g. some in USD and others in XLM. The price history, `prices` and `twap` stay in the
+    /// default oracle's base.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `base` - The asset to quote the price in
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_base_asset(env: Env, asset: Asset, base: Asset);
+
+    /// (Admin only) Quote the price of an asset from `lastprice` in the default oracle's base
+    /// again
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_base_asset(env: Env, asset: Asset);
+
+    /// Fetch the asset `lastprice` quotes the price of an asset in, if one is set instead of the
+    /// default oracle's base
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the base for
+    fn base_asset(env: Env, asset: Asset) -> Option<Asset>;
+
+    /// (Admin only) Set the pricing settings of an asset and add it to the list of configured
+    /// assets
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `config` - The pricing settings for the asset
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    /// If sources are set and `min_sources` is zero or greater than the number of sources
+    /// If a source has a weight of zero
+    fn set_asset_config(env: Env, asset: Asset, config: AssetConfig);
+
+    /// Fetch the pricing settings of an asset, if it is configured
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the settings for
+    fn get_asset_config(env: Env, asset: Asset) -> Option<AssetConfig>;
+
+    /// (Admin only) Remove all the pricing settings of an asset, including its cross rate,
+    /// fallback feed, base asset, circuit breaker state and pause, and drop it from the list of
+    /// configured assets. The asset is priced by the default oracle again.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to remove the settings for
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_asset_config(env: Env, asset: Asset);
+
+    /// Fetch the assets with pricing settings, a cross rate, a fallback feed or a base asset
+    fn assets(env: Env) -> Vec<Asset>;
 
-    /// Fetch the number of decimals for the oracle
+    /// (Admin only) Set a circuit breaker for an asset. If a new price deviates from the last
+    /// accepted price by more than `max_deviation` within `heartbeat` seconds, the last accepted
+    /// price is returned until the guardian accepts the new price.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `max_deviation` - The maximum deviation from the last accepted price, with 7 decimals
+    /// * `heartbeat` - The time after which any new price is accepted, in seconds
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_breaker(env: Env, asset: Asset, max_deviation: u32, heartbeat: u64);
+
+    /// (Admin only) Remove the circuit breaker for an asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_breaker(env: Env, asset: Asset);
+
+    /// (Guardian only) Accept the price that tripped the circuit breaker of an asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to accept the price for
+    ///
+    /// # Panics
+    /// If the caller is not the guardian
+    /// If the circuit breaker of the asset is not tripped
+    fn ack_price(env: Env, asset: Asset);
+
+    /// (Guardian only) Pause pricing for an asset. Price reads for a paused asset panic instead of
+    /// returning a value, while other assets keep working.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to pause
+    ///
+    /// # Panics
+    /// If the caller is not the guardian
+    fn pause(env: Env, asset: Asset);
+
+    /// (Guardian only) Resume pricing for a paused asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to unpause
+    ///
+    /// # Panics
+    /// If the caller is not the guardian
+    fn unpause(env: Env, asset: Asset);
+
+    /// Check if pricing for an asset is paused
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to check
+    fn is_paused(env: Env, asset: Asset) -> bool;
+
+    /// (Admin only) Pin an asset to a constant price. A fixed price takes precedence over any
+    /// feeds configured for the asset and is always reported with the current ledger timestamp.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `price` - The price, in the oracle's decimals
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_fixed_price(env: Env, asset: Asset, price: i128);
+
+    /// (Admin only) Remove the fixed price of an asset, switching it back to its live feeds
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_fixed_price(env: Env, asset: Asset);
+
+    /// (Admin only) Price an asset with the median of several upstream feeds, weighted by the
+    /// confidence weight of each feed less its staleness penalty. An empty list of sources prices
+    /// the asset by the default oracle again.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `sources` - The upstream feeds to query
+    /// * `min_sources` - The minimum number of feeds that must report a price
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    /// If `min_sources` is zero or greater than the number of sources
+    /// If a source has a weight of zero
+    fn set_sources(env: Env, asset: Asset, sources: Vec<Source>, min_sources: u32);
+
+    /// (Admin only) Set the maximum age of a price for an asset. Older prices are rejected.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `max_age` - The maximum age in seconds, or zero to accept prices of any age
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_max_age(env: Env, asset: Asset, max_age: u64);
+
+    /// (Admin only) Derive the price of an asset from two upstream feeds as `base` price ×
+    /// `quote` price. A cross rate takes precedence over any other primary feeds of the asset.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `base` - The feed for the asset priced in an intermediate asset
+    /// * `quote` - The feed for the intermediate asset priced in the oracle's base
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_cross_rate(env: Env, asset: Asset, base: Source, quote: Source);
+
+    /// (Admin only) Remove the cross rate for an asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_cross_rate(env: Env, asset: Asset);
+
+    /// (Admin only) Set a fallback feed for an asset. The fallback price is returned instead of
+    /// the primary price when the primary feeds fail, have no price, are stale, or deviate from
+    /// the fallback price by more than `max_deviation`.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    /// * `source` - The fallback feed
+    /// * `max_deviation` - The maximum deviation of the primary price from the fallback price, with 7 decimals
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn set_fallback(env: Env, asset: Asset, source: Source, max_deviation: u32);
+
+    /// (Admin only) Remove the fallback feed for an asset
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to configure
+    ///
+    /// # Panics
+    /// If the caller is not the admin
+    fn remove_fallback(env: Env, asset: Asset);
+
+    /// Fetch the price reported by each upstream feed of an asset and its weight in the median
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the breakdown for
+    fn price_breakdown(env: Env, asset: Asset) -> Vec<SourcePrice>;
+
+    /// Fetch the number of decimals for the oracle. Prices from configured feeds are normalized
+    /// to these decimals.
     fn decimals(env: Env) -> u32;
 
-    /// Fetch the last price for the asset
+    /// Fetch the last price for the asset. If several upstream feeds are configured for the
+    /// asset, the median price is returned with the oldest timestamp among the feeds used. If a
+    /// base asset is set for the asset, the price is quoted in it as for `lastprice_in`.
     ///
     /// # Arguments
     /// * `asset` - The asset to fetch the price for
+    ///
+    /// # Panics
+    /// If fewer than the minimum number of upstream feeds report a price
+    /// If the price is older than the maximum age for the asset
+    /// If pricing for the asset is paused
     fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+
+    /// Fetch the last price for the asset quoted in another asset instead of the oracle's base,
+    /// e.g. in XLM for pools priced in XLM. The price is converted with the oracle's price for the
+    /// quote asset, so the quote asset's own feeds and settings define the conversion path. The
+    /// price is returned with the older timestamp of the two prices.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the price for
+    /// * `quote` - The asset to quote the price in
+    ///
+    /// # Panics
+    /// If the price of the asset or of the quote asset can't be fetched, as for `lastprice`
+    fn lastprice_in(env: Env, asset: Asset, quote: Asset) -> Option<PriceData>;
+
+    /// Record the current upstream price of the asset in its price history, and publish it as a
+    /// price update. The history keeps the most recent observations in temporary storage, so it
+    /// expires if the asset is not updated for about two days. Does nothing if the upstream price
+    /// has not changed since the last observation.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to record the price for
+    ///
+    /// # Panics
+    /// If pricing for the asset is paused
+    fn update(env: Env, asset: Asset);
+
+    /// Fetch the most recent prices in the price history of the asset, newest first
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the prices for
+    /// * `records` - The maximum number of prices to return
+    ///
+    /// # Panics
+    /// If pricing for the asset is paused
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+
+    /// Fetch the time-weighted average price of the asset over its most recent observations.
+    /// Each observation is weighted by the time until the next one, and the latest by the time
+    /// until now. The average is reported with the timestamp of the latest observation.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset to fetch the price for
+    /// * `records` - The number of recent observations to average
+    ///
+    /// # Panics
+    /// If pricing for the asset is paused
+    fn twap(env: Env, asset: Asset, records: u32) -> Option<PriceData>;
 }
 
 #[contractimpl]
 impl BridgeOracle for BridgeOracleContract {
-    fn initialize(e: Env, from_asset: Address, to_asset: Address, oracle: Address) {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        guardian: Address,
+        from_asset: Address,
+        to_asset: Address,
+        oracle: Address,
+    ) {
         storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, BridgeOracleError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
         let from_asset = Asset::Stellar(from_asset);
         storage::set_from_asset(&e, &from_asset);
         let to_asset = Asset::Stellar(to_asset);
         storage::set_to_asset(&e, &to_asset);
         storage::set_oracle(&e, &oracle);
+        storage::set_version(&e, STORAGE_VERSION);
+        storage::set_is_init(&e);
+    }
+
+    fn propose_admin(env: Env, new_admin: Address) {
+        storage::extend_instance(&env);
+        let admin = orbit_access::propose_transfer(&env, Role::Admin, &new_admin);
+
+        BridgeOracleEvents::propose_admin(&env, admin, new_admin);
+    }
+
+    fn accept_admin(env: Env) {
+        storage::extend_instance(&env);
+        let (old_admin, new_admin) = orbit_access::accept_transfer(&env, Role::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, BridgeOracleError::NoPendingAdmin));
+
+        BridgeOracleEvents::accept_admin(&env, old_admin, new_admin);
+    }
+
+    fn admin(env: Env) -> Address {
+        storage::extend_instance(&env);
+        orbit_access::get_role(&env, Role::Admin).unwrap()
+    }
+
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        storage::extend_instance(&env);
+        let admin = orbit_access::require_role(&env, Role::Admin);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        BridgeOracleEvents::upgrade(&env, admin, new_wasm_hash);
+    }
+
+    fn migrate(env: Env, assets: Vec<Asset>) {
+        storage::extend_instance(&env);
+
+        // version 0 is the layout of contracts initialized before versioning, which have no admin
+        // and no upgrade entry point, so they can only be replaced by a new deployment
+        let version = storage::get_version(&env);
+        if version == 0 || version > STORAGE_VERSION {
+            panic_with_error!(&env, BridgeOracleError::InvalidStorageVersion);
+        }
+        orbit_access::require_role(&env, Role::Admin);
+        if version == STORAGE_VERSION {
+            return;
+        }
+
+        // version 1 kept each setting of an asset under its own key, its price observations in a
+        // single persistent entry, and its feeds without weights
+        for asset in assets.iter() {
+            migrate_asset_config(&env, &asset);
+            migrate_history(&env, &asset);
+            migrate_feeds(&env, &asset);
+        }
+        storage::set_version(&env, STORAGE_VERSION);
+
+        BridgeOracleEvents::migrate(&env, version, STORAGE_VERSION);
+    }
+
+    fn version(env: Env) -> u32 {
+        storage::extend_instance(&env);
+        storage::get_version(&env)
+    }
+
+    fn set_guardian(env: Env, guardian: Address) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        orbit_access::set_role(&env, Role::Guardian, &guardian);
+    }
+
+    fn set_base_asset(env: Env, asset: Asset, base: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::set_base_asset(&env, &asset, &base);
+        add_asset(&env, &asset);
+    }
+
+    fn remove_base_asset(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::del_base_asset(&env, &asset);
+    }
+
+    fn base_asset(env: Env, asset: Asset) -> Option<Asset> {
+        storage::extend_instance(&env);
+        storage::get_base_asset(&env, &asset)
+    }
+
+    fn set_asset_config(env: Env, asset: Asset, config: AssetConfig) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        store_asset_config(&env, asset, config);
+    }
+
+    fn get_asset_config(env: Env, asset: Asset) -> Option<AssetConfig> {
+        storage::extend_instance(&env);
+        storage::get_asset_config(&env, &asset)
+    }
+
+    fn remove_asset_config(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::del_asset_config(&env, &asset);
+        storage::del_cross_rate(&env, &asset);
+        storage::del_fallback(&env, &asset);
+        storage::del_base_asset(&env, &asset);
+        storage::del_accepted_price(&env, &asset);
+        storage::del_tripped_price(&env, &asset);
+        storage::del_paused(&env, &asset);
+        let mut assets = storage::get_assets(&env);
+        if let Some(index) = assets.first_index_of(&asset) {
+            assets.remove(index);
+            storage::set_assets(&env, &assets);
+        }
+
+        BridgeOracleEvents::remove_asset_config(&env, asset);
+    }
+
+    fn assets(env: Env) -> Vec<Asset> {
+        storage::extend_instance(&env);
+        storage::get_assets(&env)
+    }
+
+    fn set_breaker(env: Env, asset: Asset, max_deviation: u32, heartbeat: u64) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.max_deviation = max_deviation;
+        config.heartbeat = heartbeat;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn remove_breaker(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.max_deviation = 0;
+        config.heartbeat = 0;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn ack_price(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Guardian);
+
+        let tripped = storage::get_tripped_price(&env, &asset)
+            .unwrap_or_else(|| panic_with_error!(&env, BridgeOracleError::NoTrippedPrice));
+        storage::set_accepted_price(&env, &asset, &tripped);
+        storage::del_tripped_price(&env, &asset);
+
+        BridgeOracleEvents::ack_price(&env, asset, tripped);
+    }
+
+    fn pause(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Guardian);
+
+        storage::set_paused(&env, &asset);
+
+        BridgeOracleEvents::pause(&env, asset);
+    }
+
+    fn unpause(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Guardian);
+
+        storage::del_paused(&env, &asset);
+
+        BridgeOracleEvents::unpause(&env, asset);
+    }
+
+    fn is_paused(env: Env, asset: Asset) -> bool {
+        storage::extend_instance(&env);
+        storage::get_paused(&env, &asset)
+    }
+
+    fn set_fixed_price(env: Env, asset: Asset, price: i128) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.fixed_price = price;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn remove_fixed_price(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.fixed_price = 0;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn set_sources(env: Env, asset: Asset, sources: Vec<Source>, min_sources: u32) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.min_sources = if sources.is_empty() { 0 } else { min_sources };
+        config.sources = sources;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn set_max_age(env: Env, asset: Asset, max_age: u64) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        let mut config = load_asset_config(&env, &asset);
+        config.max_age = max_age;
+        store_asset_config(&env, asset, config);
+    }
+
+    fn set_cross_rate(env: Env, asset: Asset, base: Source, quote: Source) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::set_cross_rate(&env, &asset, &CrossRate { base, quote });
+        add_asset(&env, &asset);
+    }
+
+    fn remove_cross_rate(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::del_cross_rate(&env, &asset);
+    }
+
+    fn set_fallback(env: Env, asset: Asset, source: Source, max_deviation: u32) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::set_fallback(&env, &asset, &Fallback { source, max_deviation });
+        add_asset(&env, &asset);
+    }
+
+    fn remove_fallback(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        orbit_access::require_role(&env, Role::Admin);
+
+        storage::del_fallback(&env, &asset);
+    }
+
+    fn price_breakdown(env: Env, asset: Asset) -> Vec<SourcePrice> {
+        storage::extend_instance(&env);
+        let config = load_asset_config(&env, &asset);
+        let max_age = Some(config.max_age).filter(|max_age| *max_age > 0);
+        fetch_source_prices(&env, &config, max_age)
     }
 
     fn decimals(env: Env) -> u32 {
         storage::extend_instance(&env);
-        let oracle = storage::get_oracle(&env);
-        let args = vec![&env];
-        env.invoke_contract::<u32>(&oracle, &Symbol::new(&env, "decimals"), args)
+        fetch_decimals(&env)
     }
 
     fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
         storage::extend_instance(&env);
-        let from_asset = storage::get_from_asset(&env);
-        let oracle = storage::get_oracle(&env);
+        if let Some(base) = storage::get_base_asset(&env, &asset) {
+            return fetch_price_in(&env, asset, base);
+        }
+        match fetch_price(&env, asset) {
+            Ok(price) => price.map(|(price_data, _)| price_data),
+            Err(error) => panic_with_error!(&env, error),
+        }
+    }
 
-        let is_same_asset = match (&from_asset, &asset) {
-            (Asset::Stellar(a), Asset::Stellar(b)) => a == b,
-            _ => false,
+    fn lastprice_in(env: Env, asset: Asset, quote: Asset) -> Option<PriceData> {
+        storage::extend_instance(&env);
+        fetch_price_in(&env, asset, quote)
+    }
+
+    fn update(env: Env, asset: Asset) {
+        storage::extend_instance(&env);
+        let (price, source) = match fetch_price(&env, asset.clone()) {
+            Ok(Some(price)) => price,
+            Ok(None) => return,
+            Err(error) => panic_with_error!(&env, error),
         };
 
-        let mut args: Vec<Val> = vec![&env];
-        if is_same_asset {
-            args.push_back(storage::get_to_asset(&env).into_val(&env))
-        } else {
-            args.push_back(asset.into_val(&env));
+        let index = storage::get_history_index(&env, &asset);
+        if let Some(last) = fetch_history(&env, &asset, 1).first() {
+            if last.timestamp >= price.timestamp {
+                return;
+            }
+        }
+        storage::set_history_price(&env, &asset, index % MAX_OBSERVATIONS, &price);
+        storage::set_history_index(&env, &asset, index + 1);
+
+        BridgeOracleEvents::price_update(&env, asset, price, source);
+    }
+
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+        storage::extend_instance(&env);
+        if storage::get_paused(&env, &asset) {
+            panic_with_error!(&env, BridgeOracleError::AssetPaused);
+        }
+        let prices = fetch_history(&env, &asset, records);
+        if prices.is_empty() {
+            return None;
+        }
+        Some(prices)
+    }
+
+    fn twap(env: Env, asset: Asset, records: u32) -> Option<PriceData> {
+        storage::extend_instance(&env);
+        if storage::get_paused(&env, &asset) {
+            panic_with_error!(&env, BridgeOracleError::AssetPaused);
+        }
+        // observations newest first
+        let recent = fetch_history(&env, &asset, records);
+        let latest = recent.first()?;
+        let mut end = env.ledger().timestamp().max(latest.timestamp);
+        let mut weighted_sum: i128 = 0;
+        let mut total_time: i128 = 0;
+        for observation in recent.iter() {
+            let time = (end - observation.timestamp) as i128;
+            weighted_sum += observation.price * time;
+            total_time += time;
+            end = observation.timestamp;
         }
-        env.invoke_contract::<Option<PriceData>>(&oracle, &Symbol::new(&env, "lastprice"), args)
+
+        if total_time == 0 {
+            return Some(latest);
+        }
+        Some(PriceData {
+            price: weighted_sum / total_time,
+            timestamp: latest.timestamp,
+        })
+    }
+}
+
+/// Fetch the price for an asset quoted in another asset, converted with the price of the quote
+/// asset, with the older timestamp of the two prices
+///
+/// # Panics
+/// If the price of the asset or of the quote asset can't be fetched
+fn fetch_price_in(env: &Env, asset: Asset, quote: Asset) -> Option<PriceData> {
+    let price = match fetch_price(env, asset) {
+        Ok(price) => price,
+        Err(error) => panic_with_error!(env, error),
+    };
+    let quote_price = match fetch_price(env, quote) {
+        Ok(price) => price,
+        Err(error) => panic_with_error!(env, error),
+    };
+    match (price, quote_price) {
+        (Some((price, _)), Some((quote_price, _))) if quote_price.price > 0 => {
+            let scalar = 10i128.pow(fetch_decimals(env));
+            Some(PriceData {
+                price: math::mul_div_floor(env, price.price, scalar, quote_price.price),
+                timestamp: price.timestamp.min(quote_price.timestamp),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Fetch up to `records` of the most recent observations of an asset from its ring buffer, newest
+/// first. Stops at the first expired slot.
+fn fetch_history(env: &Env, asset: &Asset, records: u32) -> Vec<PriceData> {
+    let index = storage::get_history_index(env, asset);
+    let count = records.min(index).min(MAX_OBSERVATIONS);
+    let mut history = Vec::new(env);
+    for i in 1..=count {
+        match storage::get_history_price(env, asset, (index - i) % MAX_OBSERVATIONS) {
+            Some(price) => history.push_back(price),
+            None => break,
+        }
+    }
+    history
+}
+
+/// Fetch the pricing settings of an asset, or the empty settings if it is not configured
+fn load_asset_config(env: &Env, asset: &Asset) -> AssetConfig {
+    storage::get_asset_config(env, asset).unwrap_or(AssetConfig {
+        sources: Vec::new(env),
+        min_sources: 0,
+        max_age: 0,
+        max_deviation: 0,
+        heartbeat: 0,
+        fixed_price: 0,
+    })
+}
+
+/// Validate and store the pricing settings of an asset, adding it to the list of configured
+/// assets. Clears the circuit breaker state if the asset has no circuit breaker.
+fn store_asset_config(env: &Env, asset: Asset, config: AssetConfig) {
+    if !config.sources.is_empty()
+        && (config.min_sources == 0 || config.min_sources > config.sources.len())
+    {
+        panic_with_error!(env, BridgeOracleError::InvalidSourceConfig);
+    }
+    if config.sources.iter().any(|source| source.weight == 0) {
+        panic_with_error!(env, BridgeOracleError::InvalidSourceConfig);
+    }
+
+    storage::set_asset_config(env, &asset, &config);
+    if config.max_deviation == 0 {
+        storage::del_accepted_price(env, &asset);
+        storage::del_tripped_price(env, &asset);
+    }
+    add_asset(env, &asset);
+
+    BridgeOracleEvents::set_asset_config(env, asset, config);
+}
+
+/// Merge the settings a bridge oracle of storage version 1 kept for an asset into its
+/// `AssetConfig`, adding it to the list of configured assets if it had any
+fn migrate_asset_config(env: &Env, asset: &Asset) {
+    let legacy_sources = storage::take_legacy_sources(env, asset);
+    let max_age = storage::take_legacy_max_age(env, asset);
+    let breaker = storage::take_legacy_breaker(env, asset);
+    let fixed_price = storage::take_legacy_fixed_price(env, asset);
+    if legacy_sources.is_none() && max_age.is_none() && breaker.is_none() && fixed_price.is_none() {
+        return;
+    }
+
+    let mut config = load_asset_config(env, asset);
+    if let Some(legacy_sources) = legacy_sources {
+        for source in legacy_sources.sources.iter() {
+            config.sources.push_back(source.upgrade());
+        }
+        config.min_sources = legacy_sources.min_sources;
+    }
+    config.max_age = max_age.unwrap_or(0);
+    if let Some(breaker) = breaker {
+        config.max_deviation = breaker.max_deviation;
+        config.heartbeat = breaker.heartbeat;
+    }
+    config.fixed_price = fixed_price.unwrap_or(0);
+    storage::set_asset_config(env, asset, &config);
+    add_asset(env, asset);
+}
+
+/// Move the price observations a bridge oracle of storage version 1 kept for an asset into its
+/// ring buffer, keeping the most recent `MAX_OBSERVATIONS`
+fn migrate_history(env: &Env, asset: &Asset) {
+    let observations = storage::take_legacy_observations(env, asset).unwrap_or(Vec::new(env));
+    let recent = observations.slice(observations.len().saturating_sub(MAX_OBSERVATIONS)..);
+    for (slot, price) in recent.iter().enumerate() {
+        storage::set_history_price(env, asset, slot as u32, &price);
+    }
+    storage::set_history_index(env, asset, recent.len());
+}
+
+/// Rewrite the fallback feed and cross rate a bridge oracle of storage version 1 kept for an asset
+/// with weighted feeds
+fn migrate_feeds(env: &Env, asset: &Asset) {
+    if let Some(fallback) = storage::take_legacy_fallback(env, asset) {
+        let fallback = Fallback {
+            source: fallback.source.upgrade(),
+            max_deviation: fallback.max_deviation,
+        };
+        storage::set_fallback(env, asset, &fallback);
+    }
+    if let Some(cross_rate) = storage::take_legacy_cross_rate(env, asset) {
+        let cross_rate = CrossRate {
+            base: cross_rate.base.upgrade(),
+            quote: cross_rate.quote.upgrade(),
+        };
+        storage::set_cross_rate(env, asset, &cross_rate);
+    }
+}
+
+/// Add an asset to the list of configured assets, if it is not listed yet
+fn add_asset(env: &Env, asset: &Asset) {
+    let mut assets = storage::get_assets(env);
+    if !assets.contains(asset) {
+        assets.push_back(asset.clone());
+        storage::set_assets(env, &assets);
+    }
+}
+
+/// Fetch the price for an asset, using its fixed price if it has one or passing its upstream
+/// price through its circuit breaker. Fails if pricing for the asset is paused.
+fn fetch_price(env: &Env, asset: Asset) -> Result<Option<(PriceData, PriceSource)>, BridgeOracleError> {
+    if storage::get_paused(env, &asset) {
+        return Err(BridgeOracleError::AssetPaused);
+    }
+    let config = load_asset_config(env, &asset);
+    if config.fixed_price != 0 {
+        let price_data = PriceData {
+            price: config.fixed_price,
+            timestamp: env.ledger().timestamp(),
+        };
+        return Ok(Some((price_data, PriceSource::Fixed)));
+    }
+
+    let price = fetch_upstream_price(env, asset.clone(), &config)?;
+    match price {
+        Some(price) if config.max_deviation != 0 => Ok(apply_breaker(env, asset, price, &config)),
+        price => Ok(price),
+    }
+}
+
+/// Return the new price if the circuit breaker accepts it, or the last accepted price if the
+/// breaker is tripped. A held price older than the maximum age of the asset is not returned.
+fn apply_breaker(
+    env: &Env,
+    asset: Asset,
+    price: (PriceData, PriceSource),
+    config: &AssetConfig,
+) -> Option<(PriceData, PriceSource)> {
+    let (price_data, _) = &price;
+    let accepted = match storage::get_accepted_price(env, &asset) {
+        Some(accepted) => accepted,
+        None => {
+            storage::set_accepted_price(env, &asset, price_data);
+            return Some(price);
+        }
+    };
+    if storage::get_tripped_price(env, &asset).is_some() || price_data.timestamp <= accepted.timestamp {
+        return hold_price(env, accepted, config);
+    }
+
+    if price_data.timestamp - accepted.timestamp <= config.heartbeat
+        && exceeds_deviation(price_data.price, accepted.price, config.max_deviation)
+    {
+        storage::set_tripped_price(env, &asset, price_data);
+        BridgeOracleEvents::deviation_tripped(env, asset, accepted.clone(), price_data.clone());
+        return hold_price(env, accepted, config);
+    }
+    storage::set_accepted_price(env, &asset, price_data);
+    Some(price)
+}
+
+/// Return the last accepted price held by the circuit breaker, or None if it is older than the
+/// maximum age of the asset
+fn hold_price(
+    env: &Env,
+    accepted: PriceData,
+    config: &AssetConfig,
+) -> Option<(PriceData, PriceSource)> {
+    let max_age = Some(config.max_age).filter(|max_age| *max_age > 0);
+    if is_stale(env, &accepted, max_age) {
+        return None;
+    }
+    Some((accepted, PriceSource::Held))
+}
+
+/// Fetch the upstream price for an asset, switching to its fallback feed if the primary price
+/// can't be used
+fn fetch_upstream_price(
+    env: &Env,
+    asset: Asset,
+    config: &AssetConfig,
+) -> Result<Option<(PriceData, PriceSource)>, BridgeOracleError> {
+    let max_age = Some(config.max_age).filter(|max_age| *max_age > 0);
+    let primary = fetch_primary_price(env, asset.clone(), config, max_age);
+    let fallback = match storage::get_fallback(env, &asset) {
+        Some(fallback) => fallback,
+        None => return primary,
+    };
+
+    let fallback_price =
+        fetch_source_price(env, &fallback.source).filter(|price| !is_stale(env, price, max_age));
+    match (primary, fallback_price) {
+        (Ok(Some((price, source))), Some(fallback_price))
+            if !exceeds_deviation(price.price, fallback_price.price, fallback.max_deviation) =>
+        {
+            Ok(Some((price, source)))
+        }
+        (_, Some(fallback_price)) => {
+            BridgeOracleEvents::fallback(env, asset, fallback_price.clone());
+            Ok(Some((fallback_price, PriceSource::Fallback)))
+        }
+        (primary, None) => primary,
+    }
+}
+
+/// Check if a price deviates from a reference price by more than `max_deviation`
+fn exceeds_deviation(price: i128, reference: i128, max_deviation: u32) -> bool {
+    (price - reference).abs() * SCALAR_7 > reference * max_deviation as i128
+}
+
+/// Fetch the price for an asset from its primary feeds, using its cross rate, the median of its
+/// configured feeds, or the default oracle if it has neither
+fn fetch_primary_price(
+    env: &Env,
+    asset: Asset,
+    config: &AssetConfig,
+    max_age: Option<u64>,
+) -> Result<Option<(PriceData, PriceSource)>, BridgeOracleError> {
+    if let Some(cross_rate) = storage::get_cross_rate(env, &asset) {
+        let price_data = fetch_cross_price(env, &cross_rate, max_age)?;
+        return Ok(Some((price_data, PriceSource::CrossRate)));
+    }
+    if !config.sources.is_empty() {
+        let price_data = fetch_median_price(env, config, max_age)?;
+        return Ok(Some((price_data, PriceSource::Median)));
+    }
+    match fetch_default_price(env, asset) {
+        Some(price_data) if is_stale(env, &price_data, max_age) => {
+            Err(BridgeOracleError::StalePrice)
+        }
+        price_data => Ok(price_data.map(|price_data| (price_data, PriceSource::Default))),
+    }
+}
+
+/// Fetch the price derived from a cross rate, with the oldest timestamp of its two feeds
+fn fetch_cross_price(
+    env: &Env,
+    cross_rate: &CrossRate,
+    max_age: Option<u64>,
+) -> Result<PriceData, BridgeOracleError> {
+    let base = fetch_source_price(env, &cross_rate.base)
+        .ok_or(BridgeOracleError::InsufficientSources)?;
+    let quote = fetch_source_price(env, &cross_rate.quote)
+        .ok_or(BridgeOracleError::InsufficientSources)?;
+    if is_stale(env, &base, max_age) || is_stale(env, &quote, max_age) {
+        return Err(BridgeOracleError::StalePrice);
+    }
+
+    let scalar = 10i128.pow(fetch_decimals(env));
+    Ok(PriceData {
+        price: math::mul_div_floor(env, base.price, quote.price, scalar),
+        timestamp: base.timestamp.min(quote.timestamp),
+    })
+}
+
+/// Check if a price is older than the maximum age
+fn is_stale(env: &Env, price_data: &PriceData, max_age: Option<u64>) -> bool {
+    match max_age {
+        Some(max_age) => env.ledger().timestamp() > price_data.timestamp + max_age,
+        None => false,
+    }
+}
+
+/// Fetch the price reported by each upstream feed of an asset and its weight after the staleness
+/// penalty. Feeds that fail, have no price, have a stale price or have no weight left are
+/// skipped with a weight of zero.
+fn fetch_source_prices(env: &Env, config: &AssetConfig, max_age: Option<u64>) -> Vec<SourcePrice> {
+    let now = env.ledger().timestamp();
+    let mut source_prices = Vec::new(env);
+    for source in config.sources.iter() {
+        let (price, timestamp, weight) = match fetch_source_price(env, &source) {
+            Some(price_data) if is_stale(env, &price_data, max_age) => {
+                (price_data.price, price_data.timestamp, 0)
+            }
+            Some(price_data) => {
+                let age = now.saturating_sub(price_data.timestamp);
+                let penalty = (source.staleness_penalty as u64).saturating_mul(age);
+                let weight = (source.weight as u64).saturating_sub(penalty) as u32;
+                (price_data.price, price_data.timestamp, weight)
+            }
+            None => (0, 0, 0),
+        };
+        source_prices.push_back(SourcePrice {
+            source,
+            price,
+            timestamp,
+            weight,
+        });
+    }
+    source_prices
+}
+
+/// Fetch the weighted median price of the upstream feeds of an asset, with the oldest timestamp
+/// among the feeds used. Feeds that fail, have no price, have a stale price or have no weight
+/// left are skipped.
+fn fetch_median_price(
+    env: &Env,
+    config: &AssetConfig,
+    max_age: Option<u64>,
+) -> Result<PriceData, BridgeOracleError> {
+    // (price, weight) pairs sorted by price in ascending order
+    let mut prices: Vec<(i128, u32)> = vec![env];
+    let mut timestamp = u64::MAX;
+    let mut total_weight: u64 = 0;
+    let mut has_stale = false;
+    for source_price in fetch_source_prices(env, config, max_age).iter() {
+        if source_price.weight == 0 {
+            has_stale |= source_price.timestamp != 0;
+            continue;
+        }
+        let index = prices
+            .iter()
+            .position(|(price, _)| price > source_price.price)
+            .unwrap_or(prices.len() as usize);
+        prices.insert(index as u32, (source_price.price, source_price.weight));
+        timestamp = timestamp.min(source_price.timestamp);
+        total_weight += source_price.weight as u64;
+    }
+
+    if prices.len() < config.min_sources {
+        if has_stale {
+            return Err(BridgeOracleError::StalePrice);
+        }
+        return Err(BridgeOracleError::InsufficientSources);
+    }
+
+    // the first price with at least half of the total weight at or below it, averaged with the
+    // next price if it holds exactly half
+    let mut cumulative_weight: u64 = 0;
+    let mut price = 0;
+    for i in 0..prices.len() {
+        let (current, weight) = prices.get_unchecked(i);
+        cumulative_weight += weight as u64;
+        if cumulative_weight * 2 > total_weight {
+            price = current;
+            break;
+        }
+        if cumulative_weight * 2 == total_weight {
+            price = (current + prices.get_unchecked(i + 1).0) / 2;
+            break;
+        }
+    }
+    Ok(PriceData { price, timestamp })
+}
+
+/// Fetch the number of decimals of the default oracle
+fn fetch_decimals(env: &Env) -> u32 {
+    let oracle = storage::get_oracle(env);
+    let args = vec![env];
+    env.invoke_contract::<u32>(&oracle, &Symbol::new(env, "decimals"), args)
+}
+
+/// Fetch the price from an upstream feed normalized to the oracle's decimals, or None if the
+/// feed fails or has no price
+fn fetch_source_price(env: &Env, source: &Source) -> Option<PriceData> {
+    let price_data = match source.kind {
+        SourceKind::Sep40 => fetch_sep40_price(env, &source.oracle, source.asset.clone()),
+        SourceKind::Reflector => {
+            reflector::fetch_price(env, &source.oracle, source.asset.clone())
+        }
+    }?;
+    Some(PriceData {
+        price: normalize_decimals(price_data.price, source.decimals, fetch_decimals(env)),
+        timestamp: price_data.timestamp,
+    })
+}
+
+/// Fetch the price from a SEP-40 oracle, or None if the oracle fails or has no price
+fn fetch_sep40_price(env: &Env, oracle: &Address, asset: Asset) -> Option<PriceData> {
+    let args: Vec<Val> = vec![env, asset.into_val(env)];
+    let result = env.try_invoke_contract::<Option<PriceData>, soroban_sdk::Error>(
+        oracle,
+        &Symbol::new(env, "lastprice"),
+        args,
+    );
+    match result {
+        Ok(Ok(price_data)) => price_data,
+        _ => None,
+    }
+}
+
+/// Scale a price from `from_decimals` to `to_decimals`
+fn normalize_decimals(price: i128, from_decimals: u32, to_decimals: u32) -> i128 {
+    if from_decimals > to_decimals {
+        price / 10i128.pow(from_decimals - to_decimals)
+    } else {
+        price * 10i128.pow(to_decimals - from_decimals)
+    }
+}
+
+/// Fetch the price for an asset from the default oracle, converting `from_asset` to `to_asset`
+fn fetch_default_price(env: &Env, asset: Asset) -> Option<PriceData> {
+    let from_asset = storage::get_from_asset(env);
+    let oracle = storage::get_oracle(env);
+
+    let is_same_asset = match (&from_asset, &asset) {
+        (Asset::Stellar(a), Asset::Stellar(b)) => a == b,
+        _ => false,
+    };
+
+    let mut args: Vec<Val> = vec![env];
+    if is_same_asset {
+        args.push_back(storage::get_to_asset(env).into_val(env))
+    } else {
+        args.push_back(asset.into_val(env));
     }
+    env.invoke_contract::<Option<PriceData>>(&oracle, &Symbol::new(env, "lastprice"), args)
 }
\ No newline at end of file
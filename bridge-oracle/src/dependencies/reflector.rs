@@ -0,0 +1,68 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+/// Asset type used by the Reflector oracle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Price data for an asset at a specific round of the Reflector oracle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The subset of the Reflector oracle interface used by the bridge oracle
+#[allow(dead_code)]
+#[contractclient(name = "ReflectorClient")]
+pub trait Reflector {
+    /// Fetch the number of decimals of the prices
+    fn decimals(env: Env) -> u32;
+
+    /// Fetch the length of a round in seconds
+    fn resolution(env: Env) -> u32;
+
+    /// Fetch the price of the asset in the latest round, or None if the asset was not
+    /// priced in that round
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+
+    /// Fetch the prices of the asset in the most recent rounds, newest first
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+}
+
+impl From<sep_40_oracle::Asset> for Asset {
+    fn from(asset: sep_40_oracle::Asset) -> Self {
+        match asset {
+            sep_40_oracle::Asset::Stellar(address) => Asset::Stellar(address),
+            sep_40_oracle::Asset::Other(symbol) => Asset::Other(symbol),
+        }
+    }
+}
+
+/// Fetch the most recent price of an asset from a Reflector oracle. Reflector only reports a
+/// price from `lastprice` if the asset was updated in the latest round, so the last two rounds
+/// are checked before giving up. Returns None if the oracle fails or has no recent price.
+pub fn fetch_price(
+    env: &Env,
+    oracle: &Address,
+    asset: sep_40_oracle::Asset,
+) -> Option<sep_40_oracle::PriceData> {
+    let client = ReflectorClient::new(env, oracle);
+    let asset = Asset::from(asset);
+    let price_data = match client.try_lastprice(&asset) {
+        Ok(Ok(Some(price_data))) => price_data,
+        Ok(Ok(None)) => match client.try_prices(&asset, &2) {
+            Ok(Ok(Some(prices))) => prices.first()?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(sep_40_oracle::PriceData {
+        price: price_data.price,
+        timestamp: price_data.timestamp,
+    })
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the bridge oracle contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Bridge oracle specific errors start at 2200.
+pub enum BridgeOracleError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Bridge Oracle
+    InsufficientSources = 2200,
+    InvalidSourceConfig = 2201,
+    StalePrice = 2202,
+    NoPendingAdmin = 2203,
+    NoTrippedPrice = 2204,
+    AssetPaused = 2205,
+    InvalidStorageVersion = 2206,
+}
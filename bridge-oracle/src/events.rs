@@ -0,0 +1,118 @@
+use sep_40_oracle::{Asset, PriceData};
+use orbit_events::topics;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::storage::{AssetConfig, PriceSource};
+
+pub struct BridgeOracleEvents {}
+
+impl BridgeOracleEvents {
+    /// Emitted when the admin proposes a new admin
+    ///
+    /// - topics - `["propose_admin", admin: Address]`
+    /// - data - `new_admin: Address`
+    pub fn propose_admin(env: &Env, admin: Address, new_admin: Address) {
+        let topics = (Symbol::new(env, topics::PROPOSE_ADMIN), admin);
+        env.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when a proposed admin accepts the role
+    ///
+    /// - topics - `["accept_admin", old_admin: Address]`
+    /// - data - `new_admin: Address`
+    pub fn accept_admin(env: &Env, old_admin: Address, new_admin: Address) {
+        let topics = (Symbol::new(env, topics::ACCEPT_ADMIN), old_admin);
+        env.events().publish(topics, new_admin);
+    }
+
+    /// Emitted when the contract is upgraded
+    ///
+    /// - topics - `["upgrade", admin: Address]`
+    /// - data - `new_wasm_hash: BytesN<32>`
+    pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(env, topics::UPGRADE), admin);
+        env.events().publish(topics, new_wasm_hash);
+    }
+
+    /// Emitted when the storage of the contract is migrated to a new layout
+    ///
+    /// - topics - `["migrate"]`
+    /// - data - `[from_version: u32, to_version: u32]`
+    pub fn migrate(env: &Env, from_version: u32, to_version: u32) {
+        let topics = (Symbol::new(env, topics::MIGRATE),);
+        env.events().publish(topics, (from_version, to_version));
+    }
+
+    /// Emitted when the admin updates the pricing settings of an asset
+    ///
+    /// - topics - `["set_asset_config", asset: Asset]`
+    /// - data - `config: AssetConfig`
+    pub fn set_asset_config(env: &Env, asset: Asset, config: AssetConfig) {
+        let topics = (Symbol::new(env, topics::SET_ASSET_CONFIG), asset);
+        env.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin removes the pricing settings of an asset
+    ///
+    /// - topics - `["remove_asset_config", asset: Asset]`
+    /// - data - `()`
+    pub fn remove_asset_config(env: &Env, asset: Asset) {
+        let topics = (Symbol::new(env, topics::REMOVE_ASSET_CONFIG), asset);
+        env.events().publish(topics, ());
+    }
+
+    /// Emitted when a new price for an asset is recorded by `update`
+    ///
+    /// - topics - `["price_update", asset: Asset]`
+    /// - data - `[price: i128, source: PriceSource, timestamp: u64]`
+    pub fn price_update(env: &Env, asset: Asset, price: PriceData, source: PriceSource) {
+        let topics = (Symbol::new(env, topics::PRICE_UPDATE), asset);
+        env.events()
+            .publish(topics, (price.price, source, price.timestamp));
+    }
+
+    /// Emitted when a new price deviates too far from the last accepted price of an asset and the
+    /// circuit breaker holds the last accepted price
+    ///
+    /// - topics - `["deviation_tripped", asset: Asset]`
+    /// - data - `[accepted: PriceData, tripped: PriceData]`
+    pub fn deviation_tripped(env: &Env, asset: Asset, accepted: PriceData, tripped: PriceData) {
+        let topics = (Symbol::new(env, topics::DEVIATION_TRIPPED), asset);
+        env.events().publish(topics, (accepted, tripped));
+    }
+
+    /// Emitted when the guardian accepts the price that tripped the circuit breaker of an asset
+    ///
+    /// - topics - `["ack_price", asset: Asset]`
+    /// - data - `price: PriceData`
+    pub fn ack_price(env: &Env, asset: Asset, price: PriceData) {
+        let topics = (Symbol::new(env, topics::ACK_PRICE), asset);
+        env.events().publish(topics, price);
+    }
+
+    /// Emitted when the guardian pauses pricing for an asset
+    ///
+    /// - topics - `["pause", asset: Asset]`
+    /// - data - `()`
+    pub fn pause(env: &Env, asset: Asset) {
+        let topics = (Symbol::new(env, topics::PAUSE), asset);
+        env.events().publish(topics, ());
+    }
+
+    /// Emitted when the guardian resumes pricing for an asset
+    ///
+    /// - topics - `["unpause", asset: Asset]`
+    /// - data - `()`
+    pub fn unpause(env: &Env, asset: Asset) {
+        let topics = (Symbol::new(env, topics::UNPAUSE), asset);
+        env.events().publish(topics, ());
+    }
+
+    /// Emitted when the fallback feed of an asset is used instead of its primary feeds
+    ///
+    /// - topics - `["fallback", asset: Asset]`
+    /// - data - `price: PriceData`
+    pub fn fallback(env: &Env, asset: Asset, price: PriceData) {
+        let topics = (Symbol::new(env, topics::FALLBACK), asset);
+        env.events().publish(topics, price);
+    }
+}
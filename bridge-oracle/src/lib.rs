@@ -1,8 +1,12 @@
 #![no_std]
 
 mod contract;
+mod dependencies;
+mod errors;
+mod events;
 mod storage;
 mod test;
 
-
-
+pub use contract::*;
+pub use errors::BridgeOracleError;
+pub use storage::{AssetConfig, CrossRate, Fallback, PriceSource, Source, SourceKind, SourcePrice};
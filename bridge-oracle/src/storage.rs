@@ -1,13 +1,189 @@
-use sep_40_oracle::Asset;
-use soroban_sdk::{Address, Env, Symbol};
+use sep_40_oracle::{Asset, PriceData};
+use soroban_sdk::{contracttype, Address, Env, Symbol, TryFromVal, Val, Vec};
 
 pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
 pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+pub(crate) const LEDGER_THRESHOLD_HISTORY: u32 = 17280; // ~ 1 day
+pub(crate) const LEDGER_BUMP_HISTORY: u32 = 34560; // ~ 2 days
 
 
+const IS_INIT_KEY: &str = "IsInit";
 const FROM_ASSET_KEY: &str = "FomAsset";
 const TO_ASSET_KEY: &str = "ToAsset";
 const ORACLE_KEY: &str = "Oracle";
+const VERSION_KEY: &str = "Version";
+const ASSETS_KEY: &str = "Assets";
+
+/// The interface of an upstream price feed
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum SourceKind {
+    Sep40,
+    Reflector,
+}
+
+/// Where a price returned by the oracle came from
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum PriceSource {
+    Fixed,     // the asset's fixed price
+    Default,   // the default oracle
+    Median,    // the median of the asset's feeds
+    CrossRate, // the asset's cross rate
+    Fallback,  // the asset's fallback feed
+    Held,      // the last accepted price, held by the asset's circuit breaker
+}
+
+/// An upstream price feed for an asset
+#[derive(Clone)]
+#[contracttype]
+pub struct Source {
+    pub kind: SourceKind,       // the interface of the upstream oracle
+    pub oracle: Address,        // the upstream oracle contract
+    pub asset: Asset,           // the asset to query on the upstream oracle
+    pub decimals: u32,          // the decimals of the prices reported by the upstream oracle
+    pub weight: u32,            // the confidence weight of the feed in the median of an asset
+    pub staleness_penalty: u32, // the weight lost for each second of age of the feed's price
+}
+
+/// The contribution of an upstream feed to the aggregated price of an asset
+#[derive(Clone)]
+#[contracttype]
+pub struct SourcePrice {
+    pub source: Source,
+    pub price: i128,    // the normalized price reported by the feed, or zero if it has none
+    pub timestamp: u64, // the timestamp of the price, or zero if it has none
+    pub weight: u32,    // the weight of the price after the staleness penalty, or zero if skipped
+}
+
+/// The pricing settings for an asset. A zero value disables the setting.
+///
+/// If `max_deviation` is set, a circuit breaker holds the last accepted price of the asset when a
+/// new price deviates from it by more than `max_deviation` within `heartbeat` seconds.
+#[derive(Clone)]
+#[contracttype]
+pub struct AssetConfig {
+    pub sources: Vec<Source>, // the feeds to take the median of, or empty for the default oracle
+    pub min_sources: u32,     // the minimum number of feeds that must report a price
+    pub max_age: u64,         // the maximum age of a price, in seconds
+    pub max_deviation: u32,   // the maximum deviation from the last accepted price, with 7 decimals
+    pub heartbeat: u64,       // the time after which any new price is accepted, in seconds
+    pub fixed_price: i128,    // the constant price the asset is pinned to, in the oracle's decimals
+}
+
+/// A secondary feed for an asset, used when its primary feeds are unavailable, stale, or deviate
+/// from it by more than `max_deviation`
+#[derive(Clone)]
+#[contracttype]
+pub struct Fallback {
+    pub source: Source,
+    pub max_deviation: u32, // the maximum deviation from the fallback price, with 7 decimals
+}
+
+/// A price derived from two upstream feeds as `base` price × `quote` price, e.g. EUR/USD from
+/// an EUR/USDC feed and a USDC/USD feed
+#[derive(Clone)]
+#[contracttype]
+pub struct CrossRate {
+    pub base: Source,
+    pub quote: Source,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum BridgeOracleDataKey {
+    // The number of price observations ever recorded for an asset
+    HistoryIndex(Asset),
+    // A slot of the ring buffer of recent price observations for an asset
+    History(Asset, u32),
+    // The pricing settings for an asset
+    Config(Asset),
+    // The fallback feed for an asset
+    Fallback(Asset),
+    // The feeds an asset's price is derived from
+    CrossRate(Asset),
+    // The asset `lastprice` quotes the price of an asset in
+    Base(Asset),
+    // The last price accepted by the circuit breaker for an asset
+    Accepted(Asset),
+    // The price that tripped the circuit breaker for an asset, waiting for the guardian
+    Tripped(Asset),
+    // Whether pricing for an asset is paused by the guardian
+    Paused(Asset),
+}
+
+/// An upstream price feed for an asset, as stored by storage version 1
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacySource {
+    pub kind: SourceKind,
+    pub oracle: Address,
+    pub asset: Asset,
+    pub decimals: u32,
+}
+
+impl LegacySource {
+    /// Convert the feed to the current layout. Every feed gets the same weight and no staleness
+    /// penalty, so the median of an asset's feeds is unchanged.
+    pub fn upgrade(self) -> Source {
+        Source {
+            kind: self.kind,
+            oracle: self.oracle,
+            asset: self.asset,
+            decimals: self.decimals,
+            weight: 1,
+            staleness_penalty: 0,
+        }
+    }
+}
+
+/// The upstream price feeds for an asset and how many must report a price, as stored by storage
+/// version 1
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacySourceConfig {
+    pub sources: Vec<LegacySource>,
+    pub min_sources: u32,
+}
+
+/// The circuit breaker for an asset, as stored by storage version 1
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyBreaker {
+    pub max_deviation: u32,
+    pub heartbeat: u64,
+}
+
+/// A secondary feed for an asset, as stored by storage version 1
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyFallback {
+    pub source: LegacySource,
+    pub max_deviation: u32,
+}
+
+/// A price derived from two upstream feeds, as stored by storage version 1
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyCrossRate {
+    pub base: LegacySource,
+    pub quote: LegacySource,
+}
+
+/// The keys of the entries storage version 1 kept for an asset in a layout that has since changed.
+/// `Fallback` and `CrossRate` share their keys with `BridgeOracleDataKey` but held unweighted
+/// feeds.
+#[derive(Clone)]
+#[contracttype]
+pub enum LegacyDataKey {
+    Observations(Asset),
+    Sources(Asset),
+    MaxAge(Asset),
+    Breaker(Asset),
+    FixedPrice(Asset),
+    Fallback(Asset),
+    CrossRate(Asset),
+}
 
 pub fn extend_instance(env: &Env) {
     env.storage()
@@ -15,6 +191,30 @@ pub fn extend_instance(env: &Env) {
         .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+pub fn get_is_init(env: &Env) -> bool {
+    env.storage().instance().has(&Symbol::new(env, IS_INIT_KEY))
+}
+
+pub fn set_is_init(env: &Env) {
+    env.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(env, IS_INIT_KEY), &true);
+}
+
+/// Fetch the version of the storage layout, or 0 if the contract was initialized before the
+/// version was tracked
+pub fn get_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&Symbol::new(env, VERSION_KEY))
+        .unwrap_or(0)
+}
+
+pub fn set_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(env, VERSION_KEY), &version);
+}
 
 pub fn get_from_asset(env: &Env) -> Asset {
     env.storage()
@@ -53,4 +253,306 @@ pub fn set_oracle(env: &Env, address: &Address) {
     env.storage()
         .instance()
         .set(&Symbol::new(env, ORACLE_KEY), address);
-}
\ No newline at end of file
+}
+
+/// Fetch the number of price observations recorded for an asset. Resets to 0 if no observation
+/// was recorded for long enough that the entry expired.
+pub fn get_history_index(env: &Env, asset: &Asset) -> u32 {
+    let key = BridgeOracleDataKey::HistoryIndex(asset.clone());
+    env.storage()
+        .temporary()
+        .get::<BridgeOracleDataKey, u32>(&key)
+        .unwrap_or(0)
+}
+
+pub fn set_history_index(env: &Env, asset: &Asset, index: u32) {
+    let key = BridgeOracleDataKey::HistoryIndex(asset.clone());
+    env.storage()
+        .temporary()
+        .set::<BridgeOracleDataKey, u32>(&key, &index);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_THRESHOLD_HISTORY, LEDGER_BUMP_HISTORY);
+}
+
+/// Fetch the price observation stored in a slot of the ring buffer of an asset, if it has not
+/// expired
+pub fn get_history_price(env: &Env, asset: &Asset, slot: u32) -> Option<PriceData> {
+    let key = BridgeOracleDataKey::History(asset.clone(), slot);
+    env.storage()
+        .temporary()
+        .get::<BridgeOracleDataKey, PriceData>(&key)
+}
+
+pub fn set_history_price(env: &Env, asset: &Asset, slot: u32, price: &PriceData) {
+    let key = BridgeOracleDataKey::History(asset.clone(), slot);
+    env.storage()
+        .temporary()
+        .set::<BridgeOracleDataKey, PriceData>(&key, price);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_THRESHOLD_HISTORY, LEDGER_BUMP_HISTORY);
+}
+
+pub fn get_assets(env: &Env) -> Vec<Asset> {
+    env.storage()
+        .instance()
+        .get(&Symbol::new(env, ASSETS_KEY))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_assets(env: &Env, assets: &Vec<Asset>) {
+    env.storage()
+        .instance()
+        .set::<Symbol, Vec<Asset>>(&Symbol::new(env, ASSETS_KEY), assets);
+}
+
+pub fn get_asset_config(env: &Env, asset: &Asset) -> Option<AssetConfig> {
+    let key = BridgeOracleDataKey::Config(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, AssetConfig>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_asset_config(env: &Env, asset: &Asset, config: &AssetConfig) {
+    let key = BridgeOracleDataKey::Config(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, AssetConfig>(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_asset_config(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Config(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_fallback(env: &Env, asset: &Asset) -> Option<Fallback> {
+    let key = BridgeOracleDataKey::Fallback(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, Fallback>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_fallback(env: &Env, asset: &Asset, fallback: &Fallback) {
+    let key = BridgeOracleDataKey::Fallback(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, Fallback>(&key, fallback);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_fallback(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Fallback(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_cross_rate(env: &Env, asset: &Asset) -> Option<CrossRate> {
+    let key = BridgeOracleDataKey::CrossRate(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, CrossRate>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_cross_rate(env: &Env, asset: &Asset, cross_rate: &CrossRate) {
+    let key = BridgeOracleDataKey::CrossRate(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, CrossRate>(&key, cross_rate);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_cross_rate(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::CrossRate(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Fetch the asset `lastprice` quotes the price of an asset in, if one is set instead of the
+/// default oracle's base
+pub fn get_base_asset(env: &Env, asset: &Asset) -> Option<Asset> {
+    let key = BridgeOracleDataKey::Base(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, Asset>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_base_asset(env: &Env, asset: &Asset, base: &Asset) {
+    let key = BridgeOracleDataKey::Base(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, Asset>(&key, base);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_base_asset(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Base(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_accepted_price(env: &Env, asset: &Asset) -> Option<PriceData> {
+    let key = BridgeOracleDataKey::Accepted(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, PriceData>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_accepted_price(env: &Env, asset: &Asset, accepted: &PriceData) {
+    let key = BridgeOracleDataKey::Accepted(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, PriceData>(&key, accepted);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_accepted_price(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Accepted(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_tripped_price(env: &Env, asset: &Asset) -> Option<PriceData> {
+    let key = BridgeOracleDataKey::Tripped(asset.clone());
+    let result = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, PriceData>(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+pub fn set_tripped_price(env: &Env, asset: &Asset, tripped: &PriceData) {
+    let key = BridgeOracleDataKey::Tripped(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, PriceData>(&key, tripped);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_tripped_price(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Tripped(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_paused(env: &Env, asset: &Asset) -> bool {
+    let key = BridgeOracleDataKey::Paused(asset.clone());
+    if let Some(result) = env
+        .storage()
+        .persistent()
+        .get::<BridgeOracleDataKey, bool>(&key)
+    {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        result
+    } else {
+        false
+    }
+}
+
+pub fn set_paused(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Paused(asset.clone());
+    env.storage()
+        .persistent()
+        .set::<BridgeOracleDataKey, bool>(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+pub fn del_paused(env: &Env, asset: &Asset) {
+    let key = BridgeOracleDataKey::Paused(asset.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Remove and return the price observations a bridge oracle of storage version 1 kept for an
+/// asset, oldest first
+pub fn take_legacy_observations(env: &Env, asset: &Asset) -> Option<Vec<PriceData>> {
+    take_legacy(env, &LegacyDataKey::Observations(asset.clone()))
+}
+
+/// Remove and return the feeds a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_sources(env: &Env, asset: &Asset) -> Option<LegacySourceConfig> {
+    take_legacy(env, &LegacyDataKey::Sources(asset.clone()))
+}
+
+/// Remove and return the maximum price age a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_max_age(env: &Env, asset: &Asset) -> Option<u64> {
+    take_legacy(env, &LegacyDataKey::MaxAge(asset.clone()))
+}
+
+/// Remove and return the circuit breaker a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_breaker(env: &Env, asset: &Asset) -> Option<LegacyBreaker> {
+    take_legacy(env, &LegacyDataKey::Breaker(asset.clone()))
+}
+
+/// Remove and return the fixed price a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_fixed_price(env: &Env, asset: &Asset) -> Option<i128> {
+    take_legacy(env, &LegacyDataKey::FixedPrice(asset.clone()))
+}
+
+/// Remove and return the fallback feed a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_fallback(env: &Env, asset: &Asset) -> Option<LegacyFallback> {
+    take_legacy(env, &LegacyDataKey::Fallback(asset.clone()))
+}
+
+/// Remove and return the cross rate a bridge oracle of storage version 1 kept for an asset
+pub fn take_legacy_cross_rate(env: &Env, asset: &Asset) -> Option<LegacyCrossRate> {
+    take_legacy(env, &LegacyDataKey::CrossRate(asset.clone()))
+}
+
+/// Remove and return a persistent entry a bridge oracle of storage version 1 kept
+fn take_legacy<V: TryFromVal<Env, Val>>(env: &Env, key: &LegacyDataKey) -> Option<V> {
+    let result = env.storage().persistent().get(key);
+    env.storage().persistent().remove(key);
+    result
+}
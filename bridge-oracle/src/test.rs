@@ -0,0 +1,1046 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{BridgeOracleClient, BridgeOracleContract};
+use crate::errors::BridgeOracleError;
+use crate::dependencies::reflector;
+use crate::storage::{
+    AssetConfig, LegacyBreaker, LegacyCrossRate, LegacyDataKey, LegacyFallback, LegacySource,
+    LegacySourceConfig, PriceSource, Source, SourceKind,
+};
+use sep_40_oracle::PriceData;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient, MockPriceOracleWASM};
+use soroban_sdk::testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec};
+
+fn create_mock_oracle<'a>(env: &Env, assets: Vec<Asset>) -> MockPriceOracleClient<'a> {
+    let oracle_id = env.register_contract_wasm(None, MockPriceOracleWASM);
+    let oracle_client = MockPriceOracleClient::new(env, &oracle_id);
+    oracle_client.set_data(
+        &Address::generate(env),
+        &Asset::Other(Symbol::new(env, "USD")),
+        &assets,
+        &7,
+        &300,
+    );
+    oracle_client
+}
+
+#[contract]
+pub struct MockReflector;
+
+#[contractimpl]
+impl MockReflector {
+    /// Record the prices of an asset for each round, newest first. A missing latest price
+    /// means the asset was not priced in the latest round.
+    pub fn set_prices(
+        env: Env,
+        asset: reflector::Asset,
+        prices: Vec<reflector::PriceData>,
+        in_latest: bool,
+    ) {
+        env.storage().instance().set(&asset, &(prices, in_latest));
+    }
+
+    pub fn lastprice(env: Env, asset: reflector::Asset) -> Option<reflector::PriceData> {
+        let (prices, in_latest): (Vec<reflector::PriceData>, bool) =
+            env.storage().instance().get(&asset)?;
+        if in_latest {
+            prices.first()
+        } else {
+            None
+        }
+    }
+
+    pub fn prices(
+        env: Env,
+        asset: reflector::Asset,
+        records: u32,
+    ) -> Option<Vec<reflector::PriceData>> {
+        let (prices, _): (Vec<reflector::PriceData>, bool) = env.storage().instance().get(&asset)?;
+        Some(prices.slice(0..records.min(prices.len())))
+    }
+}
+
+fn create_bridge_oracle<'a>(
+    env: &Env,
+) -> (BridgeOracleClient<'a>, MockPriceOracleClient<'a>, Address, Address) {
+    let usdc = Address::generate(env);
+    let xlm = Address::generate(env);
+    let ousd = Address::generate(env);
+    let oracle_client = create_mock_oracle(
+        env,
+        vec![env, Asset::Stellar(usdc.clone()), Asset::Stellar(xlm.clone())],
+    );
+
+    let bridge_id = env.register_contract(None, BridgeOracleContract);
+    let bridge_client = BridgeOracleClient::new(env, &bridge_id);
+    bridge_client.initialize(
+        &Address::generate(env),
+        &Address::generate(env),
+        &ousd,
+        &usdc,
+        &oracle_client.address,
+    );
+    (bridge_client, oracle_client, ousd, xlm)
+}
+
+#[test]
+fn test_lastprice_bridges_asset() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    oracle_client.set_price_stable(&vec![&env, 1_0000000, 0_1000000]);
+
+    assert_eq!(bridge_client.decimals(), 7);
+    let price = bridge_client.lastprice(&Asset::Stellar(ousd)).unwrap();
+    assert_eq!(price.price, 1_0000000);
+    assert_eq!(price.timestamp, 1000);
+    let price = bridge_client.lastprice(&Asset::Stellar(xlm)).unwrap();
+    assert_eq!(price.price, 0_1000000);
+}
+
+#[test]
+fn test_twap() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm);
+    assert_eq!(bridge_client.twap(&asset, &3), None);
+    assert_eq!(bridge_client.prices(&asset, &3), None);
+
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &1000);
+    bridge_client.update(&asset);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                bridge_client.address.clone(),
+                (Symbol::new(&env, "price_update"), asset.clone()).into_val(&env),
+                (0_1000000_i128, PriceSource::Default, 1000_u64).into_val(&env)
+            )
+        ]
+    );
+    // an unchanged upstream price is not recorded twice
+    let event_count = events.len();
+    bridge_client.update(&asset);
+    assert_eq!(env.events().all().len(), event_count);
+
+    env.ledger().with_mut(|li| li.timestamp = 1300);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1600000], &1300);
+    bridge_client.update(&asset);
+
+    env.ledger().with_mut(|li| li.timestamp = 1600);
+    // 0.10 for 300s and 0.16 for 300s
+    let twap = bridge_client.twap(&asset, &3).unwrap();
+    assert_eq!(twap.price, 0_1300000);
+    assert_eq!(twap.timestamp, 1300);
+    // only the latest observation
+    let twap = bridge_client.twap(&asset, &1).unwrap();
+    assert_eq!(twap.price, 0_1600000);
+    assert_eq!(twap.timestamp, 1300);
+
+    let prices = bridge_client.prices(&asset, &3).unwrap();
+    assert_eq!(prices.len(), 2);
+    assert_eq!(prices.get_unchecked(0).price, 0_1600000);
+    assert_eq!(prices.get_unchecked(0).timestamp, 1300);
+    assert_eq!(prices.get_unchecked(1).price, 0_1000000);
+    assert_eq!(prices.get_unchecked(1).timestamp, 1000);
+    let prices = bridge_client.prices(&asset, &1).unwrap();
+    assert_eq!(prices.len(), 1);
+    assert_eq!(prices.get_unchecked(0).price, 0_1600000);
+}
+
+#[test]
+fn test_median_of_sources() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, _, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm.clone());
+
+    let oracle_0 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    let oracle_1 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    let oracle_2 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    oracle_0.set_price(&vec![&env, 0_1000000], &900);
+    oracle_1.set_price(&vec![&env, 0_1200000], &950);
+    oracle_2.set_price(&vec![&env, 0_5000000], &1000);
+    let sources = vec![
+        &env,
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_0.address.clone(),
+            asset: asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_1.address.clone(),
+            asset: asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_2.address.clone(),
+            asset: asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+    bridge_client.set_sources(&asset, &sources, &2);
+
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_1200000);
+    assert_eq!(price.timestamp, 900);
+
+    // an oracle without a price for the asset is skipped
+    let oracle_3 = create_mock_oracle(&env, vec![&env, Asset::Stellar(Address::generate(&env))]);
+    let sources = vec![
+        &env,
+        sources.get_unchecked(0),
+        sources.get_unchecked(1),
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_3.address.clone(),
+            asset: asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+    bridge_client.set_sources(&asset, &sources, &2);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1100000);
+
+    bridge_client.set_sources(&asset, &sources, &3);
+    let result = bridge_client.try_lastprice(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::InsufficientSources.into())));
+
+    // removing the sources falls back to the default oracle
+    bridge_client.set_sources(&asset, &vec![&env], &0);
+    assert_eq!(bridge_client.lastprice(&asset), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_set_sources_requires_admin() {
+    let env = Env::default();
+    let (bridge_client, oracle_client, ousd, _) = create_bridge_oracle(&env);
+    let not_admin = Address::generate(&env);
+    let sources = vec![
+        &env,
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_client.address.clone(),
+            asset: Asset::Stellar(ousd.clone()),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+
+    bridge_client
+        .mock_auths(&[MockAuth {
+            address: &not_admin,
+            invoke: &MockAuthInvoke {
+                contract: &bridge_client.address,
+                fn_name: "set_sources",
+                args: (Asset::Stellar(ousd.clone()), sources.clone(), 1_u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_sources(&Asset::Stellar(ousd), &sources, &1);
+}
+
+#[test]
+fn test_stale_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(ousd);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &1000);
+    bridge_client.set_max_age(&asset, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1600);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 1_0000000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1601);
+    let result = bridge_client.try_lastprice(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::StalePrice.into())));
+
+    // stale feeds are skipped when taking the median
+    let xlm_asset = Asset::Stellar(xlm.clone());
+    let oracle_0 = create_mock_oracle(&env, vec![&env, xlm_asset.clone()]);
+    let oracle_1 = create_mock_oracle(&env, vec![&env, xlm_asset.clone()]);
+    oracle_0.set_price(&vec![&env, 0_1000000], &1000);
+    oracle_1.set_price(&vec![&env, 0_1200000], &1500);
+    let sources = vec![
+        &env,
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_0.address.clone(),
+            asset: xlm_asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_1.address.clone(),
+            asset: xlm_asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+    bridge_client.set_sources(&xlm_asset, &sources, &1);
+    bridge_client.set_max_age(&xlm_asset, &300);
+    assert_eq!(bridge_client.lastprice(&xlm_asset).unwrap().price, 0_1200000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1900);
+    let result = bridge_client.try_lastprice(&xlm_asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::StalePrice.into())));
+
+    bridge_client.set_max_age(&xlm_asset, &0);
+    assert_eq!(bridge_client.lastprice(&xlm_asset).unwrap().price, 0_1100000);
+}
+
+#[test]
+fn test_fallback() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm.clone());
+    let fallback_oracle = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    bridge_client.set_fallback(
+        &asset,
+        &Source {
+            kind: SourceKind::Sep40,
+            oracle: fallback_oracle.address.clone(),
+            asset: asset.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        &0_0500000,
+    );
+    bridge_client.set_max_age(&asset, &600);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1040000], &1000);
+    fallback_oracle.set_price(&vec![&env, 0_1000000], &1000);
+
+    // within the deviation bound the primary price is used
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1040000);
+
+    // beyond the deviation bound the fallback price is used
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1060000], &1000);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1000000);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                bridge_client.address.clone(),
+                (Symbol::new(&env, "fallback"), asset.clone()).into_val(&env),
+                PriceData {
+                    price: 0_1000000,
+                    timestamp: 1000,
+                }
+                .into_val(&env)
+            )
+        ]
+    );
+
+    // a stale primary price switches to the fallback
+    env.ledger().with_mut(|li| li.timestamp = 1700);
+    fallback_oracle.set_price(&vec![&env, 0_2000000], &1700);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_2000000);
+
+    bridge_client.remove_fallback(&asset);
+    let result = bridge_client.try_lastprice(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::StalePrice.into())));
+}
+
+#[test]
+fn test_source_decimals_are_normalized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, _, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm.clone());
+
+    let oracle_0 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    let oracle_1 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    oracle_0.set_price(&vec![&env, 0_10000000000000], &1000);
+    oracle_1.set_price(&vec![&env, 0_12], &1000);
+    let sources = vec![
+        &env,
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_0.address.clone(),
+            asset: asset.clone(),
+            decimals: 14,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_1.address.clone(),
+            asset: asset.clone(),
+            decimals: 2,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+    bridge_client.set_sources(&asset, &sources, &2);
+
+    assert_eq!(bridge_client.decimals(), 7);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1100000);
+}
+
+#[test]
+fn test_cross_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, _, _) = create_bridge_oracle(&env);
+    let oeur = Asset::Stellar(Address::generate(&env));
+    let eur = Asset::Other(Symbol::new(&env, "EUR"));
+    let usdc = oracle_client.assets().get_unchecked(0);
+
+    // EUR priced in USDC by a fiat feed with 4 decimals
+    let fiat_oracle = create_mock_oracle(&env, vec![&env, eur.clone()]);
+    fiat_oracle.set_price(&vec![&env, 1_0800], &900);
+    oracle_client.set_price(&vec![&env, 0_9900000, 0_1000000], &1000);
+    bridge_client.set_cross_rate(
+        &oeur,
+        &Source {
+            kind: SourceKind::Sep40,
+            oracle: fiat_oracle.address.clone(),
+            asset: eur.clone(),
+            decimals: 4,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+        &Source {
+            kind: SourceKind::Sep40,
+            oracle: oracle_client.address.clone(),
+            asset: usdc.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    );
+
+    let price = bridge_client.lastprice(&oeur).unwrap();
+    assert_eq!(price.price, 1_0692000);
+    assert_eq!(price.timestamp, 900);
+
+    bridge_client.set_max_age(&oeur, &50);
+    let result = bridge_client.try_lastprice(&oeur);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::StalePrice.into())));
+
+    bridge_client.remove_cross_rate(&oeur);
+    assert_eq!(bridge_client.lastprice(&oeur), None);
+}
+
+#[test]
+fn test_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (bridge_client, _, _, _) = create_bridge_oracle(&env);
+    let admin = bridge_client.admin();
+    let new_admin = Address::generate(&env);
+
+    let result = bridge_client.try_accept_admin();
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::NoPendingAdmin.into())));
+
+    bridge_client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(bridge_client.admin(), admin);
+
+    bridge_client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(bridge_client.admin(), new_admin);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                bridge_client.address.clone(),
+                (Symbol::new(&env, "accept_admin"), admin.clone()).into_val(&env),
+                new_admin.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_deviation_breaker() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm);
+    let guardian = Address::generate(&env);
+    bridge_client.set_guardian(&guardian);
+    bridge_client.set_breaker(&asset, &0_1000000, &3600);
+
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &1000);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1000000);
+
+    // a move within the bound is accepted
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1080000], &1300);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1080000);
+
+    // a move beyond the bound within a heartbeat trips the breaker
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_0500000], &1600);
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_1080000);
+    assert_eq!(price.timestamp, 1300);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                bridge_client.address.clone(),
+                (Symbol::new(&env, "deviation_tripped"), asset.clone()).into_val(&env),
+                (
+                    PriceData {
+                        price: 0_1080000,
+                        timestamp: 1300,
+                    },
+                    PriceData {
+                        price: 0_0500000,
+                        timestamp: 1600,
+                    }
+                )
+                    .into_val(&env)
+            )
+        ]
+    );
+
+    // the old price is held until the guardian accepts the new one
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_0510000], &1900);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1080000);
+    bridge_client.ack_price(&asset);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_0510000);
+
+    let result = bridge_client.try_ack_price(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::NoTrippedPrice.into())));
+
+    // after a heartbeat any price is accepted
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &5501);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_1000000);
+
+    // a held price is only returned until it is older than the maximum age
+    bridge_client.set_max_age(&asset, &600);
+    env.ledger().with_mut(|li| li.timestamp = 6000);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_0500000], &5600);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().timestamp, 5501);
+    env.ledger().with_mut(|li| li.timestamp = 6102);
+    assert_eq!(bridge_client.lastprice(&asset), None);
+}
+
+#[test]
+fn test_reflector_source() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, _, _, _) = create_bridge_oracle(&env);
+    let btc = Symbol::new(&env, "BTC");
+    let asset = Asset::Other(btc.clone());
+
+    let reflector_id = env.register_contract(None, MockReflector);
+    let reflector_client = MockReflectorClient::new(&env, &reflector_id);
+    reflector_client.set_prices(
+        &reflector::Asset::Other(btc.clone()),
+        &vec![
+            &env,
+            reflector::PriceData {
+                price: 60000_00000000000000,
+                timestamp: 900,
+            },
+            reflector::PriceData {
+                price: 59000_00000000000000,
+                timestamp: 600,
+            },
+        ],
+        &true,
+    );
+    let sources = vec![
+        &env,
+        Source {
+            kind: SourceKind::Reflector,
+            oracle: reflector_id.clone(),
+            asset: asset.clone(),
+            decimals: 14,
+            weight: 1,
+            staleness_penalty: 0,
+        },
+    ];
+    bridge_client.set_sources(&asset, &sources, &1);
+
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 60000_0000000);
+    assert_eq!(price.timestamp, 900);
+
+    // an asset missing from the latest round uses its most recent round
+    reflector_client.set_prices(
+        &reflector::Asset::Other(btc.clone()),
+        &vec![
+            &env,
+            reflector::PriceData {
+                price: 59000_00000000000000,
+                timestamp: 600,
+            },
+        ],
+        &false,
+    );
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 59000_0000000);
+    assert_eq!(price.timestamp, 600);
+}
+
+#[test]
+fn test_fixed_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, _) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(ousd);
+    oracle_client.set_price(&vec![&env, 0_9900000, 0_1000000], &900);
+
+    bridge_client.set_fixed_price(&asset, &1_0000000);
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 1_0000000);
+    assert_eq!(price.timestamp, 1000);
+
+    bridge_client.remove_fixed_price(&asset);
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_9900000);
+    assert_eq!(price.timestamp, 900);
+}
+
+#[test]
+fn test_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    let guardian = Address::generate(&env);
+    bridge_client.set_guardian(&guardian);
+    let asset = Asset::Stellar(ousd);
+    let xlm_asset = Asset::Stellar(xlm);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &1000);
+
+    bridge_client.pause(&asset);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert!(bridge_client.is_paused(&asset));
+    let result = bridge_client.try_lastprice(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::AssetPaused.into())));
+    let result = bridge_client.try_update(&asset);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::AssetPaused.into())));
+    let result = bridge_client.try_twap(&asset, &1);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::AssetPaused.into())));
+
+    // other assets keep working
+    assert!(!bridge_client.is_paused(&xlm_asset));
+    assert_eq!(bridge_client.lastprice(&xlm_asset).unwrap().price, 0_1000000);
+
+    bridge_client.unpause(&asset);
+    assert_eq!(env.auths()[0].0, guardian);
+    assert!(!bridge_client.is_paused(&asset));
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 1_0000000);
+}
+
+#[test]
+fn test_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(ousd);
+    let xlm_asset = Asset::Stellar(xlm);
+    assert_eq!(bridge_client.version(), 2);
+
+    // an up to date contract is left untouched
+    bridge_client.migrate(&vec![&env]);
+    assert_eq!(env.auths()[0].0, bridge_client.admin());
+    assert_eq!(bridge_client.version(), 2);
+
+    // version 1 kept each setting of an asset under its own key
+    env.as_contract(&bridge_client.address, || {
+        let legacy_source = LegacySource {
+            kind: SourceKind::Sep40,
+            oracle: oracle_client.address.clone(),
+            asset: xlm_asset.clone(),
+            decimals: 7,
+        };
+        let persistent = env.storage().persistent();
+        persistent.set(&LegacyDataKey::FixedPrice(asset.clone()), &1_0000000_i128);
+        persistent.set(
+            &LegacyDataKey::Sources(xlm_asset.clone()),
+            &LegacySourceConfig {
+                sources: vec![&env, legacy_source.clone()],
+                min_sources: 1,
+            },
+        );
+        persistent.set(
+            &LegacyDataKey::Fallback(xlm_asset.clone()),
+            &LegacyFallback {
+                source: legacy_source.clone(),
+                max_deviation: 0_0500000,
+            },
+        );
+        persistent.set(
+            &LegacyDataKey::CrossRate(asset.clone()),
+            &LegacyCrossRate {
+                base: legacy_source.clone(),
+                quote: legacy_source,
+            },
+        );
+        persistent.set(&LegacyDataKey::MaxAge(xlm_asset.clone()), &600_u64);
+        persistent.set(
+            &LegacyDataKey::Breaker(xlm_asset.clone()),
+            &LegacyBreaker {
+                max_deviation: 0_1000000,
+                heartbeat: 3600,
+            },
+        );
+        let mut observations = Vec::new(&env);
+        for i in 0..30 {
+            observations.push_back(PriceData {
+                price: 0_1000000 + i,
+                timestamp: 100 + i as u64,
+            });
+        }
+        persistent.set(&LegacyDataKey::Observations(xlm_asset.clone()), &observations);
+        crate::storage::set_version(&env, 1);
+    });
+
+    bridge_client.migrate(&vec![&env, asset.clone(), xlm_asset.clone()]);
+    assert_eq!(bridge_client.version(), 2);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                bridge_client.address.clone(),
+                (Symbol::new(&env, "migrate"),).into_val(&env),
+                (1_u32, 2_u32).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(bridge_client.assets(), vec![&env, asset.clone(), xlm_asset.clone()]);
+    let config = bridge_client.get_asset_config(&asset).unwrap();
+    assert_eq!(config.sources.len(), 0);
+    assert_eq!(config.fixed_price, 1_0000000);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 1_0000000);
+    let config = bridge_client.get_asset_config(&xlm_asset).unwrap();
+    let source = config.sources.get(0).unwrap();
+    assert_eq!(config.sources.len(), 1);
+    assert_eq!(source.oracle, oracle_client.address);
+    assert_eq!(source.asset, xlm_asset);
+    assert_eq!(source.decimals, 7);
+    assert_eq!(source.weight, 1);
+    assert_eq!(source.staleness_penalty, 0);
+    assert_eq!(config.min_sources, 1);
+    assert_eq!(config.max_age, 600);
+    assert_eq!(config.max_deviation, 0_1000000);
+    assert_eq!(config.heartbeat, 3600);
+    assert_eq!(config.fixed_price, 0);
+    // the most recent observations fill the ring buffer
+    let history = bridge_client.prices(&xlm_asset, &30).unwrap();
+    assert_eq!(history.len(), 24);
+    assert_eq!(history.first().unwrap().price, 0_1000029);
+    assert_eq!(history.first().unwrap().timestamp, 129);
+    assert_eq!(history.last().unwrap().price, 0_1000006);
+    assert_eq!(history.last().unwrap().timestamp, 106);
+    assert_eq!(bridge_client.prices(&asset, &30), None);
+    // fallback feeds and cross rates get the same default weights as the feeds of an asset
+    env.as_contract(&bridge_client.address, || {
+        let fallback = crate::storage::get_fallback(&env, &xlm_asset).unwrap();
+        assert_eq!(fallback.source.asset, xlm_asset);
+        assert_eq!(fallback.source.weight, 1);
+        assert_eq!(fallback.source.staleness_penalty, 0);
+        assert_eq!(fallback.max_deviation, 0_0500000);
+        let cross_rate = crate::storage::get_cross_rate(&env, &asset).unwrap();
+        assert_eq!(cross_rate.base.oracle, oracle_client.address);
+        assert_eq!(cross_rate.base.weight, 1);
+        assert_eq!(cross_rate.quote.weight, 1);
+        assert_eq!(cross_rate.quote.staleness_penalty, 0);
+    });
+    env.as_contract(&bridge_client.address, || {
+        let persistent = env.storage().persistent();
+        assert!(!persistent.has(&LegacyDataKey::Observations(xlm_asset.clone())));
+        assert!(!persistent.has(&LegacyDataKey::FixedPrice(asset.clone())));
+        assert!(!persistent.has(&LegacyDataKey::Sources(xlm_asset.clone())));
+        assert!(!persistent.has(&LegacyDataKey::MaxAge(xlm_asset.clone())));
+        assert!(!persistent.has(&LegacyDataKey::Breaker(xlm_asset.clone())));
+    });
+
+    // a contract initialized before the storage version was tracked has no admin to migrate it
+    env.as_contract(&bridge_client.address, || {
+        env.storage()
+            .instance()
+            .remove(&Symbol::new(&env, "Version"));
+    });
+    assert_eq!(bridge_client.version(), 0);
+    let result = bridge_client.try_migrate(&vec![&env]);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::InvalidStorageVersion.into())));
+
+    // storage written by a newer version can't be migrated by this one
+    env.as_contract(&bridge_client.address, || {
+        crate::storage::set_version(&env, 3);
+    });
+    let result = bridge_client.try_migrate(&vec![&env]);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::InvalidStorageVersion.into())));
+}
+
+#[test]
+fn test_asset_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(ousd);
+    let xlm_asset = Asset::Stellar(xlm);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000], &1000);
+    assert!(bridge_client.get_asset_config(&asset).is_none());
+    assert_eq!(bridge_client.assets().len(), 0);
+
+    let config = AssetConfig {
+        sources: vec![&env],
+        min_sources: 0,
+        max_age: 600,
+        max_deviation: 0_1000000,
+        heartbeat: 3600,
+        fixed_price: 0_9900000,
+    };
+    bridge_client.set_asset_config(&asset, &config);
+    assert_eq!(env.auths()[0].0, bridge_client.admin());
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 0_9900000);
+
+    // the targeted setters update the same settings
+    bridge_client.set_max_age(&xlm_asset, &300);
+    bridge_client.remove_fixed_price(&asset);
+    let config = bridge_client.get_asset_config(&asset).unwrap();
+    assert_eq!(config.max_age, 600);
+    assert_eq!(config.max_deviation, 0_1000000);
+    assert_eq!(config.heartbeat, 3600);
+    assert_eq!(config.fixed_price, 0);
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 1_0000000);
+    assert_eq!(bridge_client.assets(), vec![&env, asset.clone(), xlm_asset.clone()]);
+
+    let invalid = AssetConfig {
+        sources: vec![
+            &env,
+            Source {
+                kind: SourceKind::Sep40,
+                oracle: oracle_client.address.clone(),
+                asset: xlm_asset.clone(),
+                decimals: 7,
+                weight: 1,
+                staleness_penalty: 0,
+            },
+        ],
+        min_sources: 2,
+        ..config
+    };
+    let result = bridge_client.try_set_asset_config(&asset, &invalid);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::InvalidSourceConfig.into())));
+
+    bridge_client.remove_asset_config(&asset);
+    assert!(bridge_client.get_asset_config(&asset).is_none());
+    assert_eq!(bridge_client.assets(), vec![&env, xlm_asset.clone()]);
+
+    // an asset priced only through a cross rate is listed, and removing it clears every setting
+    let eurc = Asset::Stellar(Address::generate(&env));
+    let source = Source {
+        kind: SourceKind::Sep40,
+        oracle: oracle_client.address.clone(),
+        asset: xlm_asset.clone(),
+        decimals: 7,
+        weight: 1,
+        staleness_penalty: 0,
+    };
+    bridge_client.set_cross_rate(&eurc, &source, &source);
+    bridge_client.set_fallback(&xlm_asset, &source, &0_0100000);
+    assert_eq!(bridge_client.assets(), vec![&env, xlm_asset.clone(), eurc.clone()]);
+    assert_eq!(bridge_client.lastprice(&eurc).unwrap().price, 0_0100000);
+    bridge_client.set_guardian(&Address::generate(&env));
+    bridge_client.pause(&eurc);
+    bridge_client.remove_asset_config(&eurc);
+    bridge_client.remove_asset_config(&xlm_asset);
+    assert_eq!(bridge_client.assets(), vec![&env]);
+    assert_eq!(bridge_client.lastprice(&eurc), None);
+    assert_eq!(bridge_client.lastprice(&xlm_asset).unwrap().price, 0_1000000);
+}
+
+#[test]
+fn test_price_history() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm);
+
+    for i in 0..30_u64 {
+        let timestamp = 1000 + i * 300;
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+        oracle_client.set_price(&vec![&env, 1_0000000, 0_1000000 + i as i128], &timestamp);
+        bridge_client.update(&asset);
+    }
+
+    // only the most recent 24 observations are kept
+    let prices = bridge_client.prices(&asset, &100).unwrap();
+    assert_eq!(prices.len(), 24);
+    assert_eq!(prices.get_unchecked(0).price, 0_1000029);
+    assert_eq!(prices.get_unchecked(0).timestamp, 1000 + 29 * 300);
+    assert_eq!(prices.get_unchecked(23).price, 0_1000006);
+    assert_eq!(prices.get_unchecked(23).timestamp, 1000 + 6 * 300);
+
+    let prices = bridge_client.prices(&asset, &2).unwrap();
+    assert_eq!(prices.len(), 2);
+    assert_eq!(prices.get_unchecked(1).price, 0_1000028);
+}
+
+#[test]
+fn test_weighted_median() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, _, _, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(xlm.clone());
+
+    let oracle_0 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    let oracle_1 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    let oracle_2 = create_mock_oracle(&env, vec![&env, asset.clone()]);
+    oracle_0.set_price(&vec![&env, 0_1000000], &1000);
+    oracle_1.set_price(&vec![&env, 0_1200000], &1000);
+    oracle_2.set_price(&vec![&env, 0_5000000], &1000);
+    let source = |oracle: &Address, weight: u32, staleness_penalty: u32| Source {
+        kind: SourceKind::Sep40,
+        oracle: oracle.clone(),
+        asset: asset.clone(),
+        decimals: 7,
+        weight,
+        staleness_penalty,
+    };
+    let sources = vec![
+        &env,
+        source(&oracle_0.address, 1, 0),
+        source(&oracle_1.address, 1, 0),
+        source(&oracle_2.address, 3, 1),
+    ];
+    bridge_client.set_sources(&asset, &sources, &2);
+
+    // the third feed holds more than half of the total weight
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_5000000);
+
+    // after a second the third feed loses one unit of weight and holds exactly half
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_3100000);
+    let breakdown = bridge_client.price_breakdown(&asset);
+    assert_eq!(breakdown.len(), 3);
+    assert_eq!(breakdown.get_unchecked(0).price, 0_1000000);
+    assert_eq!(breakdown.get_unchecked(0).weight, 1);
+    assert_eq!(breakdown.get_unchecked(2).price, 0_5000000);
+    assert_eq!(breakdown.get_unchecked(2).timestamp, 1000);
+    assert_eq!(breakdown.get_unchecked(2).weight, 2);
+
+    // once its weight is used up the third feed is skipped
+    env.ledger().with_mut(|li| li.timestamp = 1003);
+    let price = bridge_client.lastprice(&asset).unwrap();
+    assert_eq!(price.price, 0_1100000);
+    assert_eq!(bridge_client.price_breakdown(&asset).get_unchecked(2).weight, 0);
+
+    let sources = vec![&env, source(&oracle_0.address, 0, 0)];
+    let result = bridge_client.try_set_sources(&asset, &sources, &1);
+    assert_eq!(result.err(), Some(Ok(BridgeOracleError::InvalidSourceConfig.into())));
+}
+
+#[test]
+fn test_lastprice_in() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let (bridge_client, oracle_client, ousd, xlm) = create_bridge_oracle(&env);
+    let asset = Asset::Stellar(ousd);
+    let xlm_asset = Asset::Stellar(xlm);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1250000], &900);
+
+    // the same deployment prices assets in USD and in XLM
+    assert_eq!(bridge_client.lastprice(&asset).unwrap().price, 1_0000000);
+    let price = bridge_client.lastprice_in(&asset, &xlm_asset).unwrap();
+    assert_eq!(price.price, 8_0000000);
+    assert_eq!(price.timestamp, 900);
+    let price = bridge_client.lastprice_in(&xlm_asset, &xlm_asset).unwrap();
+    assert_eq!(price.price, 1_0000000);
+
+    // no price for an unknown quote asset
+    let unknown = Asset::Stellar(Address::generate(&env));
+    assert_eq!(bridge_client.lastprice_in(&asset, &unknown), None);
+}
+
+#[test]
+fn test_base_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd_id = Address::generate(&env);
+    let usdc_id = Address::generate(&env);
+    let ousd = Asset::Stellar(ousd_id.clone());
+    let usdc = Asset::Stellar(usdc_id.clone());
+    let xlm = Asset::Stellar(Address::generate(&env));
+    let eurc = Asset::Stellar(Address::generate(&env));
+    let oracle_client = create_mock_oracle(&env, vec![&env, usdc, xlm.clone(), eurc.clone()]);
+    oracle_client.set_price(&vec![&env, 1_0000000, 0_1250000, 1_1000000], &1000);
+    let bridge_id = env.register_contract(None, BridgeOracleContract);
+    let bridge_client = BridgeOracleClient::new(&env, &bridge_id);
+    bridge_client.initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &ousd_id,
+        &usdc_id,
+        &oracle_client.address,
+    );
+
+    // one deployment serves a pool priced in USD and a pool priced in XLM, each reading its own
+    // assets with lastprice
+    bridge_client.set_base_asset(&eurc, &xlm);
+    assert_eq!(env.auths()[0].0, bridge_client.admin());
+    assert_eq!(bridge_client.base_asset(&eurc), Some(xlm.clone()));
+    assert_eq!(bridge_client.base_asset(&ousd), None);
+    assert_eq!(bridge_client.lastprice(&ousd).unwrap().price, 1_0000000);
+    assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 0_1250000);
+    let price = bridge_client.lastprice(&eurc).unwrap();
+    assert_eq!(price.price, 8_8000000);
+    assert_eq!(price.timestamp, 1000);
+    assert_eq!(bridge_client.assets(), vec![&env, eurc.clone()]);
+
+    // the asset's own settings still apply before the conversion
+    bridge_client.set_fixed_price(&eurc, &1_2000000);
+    assert_eq!(bridge_client.lastprice(&eurc).unwrap().price, 9_6000000);
+    bridge_client.remove_base_asset(&eurc);
+    assert_eq!(bridge_client.base_asset(&eurc), None);
+    assert_eq!(bridge_client.lastprice(&eurc).unwrap().price, 1_2000000);
+
+    // removing the asset's settings removes its base
+    bridge_client.set_base_asset(&eurc, &xlm);
+    bridge_client.remove_asset_config(&eurc);
+    assert_eq!(bridge_client.base_asset(&eurc), None);
+    assert_eq!(bridge_client.lastprice(&eurc).unwrap().price, 1_1000000);
+}
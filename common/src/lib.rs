@@ -0,0 +1,10 @@
+#![no_std]
+//! Clients and types of the contracts Orbit calls, shared by the Orbit contracts, the mocks
+//! standing in for those contracts and the test suites, so they stay in sync, along with the
+//! checked math the contracts share.
+
+pub mod math;
+#[allow(clippy::too_many_arguments)]
+pub mod pool;
+pub mod soroswap;
+mod test;
@@ -0,0 +1,85 @@
+use soroban_sdk::{contracterror, panic_with_error, Env};
+
+/// 100% in basis points
+pub const MAX_BPS: i128 = 10000;
+/// 100% as a fixed point number with 7 decimals
+pub const SCALAR_7: i128 = 1_0000000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the shared math. The code matches the common error the built-in contracts
+/// report for an overflow.
+pub enum MathError {
+    OverflowError = 12,
+}
+
+/// Multiply `x` by `y` and divide by `denominator`, rounding down
+///
+/// ### Panics
+/// If the product overflows or `denominator` is zero
+pub fn mul_div_floor(e: &Env, x: i128, y: i128, denominator: i128) -> i128 {
+    let (quotient, remainder) = checked_mul_div(e, x, y, denominator);
+    if remainder != 0 && (remainder < 0) != (denominator < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// Multiply `x` by `y` and divide by `denominator`, rounding up
+///
+/// ### Panics
+/// If the product overflows or `denominator` is zero
+pub fn mul_div_ceil(e: &Env, x: i128, y: i128, denominator: i128) -> i128 {
+    let (quotient, remainder) = checked_mul_div(e, x, y, denominator);
+    if remainder != 0 && (remainder < 0) == (denominator < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Take a share of an amount given in basis points, rounding down, as for a fee
+///
+/// ### Arguments
+/// * `amount` - The amount
+/// * `bps` - The share, in basis points
+///
+/// ### Panics
+/// If the product overflows
+pub fn apply_bps(e: &Env, amount: i128, bps: u32) -> i128 {
+    mul_div_floor(e, amount, bps as i128, MAX_BPS)
+}
+
+/// Take a percentage of an amount, rounding down
+///
+/// ### Arguments
+/// * `amount` - The amount
+/// * `pct` - The percentage, with 7 decimals, where 1_0000000 is 100%
+///
+/// ### Panics
+/// If the product overflows
+pub fn apply_pct(e: &Env, amount: i128, pct: i128) -> i128 {
+    mul_div_floor(e, amount, pct, SCALAR_7)
+}
+
+/// Calculate the percentage one amount is of another, with 7 decimals, rounding down
+///
+/// ### Arguments
+/// * `part` - The amount taken as a percentage
+/// * `whole` - The amount that is 100%
+///
+/// ### Panics
+/// If the product overflows or `whole` is zero
+pub fn pct_of(e: &Env, part: i128, whole: i128) -> i128 {
+    mul_div_floor(e, part, SCALAR_7, whole)
+}
+
+/// Multiply `x` by `y` and divide by `denominator`, returning the quotient rounded toward zero
+/// and the remainder
+fn checked_mul_div(e: &Env, x: i128, y: i128, denominator: i128) -> (i128, i128) {
+    x.checked_mul(y)
+        .and_then(|product| Some((product.checked_div(denominator)?, product % denominator)))
+        .unwrap_or_else(|| panic_with_error!(e, MathError::OverflowError))
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::contractimport;
+
+contractimport!(file = "../wasm/pool.wasm");
+
+/// The type of a `Request` submitted to the Blend pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum RequestType {
+    Supply = 0,
+    Withdraw = 1,
+    SupplyCollateral = 2,
+    WithdrawCollateral = 3,
+    Borrow = 4,
+    Repay = 5,
+    FillUserLiquidationAuction = 6,
+    FillBadDebtAuction = 7,
+    FillInterestAuction = 8,
+    DeleteLiquidationAuction = 9,
+}
+
+impl RequestType {
+    /// Get the type of a request from its `request_type`, if it is one the pool accepts
+    pub fn from_u32(request_type: u32) -> Option<Self> {
+        match request_type {
+            0 => Some(RequestType::Supply),
+            1 => Some(RequestType::Withdraw),
+            2 => Some(RequestType::SupplyCollateral),
+            3 => Some(RequestType::WithdrawCollateral),
+            4 => Some(RequestType::Borrow),
+            5 => Some(RequestType::Repay),
+            6 => Some(RequestType::FillUserLiquidationAuction),
+            7 => Some(RequestType::FillBadDebtAuction),
+            8 => Some(RequestType::FillInterestAuction),
+            9 => Some(RequestType::DeleteLiquidationAuction),
+            _ => None,
+        }
+    }
+
+    /// Get the `request_type` the pool expects for a request of this type
+    pub fn into_u32(self) -> u32 {
+        self as u32
+    }
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// The part of the Soroswap router Orbit uses to swap
+#[contractclient(name = "SoroswapRouterClient")]
+pub trait SoroswapRouter {
+    /// Quote a swap along a path of tokens
+    ///
+    /// Returns the amount of each token of the path
+    ///
+    /// ### Arguments
+    /// * `amount_in` - The amount of the first token of the path to swap
+    /// * `path` - The tokens to swap through, in order
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+
+    /// Swap an exact amount of the first token of a path for as much of the last as it buys
+    ///
+    /// Returns the amount of each token of the path
+    ///
+    /// ### Arguments
+    /// * `amount_in` - The amount of the first token of the path to swap
+    /// * `amount_out_min` - The least of the last token of the path to receive
+    /// * `path` - The tokens to swap through, in order
+    /// * `to` - The Address that receives the last token of the path
+    /// * `deadline` - The latest time the swap can be made
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+}
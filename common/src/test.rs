@@ -0,0 +1,51 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::math::{self, MathError};
+use soroban_sdk::{contract, contractimpl, Env};
+
+/// Calls the math helpers from inside a contract, so their errors reach the caller
+#[contract]
+pub struct Calculator;
+
+#[contractimpl]
+impl Calculator {
+    pub fn mul_div_floor(e: Env, x: i128, y: i128, denominator: i128) -> i128 {
+        math::mul_div_floor(&e, x, y, denominator)
+    }
+
+    pub fn mul_div_ceil(e: Env, x: i128, y: i128, denominator: i128) -> i128 {
+        math::mul_div_ceil(&e, x, y, denominator)
+    }
+}
+
+#[test]
+fn test_mul_div() {
+    let e = Env::default();
+    let calculator = CalculatorClient::new(&e, &e.register_contract(None, Calculator));
+
+    // results round down or up, whatever the signs
+    assert_eq!(calculator.mul_div_floor(&7, &3, &2), 10);
+    assert_eq!(calculator.mul_div_ceil(&7, &3, &2), 11);
+    assert_eq!(calculator.mul_div_floor(&-7, &3, &2), -11);
+    assert_eq!(calculator.mul_div_ceil(&-7, &3, &2), -10);
+    assert_eq!(calculator.mul_div_floor(&7, &3, &-2), -11);
+    assert_eq!(calculator.mul_div_ceil(&7, &3, &-2), -10);
+    assert_eq!(calculator.mul_div_ceil(&8, &3, &2), 12);
+
+    // an overflow or a zero denominator reverts
+    let result = calculator.try_mul_div_floor(&i128::MAX, &2, &2);
+    assert_eq!(result.err(), Some(Ok(MathError::OverflowError.into())));
+    let result = calculator.try_mul_div_ceil(&1, &1, &0);
+    assert_eq!(result.err(), Some(Ok(MathError::OverflowError.into())));
+}
+
+#[test]
+fn test_shares() {
+    let e = Env::default();
+
+    assert_eq!(math::apply_bps(&e, 1000_0000000, 30), 3_0000000);
+    assert_eq!(math::apply_bps(&e, 333, 5000), 166);
+    assert_eq!(math::apply_pct(&e, 1000_0000000, 0_2000000), 200_0000000);
+    assert_eq!(math::pct_of(&e, 50, 200), 0_2500000);
+    assert_eq!(math::pct_of(&e, 1, 3), 0_3333333);
+}
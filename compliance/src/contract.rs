@@ -0,0 +1,143 @@
+use crate::errors::ComplianceError;
+use crate::events::ComplianceEvents;
+use crate::storage::{self, ComplianceDataKey};
+use orbit_access::Role;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct ComplianceContract;
+
+#[contractclient(name = "ComplianceClient")]
+pub trait Compliance {
+    /// Initialize the compliance registry. Denylisted addresses are never allowed, and when the
+    /// allowlist is enabled only allowlisted addresses are.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `allowlist_enabled` - Whether only allowlisted addresses are allowed
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, allowlist_enabled: bool);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Turn the allowlist on or off
+    ///
+    /// ### Arguments
+    /// * `enabled` - Whether only allowlisted addresses are allowed
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_allowlist_enabled(e: Env, enabled: bool);
+
+    /// Check if only allowlisted addresses are allowed
+    fn get_allowlist_enabled(e: Env) -> bool;
+
+    /// (Admin only) Add an address to or remove it from the allowlist
+    ///
+    /// ### Arguments
+    /// * `user` - The Address
+    /// * `allowed` - Whether the address is allowlisted
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_allowed(e: Env, user: Address, allowed: bool);
+
+    /// (Admin only) Add an address to or remove it from the denylist
+    ///
+    /// ### Arguments
+    /// * `user` - The Address
+    /// * `denied` - Whether the address is denylisted
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_denied(e: Env, user: Address, denied: bool);
+
+    /// Check if an address is on the denylist
+    ///
+    /// ### Arguments
+    /// * `user` - The Address
+    fn is_denied(e: Env, user: Address) -> bool;
+
+    /// Check if an address is allowed to use the contracts consulting the registry
+    ///
+    /// ### Arguments
+    /// * `user` - The Address
+    fn is_allowed(e: Env, user: Address) -> bool;
+}
+
+#[contractimpl]
+impl Compliance for ComplianceContract {
+    fn initialize(e: Env, admin: Address, allowlist_enabled: bool) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, ComplianceError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_allowlist_enabled(&e, allowlist_enabled);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_allowlist_enabled(e: Env, enabled: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_allowlist_enabled(&e, enabled);
+
+        ComplianceEvents::set_allowlist_enabled(&e, enabled);
+    }
+
+    fn get_allowlist_enabled(e: Env) -> bool {
+        storage::extend_instance(&e);
+        storage::get_allowlist_enabled(&e)
+    }
+
+    fn set_allowed(e: Env, user: Address, allowed: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_listed(&e, &ComplianceDataKey::Allowed(user.clone()), allowed);
+
+        ComplianceEvents::set_allowed(&e, user, allowed);
+    }
+
+    fn set_denied(e: Env, user: Address, denied: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_listed(&e, &ComplianceDataKey::Denied(user.clone()), denied);
+
+        ComplianceEvents::set_denied(&e, user, denied);
+    }
+
+    fn is_denied(e: Env, user: Address) -> bool {
+        storage::extend_instance(&e);
+        storage::is_listed(&e, &ComplianceDataKey::Denied(user))
+    }
+
+    fn is_allowed(e: Env, user: Address) -> bool {
+        storage::extend_instance(&e);
+        if storage::is_listed(&e, &ComplianceDataKey::Denied(user.clone())) {
+            return false;
+        }
+        !storage::get_allowlist_enabled(&e)
+            || storage::is_listed(&e, &ComplianceDataKey::Allowed(user))
+    }
+}
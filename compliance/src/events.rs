@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct ComplianceEvents {}
+
+impl ComplianceEvents {
+    /// Emitted when the admin turns the allowlist on or off
+    ///
+    /// - topics - `["set_allowlist_enabled"]`
+    /// - data - `enabled: bool`
+    pub fn set_allowlist_enabled(e: &Env, enabled: bool) {
+        let topics = (Symbol::new(e, topics::SET_ALLOWLIST_ENABLED),);
+        e.events().publish(topics, enabled);
+    }
+
+    /// Emitted when the admin adds an address to or removes it from the allowlist
+    ///
+    /// - topics - `["set_allowed", user: Address]`
+    /// - data - `allowed: bool`
+    pub fn set_allowed(e: &Env, user: Address, allowed: bool) {
+        let topics = (Symbol::new(e, topics::SET_ALLOWED), user);
+        e.events().publish(topics, allowed);
+    }
+
+    /// Emitted when the admin adds an address to or removes it from the denylist
+    ///
+    /// - topics - `["set_denied", user: Address]`
+    /// - data - `denied: bool`
+    pub fn set_denied(e: &Env, user: Address, denied: bool) {
+        let topics = (Symbol::new(e, topics::SET_DENIED), user);
+        e.events().publish(topics, denied);
+    }
+}
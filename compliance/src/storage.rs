@@ -0,0 +1,93 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ALLOWLIST_ENABLED_KEY: &str = "AllowlistEnabled";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ComplianceDataKey {
+    // An address on the allowlist
+    Allowed(Address),
+    // An address on the denylist
+    Denied(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Lists **********/
+
+/// Check if only allowlisted addresses are allowed
+pub fn get_allowlist_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ALLOWLIST_ENABLED_KEY))
+        .unwrap_or(false)
+}
+
+/// Set whether only allowlisted addresses are allowed
+///
+/// ### Arguments
+/// * `enabled` - Whether the allowlist is enforced
+pub fn set_allowlist_enabled(e: &Env, enabled: bool) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, ALLOWLIST_ENABLED_KEY), &enabled);
+}
+
+/// Check if an address is on a list
+///
+/// ### Arguments
+/// * `key` - The list entry of the address
+pub fn is_listed(e: &Env, key: &ComplianceDataKey) -> bool {
+    let result = e.storage().persistent().get::<ComplianceDataKey, bool>(key);
+    match result {
+        Some(listed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            listed
+        }
+        None => false,
+    }
+}
+
+/// Add an address to or remove it from a list
+///
+/// ### Arguments
+/// * `key` - The list entry of the address
+/// * `listed` - Whether the address is on the list
+pub fn set_listed(e: &Env, key: &ComplianceDataKey, listed: bool) {
+    if listed {
+        e.storage()
+            .persistent()
+            .set::<ComplianceDataKey, bool>(key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    } else {
+        e.storage().persistent().remove(key);
+    }
+}
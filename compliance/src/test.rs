@@ -0,0 +1,31 @@
+#![cfg(test)]
+use crate::contract::{ComplianceClient, ComplianceContract};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_allow_and_deny_lists() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let compliance_id = e.register_contract(None, ComplianceContract);
+    let compliance_client = ComplianceClient::new(&e, &compliance_id);
+    compliance_client.initialize(&Address::generate(&e), &false);
+    let user = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    // everyone but denylisted addresses is allowed while the allowlist is off
+    assert!(compliance_client.is_allowed(&user));
+    compliance_client.set_denied(&user, &true);
+    assert!(compliance_client.is_denied(&user));
+    assert!(!compliance_client.is_allowed(&user));
+
+    compliance_client.set_allowlist_enabled(&true);
+    assert!(!compliance_client.is_allowed(&other));
+    compliance_client.set_allowed(&other, &true);
+    compliance_client.set_allowed(&user, &true);
+    assert!(compliance_client.is_allowed(&other));
+    // the denylist wins over the allowlist
+    assert!(!compliance_client.is_allowed(&user));
+    compliance_client.set_denied(&user, &false);
+    assert!(compliance_client.is_allowed(&user));
+}
@@ -0,0 +1,191 @@
+use crate::dependencies::treasury::TreasuryClient;
+use crate::errors::D3MError;
+use crate::events::D3MEvents;
+use crate::storage::{self, D3MConfig};
+use orbit_access::Role;
+use orbit_common::math::{self, SCALAR_7};
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, Vec};
+
+#[contract]
+pub struct D3MContract;
+
+#[contractclient(name = "D3MClient")]
+pub trait D3M {
+    /// Initialize the direct deposit module
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `token` - The Address of the minted token
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Approve an external pool or update its settings. The module must be the
+    /// admin of the treasury supplying the pool.
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    /// * `config` - The direct deposit settings of the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the ceiling or floor is negative, or the target utilization is not between 0 and 100%
+    fn set_pool(e: Env, pool: Address, config: D3MConfig);
+
+    /// (Admin only) Remove a pool once the module has fully unwound from it
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the pool is not approved
+    /// If the treasury of the pool still has supply
+    fn remove_pool(e: Env, pool: Address);
+
+    /// Get the approved pools
+    fn get_pools(e: Env) -> Vec<Address>;
+
+    /// Get the direct deposit settings of a pool
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    ///
+    /// ### Panics
+    /// If the pool is not approved
+    fn get_pool(e: Env, pool: Address) -> D3MConfig;
+
+    /// Rebalance the deposit in a pool toward its target utilization. Mints into the pool while
+    /// utilization is above the target and unwinds while it is below, within the floor and
+    /// ceiling. Only idle liquidity can be unwound.
+    ///
+    /// The amount borrowed is approximated as the supply minted by the treasury that is not
+    /// held as liquidity by the pool.
+    ///
+    /// Returns the change in supply, positive when minting and negative when unwinding
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the pool
+    ///
+    /// ### Panics
+    /// If the pool is not approved
+    fn exec(e: Env, pool: Address) -> i128;
+}
+
+#[contractimpl]
+impl D3M for D3MContract {
+    fn initialize(e: Env, admin: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, D3MError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_pool(e: Env, pool: Address, config: D3MConfig) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if config.ceiling < 0
+            || config.floor < 0
+            || config.target_util <= 0
+            || config.target_util > SCALAR_7
+        {
+            panic_with_error!(&e, D3MError::InvalidConfig);
+        }
+
+        let mut pools = storage::get_pools(&e);
+        if !pools.contains(&pool) {
+            pools.push_back(pool.clone());
+            storage::set_pools(&e, &pools);
+        }
+        storage::set_config(&e, &pool, &config);
+
+        D3MEvents::set_pool(&e, pool, config);
+    }
+
+    fn remove_pool(e: Env, pool: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let config = load_config(&e, &pool);
+        if TreasuryClient::new(&e, &config.treasury).get_token_supply() > 0 {
+            panic_with_error!(&e, D3MError::PoolNotEmpty);
+        }
+        let mut pools = storage::get_pools(&e);
+        if let Some(index) = pools.first_index_of(&pool) {
+            pools.remove(index);
+        }
+        storage::set_pools(&e, &pools);
+        storage::del_config(&e, &pool);
+
+        D3MEvents::remove_pool(&e, pool);
+    }
+
+    fn get_pools(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_pools(&e)
+    }
+
+    fn get_pool(e: Env, pool: Address) -> D3MConfig {
+        storage::extend_instance(&e);
+        load_config(&e, &pool)
+    }
+
+    fn exec(e: Env, pool: Address) -> i128 {
+        storage::extend_instance(&e);
+
+        let config = load_config(&e, &pool);
+        let treasury_client = TreasuryClient::new(&e, &config.treasury);
+        let supply = treasury_client.get_token_supply();
+        let idle = TokenClient::new(&e, &storage::get_token(&e)).balance(&pool);
+        let borrowed = (supply - idle).max(0);
+        let target = math::mul_div_floor(&e, borrowed, SCALAR_7, config.target_util)
+            .max(config.floor)
+            .min(config.ceiling);
+
+        let change = if target > supply {
+            let amount = target - supply;
+            treasury_client.increase_supply(&amount);
+            amount
+        } else {
+            let amount = (supply - target).min(idle);
+            if amount > 0 {
+                treasury_client.decrease_supply(&amount);
+            }
+            -amount
+        };
+
+        D3MEvents::exec(&e, pool, change, supply + change);
+        change
+    }
+}
+
+/// Load the direct deposit settings of a pool
+///
+/// ### Panics
+/// If the pool is not approved
+fn load_config(e: &Env, pool: &Address) -> D3MConfig {
+    storage::get_config(e, pool).unwrap_or_else(|| panic_with_error!(e, D3MError::PoolNotApproved))
+}
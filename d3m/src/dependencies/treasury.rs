@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Env};
+
+/// The part of the treasury the module uses to mint into a pool and unwind
+#[allow(dead_code)]
+#[contractclient(name = "TreasuryClient")]
+pub trait Treasury {
+    fn increase_supply(e: Env, amount: i128);
+
+    fn decrease_supply(e: Env, amount: i128);
+
+    fn get_token_supply(e: Env) -> i128;
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the direct deposit module. Common errors are codes that match up with the built-in
+/// contracts error reporting. Direct deposit module specific errors start at 4100.
+pub enum D3MError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Direct Deposit Module
+    PoolNotApproved = 4100,
+    InvalidConfig = 4101,
+    PoolNotEmpty = 4102,
+}
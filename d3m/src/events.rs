@@ -0,0 +1,35 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::D3MConfig;
+
+pub struct D3MEvents {}
+
+impl D3MEvents {
+    /// Emitted when the admin approves a pool or updates its settings
+    ///
+    /// - topics - `["set_pool", pool: Address]`
+    /// - data - `config: D3MConfig`
+    pub fn set_pool(e: &Env, pool: Address, config: D3MConfig) {
+        let topics = (Symbol::new(e, topics::SET_POOL), pool);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin removes an unwound pool
+    ///
+    /// - topics - `["remove_pool", pool: Address]`
+    /// - data - `()`
+    pub fn remove_pool(e: &Env, pool: Address) {
+        let topics = (Symbol::new(e, topics::REMOVE_POOL), pool);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when the module rebalances its deposit in a pool
+    ///
+    /// - topics - `["exec", pool: Address]`
+    /// - data - `[change: i128, supply: i128]`
+    pub fn exec(e: &Env, pool: Address, change: i128, supply: i128) {
+        let topics = (Symbol::new(e, topics::EXEC), pool);
+        e.events().publish(topics, (change, supply));
+    }
+}
@@ -0,0 +1,126 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, vec, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const POOLS_KEY: &str = "Pools";
+
+/// The direct deposit settings of an external pool
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct D3MConfig {
+    pub treasury: Address, // the treasury minting into the pool, administered by the module
+    pub ceiling: i128,     // the most the module can mint into the pool
+    pub floor: i128,       // the least the module keeps in the pool, so there is liquidity to borrow
+    pub target_util: i128, // the utilization that yields the target borrow rate, with 7 decimals
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum D3MDataKey {
+    // The direct deposit settings of a pool
+    Pool(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the minted token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the minted token
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Pools **********/
+
+/// Fetch the approved pools
+pub fn get_pools(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, POOLS_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the approved pools
+///
+/// ### Arguments
+/// * `pools` - The Addresses of the pools
+pub fn set_pools(e: &Env, pools: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, POOLS_KEY), pools);
+}
+
+/// Fetch the direct deposit settings of a pool, if it is approved
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn get_config(e: &Env, pool: &Address) -> Option<D3MConfig> {
+    let key = D3MDataKey::Pool(pool.clone());
+    let result = e.storage().persistent().get::<D3MDataKey, D3MConfig>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the direct deposit settings of a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `config` - The direct deposit settings
+pub fn set_config(e: &Env, pool: &Address, config: &D3MConfig) {
+    let key = D3MDataKey::Pool(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<D3MDataKey, D3MConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the direct deposit settings of a pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn del_config(e: &Env, pool: &Address) {
+    let key = D3MDataKey::Pool(pool.clone());
+    e.storage().persistent().remove(&key);
+}
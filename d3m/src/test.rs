@@ -0,0 +1,244 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{D3MClient, D3MContract};
+use crate::errors::D3MError;
+use crate::storage::D3MConfig;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+/// Mints into and burns from a pool while tracking its supply, like the treasury
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn setup(e: Env, token: Address, pool: Address) {
+        e.storage().instance().set(&Symbol::new(&e, "token"), &token);
+        e.storage().instance().set(&Symbol::new(&e, "pool"), &pool);
+    }
+
+    pub fn increase_supply(e: Env, amount: i128) {
+        let (token, pool) = Self::contracts(&e);
+        StellarAssetClient::new(&e, &token).mint(&pool, &amount);
+        let supply = Self::get_token_supply(e.clone()) + amount;
+        e.storage().instance().set(&Symbol::new(&e, "supply"), &supply);
+    }
+
+    pub fn decrease_supply(e: Env, amount: i128) {
+        let (token, pool) = Self::contracts(&e);
+        TokenClient::new(&e, &token).burn(&pool, &amount);
+        let supply = Self::get_token_supply(e.clone()) - amount;
+        e.storage().instance().set(&Symbol::new(&e, "supply"), &supply);
+    }
+
+    pub fn get_token_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "supply"))
+            .unwrap_or(0)
+    }
+}
+
+impl MockTreasury {
+    fn contracts(e: &Env) -> (Address, Address) {
+        let token = e.storage().instance().get(&Symbol::new(e, "token")).unwrap();
+        let pool = e.storage().instance().get(&Symbol::new(e, "pool")).unwrap();
+        (token, pool)
+    }
+}
+
+#[test]
+fn test_exec_targets_utilization() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let pool = Address::generate(&e);
+    let borrower = Address::generate(&e);
+    let treasury = e.register_contract(None, MockTreasury);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury);
+    treasury_client.setup(&ousd, &pool);
+
+    let d3m_id = e.register_contract(None, D3MContract);
+    let d3m_client = D3MClient::new(&e, &d3m_id);
+    d3m_client.initialize(&Address::generate(&e), &ousd);
+    let result = d3m_client.try_exec(&pool);
+    assert_eq!(result.err(), Some(Ok(D3MError::PoolNotApproved.into())));
+
+    let config = D3MConfig {
+        treasury: treasury.clone(),
+        ceiling: 1000000_0000000,
+        floor: 100000_0000000,
+        target_util: 0_8000000,
+    };
+    d3m_client.set_pool(&pool, &config);
+
+    // seed the floor so there is liquidity to borrow
+    assert_eq!(d3m_client.exec(&pool), 100000_0000000);
+    assert_eq!(d3m_client.exec(&pool), 0);
+
+    // borrowing 90k pushes utilization to 90%, so the module mints up to 80%
+    ousd_client.transfer(&pool, &borrower, &90000_0000000);
+    assert_eq!(d3m_client.exec(&pool), 12500_0000000);
+    assert_eq!(treasury_client.get_token_supply(), 112500_0000000);
+    assert_eq!(ousd_client.balance(&pool), 22500_0000000);
+
+    // the ceiling caps the deposit
+    ousd_client.transfer(&pool, &borrower, &22500_0000000);
+    d3m_client.set_pool(
+        &pool,
+        &D3MConfig {
+            ceiling: 120000_0000000,
+            ..config.clone()
+        },
+    );
+    assert_eq!(d3m_client.exec(&pool), 7500_0000000);
+
+    // once borrowers repay and utilization drops, the module unwinds to the floor
+    ousd_client.transfer(&borrower, &pool, &112500_0000000);
+    assert_eq!(d3m_client.exec(&pool), -20000_0000000);
+    assert_eq!(treasury_client.get_token_supply(), 100000_0000000);
+
+    // setting the ceiling to zero unwinds everything
+    d3m_client.set_pool(
+        &pool,
+        &D3MConfig {
+            ceiling: 0,
+            ..config
+        },
+    );
+    let result = d3m_client.try_remove_pool(&pool);
+    assert_eq!(result.err(), Some(Ok(D3MError::PoolNotEmpty.into())));
+    assert_eq!(d3m_client.exec(&pool), -100000_0000000);
+    d3m_client.remove_pool(&pool);
+    assert_eq!(d3m_client.get_pools().len(), 0);
+}
+
+#[test]
+fn test_set_pool_validation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let admin = Address::generate(&e);
+    let pool = Address::generate(&e);
+
+    let d3m_id = e.register_contract(None, D3MContract);
+    let d3m_client = D3MClient::new(&e, &d3m_id);
+    d3m_client.initialize(&admin, &ousd);
+    let result = d3m_client.try_initialize(&admin, &ousd);
+    assert_eq!(result.err(), Some(Ok(D3MError::AlreadyInitializedError.into())));
+
+    // a target utilization of 100% is allowed, and updating a pool does not list it twice
+    let config = D3MConfig {
+        treasury: Address::generate(&e),
+        ceiling: 0,
+        floor: 0,
+        target_util: 1_0000000,
+    };
+    d3m_client.set_pool(&pool, &config);
+    assert_eq!(
+        e.auths()[0],
+        (
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    d3m_id.clone(),
+                    Symbol::new(&e, "set_pool"),
+                    vec![&e, pool.into_val(&e), config.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    d3m_client.set_pool(&pool, &config);
+    assert_eq!(d3m_client.get_pools(), vec![&e, pool.clone()]);
+    assert_eq!(d3m_client.get_pool(&pool), config);
+
+    let invalid = [
+        D3MConfig {
+            ceiling: -1,
+            ..config.clone()
+        },
+        D3MConfig {
+            floor: -1,
+            ..config.clone()
+        },
+        D3MConfig {
+            target_util: 0,
+            ..config.clone()
+        },
+        D3MConfig {
+            target_util: 1_0000001,
+            ..config.clone()
+        },
+    ];
+    for config in invalid {
+        let result = d3m_client.try_set_pool(&pool, &config);
+        assert_eq!(result.err(), Some(Ok(D3MError::InvalidConfig.into())));
+    }
+
+    let unknown = Address::generate(&e);
+    let result = d3m_client.try_get_pool(&unknown);
+    assert_eq!(result.err(), Some(Ok(D3MError::PoolNotApproved.into())));
+    let result = d3m_client.try_remove_pool(&unknown);
+    assert_eq!(result.err(), Some(Ok(D3MError::PoolNotApproved.into())));
+}
+
+#[test]
+fn test_exec_unwinds_only_idle_liquidity() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let pool = Address::generate(&e);
+    let borrower = Address::generate(&e);
+    let treasury = e.register_contract(None, MockTreasury);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury);
+    treasury_client.setup(&ousd, &pool);
+
+    let d3m_id = e.register_contract(None, D3MContract);
+    let d3m_client = D3MClient::new(&e, &d3m_id);
+    d3m_client.initialize(&Address::generate(&e), &ousd);
+    let config = D3MConfig {
+        treasury: treasury.clone(),
+        ceiling: 1000000_0000000,
+        floor: 0,
+        target_util: 0_8000000,
+    };
+    d3m_client.set_pool(&pool, &config);
+
+    // nothing is minted without a floor or borrowers
+    assert_eq!(d3m_client.exec(&pool), 0);
+    assert_eq!(treasury_client.get_token_supply(), 0);
+
+    d3m_client.set_pool(
+        &pool,
+        &D3MConfig {
+            floor: 100000_0000000,
+            ..config.clone()
+        },
+    );
+    assert_eq!(d3m_client.exec(&pool), 100000_0000000);
+    ousd_client.transfer(&pool, &borrower, &75000_0000000);
+
+    // winding down only burns what borrowers left in the pool
+    d3m_client.set_pool(
+        &pool,
+        &D3MConfig {
+            ceiling: 0,
+            ..config
+        },
+    );
+    assert_eq!(d3m_client.exec(&pool), -25000_0000000);
+    assert_eq!(ousd_client.balance(&pool), 0);
+    assert_eq!(d3m_client.exec(&pool), 0);
+    assert_eq!(treasury_client.get_token_supply(), 75000_0000000);
+    let result = d3m_client.try_remove_pool(&pool);
+    assert_eq!(result.err(), Some(Ok(D3MError::PoolNotEmpty.into())));
+
+    ousd_client.transfer(&borrower, &pool, &75000_0000000);
+    assert_eq!(d3m_client.exec(&pool), -75000_0000000);
+    assert_eq!(treasury_client.get_token_supply(), 0);
+    d3m_client.remove_pool(&pool);
+}
@@ -0,0 +1,301 @@
+use crate::dependencies::insurance::InsuranceClient;
+use crate::dependencies::shutdown::ShutdownClient;
+use crate::errors::DebtAuctionError;
+use crate::events::DebtAuctionEvents;
+use crate::storage::{self, DebtAuction, DebtAuctionSettings};
+use orbit_access::Role;
+use sep_41_token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct DebtAuctionContract;
+
+#[contractclient(name = "DebtAuctionClient")]
+pub trait DebtAuctionTrait {
+    /// Initialize the debt auction. The contract must be the admin of the governance token so
+    /// it can mint the lots.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `guardian` - The Address for the guardian, who starts and cancels auctions
+    /// * `shutdown` - The Address of the shutdown module
+    /// * `insurance` - The Address of the insurance fund the auctions recapitalize
+    /// * `token` - The Address of the oUSD token
+    /// * `gov_token` - The Address of the governance token
+    /// * `settings` - The caps on a single auction
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the settings are invalid
+    #[allow(clippy::too_many_arguments)]
+    fn initialize(
+        e: Env,
+        admin: Address,
+        guardian: Address,
+        shutdown: Address,
+        insurance: Address,
+        token: Address,
+        gov_token: Address,
+        settings: DebtAuctionSettings,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set a new guardian
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_guardian(e: Env, guardian: Address);
+
+    /// (Admin only) Set the caps on a single auction. Open auctions keep their terms.
+    ///
+    /// ### Arguments
+    /// * `settings` - The caps on a single auction
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If any cap or the duration is zero or negative
+    fn set_settings(e: Env, settings: DebtAuctionSettings);
+
+    /// Get the caps on a single auction
+    fn get_settings(e: Env) -> DebtAuctionSettings;
+
+    /// (Guardian only) Start an auction minting governance tokens to raise oUSD for the
+    /// insurance fund, once the fund can no longer cover the remaining bad debt
+    ///
+    /// Returns the id of the auction
+    ///
+    /// ### Arguments
+    /// * `debt` - The oUSD the auction raises
+    /// * `lot` - The most governance tokens the auction mints
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    /// If the protocol is shut down
+    /// If `debt` or `lot` is zero or negative, or exceeds its cap
+    /// If the insurance fund holds enough oUSD to cover `debt`
+    fn start(e: Env, debt: i128, lot: i128) -> u32;
+
+    /// Get an open auction
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the auction
+    ///
+    /// ### Panics
+    /// If the auction does not exist
+    fn get_auction(e: Env, id: u32) -> DebtAuction;
+
+    /// Get the governance tokens an auction currently offers. The lot grows linearly from zero
+    /// to its full size over the auction duration, so it fills at the smallest lot a bidder
+    /// accepts.
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the auction
+    ///
+    /// ### Panics
+    /// If the auction does not exist
+    fn get_lot(e: Env, id: u32) -> i128;
+
+    /// Fill an auction by paying its oUSD into the insurance fund for the current lot of
+    /// freshly minted governance tokens
+    ///
+    /// Returns the governance tokens minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address filling the auction
+    /// * `id` - The id of the auction
+    ///
+    /// ### Panics
+    /// If the protocol is shut down
+    /// If the auction does not exist
+    fn fill(e: Env, from: Address, id: u32) -> i128;
+
+    /// (Guardian only) Cancel an open auction
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the auction
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    /// If the auction does not exist
+    fn cancel(e: Env, id: u32);
+}
+
+#[contractimpl]
+impl DebtAuctionTrait for DebtAuctionContract {
+    #[allow(clippy::too_many_arguments)]
+    fn initialize(
+        e: Env,
+        admin: Address,
+        guardian: Address,
+        shutdown: Address,
+        insurance: Address,
+        token: Address,
+        gov_token: Address,
+        settings: DebtAuctionSettings,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, DebtAuctionError::AlreadyInitializedError);
+        }
+        require_valid_settings(&e, &settings);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+        storage::set_shutdown(&e, &shutdown);
+        storage::set_insurance(&e, &insurance);
+        storage::set_token(&e, &token);
+        storage::set_gov_token(&e, &gov_token);
+        storage::set_settings(&e, &settings);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_guardian(e: Env, guardian: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+    }
+
+    fn set_settings(e: Env, settings: DebtAuctionSettings) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_settings(&e, &settings);
+
+        storage::set_settings(&e, &settings);
+
+        DebtAuctionEvents::set_settings(&e, settings);
+    }
+
+    fn get_settings(e: Env) -> DebtAuctionSettings {
+        storage::extend_instance(&e);
+        storage::get_settings(&e)
+    }
+
+    fn start(e: Env, debt: i128, lot: i128) -> u32 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Guardian);
+        require_not_shutdown(&e);
+        if debt <= 0 || lot <= 0 {
+            panic_with_error!(&e, DebtAuctionError::NegativeAmountError);
+        }
+        let settings = storage::get_settings(&e);
+        if debt > settings.max_debt || lot > settings.max_lot {
+            panic_with_error!(&e, DebtAuctionError::AuctionCapExceeded);
+        }
+        let insurance = storage::get_insurance(&e);
+        if TokenClient::new(&e, &storage::get_token(&e)).balance(&insurance) >= debt {
+            panic_with_error!(&e, DebtAuctionError::InsuranceNotDepleted);
+        }
+
+        let id = storage::get_next_id(&e);
+        let start = e.ledger().timestamp();
+        let auction = DebtAuction {
+            debt,
+            lot,
+            start,
+            end: start + settings.duration,
+        };
+        storage::set_auction(&e, id, &auction);
+        storage::set_next_id(&e, id + 1);
+
+        DebtAuctionEvents::start(&e, id, debt, lot);
+        id
+    }
+
+    fn get_auction(e: Env, id: u32) -> DebtAuction {
+        storage::extend_instance(&e);
+        load_auction(&e, id)
+    }
+
+    fn get_lot(e: Env, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        calc_lot(&e, &load_auction(&e, id))
+    }
+
+    fn fill(e: Env, from: Address, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_not_shutdown(&e);
+
+        let auction = load_auction(&e, id);
+        let lot = calc_lot(&e, &auction);
+        storage::del_auction(&e, id);
+
+        InsuranceClient::new(&e, &storage::get_insurance(&e)).deposit(
+            &from,
+            &storage::get_token(&e),
+            &auction.debt,
+        );
+        if lot > 0 {
+            StellarAssetClient::new(&e, &storage::get_gov_token(&e)).mint(&from, &lot);
+        }
+
+        DebtAuctionEvents::fill(&e, id, from, auction.debt, lot);
+        lot
+    }
+
+    fn cancel(e: Env, id: u32) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Guardian);
+
+        load_auction(&e, id);
+        storage::del_auction(&e, id);
+
+        DebtAuctionEvents::cancel(&e, id);
+    }
+}
+
+/// Require that the auction caps are positive
+///
+/// ### Panics
+/// If any cap or the duration is zero or negative
+fn require_valid_settings(e: &Env, settings: &DebtAuctionSettings) {
+    if settings.max_debt <= 0 || settings.max_lot <= 0 || settings.duration == 0 {
+        panic_with_error!(e, DebtAuctionError::InvalidSettings);
+    }
+}
+
+/// Require that the protocol is not shut down
+///
+/// ### Panics
+/// If the shutdown module has been triggered
+fn require_not_shutdown(e: &Env) {
+    if ShutdownClient::new(e, &storage::get_shutdown(e)).is_shutdown() {
+        panic_with_error!(e, DebtAuctionError::IsShutdown);
+    }
+}
+
+/// Load an open auction
+///
+/// ### Panics
+/// If the auction does not exist
+fn load_auction(e: &Env, id: u32) -> DebtAuction {
+    storage::get_auction(e, id)
+        .unwrap_or_else(|| panic_with_error!(e, DebtAuctionError::AuctionNotFound))
+}
+
+/// Calculate the governance tokens an auction currently offers
+fn calc_lot(e: &Env, auction: &DebtAuction) -> i128 {
+    let now = e.ledger().timestamp().min(auction.end);
+    let elapsed = now.saturating_sub(auction.start) as i128;
+    auction.lot * elapsed / (auction.end - auction.start) as i128
+}
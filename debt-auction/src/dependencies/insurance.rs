@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the insurance fund the debt auction recapitalizes
+#[allow(dead_code)]
+#[contractclient(name = "InsuranceClient")]
+pub trait Insurance {
+    fn deposit(e: Env, from: Address, token: Address, amount: i128);
+}
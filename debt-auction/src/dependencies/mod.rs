@@ -0,0 +1,2 @@
+pub mod insurance;
+pub mod shutdown;
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Env};
+
+/// The part of the shutdown module the debt auction checks before minting
+#[allow(dead_code)]
+#[contractclient(name = "ShutdownClient")]
+pub trait Shutdown {
+    fn is_shutdown(e: Env) -> bool;
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the debt auction contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Debt auction specific errors start at 4200.
+pub enum DebtAuctionError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Debt Auction
+    AuctionNotFound = 4200,
+    InvalidSettings = 4201,
+    AuctionCapExceeded = 4202,
+    InsuranceNotDepleted = 4203,
+    IsShutdown = 4204,
+}
@@ -0,0 +1,44 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::DebtAuctionSettings;
+
+pub struct DebtAuctionEvents {}
+
+impl DebtAuctionEvents {
+    /// Emitted when the admin updates the auction caps
+    ///
+    /// - topics - `["set_settings"]`
+    /// - data - `settings: DebtAuctionSettings`
+    pub fn set_settings(e: &Env, settings: DebtAuctionSettings) {
+        let topics = (Symbol::new(e, topics::SET_SETTINGS),);
+        e.events().publish(topics, settings);
+    }
+
+    /// Emitted when the guardian starts an auction
+    ///
+    /// - topics - `["start", id: u32]`
+    /// - data - `[debt: i128, lot: i128]`
+    pub fn start(e: &Env, id: u32, debt: i128, lot: i128) {
+        let topics = (Symbol::new(e, topics::START), id);
+        e.events().publish(topics, (debt, lot));
+    }
+
+    /// Emitted when an auction is filled
+    ///
+    /// - topics - `["fill", id: u32, filler: Address]`
+    /// - data - `[debt: i128, lot: i128]`
+    pub fn fill(e: &Env, id: u32, filler: Address, debt: i128, lot: i128) {
+        let topics = (Symbol::new(e, topics::FILL), id, filler);
+        e.events().publish(topics, (debt, lot));
+    }
+
+    /// Emitted when the guardian cancels an auction
+    ///
+    /// - topics - `["cancel", id: u32]`
+    /// - data - `()`
+    pub fn cancel(e: &Env, id: u32) {
+        let topics = (Symbol::new(e, topics::CANCEL), id);
+        e.events().publish(topics, ());
+    }
+}
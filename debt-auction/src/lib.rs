@@ -0,0 +1,13 @@
+#![no_std]
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+mod storage;
+mod contract;
+mod dependencies;
+mod errors;
+mod events;
+mod test;
+
+pub use contract::*;
+pub use errors::DebtAuctionError;
+pub use storage::{DebtAuction, DebtAuctionSettings};
@@ -0,0 +1,223 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const SHUTDOWN_KEY: &str = "Shutdown";
+const INSURANCE_KEY: &str = "Insurance";
+const TOKEN_KEY: &str = "Token";
+const GOV_TOKEN_KEY: &str = "GovToken";
+const SETTINGS_KEY: &str = "Settings";
+const NEXT_ID_KEY: &str = "NextId";
+
+/// The caps on a single debt auction
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DebtAuctionSettings {
+    pub max_debt: i128, // the most oUSD a single auction can raise
+    pub max_lot: i128,  // the most governance tokens a single auction can mint
+    pub duration: u64,  // the seconds it takes the lot of an auction to grow to its full size
+}
+
+/// An auction minting governance tokens for oUSD
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DebtAuction {
+    pub debt: i128, // the oUSD the auction raises for the insurance fund
+    pub lot: i128,  // the most governance tokens the auction mints
+    pub start: u64, // the timestamp the auction started at
+    pub end: u64,   // the timestamp the lot reaches its full size at
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DebtAuctionDataKey {
+    // An open auction
+    Auction(u32),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the shutdown module
+///
+/// ### Panics
+/// If the shutdown module does not exist
+pub fn get_shutdown(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SHUTDOWN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the shutdown module
+///
+/// ### Arguments
+/// * `shutdown` - The Address for the shutdown module
+pub fn set_shutdown(e: &Env, shutdown: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, SHUTDOWN_KEY), shutdown);
+}
+
+/// Fetch the Address of the insurance fund
+///
+/// ### Panics
+/// If the insurance fund does not exist
+pub fn get_insurance(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, INSURANCE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the insurance fund
+///
+/// ### Arguments
+/// * `insurance` - The Address for the insurance fund
+pub fn set_insurance(e: &Env, insurance: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, INSURANCE_KEY), insurance);
+}
+
+/// Fetch the Address of the oUSD token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the oUSD token
+///
+/// ### Arguments
+/// * `token` - The Address for the oUSD token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the Address of the governance token
+///
+/// ### Panics
+/// If the governance token does not exist
+pub fn get_gov_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, GOV_TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the governance token
+///
+/// ### Arguments
+/// * `gov_token` - The Address for the governance token
+pub fn set_gov_token(e: &Env, gov_token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, GOV_TOKEN_KEY), gov_token);
+}
+
+/********** Auctions **********/
+
+/// Fetch the auction caps
+///
+/// ### Panics
+/// If the settings do not exist
+pub fn get_settings(e: &Env) -> DebtAuctionSettings {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SETTINGS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the auction caps
+///
+/// ### Arguments
+/// * `settings` - The auction caps
+pub fn set_settings(e: &Env, settings: &DebtAuctionSettings) {
+    e.storage()
+        .instance()
+        .set::<Symbol, DebtAuctionSettings>(&Symbol::new(e, SETTINGS_KEY), settings);
+}
+
+/// Fetch the id of the next auction
+pub fn get_next_id(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, NEXT_ID_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the id of the next auction
+///
+/// ### Arguments
+/// * `id` - The id of the next auction
+pub fn set_next_id(e: &Env, id: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, NEXT_ID_KEY), &id);
+}
+
+/// Fetch an open auction
+///
+/// ### Arguments
+/// * `id` - The id of the auction
+pub fn get_auction(e: &Env, id: u32) -> Option<DebtAuction> {
+    let key = DebtAuctionDataKey::Auction(id);
+    let result = e.storage().persistent().get::<DebtAuctionDataKey, DebtAuction>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set an open auction
+///
+/// ### Arguments
+/// * `id` - The id of the auction
+/// * `auction` - The auction
+pub fn set_auction(e: &Env, id: u32, auction: &DebtAuction) {
+    let key = DebtAuctionDataKey::Auction(id);
+    e.storage()
+        .persistent()
+        .set::<DebtAuctionDataKey, DebtAuction>(&key, auction);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove an auction once it is filled or canceled
+///
+/// ### Arguments
+/// * `id` - The id of the auction
+pub fn del_auction(e: &Env, id: u32) {
+    let key = DebtAuctionDataKey::Auction(id);
+    e.storage().persistent().remove(&key);
+}
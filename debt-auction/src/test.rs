@@ -0,0 +1,228 @@
+#![cfg(test)]
+use crate::contract::{DebtAuctionClient, DebtAuctionContract};
+use crate::errors::DebtAuctionError;
+use crate::storage::DebtAuctionSettings;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+/// Reports whether the protocol is shut down, like the shutdown module
+#[contract]
+pub struct MockShutdown;
+
+#[contractimpl]
+impl MockShutdown {
+    pub fn set_shutdown(e: Env, shutdown: bool) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "shutdown"), &shutdown);
+    }
+
+    pub fn is_shutdown(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "shutdown"))
+            .unwrap_or(false)
+    }
+}
+
+/// Takes deposits, like the insurance fund
+#[contract]
+pub struct MockInsurance;
+
+#[contractimpl]
+impl MockInsurance {
+    pub fn deposit(e: Env, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+    }
+}
+
+#[test]
+fn test_debt_auction() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = StellarAssetClient::new(&e, &ousd);
+    let orbt = e.register_stellar_asset_contract(Address::generate(&e));
+    let shutdown = e.register_contract(None, MockShutdown);
+    let shutdown_client = MockShutdownClient::new(&e, &shutdown);
+    let insurance = e.register_contract(None, MockInsurance);
+    let bidder = Address::generate(&e);
+    ousd_client.mint(&bidder, &50000_0000000);
+
+    let auction_id = e.register_contract(None, DebtAuctionContract);
+    let auction_client = DebtAuctionClient::new(&e, &auction_id);
+    auction_client.initialize(
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &shutdown,
+        &insurance,
+        &ousd,
+        &orbt,
+        &DebtAuctionSettings {
+            max_debt: 10000_0000000,
+            max_lot: 100000_0000000,
+            duration: 24 * 60 * 60,
+        },
+    );
+    StellarAssetClient::new(&e, &orbt).set_admin(&auction_id);
+
+    let result = auction_client.try_start(&20000_0000000, &100000_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(DebtAuctionError::AuctionCapExceeded.into()))
+    );
+    // the fund can still cover the debt
+    ousd_client.mint(&insurance, &10000_0000000);
+    let result = auction_client.try_start(&10000_0000000, &100000_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(DebtAuctionError::InsuranceNotDepleted.into()))
+    );
+    TokenClient::new(&e, &ousd).burn(&insurance, &10000_0000000);
+
+    let id = auction_client.start(&10000_0000000, &100000_0000000);
+    assert_eq!(auction_client.get_lot(&id), 0);
+    e.ledger().with_mut(|li| li.timestamp += 6 * 60 * 60);
+    assert_eq!(auction_client.get_lot(&id), 25000_0000000);
+
+    assert_eq!(auction_client.fill(&bidder, &id), 25000_0000000);
+    assert_eq!(TokenClient::new(&e, &orbt).balance(&bidder), 25000_0000000);
+    assert_eq!(TokenClient::new(&e, &ousd).balance(&insurance), 10000_0000000);
+    let result = auction_client.try_fill(&bidder, &id);
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::AuctionNotFound.into())));
+
+    // no auctions start or fill once the protocol is shut down
+    TokenClient::new(&e, &ousd).burn(&insurance, &10000_0000000);
+    let id = auction_client.start(&10000_0000000, &100000_0000000);
+    shutdown_client.set_shutdown(&true);
+    let result = auction_client.try_fill(&bidder, &id);
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::IsShutdown.into())));
+    let result = auction_client.try_start(&10000_0000000, &100000_0000000);
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::IsShutdown.into())));
+    auction_client.cancel(&id);
+    let result = auction_client.try_get_auction(&id);
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::AuctionNotFound.into())));
+}
+
+#[test]
+fn test_debt_auction_limits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let orbt = e.register_stellar_asset_contract(Address::generate(&e));
+    let shutdown = e.register_contract(None, MockShutdown);
+    let insurance = e.register_contract(None, MockInsurance);
+    let guardian = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&bidder, &50000_0000000);
+
+    let settings = DebtAuctionSettings {
+        max_debt: 10000_0000000,
+        max_lot: 100000_0000000,
+        duration: 24 * 60 * 60,
+    };
+    let invalid = [
+        DebtAuctionSettings {
+            max_debt: 0,
+            ..settings.clone()
+        },
+        DebtAuctionSettings {
+            max_lot: -1,
+            ..settings.clone()
+        },
+        DebtAuctionSettings {
+            duration: 0,
+            ..settings.clone()
+        },
+    ];
+    let auction_id = e.register_contract(None, DebtAuctionContract);
+    let auction_client = DebtAuctionClient::new(&e, &auction_id);
+    let admin = Address::generate(&e);
+    let result = auction_client.try_initialize(
+        &admin, &guardian, &shutdown, &insurance, &ousd, &orbt, &invalid[0],
+    );
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::InvalidSettings.into())));
+    auction_client.initialize(
+        &admin, &guardian, &shutdown, &insurance, &ousd, &orbt, &settings,
+    );
+    StellarAssetClient::new(&e, &orbt).set_admin(&auction_id);
+    let result = auction_client.try_initialize(
+        &admin, &guardian, &shutdown, &insurance, &ousd, &orbt, &settings,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(DebtAuctionError::AlreadyInitializedError.into()))
+    );
+    for settings in invalid {
+        let result = auction_client.try_set_settings(&settings);
+        assert_eq!(result.err(), Some(Ok(DebtAuctionError::InvalidSettings.into())));
+    }
+
+    for (debt, lot) in [(0, 1), (1, 0), (-1, 1)] {
+        let result = auction_client.try_start(&debt, &lot);
+        assert_eq!(
+            result.err(),
+            Some(Ok(DebtAuctionError::NegativeAmountError.into()))
+        );
+    }
+    let result = auction_client.try_start(&10000_0000000, &100000_0000001);
+    assert_eq!(
+        result.err(),
+        Some(Ok(DebtAuctionError::AuctionCapExceeded.into()))
+    );
+
+    // the fund is depleted once it holds less than the debt
+    StellarAssetClient::new(&e, &ousd).mint(&insurance, &1000_0000000);
+    let result = auction_client.try_start(&1000_0000000, &100000_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(DebtAuctionError::InsuranceNotDepleted.into()))
+    );
+
+    // an auction can take up both caps
+    let id = auction_client.start(&10000_0000000, &100000_0000000);
+    assert_eq!(
+        e.auths()[0],
+        (
+            guardian.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    auction_id.clone(),
+                    Symbol::new(&e, "start"),
+                    vec![
+                        &e,
+                        10000_0000000_i128.into_val(&e),
+                        100000_0000000_i128.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // open auctions keep their terms when the caps change
+    auction_client.set_settings(&DebtAuctionSettings {
+        max_debt: 1000_0000000,
+        max_lot: 1000_0000000,
+        duration: 60 * 60,
+    });
+    let auction = auction_client.get_auction(&id);
+    assert_eq!(auction.lot, 100000_0000000);
+    assert_eq!(auction.end, 1000 + 24 * 60 * 60);
+
+    // the lot stops growing once the auction ends
+    e.ledger().with_mut(|li| li.timestamp += 48 * 60 * 60);
+    assert_eq!(auction_client.get_lot(&id), 100000_0000000);
+    assert_eq!(auction_client.fill(&bidder, &id), 100000_0000000);
+    assert_eq!(ousd_client.balance(&bidder), 40000_0000000);
+    assert_eq!(ousd_client.balance(&insurance), 11000_0000000);
+    assert_eq!(TokenClient::new(&e, &orbt).balance(&bidder), 100000_0000000);
+
+    let result = auction_client.try_cancel(&id);
+    assert_eq!(result.err(), Some(Ok(DebtAuctionError::AuctionNotFound.into())));
+}
@@ -0,0 +1,211 @@
+use crate::dependencies::pool_factory::PoolFactoryClient;
+use crate::dependencies::treasury_factory::TreasuryFactoryClient;
+use crate::errors::DeployerError;
+use crate::events::DeployerEvents;
+use crate::storage::{self, DeployerHashes, Deployment, ProtocolConfig};
+use orbit_access::Role;
+use sep_41_token::StellarAssetClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, vec, Address, Bytes, BytesN, Env,
+    IntoVal, Symbol, Val, Vec,
+};
+
+#[contract]
+pub struct DeployerContract;
+
+#[contractclient(name = "DeployerClient")]
+pub trait Deployer {
+    /// Initialize the deployer
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `hashes` - The wasm hashes of the contracts the deployer instantiates
+    /// * `pool_factory` - The Address of the pool factory
+    /// * `treasury_factory` - The Address of the treasury factory
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(
+        e: Env,
+        admin: Address,
+        hashes: DeployerHashes,
+        pool_factory: Address,
+        treasury_factory: Address,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the wasm hashes of the contracts the deployer instantiates
+    ///
+    /// ### Arguments
+    /// * `hashes` - The wasm hashes
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_hashes(e: Env, hashes: DeployerHashes);
+
+    /// Get the wasm hashes of the contracts the deployer instantiates
+    fn get_hashes(e: Env) -> DeployerHashes;
+
+    /// (Admin only) Deploy the protocol for a stablecoin in a single transaction. Deploys and
+    /// initializes the admin contract and the bridge oracle, deploys a pool priced by the bridge
+    /// oracle and a treasury supplying it, and hands the admin of the stablecoin to the treasury.
+    /// Each contract is initialized in the same call that deploys it, so none can be claimed by
+    /// someone else in between.
+    ///
+    /// The owner must also administer the stablecoin and the treasury factory, so the whole
+    /// deployment is authorized at once.
+    ///
+    /// ### Arguments
+    /// * `salt` - The salt for the deployment
+    /// * `config` - The settings of the deployment
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the salt was already used
+    fn deploy(e: Env, salt: BytesN<32>, config: ProtocolConfig) -> Deployment;
+
+    /// Get the contracts of a deployment
+    ///
+    /// ### Arguments
+    /// * `salt` - The salt of the deployment
+    ///
+    /// ### Panics
+    /// If the deployment does not exist
+    fn get_deployment(e: Env, salt: BytesN<32>) -> Deployment;
+}
+
+#[contractimpl]
+impl Deployer for DeployerContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        hashes: DeployerHashes,
+        pool_factory: Address,
+        treasury_factory: Address,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, DeployerError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_hashes(&e, &hashes);
+        storage::set_pool_factory(&e, &pool_factory);
+        storage::set_treasury_factory(&e, &treasury_factory);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_hashes(e: Env, hashes: DeployerHashes) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_hashes(&e, &hashes);
+
+        DeployerEvents::set_hashes(&e, hashes);
+    }
+
+    fn get_hashes(e: Env) -> DeployerHashes {
+        storage::extend_instance(&e);
+        storage::get_hashes(&e)
+    }
+
+    fn deploy(e: Env, salt: BytesN<32>, config: ProtocolConfig) -> Deployment {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if storage::get_deployment(&e, &salt).is_some() {
+            panic_with_error!(&e, DeployerError::DeploymentExists);
+        }
+        let hashes = storage::get_hashes(&e);
+
+        let admin_contract = deploy_and_init(
+            &e,
+            &hashes.admin,
+            &derive_salt(&e, &salt, 0),
+            vec![
+                &e,
+                config.owner.into_val(&e),
+                config.ir_manager.into_val(&e),
+                config.guardian.into_val(&e),
+            ],
+        );
+        let bridge_oracle = deploy_and_init(
+            &e,
+            &hashes.bridge_oracle,
+            &derive_salt(&e, &salt, 1),
+            vec![
+                &e,
+                config.owner.into_val(&e),
+                config.guardian.into_val(&e),
+                config.token.into_val(&e),
+                config.peg_asset.into_val(&e),
+                config.oracle.into_val(&e),
+            ],
+        );
+        let pool = PoolFactoryClient::new(&e, &storage::get_pool_factory(&e)).deploy(
+            &config.owner,
+            &config.pool_name,
+            &derive_salt(&e, &salt, 2),
+            &bridge_oracle,
+            &config.backstop_take_rate,
+            &config.max_positions,
+        );
+        let treasury = TreasuryFactoryClient::new(&e, &storage::get_treasury_factory(&e))
+            .deploy(&derive_salt(&e, &salt, 3), &config.token, &pool);
+        StellarAssetClient::new(&e, &config.token).set_admin(&treasury);
+
+        let deployment = Deployment {
+            admin_contract,
+            bridge_oracle,
+            pool,
+            treasury,
+        };
+        storage::set_deployment(&e, &salt, &deployment);
+
+        DeployerEvents::deploy(&e, salt, deployment.clone());
+        deployment
+    }
+
+    fn get_deployment(e: Env, salt: BytesN<32>) -> Deployment {
+        storage::extend_instance(&e);
+        storage::get_deployment(&e, &salt)
+            .unwrap_or_else(|| panic_with_error!(&e, DeployerError::DeploymentNotFound))
+    }
+}
+
+/// Deploy a contract and initialize it in the same call
+fn deploy_and_init(
+    e: &Env,
+    wasm_hash: &BytesN<32>,
+    salt: &BytesN<32>,
+    init_args: Vec<Val>,
+) -> Address {
+    let contract_id = e
+        .deployer()
+        .with_current_contract(salt.clone())
+        .deploy(wasm_hash.clone());
+    e.invoke_contract::<Val>(&contract_id, &Symbol::new(e, "initialize"), init_args);
+    contract_id
+}
+
+/// Derive the salt of one of the contracts of a deployment
+fn derive_salt(e: &Env, salt: &BytesN<32>, index: u8) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &salt.to_array());
+    data.push_back(index);
+    e.crypto().sha256(&data)
+}
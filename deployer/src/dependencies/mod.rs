@@ -0,0 +1,2 @@
+pub mod pool_factory;
+pub mod treasury_factory;
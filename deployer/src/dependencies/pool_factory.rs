@@ -0,0 +1,16 @@
+use soroban_sdk::{contractclient, Address, BytesN, Env, Symbol};
+
+/// The part of the pool factory the deployer uses to deploy pools
+#[allow(dead_code)]
+#[contractclient(name = "PoolFactoryClient")]
+pub trait PoolFactory {
+    fn deploy(
+        e: Env,
+        admin: Address,
+        name: Symbol,
+        salt: BytesN<32>,
+        oracle: Address,
+        backstop_take_rate: u32,
+        max_positions: u32,
+    ) -> Address;
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the deployer contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Deployer specific errors start at 4000.
+pub enum DeployerError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Deployer
+    DeploymentExists = 4000,
+    DeploymentNotFound = 4001,
+}
@@ -0,0 +1,26 @@
+use orbit_events::topics;
+use soroban_sdk::{BytesN, Env, Symbol};
+
+use crate::storage::{DeployerHashes, Deployment};
+
+pub struct DeployerEvents {}
+
+impl DeployerEvents {
+    /// Emitted when the admin updates the wasm hashes
+    ///
+    /// - topics - `["set_hashes"]`
+    /// - data - `hashes: DeployerHashes`
+    pub fn set_hashes(e: &Env, hashes: DeployerHashes) {
+        let topics = (Symbol::new(e, topics::SET_HASHES),);
+        e.events().publish(topics, hashes);
+    }
+
+    /// Emitted when the protocol is deployed
+    ///
+    /// - topics - `["deploy", salt: BytesN<32>]`
+    /// - data - `deployment: Deployment`
+    pub fn deploy(e: &Env, salt: BytesN<32>, deployment: Deployment) {
+        let topics = (Symbol::new(e, topics::DEPLOY), salt);
+        e.events().publish(topics, deployment);
+    }
+}
@@ -0,0 +1,170 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, BytesN, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const HASHES_KEY: &str = "Hashes";
+const POOL_FACTORY_KEY: &str = "PoolFactory";
+const TREASURY_FACTORY_KEY: &str = "TreasuryFactory";
+
+/// The wasm hashes of the contracts the deployer instantiates directly
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DeployerHashes {
+    pub admin: BytesN<32>,         // the admin contract
+    pub bridge_oracle: BytesN<32>, // the bridge oracle
+}
+
+/// The settings of a new deployment of the protocol
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ProtocolConfig {
+    pub owner: Address,          // the owner of the admin contract, pool, bridge oracle and treasury
+    pub ir_manager: Address,     // the interest rate manager of the admin contract
+    pub guardian: Address,       // the guardian of the admin contract and bridge oracle
+    pub token: Address,          // the stellar asset contract of the stablecoin
+    pub peg_asset: Address,      // the asset the bridge oracle converts the stablecoin to
+    pub oracle: Address,         // the oracle the bridge oracle reads prices from
+    pub pool_name: Symbol,       // the name of the pool
+    pub backstop_take_rate: u32, // the backstop take rate of the pool, with 7 decimals
+    pub max_positions: u32,      // the maximum positions of a user in the pool
+}
+
+/// The contracts of a deployment of the protocol
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Deployment {
+    pub admin_contract: Address,
+    pub bridge_oracle: Address,
+    pub pool: Address,
+    pub treasury: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DeployerDataKey {
+    // The contracts of a deployment by salt
+    Deployment(BytesN<32>),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Hashes **********/
+
+/// Fetch the wasm hashes of the contracts the deployer instantiates
+///
+/// ### Panics
+/// If the hashes do not exist
+pub fn get_hashes(e: &Env) -> DeployerHashes {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, HASHES_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the wasm hashes of the contracts the deployer instantiates
+///
+/// ### Arguments
+/// * `hashes` - The wasm hashes
+pub fn set_hashes(e: &Env, hashes: &DeployerHashes) {
+    e.storage()
+        .instance()
+        .set::<Symbol, DeployerHashes>(&Symbol::new(e, HASHES_KEY), hashes);
+}
+
+/********** Factories **********/
+
+/// Fetch the pool factory Address
+///
+/// ### Panics
+/// If the pool factory does not exist
+pub fn get_pool_factory(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, POOL_FACTORY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the pool factory Address
+///
+/// ### Arguments
+/// * `pool_factory` - The Address for the pool factory
+pub fn set_pool_factory(e: &Env, pool_factory: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, POOL_FACTORY_KEY), pool_factory);
+}
+
+/// Fetch the treasury factory Address
+///
+/// ### Panics
+/// If the treasury factory does not exist
+pub fn get_treasury_factory(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TREASURY_FACTORY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the treasury factory Address
+///
+/// ### Arguments
+/// * `treasury_factory` - The Address for the treasury factory
+pub fn set_treasury_factory(e: &Env, treasury_factory: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TREASURY_FACTORY_KEY), treasury_factory);
+}
+
+/********** Deployments **********/
+
+/// Fetch the contracts of a deployment, if it exists
+///
+/// ### Arguments
+/// * `salt` - The salt of the deployment
+pub fn get_deployment(e: &Env, salt: &BytesN<32>) -> Option<Deployment> {
+    let key = DeployerDataKey::Deployment(salt.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<DeployerDataKey, Deployment>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the contracts of a deployment
+///
+/// ### Arguments
+/// * `salt` - The salt of the deployment
+/// * `deployment` - The contracts of the deployment
+pub fn set_deployment(e: &Env, salt: &BytesN<32>, deployment: &Deployment) {
+    let key = DeployerDataKey::Deployment(salt.clone());
+    e.storage()
+        .persistent()
+        .set::<DeployerDataKey, Deployment>(&key, deployment);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
@@ -0,0 +1,144 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{DeployerClient, DeployerContract};
+use crate::errors::DeployerError;
+use crate::storage::{self, DeployerHashes, Deployment, ProtocolConfig};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol};
+
+fn create_deployer(e: &Env, admin: &Address) -> DeployerClient<'static> {
+    let hashes = DeployerHashes {
+        admin: BytesN::from_array(e, &[1; 32]),
+        bridge_oracle: BytesN::from_array(e, &[2; 32]),
+    };
+    let deployer_id = e.register_contract(None, DeployerContract);
+    let deployer_client = DeployerClient::new(e, &deployer_id);
+    deployer_client.initialize(admin, &hashes, &Address::generate(e), &Address::generate(e));
+    deployer_client
+}
+
+fn default_config(e: &Env) -> ProtocolConfig {
+    ProtocolConfig {
+        owner: Address::generate(e),
+        ir_manager: Address::generate(e),
+        guardian: Address::generate(e),
+        token: Address::generate(e),
+        peg_asset: Address::generate(e),
+        oracle: Address::generate(e),
+        pool_name: Symbol::new(e, "orbit"),
+        backstop_take_rate: 0_1000000,
+        max_positions: 4,
+    }
+}
+
+#[test]
+fn test_initialize_and_set_hashes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let hashes = DeployerHashes {
+        admin: BytesN::from_array(&e, &[1; 32]),
+        bridge_oracle: BytesN::from_array(&e, &[2; 32]),
+    };
+
+    let deployer_id = e.register_contract(None, DeployerContract);
+    let deployer_client = DeployerClient::new(&e, &deployer_id);
+    deployer_client.initialize(
+        &admin,
+        &hashes,
+        &Address::generate(&e),
+        &Address::generate(&e),
+    );
+    assert_eq!(deployer_client.get_hashes(), hashes);
+
+    let result = deployer_client.try_initialize(
+        &admin,
+        &hashes,
+        &Address::generate(&e),
+        &Address::generate(&e),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(DeployerError::AlreadyInitializedError.into()))
+    );
+
+    let new_hashes = DeployerHashes {
+        admin: BytesN::from_array(&e, &[3; 32]),
+        bridge_oracle: BytesN::from_array(&e, &[4; 32]),
+    };
+    deployer_client.set_hashes(&new_hashes);
+    assert_eq!(deployer_client.get_hashes(), new_hashes);
+
+    let result = deployer_client.try_get_deployment(&BytesN::from_array(&e, &[0; 32]));
+    assert_eq!(
+        result.err(),
+        Some(Ok(DeployerError::DeploymentNotFound.into()))
+    );
+}
+
+#[test]
+fn test_admin_functions_require_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let deployer_client = create_deployer(&e, &admin);
+
+    let hashes = DeployerHashes {
+        admin: BytesN::from_array(&e, &[3; 32]),
+        bridge_oracle: BytesN::from_array(&e, &[4; 32]),
+    };
+    deployer_client.set_hashes(&hashes);
+    assert_eq!(
+        e.auths()[0],
+        (
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    deployer_client.address.clone(),
+                    Symbol::new(&e, "set_hashes"),
+                    vec![&e, hashes.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // the new admin must also sign, so the admin cannot be handed to an unusable address
+    let new_admin = Address::generate(&e);
+    deployer_client.set_admin(&new_admin);
+    let auths = e.auths();
+    assert_eq!(auths.len(), 2);
+    assert_eq!(auths[0].0, admin);
+    assert_eq!(auths[1].0, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_deploy_requires_admin() {
+    let e = Env::default();
+    let deployer_client = create_deployer(&e, &Address::generate(&e));
+
+    deployer_client.deploy(&BytesN::from_array(&e, &[5; 32]), &default_config(&e));
+}
+
+#[test]
+fn test_deploy_requires_unused_salt() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let deployer_client = create_deployer(&e, &Address::generate(&e));
+
+    let salt = BytesN::from_array(&e, &[5; 32]);
+    let deployment = Deployment {
+        admin_contract: Address::generate(&e),
+        bridge_oracle: Address::generate(&e),
+        pool: Address::generate(&e),
+        treasury: Address::generate(&e),
+    };
+    e.as_contract(&deployer_client.address, || {
+        storage::set_deployment(&e, &salt, &deployment);
+    });
+    assert_eq!(deployer_client.get_deployment(&salt), deployment);
+
+    let result = deployer_client.try_deploy(&salt, &default_config(&e));
+    assert_eq!(result.err(), Some(Ok(DeployerError::DeploymentExists.into())));
+}
@@ -0,0 +1,18 @@
+use orbit_common::pool::ReserveConfig;
+use soroban_sdk::{contracttype, Address};
+
+/// Event data for a change of the address holding a role
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct RoleUpdate {
+    pub old: Address,
+    pub new: Address,
+}
+
+/// Event data for a queued change of a reserve config
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ReserveConfigUpdate {
+    pub old: ReserveConfig,
+    pub new: ReserveConfig,
+}
@@ -0,0 +1,8 @@
+#![no_std]
+//! The events the Orbit contracts publish, shared by the contracts that publish them and the
+//! indexers and tests that read them, so both agree on each event's name and data.
+
+mod admin;
+pub mod topics;
+
+pub use admin::{ReserveConfigUpdate, RoleUpdate};
@@ -0,0 +1,103 @@
+//! The name of each event Orbit contracts publish, which is its first topic. Contracts publish
+//! with `Symbol::new(e, topics::DEPOSIT)`, and indexers and tests match events by the same
+//! names. Contracts that publish an event of the same name share its constant.
+
+pub const ACCEPT_ADMIN: &str = "accept_admin";
+pub const ACCEPT_OWNERSHIP: &str = "accept_ownership";
+pub const ACK_PRICE: &str = "ack_price";
+pub const ADD_POOL: &str = "add_pool";
+pub const ADD_REWARD_TOKEN: &str = "add_reward_token";
+pub const ADD_TASK: &str = "add_task";
+pub const ADD_TOKEN: &str = "add_token";
+pub const APPROVE: &str = "approve";
+pub const BURN: &str = "burn";
+pub const CANCEL: &str = "cancel";
+pub const CANCEL_SET_POOL_ADMIN: &str = "cancel_set_pool_admin";
+pub const CANCEL_SET_RESERVE: &str = "cancel_set_reserve";
+pub const CLAIM: &str = "claim";
+pub const CONSUME: &str = "consume";
+pub const COVER_BAD_DEBT: &str = "cover_bad_debt";
+pub const CREATE: &str = "create";
+pub const CREATE_SCHEDULE: &str = "create_schedule";
+pub const DEPLOY: &str = "deploy";
+pub const DEPOSIT: &str = "deposit";
+pub const DEVIATION_TRIPPED: &str = "deviation_tripped";
+pub const DISTRIBUTE: &str = "distribute";
+pub const EXEC: &str = "exec";
+pub const EXECUTE: &str = "execute";
+pub const FALLBACK: &str = "fallback";
+pub const FILL: &str = "fill";
+pub const FLASH_LOAN: &str = "flash_loan";
+pub const FREEZE_POOL: &str = "freeze_pool";
+pub const GRANT: &str = "grant";
+pub const INITIALIZE: &str = "initialize";
+pub const LAUNCH: &str = "launch";
+pub const LOCK: &str = "lock";
+pub const MIGRATE: &str = "migrate";
+pub const MINT: &str = "mint";
+pub const NEW_EPOCH: &str = "new_epoch";
+pub const NOTIFY_REWARD: &str = "notify_reward";
+pub const PAUSE: &str = "pause";
+pub const PRICE_UPDATE: &str = "price_update";
+pub const PROPOSE: &str = "propose";
+pub const PROPOSE_ADMIN: &str = "propose_admin";
+pub const PROPOSE_OWNERSHIP: &str = "propose_ownership";
+pub const PUSH: &str = "push";
+pub const QUEUE: &str = "queue";
+pub const QUEUE_INIT_RESERVE: &str = "queue_init_reserve";
+pub const QUEUE_SET_POOL_ADMIN: &str = "queue_set_pool_admin";
+pub const QUEUE_SET_RESERVE: &str = "queue_set_reserve";
+pub const RECLAIM: &str = "reclaim";
+pub const RECORD: &str = "record";
+pub const REDEEM: &str = "redeem";
+pub const REGISTER: &str = "register";
+pub const RELEASE: &str = "release";
+pub const REMOVE_ASSET_CONFIG: &str = "remove_asset_config";
+pub const REMOVE_POOL: &str = "remove_pool";
+pub const REMOVE_TASK: &str = "remove_task";
+pub const REMOVE_TOKEN: &str = "remove_token";
+pub const REVOKE: &str = "revoke";
+pub const ROLLBACK_RESERVE: &str = "rollback_reserve";
+pub const SET_ALLOWED: &str = "set_allowed";
+pub const SET_ALLOWLIST_ENABLED: &str = "set_allowlist_enabled";
+pub const SET_ASSET_CONFIG: &str = "set_asset_config";
+pub const SET_COLLATERAL: &str = "set_collateral";
+pub const SET_CONFIG: &str = "set_config";
+pub const SET_DELAY: &str = "set_delay";
+pub const SET_DENIED: &str = "set_denied";
+pub const SET_DESTINATIONS: &str = "set_destinations";
+pub const SET_GOVERNOR: &str = "set_governor";
+pub const SET_GUARDIAN: &str = "set_guardian";
+pub const SET_HASHES: &str = "set_hashes";
+pub const SET_IR_MANAGER: &str = "set_ir_manager";
+pub const SET_KEEPER: &str = "set_keeper";
+pub const SET_LIMIT: &str = "set_limit";
+pub const SET_MINTER: &str = "set_minter";
+pub const SET_POOL: &str = "set_pool";
+pub const SET_POOL_ADMIN: &str = "set_pool_admin";
+pub const SET_POOL_STATUS: &str = "set_pool_status";
+pub const SET_RATE: &str = "set_rate";
+pub const SET_REBATE: &str = "set_rebate";
+pub const SET_RELAYERS: &str = "set_relayers";
+pub const SET_REPORTER: &str = "set_reporter";
+pub const SET_REWARD: &str = "set_reward";
+pub const SET_REWARD_RATE: &str = "set_reward_rate";
+pub const SET_ROOT: &str = "set_root";
+pub const SET_ROUTE: &str = "set_route";
+pub const SET_SETTINGS: &str = "set_settings";
+pub const SET_TERMS: &str = "set_terms";
+pub const SET_TOKENS: &str = "set_tokens";
+pub const SETTLE: &str = "settle";
+pub const SHUTDOWN: &str = "shutdown";
+pub const STAKE: &str = "stake";
+pub const START: &str = "start";
+pub const TRANSFER: &str = "transfer";
+pub const TRIGGER: &str = "trigger";
+pub const UNPAUSE: &str = "unpause";
+pub const UNSTAKE: &str = "unstake";
+pub const UNWRAP: &str = "unwrap";
+pub const UPDATE: &str = "update";
+pub const UPGRADE: &str = "upgrade";
+pub const VOTE: &str = "vote";
+pub const WITHDRAW: &str = "withdraw";
+pub const WRAP: &str = "wrap";
@@ -0,0 +1,317 @@
+use crate::errors::FarmingError;
+use crate::events::FarmingEvents;
+use crate::storage::{self, FarmPool, UserInfo};
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+const SCALAR_12: i128 = 1_000_000_000_000;
+
+#[contract]
+pub struct FarmingContract;
+
+#[contractclient(name = "FarmingClient")]
+pub trait Farming {
+    /// Initialize the farming contract. Emissions are paid out of the contract's reward token
+    /// balance, which is funded by transferring reward tokens to the contract.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `reward_token` - The Address of the protocol token emitted as rewards
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, reward_token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Add a reward pool for an LP token
+    ///
+    /// Returns the id of the pool
+    ///
+    /// ### Arguments
+    /// * `lp_token` - The LP token staked in the pool
+    /// * `reward_rate` - The reward tokens emitted per second to the pool's stakers
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the reward rate is negative
+    fn add_pool(e: Env, lp_token: Address, reward_rate: i128) -> u32;
+
+    /// (Admin only) Set the reward rate of a pool. Rewards up to now are emitted at the old rate.
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the pool
+    /// * `reward_rate` - The reward tokens emitted per second to the pool's stakers
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the pool does not exist or the reward rate is negative
+    fn set_reward_rate(e: Env, id: u32, reward_rate: i128);
+
+    /// Get a reward pool
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the pool
+    ///
+    /// ### Panics
+    /// If the pool does not exist
+    fn get_pool(e: Env, id: u32) -> FarmPool;
+
+    /// Get the number of reward pools
+    fn get_pool_count(e: Env) -> u32;
+
+    /// Get the position of a staker in a reward pool
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the pool
+    /// * `user` - The Address of the staker
+    fn get_user(e: Env, id: u32, user: Address) -> UserInfo;
+
+    /// Get the rewards of a staker in a reward pool that have not been claimed
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the pool
+    /// * `user` - The Address of the staker
+    ///
+    /// ### Panics
+    /// If the pool does not exist
+    fn get_claimable(e: Env, id: u32, user: Address) -> i128;
+
+    /// Deposit LP tokens into a reward pool
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `id` - The id of the pool
+    /// * `amount` - The amount of LP tokens to deposit
+    ///
+    /// ### Panics
+    /// If the pool does not exist
+    fn deposit(e: Env, from: Address, id: u32, amount: i128);
+
+    /// Withdraw LP tokens from a reward pool. Accrued rewards stay claimable.
+    ///
+    /// ### Arguments
+    /// * `from` - The Address withdrawing
+    /// * `id` - The id of the pool
+    /// * `amount` - The amount of LP tokens to withdraw
+    ///
+    /// ### Panics
+    /// If the pool does not exist
+    /// If `from` has deposited less than `amount`
+    fn withdraw(e: Env, from: Address, id: u32, amount: i128);
+
+    /// Claim the rewards of a reward pool
+    ///
+    /// Returns the amount of reward tokens claimed
+    ///
+    /// ### Arguments
+    /// * `from` - The Address claiming
+    /// * `id` - The id of the pool
+    ///
+    /// ### Panics
+    /// If the pool does not exist
+    /// If the contract does not hold enough reward tokens
+    fn claim(e: Env, from: Address, id: u32) -> i128;
+}
+
+#[contractimpl]
+impl Farming for FarmingContract {
+    fn initialize(e: Env, admin: Address, reward_token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, FarmingError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_reward_token(&e, &reward_token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn add_pool(e: Env, lp_token: Address, reward_rate: i128) -> u32 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if reward_rate < 0 {
+            panic_with_error!(&e, FarmingError::NegativeAmountError);
+        }
+
+        let id = storage::get_pool_count(&e);
+        storage::set_pool(
+            &e,
+            id,
+            &FarmPool {
+                lp_token: lp_token.clone(),
+                reward_rate,
+                total_staked: 0,
+                index: 0,
+                last_update: e.ledger().timestamp(),
+            },
+        );
+        storage::set_pool_count(&e, id + 1);
+
+        FarmingEvents::add_pool(&e, id, lp_token, reward_rate);
+        id
+    }
+
+    fn set_reward_rate(e: Env, id: u32, reward_rate: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if reward_rate < 0 {
+            panic_with_error!(&e, FarmingError::NegativeAmountError);
+        }
+
+        let mut pool = load_pool(&e, id);
+        update_pool(&e, &mut pool);
+        pool.reward_rate = reward_rate;
+        storage::set_pool(&e, id, &pool);
+
+        FarmingEvents::set_reward_rate(&e, id, reward_rate);
+    }
+
+    fn get_pool(e: Env, id: u32) -> FarmPool {
+        storage::extend_instance(&e);
+        load_pool(&e, id)
+    }
+
+    fn get_pool_count(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_pool_count(&e)
+    }
+
+    fn get_user(e: Env, id: u32, user: Address) -> UserInfo {
+        storage::extend_instance(&e);
+        storage::get_user(&e, id, &user)
+    }
+
+    fn get_claimable(e: Env, id: u32, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        let mut pool = load_pool(&e, id);
+        update_pool(&e, &mut pool);
+        let mut user_info = storage::get_user(&e, id, &user);
+        accrue_user(&pool, &mut user_info);
+        user_info.accrued
+    }
+
+    fn deposit(e: Env, from: Address, id: u32, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        let mut pool = load_pool(&e, id);
+        update_pool(&e, &mut pool);
+        let mut user_info = storage::get_user(&e, id, &from);
+        accrue_user(&pool, &mut user_info);
+
+        TokenClient::new(&e, &pool.lp_token).transfer(
+            &from,
+            &e.current_contract_address(),
+            &amount,
+        );
+        user_info.amount += amount;
+        pool.total_staked += amount;
+        storage::set_user(&e, id, &from, &user_info);
+        storage::set_pool(&e, id, &pool);
+
+        FarmingEvents::deposit(&e, id, from, amount);
+    }
+
+    fn withdraw(e: Env, from: Address, id: u32, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        let mut pool = load_pool(&e, id);
+        update_pool(&e, &mut pool);
+        let mut user_info = storage::get_user(&e, id, &from);
+        if user_info.amount < amount {
+            panic_with_error!(&e, FarmingError::InsufficientBalance);
+        }
+        accrue_user(&pool, &mut user_info);
+
+        user_info.amount -= amount;
+        pool.total_staked -= amount;
+        storage::set_user(&e, id, &from, &user_info);
+        storage::set_pool(&e, id, &pool);
+        TokenClient::new(&e, &pool.lp_token).transfer(
+            &e.current_contract_address(),
+            &from,
+            &amount,
+        );
+
+        FarmingEvents::withdraw(&e, id, from, amount);
+    }
+
+    fn claim(e: Env, from: Address, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let mut pool = load_pool(&e, id);
+        update_pool(&e, &mut pool);
+        let mut user_info = storage::get_user(&e, id, &from);
+        accrue_user(&pool, &mut user_info);
+
+        let amount = user_info.accrued;
+        if amount > 0 {
+            let reward_client = TokenClient::new(&e, &storage::get_reward_token(&e));
+            if reward_client.balance(&e.current_contract_address()) < amount {
+                panic_with_error!(&e, FarmingError::InsufficientRewards);
+            }
+            reward_client.transfer(&e.current_contract_address(), &from, &amount);
+            user_info.accrued = 0;
+        }
+        storage::set_user(&e, id, &from, &user_info);
+        storage::set_pool(&e, id, &pool);
+
+        FarmingEvents::claim(&e, id, from, amount);
+        amount
+    }
+}
+
+/// Load a reward pool
+///
+/// ### Panics
+/// If the pool does not exist
+fn load_pool(e: &Env, id: u32) -> FarmPool {
+    storage::get_pool(e, id).unwrap_or_else(|| panic_with_error!(e, FarmingError::PoolNotFound))
+}
+
+/// Emit the rewards of a pool up to now. Nothing is emitted while nothing is staked.
+fn update_pool(e: &Env, pool: &mut FarmPool) {
+    let now = e.ledger().timestamp();
+    if pool.total_staked > 0 && now > pool.last_update {
+        let emitted = pool.reward_rate * (now - pool.last_update) as i128;
+        pool.index += emitted * SCALAR_12 / pool.total_staked;
+    }
+    pool.last_update = now;
+}
+
+/// Accrue the rewards emitted to a staker since their last update
+fn accrue_user(pool: &FarmPool, user_info: &mut UserInfo) {
+    user_info.accrued += user_info.amount * (pool.index - user_info.index) / SCALAR_12;
+    user_info.index = pool.index;
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, FarmingError::NegativeAmountError);
+    }
+}
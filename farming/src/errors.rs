@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the farming contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Farming specific errors start at 3000.
+pub enum FarmingError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Farming
+    PoolNotFound = 3000,
+    InsufficientBalance = 3001,
+    InsufficientRewards = 3002,
+}
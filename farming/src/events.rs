@@ -0,0 +1,51 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct FarmingEvents {}
+
+impl FarmingEvents {
+    /// Emitted when the admin adds a reward pool
+    ///
+    /// - topics - `["add_pool", id: u32]`
+    /// - data - `[lp_token: Address, reward_rate: i128]`
+    pub fn add_pool(e: &Env, id: u32, lp_token: Address, reward_rate: i128) {
+        let topics = (Symbol::new(e, topics::ADD_POOL), id);
+        e.events().publish(topics, (lp_token, reward_rate));
+    }
+
+    /// Emitted when the admin updates the reward rate of a pool
+    ///
+    /// - topics - `["set_reward_rate", id: u32]`
+    /// - data - `reward_rate: i128`
+    pub fn set_reward_rate(e: &Env, id: u32, reward_rate: i128) {
+        let topics = (Symbol::new(e, topics::SET_REWARD_RATE), id);
+        e.events().publish(topics, reward_rate);
+    }
+
+    /// Emitted when LP tokens are deposited into a pool
+    ///
+    /// - topics - `["deposit", id: u32, from: Address]`
+    /// - data - `amount: i128`
+    pub fn deposit(e: &Env, id: u32, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), id, from);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when LP tokens are withdrawn from a pool
+    ///
+    /// - topics - `["withdraw", id: u32, from: Address]`
+    /// - data - `amount: i128`
+    pub fn withdraw(e: &Env, id: u32, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), id, from);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a staker claims the rewards of a pool
+    ///
+    /// - topics - `["claim", id: u32, from: Address]`
+    /// - data - `amount: i128`
+    pub fn claim(e: &Env, id: u32, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::CLAIM), id, from);
+        e.events().publish(topics, amount);
+    }
+}
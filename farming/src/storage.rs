@@ -0,0 +1,177 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const REWARD_TOKEN_KEY: &str = "RewardToken";
+const POOL_COUNT_KEY: &str = "PoolCount";
+
+/// A reward pool emitting the reward token to stakers of an LP token
+#[derive(Clone)]
+#[contracttype]
+pub struct FarmPool {
+    pub lp_token: Address,
+    pub reward_rate: i128,  // the reward tokens emitted per second
+    pub total_staked: i128, // the LP tokens staked
+    pub index: i128,        // the rewards emitted per staked LP token, with 12 decimals
+    pub last_update: u64,   // the time `index` was last updated
+}
+
+/// The position of a staker in a reward pool
+#[derive(Clone)]
+#[contracttype]
+pub struct UserInfo {
+    pub amount: i128,  // the LP tokens staked
+    pub index: i128,   // the pool index the staker last accrued at
+    pub accrued: i128, // the rewards accrued and not yet claimed
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum FarmingDataKey {
+    // A reward pool by id
+    Pool(u32),
+    // The position of a staker in a reward pool
+    User(u32, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Reward Token **********/
+
+/// Fetch the Address of the reward token
+///
+/// ### Panics
+/// If the reward token does not exist
+pub fn get_reward_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REWARD_TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the reward token
+///
+/// ### Arguments
+/// * `token` - The Address of the reward token
+pub fn set_reward_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, REWARD_TOKEN_KEY), token);
+}
+
+/********** Pools **********/
+
+/// Fetch the number of reward pools, which is also the id of the next pool
+pub fn get_pool_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, POOL_COUNT_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of reward pools
+///
+/// ### Arguments
+/// * `count` - The number of reward pools
+pub fn set_pool_count(e: &Env, count: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, POOL_COUNT_KEY), &count);
+}
+
+/// Fetch a reward pool, if it exists
+///
+/// ### Arguments
+/// * `id` - The id of the pool
+pub fn get_pool(e: &Env, id: u32) -> Option<FarmPool> {
+    let key = FarmingDataKey::Pool(id);
+    let result = e
+        .storage()
+        .persistent()
+        .get::<FarmingDataKey, FarmPool>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set a reward pool
+///
+/// ### Arguments
+/// * `id` - The id of the pool
+/// * `pool` - The reward pool
+pub fn set_pool(e: &Env, id: u32, pool: &FarmPool) {
+    let key = FarmingDataKey::Pool(id);
+    e.storage()
+        .persistent()
+        .set::<FarmingDataKey, FarmPool>(&key, pool);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the position of a staker in a reward pool
+///
+/// ### Arguments
+/// * `id` - The id of the pool
+/// * `user` - The Address of the staker
+pub fn get_user(e: &Env, id: u32, user: &Address) -> UserInfo {
+    let key = FarmingDataKey::User(id, user.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<FarmingDataKey, UserInfo>(&key);
+    match result {
+        Some(info) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            info
+        }
+        None => UserInfo {
+            amount: 0,
+            index: 0,
+            accrued: 0,
+        },
+    }
+}
+
+/// Set the position of a staker in a reward pool
+///
+/// ### Arguments
+/// * `id` - The id of the pool
+/// * `user` - The Address of the staker
+/// * `info` - The position of the staker
+pub fn set_user(e: &Env, id: u32, user: &Address, info: &UserInfo) {
+    let key = FarmingDataKey::User(id, user.clone());
+    e.storage()
+        .persistent()
+        .set::<FarmingDataKey, UserInfo>(&key, info);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
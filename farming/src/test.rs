@@ -0,0 +1,82 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{FarmingClient, FarmingContract};
+use crate::errors::FarmingError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_farming() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let token_admin = Address::generate(&e);
+    let reward_token = e.register_stellar_asset_contract(token_admin.clone());
+    let lp_ousd_usdc = e.register_stellar_asset_contract(token_admin.clone());
+    let lp_ousd_xlm = e.register_stellar_asset_contract(token_admin);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    StellarAssetClient::new(&e, &lp_ousd_usdc).mint(&samwise, &100_0000000);
+    StellarAssetClient::new(&e, &lp_ousd_usdc).mint(&frodo, &300_0000000);
+    StellarAssetClient::new(&e, &lp_ousd_xlm).mint(&frodo, &50_0000000);
+
+    let farming_id = e.register_contract(None, FarmingContract);
+    let farming_client = FarmingClient::new(&e, &farming_id);
+    farming_client.initialize(&Address::generate(&e), &reward_token);
+    let pool_0 = farming_client.add_pool(&lp_ousd_usdc, &1_0000000);
+    let pool_1 = farming_client.add_pool(&lp_ousd_xlm, &0_5000000);
+    assert_eq!(farming_client.get_pool_count(), 2);
+    StellarAssetClient::new(&e, &reward_token).mint(&farming_id, &1000_0000000);
+
+    farming_client.deposit(&samwise, &pool_0, &100_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 100);
+    farming_client.deposit(&frodo, &pool_0, &300_0000000);
+    farming_client.deposit(&frodo, &pool_1, &50_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 100);
+
+    // samwise earns all of the first 100 seconds and a quarter of the next 100
+    assert_eq!(farming_client.get_claimable(&pool_0, &samwise), 125_0000000);
+    assert_eq!(farming_client.get_claimable(&pool_0, &frodo), 75_0000000);
+    assert_eq!(farming_client.get_claimable(&pool_1, &frodo), 50_0000000);
+
+    farming_client.withdraw(&samwise, &pool_0, &100_0000000);
+    assert_eq!(TokenClient::new(&e, &lp_ousd_usdc).balance(&samwise), 100_0000000);
+    let result = farming_client.try_withdraw(&samwise, &pool_0, &1);
+    assert_eq!(result.err(), Some(Ok(FarmingError::InsufficientBalance.into())));
+
+    farming_client.set_reward_rate(&pool_0, &2_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 100);
+    assert_eq!(farming_client.claim(&samwise, &pool_0), 125_0000000);
+    // the reward index rounds down
+    assert_eq!(farming_client.claim(&frodo, &pool_0), 274_9999999);
+    assert_eq!(farming_client.claim(&frodo, &pool_1), 100_0000000);
+    assert_eq!(TokenClient::new(&e, &reward_token).balance(&frodo), 374_9999999);
+    assert_eq!(farming_client.claim(&frodo, &pool_1), 0);
+
+    let result = farming_client.try_deposit(&frodo, &2, &1);
+    assert_eq!(result.err(), Some(Ok(FarmingError::PoolNotFound.into())));
+}
+
+#[test]
+fn test_claim_requires_funding() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token_admin = Address::generate(&e);
+    let reward_token = e.register_stellar_asset_contract(token_admin.clone());
+    let lp_token = e.register_stellar_asset_contract(token_admin);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &lp_token).mint(&samwise, &100_0000000);
+
+    let farming_id = e.register_contract(None, FarmingContract);
+    let farming_client = FarmingClient::new(&e, &farming_id);
+    farming_client.initialize(&Address::generate(&e), &reward_token);
+    let pool = farming_client.add_pool(&lp_token, &1_0000000);
+    farming_client.deposit(&samwise, &pool, &100_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 10);
+
+    let result = farming_client.try_claim(&samwise, &pool);
+    assert_eq!(result.err(), Some(Ok(FarmingError::InsufficientRewards.into())));
+    StellarAssetClient::new(&e, &reward_token).mint(&farming_id, &10_0000000);
+    assert_eq!(farming_client.claim(&samwise, &pool), 10_0000000);
+}
@@ -0,0 +1,268 @@
+use crate::errors::FeeDistributorError;
+use crate::events::FeeDistributorEvents;
+use crate::storage::{self, Destination, DestinationKind, Epoch};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::TokenClient;
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, IntoVal, Symbol,
+    Val, Vec,
+};
+
+#[contract]
+pub struct FeeDistributorContract;
+
+#[contractclient(name = "FeeDistributorClient")]
+pub trait FeeDistributor {
+    /// Initialize the fee distributor. Protocol revenue is received by transferring it to the
+    /// contract.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `tokens` - The revenue tokens to distribute
+    /// * `destinations` - The revenue destinations
+    /// * `epoch_length` - The minimum time between distributions, in seconds
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the destinations are invalid or the epoch length is zero
+    fn initialize(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        destinations: Vec<Destination>,
+        epoch_length: u64,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the revenue tokens to distribute
+    ///
+    /// ### Arguments
+    /// * `tokens` - The revenue token addresses
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_tokens(e: Env, tokens: Vec<Address>);
+
+    /// Get the revenue tokens to distribute
+    fn get_tokens(e: Env) -> Vec<Address>;
+
+    /// (Admin only) Set the revenue destinations, like the stakers, the insurance fund, a
+    /// buyback module or the DAO treasury
+    ///
+    /// ### Arguments
+    /// * `destinations` - The revenue destinations
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If there are no destinations or the weights sum to zero
+    fn set_destinations(e: Env, destinations: Vec<Destination>);
+
+    /// Get the revenue destinations
+    fn get_destinations(e: Env) -> Vec<Destination>;
+
+    /// Get the last distribution
+    fn get_epoch(e: Env) -> Epoch;
+
+    /// Get the amount of a token distributed in an epoch
+    ///
+    /// ### Arguments
+    /// * `epoch` - The epoch number
+    /// * `token` - The token
+    fn get_distributed(e: Env, epoch: u32, token: Address) -> i128;
+
+    /// Distribute the contract's balance of each revenue token across the destinations by
+    /// weight, and start a new epoch. Anyone can call this once per epoch.
+    ///
+    /// Returns the amount of each revenue token distributed
+    ///
+    /// ### Panics
+    /// If the epoch length has not passed since the last distribution
+    fn distribute(e: Env) -> Vec<(Address, i128)>;
+}
+
+#[contractimpl]
+impl FeeDistributor for FeeDistributorContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        destinations: Vec<Destination>,
+        epoch_length: u64,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, FeeDistributorError::AlreadyInitializedError);
+        }
+        require_valid_destinations(&e, &destinations);
+        if epoch_length == 0 {
+            panic_with_error!(&e, FeeDistributorError::InvalidConfig);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_tokens(&e, &tokens);
+        storage::set_destinations(&e, &destinations);
+        storage::set_epoch_length(&e, epoch_length);
+        storage::set_epoch(
+            &e,
+            &Epoch {
+                number: 0,
+                timestamp: e.ledger().timestamp(),
+            },
+        );
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_tokens(e: Env, tokens: Vec<Address>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_tokens(&e, &tokens);
+
+        FeeDistributorEvents::set_tokens(&e, tokens);
+    }
+
+    fn get_tokens(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_tokens(&e)
+    }
+
+    fn set_destinations(e: Env, destinations: Vec<Destination>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_destinations(&e, &destinations);
+
+        storage::set_destinations(&e, &destinations);
+
+        FeeDistributorEvents::set_destinations(&e, destinations);
+    }
+
+    fn get_destinations(e: Env) -> Vec<Destination> {
+        storage::extend_instance(&e);
+        storage::get_destinations(&e)
+    }
+
+    fn get_epoch(e: Env) -> Epoch {
+        storage::extend_instance(&e);
+        storage::get_epoch(&e)
+    }
+
+    fn get_distributed(e: Env, epoch: u32, token: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_distributed(&e, epoch, &token)
+    }
+
+    fn distribute(e: Env) -> Vec<(Address, i128)> {
+        storage::extend_instance(&e);
+
+        let mut epoch = storage::get_epoch(&e);
+        if e.ledger().timestamp() < epoch.timestamp + storage::get_epoch_length(&e) {
+            panic_with_error!(&e, FeeDistributorError::EpochNotEnded);
+        }
+        epoch.number += 1;
+        epoch.timestamp = e.ledger().timestamp();
+        storage::set_epoch(&e, &epoch);
+
+        let destinations = storage::get_destinations(&e);
+        let total_weight: i128 = destinations.iter().map(|d| d.weight as i128).sum();
+        let mut distributed = vec![&e];
+        for token in storage::get_tokens(&e).iter() {
+            let balance = TokenClient::new(&e, &token).balance(&e.current_contract_address());
+            if balance <= 0 {
+                continue;
+            }
+            let mut remaining = balance;
+            for (i, destination) in destinations.iter().enumerate() {
+                // the last destination receives the rounding remainder
+                let amount = if i as u32 == destinations.len() - 1 {
+                    remaining
+                } else {
+                    math::mul_div_floor(&e, balance, destination.weight as i128, total_weight)
+                };
+                if amount > 0 {
+                    send(&e, &token, &destination, amount);
+                    remaining -= amount;
+                    FeeDistributorEvents::distribute(
+                        &e,
+                        epoch.number,
+                        token.clone(),
+                        destination.address,
+                        amount,
+                    );
+                }
+            }
+            storage::set_distributed(&e, epoch.number, &token, balance);
+            distributed.push_back((token, balance));
+        }
+        distributed
+    }
+}
+
+/// Send revenue to a destination
+fn send(e: &Env, token: &Address, destination: &Destination, amount: i128) {
+    let function = match destination.kind {
+        DestinationKind::Transfer => {
+            TokenClient::new(e, token).transfer(
+                &e.current_contract_address(),
+                &destination.address,
+                &amount,
+            );
+            return;
+        }
+        DestinationKind::Staking => Symbol::new(e, "notify_reward"),
+        DestinationKind::Insurance => Symbol::new(e, "deposit"),
+    };
+
+    // the destination pulls the tokens from this contract
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: vec![
+                    e,
+                    e.current_contract_address().into_val(e),
+                    destination.address.into_val(e),
+                    amount.into_val(e),
+                ],
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+    let args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e),
+        token.into_val(e),
+        amount.into_val(e),
+    ];
+    e.invoke_contract::<Val>(&destination.address, &function, args);
+}
+
+/// Require that the revenue destinations are valid
+///
+/// ### Panics
+/// If there are no destinations or the weights sum to zero
+fn require_valid_destinations(e: &Env, destinations: &Vec<Destination>) {
+    let total_weight: u64 = destinations.iter().map(|d| d.weight as u64).sum();
+    if total_weight == 0 {
+        panic_with_error!(e, FeeDistributorError::InvalidDestinations);
+    }
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the fee distributor contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Fee distributor specific errors start at 2800.
+pub enum FeeDistributorError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Fee Distributor
+    EpochNotEnded = 2800,
+    InvalidDestinations = 2801,
+    InvalidConfig = 2802,
+}
@@ -0,0 +1,35 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::Destination;
+
+pub struct FeeDistributorEvents {}
+
+impl FeeDistributorEvents {
+    /// Emitted when the admin updates the revenue tokens
+    ///
+    /// - topics - `["set_tokens"]`
+    /// - data - `tokens: Vec<Address>`
+    pub fn set_tokens(e: &Env, tokens: Vec<Address>) {
+        let topics = (Symbol::new(e, topics::SET_TOKENS),);
+        e.events().publish(topics, tokens);
+    }
+
+    /// Emitted when the admin updates the revenue destinations
+    ///
+    /// - topics - `["set_destinations"]`
+    /// - data - `destinations: Vec<Destination>`
+    pub fn set_destinations(e: &Env, destinations: Vec<Destination>) {
+        let topics = (Symbol::new(e, topics::SET_DESTINATIONS),);
+        e.events().publish(topics, destinations);
+    }
+
+    /// Emitted when revenue in a token is sent to a destination
+    ///
+    /// - topics - `["distribute", epoch: u32, token: Address]`
+    /// - data - `[destination: Address, amount: i128]`
+    pub fn distribute(e: &Env, epoch: u32, token: Address, destination: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::DISTRIBUTE), epoch, token);
+        e.events().publish(topics, (destination, amount));
+    }
+}
@@ -0,0 +1,188 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKENS_KEY: &str = "Tokens";
+const DESTINATIONS_KEY: &str = "Destinations";
+const EPOCH_LENGTH_KEY: &str = "EpochLength";
+const EPOCH_KEY: &str = "Epoch";
+
+/// How revenue is delivered to a destination
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum DestinationKind {
+    Transfer,  // transfer the tokens to the destination
+    Staking,   // send the tokens through the destination's `notify_reward(from, token, amount)`
+    Insurance, // send the tokens through the destination's `deposit(from, token, amount)`
+}
+
+/// A destination of protocol revenue
+#[derive(Clone)]
+#[contracttype]
+pub struct Destination {
+    pub address: Address,
+    pub kind: DestinationKind,
+    pub weight: u32, // the share of revenue relative to the weights of all destinations
+}
+
+/// The last distribution
+#[derive(Clone)]
+#[contracttype]
+pub struct Epoch {
+    pub number: u32,    // the number of distributions made
+    pub timestamp: u64, // the time of the last distribution
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum FeeDistributorDataKey {
+    // The amount of a token distributed in an epoch
+    Distributed(u32, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Settings **********/
+
+/// Fetch the revenue tokens
+///
+/// ### Panics
+/// If the tokens do not exist
+pub fn get_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKENS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the revenue tokens
+///
+/// ### Arguments
+/// * `tokens` - The revenue token addresses
+pub fn set_tokens(e: &Env, tokens: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, TOKENS_KEY), tokens);
+}
+
+/// Fetch the revenue destinations
+///
+/// ### Panics
+/// If the destinations do not exist
+pub fn get_destinations(e: &Env) -> Vec<Destination> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, DESTINATIONS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the revenue destinations
+///
+/// ### Arguments
+/// * `destinations` - The revenue destinations
+pub fn set_destinations(e: &Env, destinations: &Vec<Destination>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Destination>>(&Symbol::new(e, DESTINATIONS_KEY), destinations);
+}
+
+/// Fetch the minimum time between distributions, in seconds
+///
+/// ### Panics
+/// If the epoch length does not exist
+pub fn get_epoch_length(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, EPOCH_LENGTH_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the minimum time between distributions
+///
+/// ### Arguments
+/// * `epoch_length` - The minimum time between distributions, in seconds
+pub fn set_epoch_length(e: &Env, epoch_length: u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, EPOCH_LENGTH_KEY), &epoch_length);
+}
+
+/********** Epochs **********/
+
+/// Fetch the last distribution
+///
+/// ### Panics
+/// If the epoch does not exist
+pub fn get_epoch(e: &Env) -> Epoch {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, EPOCH_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the last distribution
+///
+/// ### Arguments
+/// * `epoch` - The last distribution
+pub fn set_epoch(e: &Env, epoch: &Epoch) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Epoch>(&Symbol::new(e, EPOCH_KEY), epoch);
+}
+
+/// Fetch the amount of a token distributed in an epoch
+///
+/// ### Arguments
+/// * `epoch` - The epoch number
+/// * `token` - The Address of the token
+pub fn get_distributed(e: &Env, epoch: u32, token: &Address) -> i128 {
+    let key = FeeDistributorDataKey::Distributed(epoch, token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<FeeDistributorDataKey, i128>(&key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Set the amount of a token distributed in an epoch
+///
+/// ### Arguments
+/// * `epoch` - The epoch number
+/// * `token` - The Address of the token
+/// * `amount` - The amount distributed
+pub fn set_distributed(e: &Env, epoch: u32, token: &Address, amount: i128) {
+    let key = FeeDistributorDataKey::Distributed(epoch, token.clone());
+    e.storage()
+        .persistent()
+        .set::<FeeDistributorDataKey, i128>(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
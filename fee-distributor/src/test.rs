@@ -0,0 +1,121 @@
+#![cfg(test)]
+use crate::contract::{FeeDistributorClient, FeeDistributorContract};
+use crate::errors::FeeDistributorError;
+use crate::storage::{Destination, DestinationKind};
+use sep_41_token::TokenClient as Sep41TokenClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, Symbol};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+/// Records the rewards sent through `notify_reward`, like the staking contract
+#[contract]
+pub struct MockStaking;
+
+#[contractimpl]
+impl MockStaking {
+    pub fn notify_reward(e: Env, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+        Sep41TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "notified"), &amount);
+    }
+
+    pub fn notified(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "notified"))
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_distribute() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let token_admin = Address::generate(&e);
+    let ousd = e.register_stellar_asset_contract(token_admin.clone());
+    let usdc = e.register_stellar_asset_contract(token_admin);
+    let staking = e.register_contract(None, MockStaking);
+    let treasury = Address::generate(&e);
+    let buyback = Address::generate(&e);
+
+    let distributor_id = e.register_contract(None, FeeDistributorContract);
+    let distributor_client = FeeDistributorClient::new(&e, &distributor_id);
+    distributor_client.initialize(
+        &Address::generate(&e),
+        &vec![&e, ousd.clone(), usdc.clone()],
+        &vec![
+            &e,
+            Destination {
+                address: staking.clone(),
+                kind: DestinationKind::Staking,
+                weight: 5,
+            },
+            Destination {
+                address: buyback.clone(),
+                kind: DestinationKind::Transfer,
+                weight: 2,
+            },
+            Destination {
+                address: treasury.clone(),
+                kind: DestinationKind::Transfer,
+                weight: 3,
+            },
+        ],
+        &WEEK,
+    );
+    StellarAssetClient::new(&e, &ousd).mint(&distributor_id, &100_0000001);
+
+    let result = distributor_client.try_distribute();
+    assert_eq!(result.err(), Some(Ok(FeeDistributorError::EpochNotEnded.into())));
+
+    // distribute without mocked auths, so the staking destination relies on the distributor
+    // authorizing its own transfer
+    e.set_auths(&[]);
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    let distributed = distributor_client.distribute();
+    assert_eq!(distributed, vec![&e, (ousd.clone(), 100_0000001)]);
+    let ousd_client = TokenClient::new(&e, &ousd);
+    assert_eq!(ousd_client.balance(&staking), 50_0000000);
+    assert_eq!(MockStakingClient::new(&e, &staking).notified(), 50_0000000);
+    assert_eq!(ousd_client.balance(&buyback), 20_0000000);
+    assert_eq!(ousd_client.balance(&treasury), 30_0000001);
+    assert_eq!(ousd_client.balance(&distributor_id), 0);
+
+    let epoch = distributor_client.get_epoch();
+    assert_eq!(epoch.number, 1);
+    assert_eq!(epoch.timestamp, 1000 + WEEK);
+    assert_eq!(distributor_client.get_distributed(&1, &ousd), 100_0000001);
+    assert_eq!(distributor_client.get_distributed(&1, &usdc), 0);
+
+    let result = distributor_client.try_distribute();
+    assert_eq!(result.err(), Some(Ok(FeeDistributorError::EpochNotEnded.into())));
+}
+
+#[test]
+fn test_set_destinations_validates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let distributor_id = e.register_contract(None, FeeDistributorContract);
+    let distributor_client = FeeDistributorClient::new(&e, &distributor_id);
+    let destinations = vec![
+        &e,
+        Destination {
+            address: Address::generate(&e),
+            kind: DestinationKind::Transfer,
+            weight: 1,
+        },
+    ];
+    distributor_client.initialize(&Address::generate(&e), &vec![&e], &destinations, &WEEK);
+
+    let result = distributor_client.try_set_destinations(&vec![&e]);
+    assert_eq!(result.err(), Some(Ok(FeeDistributorError::InvalidDestinations.into())));
+    let mut zero_weight = destinations.get_unchecked(0);
+    zero_weight.weight = 0;
+    let result = distributor_client.try_set_destinations(&vec![&e, zero_weight]);
+    assert_eq!(result.err(), Some(Ok(FeeDistributorError::InvalidDestinations.into())));
+}
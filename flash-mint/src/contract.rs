@@ -0,0 +1,216 @@
+use crate::dependencies::receiver::FlashReceiverClient;
+use crate::dependencies::treasury::TreasuryClient;
+use crate::errors::FlashMintError;
+use crate::events::FlashMintEvents;
+use crate::storage;
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Bytes, Env};
+
+const MAX_BPS: u32 = 10000;
+
+#[contract]
+pub struct FlashMintContract;
+
+#[contractclient(name = "FlashMintClient")]
+pub trait FlashMint {
+    /// Initialize the flash mint. The contract must be set as the flash minter of the treasury.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `token` - The Address of the token lent
+    /// * `treasury` - The Address of the treasury minting the loans
+    /// * `fee_distributor` - The Address of the fee distributor receiving the fees
+    /// * `fee` - The flash loan fee, in basis points
+    /// * `max_flash_loan` - The largest flash loan
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the fee is greater than 100% or the ceiling is negative
+    fn initialize(
+        e: Env,
+        admin: Address,
+        token: Address,
+        treasury: Address,
+        fee_distributor: Address,
+        fee: u32,
+        max_flash_loan: i128,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the flash loan fee and the largest flash loan
+    ///
+    /// ### Arguments
+    /// * `fee` - The flash loan fee, in basis points
+    /// * `max_flash_loan` - The largest flash loan
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the fee is greater than 100% or the ceiling is negative
+    fn set_terms(e: Env, fee: u32, max_flash_loan: i128);
+
+    /// Get the token lent
+    fn get_token(e: Env) -> Address;
+
+    /// Get the largest flash loan
+    fn max_flash_loan(e: Env) -> i128;
+
+    /// Get the fee owed on a flash loan
+    ///
+    /// ### Arguments
+    /// * `amount` - The amount borrowed
+    fn flash_fee(e: Env, amount: i128) -> i128;
+
+    /// Borrow freshly minted tokens for the rest of the transaction. The tokens are sent to
+    /// `receiver` and its `on_flash_loan` is called, which must send the loan plus the fee back
+    /// to this contract. The loan is burned and the fee is sent to the fee distributor.
+    ///
+    /// Returns the fee paid, including anything repaid beyond it
+    ///
+    /// ### Arguments
+    /// * `initiator` - The Address requesting the loan
+    /// * `receiver` - The Address of the contract receiving the loan
+    /// * `amount` - The amount to borrow
+    /// * `data` - Arbitrary data passed through to the receiver
+    ///
+    /// ### Panics
+    /// If a loan is already out. The host refuses to re-enter this contract from the receiver,
+    /// and a second loan taken while the lock is still held fails with `ReentrantCallError`
+    /// If `amount` is zero or negative, or greater than the largest flash loan
+    /// If the loan plus the fee is not repaid
+    fn flash_loan(e: Env, initiator: Address, receiver: Address, amount: i128, data: Bytes) -> i128;
+}
+
+#[contractimpl]
+impl FlashMint for FlashMintContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        token: Address,
+        treasury: Address,
+        fee_distributor: Address,
+        fee: u32,
+        max_flash_loan: i128,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, FlashMintError::AlreadyInitializedError);
+        }
+        require_valid_terms(&e, fee, max_flash_loan);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_treasury(&e, &treasury);
+        storage::set_fee_distributor(&e, &fee_distributor);
+        storage::set_fee(&e, fee);
+        storage::set_max_flash_loan(&e, max_flash_loan);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_terms(e: Env, fee: u32, max_flash_loan: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_terms(&e, fee, max_flash_loan);
+
+        storage::set_fee(&e, fee);
+        storage::set_max_flash_loan(&e, max_flash_loan);
+
+        FlashMintEvents::set_terms(&e, fee, max_flash_loan);
+    }
+
+    fn get_token(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_token(&e)
+    }
+
+    fn max_flash_loan(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_max_flash_loan(&e)
+    }
+
+    fn flash_fee(e: Env, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        calc_fee(&e, amount)
+    }
+
+    fn flash_loan(
+        e: Env,
+        initiator: Address,
+        receiver: Address,
+        amount: i128,
+        data: Bytes,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        // the host already refuses to re-enter this contract from the receiver, so the lock only
+        // fires if that stops holding, e.g. once reentrant calls are allowed
+        orbit_access::nonreentrant!(&e);
+        initiator.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&e, FlashMintError::NegativeAmountError);
+        }
+        if amount > storage::get_max_flash_loan(&e) {
+            panic_with_error!(&e, FlashMintError::MaxFlashLoanExceeded);
+        }
+
+        let token = storage::get_token(&e);
+        let token_client = TokenClient::new(&e, &token);
+        let fee = calc_fee(&e, amount);
+        let balance_before = token_client.balance(&e.current_contract_address());
+
+        TreasuryClient::new(&e, &storage::get_treasury(&e)).flash_mint(&amount);
+        token_client.transfer(&e.current_contract_address(), &receiver, &amount);
+        FlashReceiverClient::new(&e, &receiver).on_flash_loan(
+            &initiator, &token, &amount, &fee, &data,
+        );
+
+        // anything repaid beyond the loan is revenue, so a receiver can't leave tokens behind
+        let repaid = token_client.balance(&e.current_contract_address()) - balance_before;
+        if repaid < amount + fee {
+            panic_with_error!(&e, FlashMintError::RepaymentFailed);
+        }
+        token_client.burn(&e.current_contract_address(), &amount);
+        let revenue = repaid - amount;
+        if revenue > 0 {
+            token_client.transfer(
+                &e.current_contract_address(),
+                &storage::get_fee_distributor(&e),
+                &revenue,
+            );
+        }
+
+        FlashMintEvents::flash_loan(&e, initiator, receiver, amount, revenue);
+        revenue
+    }
+}
+
+/// Calculate the fee owed on a flash loan
+fn calc_fee(e: &Env, amount: i128) -> i128 {
+    math::apply_bps(e, amount, storage::get_fee(e))
+}
+
+/// Require that the flash loan terms are valid
+///
+/// ### Panics
+/// If the fee is greater than 100% or the ceiling is negative
+fn require_valid_terms(e: &Env, fee: u32, max_flash_loan: i128) {
+    if fee > MAX_BPS || max_flash_loan < 0 {
+        panic_with_error!(e, FlashMintError::InvalidTerms);
+    }
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contractclient, Address, Bytes, Env};
+
+/// The callback a flash loan receiver implements. The receiver must send the loan plus the fee
+/// back to the flash mint contract before it returns.
+#[contractclient(name = "FlashReceiverClient")]
+pub trait FlashReceiver {
+    /// Use a flash loan
+    ///
+    /// ### Arguments
+    /// * `initiator` - The Address that requested the loan
+    /// * `token` - The Address of the token lent
+    /// * `amount` - The amount lent
+    /// * `fee` - The fee owed on top of the loan
+    /// * `data` - Arbitrary data passed through from the initiator
+    fn on_flash_loan(
+        e: Env,
+        initiator: Address,
+        token: Address,
+        amount: i128,
+        fee: i128,
+        data: Bytes,
+    );
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Env};
+
+/// The part of the treasury the flash mint uses to mint loans
+#[allow(dead_code)]
+#[contractclient(name = "TreasuryClient")]
+pub trait Treasury {
+    fn flash_mint(e: Env, amount: i128);
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the flash mint contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Flash mint specific errors start at 4500.
+pub enum FlashMintError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Flash Mint
+    InvalidTerms = 4500,
+    MaxFlashLoanExceeded = 4501,
+    RepaymentFailed = 4502,
+}
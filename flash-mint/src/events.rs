@@ -0,0 +1,24 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct FlashMintEvents {}
+
+impl FlashMintEvents {
+    /// Emitted when the admin updates the fee or the loan ceiling
+    ///
+    /// - topics - `["set_terms"]`
+    /// - data - `[fee: u32, max_flash_loan: i128]`
+    pub fn set_terms(e: &Env, fee: u32, max_flash_loan: i128) {
+        let topics = (Symbol::new(e, topics::SET_TERMS),);
+        e.events().publish(topics, (fee, max_flash_loan));
+    }
+
+    /// Emitted when a flash loan is repaid
+    ///
+    /// - topics - `["flash_loan", initiator: Address, receiver: Address]`
+    /// - data - `[amount: i128, fee: i128]`
+    pub fn flash_loan(e: &Env, initiator: Address, receiver: Address, amount: i128, fee: i128) {
+        let topics = (Symbol::new(e, topics::FLASH_LOAN), initiator, receiver);
+        e.events().publish(topics, (amount, fee));
+    }
+}
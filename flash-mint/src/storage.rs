@@ -0,0 +1,139 @@
+use soroban_sdk::{unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const TREASURY_KEY: &str = "Treasury";
+const FEE_DISTRIBUTOR_KEY: &str = "FeeDistributor";
+const FEE_KEY: &str = "Fee";
+const MAX_FLASH_LOAN_KEY: &str = "MaxFlashLoan";
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the token lent
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token lent
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the Address of the treasury minting the loans
+///
+/// ### Panics
+/// If the treasury does not exist
+pub fn get_treasury(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TREASURY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the treasury minting the loans
+///
+/// ### Arguments
+/// * `treasury` - The Address for the treasury
+pub fn set_treasury(e: &Env, treasury: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TREASURY_KEY), treasury);
+}
+
+/// Fetch the Address of the fee distributor receiving the fees
+///
+/// ### Panics
+/// If the fee distributor does not exist
+pub fn get_fee_distributor(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FEE_DISTRIBUTOR_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the fee distributor receiving the fees
+///
+/// ### Arguments
+/// * `fee_distributor` - The Address for the fee distributor
+pub fn set_fee_distributor(e: &Env, fee_distributor: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, FEE_DISTRIBUTOR_KEY), fee_distributor);
+}
+
+/********** Terms **********/
+
+/// Fetch the flash loan fee, in basis points
+///
+/// ### Panics
+/// If the fee does not exist
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FEE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the flash loan fee
+///
+/// ### Arguments
+/// * `fee` - The fee, in basis points
+pub fn set_fee(e: &Env, fee: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, FEE_KEY), &fee);
+}
+
+/// Fetch the largest flash loan
+///
+/// ### Panics
+/// If the ceiling does not exist
+pub fn get_max_flash_loan(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, MAX_FLASH_LOAN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the largest flash loan
+///
+/// ### Arguments
+/// * `amount` - The largest flash loan
+pub fn set_max_flash_loan(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, MAX_FLASH_LOAN_KEY), &amount);
+}
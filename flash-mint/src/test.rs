@@ -0,0 +1,260 @@
+#![cfg(test)]
+use crate::contract::{FlashMint, FlashMintClient, FlashMintContract};
+use crate::errors::FlashMintError;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, Env, Error, IntoVal, Symbol};
+
+/// Mints to the flash minter, like the treasury
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn setup(e: Env, token: Address, flash_minter: Address) {
+        e.storage().instance().set(&Symbol::new(&e, "token"), &token);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "flash_minter"), &flash_minter);
+    }
+
+    pub fn flash_mint(e: Env, amount: i128) {
+        let token: Address = e.storage().instance().get(&Symbol::new(&e, "token")).unwrap();
+        let flash_minter: Address = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "flash_minter"))
+            .unwrap();
+        flash_minter.require_auth();
+        StellarAssetClient::new(&e, &token).mint(&flash_minter, &amount);
+    }
+}
+
+/// Repays each flash loan, with or without the fee, out of its own balance, after taking a
+/// second loan from the same lender if set to
+#[contract]
+pub struct MockReceiver;
+
+#[contractimpl]
+impl MockReceiver {
+    pub fn set_lender(e: Env, lender: Address, repay_fee: bool) {
+        e.storage().instance().set(&Symbol::new(&e, "lender"), &lender);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "repay_fee"), &repay_fee);
+    }
+
+    pub fn set_nested(e: Env) {
+        e.storage().instance().set(&Symbol::new(&e, "nested"), &true);
+    }
+
+    pub fn on_flash_loan(
+        e: Env,
+        _initiator: Address,
+        token: Address,
+        amount: i128,
+        fee: i128,
+        _data: Bytes,
+    ) {
+        let lender: Address = e.storage().instance().get(&Symbol::new(&e, "lender")).unwrap();
+        let repay_fee: bool = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "repay_fee"))
+            .unwrap();
+        if e.storage().instance().has(&Symbol::new(&e, "nested")) {
+            FlashMintClient::new(&e, &lender).flash_loan(
+                &e.current_contract_address(),
+                &e.current_contract_address(),
+                &amount,
+                &Bytes::new(&e),
+            );
+        }
+        let repayment = if repay_fee { amount + fee } else { amount };
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &lender, &repayment);
+    }
+}
+
+#[test]
+fn test_flash_loan() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let treasury = e.register_contract(None, MockTreasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let fee_distributor = Address::generate(&e);
+    let flash_mint_id = e.register_contract(None, FlashMintContract);
+    let flash_mint_client = FlashMintClient::new(&e, &flash_mint_id);
+    MockTreasuryClient::new(&e, &treasury).setup(&ousd, &flash_mint_id);
+    flash_mint_client.initialize(
+        &Address::generate(&e),
+        &ousd,
+        &treasury,
+        &fee_distributor,
+        &5,
+        &1000000_0000000,
+    );
+    assert_eq!(flash_mint_client.flash_fee(&100000_0000000), 50_0000000);
+
+    let receiver = e.register_contract(None, MockReceiver);
+    let receiver_client = MockReceiverClient::new(&e, &receiver);
+    receiver_client.set_lender(&flash_mint_id, &true);
+    StellarAssetClient::new(&e, &ousd).mint(&receiver, &100_0000000);
+    let initiator = Address::generate(&e);
+    let data = Bytes::new(&e);
+
+    let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &1000001_0000000, &data);
+    assert_eq!(
+        result.err(),
+        Some(Ok(FlashMintError::MaxFlashLoanExceeded.into()))
+    );
+    let fee = flash_mint_client.flash_loan(&initiator, &receiver, &100000_0000000, &data);
+    assert_eq!(fee, 50_0000000);
+    assert_eq!(ousd_client.balance(&receiver), 50_0000000);
+    assert_eq!(ousd_client.balance(&fee_distributor), 50_0000000);
+    assert_eq!(ousd_client.balance(&flash_mint_id), 0);
+
+    // a loan repaid without the fee reverts
+    receiver_client.set_lender(&flash_mint_id, &false);
+    let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &100000_0000000, &data);
+    assert_eq!(result.err(), Some(Ok(FlashMintError::RepaymentFailed.into())));
+
+    // the host refuses a second loan from the receiver's callback
+    receiver_client.set_lender(&flash_mint_id, &true);
+    receiver_client.set_nested();
+    let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &100000_0000000, &data);
+    assert_eq!(
+        result.err(),
+        Some(Ok(Error::from_type_and_code(
+            ScErrorType::Context,
+            ScErrorCode::InvalidAction
+        )))
+    );
+    assert_eq!(ousd_client.balance(&receiver), 50_0000000);
+    assert_eq!(ousd_client.balance(&fee_distributor), 50_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_flash_loan_reentered() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let treasury = e.register_contract(None, MockTreasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let flash_mint_id = e.register_contract(None, FlashMintContract);
+    MockTreasuryClient::new(&e, &treasury).setup(&ousd, &flash_mint_id);
+    FlashMintClient::new(&e, &flash_mint_id).initialize(
+        &Address::generate(&e),
+        &ousd,
+        &treasury,
+        &Address::generate(&e),
+        &5,
+        &1000000_0000000,
+    );
+    let receiver = e.register_contract(None, MockReceiver);
+    MockReceiverClient::new(&e, &receiver).set_lender(&flash_mint_id, &true);
+    StellarAssetClient::new(&e, &ousd).mint(&receiver, &100_0000000);
+
+    // a loan taken from within a loan still holding the lock, as the receiver would if the host
+    // let it re-enter, fails with `ReentrantCallError`
+    e.as_contract(&flash_mint_id, || {
+        orbit_access::nonreentrant!(&e);
+        FlashMintContract::flash_loan(
+            e.clone(),
+            Address::generate(&e),
+            receiver.clone(),
+            100000_0000000,
+            Bytes::new(&e),
+        );
+    });
+}
+
+#[test]
+fn test_flash_loan_terms() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let treasury = e.register_contract(None, MockTreasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let admin = Address::generate(&e);
+    let fee_distributor = Address::generate(&e);
+    let flash_mint_id = e.register_contract(None, FlashMintContract);
+    let flash_mint_client = FlashMintClient::new(&e, &flash_mint_id);
+    MockTreasuryClient::new(&e, &treasury).setup(&ousd, &flash_mint_id);
+    let result = flash_mint_client.try_initialize(
+        &admin,
+        &ousd,
+        &treasury,
+        &fee_distributor,
+        &10001,
+        &0,
+    );
+    assert_eq!(result.err(), Some(Ok(FlashMintError::InvalidTerms.into())));
+    flash_mint_client.initialize(&admin, &ousd, &treasury, &fee_distributor, &5, &0);
+    let result =
+        flash_mint_client.try_initialize(&admin, &ousd, &treasury, &fee_distributor, &5, &0);
+    assert_eq!(
+        result.err(),
+        Some(Ok(FlashMintError::AlreadyInitializedError.into()))
+    );
+
+    for (fee, max_flash_loan) in [(10001, 100_0000000), (5, -1)] {
+        let result = flash_mint_client.try_set_terms(&fee, &max_flash_loan);
+        assert_eq!(result.err(), Some(Ok(FlashMintError::InvalidTerms.into())));
+    }
+
+    let receiver = e.register_contract(None, MockReceiver);
+    let receiver_client = MockReceiverClient::new(&e, &receiver);
+    receiver_client.set_lender(&flash_mint_id, &true);
+    StellarAssetClient::new(&e, &ousd).mint(&receiver, &100_0000000);
+    let initiator = Address::generate(&e);
+    let data = Bytes::new(&e);
+
+    // a ceiling of zero disables flash loans
+    let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &1, &data);
+    assert_eq!(
+        result.err(),
+        Some(Ok(FlashMintError::MaxFlashLoanExceeded.into()))
+    );
+
+    // a fee of 100% and a loan of the whole ceiling are allowed
+    flash_mint_client.set_terms(&10000, &100_0000000);
+    assert_eq!(
+        e.auths()[0],
+        (
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    flash_mint_id.clone(),
+                    Symbol::new(&e, "set_terms"),
+                    vec![&e, 10000_u32.into_val(&e), 100_0000000_i128.into_val(&e)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(flash_mint_client.max_flash_loan(), 100_0000000);
+    for amount in [0, -1] {
+        let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &amount, &data);
+        assert_eq!(
+            result.err(),
+            Some(Ok(FlashMintError::NegativeAmountError.into()))
+        );
+    }
+    let fee = flash_mint_client.flash_loan(&initiator, &receiver, &100_0000000, &data);
+    assert_eq!(fee, 100_0000000);
+    assert_eq!(e.auths()[0].0, initiator);
+    assert_eq!(ousd_client.balance(&receiver), 0);
+    assert_eq!(ousd_client.balance(&fee_distributor), 100_0000000);
+
+    // tokens the contract already holds don't count toward the repayment
+    flash_mint_client.set_terms(&5, &100_0000000);
+    StellarAssetClient::new(&e, &ousd).mint(&flash_mint_id, &10_0000000);
+    StellarAssetClient::new(&e, &ousd).mint(&receiver, &100_0000000);
+    receiver_client.set_lender(&flash_mint_id, &false);
+    let result = flash_mint_client.try_flash_loan(&initiator, &receiver, &100_0000000, &data);
+    assert_eq!(result.err(), Some(Ok(FlashMintError::RepaymentFailed.into())));
+    assert_eq!(ousd_client.balance(&flash_mint_id), 10_0000000);
+    assert_eq!(ousd_client.balance(&receiver), 100_0000000);
+}
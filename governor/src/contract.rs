@@ -0,0 +1,389 @@
+use crate::errors::GovernorError;
+use crate::events::GovernorEvents;
+use crate::storage::{self, GovernorSettings, Proposal, ProposalStatus, VoteCount};
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, String, Symbol,
+    TryFromVal, Val, Vec,
+};
+
+/// Vote against a proposal
+pub const VOTE_AGAINST: u32 = 0;
+/// Vote for a proposal
+pub const VOTE_FOR: u32 = 1;
+/// Abstain from a proposal. Abstaining votes count towards quorum.
+pub const VOTE_ABSTAIN: u32 = 2;
+
+#[contract]
+pub struct GovernorContract;
+
+#[contractclient(name = "GovernorClient")]
+pub trait Governor {
+    /// Initialize the governor
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the token used to vote
+    /// * `guardian` - The Address for the guardian, who can cancel malicious proposals
+    /// * `settings` - The voting rules
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the settings are invalid
+    fn initialize(e: Env, token: Address, guardian: Address, settings: GovernorSettings);
+
+    /// (Guardian only) Set a new guardian
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    fn set_guardian(e: Env, guardian: Address);
+
+    /// Get the voting rules. The voting rules can only be changed by a proposal calling
+    /// `set_settings` on the governor.
+    fn get_settings(e: Env) -> GovernorSettings;
+
+    /// Create a proposal. Voting opens immediately and lasts for the voting period.
+    ///
+    /// Returns the id of the proposal
+    ///
+    /// ### Arguments
+    /// * `proposer` - The Address creating the proposal
+    /// * `calls` - The (contract, function, args) calls to execute if the proposal passes, in order
+    /// * `description` - A description of the proposal
+    ///
+    /// ### Panics
+    /// If the proposer holds less than the proposal threshold of the voting token
+    /// If `calls` is empty, or calls the governor with a function other than `set_settings`
+    fn propose(
+        e: Env,
+        proposer: Address,
+        calls: Vec<(Address, Symbol, Vec<Val>)>,
+        description: String,
+    ) -> u32;
+
+    /// Get a proposal
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the proposal
+    ///
+    /// ### Panics
+    /// If the proposal does not exist
+    fn get_proposal(e: Env, id: u32) -> Proposal;
+
+    /// Get the votes cast on a proposal
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the proposal
+    fn get_votes(e: Env, id: u32) -> VoteCount;
+
+    /// Vote on a proposal by locking voting tokens in the governor. The tokens can be
+    /// reclaimed with `reclaim` once voting ends, so every token is counted once per proposal.
+    ///
+    /// ### Arguments
+    /// * `voter` - The Address voting
+    /// * `id` - The id of the proposal
+    /// * `support` - 0 to vote against, 1 to vote for, or 2 to abstain
+    /// * `amount` - The amount of voting tokens to lock
+    ///
+    /// ### Panics
+    /// If the proposal is not open for voting
+    /// If the voter has already voted on the proposal
+    fn vote(e: Env, voter: Address, id: u32, support: u32, amount: i128);
+
+    /// Reclaim the tokens locked to vote on a proposal
+    ///
+    /// Returns the amount of tokens reclaimed
+    ///
+    /// ### Arguments
+    /// * `voter` - The Address that voted
+    /// * `id` - The id of the proposal
+    ///
+    /// ### Panics
+    /// If voting on the proposal has not ended and the proposal is not canceled
+    /// If the voter has no tokens locked for the proposal
+    fn reclaim(e: Env, voter: Address, id: u32) -> i128;
+
+    /// Queue a passed proposal for execution once the timelock expires. A proposal passes if
+    /// the votes for and abstaining reach quorum and there are more votes for than against.
+    ///
+    /// Returns the time the proposal can be executed
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the proposal
+    ///
+    /// ### Panics
+    /// If voting on the proposal has not ended
+    /// If the proposal did not pass
+    fn queue(e: Env, id: u32) -> u64;
+
+    /// Execute the calls of a queued proposal, with the governor as the invoker
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the proposal
+    ///
+    /// ### Panics
+    /// If the proposal is not queued or the timelock has not expired
+    /// If any call fails
+    fn execute(e: Env, id: u32);
+
+    /// (Proposer or guardian only) Cancel a proposal that has not been executed
+    ///
+    /// ### Arguments
+    /// * `caller` - The proposer or guardian
+    /// * `id` - The id of the proposal
+    ///
+    /// ### Panics
+    /// If the caller is not the proposer or guardian
+    /// If the proposal is already executed or canceled
+    fn cancel(e: Env, caller: Address, id: u32);
+}
+
+#[contractimpl]
+impl Governor for GovernorContract {
+    fn initialize(e: Env, token: Address, guardian: Address, settings: GovernorSettings) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, GovernorError::AlreadyInitializedError);
+        }
+        require_valid_settings(&e, &settings);
+
+        storage::set_token(&e, &token);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+        storage::set_settings(&e, &settings);
+        storage::set_is_init(&e);
+    }
+
+    fn set_guardian(e: Env, guardian: Address) {
+        storage::extend_instance(&e);
+        let old_guardian = orbit_access::require_role(&e, Role::Guardian);
+
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+
+        GovernorEvents::set_guardian(&e, old_guardian, guardian);
+    }
+
+    fn get_settings(e: Env) -> GovernorSettings {
+        storage::extend_instance(&e);
+        storage::get_settings(&e)
+    }
+
+    fn propose(
+        e: Env,
+        proposer: Address,
+        calls: Vec<(Address, Symbol, Vec<Val>)>,
+        description: String,
+    ) -> u32 {
+        storage::extend_instance(&e);
+        proposer.require_auth();
+
+        let settings = storage::get_settings(&e);
+        let balance = TokenClient::new(&e, &storage::get_token(&e)).balance(&proposer);
+        if balance < settings.proposal_threshold {
+            panic_with_error!(&e, GovernorError::InsufficientVotingPower);
+        }
+        if calls.is_empty() {
+            panic_with_error!(&e, GovernorError::InvalidCall);
+        }
+        let set_settings = Symbol::new(&e, "set_settings");
+        for (contract, function, _) in calls.iter() {
+            if contract == e.current_contract_address() && function != set_settings {
+                panic_with_error!(&e, GovernorError::InvalidCall);
+            }
+        }
+
+        let id = storage::get_proposal_count(&e);
+        let vote_end = e.ledger().timestamp() + settings.voting_period;
+        storage::set_proposal(
+            &e,
+            id,
+            &Proposal {
+                proposer: proposer.clone(),
+                calls,
+                description: description.clone(),
+                vote_end,
+                eta: 0,
+                status: ProposalStatus::Active,
+            },
+        );
+        storage::set_proposal_count(&e, id + 1);
+
+        GovernorEvents::propose(&e, id, proposer, description, vote_end);
+        id
+    }
+
+    fn get_proposal(e: Env, id: u32) -> Proposal {
+        storage::extend_instance(&e);
+        load_proposal(&e, id)
+    }
+
+    fn get_votes(e: Env, id: u32) -> VoteCount {
+        storage::extend_instance(&e);
+        storage::get_votes(&e, id)
+    }
+
+    fn vote(e: Env, voter: Address, id: u32, support: u32, amount: i128) {
+        storage::extend_instance(&e);
+        voter.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&e, GovernorError::NegativeAmountError);
+        }
+
+        let proposal = load_proposal(&e, id);
+        if proposal.status != ProposalStatus::Active || e.ledger().timestamp() >= proposal.vote_end {
+            panic_with_error!(&e, GovernorError::VotingClosed);
+        }
+        if storage::get_voter_lock(&e, id, &voter).is_some() {
+            panic_with_error!(&e, GovernorError::AlreadyVoted);
+        }
+
+        let mut votes = storage::get_votes(&e, id);
+        match support {
+            VOTE_AGAINST => votes.votes_against += amount,
+            VOTE_FOR => votes.votes_for += amount,
+            VOTE_ABSTAIN => votes.votes_abstain += amount,
+            _ => panic_with_error!(&e, GovernorError::InvalidSupport),
+        }
+
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &voter,
+            &e.current_contract_address(),
+            &amount,
+        );
+        storage::set_votes(&e, id, &votes);
+        storage::set_voter_lock(&e, id, &voter, amount);
+
+        GovernorEvents::vote(&e, id, voter, support, amount);
+    }
+
+    fn reclaim(e: Env, voter: Address, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        voter.require_auth();
+
+        let proposal = load_proposal(&e, id);
+        if proposal.status != ProposalStatus::Canceled && e.ledger().timestamp() < proposal.vote_end
+        {
+            panic_with_error!(&e, GovernorError::VotingNotEnded);
+        }
+        let amount = storage::get_voter_lock(&e, id, &voter)
+            .unwrap_or_else(|| panic_with_error!(&e, GovernorError::NoVotes));
+
+        storage::del_voter_lock(&e, id, &voter);
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &e.current_contract_address(),
+            &voter,
+            &amount,
+        );
+
+        GovernorEvents::reclaim(&e, id, voter, amount);
+        amount
+    }
+
+    fn queue(e: Env, id: u32) -> u64 {
+        storage::extend_instance(&e);
+
+        let mut proposal = load_proposal(&e, id);
+        if proposal.status != ProposalStatus::Active {
+            panic_with_error!(&e, GovernorError::InvalidProposalStatus);
+        }
+        if e.ledger().timestamp() < proposal.vote_end {
+            panic_with_error!(&e, GovernorError::VotingNotEnded);
+        }
+        let settings = storage::get_settings(&e);
+        let votes = storage::get_votes(&e, id);
+        if votes.votes_for + votes.votes_abstain < settings.quorum
+            || votes.votes_for <= votes.votes_against
+        {
+            panic_with_error!(&e, GovernorError::ProposalNotSucceeded);
+        }
+
+        proposal.eta = e.ledger().timestamp() + settings.timelock;
+        proposal.status = ProposalStatus::Queued;
+        storage::set_proposal(&e, id, &proposal);
+
+        GovernorEvents::queue(&e, id, proposal.eta);
+        proposal.eta
+    }
+
+    fn execute(e: Env, id: u32) {
+        storage::extend_instance(&e);
+
+        let mut proposal = load_proposal(&e, id);
+        if proposal.status != ProposalStatus::Queued {
+            panic_with_error!(&e, GovernorError::ProposalNotQueued);
+        }
+        if e.ledger().timestamp() < proposal.eta {
+            panic_with_error!(&e, GovernorError::TimelockNotExpired);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        storage::set_proposal(&e, id, &proposal);
+
+        for (contract, function, args) in proposal.calls.iter() {
+            if contract == e.current_contract_address() {
+                // the governor can't invoke itself, so its own calls are applied directly
+                execute_self_call(&e, &function, &args);
+            } else {
+                e.invoke_contract::<Val>(&contract, &function, args);
+            }
+        }
+
+        GovernorEvents::execute(&e, id);
+    }
+
+    fn cancel(e: Env, caller: Address, id: u32) {
+        storage::extend_instance(&e);
+        caller.require_auth();
+
+        let mut proposal = load_proposal(&e, id);
+        if caller != proposal.proposer && !orbit_access::has_role(&e, Role::Guardian, &caller) {
+            panic_with_error!(&e, GovernorError::UnauthorizedError);
+        }
+        if proposal.status == ProposalStatus::Executed || proposal.status == ProposalStatus::Canceled
+        {
+            panic_with_error!(&e, GovernorError::InvalidProposalStatus);
+        }
+
+        proposal.status = ProposalStatus::Canceled;
+        storage::set_proposal(&e, id, &proposal);
+
+        GovernorEvents::cancel(&e, id, caller);
+    }
+}
+
+/// Load a proposal
+///
+/// ### Panics
+/// If the proposal does not exist
+fn load_proposal(e: &Env, id: u32) -> Proposal {
+    storage::get_proposal(e, id).unwrap_or_else(|| panic_with_error!(e, GovernorError::ProposalNotFound))
+}
+
+/// Apply a proposal call made to the governor itself
+///
+/// ### Panics
+/// If the call is not a valid `set_settings` call
+fn execute_self_call(e: &Env, function: &Symbol, args: &Vec<Val>) {
+    if *function != Symbol::new(e, "set_settings") || args.len() != 1 {
+        panic_with_error!(e, GovernorError::InvalidCall);
+    }
+    let settings = GovernorSettings::try_from_val(e, &args.get_unchecked(0))
+        .unwrap_or_else(|_| panic_with_error!(e, GovernorError::InvalidCall));
+    require_valid_settings(e, &settings);
+
+    storage::set_settings(e, &settings);
+
+    GovernorEvents::set_settings(e, settings);
+}
+
+/// Require that the voting rules are valid
+///
+/// ### Panics
+/// If the proposal threshold is negative, or the quorum or voting period is zero
+fn require_valid_settings(e: &Env, settings: &GovernorSettings) {
+    if settings.proposal_threshold < 0 || settings.quorum <= 0 || settings.voting_period == 0 {
+        panic_with_error!(e, GovernorError::InvalidSettings);
+    }
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the governor contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Governor specific errors start at 2400.
+pub enum GovernorError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Governor
+    ProposalNotFound = 2400,
+    InsufficientVotingPower = 2401,
+    VotingClosed = 2402,
+    VotingNotEnded = 2403,
+    ProposalNotSucceeded = 2404,
+    ProposalNotQueued = 2405,
+    TimelockNotExpired = 2406,
+    AlreadyVoted = 2407,
+    InvalidProposalStatus = 2408,
+    InvalidSettings = 2409,
+    InvalidCall = 2410,
+    InvalidSupport = 2411,
+    NoVotes = 2412,
+}
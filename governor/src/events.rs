@@ -0,0 +1,80 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::storage::GovernorSettings;
+
+pub struct GovernorEvents {}
+
+impl GovernorEvents {
+    /// Emitted when the guardian is updated
+    ///
+    /// - topics - `["set_guardian", old_guardian: Address]`
+    /// - data - `guardian: Address`
+    pub fn set_guardian(e: &Env, old_guardian: Address, guardian: Address) {
+        let topics = (Symbol::new(e, topics::SET_GUARDIAN), old_guardian);
+        e.events().publish(topics, guardian);
+    }
+
+    /// Emitted when a proposal updates the voting rules
+    ///
+    /// - topics - `["set_settings"]`
+    /// - data - `settings: GovernorSettings`
+    pub fn set_settings(e: &Env, settings: GovernorSettings) {
+        let topics = (Symbol::new(e, topics::SET_SETTINGS),);
+        e.events().publish(topics, settings);
+    }
+
+    /// Emitted when a proposal is created
+    ///
+    /// - topics - `["propose", id: u32, proposer: Address]`
+    /// - data - `[description: String, vote_end: u64]`
+    pub fn propose(e: &Env, id: u32, proposer: Address, description: String, vote_end: u64) {
+        let topics = (Symbol::new(e, topics::PROPOSE), id, proposer);
+        e.events().publish(topics, (description, vote_end));
+    }
+
+    /// Emitted when a vote is cast on a proposal
+    ///
+    /// - topics - `["vote", id: u32, voter: Address]`
+    /// - data - `[support: u32, amount: i128]`
+    pub fn vote(e: &Env, id: u32, voter: Address, support: u32, amount: i128) {
+        let topics = (Symbol::new(e, topics::VOTE), id, voter);
+        e.events().publish(topics, (support, amount));
+    }
+
+    /// Emitted when a voter reclaims the tokens locked to vote on a proposal
+    ///
+    /// - topics - `["reclaim", id: u32, voter: Address]`
+    /// - data - `amount: i128`
+    pub fn reclaim(e: &Env, id: u32, voter: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::RECLAIM), id, voter);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a passed proposal is queued for execution
+    ///
+    /// - topics - `["queue", id: u32]`
+    /// - data - `eta: u64`
+    pub fn queue(e: &Env, id: u32, eta: u64) {
+        let topics = (Symbol::new(e, topics::QUEUE), id);
+        e.events().publish(topics, eta);
+    }
+
+    /// Emitted when a queued proposal is executed
+    ///
+    /// - topics - `["execute", id: u32]`
+    /// - data - `()`
+    pub fn execute(e: &Env, id: u32) {
+        let topics = (Symbol::new(e, topics::EXECUTE), id);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a proposal is canceled
+    ///
+    /// - topics - `["cancel", id: u32]`
+    /// - data - `caller: Address`
+    pub fn cancel(e: &Env, id: u32, caller: Address) {
+        let topics = (Symbol::new(e, topics::CANCEL), id);
+        e.events().publish(topics, caller);
+    }
+}
@@ -0,0 +1,259 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, String, Symbol, Val, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const SETTINGS_KEY: &str = "Settings";
+const PROPOSAL_COUNT_KEY: &str = "PropCount";
+
+/// The voting rules of the governor
+#[derive(Clone)]
+#[contracttype]
+pub struct GovernorSettings {
+    pub proposal_threshold: i128, // the token balance needed to create a proposal
+    pub voting_period: u64,       // the time a proposal is open for voting, in seconds
+    pub quorum: i128,             // the votes for or abstaining needed for a proposal to pass
+    pub timelock: u64,            // the delay between queueing and executing a proposal, in seconds
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ProposalStatus {
+    Active,   // open for voting, or voting ended and not yet queued
+    Queued,   // passed and waiting for its timelock to expire
+    Executed, // passed and executed
+    Canceled, // canceled by the proposer or the guardian
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Proposal {
+    pub proposer: Address,
+    pub calls: Vec<(Address, Symbol, Vec<Val>)>,
+    pub description: String,
+    pub vote_end: u64, // the time voting closes
+    pub eta: u64,      // the time a queued proposal can be executed
+    pub status: ProposalStatus,
+}
+
+/// The votes cast on a proposal
+#[derive(Clone)]
+#[contracttype]
+pub struct VoteCount {
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub votes_abstain: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum GovernorDataKey {
+    // A proposal by id
+    Proposal(u32),
+    // The votes cast on a proposal
+    Votes(u32),
+    // The tokens an address locked to vote on a proposal
+    Voter(u32, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the voting token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the voting token
+///
+/// ### Arguments
+/// * `token` - The Address for the voting token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Settings **********/
+
+/// Fetch the voting rules
+///
+/// ### Panics
+/// If the settings do not exist
+pub fn get_settings(e: &Env) -> GovernorSettings {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SETTINGS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the voting rules
+///
+/// ### Arguments
+/// * `settings` - The voting rules
+pub fn set_settings(e: &Env, settings: &GovernorSettings) {
+    e.storage()
+        .instance()
+        .set::<Symbol, GovernorSettings>(&Symbol::new(e, SETTINGS_KEY), settings);
+}
+
+/********** Proposals **********/
+
+/// Fetch the number of proposals created, which is also the id of the next proposal
+pub fn get_proposal_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, PROPOSAL_COUNT_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of proposals created
+///
+/// ### Arguments
+/// * `count` - The number of proposals
+pub fn set_proposal_count(e: &Env, count: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, PROPOSAL_COUNT_KEY), &count);
+}
+
+/// Fetch a proposal, if it exists
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+pub fn get_proposal(e: &Env, id: u32) -> Option<Proposal> {
+    let key = GovernorDataKey::Proposal(id);
+    let result = e
+        .storage()
+        .persistent()
+        .get::<GovernorDataKey, Proposal>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set a proposal
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+/// * `proposal` - The proposal
+pub fn set_proposal(e: &Env, id: u32, proposal: &Proposal) {
+    let key = GovernorDataKey::Proposal(id);
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, Proposal>(&key, proposal);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the votes cast on a proposal
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+pub fn get_votes(e: &Env, id: u32) -> VoteCount {
+    let key = GovernorDataKey::Votes(id);
+    let result = e
+        .storage()
+        .persistent()
+        .get::<GovernorDataKey, VoteCount>(&key);
+    match result {
+        Some(votes) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            votes
+        }
+        None => VoteCount {
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+        },
+    }
+}
+
+/// Set the votes cast on a proposal
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+/// * `votes` - The votes cast
+pub fn set_votes(e: &Env, id: u32, votes: &VoteCount) {
+    let key = GovernorDataKey::Votes(id);
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, VoteCount>(&key, votes);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the tokens an address locked to vote on a proposal, if it voted
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+/// * `voter` - The Address of the voter
+pub fn get_voter_lock(e: &Env, id: u32, voter: &Address) -> Option<i128> {
+    let key = GovernorDataKey::Voter(id, voter.clone());
+    let result = e.storage().persistent().get::<GovernorDataKey, i128>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the tokens an address locked to vote on a proposal
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+/// * `voter` - The Address of the voter
+/// * `amount` - The amount of tokens locked
+pub fn set_voter_lock(e: &Env, id: u32, voter: &Address, amount: i128) {
+    let key = GovernorDataKey::Voter(id, voter.clone());
+    e.storage()
+        .persistent()
+        .set::<GovernorDataKey, i128>(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the record of the tokens an address locked to vote on a proposal
+///
+/// ### Arguments
+/// * `id` - The id of the proposal
+/// * `voter` - The Address of the voter
+pub fn del_voter_lock(e: &Env, id: u32, voter: &Address) {
+    let key = GovernorDataKey::Voter(id, voter.clone());
+    e.storage().persistent().remove(&key);
+}
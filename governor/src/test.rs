@@ -0,0 +1,238 @@
+#![cfg(test)]
+use crate::contract::{GovernorClient, GovernorContract, VOTE_AGAINST, VOTE_FOR};
+use crate::errors::GovernorError;
+use crate::storage::{GovernorSettings, ProposalStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn set_value(e: Env, value: u32) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "value"), &value);
+    }
+
+    pub fn value(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "value"))
+            .unwrap_or(0)
+    }
+}
+
+fn default_settings() -> GovernorSettings {
+    GovernorSettings {
+        proposal_threshold: 100_0000000,
+        voting_period: 3 * 24 * 60 * 60,
+        quorum: 1000_0000000,
+        timelock: 24 * 60 * 60,
+    }
+}
+
+/// Create a governor, returning the governor client, the guardian and the voting token address
+fn create_governor<'a>(e: &Env) -> (GovernorClient<'a>, Address, Address) {
+    let token = e.register_stellar_asset_contract(Address::generate(e));
+    let guardian = Address::generate(e);
+    let governor_id = e.register_contract(None, GovernorContract);
+    let governor_client = GovernorClient::new(e, &governor_id);
+    governor_client.initialize(&token, &guardian, &default_settings());
+    (governor_client, guardian, token)
+}
+
+fn set_value_calls(e: &Env, target: &Address, value: u32) -> Vec<(Address, Symbol, Vec<Val>)> {
+    vec![
+        e,
+        (
+            target.clone(),
+            Symbol::new(e, "set_value"),
+            vec![e, value.into_val(e)],
+        ),
+    ]
+}
+
+#[test]
+fn test_proposal_lifecycle() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (governor_client, _, token) = create_governor(&e);
+    let target = e.register_contract(None, MockTarget);
+    let target_client = MockTargetClient::new(&e, &target);
+    let proposer = Address::generate(&e);
+    let voter = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&proposer, &100_0000000);
+    StellarAssetClient::new(&e, &token).mint(&voter, &1500_0000000);
+
+    let id = governor_client.propose(
+        &proposer,
+        &set_value_calls(&e, &target, 7),
+        &String::from_str(&e, "set the value to 7"),
+    );
+    assert_eq!(id, 0);
+    let proposal = governor_client.get_proposal(&id);
+    assert_eq!(proposal.vote_end, 1000 + 3 * 24 * 60 * 60);
+    assert_eq!(proposal.status, ProposalStatus::Active);
+
+    governor_client.vote(&voter, &id, &VOTE_FOR, &1200_0000000);
+    governor_client.vote(&proposer, &id, &VOTE_AGAINST, &100_0000000);
+    let result = governor_client.try_vote(&voter, &id, &VOTE_FOR, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(GovernorError::AlreadyVoted.into())));
+    let votes = governor_client.get_votes(&id);
+    assert_eq!(votes.votes_for, 1200_0000000);
+    assert_eq!(votes.votes_against, 100_0000000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&voter), 300_0000000);
+
+    // voting is still open
+    let result = governor_client.try_queue(&id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::VotingNotEnded.into())));
+    let result = governor_client.try_reclaim(&voter, &id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::VotingNotEnded.into())));
+
+    e.ledger().with_mut(|li| li.timestamp += 3 * 24 * 60 * 60);
+    let result = governor_client.try_vote(&Address::generate(&e), &id, &VOTE_FOR, &1);
+    assert_eq!(result.err(), Some(Ok(GovernorError::VotingClosed.into())));
+    let eta = governor_client.queue(&id);
+    assert_eq!(eta, e.ledger().timestamp() + 24 * 60 * 60);
+
+    let result = governor_client.try_execute(&id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::TimelockNotExpired.into())));
+    e.ledger().with_mut(|li| li.timestamp = eta);
+    governor_client.execute(&id);
+    assert_eq!(target_client.value(), 7);
+    assert_eq!(governor_client.get_proposal(&id).status, ProposalStatus::Executed);
+    let result = governor_client.try_execute(&id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::ProposalNotQueued.into())));
+
+    assert_eq!(governor_client.reclaim(&voter, &id), 1200_0000000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&voter), 1500_0000000);
+    let result = governor_client.try_reclaim(&voter, &id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::NoVotes.into())));
+}
+
+#[test]
+fn test_proposal_defeated() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (governor_client, _, token) = create_governor(&e);
+    let target = e.register_contract(None, MockTarget);
+    let proposer = Address::generate(&e);
+    let voter = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&proposer, &100_0000000);
+    StellarAssetClient::new(&e, &token).mint(&voter, &2000_0000000);
+
+    let result = governor_client.try_propose(
+        &voter,
+        &set_value_calls(&e, &target, 1),
+        &String::from_str(&e, "no threshold"),
+    );
+    assert!(result.is_ok());
+    let result = governor_client.try_propose(
+        &Address::generate(&e),
+        &set_value_calls(&e, &target, 1),
+        &String::from_str(&e, "no threshold"),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(GovernorError::InsufficientVotingPower.into()))
+    );
+
+    // below quorum
+    let id_1 = governor_client.propose(
+        &proposer,
+        &set_value_calls(&e, &target, 1),
+        &String::from_str(&e, "below quorum"),
+    );
+    governor_client.vote(&voter, &id_1, &VOTE_FOR, &999_0000000);
+
+    // more votes against than for
+    let id_2 = governor_client.propose(
+        &proposer,
+        &set_value_calls(&e, &target, 2),
+        &String::from_str(&e, "voted down"),
+    );
+    governor_client.vote(&voter, &id_2, &VOTE_AGAINST, &1000_0000000);
+    governor_client.vote(&proposer, &id_2, &VOTE_FOR, &100_0000000);
+
+    e.ledger().with_mut(|li| li.timestamp += 3 * 24 * 60 * 60);
+    let result = governor_client.try_queue(&id_1);
+    assert_eq!(result.err(), Some(Ok(GovernorError::ProposalNotSucceeded.into())));
+    let result = governor_client.try_queue(&id_2);
+    assert_eq!(result.err(), Some(Ok(GovernorError::ProposalNotSucceeded.into())));
+}
+
+#[test]
+fn test_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (governor_client, guardian, token) = create_governor(&e);
+    let target = e.register_contract(None, MockTarget);
+    let proposer = Address::generate(&e);
+    let voter = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&proposer, &100_0000000);
+    StellarAssetClient::new(&e, &token).mint(&voter, &1000_0000000);
+
+    let id = governor_client.propose(
+        &proposer,
+        &set_value_calls(&e, &target, 1),
+        &String::from_str(&e, "malicious"),
+    );
+    governor_client.vote(&voter, &id, &VOTE_FOR, &1000_0000000);
+
+    let result = governor_client.try_cancel(&voter, &id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::UnauthorizedError.into())));
+    governor_client.cancel(&guardian, &id);
+    assert_eq!(governor_client.get_proposal(&id).status, ProposalStatus::Canceled);
+
+    // votes can be reclaimed as soon as a proposal is canceled
+    assert_eq!(governor_client.reclaim(&voter, &id), 1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 3 * 24 * 60 * 60);
+    let result = governor_client.try_queue(&id);
+    assert_eq!(result.err(), Some(Ok(GovernorError::InvalidProposalStatus.into())));
+}
+
+#[test]
+fn test_set_settings_by_proposal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (governor_client, _, token) = create_governor(&e);
+    let proposer = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&proposer, &1000_0000000);
+
+    let mut settings = default_settings();
+    settings.quorum = 500_0000000;
+    let calls = vec![
+        &e,
+        (
+            governor_client.address.clone(),
+            Symbol::new(&e, "set_settings"),
+            vec![&e, settings.clone().into_val(&e)],
+        ),
+    ];
+    let id = governor_client.propose(&proposer, &calls, &String::from_str(&e, "lower quorum"));
+    governor_client.vote(&proposer, &id, &VOTE_FOR, &1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 3 * 24 * 60 * 60);
+    governor_client.queue(&id);
+    e.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    governor_client.execute(&id);
+    assert_eq!(governor_client.get_settings().quorum, 500_0000000);
+    governor_client.reclaim(&proposer, &id);
+
+    // other calls to the governor can't be proposed
+    let calls = vec![
+        &e,
+        (
+            governor_client.address.clone(),
+            Symbol::new(&e, "cancel"),
+            vec![&e, proposer.into_val(&e), 0u32.into_val(&e)],
+        ),
+    ];
+    let result = governor_client.try_propose(&proposer, &calls, &String::from_str(&e, "bad"));
+    assert_eq!(result.err(), Some(Ok(GovernorError::InvalidCall.into())));
+}
@@ -0,0 +1,184 @@
+use crate::errors::InsuranceError;
+use crate::events::InsuranceEvents;
+use crate::storage;
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, unwrap::UnwrapOptimized, Address, Env,
+};
+
+#[contract]
+pub struct InsuranceContract;
+
+#[contractclient(name = "InsuranceClient")]
+pub trait Insurance {
+    /// Initialize the insurance fund
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `guardian` - The Address for the guardian, who can spend the fund on bad debt
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, guardian: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set a new guardian
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_guardian(e: Env, guardian: Address);
+
+    /// Get the guardian address
+    fn get_guardian(e: Env) -> Address;
+
+    /// Deposit protocol revenue into the fund, like a slice of flash loan fees, peg keeping
+    /// profits or harvested interest
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `token` - The token deposited
+    /// * `amount` - The amount deposited
+    fn deposit(e: Env, from: Address, token: Address, amount: i128);
+
+    /// (Admin only) Withdraw from the fund
+    ///
+    /// ### Arguments
+    /// * `token` - The token to withdraw
+    /// * `to` - The Address receiving the tokens
+    /// * `amount` - The amount to withdraw
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn withdraw(e: Env, token: Address, to: Address, amount: i128);
+
+    /// (Guardian only) Cover bad debt a pool took on in a token by burning the fund's holdings
+    /// of the token. Burning takes the unbacked tokens the bad debt left behind out of
+    /// circulation, so the rest of the supply stays fully backed.
+    ///
+    /// ### Arguments
+    /// * `pool` - The pool holding the bad debt
+    /// * `token` - The token the bad debt is in
+    /// * `amount` - The amount of bad debt to cover
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    /// If the fund holds less than `amount` of the token
+    fn cover_bad_debt(e: Env, pool: Address, token: Address, amount: i128);
+
+    /// Get the total revenue deposited into the fund in a token
+    ///
+    /// ### Arguments
+    /// * `token` - The token
+    fn get_received(e: Env, token: Address) -> i128;
+
+    /// Get the total bad debt of a pool the fund has covered in a token
+    ///
+    /// ### Arguments
+    /// * `pool` - The pool
+    /// * `token` - The token
+    fn get_covered(e: Env, pool: Address, token: Address) -> i128;
+}
+
+#[contractimpl]
+impl Insurance for InsuranceContract {
+    fn initialize(e: Env, admin: Address, guardian: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, InsuranceError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_guardian(e: Env, guardian: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+
+        InsuranceEvents::set_guardian(&e, guardian);
+    }
+
+    fn get_guardian(e: Env) -> Address {
+        storage::extend_instance(&e);
+        orbit_access::get_role(&e, Role::Guardian).unwrap_optimized()
+    }
+
+    fn deposit(e: Env, from: Address, token: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        storage::set_received(&e, &token, storage::get_received(&e, &token) + amount);
+
+        InsuranceEvents::deposit(&e, token, from, amount);
+    }
+
+    fn withdraw(e: Env, token: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_positive(&e, amount);
+
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &to, &amount);
+
+        InsuranceEvents::withdraw(&e, token, to, amount);
+    }
+
+    fn cover_bad_debt(e: Env, pool: Address, token: Address, amount: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Guardian);
+        require_positive(&e, amount);
+
+        let token_client = TokenClient::new(&e, &token);
+        if token_client.balance(&e.current_contract_address()) < amount {
+            panic_with_error!(&e, InsuranceError::InsufficientFunds);
+        }
+        token_client.burn(&e.current_contract_address(), &amount);
+        storage::set_covered(&e, &pool, &token, storage::get_covered(&e, &pool, &token) + amount);
+
+        InsuranceEvents::cover_bad_debt(&e, pool, token, amount);
+    }
+
+    fn get_received(e: Env, token: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_received(&e, &token)
+    }
+
+    fn get_covered(e: Env, pool: Address, token: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_covered(&e, &pool, &token)
+    }
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, InsuranceError::NegativeAmountError);
+    }
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the insurance fund contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Insurance fund specific errors start at 2700.
+pub enum InsuranceError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Insurance Fund
+    InsufficientFunds = 2700,
+}
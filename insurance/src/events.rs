@@ -0,0 +1,42 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct InsuranceEvents {}
+
+impl InsuranceEvents {
+    /// Emitted when the guardian is updated
+    ///
+    /// - topics - `["set_guardian"]`
+    /// - data - `guardian: Address`
+    pub fn set_guardian(e: &Env, guardian: Address) {
+        let topics = (Symbol::new(e, topics::SET_GUARDIAN),);
+        e.events().publish(topics, guardian);
+    }
+
+    /// Emitted when protocol revenue is deposited into the fund
+    ///
+    /// - topics - `["deposit", token: Address]`
+    /// - data - `[from: Address, amount: i128]`
+    pub fn deposit(e: &Env, token: Address, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), token);
+        e.events().publish(topics, (from, amount));
+    }
+
+    /// Emitted when the admin withdraws from the fund
+    ///
+    /// - topics - `["withdraw", token: Address]`
+    /// - data - `[to: Address, amount: i128]`
+    pub fn withdraw(e: &Env, token: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), token);
+        e.events().publish(topics, (to, amount));
+    }
+
+    /// Emitted when the fund covers bad debt of a pool
+    ///
+    /// - topics - `["cover_bad_debt", pool: Address, token: Address]`
+    /// - data - `amount: i128`
+    pub fn cover_bad_debt(e: &Env, pool: Address, token: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::COVER_BAD_DEBT), pool, token);
+        e.events().publish(topics, amount);
+    }
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum InsuranceDataKey {
+    // The total revenue received in a token
+    Received(Address),
+    // The total bad debt of a pool covered in a token
+    Covered(Address, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Accounting **********/
+
+/// Fetch the total revenue received in a token
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn get_received(e: &Env, token: &Address) -> i128 {
+    let key = InsuranceDataKey::Received(token.clone());
+    get_persistent_amount(e, &key)
+}
+
+/// Set the total revenue received in a token
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+/// * `amount` - The total revenue received
+pub fn set_received(e: &Env, token: &Address, amount: i128) {
+    let key = InsuranceDataKey::Received(token.clone());
+    set_persistent_amount(e, &key, amount);
+}
+
+/// Fetch the total bad debt of a pool covered in a token
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `token` - The Address of the token
+pub fn get_covered(e: &Env, pool: &Address, token: &Address) -> i128 {
+    let key = InsuranceDataKey::Covered(pool.clone(), token.clone());
+    get_persistent_amount(e, &key)
+}
+
+/// Set the total bad debt of a pool covered in a token
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+/// * `token` - The Address of the token
+/// * `amount` - The total bad debt covered
+pub fn set_covered(e: &Env, pool: &Address, token: &Address, amount: i128) {
+    let key = InsuranceDataKey::Covered(pool.clone(), token.clone());
+    set_persistent_amount(e, &key, amount);
+}
+
+fn get_persistent_amount(e: &Env, key: &InsuranceDataKey) -> i128 {
+    let result = e.storage().persistent().get::<InsuranceDataKey, i128>(key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            amount
+        }
+        None => 0,
+    }
+}
+
+fn set_persistent_amount(e: &Env, key: &InsuranceDataKey, amount: i128) {
+    e.storage()
+        .persistent()
+        .set::<InsuranceDataKey, i128>(key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
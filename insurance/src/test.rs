@@ -0,0 +1,57 @@
+#![cfg(test)]
+use crate::contract::{InsuranceClient, InsuranceContract};
+use crate::errors::InsuranceError;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+#[test]
+fn test_cover_bad_debt() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token = e.register_stellar_asset_contract(Address::generate(&e));
+    let token_client = TokenClient::new(&e, &token);
+    let admin = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let insurance_id = e.register_contract(None, InsuranceContract);
+    let insurance_client = InsuranceClient::new(&e, &insurance_id);
+    insurance_client.initialize(&admin, &guardian);
+
+    let treasury = Address::generate(&e);
+    let pool = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&treasury, &1000_0000000);
+    insurance_client.deposit(&treasury, &token, &400_0000000);
+    insurance_client.deposit(&treasury, &token, &100_0000000);
+    assert_eq!(insurance_client.get_received(&token), 500_0000000);
+    assert_eq!(token_client.balance(&insurance_id), 500_0000000);
+
+    insurance_client.cover_bad_debt(&pool, &token, &300_0000000);
+    assert_eq!(
+        e.auths()[0],
+        (
+            guardian.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    insurance_id.clone(),
+                    Symbol::new(&e, "cover_bad_debt"),
+                    vec![
+                        &e,
+                        pool.into_val(&e),
+                        token.into_val(&e),
+                        300_0000000_i128.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+    assert_eq!(insurance_client.get_covered(&pool, &token), 300_0000000);
+    assert_eq!(token_client.balance(&insurance_id), 200_0000000);
+
+    let result = insurance_client.try_cover_bad_debt(&pool, &token, &200_0000001);
+    assert_eq!(result.err(), Some(Ok(InsuranceError::InsufficientFunds.into())));
+
+    insurance_client.withdraw(&token, &treasury, &200_0000000);
+    assert_eq!(token_client.balance(&treasury), 700_0000000);
+    assert_eq!(token_client.balance(&insurance_id), 0);
+}
@@ -0,0 +1,106 @@
+use crate::dependencies::pool::{AuctionData, Client as PoolClient, Positions};
+use orbit_common::math::{self, SCALAR_7};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, vec, Address, Env, Map, Vec,
+};
+
+const PER_BLOCK: i128 = 50000; // the auction modifiers move 0.5% every block
+const USER_LIQUIDATION: u32 = 0;
+
+/// The liquidation state of a user in a pool
+#[derive(Clone)]
+#[contracttype]
+pub struct LiquidationInfo {
+    pub user: Address,
+    pub positions: Positions,    // the user's b-token and d-token positions by reserve index
+    pub has_auction: bool,       // whether the user is being liquidated
+    pub bid: Map<Address, i128>, // the liabilities the filler takes on now, if being liquidated
+    pub lot: Map<Address, i128>, // the collateral the filler receives now, if being liquidated
+    pub block: u32,              // the block the liquidation auction started, if being liquidated
+}
+
+#[contract]
+pub struct LiquidationQueryContract;
+
+#[contractclient(name = "LiquidationQueryClient")]
+pub trait LiquidationQuery {
+    /// Fetch the liquidation state of several users in a Blend pool at once, so liquidation bots
+    /// don't need a pool read per user and per auction.
+    ///
+    /// The pool does not expose its reserve rates, so positions are returned in b-tokens and
+    /// d-tokens for the caller to value. Auction lots and bids are scaled the way the pool fills
+    /// them at the current block.
+    ///
+    /// ### Arguments
+    /// * `pool` - The Address of the Blend pool
+    /// * `users` - The Addresses of the users
+    fn query(e: Env, pool: Address, users: Vec<Address>) -> Vec<LiquidationInfo>;
+}
+
+#[contractimpl]
+impl LiquidationQuery for LiquidationQueryContract {
+    fn query(e: Env, pool: Address, users: Vec<Address>) -> Vec<LiquidationInfo> {
+        let pool_client = PoolClient::new(&e, &pool);
+        let mut infos = vec![&e];
+        for user in users.iter() {
+            let positions = pool_client.get_positions(&user);
+            let info = match pool_client.try_get_auction(&USER_LIQUIDATION, &user) {
+                Ok(Ok(auction)) => {
+                    let auction = scale_auction(&e, auction);
+                    LiquidationInfo {
+                        user,
+                        positions,
+                        has_auction: true,
+                        bid: auction.bid,
+                        lot: auction.lot,
+                        block: auction.block,
+                    }
+                }
+                _ => LiquidationInfo {
+                    user,
+                    positions,
+                    has_auction: false,
+                    bid: Map::new(&e),
+                    lot: Map::new(&e),
+                    block: 0,
+                },
+            };
+            infos.push_back(info);
+        }
+        infos
+    }
+}
+
+/// Scale an auction to the current block. The lot grows from 0% to 100% over the first 200
+/// blocks, then the bid shrinks from 100% to 0% over the next 200.
+fn scale_auction(e: &Env, auction: AuctionData) -> AuctionData {
+    let blocks = (e.ledger().sequence() - auction.block) as i128;
+    let (bid_modifier, lot_modifier) = if blocks <= 200 {
+        (SCALAR_7, blocks * PER_BLOCK)
+    } else if blocks < 400 {
+        (SCALAR_7 - (blocks - 200) * PER_BLOCK, SCALAR_7)
+    } else {
+        (0, SCALAR_7)
+    };
+
+    let mut bid = Map::new(e);
+    for (asset, amount) in auction.bid.iter() {
+        // round the bid up, as the pool does
+        let scaled = math::mul_div_ceil(e, amount, bid_modifier, SCALAR_7);
+        if scaled > 0 {
+            bid.set(asset, scaled);
+        }
+    }
+    let mut lot = Map::new(e);
+    for (asset, amount) in auction.lot.iter() {
+        let scaled = math::apply_pct(e, amount, lot_modifier);
+        if scaled > 0 {
+            lot.set(asset, scaled);
+        }
+    }
+    AuctionData {
+        bid,
+        lot,
+        block: auction.block,
+    }
+}
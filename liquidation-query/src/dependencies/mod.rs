@@ -0,0 +1 @@
+pub use orbit_common::pool;
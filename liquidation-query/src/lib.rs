@@ -0,0 +1,8 @@
+#![no_std]
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+mod contract;
+mod dependencies;
+mod test;
+
+pub use contract::*;
@@ -0,0 +1,131 @@
+#![cfg(test)]
+use crate::contract::{LiquidationQueryClient, LiquidationQueryContract};
+use crate::dependencies::pool::{AuctionData, Positions};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, map, vec, Address, Env};
+
+/// Stores one liquidation auction, like a Blend pool
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_auction(e: Env, user: Address, auction: AuctionData) {
+        e.storage().instance().set(&user, &auction);
+    }
+
+    pub fn get_positions(e: Env, user: Address) -> Positions {
+        let debt = if e.storage().instance().has(&user) { 100_0000000 } else { 0 };
+        Positions {
+            collateral: map![&e, (0, 200_0000000)],
+            liabilities: map![&e, (1, debt)],
+            supply: map![&e],
+        }
+    }
+
+    pub fn get_auction(e: Env, _auction_type: u32, user: Address) -> AuctionData {
+        e.storage().instance().get(&user).unwrap()
+    }
+}
+
+#[test]
+fn test_query_scales_auctions() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.sequence_number = 1000);
+    let pool = e.register_contract(None, MockPool);
+    let query_id = e.register_contract(None, LiquidationQueryContract);
+    let query_client = LiquidationQueryClient::new(&e, &query_id);
+    let collateral = Address::generate(&e);
+    let debt = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    MockPoolClient::new(&e, &pool).set_auction(
+        &frodo,
+        &AuctionData {
+            bid: map![&e, (debt.clone(), 100_0000000)],
+            lot: map![&e, (collateral.clone(), 150_0000000)],
+            block: 900,
+        },
+    );
+
+    // halfway through the lot ramp
+    let infos = query_client.query(&pool, &vec![&e, samwise.clone(), frodo.clone()]);
+    assert_eq!(infos.len(), 2);
+    let info = infos.get(0).unwrap();
+    assert_eq!(info.user, samwise);
+    assert_eq!(info.positions.liabilities.get(1), Some(0));
+    assert!(!info.has_auction);
+    let auction = infos.get(1).unwrap();
+    assert!(auction.has_auction);
+    assert_eq!(auction.bid.get(debt.clone()), Some(100_0000000));
+    assert_eq!(auction.lot.get(collateral.clone()), Some(75_0000000));
+
+    // halfway through the bid ramp
+    e.ledger().with_mut(|li| li.sequence_number = 1200);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    let auction = infos.get(0).unwrap();
+    assert_eq!(auction.bid.get(debt.clone()), Some(50_0000000));
+    assert_eq!(auction.lot.get(collateral.clone()), Some(150_0000000));
+
+    // the bid is free after 400 blocks
+    e.ledger().with_mut(|li| li.sequence_number = 1300);
+    let infos = query_client.query(&pool, &vec![&e, frodo]);
+    let auction = infos.get(0).unwrap();
+    assert_eq!(auction.bid.get(debt), None);
+    assert_eq!(auction.block, 900);
+}
+
+#[test]
+fn test_query_boundaries() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.sequence_number = 1000);
+    let pool = e.register_contract(None, MockPool);
+    let query_id = e.register_contract(None, LiquidationQueryContract);
+    let query_client = LiquidationQueryClient::new(&e, &query_id);
+    let collateral = Address::generate(&e);
+    let debt = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    MockPoolClient::new(&e, &pool).set_auction(
+        &frodo,
+        &AuctionData {
+            bid: map![&e, (debt.clone(), 100_0000001)],
+            lot: map![&e, (collateral.clone(), 199)],
+            block: 1000,
+        },
+    );
+
+    assert_eq!(query_client.query(&pool, &vec![&e]).len(), 0);
+
+    // an auction starting this block offers no lot yet
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    let auction = infos.get(0).unwrap();
+    assert!(auction.has_auction);
+    assert_eq!(auction.bid.get(debt.clone()), Some(100_0000001));
+    assert_eq!(auction.lot.len(), 0);
+
+    // the lot rounds down, so dust lots are left out until they are worth something
+    e.ledger().with_mut(|li| li.sequence_number = 1001);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    assert_eq!(infos.get(0).unwrap().lot.len(), 0);
+    e.ledger().with_mut(|li| li.sequence_number = 1002);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    assert_eq!(infos.get(0).unwrap().lot.get(collateral.clone()), Some(1));
+
+    // both ramps are full at 200 blocks
+    e.ledger().with_mut(|li| li.sequence_number = 1200);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    let auction = infos.get(0).unwrap();
+    assert_eq!(auction.bid.get(debt.clone()), Some(100_0000001));
+    assert_eq!(auction.lot.get(collateral), Some(199));
+
+    // the bid rounds up, so it is owed until the last block of the ramp
+    e.ledger().with_mut(|li| li.sequence_number = 1201);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    assert_eq!(infos.get(0).unwrap().bid.get(debt.clone()), Some(99_5000001));
+    e.ledger().with_mut(|li| li.sequence_number = 1399);
+    let infos = query_client.query(&pool, &vec![&e, frodo.clone()]);
+    assert_eq!(infos.get(0).unwrap().bid.get(debt.clone()), Some(5000001));
+    e.ledger().with_mut(|li| li.sequence_number = 1400);
+    let infos = query_client.query(&pool, &vec![&e, frodo]);
+    assert_eq!(infos.get(0).unwrap().bid.len(), 0);
+}
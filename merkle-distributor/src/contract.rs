@@ -0,0 +1,211 @@
+use crate::errors::MerkleDistributorError;
+use crate::events::MerkleDistributorEvents;
+use crate::storage;
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Vec,
+};
+
+#[contract]
+pub struct MerkleDistributorContract;
+
+#[contractclient(name = "MerkleDistributorClient")]
+pub trait MerkleDistributor {
+    /// Initialize the distributor with its first distribution. The distributor is funded by
+    /// sending it tokens.
+    ///
+    /// Each leaf of a distribution is the sha256 hash of the XDR of `(index, claimant, amount)`,
+    /// and each node is the sha256 hash of its two children concatenated in ascending order.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the distributed token
+    /// * `root` - The merkle root of the distribution
+    /// * `deadline` - The time claims close, in seconds
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, token: Address, root: BytesN<32>, deadline: u64);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Start a new distribution once the current one has closed
+    ///
+    /// ### Arguments
+    /// * `root` - The merkle root of the distribution
+    /// * `deadline` - The time claims close, in seconds
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the current distribution has not closed
+    fn set_root(e: Env, root: BytesN<32>, deadline: u64);
+
+    /// Get the merkle root of the current distribution
+    fn get_root(e: Env) -> BytesN<32>;
+
+    /// Get the time claims for the current distribution close
+    fn get_deadline(e: Env) -> u64;
+
+    /// Check if a leaf of the current distribution was claimed
+    ///
+    /// ### Arguments
+    /// * `index` - The index of the leaf
+    fn is_claimed(e: Env, index: u32) -> bool;
+
+    /// Claim tokens from the current distribution. Anyone can submit a claim, and the tokens
+    /// are always sent to the claimant.
+    ///
+    /// ### Arguments
+    /// * `claimant` - The Address receiving the tokens
+    /// * `index` - The index of the leaf
+    /// * `amount` - The amount of tokens in the leaf
+    /// * `proof` - The sibling hashes from the leaf up to the root
+    ///
+    /// ### Panics
+    /// If the claim period has ended
+    /// If the leaf was already claimed
+    /// If the proof does not match the merkle root
+    fn claim(e: Env, claimant: Address, index: u32, amount: i128, proof: Vec<BytesN<32>>);
+
+    /// (Admin only) Reclaim the unclaimed tokens once the current distribution has closed
+    ///
+    /// Returns the amount of tokens reclaimed
+    ///
+    /// ### Arguments
+    /// * `to` - The Address receiving the tokens
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the current distribution has not closed
+    fn reclaim(e: Env, to: Address) -> i128;
+}
+
+#[contractimpl]
+impl MerkleDistributor for MerkleDistributorContract {
+    fn initialize(e: Env, admin: Address, token: Address, root: BytesN<32>, deadline: u64) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, MerkleDistributorError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_root(&e, &root);
+        storage::set_deadline(&e, deadline);
+        storage::set_is_init(&e);
+
+        MerkleDistributorEvents::set_root(&e, root, deadline);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_root(e: Env, root: BytesN<32>, deadline: u64) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_closed(&e);
+
+        storage::set_root(&e, &root);
+        storage::set_deadline(&e, deadline);
+
+        MerkleDistributorEvents::set_root(&e, root, deadline);
+    }
+
+    fn get_root(e: Env) -> BytesN<32> {
+        storage::extend_instance(&e);
+        storage::get_root(&e)
+    }
+
+    fn get_deadline(e: Env) -> u64 {
+        storage::extend_instance(&e);
+        storage::get_deadline(&e)
+    }
+
+    fn is_claimed(e: Env, index: u32) -> bool {
+        storage::extend_instance(&e);
+        storage::get_claimed(&e, &storage::get_root(&e), index)
+    }
+
+    fn claim(e: Env, claimant: Address, index: u32, amount: i128, proof: Vec<BytesN<32>>) {
+        storage::extend_instance(&e);
+        if e.ledger().timestamp() > storage::get_deadline(&e) {
+            panic_with_error!(&e, MerkleDistributorError::ClaimPeriodEnded);
+        }
+        if amount <= 0 {
+            panic_with_error!(&e, MerkleDistributorError::NegativeAmountError);
+        }
+        let root = storage::get_root(&e);
+        if storage::get_claimed(&e, &root, index) {
+            panic_with_error!(&e, MerkleDistributorError::AlreadyClaimed);
+        }
+        let mut node = hash_leaf(&e, index, &claimant, amount);
+        for sibling in proof.iter() {
+            node = hash_pair(&e, &node, &sibling);
+        }
+        if node != root {
+            panic_with_error!(&e, MerkleDistributorError::InvalidProof);
+        }
+
+        storage::set_claimed(&e, &root, index);
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &e.current_contract_address(),
+            &claimant,
+            &amount,
+        );
+
+        MerkleDistributorEvents::claim(&e, index, claimant, amount);
+    }
+
+    fn reclaim(e: Env, to: Address) -> i128 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_closed(&e);
+
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        let amount = token_client.balance(&e.current_contract_address());
+        if amount > 0 {
+            token_client.transfer(&e.current_contract_address(), &to, &amount);
+        }
+
+        MerkleDistributorEvents::reclaim(&e, to, amount);
+        amount
+    }
+}
+
+/// Require that the claim period of the current distribution has ended
+///
+/// ### Panics
+/// If the current distribution has not closed
+fn require_closed(e: &Env) {
+    if e.ledger().timestamp() <= storage::get_deadline(e) {
+        panic_with_error!(e, MerkleDistributorError::ClaimPeriodActive);
+    }
+}
+
+/// Hash a leaf of a distribution
+pub(crate) fn hash_leaf(e: &Env, index: u32, claimant: &Address, amount: i128) -> BytesN<32> {
+    e.crypto()
+        .sha256(&(index, claimant.clone(), amount).to_xdr(e))
+}
+
+/// Hash two nodes of a merkle tree, in ascending order
+pub(crate) fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(e, &first.to_array());
+    data.append(&Bytes::from_array(e, &second.to_array()));
+    e.crypto().sha256(&data)
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the merkle distributor contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Merkle distributor specific errors start at 3800.
+pub enum MerkleDistributorError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Merkle Distributor
+    InvalidProof = 3800,
+    AlreadyClaimed = 3801,
+    ClaimPeriodEnded = 3802,
+    ClaimPeriodActive = 3803,
+}
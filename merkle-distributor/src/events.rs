@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub struct MerkleDistributorEvents {}
+
+impl MerkleDistributorEvents {
+    /// Emitted when the admin starts a new distribution
+    ///
+    /// - topics - `["set_root", root: BytesN<32>]`
+    /// - data - `deadline: u64`
+    pub fn set_root(e: &Env, root: BytesN<32>, deadline: u64) {
+        let topics = (Symbol::new(e, topics::SET_ROOT), root);
+        e.events().publish(topics, deadline);
+    }
+
+    /// Emitted when a leaf of the current distribution is claimed
+    ///
+    /// - topics - `["claim", index: u32, claimant: Address]`
+    /// - data - `amount: i128`
+    pub fn claim(e: &Env, index: u32, claimant: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::CLAIM), index, claimant);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when the admin reclaims the unclaimed tokens after the deadline
+    ///
+    /// - topics - `["reclaim", to: Address]`
+    /// - data - `amount: i128`
+    pub fn reclaim(e: &Env, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::RECLAIM), to);
+        e.events().publish(topics, amount);
+    }
+}
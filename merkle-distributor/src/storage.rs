@@ -0,0 +1,138 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, BytesN, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const ROOT_KEY: &str = "Root";
+const DEADLINE_KEY: &str = "Deadline";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MerkleDistributorDataKey {
+    // If a leaf of a distribution was claimed, by merkle root and leaf index
+    Claimed(BytesN<32>, u32),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the distributed token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the distributed token
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Distribution **********/
+
+/// Fetch the merkle root of the current distribution
+///
+/// ### Panics
+/// If the root does not exist
+pub fn get_root(e: &Env) -> BytesN<32> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ROOT_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the merkle root of the current distribution
+///
+/// ### Arguments
+/// * `root` - The merkle root
+pub fn set_root(e: &Env, root: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, BytesN<32>>(&Symbol::new(e, ROOT_KEY), root);
+}
+
+/// Fetch the time claims for the current distribution close
+///
+/// ### Panics
+/// If the deadline does not exist
+pub fn get_deadline(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, DEADLINE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the time claims for the current distribution close
+///
+/// ### Arguments
+/// * `deadline` - The deadline, in seconds
+pub fn set_deadline(e: &Env, deadline: u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, DEADLINE_KEY), &deadline);
+}
+
+/********** Claims **********/
+
+/// Check if a leaf of a distribution was claimed
+///
+/// ### Arguments
+/// * `root` - The merkle root of the distribution
+/// * `index` - The index of the leaf
+pub fn get_claimed(e: &Env, root: &BytesN<32>, index: u32) -> bool {
+    let key = MerkleDistributorDataKey::Claimed(root.clone(), index);
+    let result = e.storage().persistent().has(&key);
+    if result {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set a leaf of a distribution as claimed
+///
+/// ### Arguments
+/// * `root` - The merkle root of the distribution
+/// * `index` - The index of the leaf
+pub fn set_claimed(e: &Env, root: &BytesN<32>, index: u32) {
+    let key = MerkleDistributorDataKey::Claimed(root.clone(), index);
+    e.storage()
+        .persistent()
+        .set::<MerkleDistributorDataKey, bool>(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+use crate::contract::{hash_leaf, hash_pair, MerkleDistributorClient, MerkleDistributorContract};
+use crate::errors::MerkleDistributorError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, BytesN, Env};
+
+#[test]
+fn test_claim_and_reclaim() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let token = e.register_stellar_asset_contract(Address::generate(&e));
+    let token_client = TokenClient::new(&e, &token);
+    let admin = Address::generate(&e);
+    let claimants = [
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    ];
+    let amounts = [100_0000000, 250_0000000, 50_0000000, 600_0000000];
+
+    // build a tree with 4 leaves
+    let leaves: std::vec::Vec<BytesN<32>> = (0..4)
+        .map(|i| hash_leaf(&e, i as u32, &claimants[i], amounts[i]))
+        .collect();
+    let left = hash_pair(&e, &leaves[0], &leaves[1]);
+    let right = hash_pair(&e, &leaves[2], &leaves[3]);
+    let root = hash_pair(&e, &left, &right);
+
+    let distributor_id = e.register_contract(None, MerkleDistributorContract);
+    let distributor_client = MerkleDistributorClient::new(&e, &distributor_id);
+    distributor_client.initialize(&admin, &token, &root, &(1000 + 30 * 24 * 60 * 60));
+    StellarAssetClient::new(&e, &token).mint(&distributor_id, &1000_0000000);
+
+    distributor_client.claim(
+        &claimants[1],
+        &1,
+        &amounts[1],
+        &vec![&e, leaves[0].clone(), right.clone()],
+    );
+    assert!(distributor_client.is_claimed(&1));
+    assert!(!distributor_client.is_claimed(&0));
+    assert_eq!(token_client.balance(&claimants[1]), 250_0000000);
+
+    let result = distributor_client.try_claim(
+        &claimants[1],
+        &1,
+        &amounts[1],
+        &vec![&e, leaves[0].clone(), right.clone()],
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(MerkleDistributorError::AlreadyClaimed.into()))
+    );
+
+    // claiming more than the leaf amount fails the proof
+    let result = distributor_client.try_claim(
+        &claimants[2],
+        &2,
+        &500_0000000,
+        &vec![&e, leaves[3].clone(), left.clone()],
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(MerkleDistributorError::InvalidProof.into()))
+    );
+    distributor_client.claim(
+        &claimants[2],
+        &2,
+        &amounts[2],
+        &vec![&e, leaves[3].clone(), left.clone()],
+    );
+
+    let result = distributor_client.try_reclaim(&admin);
+    assert_eq!(
+        result.err(),
+        Some(Ok(MerkleDistributorError::ClaimPeriodActive.into()))
+    );
+
+    // the unclaimed balance is reclaimable after the deadline
+    e.ledger().with_mut(|li| li.timestamp += 30 * 24 * 60 * 60 + 1);
+    let result = distributor_client.try_claim(
+        &claimants[3],
+        &3,
+        &amounts[3],
+        &vec![&e, leaves[2].clone(), left.clone()],
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(MerkleDistributorError::ClaimPeriodEnded.into()))
+    );
+    assert_eq!(distributor_client.reclaim(&admin), 700_0000000);
+    assert_eq!(token_client.balance(&admin), 700_0000000);
+    assert_eq!(token_client.balance(&distributor_id), 0);
+
+    // a new distribution tracks its own claims
+    let new_root = hash_pair(&e, &leaves[1], &leaves[0]);
+    distributor_client.set_root(&new_root, &(e.ledger().timestamp() + 1000));
+    assert_eq!(distributor_client.get_root(), new_root);
+    assert!(!distributor_client.is_claimed(&1));
+}
@@ -0,0 +1,140 @@
+use crate::errors::MockAmmError;
+use crate::storage::{self, SwapBehavior};
+use orbit_common::soroswap::SoroswapRouter;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+const BPS: i128 = 10000;
+
+#[contract]
+pub struct MockAmmContract;
+
+/// A stand-in for the Soroswap router, which swaps with a set behavior. The AMM pays out of its
+/// own balance, so the test must fund it. Swap with a `SoroswapRouterClient`.
+#[contractclient(name = "MockAmmClient")]
+pub trait MockAmm {
+    /// Set the behavior of swaps when no behavior is queued. Swaps fail until it is set.
+    ///
+    /// ### Arguments
+    /// * `behavior` - The default behavior
+    fn set_default(e: Env, behavior: SwapBehavior);
+
+    /// Queue a behavior for a single swap. Queued behaviors are used in order, one per swap,
+    /// before falling back to the default.
+    ///
+    /// ### Arguments
+    /// * `behavior` - The behavior of the swap
+    fn queue(e: Env, behavior: SwapBehavior);
+
+    /// Clear the queued behaviors. A failed swap reverts, so a queued `SwapBehavior::Fail`
+    /// stays at the front of the queue until it is cleared.
+    fn clear_queue(e: Env);
+
+    /// Get the number of swaps made
+    fn get_swaps(e: Env) -> u32;
+}
+
+#[contractimpl]
+impl MockAmm for MockAmmContract {
+    fn set_default(e: Env, behavior: SwapBehavior) {
+        storage::extend_instance(&e);
+        storage::set_default(&e, &behavior);
+    }
+
+    fn queue(e: Env, behavior: SwapBehavior) {
+        storage::extend_instance(&e);
+        let mut queue = storage::get_queue(&e);
+        queue.push_back(behavior);
+        storage::set_queue(&e, &queue);
+    }
+
+    fn clear_queue(e: Env) {
+        storage::extend_instance(&e);
+        storage::set_queue(&e, &vec![&e]);
+    }
+
+    fn get_swaps(e: Env) -> u32 {
+        storage::get_swaps(&e)
+    }
+}
+
+#[contractimpl]
+impl SoroswapRouter for MockAmmContract {
+    /// Quote a swap with the behavior the next swap will use, without using it up
+    ///
+    /// ### Panics
+    /// If the path is not a pair of tokens
+    /// If the next swap is set to fail
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128> {
+        storage::extend_instance(&e);
+        require_pair(&e, &path);
+        let behavior = storage::get_queue(&e)
+            .first()
+            .unwrap_or_else(|| storage::get_default(&e));
+        vec![&e, amount_in, amount_out(&e, &behavior, amount_in)]
+    }
+
+    /// Swap with the next behavior, paid out of the AMM's own balance
+    ///
+    /// ### Panics
+    /// If the path is not a pair of tokens
+    /// If the swap is set to fail
+    /// If less than `amount_out_min` would be received
+    /// If the deadline has passed
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128> {
+        to.require_auth();
+        storage::extend_instance(&e);
+        require_pair(&e, &path);
+        if amount_in < 0 || amount_out_min < 0 {
+            panic_with_error!(&e, MockAmmError::NegativeAmountError);
+        }
+        if e.ledger().timestamp() > deadline {
+            panic_with_error!(&e, MockAmmError::Expired);
+        }
+
+        let mut queue = storage::get_queue(&e);
+        let behavior = match queue.pop_front() {
+            Some(behavior) => {
+                storage::set_queue(&e, &queue);
+                behavior
+            }
+            None => storage::get_default(&e),
+        };
+        let amount_out = amount_out(&e, &behavior, amount_in);
+        if amount_out < amount_out_min {
+            panic_with_error!(&e, MockAmmError::InsufficientOutputAmount);
+        }
+
+        let this = e.current_contract_address();
+        TokenClient::new(&e, &path.get_unchecked(0)).transfer(&to, &this, &amount_in);
+        TokenClient::new(&e, &path.get_unchecked(1)).transfer(&this, &to, &amount_out);
+        storage::set_swaps(&e, storage::get_swaps(&e) + 1);
+        vec![&e, amount_in, amount_out]
+    }
+}
+
+/// Require a path of exactly two tokens
+fn require_pair(e: &Env, path: &Vec<Address>) {
+    if path.len() != 2 {
+        panic_with_error!(e, MockAmmError::InvalidPath);
+    }
+}
+
+/// Calculate the amount paid out for a swap
+fn amount_out(e: &Env, behavior: &SwapBehavior, amount_in: i128) -> i128 {
+    match behavior {
+        SwapBehavior::Exact(amount) => *amount,
+        SwapBehavior::Curve(reserve_in, reserve_out, fee) => {
+            let amount_in_after_fee = amount_in * (BPS - *fee as i128) / BPS;
+            reserve_out * amount_in_after_fee / (reserve_in + amount_in_after_fee)
+        }
+        SwapBehavior::Fail => panic_with_error!(e, MockAmmError::SwapFailed),
+    }
+}
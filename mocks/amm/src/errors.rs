@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the AMM mock. Common errors are codes that match up with the built-in
+/// contracts error reporting. AMM mock specific errors start at 5100.
+pub enum MockAmmError {
+    // Common Errors
+    InternalError = 1,
+    NegativeAmountError = 8,
+
+    // Mock AMM
+    InvalidPath = 5100,
+    SwapFailed = 5101,
+    InsufficientOutputAmount = 5102,
+    Expired = 5103,
+}
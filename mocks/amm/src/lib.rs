@@ -0,0 +1,11 @@
+#![no_std]
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+mod contract;
+mod errors;
+mod storage;
+mod test;
+
+pub use contract::*;
+pub use errors::MockAmmError;
+pub use storage::SwapBehavior;
@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, vec, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const DEFAULT_KEY: &str = "Default";
+const QUEUE_KEY: &str = "Queue";
+const SWAPS_KEY: &str = "Swaps";
+
+/// How the AMM prices a swap
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum SwapBehavior {
+    // Pay out a fixed amount, whatever the input
+    Exact(i128),
+    // Pay out along a constant product curve of (reserve in, reserve out, fee in bps), so
+    // larger swaps slip further from the spot price
+    Curve(i128, i128, u32),
+    // Revert the swap
+    Fail,
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the behavior used when no behavior is queued
+pub fn get_default(e: &Env) -> SwapBehavior {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, DEFAULT_KEY))
+        .unwrap_or(SwapBehavior::Fail)
+}
+
+/// Set the behavior used when no behavior is queued
+///
+/// ### Arguments
+/// * `behavior` - The default behavior
+pub fn set_default(e: &Env, behavior: &SwapBehavior) {
+    e.storage()
+        .instance()
+        .set::<Symbol, SwapBehavior>(&Symbol::new(e, DEFAULT_KEY), behavior);
+}
+
+/// Fetch the behaviors queued for the next swaps, in order
+pub fn get_queue(e: &Env) -> Vec<SwapBehavior> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, QUEUE_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the behaviors queued for the next swaps
+///
+/// ### Arguments
+/// * `queue` - The behaviors, in order
+pub fn set_queue(e: &Env, queue: &Vec<SwapBehavior>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<SwapBehavior>>(&Symbol::new(e, QUEUE_KEY), queue);
+}
+
+/// Fetch the number of swaps made
+pub fn get_swaps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SWAPS_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of swaps made
+///
+/// ### Arguments
+/// * `swaps` - The number of swaps made
+pub fn set_swaps(e: &Env, swaps: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, SWAPS_KEY), &swaps);
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+use crate::contract::{MockAmmClient, MockAmmContract};
+use crate::errors::MockAmmError;
+use crate::storage::SwapBehavior;
+use orbit_common::soroswap::SoroswapRouterClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env};
+
+#[test]
+fn test_swap_behaviors() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let usdc = e.register_stellar_asset_contract(Address::generate(&e));
+    let amm_id = e.register_contract(None, MockAmmContract);
+    let amm_client = MockAmmClient::new(&e, &amm_id);
+    let router = SoroswapRouterClient::new(&e, &amm_id);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&samwise, &1000_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&amm_id, &10000_0000000);
+    let path = vec![&e, ousd.clone(), usdc.clone()];
+
+    // swaps fail until a behavior is set
+    let result = router.try_swap_exact_tokens_for_tokens(
+        &1_0000000,
+        &0,
+        &path,
+        &samwise,
+        &1000,
+    );
+    assert_eq!(result.err(), Some(Ok(MockAmmError::SwapFailed.into())));
+
+    // a curve with 10k of each token and no fee slips 1% on a 100 token swap
+    amm_client.set_default(&SwapBehavior::Curve(10000_0000000, 10000_0000000, 0));
+    let amounts = router.router_get_amounts_out(&100_0000000, &path);
+    assert_eq!(amounts, vec![&e, 100_0000000, 99_0099009]);
+    let result = router.try_swap_exact_tokens_for_tokens(
+        &100_0000000,
+        &100_0000000,
+        &path,
+        &samwise,
+        &1000,
+    );
+    assert_eq!(result.err(), Some(Ok(MockAmmError::InsufficientOutputAmount.into())));
+    router.swap_exact_tokens_for_tokens(&100_0000000, &99_0000000, &path, &samwise, &1000);
+    assert_eq!(TokenClient::new(&e, &usdc).balance(&samwise), 99_0099009);
+
+    // queued behaviors are used once each, in order
+    amm_client.queue(&SwapBehavior::Exact(50_0000000));
+    amm_client.queue(&SwapBehavior::Fail);
+    let amounts = router.swap_exact_tokens_for_tokens(&100_0000000, &0, &path, &samwise, &1000);
+    assert_eq!(amounts, vec![&e, 100_0000000, 50_0000000]);
+    let result = router.try_swap_exact_tokens_for_tokens(
+        &100_0000000,
+        &0,
+        &path,
+        &samwise,
+        &1000,
+    );
+    assert_eq!(result.err(), Some(Ok(MockAmmError::SwapFailed.into())));
+    amm_client.clear_queue();
+    let amounts = router.router_get_amounts_out(&100_0000000, &path);
+    assert_eq!(amounts.get_unchecked(1), 99_0099009);
+    assert_eq!(amm_client.get_swaps(), 2);
+    assert_eq!(TokenClient::new(&e, &ousd).balance(&samwise), 800_0000000);
+
+    let result = router.try_swap_exact_tokens_for_tokens(&1_0000000, &0, &path, &samwise, &999);
+    assert_eq!(result.err(), Some(Ok(MockAmmError::Expired.into())));
+    let path = vec![&e, ousd.clone()];
+    let result = router.try_router_get_amounts_out(&1_0000000, &path);
+    assert_eq!(result.err(), Some(Ok(MockAmmError::InvalidPath.into())));
+}
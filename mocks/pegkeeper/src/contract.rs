@@ -0,0 +1,133 @@
+use crate::errors::MockPegkeeperError;
+use crate::storage::{self, Failure};
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Bytes, Env, Symbol,
+};
+
+#[contract]
+pub struct MockPegkeeperContract;
+
+#[contractclient(name = "MockPegkeeperClient")]
+pub trait MockPegkeeper {
+    /// Initialize the mock pegkeeper. A stand-in for a pegkeeper in tests of the treasury, which
+    /// pays back each loan out of its own balance, so the test must fund it for the fees, and
+    /// can be set by the admin to fail to pay back in each of the ways a pegkeeper can.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address of the admin
+    /// * `treasury` - The Address of the treasury lending to the pegkeeper
+    /// * `token` - The Address of the token lent
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, treasury: Address, token: Address);
+
+    /// (Admin only) Set how the pegkeeper fails to pay back loans
+    ///
+    /// ### Arguments
+    /// * `failure` - How loans are not paid back, `Failure::None` by default
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_failure(e: Env, failure: Failure);
+
+    /// Fetch how the pegkeeper fails to pay back loans
+    fn get_failure(e: Env) -> Failure;
+
+    /// Fetch the amount held back from loans to be repaid late
+    fn get_owed(e: Env) -> i128;
+
+    /// Pay the treasury what was held back from loans with `Failure::RepayLate`. Returns the
+    /// amount paid.
+    fn repay_late(e: Env) -> i128;
+
+    /// Receive a loan from the treasury, and pay it back as set by `set_failure`
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the token lent
+    /// * `amount` - The amount lent
+    /// * `fee` - The fee owed on top of the loan
+    /// * `data` - Arbitrary data passed through by the treasury, unused
+    ///
+    /// ### Panics
+    /// If a loan is already being received, with `ReentrantCallError`
+    /// If the caller is not the treasury
+    /// If the pegkeeper is set to `Failure::Panic`
+    fn fl_receive(e: Env, token: Address, amount: i128, fee: i128, data: Bytes);
+}
+
+#[contractimpl]
+impl MockPegkeeper for MockPegkeeperContract {
+    fn initialize(e: Env, admin: Address, treasury: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, MockPegkeeperError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_treasury(&e, &treasury);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_failure(e: Env, failure: Failure) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        storage::set_failure(&e, &failure);
+    }
+
+    fn get_failure(e: Env) -> Failure {
+        storage::get_failure(&e)
+    }
+
+    fn get_owed(e: Env) -> i128 {
+        storage::get_owed(&e)
+    }
+
+    fn repay_late(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        let owed = storage::get_owed(&e);
+        if owed > 0 {
+            TokenClient::new(&e, &storage::get_token(&e)).transfer(
+                &e.current_contract_address(),
+                &storage::get_treasury(&e),
+                &owed,
+            );
+            storage::set_owed(&e, 0);
+        }
+        owed
+    }
+
+    fn fl_receive(e: Env, token: Address, amount: i128, fee: i128, _data: Bytes) {
+        storage::extend_instance(&e);
+        orbit_access::nonreentrant!(&e);
+        let treasury = storage::get_treasury(&e);
+        treasury.require_auth();
+
+        let this = e.current_contract_address();
+        let token_client = TokenClient::new(&e, &token);
+        let owed = amount + fee;
+        let repaid = match storage::get_failure(&e) {
+            Failure::None => owed,
+            Failure::DontRepay => 0,
+            Failure::RepayShort(shortfall) => owed - shortfall,
+            Failure::RepayLate => {
+                storage::set_owed(&e, storage::get_owed(&e) + owed);
+                0
+            }
+            Failure::Panic => {
+                let admin = orbit_access::get_role(&e, Role::Admin).unwrap();
+                token_client.transfer(&this, &admin, &amount);
+                panic_with_error!(&e, MockPegkeeperError::StrategyFailed);
+            }
+        };
+        if repaid > 0 {
+            token_client.transfer(&this, &treasury, &repaid);
+        }
+
+        let topics = (Symbol::new(&e, "fl_receive"), treasury);
+        e.events().publish(topics, (amount, repaid));
+    }
+}
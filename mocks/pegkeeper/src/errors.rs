@@ -0,0 +1,15 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the pegkeeper mock. Common errors are codes that match up with the built-in
+/// contracts error reporting. Pegkeeper mock specific errors start at 5400.
+pub enum MockPegkeeperError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+
+    // Mock Pegkeeper
+    StrategyFailed = 5400,
+}
@@ -0,0 +1,125 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TREASURY_KEY: &str = "Treasury";
+const TOKEN_KEY: &str = "Token";
+const FAILURE_KEY: &str = "Failure";
+const OWED_KEY: &str = "Owed";
+
+/// How the pegkeeper fails to pay back a loan
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Failure {
+    // Pay back the loan plus the fee
+    None,
+    // Keep the loan and the fee
+    DontRepay,
+    // Pay back the loan plus the fee, short by an amount
+    RepayShort(i128),
+    // Keep the loan and the fee until `repay_late` is called, after the loan has ended
+    RepayLate,
+    // Send the loan to the admin, as the first leg of a strategy, then panic
+    Panic,
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Pegkeeper **********/
+
+/// Fetch the treasury lending to the pegkeeper
+///
+/// ### Panics
+/// If the treasury does not exist
+pub fn get_treasury(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TREASURY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the treasury lending to the pegkeeper
+///
+/// ### Arguments
+/// * `treasury` - The Address of the treasury
+pub fn set_treasury(e: &Env, treasury: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TREASURY_KEY), treasury);
+}
+
+/// Fetch the token lent
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the token lent
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch how the pegkeeper fails to pay back a loan
+pub fn get_failure(e: &Env) -> Failure {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FAILURE_KEY))
+        .unwrap_or(Failure::None)
+}
+
+/// Set how the pegkeeper fails to pay back a loan
+///
+/// ### Arguments
+/// * `failure` - How loans are not paid back
+pub fn set_failure(e: &Env, failure: &Failure) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Failure>(&Symbol::new(e, FAILURE_KEY), failure);
+}
+
+/// Fetch the amount held back to be repaid late
+pub fn get_owed(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, OWED_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the amount held back to be repaid late
+///
+/// ### Arguments
+/// * `owed` - The amount owed
+pub fn set_owed(e: &Env, owed: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, OWED_KEY), &owed);
+}
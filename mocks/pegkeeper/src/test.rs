@@ -0,0 +1,83 @@
+#![cfg(test)]
+use crate::contract::{MockPegkeeperClient, MockPegkeeperContract};
+use crate::errors::MockPegkeeperError;
+use crate::storage::Failure;
+use mock_treasury::{MockTreasuryClient, MockTreasuryContract, MockTreasuryError, Repayment};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, Env, FromVal, Symbol};
+
+/// The amount lent and the fee collected of each loan the treasury reported
+fn keep_peg_events(e: &Env, treasury: &Address) -> std::vec::Vec<(i128, i128)> {
+    let keep_peg = Symbol::new(e, "keep_peg");
+    e.events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            contract == treasury && Symbol::from_val(e, &topics.get_unchecked(0)) == keep_peg
+        })
+        .map(|(_, _, data)| <(i128, i128)>::from_val(e, &data))
+        .collect()
+}
+
+#[test]
+fn test_failures() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let treasury = e.register_contract(None, MockTreasuryContract);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let pegkeeper = e.register_contract(None, MockPegkeeperContract);
+    let pegkeeper_client = MockPegkeeperClient::new(&e, &pegkeeper);
+    pegkeeper_client.initialize(&admin, &treasury, &ousd);
+    treasury_client.initialize(&ousd, &pegkeeper);
+    treasury_client.set_fee(&30);
+    StellarAssetClient::new(&e, &ousd).mint(&pegkeeper, &100_0000000);
+    let data = Bytes::new(&e);
+
+    let result = pegkeeper_client.try_initialize(&admin, &treasury, &ousd);
+    assert_eq!(result.err(), Some(Ok(MockPegkeeperError::AlreadyInitializedError.into())));
+    assert_eq!(pegkeeper_client.get_failure(), Failure::None);
+
+    // a loan paid back in full is burned and reported
+    assert_eq!(treasury_client.keep_peg(&1000_0000000, &data), 3_0000000);
+    assert_eq!(keep_peg_events(&e, &treasury), [(1000_0000000, 3_0000000)]);
+
+    // each failure reverts the loan, leaving nothing minted and nothing reported, and a
+    // pegkeeper that reverts is reported as the call failing
+    let failures = [
+        (Failure::DontRepay, MockTreasuryError::RepaymentTransferFailed),
+        (Failure::RepayShort(1), MockTreasuryError::RepaymentTransferFailed),
+        (Failure::RepayLate, MockTreasuryError::RepaymentTransferFailed),
+        (Failure::Panic, MockTreasuryError::PegkeeperCallFailed),
+    ];
+    for (failure, error) in failures.iter() {
+        pegkeeper_client.set_failure(failure);
+        assert_eq!(e.auths()[0].0, admin);
+        let result = treasury_client.try_keep_peg(&1000_0000000, &data);
+        assert_eq!(result.err(), Some(Ok((*error).into())), "{:?}", failure);
+        assert_eq!(keep_peg_events(&e, &treasury).len(), 1, "{:?}", failure);
+
+        assert_eq!(ousd_client.balance(&pegkeeper), 97_0000000, "{:?}", failure);
+        assert_eq!(ousd_client.balance(&treasury), 3_0000000, "{:?}", failure);
+        assert_eq!(ousd_client.balance(&admin), 0, "{:?}", failure);
+        assert_eq!(pegkeeper_client.get_owed(), 0, "{:?}", failure);
+        assert_eq!(treasury_client.get_loans(), 1, "{:?}", failure);
+    }
+
+    // a treasury that doesn't check the repayment lets a late repayer keep the loan until it
+    // chooses to pay
+    treasury_client.set_repayment(&Repayment::Waive);
+    pegkeeper_client.set_failure(&Failure::RepayLate);
+    assert_eq!(treasury_client.keep_peg(&1000_0000000, &data), 0);
+    assert_eq!(keep_peg_events(&e, &treasury)[1], (1000_0000000, 0));
+    assert_eq!(pegkeeper_client.get_owed(), 1003_0000000);
+    assert_eq!(ousd_client.balance(&pegkeeper), 1097_0000000);
+    assert_eq!(pegkeeper_client.repay_late(), 1003_0000000);
+    assert_eq!(pegkeeper_client.get_owed(), 0);
+    assert_eq!(ousd_client.balance(&pegkeeper), 94_0000000);
+    assert_eq!(ousd_client.balance(&treasury), 1006_0000000);
+    assert_eq!(pegkeeper_client.repay_late(), 0);
+}
@@ -0,0 +1,272 @@
+use crate::errors::MockPoolError;
+use crate::storage::{self, Positions, Request, SCALAR_7};
+use orbit_common::pool::RequestType;
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, Error, Map, Vec,
+};
+
+#[contract]
+pub struct MockPoolContract;
+
+#[contractclient(name = "MockPoolClient")]
+pub trait MockPool {
+    /// Initialize the mock pool. A stand-in for a Blend pool in tests, which supplies, withdraws,
+    /// borrows and repays at face value with no interest or health checks, and can be set to fail
+    /// in the ways a real pool can surprise its callers.
+    ///
+    /// ### Arguments
+    /// * `reserves` - The reserve assets, in reserve index order
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, reserves: Vec<Address>);
+
+    /// Set if every submit reverts
+    ///
+    /// ### Arguments
+    /// * `revert` - If submits revert
+    fn set_revert(e: Env, revert: bool);
+
+    /// Set the contract error submits revert with
+    ///
+    /// ### Arguments
+    /// * `error` - The code of the error, `SubmitFailed` by default
+    fn set_revert_error(e: Env, error: u32);
+
+    /// Set the percent of each withdrawal, borrow and auction fill the pool carries out. Supplies
+    /// and repayments are always taken in full.
+    ///
+    /// ### Arguments
+    /// * `fill` - The percent carried out, 100 by default
+    ///
+    /// ### Panics
+    /// If the percent is over 100
+    fn set_fill(e: Env, fill: u32);
+
+    /// Set the scalar the liabilities reported for every user are multiplied by. The
+    /// liabilities the pool holds are unchanged.
+    ///
+    /// ### Arguments
+    /// * `inflation` - The scalar, with 7 decimals
+    ///
+    /// ### Panics
+    /// If the scalar is negative
+    fn set_inflation(e: Env, inflation: i128);
+
+    /// Report positions for a user in place of the ones they hold
+    ///
+    /// ### Arguments
+    /// * `user` - The user
+    /// * `positions` - The positions to report
+    fn set_reported(e: Env, user: Address, positions: Positions);
+
+    /// Report the positions a user holds again
+    ///
+    /// ### Arguments
+    /// * `user` - The user
+    fn clear_reported(e: Env, user: Address);
+
+    /// Fetch the percent of the auctions of a user that have been filled
+    ///
+    /// ### Arguments
+    /// * `user` - The user whose positions are auctioned
+    fn get_filled(e: Env, user: Address) -> i128;
+
+    /// Fetch the reserve assets, in reserve index order
+    fn get_reserve_list(e: Env) -> Vec<Address>;
+
+    /// Fetch the positions reported for a user
+    ///
+    /// ### Arguments
+    /// * `address` - The user
+    fn get_positions(e: Env, address: Address) -> Positions;
+
+    /// Submit requests to the pool, as Blend's `submit`. Returns the positions reported for
+    /// `from` afterwards.
+    ///
+    /// ### Arguments
+    /// * `from` - The Address whose positions change
+    /// * `spender` - The Address that pays in tokens
+    /// * `to` - The Address that receives tokens
+    /// * `requests` - The requests
+    ///
+    /// ### Panics
+    /// If submits are set to revert
+    /// If a request has an unknown type, reserve or a negative amount
+    fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions;
+}
+
+#[contractimpl]
+impl MockPool for MockPoolContract {
+    fn initialize(e: Env, reserves: Vec<Address>) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, MockPoolError::AlreadyInitializedError);
+        }
+
+        storage::set_reserves(&e, &reserves);
+        storage::set_is_init(&e);
+    }
+
+    fn set_revert(e: Env, revert: bool) {
+        storage::extend_instance(&e);
+        storage::set_revert(&e, revert);
+    }
+
+    fn set_revert_error(e: Env, error: u32) {
+        storage::extend_instance(&e);
+        storage::set_revert_error(&e, error);
+    }
+
+    fn set_fill(e: Env, fill: u32) {
+        storage::extend_instance(&e);
+        if fill > 100 {
+            panic_with_error!(&e, MockPoolError::InvalidFill);
+        }
+        storage::set_fill(&e, fill);
+    }
+
+    fn set_inflation(e: Env, inflation: i128) {
+        storage::extend_instance(&e);
+        if inflation < 0 {
+            panic_with_error!(&e, MockPoolError::NegativeAmountError);
+        }
+        storage::set_inflation(&e, inflation);
+    }
+
+    fn set_reported(e: Env, user: Address, positions: Positions) {
+        storage::extend_instance(&e);
+        storage::set_reported(&e, &user, &positions);
+    }
+
+    fn clear_reported(e: Env, user: Address) {
+        storage::extend_instance(&e);
+        storage::del_reported(&e, &user);
+    }
+
+    fn get_filled(e: Env, user: Address) -> i128 {
+        storage::get_filled(&e, &user)
+    }
+
+    fn get_reserve_list(e: Env) -> Vec<Address> {
+        storage::get_reserves(&e)
+    }
+
+    fn get_positions(e: Env, address: Address) -> Positions {
+        reported_positions(&e, &address)
+    }
+
+    fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions {
+        from.require_auth();
+        if spender != from {
+            spender.require_auth();
+        }
+        storage::extend_instance(&e);
+        if storage::get_revert(&e) {
+            panic_with_error!(&e, Error::from_contract_error(storage::get_revert_error(&e)));
+        }
+
+        let this = e.current_contract_address();
+        let reserves = storage::get_reserves(&e);
+        let fill = storage::get_fill(&e) as i128;
+        let mut positions = storage::get_positions(&e, &from);
+        for request in requests.iter() {
+            if request.amount < 0 {
+                panic_with_error!(&e, MockPoolError::NegativeAmountError);
+            }
+            let request_type = RequestType::from_u32(request.request_type)
+                .unwrap_or_else(|| panic_with_error!(&e, MockPoolError::InvalidRequest));
+            match request_type {
+                RequestType::Supply | RequestType::SupplyCollateral => {
+                    let index = reserve_index(&e, &reserves, &request.address);
+                    let token_client = TokenClient::new(&e, &request.address);
+                    token_client.transfer(&spender, &this, &request.amount);
+                    let balances = match request_type {
+                        RequestType::Supply => &mut positions.supply,
+                        _ => &mut positions.collateral,
+                    };
+                    add(balances, index, request.amount);
+                }
+                RequestType::Withdraw | RequestType::WithdrawCollateral => {
+                    let index = reserve_index(&e, &reserves, &request.address);
+                    let balances = match request_type {
+                        RequestType::Withdraw => &mut positions.supply,
+                        _ => &mut positions.collateral,
+                    };
+                    let balance = balances.get(index).unwrap_or(0);
+                    let amount = (request.amount * fill / 100).min(balance);
+                    add(balances, index, -amount);
+                    TokenClient::new(&e, &request.address).transfer(&this, &to, &amount);
+                }
+                RequestType::Borrow => {
+                    let index = reserve_index(&e, &reserves, &request.address);
+                    let amount = request.amount * fill / 100;
+                    add(&mut positions.liabilities, index, amount);
+                    TokenClient::new(&e, &request.address).transfer(&this, &to, &amount);
+                }
+                RequestType::Repay => {
+                    let index = reserve_index(&e, &reserves, &request.address);
+                    let amount = request
+                        .amount
+                        .min(positions.liabilities.get(index).unwrap_or(0));
+                    TokenClient::new(&e, &request.address).transfer(&spender, &this, &amount);
+                    add(&mut positions.liabilities, index, -amount);
+                }
+                RequestType::FillUserLiquidationAuction
+                | RequestType::FillBadDebtAuction
+                | RequestType::FillInterestAuction => {
+                    let filled = storage::get_filled(&e, &request.address);
+                    storage::set_filled(&e, &request.address, filled + request.amount * fill / 100);
+                }
+                RequestType::DeleteLiquidationAuction => {
+                    storage::set_filled(&e, &request.address, 0)
+                }
+            }
+        }
+        storage::set_positions(&e, &from, &positions);
+        reported_positions(&e, &from)
+    }
+}
+
+/// Find the index of a reserve
+fn reserve_index(e: &Env, reserves: &Vec<Address>, asset: &Address) -> u32 {
+    reserves
+        .first_index_of(asset)
+        .unwrap_or_else(|| panic_with_error!(e, MockPoolError::InvalidReserve))
+}
+
+/// Add to a balance, removing it once it is empty
+fn add(balances: &mut Map<u32, i128>, index: u32, amount: i128) {
+    let balance = balances.get(index).unwrap_or(0) + amount;
+    if balance == 0 {
+        balances.remove(index);
+    } else {
+        balances.set(index, balance);
+    }
+}
+
+/// Fetch the positions reported for a user, with liabilities inflated
+fn reported_positions(e: &Env, user: &Address) -> Positions {
+    let mut positions = storage::get_reported(e, user)
+        .unwrap_or_else(|| storage::get_positions(e, user));
+    let inflation = storage::get_inflation(e);
+    for (index, liability) in positions.liabilities.clone().iter() {
+        positions
+            .liabilities
+            .set(index, liability * inflation / SCALAR_7);
+    }
+    positions
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the Blend pool mock. Common errors are codes that match up with the built-in
+/// contracts error reporting. Pool mock specific errors start at 5200.
+pub enum MockPoolError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    NegativeAmountError = 8,
+
+    // Mock Pool
+    SubmitFailed = 5200,
+    InvalidRequest = 5201,
+    InvalidReserve = 5202,
+    InvalidFill = 5203,
+}
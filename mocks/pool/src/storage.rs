@@ -0,0 +1,232 @@
+use crate::errors::MockPoolError;
+use soroban_sdk::{
+    contracttype, map, unwrap::UnwrapOptimized, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+    Vec,
+};
+
+pub use orbit_common::pool::{Positions, Request};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const SCALAR_7: i128 = 1_0000000;
+
+const IS_INIT_KEY: &str = "IsInit";
+const RESERVES_KEY: &str = "Reserves";
+const REVERT_KEY: &str = "Revert";
+const REVERT_ERROR_KEY: &str = "RevertError";
+const FILL_KEY: &str = "Fill";
+const INFLATION_KEY: &str = "Inflation";
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MockPoolDataKey {
+    // The positions a user actually holds
+    Positions(Address),
+    // The positions reported for a user in place of the ones they hold
+    Reported(Address),
+    // The percent of auctions of a user that have been filled
+    Filled(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Pool **********/
+
+/// Fetch the reserve assets, in reserve index order
+///
+/// ### Panics
+/// If the reserves do not exist
+pub fn get_reserves(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, RESERVES_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the reserve assets
+///
+/// ### Arguments
+/// * `reserves` - The reserve assets, in reserve index order
+pub fn set_reserves(e: &Env, reserves: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, RESERVES_KEY), reserves);
+}
+
+/// Fetch the positions a user holds
+///
+/// ### Arguments
+/// * `user` - The user
+pub fn get_positions(e: &Env, user: &Address) -> Positions {
+    let key = MockPoolDataKey::Positions(user.clone());
+    get_persistent(e, &key).unwrap_or(Positions {
+        liabilities: map![e],
+        collateral: map![e],
+        supply: map![e],
+    })
+}
+
+/// Set the positions a user holds
+///
+/// ### Arguments
+/// * `user` - The user
+/// * `positions` - The positions
+pub fn set_positions(e: &Env, user: &Address, positions: &Positions) {
+    set_persistent(e, &MockPoolDataKey::Positions(user.clone()), positions);
+}
+
+/// Fetch the percent of auctions of a user that have been filled
+///
+/// ### Arguments
+/// * `user` - The user
+pub fn get_filled(e: &Env, user: &Address) -> i128 {
+    get_persistent(e, &MockPoolDataKey::Filled(user.clone())).unwrap_or(0)
+}
+
+/// Set the percent of auctions of a user that have been filled
+///
+/// ### Arguments
+/// * `user` - The user
+/// * `filled` - The percent filled
+pub fn set_filled(e: &Env, user: &Address, filled: i128) {
+    set_persistent(e, &MockPoolDataKey::Filled(user.clone()), &filled);
+}
+
+/********** Failures **********/
+
+/// Fetch if submits revert
+pub fn get_revert(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REVERT_KEY))
+        .unwrap_or(false)
+}
+
+/// Set if submits revert
+///
+/// ### Arguments
+/// * `revert` - If submits revert
+pub fn set_revert(e: &Env, revert: bool) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, REVERT_KEY), &revert);
+}
+
+/// Fetch the contract error submits revert with
+pub fn get_revert_error(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REVERT_ERROR_KEY))
+        .unwrap_or(MockPoolError::SubmitFailed as u32)
+}
+
+/// Set the contract error submits revert with
+///
+/// ### Arguments
+/// * `error` - The code of the error
+pub fn set_revert_error(e: &Env, error: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, REVERT_ERROR_KEY), &error);
+}
+
+/// Fetch the percent of withdrawals, borrows and auction fills the pool carries out
+pub fn get_fill(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FILL_KEY))
+        .unwrap_or(100)
+}
+
+/// Set the percent of withdrawals, borrows and auction fills the pool carries out
+///
+/// ### Arguments
+/// * `fill` - The percent carried out
+pub fn set_fill(e: &Env, fill: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, FILL_KEY), &fill);
+}
+
+/// Fetch the scalar reported liabilities are multiplied by, with 7 decimals
+pub fn get_inflation(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, INFLATION_KEY))
+        .unwrap_or(SCALAR_7)
+}
+
+/// Set the scalar reported liabilities are multiplied by
+///
+/// ### Arguments
+/// * `inflation` - The scalar, with 7 decimals
+pub fn set_inflation(e: &Env, inflation: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, INFLATION_KEY), &inflation);
+}
+
+/// Fetch the positions reported for a user in place of the ones they hold, if any
+///
+/// ### Arguments
+/// * `user` - The user
+pub fn get_reported(e: &Env, user: &Address) -> Option<Positions> {
+    get_persistent(e, &MockPoolDataKey::Reported(user.clone()))
+}
+
+/// Set the positions reported for a user in place of the ones they hold
+///
+/// ### Arguments
+/// * `user` - The user
+/// * `positions` - The positions to report
+pub fn set_reported(e: &Env, user: &Address, positions: &Positions) {
+    set_persistent(e, &MockPoolDataKey::Reported(user.clone()), positions);
+}
+
+/// Remove the positions reported for a user, so the ones they hold are reported
+///
+/// ### Arguments
+/// * `user` - The user
+pub fn del_reported(e: &Env, user: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&MockPoolDataKey::Reported(user.clone()));
+}
+
+/********** Helpers **********/
+
+fn get_persistent<V: TryFromVal<Env, Val>>(e: &Env, key: &MockPoolDataKey) -> Option<V> {
+    let result = e.storage().persistent().get::<MockPoolDataKey, V>(key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+fn set_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &MockPoolDataKey, value: &V) {
+    e.storage().persistent().set::<MockPoolDataKey, V>(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
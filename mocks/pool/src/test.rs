@@ -0,0 +1,91 @@
+#![cfg(test)]
+use crate::contract::{MockPoolClient, MockPoolContract};
+use crate::errors::MockPoolError;
+use crate::storage::{Positions, Request};
+use orbit_common::pool::RequestType;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{map, vec, Address, Env, Vec};
+
+fn request(e: &Env, request_type: RequestType, address: &Address, amount: i128) -> Vec<Request> {
+    vec![
+        e,
+        Request {
+            request_type: request_type.into_u32(),
+            address: address.clone(),
+            amount,
+        },
+    ]
+}
+
+#[test]
+fn test_failure_modes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let xlm = e.register_stellar_asset_contract(Address::generate(&e));
+    let pool_id = e.register_contract(None, MockPoolContract);
+    let pool_client = MockPoolClient::new(&e, &pool_id);
+    pool_client.initialize(&vec![&e, ousd.clone(), xlm.clone()]);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &xlm).mint(&samwise, &1000_0000000);
+    StellarAssetClient::new(&e, &ousd).mint(&pool_id, &1000_0000000);
+    let ousd_client = TokenClient::new(&e, &ousd);
+
+    // requests are carried out at face value by default
+    let supply = request(&e, RequestType::SupplyCollateral, &xlm, 1000_0000000);
+    pool_client.submit(&samwise, &samwise, &samwise, &supply);
+    let borrow = request(&e, RequestType::Borrow, &ousd, 50_0000000);
+    let positions = pool_client.submit(&samwise, &samwise, &samwise, &borrow);
+    assert_eq!(positions.collateral, map![&e, (1, 1000_0000000)]);
+    assert_eq!(positions.liabilities, map![&e, (0, 50_0000000)]);
+
+    // partial fills pay out and record only part of a borrow
+    pool_client.set_fill(&40);
+    let positions = pool_client.submit(&samwise, &samwise, &samwise, &borrow);
+    assert_eq!(positions.liabilities, map![&e, (0, 70_0000000)]);
+    assert_eq!(ousd_client.balance(&samwise), 70_0000000);
+    let fill = request(&e, RequestType::FillUserLiquidationAuction, &samwise, 100);
+    pool_client.submit(&samwise, &samwise, &samwise, &fill);
+    assert_eq!(pool_client.get_filled(&samwise), 40);
+    pool_client.set_fill(&100);
+
+    // inflated liabilities are reported but not held
+    pool_client.set_inflation(&2_0000000);
+    assert_eq!(pool_client.get_positions(&samwise).liabilities, map![&e, (0, 140_0000000)]);
+    let repay = request(&e, RequestType::Repay, &ousd, 70_0000000);
+    pool_client.submit(&samwise, &samwise, &samwise, &repay);
+    assert_eq!(pool_client.get_positions(&samwise).liabilities, map![&e]);
+    pool_client.set_inflation(&1_0000000);
+
+    // bad positions are reported in place of the real ones
+    let bad = Positions {
+        liabilities: map![&e, (1, 5_0000000)],
+        collateral: map![&e],
+        supply: map![&e],
+    };
+    pool_client.set_reported(&samwise, &bad);
+    assert_eq!(pool_client.get_positions(&samwise), bad);
+    pool_client.clear_reported(&samwise);
+    assert_eq!(pool_client.get_positions(&samwise).collateral, map![&e, (1, 1000_0000000)]);
+
+    // reverted submits change nothing
+    pool_client.set_revert(&true);
+    let withdraw = request(&e, RequestType::WithdrawCollateral, &xlm, 1);
+    let result = pool_client.try_submit(&samwise, &samwise, &samwise, &withdraw);
+    assert_eq!(result.err(), Some(Ok(MockPoolError::SubmitFailed.into())));
+    pool_client.set_revert(&false);
+
+    let invalid = Request {
+        request_type: 10,
+        address: xlm.clone(),
+        amount: 1,
+    };
+    let result = pool_client.try_submit(&samwise, &samwise, &samwise, &vec![&e, invalid]);
+    assert_eq!(result.err(), Some(Ok(MockPoolError::InvalidRequest.into())));
+    let unlisted = request(&e, RequestType::Supply, &pool_id, 1);
+    let result = pool_client.try_submit(&samwise, &samwise, &samwise, &unlisted);
+    assert_eq!(result.err(), Some(Ok(MockPoolError::InvalidReserve.into())));
+    let result = pool_client.try_set_fill(&101);
+    assert_eq!(result.err(), Some(Ok(MockPoolError::InvalidFill.into())));
+}
@@ -0,0 +1,210 @@
+use crate::errors::ScriptedOracleError;
+use crate::storage::{self, Move, ScriptPoint};
+use sep_40_oracle::{Asset, PriceData};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Env, Vec};
+
+#[contract]
+pub struct ScriptedOracleContract;
+
+#[contractclient(name = "ScriptedOracleClient")]
+pub trait ScriptedOracle {
+    /// Initialize the scripted oracle. A SEP-40 oracle for tests, whose price for each asset
+    /// follows a script of steps, ramps and stale periods keyed by timestamp, so a test only
+    /// has to move time to move prices.
+    ///
+    /// ### Arguments
+    /// * `base` - The asset prices are quoted in
+    /// * `decimals` - The decimals of reported prices
+    /// * `resolution` - The seconds between price updates
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, base: Asset, decimals: u32, resolution: u32);
+
+    /// Set the price script of an asset, replacing any existing script
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    /// * `script` - The points of the script, in time order. The first point must be a step.
+    ///
+    /// ### Panics
+    /// If the script is empty, out of order or does not start with a step
+    /// If a step or ramp has a price of zero or less
+    fn set_script(e: Env, asset: Asset, script: Vec<ScriptPoint>);
+
+    /// Fetch the price script of an asset, if it has one
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    fn get_script(e: Env, asset: Asset) -> Option<Vec<ScriptPoint>>;
+
+    /// Fetch the asset prices are quoted in
+    fn base(e: Env) -> Asset;
+
+    /// Fetch the assets with a price script
+    fn assets(e: Env) -> Vec<Asset>;
+
+    /// Fetch the decimals of reported prices
+    fn decimals(e: Env) -> u32;
+
+    /// Fetch the seconds between price updates
+    fn resolution(e: Env) -> u32;
+
+    /// Fetch the price of an asset as reported at a time
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    /// * `timestamp` - The time to fetch the price at
+    fn price(e: Env, asset: Asset, timestamp: u64) -> Option<PriceData>;
+
+    /// Fetch the latest prices reported for an asset, newest first
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    /// * `records` - The most prices to fetch
+    fn prices(e: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+
+    /// Fetch the latest price reported for an asset
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    fn lastprice(e: Env, asset: Asset) -> Option<PriceData>;
+}
+
+#[contractimpl]
+impl ScriptedOracle for ScriptedOracleContract {
+    fn initialize(e: Env, base: Asset, decimals: u32, resolution: u32) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, ScriptedOracleError::AlreadyInitializedError);
+        }
+
+        storage::set_base(&e, &base);
+        storage::set_decimals(&e, decimals);
+        storage::set_resolution(&e, resolution);
+        storage::set_is_init(&e);
+    }
+
+    fn set_script(e: Env, asset: Asset, script: Vec<ScriptPoint>) {
+        storage::extend_instance(&e);
+        let mut last: Option<u64> = None;
+        for point in script.iter() {
+            let out_of_order = last.is_some_and(|timestamp| point.timestamp <= timestamp);
+            let no_start = last.is_none() && point.kind != Move::Step;
+            let bad_price = point.kind != Move::Stale && point.price <= 0;
+            if out_of_order || no_start || bad_price {
+                panic_with_error!(&e, ScriptedOracleError::InvalidScript);
+            }
+            last = Some(point.timestamp);
+        }
+        if last.is_none() {
+            panic_with_error!(&e, ScriptedOracleError::InvalidScript);
+        }
+
+        let mut assets = storage::get_assets(&e);
+        if !assets.contains(&asset) {
+            assets.push_back(asset.clone());
+            storage::set_assets(&e, &assets);
+        }
+        storage::set_script(&e, &asset, &script);
+    }
+
+    fn get_script(e: Env, asset: Asset) -> Option<Vec<ScriptPoint>> {
+        storage::get_script(&e, &asset)
+    }
+
+    fn base(e: Env) -> Asset {
+        storage::get_base(&e)
+    }
+
+    fn assets(e: Env) -> Vec<Asset> {
+        storage::get_assets(&e)
+    }
+
+    fn decimals(e: Env) -> u32 {
+        storage::get_decimals(&e)
+    }
+
+    fn resolution(e: Env) -> u32 {
+        storage::get_resolution(&e)
+    }
+
+    fn price(e: Env, asset: Asset, timestamp: u64) -> Option<PriceData> {
+        let script = storage::get_script(&e, &asset)?;
+        price_at(&script, storage::get_resolution(&e), timestamp)
+    }
+
+    fn prices(e: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+        let script = storage::get_script(&e, &asset)?;
+        let resolution = storage::get_resolution(&e);
+        let mut prices = vec![&e];
+        let mut timestamp = e.ledger().timestamp();
+        for _ in 0..records {
+            match price_at(&script, resolution, timestamp) {
+                Some(price) => {
+                    prices.push_back(price.clone());
+                    match price.timestamp.checked_sub(resolution as u64) {
+                        Some(previous) => timestamp = previous,
+                        None => break,
+                    }
+                }
+                None => break,
+            }
+        }
+        if prices.is_empty() {
+            None
+        } else {
+            Some(prices)
+        }
+    }
+
+    fn lastprice(e: Env, asset: Asset) -> Option<PriceData> {
+        let script = storage::get_script(&e, &asset)?;
+        price_at(&script, storage::get_resolution(&e), e.ledger().timestamp())
+    }
+}
+
+/// Evaluate a price script at a time, as the oracle would have reported it. Prices are
+/// published every `resolution` seconds, and a stale oracle keeps reporting what it last
+/// published before it stopped updating.
+fn price_at(script: &Vec<ScriptPoint>, resolution: u32, timestamp: u64) -> Option<PriceData> {
+    let mut published = timestamp;
+    for point in script.iter() {
+        if point.timestamp > timestamp {
+            break;
+        }
+        published = match point.kind {
+            Move::Stale => point.timestamp,
+            _ => timestamp,
+        };
+    }
+    published -= published % resolution as u64;
+
+    let mut from: Option<ScriptPoint> = None;
+    for point in script.iter() {
+        if point.kind == Move::Stale {
+            continue;
+        }
+        if point.timestamp <= published {
+            from = Some(point);
+        } else {
+            let from = from?;
+            let price = match point.kind {
+                Move::Ramp => {
+                    let elapsed = (published - from.timestamp) as i128;
+                    let duration = (point.timestamp - from.timestamp) as i128;
+                    from.price + (point.price - from.price) * elapsed / duration
+                }
+                _ => from.price,
+            };
+            return Some(PriceData {
+                price,
+                timestamp: published,
+            });
+        }
+    }
+    from.map(|from| PriceData {
+        price: from.price,
+        timestamp: published,
+    })
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the scripted oracle mock. Common errors are codes that match up with the
+/// built-in contracts error reporting. Scripted oracle specific errors start at 5000.
+pub enum ScriptedOracleError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+
+    // Scripted Oracle
+    InvalidScript = 5000,
+}
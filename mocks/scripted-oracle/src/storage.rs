@@ -0,0 +1,175 @@
+use sep_40_oracle::Asset;
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, vec, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const BASE_KEY: &str = "Base";
+const DECIMALS_KEY: &str = "Decimals";
+const RESOLUTION_KEY: &str = "Resolution";
+const ASSETS_KEY: &str = "Assets";
+
+/// How the price moves at a point of a script
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Move {
+    // The price jumps to the point's price at its timestamp
+    Step,
+    // The price moves linearly from the previous point, reaching the point's price at its
+    // timestamp
+    Ramp,
+    // The oracle stops updating at the point's timestamp, until the next point
+    Stale,
+}
+
+/// A point of the price script of an asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ScriptPoint {
+    pub kind: Move,
+    pub timestamp: u64,
+    pub price: i128, // the price reached at the timestamp, unused by `Move::Stale`
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ScriptedOracleDataKey {
+    // The price script of an asset
+    Script(Asset),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Oracle **********/
+
+/// Fetch the asset prices are quoted in
+///
+/// ### Panics
+/// If the base asset does not exist
+pub fn get_base(e: &Env) -> Asset {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, BASE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the asset prices are quoted in
+///
+/// ### Arguments
+/// * `base` - The base asset
+pub fn set_base(e: &Env, base: &Asset) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Asset>(&Symbol::new(e, BASE_KEY), base);
+}
+
+/// Fetch the decimals of reported prices
+///
+/// ### Panics
+/// If the decimals do not exist
+pub fn get_decimals(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, DECIMALS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the decimals of reported prices
+///
+/// ### Arguments
+/// * `decimals` - The decimals of reported prices
+pub fn set_decimals(e: &Env, decimals: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, DECIMALS_KEY), &decimals);
+}
+
+/// Fetch the seconds between price updates
+///
+/// ### Panics
+/// If the resolution does not exist
+pub fn get_resolution(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, RESOLUTION_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the seconds between price updates
+///
+/// ### Arguments
+/// * `resolution` - The seconds between price updates
+pub fn set_resolution(e: &Env, resolution: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, RESOLUTION_KEY), &resolution);
+}
+
+/// Fetch the assets with a price script
+pub fn get_assets(e: &Env) -> Vec<Asset> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ASSETS_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the assets with a price script
+///
+/// ### Arguments
+/// * `assets` - The assets with a price script
+pub fn set_assets(e: &Env, assets: &Vec<Asset>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Asset>>(&Symbol::new(e, ASSETS_KEY), assets);
+}
+
+/// Fetch the price script of an asset, if it has one
+///
+/// ### Arguments
+/// * `asset` - The asset
+pub fn get_script(e: &Env, asset: &Asset) -> Option<Vec<ScriptPoint>> {
+    let key = ScriptedOracleDataKey::Script(asset.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<ScriptedOracleDataKey, Vec<ScriptPoint>>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the price script of an asset
+///
+/// ### Arguments
+/// * `asset` - The asset
+/// * `script` - The price script
+pub fn set_script(e: &Env, asset: &Asset, script: &Vec<ScriptPoint>) {
+    let key = ScriptedOracleDataKey::Script(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<ScriptedOracleDataKey, Vec<ScriptPoint>>(&key, script);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
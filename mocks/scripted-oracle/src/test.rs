@@ -0,0 +1,122 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{ScriptedOracleClient, ScriptedOracleContract};
+use crate::errors::ScriptedOracleError;
+use crate::storage::{Move, ScriptPoint};
+use sep_40_oracle::{Asset, PriceData};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, Symbol};
+
+fn point(kind: Move, timestamp: u64, price: i128) -> ScriptPoint {
+    ScriptPoint {
+        kind,
+        timestamp,
+        price,
+    }
+}
+
+fn create_oracle<'a>(e: &Env) -> ScriptedOracleClient<'a> {
+    let oracle_id = e.register_contract(None, ScriptedOracleContract);
+    let oracle_client = ScriptedOracleClient::new(e, &oracle_id);
+    oracle_client.initialize(&Asset::Other(Symbol::new(e, "USD")), &7, &300);
+    oracle_client
+}
+
+#[test]
+fn test_step_ramp_and_stale() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let oracle_client = create_oracle(&e);
+    let xlm = Asset::Stellar(Address::generate(&e));
+
+    // XLM holds at $0.10, ramps down to $0.05 over an hour, goes stale, then recovers in a step
+    oracle_client.set_script(
+        &xlm,
+        &vec![
+            &e,
+            point(Move::Step, 0, 0_1000000),
+            point(Move::Step, 3600, 0_1000000),
+            point(Move::Ramp, 7200, 0_0500000),
+            point(Move::Stale, 9000, 0),
+            point(Move::Step, 12000, 0_0800000),
+        ],
+    );
+    assert_eq!(oracle_client.assets(), vec![&e, xlm.clone()]);
+
+    let lastprice = oracle_client.lastprice(&xlm).unwrap();
+    assert_eq!(lastprice, PriceData { price: 0_1000000, timestamp: 900 });
+
+    // halfway down the ramp
+    e.ledger().with_mut(|li| li.timestamp = 5400);
+    let lastprice = oracle_client.lastprice(&xlm).unwrap();
+    assert_eq!(lastprice, PriceData { price: 0_0750000, timestamp: 5400 });
+
+    // the price stops updating once stale
+    e.ledger().with_mut(|li| li.timestamp = 11000);
+    let lastprice = oracle_client.lastprice(&xlm).unwrap();
+    assert_eq!(lastprice, PriceData { price: 0_0500000, timestamp: 9000 });
+    let prices = oracle_client.prices(&xlm, &2).unwrap();
+    assert_eq!(prices.get_unchecked(0).timestamp, 9000);
+    assert_eq!(prices.get_unchecked(1).timestamp, 8700);
+
+    e.ledger().with_mut(|li| li.timestamp = 12100);
+    let lastprice = oracle_client.lastprice(&xlm).unwrap();
+    assert_eq!(lastprice, PriceData { price: 0_0800000, timestamp: 12000 });
+    let price = oracle_client.price(&xlm, &6000).unwrap();
+    assert_eq!(price, PriceData { price: 0_0666667, timestamp: 6000 });
+}
+
+#[test]
+fn test_spike() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let oracle_client = create_oracle(&e);
+    let usdc = Asset::Stellar(Address::generate(&e));
+
+    oracle_client.set_script(
+        &usdc,
+        &vec![
+            &e,
+            point(Move::Step, 0, 1_0000000),
+            point(Move::Step, 1200, 0_9000000),
+            point(Move::Step, 1500, 1_0000000),
+        ],
+    );
+    assert_eq!(oracle_client.lastprice(&usdc).unwrap().price, 1_0000000);
+    e.ledger().with_mut(|li| li.timestamp = 1300);
+    assert_eq!(oracle_client.lastprice(&usdc).unwrap().price, 0_9000000);
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    assert_eq!(oracle_client.lastprice(&usdc).unwrap().price, 1_0000000);
+    let prices = oracle_client.prices(&usdc, &10).unwrap();
+    assert_eq!(prices.len(), 6);
+    assert_eq!(prices.get_unchecked(1).price, 0_9000000);
+    assert_eq!(prices.get_unchecked(2).price, 1_0000000);
+}
+
+#[test]
+fn test_invalid_scripts() {
+    let e = Env::default();
+    let oracle_client = create_oracle(&e);
+    let xlm = Asset::Stellar(Address::generate(&e));
+    assert_eq!(oracle_client.lastprice(&xlm), None);
+
+    let scripts = [
+        vec![&e],
+        vec![&e, point(Move::Ramp, 0, 1_0000000)],
+        vec![&e, point(Move::Step, 0, 0)],
+        vec![
+            &e,
+            point(Move::Step, 100, 1_0000000),
+            point(Move::Step, 100, 2_0000000),
+        ],
+    ];
+    for script in scripts.iter() {
+        let result = oracle_client.try_set_script(&xlm, script);
+        assert_eq!(result.err(), Some(Ok(ScriptedOracleError::InvalidScript.into())));
+    }
+
+    // prices before the script starts are unknown
+    oracle_client.set_script(&xlm, &vec![&e, point(Move::Step, 600, 1_0000000)]);
+    assert_eq!(oracle_client.price(&xlm, &300), None);
+    assert_eq!(oracle_client.lastprice(&xlm), None);
+}
@@ -0,0 +1,240 @@
+use crate::errors::MockTreasuryError;
+use crate::storage::{self, Hook, HookPoint, Repayment};
+use sep_41_token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, xdr::ScErrorType, Address, Bytes,
+    Env, Error, Symbol, Val, Vec,
+};
+
+const BPS: i128 = 10000;
+
+#[contract]
+pub struct MockTreasuryContract;
+
+/// The callback a pegkeeper implements to receive a loan from the treasury. The pegkeeper must
+/// send the loan plus the fee back to the treasury before it returns.
+#[allow(dead_code)]
+#[contractclient(name = "PegkeeperClient")]
+pub trait Pegkeeper {
+    /// Use a loan from the treasury to restore the peg
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the token lent
+    /// * `amount` - The amount lent
+    /// * `fee` - The fee owed on top of the loan
+    /// * `data` - Arbitrary data passed through from the caller of `keep_peg`
+    fn fl_receive(e: Env, token: Address, amount: i128, fee: i128, data: Bytes);
+}
+
+#[contractclient(name = "MockTreasuryClient")]
+pub trait MockTreasury {
+    /// Initialize the mock treasury. A stand-in for the treasury in tests of a pegkeeper, which
+    /// lends newly minted tokens to the pegkeeper and can be set to charge any fee, to fail to
+    /// take the loan back, and to call out at points of the loan.
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the token, whose admin must be the treasury
+    /// * `pegkeeper` - The Address of the pegkeeper
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, token: Address, pegkeeper: Address);
+
+    /// Set the fee charged on each loan
+    ///
+    /// ### Arguments
+    /// * `fee` - The fee, in bps, 0 by default
+    ///
+    /// ### Panics
+    /// If the fee is over 10000
+    fn set_fee(e: Env, fee: u32);
+
+    /// Set the most the treasury mints for a single loan
+    ///
+    /// ### Arguments
+    /// * `cap` - The most minted for a loan, unlimited by default
+    fn set_supply_cap(e: Env, cap: i128);
+
+    /// Set how loans are taken back from the pegkeeper
+    ///
+    /// ### Arguments
+    /// * `repayment` - How loans are taken back, `Repayment::Enforce` by default
+    fn set_repayment(e: Env, repayment: Repayment);
+
+    /// Set a call the treasury makes at a point of each loan. A hook that reverts reverts the
+    /// loan.
+    ///
+    /// ### Arguments
+    /// * `point` - The point of the loan
+    /// * `contract` - The Address of the contract called
+    /// * `function` - The function called
+    /// * `args` - The arguments of the call
+    fn set_hook(e: Env, point: HookPoint, contract: Address, function: Symbol, args: Vec<Val>);
+
+    /// Remove the call made at a point of each loan
+    ///
+    /// ### Arguments
+    /// * `point` - The point of the loan
+    fn clear_hook(e: Env, point: HookPoint);
+
+    /// Fetch the fee charged on each loan, in bps
+    fn get_fee(e: Env) -> u32;
+
+    /// Fetch the fees collected on loans
+    fn get_fees(e: Env) -> i128;
+
+    /// Fetch the number of loans taken back
+    fn get_loans(e: Env) -> u32;
+
+    /// Lend newly minted tokens to the pegkeeper to restore the peg, and take them back with
+    /// the fee before returning. Returns the fee collected.
+    ///
+    /// ### Arguments
+    /// * `amount` - The amount to lend
+    /// * `data` - Arbitrary data passed through to the pegkeeper
+    ///
+    /// ### Panics
+    /// If a loan is already out, with `ReentrantCallError`
+    /// If the amount is not positive, with `InvalidArgs`
+    /// If the amount is over the supply cap, with `SupplyCapExceeded`
+    /// If a hook reverts
+    /// If the pegkeeper reverts, with `NotWhitelisted` if it refused the treasury,
+    /// `InvalidArgs` if it refused the amount, `RepaymentTransferFailed` if it could not send
+    /// the repayment, and `PegkeeperCallFailed` otherwise
+    /// If the loan is not taken back, as set by `set_repayment`, with `RepaymentTransferFailed`
+    fn keep_peg(e: Env, amount: i128, data: Bytes) -> i128;
+}
+
+#[contractimpl]
+impl MockTreasury for MockTreasuryContract {
+    fn initialize(e: Env, token: Address, pegkeeper: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, MockTreasuryError::AlreadyInitializedError);
+        }
+
+        storage::set_token(&e, &token);
+        storage::set_pegkeeper(&e, &pegkeeper);
+        storage::set_is_init(&e);
+    }
+
+    fn set_fee(e: Env, fee: u32) {
+        storage::extend_instance(&e);
+        if fee as i128 > BPS {
+            panic_with_error!(&e, MockTreasuryError::InvalidFee);
+        }
+        storage::set_fee(&e, fee);
+    }
+
+    fn set_supply_cap(e: Env, cap: i128) {
+        storage::extend_instance(&e);
+        storage::set_supply_cap(&e, cap);
+    }
+
+    fn set_repayment(e: Env, repayment: Repayment) {
+        storage::extend_instance(&e);
+        storage::set_repayment(&e, repayment);
+    }
+
+    fn set_hook(e: Env, point: HookPoint, contract: Address, function: Symbol, args: Vec<Val>) {
+        storage::extend_instance(&e);
+        let hook = Hook {
+            contract,
+            function,
+            args,
+        };
+        storage::set_hook(&e, point, &hook);
+    }
+
+    fn clear_hook(e: Env, point: HookPoint) {
+        storage::extend_instance(&e);
+        storage::del_hook(&e, point);
+    }
+
+    fn get_fee(e: Env) -> u32 {
+        storage::get_fee(&e)
+    }
+
+    fn get_fees(e: Env) -> i128 {
+        storage::get_fees(&e)
+    }
+
+    fn get_loans(e: Env) -> u32 {
+        storage::get_loans(&e)
+    }
+
+    fn keep_peg(e: Env, amount: i128, data: Bytes) -> i128 {
+        storage::extend_instance(&e);
+        orbit_access::nonreentrant!(&e);
+        if amount <= 0 {
+            panic_with_error!(&e, MockTreasuryError::InvalidArgs);
+        }
+        if amount > storage::get_supply_cap(&e) {
+            panic_with_error!(&e, MockTreasuryError::SupplyCapExceeded);
+        }
+
+        let this = e.current_contract_address();
+        let token = storage::get_token(&e);
+        let pegkeeper = storage::get_pegkeeper(&e);
+        let token_client = TokenClient::new(&e, &token);
+        let fee = amount * storage::get_fee(&e) as i128 / BPS;
+        let balance_before = token_client.balance(&this);
+
+        call_hook(&e, HookPoint::BeforeLoan);
+        StellarAssetClient::new(&e, &token).mint(&pegkeeper, &amount);
+        let pegkeeper_client = PegkeeperClient::new(&e, &pegkeeper);
+        let result = pegkeeper_client.try_fl_receive(&token, &amount, &fee, &data);
+        match result {
+            Err(Ok(error)) => panic_with_error!(&e, pegkeeper_error(error)),
+            Err(Err(_)) => panic_with_error!(&e, MockTreasuryError::PegkeeperCallFailed),
+            Ok(_) => (),
+        }
+        call_hook(&e, HookPoint::AfterLoan);
+
+        let repaid = token_client.balance(&this) - balance_before;
+        match storage::get_repayment(&e) {
+            Repayment::Enforce if repaid < amount + fee => {
+                panic_with_error!(&e, MockTreasuryError::RepaymentTransferFailed)
+            }
+            Repayment::Fail => panic_with_error!(&e, MockTreasuryError::RepaymentTransferFailed),
+            _ => (),
+        }
+        token_client.burn(&this, &repaid.min(amount));
+        let collected = (repaid - amount).max(0);
+        storage::set_fees(&e, storage::get_fees(&e) + collected);
+        storage::set_loans(&e, storage::get_loans(&e) + 1);
+
+        let topics = (Symbol::new(&e, "keep_peg"), pegkeeper);
+        e.events().publish(topics, (amount, collected));
+        collected
+    }
+}
+
+/// Make the call set for a point of the loan, if any
+fn call_hook(e: &Env, point: HookPoint) {
+    if let Some(hook) = storage::get_hook(e, point) {
+        e.invoke_contract::<Val>(&hook.contract, &hook.function, hook.args);
+    }
+}
+
+/// Map the error a pegkeeper reverted with to the error the loan fails with. The common contract
+/// errors of the pegkeeper and of the token it repays with are known. Anything else, including
+/// host errors such as a failed auth, which reach the treasury without their cause, is reported
+/// as the call failing.
+fn pegkeeper_error(error: Error) -> MockTreasuryError {
+    if !error.is_type(ScErrorType::Contract) {
+        return MockTreasuryError::PegkeeperCallFailed;
+    }
+    match error.get_code() {
+        code if code == MockTreasuryError::UnauthorizedError as u32 => {
+            MockTreasuryError::NotWhitelisted
+        }
+        code if code == MockTreasuryError::NegativeAmountError as u32 => {
+            MockTreasuryError::InvalidArgs
+        }
+        code if code == MockTreasuryError::BalanceError as u32 => {
+            MockTreasuryError::RepaymentTransferFailed
+        }
+        _ => MockTreasuryError::PegkeeperCallFailed,
+    }
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the treasury mock. Common errors are codes that match up with the built-in
+/// contracts error reporting. Treasury mock specific errors start at 5300.
+///
+/// A failed loan is reported with the error for what went wrong, and an error the pegkeeper
+/// reverts with is mapped to one of these where its code is known.
+pub enum MockTreasuryError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+    BalanceError = 10,
+
+    // Mock Treasury
+    RepaymentTransferFailed = 5300,
+    InvalidFee = 5301,
+    PegkeeperCallFailed = 5302,
+    InvalidArgs = 5303,
+    SupplyCapExceeded = 5304,
+    NotWhitelisted = 5305,
+}
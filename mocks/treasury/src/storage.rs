@@ -0,0 +1,235 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Val, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const PEGKEEPER_KEY: &str = "Pegkeeper";
+const FEE_KEY: &str = "Fee";
+const SUPPLY_CAP_KEY: &str = "SupplyCap";
+const REPAYMENT_KEY: &str = "Repayment";
+const FEES_KEY: &str = "Fees";
+const LOANS_KEY: &str = "Loans";
+
+/// How the treasury takes back a loan from the pegkeeper
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Repayment {
+    // Revert unless the loan plus the fee was sent back, as the treasury does
+    Enforce,
+    // Take back whatever was sent, burning up to the loan
+    Waive,
+    // Revert as if the repayment failed, whatever was sent back
+    Fail,
+}
+
+/// A point of a loan at which a hook is called
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum HookPoint {
+    // Before the loan is minted to the pegkeeper
+    BeforeLoan,
+    // After the pegkeeper returns, before the repayment is checked
+    AfterLoan,
+}
+
+/// A call the treasury makes at a point of a loan
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Hook {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MockTreasuryDataKey {
+    // The hook called at a point of a loan
+    Hook(HookPoint),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Treasury **********/
+
+/// Fetch the token the treasury mints
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the token the treasury mints
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the pegkeeper the treasury lends to
+///
+/// ### Panics
+/// If the pegkeeper does not exist
+pub fn get_pegkeeper(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, PEGKEEPER_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the pegkeeper the treasury lends to
+///
+/// ### Arguments
+/// * `pegkeeper` - The Address of the pegkeeper
+pub fn set_pegkeeper(e: &Env, pegkeeper: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, PEGKEEPER_KEY), pegkeeper);
+}
+
+/// Fetch the fee charged on each loan, in bps
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FEE_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the fee charged on each loan
+///
+/// ### Arguments
+/// * `fee` - The fee, in bps
+pub fn set_fee(e: &Env, fee: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, FEE_KEY), &fee);
+}
+
+/// Fetch the most the treasury mints for a single loan
+pub fn get_supply_cap(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SUPPLY_CAP_KEY))
+        .unwrap_or(i128::MAX)
+}
+
+/// Set the most the treasury mints for a single loan
+///
+/// ### Arguments
+/// * `cap` - The most minted for a loan
+pub fn set_supply_cap(e: &Env, cap: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, SUPPLY_CAP_KEY), &cap);
+}
+
+/// Fetch how the treasury takes back a loan
+pub fn get_repayment(e: &Env) -> Repayment {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REPAYMENT_KEY))
+        .unwrap_or(Repayment::Enforce)
+}
+
+/// Set how the treasury takes back a loan
+///
+/// ### Arguments
+/// * `repayment` - How loans are taken back
+pub fn set_repayment(e: &Env, repayment: Repayment) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Repayment>(&Symbol::new(e, REPAYMENT_KEY), &repayment);
+}
+
+/// Fetch the hook called at a point of a loan, if any
+///
+/// ### Arguments
+/// * `point` - The point of the loan
+pub fn get_hook(e: &Env, point: HookPoint) -> Option<Hook> {
+    e.storage()
+        .instance()
+        .get(&MockTreasuryDataKey::Hook(point))
+}
+
+/// Set the hook called at a point of a loan
+///
+/// ### Arguments
+/// * `point` - The point of the loan
+/// * `hook` - The hook
+pub fn set_hook(e: &Env, point: HookPoint, hook: &Hook) {
+    e.storage()
+        .instance()
+        .set::<MockTreasuryDataKey, Hook>(&MockTreasuryDataKey::Hook(point), hook);
+}
+
+/// Remove the hook called at a point of a loan
+///
+/// ### Arguments
+/// * `point` - The point of the loan
+pub fn del_hook(e: &Env, point: HookPoint) {
+    e.storage()
+        .instance()
+        .remove(&MockTreasuryDataKey::Hook(point));
+}
+
+/// Fetch the fees collected on loans
+pub fn get_fees(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FEES_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the fees collected on loans
+///
+/// ### Arguments
+/// * `fees` - The fees collected
+pub fn set_fees(e: &Env, fees: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, FEES_KEY), &fees);
+}
+
+/// Fetch the number of loans repaid
+pub fn get_loans(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, LOANS_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of loans repaid
+///
+/// ### Arguments
+/// * `loans` - The number of loans repaid
+pub fn set_loans(e: &Env, loans: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, LOANS_KEY), &loans);
+}
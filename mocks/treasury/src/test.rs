@@ -0,0 +1,181 @@
+#![cfg(test)]
+use crate::contract::{MockTreasuryClient, MockTreasuryContract};
+use crate::errors::MockTreasuryError;
+use crate::storage::{HookPoint, Repayment};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, vec, Address, Bytes, Env, Error, IntoVal, Symbol,
+    Vec,
+};
+
+/// Repays each loan plus the fee, less a shortfall, out of its own balance, unless set to
+/// revert with a contract error
+#[contract]
+pub struct MockPegkeeper;
+
+#[contractimpl]
+impl MockPegkeeper {
+    pub fn set_shortfall(e: Env, treasury: Address, shortfall: i128) {
+        e.storage().instance().set(&Symbol::new(&e, "treasury"), &treasury);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "shortfall"), &shortfall);
+    }
+
+    pub fn set_error(e: Env, code: u32) {
+        e.storage().instance().set(&Symbol::new(&e, "error"), &code);
+    }
+
+    pub fn fl_receive(e: Env, token: Address, amount: i128, fee: i128, _data: Bytes) {
+        let treasury: Address = e.storage().instance().get(&Symbol::new(&e, "treasury")).unwrap();
+        if let Some(code) = e.storage().instance().get(&Symbol::new(&e, "error")) {
+            panic_with_error!(&e, Error::from_contract_error(code));
+        }
+        let shortfall: i128 = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "shortfall"))
+            .unwrap();
+        TokenClient::new(&e, &token).transfer(
+            &e.current_contract_address(),
+            &treasury,
+            &(amount + fee - shortfall),
+        );
+    }
+}
+
+/// Records the balance of the pegkeeper each time it is called
+#[contract]
+pub struct MockHook;
+
+#[contractimpl]
+impl MockHook {
+    pub fn record(e: Env, token: Address, pegkeeper: Address) {
+        let balance = TokenClient::new(&e, &token).balance(&pegkeeper);
+        let mut balances: Vec<i128> = e
+            .storage()
+            .instance()
+            .get(&Symbol::new(&e, "balances"))
+            .unwrap_or(vec![&e]);
+        balances.push_back(balance);
+        e.storage().instance().set(&Symbol::new(&e, "balances"), &balances);
+    }
+
+    pub fn balances(e: Env) -> Vec<i128> {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "balances"))
+            .unwrap_or(vec![&e])
+    }
+}
+
+#[test]
+fn test_keep_peg() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let treasury = e.register_contract(None, MockTreasuryContract);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let pegkeeper = e.register_contract(None, MockPegkeeper);
+    let pegkeeper_client = MockPegkeeperClient::new(&e, &pegkeeper);
+    pegkeeper_client.set_shortfall(&treasury, &0);
+    StellarAssetClient::new(&e, &ousd).mint(&pegkeeper, &100_0000000);
+    treasury_client.initialize(&ousd, &pegkeeper);
+    let data = Bytes::new(&e);
+
+    let result = treasury_client.try_initialize(&ousd, &pegkeeper);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::AlreadyInitializedError.into())));
+    let result = treasury_client.try_keep_peg(&0, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::InvalidArgs.into())));
+
+    // loans are free until a fee is set
+    assert_eq!(treasury_client.keep_peg(&1000_0000000, &data), 0);
+    treasury_client.set_fee(&30);
+    assert_eq!(treasury_client.get_fee(), 30);
+    assert_eq!(treasury_client.keep_peg(&1000_0000000, &data), 3_0000000);
+    let result = treasury_client.try_set_fee(&10001);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::InvalidFee.into())));
+
+    // a repayment short of the fee is refused, unless the treasury is set to waive it
+    pegkeeper_client.set_shortfall(&treasury, &1_0000000);
+    let result = treasury_client.try_keep_peg(&1000_0000000, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::RepaymentTransferFailed.into())));
+    treasury_client.set_repayment(&Repayment::Waive);
+    assert_eq!(treasury_client.keep_peg(&1000_0000000, &data), 2_0000000);
+
+    // a forced failure refuses even a full repayment
+    pegkeeper_client.set_shortfall(&treasury, &0);
+    treasury_client.set_repayment(&Repayment::Fail);
+    let result = treasury_client.try_keep_peg(&1000_0000000, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::RepaymentTransferFailed.into())));
+    treasury_client.set_repayment(&Repayment::Enforce);
+
+    assert_eq!(treasury_client.get_loans(), 3);
+    assert_eq!(treasury_client.get_fees(), 5_0000000);
+    assert_eq!(ousd_client.balance(&treasury), 5_0000000);
+    assert_eq!(ousd_client.balance(&pegkeeper), 95_0000000);
+
+    // hooks are called on either side of the pegkeeper
+    let hook = e.register_contract(None, MockHook);
+    let hook_client = MockHookClient::new(&e, &hook);
+    let args = vec![&e, ousd.into_val(&e), pegkeeper.into_val(&e)];
+    let record = Symbol::new(&e, "record");
+    treasury_client.set_hook(&HookPoint::BeforeLoan, &hook, &record, &args);
+    treasury_client.set_hook(&HookPoint::AfterLoan, &hook, &record, &args);
+    treasury_client.keep_peg(&1000_0000000, &data);
+    assert_eq!(hook_client.balances(), vec![&e, 95_0000000, 92_0000000]);
+
+    // a hook that reverts reverts the loan
+    let missing = Symbol::new(&e, "missing");
+    treasury_client.set_hook(&HookPoint::AfterLoan, &hook, &missing, &vec![&e]);
+    assert!(treasury_client.try_keep_peg(&1000_0000000, &data).is_err());
+    treasury_client.clear_hook(&HookPoint::AfterLoan);
+    treasury_client.keep_peg(&1000_0000000, &data);
+    assert_eq!(treasury_client.get_loans(), 5);
+    assert_eq!(ousd_client.balance(&pegkeeper), 89_0000000);
+}
+
+#[test]
+fn test_keep_peg_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let treasury = e.register_contract(None, MockTreasuryContract);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury);
+    let ousd = e.register_stellar_asset_contract(treasury.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let pegkeeper = e.register_contract(None, MockPegkeeper);
+    let pegkeeper_client = MockPegkeeperClient::new(&e, &pegkeeper);
+    StellarAssetClient::new(&e, &ousd).mint(&pegkeeper, &100_0000000);
+    treasury_client.initialize(&ousd, &pegkeeper);
+    treasury_client.set_fee(&30);
+    let data = Bytes::new(&e);
+    // the loan is checked before anything is minted
+    let result = treasury_client.try_keep_peg(&-1, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::InvalidArgs.into())));
+    treasury_client.set_supply_cap(&1000_0000000);
+    let result = treasury_client.try_keep_peg(&1000_0000001, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::SupplyCapExceeded.into())));
+
+    // a pegkeeper that sends back more than it holds fails the transfer
+    pegkeeper_client.set_shortfall(&treasury, &-100_0000000);
+    let result = treasury_client.try_keep_peg(&1000_0000000, &data);
+    assert_eq!(result.err(), Some(Ok(MockTreasuryError::RepaymentTransferFailed.into())));
+
+    // the common errors a pegkeeper reverts with are reported as what they mean, and any other
+    // as the call failing
+    let errors = [
+        (4, MockTreasuryError::NotWhitelisted),
+        (8, MockTreasuryError::InvalidArgs),
+        (10, MockTreasuryError::RepaymentTransferFailed),
+        (5400, MockTreasuryError::PegkeeperCallFailed),
+    ];
+    for (code, error) in errors.iter() {
+        pegkeeper_client.set_error(code);
+        let result = treasury_client.try_keep_peg(&1000_0000000, &data);
+        assert_eq!(result.err(), Some(Ok((*error).into())), "{}", code);
+    }
+
+    assert_eq!(treasury_client.get_loans(), 0);
+    assert_eq!(ousd_client.balance(&pegkeeper), 100_0000000);
+}
@@ -0,0 +1,153 @@
+use crate::dependencies::bridge_oracle::BridgeOracleClient;
+use crate::errors::OracleRewardsError;
+use crate::events::OracleRewardsEvents;
+use crate::storage::{self, RewardConfig};
+use orbit_access::Role;
+use sep_40_oracle::Asset;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct OracleRewardsContract;
+
+#[contractclient(name = "OracleRewardsClient")]
+pub trait OracleRewards {
+    /// Initialize the oracle rewards module. Anyone can push an asset's price to the bridge
+    /// oracle through the module, and is paid a bounty from the module's balance for each fresh
+    /// price the oracle accepts. The module is funded by a fee distributor destination.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `oracle` - The Address of the bridge oracle
+    /// * `token` - The Address of the token bounties are paid in
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, oracle: Address, token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set or remove the reward for pushing the price of an asset
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    /// * `bounty` - The tokens paid per accepted push, or zero to stop rewarding the asset
+    /// * `heartbeat` - The most a pushed price can lag the ledger time to be paid, in seconds
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the bounty is negative
+    fn set_reward(e: Env, asset: Asset, bounty: i128, heartbeat: u64);
+
+    /// Get the reward for pushing the price of an asset, if it is rewarded
+    ///
+    /// ### Arguments
+    /// * `asset` - The asset
+    fn get_reward(e: Env, asset: Asset) -> Option<RewardConfig>;
+
+    /// Push the current upstream price of an asset to the bridge oracle, and pay the keeper the
+    /// bounty for the asset. The bounty is capped at the module's balance, so pushes still go
+    /// through when the module runs dry.
+    ///
+    /// Returns the bounty paid
+    ///
+    /// ### Arguments
+    /// * `keeper` - The Address of the keeper paid the bounty
+    /// * `asset` - The asset to push the price for
+    ///
+    /// ### Panics
+    /// If the asset is not rewarded
+    /// If the oracle does not record a newer price
+    /// If the pushed price is older than the heartbeat
+    fn push(e: Env, keeper: Address, asset: Asset) -> i128;
+}
+
+#[contractimpl]
+impl OracleRewards for OracleRewardsContract {
+    fn initialize(e: Env, admin: Address, oracle: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, OracleRewardsError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_oracle(&e, &oracle);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_reward(e: Env, asset: Asset, bounty: i128, heartbeat: u64) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if bounty < 0 {
+            panic_with_error!(&e, OracleRewardsError::NegativeAmountError);
+        }
+
+        if bounty == 0 {
+            storage::del_reward(&e, &asset);
+        } else {
+            storage::set_reward(&e, &asset, &RewardConfig { bounty, heartbeat });
+        }
+
+        OracleRewardsEvents::set_reward(&e, asset, bounty, heartbeat);
+    }
+
+    fn get_reward(e: Env, asset: Asset) -> Option<RewardConfig> {
+        storage::extend_instance(&e);
+        storage::get_reward(&e, &asset)
+    }
+
+    fn push(e: Env, keeper: Address, asset: Asset) -> i128 {
+        storage::extend_instance(&e);
+        keeper.require_auth();
+        let config = match storage::get_reward(&e, &asset) {
+            Some(config) => config,
+            None => panic_with_error!(&e, OracleRewardsError::AssetNotRewarded),
+        };
+
+        let oracle_client = BridgeOracleClient::new(&e, &storage::get_oracle(&e));
+        let last_timestamp = latest_timestamp(&oracle_client, &asset);
+        oracle_client.update(&asset);
+        let timestamp = match latest_timestamp(&oracle_client, &asset) {
+            Some(timestamp) if Some(timestamp) > last_timestamp => timestamp,
+            _ => panic_with_error!(&e, OracleRewardsError::PushNotAccepted),
+        };
+        if timestamp + config.heartbeat < e.ledger().timestamp() {
+            panic_with_error!(&e, OracleRewardsError::StalePrice);
+        }
+
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        let paid = config
+            .bounty
+            .min(token_client.balance(&e.current_contract_address()));
+        if paid > 0 {
+            token_client.transfer(&e.current_contract_address(), &keeper, &paid);
+        }
+
+        OracleRewardsEvents::push(&e, asset, keeper, timestamp, paid);
+        paid
+    }
+}
+
+/// Fetch the timestamp of the newest price the oracle recorded for an asset, if any
+fn latest_timestamp(oracle_client: &BridgeOracleClient, asset: &Asset) -> Option<u64> {
+    oracle_client
+        .prices(asset, &1)
+        .and_then(|prices| prices.first())
+        .map(|price| price.timestamp)
+}
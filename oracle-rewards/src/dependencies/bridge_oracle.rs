@@ -0,0 +1,11 @@
+use sep_40_oracle::{Asset, PriceData};
+use soroban_sdk::{contractclient, Env, Vec};
+
+/// The part of the bridge oracle the module uses to push prices and check they were accepted
+#[allow(dead_code)]
+#[contractclient(name = "BridgeOracleClient")]
+pub trait BridgeOracle {
+    fn update(env: Env, asset: Asset);
+
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+}
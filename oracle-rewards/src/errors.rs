@@ -0,0 +1,19 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the oracle rewards module. Common errors are codes that match up with the
+/// built-in contracts error reporting. Oracle rewards specific errors start at 4700.
+pub enum OracleRewardsError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Oracle Rewards
+    AssetNotRewarded = 4700,
+    PushNotAccepted = 4701,
+    StalePrice = 4702,
+}
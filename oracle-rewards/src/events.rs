@@ -0,0 +1,25 @@
+use sep_40_oracle::Asset;
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct OracleRewardsEvents {}
+
+impl OracleRewardsEvents {
+    /// Emitted when the admin sets or removes the reward for pushing an asset's price
+    ///
+    /// - topics - `["set_reward", asset: Asset]`
+    /// - data - `[bounty: i128, heartbeat: u64]`
+    pub fn set_reward(e: &Env, asset: Asset, bounty: i128, heartbeat: u64) {
+        let topics = (Symbol::new(e, topics::SET_REWARD), asset);
+        e.events().publish(topics, (bounty, heartbeat));
+    }
+
+    /// Emitted when a keeper pushes a price the bridge oracle accepts
+    ///
+    /// - topics - `["push", asset: Asset, keeper: Address]`
+    /// - data - `[timestamp: u64, paid: i128]`
+    pub fn push(e: &Env, asset: Asset, keeper: Address, timestamp: u64, paid: i128) {
+        let topics = (Symbol::new(e, topics::PUSH), asset, keeper);
+        e.events().publish(topics, (timestamp, paid));
+    }
+}
@@ -0,0 +1,128 @@
+use sep_40_oracle::Asset;
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ORACLE_KEY: &str = "Oracle";
+const TOKEN_KEY: &str = "Token";
+
+/// The reward for pushing the price of an asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct RewardConfig {
+    pub bounty: i128,   // the tokens paid per accepted push
+    pub heartbeat: u64, // the most a pushed price can lag the ledger time to be paid, in seconds
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum OracleRewardsDataKey {
+    // The reward for pushing the price of an asset
+    Reward(Asset),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Oracle **********/
+
+/// Fetch the Address of the bridge oracle
+///
+/// ### Panics
+/// If the oracle does not exist
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the bridge oracle
+///
+/// ### Arguments
+/// * `oracle` - The Address of the bridge oracle
+pub fn set_oracle(e: &Env, oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ORACLE_KEY), oracle);
+}
+
+/********** Rewards **********/
+
+/// Fetch the Address of the token rewards are paid in
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token rewards are paid in
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the reward for pushing the price of an asset, if it is rewarded
+///
+/// ### Arguments
+/// * `asset` - The asset
+pub fn get_reward(e: &Env, asset: &Asset) -> Option<RewardConfig> {
+    let key = OracleRewardsDataKey::Reward(asset.clone());
+    let result = e.storage().persistent().get::<OracleRewardsDataKey, RewardConfig>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the reward for pushing the price of an asset
+///
+/// ### Arguments
+/// * `asset` - The asset
+/// * `config` - The reward
+pub fn set_reward(e: &Env, asset: &Asset, config: &RewardConfig) {
+    let key = OracleRewardsDataKey::Reward(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<OracleRewardsDataKey, RewardConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the reward for pushing the price of an asset
+///
+/// ### Arguments
+/// * `asset` - The asset
+pub fn del_reward(e: &Env, asset: &Asset) {
+    let key = OracleRewardsDataKey::Reward(asset.clone());
+    e.storage().persistent().remove(&key);
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+use crate::contract::{OracleRewardsClient, OracleRewardsContract};
+use crate::errors::OracleRewardsError;
+use crate::storage::RewardConfig;
+use sep_40_oracle::{Asset, PriceData};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec};
+
+/// Records the upstream price on update if it is newer than the last one, like the bridge oracle
+#[contract]
+pub struct MockBridgeOracle;
+
+#[contractimpl]
+impl MockBridgeOracle {
+    pub fn set_upstream(e: Env, price: i128, timestamp: u64) {
+        let upstream = PriceData { price, timestamp };
+        e.storage().instance().set(&Symbol::new(&e, "upstream"), &upstream);
+    }
+
+    pub fn update(e: Env, _asset: Asset) {
+        let upstream: PriceData = e.storage().instance().get(&Symbol::new(&e, "upstream")).unwrap();
+        let last: Option<PriceData> = e.storage().instance().get(&Symbol::new(&e, "last"));
+        if last.is_none_or(|last| last.timestamp < upstream.timestamp) {
+            e.storage().instance().set(&Symbol::new(&e, "last"), &upstream);
+        }
+    }
+
+    pub fn prices(e: Env, _asset: Asset, _records: u32) -> Option<Vec<PriceData>> {
+        let last: Option<PriceData> = e.storage().instance().get(&Symbol::new(&e, "last"));
+        last.map(|last| vec![&e, last])
+    }
+}
+
+#[test]
+fn test_push_pays_bounty() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 10000);
+    let token = e.register_stellar_asset_contract(Address::generate(&e));
+    let token_client = TokenClient::new(&e, &token);
+    let oracle = e.register_contract(None, MockBridgeOracle);
+    let oracle_client = MockBridgeOracleClient::new(&e, &oracle);
+    let rewards_id = e.register_contract(None, OracleRewardsContract);
+    let rewards_client = OracleRewardsClient::new(&e, &rewards_id);
+    rewards_client.initialize(&Address::generate(&e), &oracle, &token);
+    StellarAssetClient::new(&e, &token).mint(&rewards_id, &1_5000000);
+
+    let asset = Asset::Other(Symbol::new(&e, "USD"));
+    let keeper = Address::generate(&e);
+    oracle_client.set_upstream(&1_0000000, &9900);
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::AssetNotRewarded.into())));
+
+    rewards_client.set_reward(&asset, &1_0000000, &300);
+    let reward = RewardConfig { bounty: 1_0000000, heartbeat: 300 };
+    assert_eq!(rewards_client.get_reward(&asset), Some(reward));
+    assert_eq!(rewards_client.push(&keeper, &asset), 1_0000000);
+    assert_eq!(token_client.balance(&keeper), 1_0000000);
+
+    // the same price is not accepted twice
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::PushNotAccepted.into())));
+
+    // prices older than the heartbeat are not paid
+    oracle_client.set_upstream(&1_0000000, &9950);
+    e.ledger().with_mut(|li| li.timestamp = 10251);
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::StalePrice.into())));
+
+    // the bounty is capped at the funds left
+    e.ledger().with_mut(|li| li.timestamp = 10250);
+    assert_eq!(rewards_client.push(&keeper, &asset), 5000000);
+    oracle_client.set_upstream(&1_0000000, &10000);
+    assert_eq!(rewards_client.push(&keeper, &asset), 0);
+    assert_eq!(token_client.balance(&keeper), 1_5000000);
+
+    rewards_client.set_reward(&asset, &0, &0);
+    assert_eq!(rewards_client.get_reward(&asset), None);
+}
+
+#[test]
+fn test_push_limits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 10000);
+    let token = e.register_stellar_asset_contract(Address::generate(&e));
+    let token_client = TokenClient::new(&e, &token);
+    let admin = Address::generate(&e);
+    let oracle = e.register_contract(None, MockBridgeOracle);
+    let oracle_client = MockBridgeOracleClient::new(&e, &oracle);
+    let rewards_id = e.register_contract(None, OracleRewardsContract);
+    let rewards_client = OracleRewardsClient::new(&e, &rewards_id);
+    rewards_client.initialize(&admin, &oracle, &token);
+    let result = rewards_client.try_initialize(&admin, &oracle, &token);
+    assert_eq!(
+        result.err(),
+        Some(Ok(OracleRewardsError::AlreadyInitializedError.into()))
+    );
+    StellarAssetClient::new(&e, &token).mint(&rewards_id, &10_0000000);
+
+    let asset = Asset::Other(Symbol::new(&e, "USD"));
+    let keeper = Address::generate(&e);
+    let result = rewards_client.try_set_reward(&asset, &-1, &300);
+    assert_eq!(
+        result.err(),
+        Some(Ok(OracleRewardsError::NegativeAmountError.into()))
+    );
+    rewards_client.set_reward(&asset, &1_0000000, &0);
+    assert_eq!(
+        e.auths()[0],
+        (
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    rewards_id.clone(),
+                    Symbol::new(&e, "set_reward"),
+                    vec![
+                        &e,
+                        asset.into_val(&e),
+                        1_0000000_i128.into_val(&e),
+                        0_u64.into_val(&e),
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+
+    // with no heartbeat, only a price from the current ledger is paid
+    oracle_client.set_upstream(&1_0000000, &9999);
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::StalePrice.into())));
+    oracle_client.set_upstream(&1_0000000, &10000);
+    assert_eq!(rewards_client.push(&keeper, &asset), 1_0000000);
+    assert_eq!(e.auths()[0].0, keeper);
+
+    // an upstream price older than the last one is not accepted, and failed pushes pay nothing
+    e.ledger().with_mut(|li| li.timestamp = 10100);
+    oracle_client.set_upstream(&1_0000000, &9000);
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::PushNotAccepted.into())));
+    assert_eq!(token_client.balance(&keeper), 1_0000000);
+    assert_eq!(token_client.balance(&rewards_id), 9_0000000);
+
+    // removing the reward stops paying for the asset
+    rewards_client.set_reward(&asset, &0, &300);
+    oracle_client.set_upstream(&1_0000000, &10100);
+    let result = rewards_client.try_push(&keeper, &asset);
+    assert_eq!(result.err(), Some(Ok(OracleRewardsError::AssetNotRewarded.into())));
+}
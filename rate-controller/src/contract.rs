@@ -0,0 +1,156 @@
+use crate::dependencies::admin::AdminClient;
+use crate::errors::RateControllerError;
+use crate::events::RateControllerEvents;
+use crate::storage::{self, ControllerConfig};
+use orbit_common::math;
+use sep_40_oracle::{Asset, PriceFeedClient};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct RateControllerContract;
+
+#[contractclient(name = "RateControllerClient")]
+pub trait RateController {
+    /// Initialize the rate controller. The controller must be the interest rate manager of the
+    /// admin contract, or be granted `set_ir_params`.
+    ///
+    /// ### Arguments
+    /// * `admin_contract` - The Address of the admin contract managing the pool and the controller
+    /// * `oracle` - The Address of the oracle pricing the pegged asset in USD
+    /// * `pool` - The Address of the pool
+    /// * `asset` - The Address of the pegged asset
+    /// * `config` - The settings of the controller
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the config is invalid
+    fn initialize(
+        e: Env,
+        admin_contract: Address,
+        oracle: Address,
+        pool: Address,
+        asset: Address,
+        config: ControllerConfig,
+    );
+
+    /// (Admin contract only) Set the settings of the controller
+    ///
+    /// ### Arguments
+    /// * `config` - The settings of the controller
+    ///
+    /// ### Panics
+    /// If the caller is not the admin contract
+    /// If the step is zero or the R1 bounds are reversed
+    fn set_config(e: Env, config: ControllerConfig);
+
+    /// Get the settings of the controller
+    fn get_config(e: Env) -> ControllerConfig;
+
+    /// Check the price of the pegged asset and nudge the R1 rate of its reserve towards the
+    /// peg, through the admin contract. R1 rises by a step while the asset trades below the
+    /// peg, making borrowing more expensive, and falls by a step while it trades above. Anyone
+    /// can call this once per interval.
+    ///
+    /// Returns the R1 rate after the update
+    ///
+    /// ### Panics
+    /// If the interval has not passed since the last update
+    /// If the oracle has no price for the asset
+    fn update(e: Env) -> u32;
+}
+
+#[contractimpl]
+impl RateController for RateControllerContract {
+    fn initialize(
+        e: Env,
+        admin_contract: Address,
+        oracle: Address,
+        pool: Address,
+        asset: Address,
+        config: ControllerConfig,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, RateControllerError::AlreadyInitializedError);
+        }
+        require_valid_config(&e, &config);
+
+        storage::set_admin_contract(&e, &admin_contract);
+        storage::set_oracle(&e, &oracle);
+        storage::set_pool(&e, &pool);
+        storage::set_asset(&e, &asset);
+        storage::set_config(&e, &config);
+        storage::set_is_init(&e);
+    }
+
+    fn set_config(e: Env, config: ControllerConfig) {
+        storage::extend_instance(&e);
+        storage::get_admin_contract(&e).require_auth();
+        require_valid_config(&e, &config);
+
+        storage::set_config(&e, &config);
+
+        RateControllerEvents::set_config(&e, config);
+    }
+
+    fn get_config(e: Env) -> ControllerConfig {
+        storage::extend_instance(&e);
+        storage::get_config(&e)
+    }
+
+    fn update(e: Env) -> u32 {
+        storage::extend_instance(&e);
+
+        let config = storage::get_config(&e);
+        let last_update = storage::get_last_update(&e);
+        if last_update != 0 && e.ledger().timestamp() < last_update + config.interval {
+            panic_with_error!(&e, RateControllerError::UpdateTooSoon);
+        }
+        storage::set_last_update(&e, e.ledger().timestamp());
+
+        let oracle = PriceFeedClient::new(&e, &storage::get_oracle(&e));
+        let asset = storage::get_asset(&e);
+        let price = oracle
+            .lastprice(&Asset::Stellar(asset.clone()))
+            .unwrap_or_else(|| panic_with_error!(&e, RateControllerError::NoPrice))
+            .price;
+        let peg = 10i128.pow(oracle.decimals());
+        let deadband = math::apply_pct(&e, peg, config.deadband as i128);
+
+        let admin_client = AdminClient::new(&e, &storage::get_admin_contract(&e));
+        let pool = storage::get_pool(&e);
+        let reserve_config = admin_client.get_reserve_config(&pool, &asset);
+        let old_r_one = reserve_config.r_one;
+        let new_r_one = if price < peg - deadband {
+            (old_r_one + config.step).min(config.max_r_one)
+        } else if price > peg + deadband {
+            old_r_one.saturating_sub(config.step).max(config.min_r_one)
+        } else {
+            old_r_one
+        };
+        if new_r_one != old_r_one {
+            admin_client.set_ir_params(
+                &e.current_contract_address(),
+                &pool,
+                &asset,
+                &new_r_one,
+                &reserve_config.r_two,
+                &reserve_config.r_three,
+                &reserve_config.util,
+            );
+        }
+
+        RateControllerEvents::update(&e, price, old_r_one, new_r_one);
+        new_r_one
+    }
+}
+
+/// Require that the settings of the controller are valid
+///
+/// ### Panics
+/// If the step is zero or the R1 bounds are reversed
+fn require_valid_config(e: &Env, config: &ControllerConfig) {
+    if config.step == 0 || config.min_r_one > config.max_r_one {
+        panic_with_error!(e, RateControllerError::InvalidConfig);
+    }
+}
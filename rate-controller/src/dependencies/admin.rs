@@ -0,0 +1,21 @@
+use crate::dependencies::pool::ReserveConfig;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the admin contract the controller uses to update interest rates
+#[allow(dead_code)]
+#[contractclient(name = "AdminClient")]
+pub trait Admin {
+    #[allow(clippy::too_many_arguments)]
+    fn set_ir_params(
+        e: Env,
+        caller: Address,
+        pool: Address,
+        asset: Address,
+        r_one: u32,
+        r_two: u32,
+        r_three: u32,
+        util: u32,
+    );
+
+    fn get_reserve_config(e: Env, pool: Address, asset: Address) -> ReserveConfig;
+}
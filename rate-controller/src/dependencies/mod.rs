@@ -0,0 +1,2 @@
+pub mod admin;
+pub use orbit_common::pool;
\ No newline at end of file
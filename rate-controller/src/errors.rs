@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the rate controller contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Rate controller specific errors start at 3100.
+pub enum RateControllerError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Rate Controller
+    UpdateTooSoon = 3100,
+    NoPrice = 3101,
+    InvalidConfig = 3102,
+}
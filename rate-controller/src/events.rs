@@ -0,0 +1,26 @@
+use orbit_events::topics;
+use soroban_sdk::{Env, Symbol};
+
+use crate::storage::ControllerConfig;
+
+pub struct RateControllerEvents {}
+
+impl RateControllerEvents {
+    /// Emitted when the admin updates the controller settings
+    ///
+    /// - topics - `["set_config"]`
+    /// - data - `config: ControllerConfig`
+    pub fn set_config(e: &Env, config: ControllerConfig) {
+        let topics = (Symbol::new(e, topics::SET_CONFIG),);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the controller checks the peg
+    ///
+    /// - topics - `["update"]`
+    /// - data - `[price: i128, old_r_one: u32, new_r_one: u32]`
+    pub fn update(e: &Env, price: i128, old_r_one: u32, new_r_one: u32) {
+        let topics = (Symbol::new(e, topics::UPDATE),);
+        e.events().publish(topics, (price, old_r_one, new_r_one));
+    }
+}
@@ -0,0 +1,169 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ADMIN_CONTRACT_KEY: &str = "AdminContract";
+const ORACLE_KEY: &str = "Oracle";
+const POOL_KEY: &str = "Pool";
+const ASSET_KEY: &str = "Asset";
+const CONFIG_KEY: &str = "Config";
+const LAST_UPDATE_KEY: &str = "LastUpdate";
+
+/// The settings of the controller. Rates have 7 decimals.
+#[derive(Clone)]
+#[contracttype]
+pub struct ControllerConfig {
+    pub deadband: u32, // how far the price can drift from $1 before rates are adjusted
+    pub step: u32,     // the change to R1 per update
+    pub min_r_one: u32,
+    pub max_r_one: u32,
+    pub interval: u64, // the minimum time between updates, in seconds
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the admin contract managing the pool
+///
+/// ### Panics
+/// If the admin contract does not exist
+pub fn get_admin_contract(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ADMIN_CONTRACT_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the admin contract managing the pool
+///
+/// ### Arguments
+/// * `admin_contract` - The Address of the admin contract
+pub fn set_admin_contract(e: &Env, admin_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_CONTRACT_KEY), admin_contract);
+}
+
+/// Fetch the Address of the oracle
+///
+/// ### Panics
+/// If the oracle does not exist
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the oracle
+///
+/// ### Arguments
+/// * `oracle` - The Address of the oracle
+pub fn set_oracle(e: &Env, oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ORACLE_KEY), oracle);
+}
+
+/// Fetch the Address of the pool
+///
+/// ### Panics
+/// If the pool does not exist
+pub fn get_pool(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, POOL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the pool
+///
+/// ### Arguments
+/// * `pool` - The Address of the pool
+pub fn set_pool(e: &Env, pool: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, POOL_KEY), pool);
+}
+
+/// Fetch the Address of the pegged asset
+///
+/// ### Panics
+/// If the asset does not exist
+pub fn get_asset(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ASSET_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the pegged asset
+///
+/// ### Arguments
+/// * `asset` - The Address of the pegged asset
+pub fn set_asset(e: &Env, asset: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ASSET_KEY), asset);
+}
+
+/********** Controller **********/
+
+/// Fetch the settings of the controller
+///
+/// ### Panics
+/// If the config does not exist
+pub fn get_config(e: &Env) -> ControllerConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, CONFIG_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the settings of the controller
+///
+/// ### Arguments
+/// * `config` - The settings of the controller
+pub fn set_config(e: &Env, config: &ControllerConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, ControllerConfig>(&Symbol::new(e, CONFIG_KEY), config);
+}
+
+/// Fetch the time of the last update
+pub fn get_last_update(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, LAST_UPDATE_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the time of the last update
+///
+/// ### Arguments
+/// * `timestamp` - The time of the last update
+pub fn set_last_update(e: &Env, timestamp: u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, LAST_UPDATE_KEY), &timestamp);
+}
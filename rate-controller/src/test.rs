@@ -0,0 +1,118 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{RateControllerClient, RateControllerContract};
+use crate::dependencies::pool::ReserveConfig;
+use crate::errors::RateControllerError;
+use crate::storage::ControllerConfig;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient, MockPriceOracleWASM};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, Symbol};
+
+/// Stores the reserve config updated through `set_ir_params`, like the admin contract
+#[contract]
+pub struct MockAdmin;
+
+#[contractimpl]
+impl MockAdmin {
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_ir_params(
+        e: Env,
+        caller: Address,
+        _pool: Address,
+        _asset: Address,
+        r_one: u32,
+        r_two: u32,
+        r_three: u32,
+        util: u32,
+    ) {
+        caller.require_auth();
+        let mut config = Self::get_reserve_config(e.clone(), _pool, _asset);
+        config.r_one = r_one;
+        config.r_two = r_two;
+        config.r_three = r_three;
+        config.util = util;
+        e.storage().instance().set(&Symbol::new(&e, "config"), &config);
+    }
+
+    pub fn get_reserve_config(e: Env, _pool: Address, _asset: Address) -> ReserveConfig {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "config"))
+            .unwrap_or(ReserveConfig {
+                decimals: 7,
+                c_factor: 0_7500000,
+                l_factor: 0_7500000,
+                util: 0_7500000,
+                max_util: 0_9500000,
+                r_one: 0_0500000,
+                r_two: 0_5000000,
+                r_three: 1_5000000,
+                reactivity: 0_0000020,
+                index: 0,
+            })
+    }
+}
+
+#[test]
+fn test_update() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = Address::generate(&e);
+    let pool = Address::generate(&e);
+    let admin_id = e.register_contract(None, MockAdmin);
+    let admin_client = MockAdminClient::new(&e, &admin_id);
+
+    let oracle_id = e.register_contract_wasm(None, MockPriceOracleWASM);
+    let oracle_client = MockPriceOracleClient::new(&e, &oracle_id);
+    oracle_client.set_data(
+        &Address::generate(&e),
+        &Asset::Other(Symbol::new(&e, "USD")),
+        &vec![&e, Asset::Stellar(ousd.clone())],
+        &7,
+        &300,
+    );
+
+    let controller_id = e.register_contract(None, RateControllerContract);
+    let controller_client = RateControllerClient::new(&e, &controller_id);
+    controller_client.initialize(
+        &admin_id,
+        &oracle_id,
+        &pool,
+        &ousd,
+        &ControllerConfig {
+            deadband: 0_0050000,
+            step: 0_0100000,
+            min_r_one: 0_0200000,
+            max_r_one: 0_0700000,
+            interval: 24 * 60 * 60,
+        },
+    );
+
+    // below the peg, R1 rises
+    oracle_client.set_price_stable(&vec![&e, 0_9900000]);
+    assert_eq!(controller_client.update(), 0_0600000);
+    assert_eq!(admin_client.get_reserve_config(&pool, &ousd).r_one, 0_0600000);
+    assert_eq!(admin_client.get_reserve_config(&pool, &ousd).r_two, 0_5000000);
+    let result = controller_client.try_update();
+    assert_eq!(result.err(), Some(Ok(RateControllerError::UpdateTooSoon.into())));
+
+    // capped at the max
+    e.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    assert_eq!(controller_client.update(), 0_0700000);
+    e.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    assert_eq!(controller_client.update(), 0_0700000);
+
+    // within the deadband, R1 holds
+    oracle_client.set_price_stable(&vec![&e, 1_0040000]);
+    e.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    assert_eq!(controller_client.update(), 0_0700000);
+
+    // above the peg, R1 falls to the min
+    oracle_client.set_price_stable(&vec![&e, 1_0100000]);
+    for _ in 0..6 {
+        e.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+        controller_client.update();
+    }
+    assert_eq!(admin_client.get_reserve_config(&pool, &ousd).r_one, 0_0200000);
+}
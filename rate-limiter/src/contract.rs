@@ -0,0 +1,195 @@
+use crate::errors::RateLimiterError;
+use crate::events::RateLimiterEvents;
+use crate::storage::{self, Bucket};
+use orbit_access::Role;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct RateLimiterContract;
+
+#[contractclient(name = "RateLimiterClient")]
+pub trait RateLimiter {
+    /// Initialize the rate limiter
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the mint limit of an asset. A new bucket starts full, and an existing
+    /// bucket keeps what is available up to the new capacity.
+    ///
+    /// ### Arguments
+    /// * `asset` - The Address of the asset being minted
+    /// * `capacity` - The most that can be minted at once
+    /// * `refill_rate` - The amount the limit refills per second
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the capacity or refill rate is negative
+    fn set_limit(e: Env, asset: Address, capacity: i128, refill_rate: i128);
+
+    /// (Admin only) Allow or disallow an address to mint against the limits
+    ///
+    /// ### Arguments
+    /// * `minter` - The Address of the minting contract
+    /// * `allowed` - If the minter is allowed
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_minter(e: Env, minter: Address, allowed: bool);
+
+    /// Check if an address is allowed to mint against the limits
+    ///
+    /// ### Arguments
+    /// * `minter` - The Address to check
+    fn is_minter(e: Env, minter: Address) -> bool;
+
+    /// Get the token bucket of an asset, refilled to the current time
+    ///
+    /// ### Arguments
+    /// * `asset` - The Address of the asset
+    ///
+    /// ### Panics
+    /// If the asset has no limit
+    fn get_bucket(e: Env, asset: Address) -> Bucket;
+
+    /// Consume from the token bucket of an asset before minting it
+    ///
+    /// ### Arguments
+    /// * `minter` - The Address minting the asset
+    /// * `asset` - The Address of the asset being minted
+    /// * `amount` - The amount being minted
+    ///
+    /// ### Panics
+    /// If the minter is not allowed
+    /// If the asset has no limit
+    /// If the amount exceeds what is available
+    fn consume(e: Env, minter: Address, asset: Address, amount: i128);
+}
+
+#[contractimpl]
+impl RateLimiter for RateLimiterContract {
+    fn initialize(e: Env, admin: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, RateLimiterError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_limit(e: Env, asset: Address, capacity: i128, refill_rate: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if capacity < 0 || refill_rate < 0 {
+            panic_with_error!(&e, RateLimiterError::NegativeAmountError);
+        }
+
+        let available = match storage::get_bucket(&e, &asset) {
+            Some(bucket) => refill(&e, &bucket).min(capacity),
+            None => capacity,
+        };
+        storage::set_bucket(
+            &e,
+            &asset,
+            &Bucket {
+                capacity,
+                refill_rate,
+                available,
+                last_update: e.ledger().timestamp(),
+            },
+        );
+
+        RateLimiterEvents::set_limit(&e, asset, capacity, refill_rate);
+    }
+
+    fn set_minter(e: Env, minter: Address, allowed: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        if allowed {
+            storage::set_minter(&e, &minter);
+        } else {
+            storage::del_minter(&e, &minter);
+        }
+
+        RateLimiterEvents::set_minter(&e, minter, allowed);
+    }
+
+    fn is_minter(e: Env, minter: Address) -> bool {
+        storage::extend_instance(&e);
+        storage::is_minter(&e, &minter)
+    }
+
+    fn get_bucket(e: Env, asset: Address) -> Bucket {
+        storage::extend_instance(&e);
+        let mut bucket = load_bucket(&e, &asset);
+        bucket.available = refill(&e, &bucket);
+        bucket.last_update = e.ledger().timestamp();
+        bucket
+    }
+
+    fn consume(e: Env, minter: Address, asset: Address, amount: i128) {
+        storage::extend_instance(&e);
+        minter.require_auth();
+        if !storage::is_minter(&e, &minter) {
+            panic_with_error!(&e, RateLimiterError::UnauthorizedError);
+        }
+        if amount < 0 {
+            panic_with_error!(&e, RateLimiterError::NegativeAmountError);
+        }
+
+        let mut bucket = load_bucket(&e, &asset);
+        let available = refill(&e, &bucket);
+        if amount > available {
+            panic_with_error!(&e, RateLimiterError::RateLimited);
+        }
+        bucket.available = available - amount;
+        bucket.last_update = e.ledger().timestamp();
+        storage::set_bucket(&e, &asset, &bucket);
+
+        RateLimiterEvents::consume(&e, asset, minter, amount, bucket.available);
+    }
+}
+
+/// Load the token bucket of an asset
+///
+/// ### Panics
+/// If the asset has no limit
+fn load_bucket(e: &Env, asset: &Address) -> Bucket {
+    match storage::get_bucket(e, asset) {
+        Some(bucket) => bucket,
+        None => panic_with_error!(e, RateLimiterError::LimitNotSet),
+    }
+}
+
+/// Calculate the amount available in a token bucket at the current time
+fn refill(e: &Env, bucket: &Bucket) -> i128 {
+    let elapsed = e.ledger().timestamp().saturating_sub(bucket.last_update) as i128;
+    bucket
+        .refill_rate
+        .saturating_mul(elapsed)
+        .saturating_add(bucket.available)
+        .min(bucket.capacity)
+}
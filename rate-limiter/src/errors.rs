@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the rate limiter contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Rate limiter specific errors start at 3300.
+pub enum RateLimiterError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Rate Limiter
+    RateLimited = 3300,
+    LimitNotSet = 3301,
+}
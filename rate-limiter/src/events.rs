@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct RateLimiterEvents {}
+
+impl RateLimiterEvents {
+    /// Emitted when the admin sets the mint limit of an asset
+    ///
+    /// - topics - `["set_limit", asset: Address]`
+    /// - data - `[capacity: i128, refill_rate: i128]`
+    pub fn set_limit(e: &Env, asset: Address, capacity: i128, refill_rate: i128) {
+        let topics = (Symbol::new(e, topics::SET_LIMIT), asset);
+        e.events().publish(topics, (capacity, refill_rate));
+    }
+
+    /// Emitted when the admin allows or disallows a minter
+    ///
+    /// - topics - `["set_minter", minter: Address]`
+    /// - data - `allowed: bool`
+    pub fn set_minter(e: &Env, minter: Address, allowed: bool) {
+        let topics = (Symbol::new(e, topics::SET_MINTER), minter);
+        e.events().publish(topics, allowed);
+    }
+
+    /// Emitted when a minter consumes from the token bucket of an asset
+    ///
+    /// - topics - `["consume", asset: Address, minter: Address]`
+    /// - data - `[amount: i128, available: i128]`
+    pub fn consume(e: &Env, asset: Address, minter: Address, amount: i128, available: i128) {
+        let topics = (Symbol::new(e, topics::CONSUME), asset, minter);
+        e.events().publish(topics, (amount, available));
+    }
+}
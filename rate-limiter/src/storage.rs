@@ -0,0 +1,119 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+
+/// A token bucket limiting how fast an asset can be minted
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Bucket {
+    pub capacity: i128,    // the most that can be minted at once
+    pub refill_rate: i128, // the amount the bucket refills per second
+    pub available: i128,   // the amount that could be minted at `last_update`
+    pub last_update: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum RateLimiterDataKey {
+    // The token bucket of an asset
+    Bucket(Address),
+    // If an address is allowed to consume from the token buckets
+    Minter(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Buckets **********/
+
+/// Fetch the token bucket of an asset, if it exists
+///
+/// ### Arguments
+/// * `asset` - The Address of the asset
+pub fn get_bucket(e: &Env, asset: &Address) -> Option<Bucket> {
+    let key = RateLimiterDataKey::Bucket(asset.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<RateLimiterDataKey, Bucket>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the token bucket of an asset
+///
+/// ### Arguments
+/// * `asset` - The Address of the asset
+/// * `bucket` - The token bucket
+pub fn set_bucket(e: &Env, asset: &Address, bucket: &Bucket) {
+    let key = RateLimiterDataKey::Bucket(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<RateLimiterDataKey, Bucket>(&key, bucket);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/********** Minters **********/
+
+/// Check if an address is allowed to consume from the token buckets
+///
+/// ### Arguments
+/// * `minter` - The Address to check
+pub fn is_minter(e: &Env, minter: &Address) -> bool {
+    let key = RateLimiterDataKey::Minter(minter.clone());
+    let result = e.storage().persistent().has(&key);
+    if result {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Allow an address to consume from the token buckets
+///
+/// ### Arguments
+/// * `minter` - The Address to allow
+pub fn set_minter(e: &Env, minter: &Address) {
+    let key = RateLimiterDataKey::Minter(minter.clone());
+    e.storage()
+        .persistent()
+        .set::<RateLimiterDataKey, bool>(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Stop an address from consuming from the token buckets
+///
+/// ### Arguments
+/// * `minter` - The Address to remove
+pub fn del_minter(e: &Env, minter: &Address) {
+    let key = RateLimiterDataKey::Minter(minter.clone());
+    e.storage().persistent().remove(&key);
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+use crate::contract::{RateLimiterClient, RateLimiterContract};
+use crate::errors::RateLimiterError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_consume_and_refill() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let psm = Address::generate(&e);
+    let ousd = Address::generate(&e);
+
+    let limiter_id = e.register_contract(None, RateLimiterContract);
+    let limiter_client = RateLimiterClient::new(&e, &limiter_id);
+    limiter_client.initialize(&admin);
+    limiter_client.set_limit(&ousd, &10_000_000_000_000, &10_0000000);
+    limiter_client.set_minter(&treasury, &true);
+    limiter_client.set_minter(&psm, &true);
+
+    // all mint paths share the same bucket
+    limiter_client.consume(&treasury, &ousd, &6_000_000_000_000);
+    limiter_client.consume(&psm, &ousd, &4_000_000_000_000);
+    assert_eq!(limiter_client.get_bucket(&ousd).available, 0);
+    let result = limiter_client.try_consume(&treasury, &ousd, &1);
+    assert_eq!(result.err(), Some(Ok(RateLimiterError::RateLimited.into())));
+
+    // the bucket refills over time up to its capacity
+    e.ledger().with_mut(|li| li.timestamp += 1000);
+    assert_eq!(limiter_client.get_bucket(&ousd).available, 100_000_000_000);
+    limiter_client.consume(&psm, &ousd, &100_000_000_000);
+    e.ledger().with_mut(|li| li.timestamp += 1_000_000);
+    assert_eq!(
+        limiter_client.get_bucket(&ousd).available,
+        10_000_000_000_000
+    );
+
+    // lowering the capacity caps what is available
+    limiter_client.set_limit(&ousd, &5_000_000_000_000, &10_0000000);
+    assert_eq!(limiter_client.get_bucket(&ousd).available, 5_000_000_000_000);
+}
+
+#[test]
+fn test_consume_requires_minter_and_limit() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let ousd = Address::generate(&e);
+
+    let limiter_id = e.register_contract(None, RateLimiterContract);
+    let limiter_client = RateLimiterClient::new(&e, &limiter_id);
+    limiter_client.initialize(&admin);
+
+    let result = limiter_client.try_consume(&treasury, &ousd, &1_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(RateLimiterError::UnauthorizedError.into()))
+    );
+
+    limiter_client.set_minter(&treasury, &true);
+    let result = limiter_client.try_consume(&treasury, &ousd, &1_0000000);
+    assert_eq!(result.err(), Some(Ok(RateLimiterError::LimitNotSet.into())));
+
+    limiter_client.set_limit(&ousd, &100_0000000, &1_0000000);
+    limiter_client.consume(&treasury, &ousd, &1_0000000);
+    assert!(limiter_client.is_minter(&treasury));
+
+    limiter_client.set_minter(&treasury, &false);
+    assert!(!limiter_client.is_minter(&treasury));
+    let result = limiter_client.try_consume(&treasury, &ousd, &1_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(RateLimiterError::UnauthorizedError.into()))
+    );
+}
@@ -0,0 +1,288 @@
+use crate::errors::RedemptionError;
+use crate::events::RedemptionEvents;
+use crate::storage::{self, BaseRate, RedemptionConfig};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_40_oracle::{Asset, PriceFeedClient};
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+const SCALAR_7: i128 = 1_0000000;
+
+#[contract]
+pub struct RedemptionContract;
+
+#[contractclient(name = "RedemptionClient")]
+pub trait Redemption {
+
+    /// Initialize the redemption contract. Collateral is paid out of the contract's own balances,
+    /// which are funded by transferring collateral tokens to the contract.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the redeemable token
+    /// * `oracle` - The Address of the oracle pricing the token and its collateral in USD
+    /// * `config` - The redemption fee and peg settings
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the config is invalid
+    fn initialize(e: Env, admin: Address, token: Address, oracle: Address, config: RedemptionConfig);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the redemption fee and peg settings
+    ///
+    /// ### Arguments
+    /// * `config` - The redemption fee and peg settings
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If `base_fee` is greater than `max_fee`, or a rate is greater than 100%
+    fn set_config(e: Env, config: RedemptionConfig);
+
+    /// Get the redemption fee and peg settings
+    fn get_config(e: Env) -> RedemptionConfig;
+
+    /// (Admin only) Set the collateral tokens paid out by redemptions. Redemptions draw from
+    /// the first token until its balance runs out, then from the next.
+    ///
+    /// ### Arguments
+    /// * `collateral` - The collateral token addresses, riskiest first
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_collateral(e: Env, collateral: Vec<Address>);
+
+    /// Get the collateral tokens paid out by redemptions, riskiest first
+    fn get_collateral(e: Env) -> Vec<Address>;
+
+    /// (Admin only) Withdraw collateral from the reserves
+    ///
+    /// ### Arguments
+    /// * `token` - The collateral token to withdraw
+    /// * `to` - The Address receiving the collateral
+    /// * `amount` - The amount to withdraw
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn withdraw(e: Env, token: Address, to: Address, amount: i128);
+
+    /// Get the current redemption fee, with 7 decimals
+    fn get_fee(e: Env) -> u32;
+
+    /// Redeem tokens for $1 of collateral each, less the redemption fee. Only possible while
+    /// the token trades below the peg threshold. Every redemption raises the fee for later
+    /// redemptions in proportion to the share of the reserves it redeems.
+    ///
+    /// Returns the amount of each collateral token paid out
+    ///
+    /// ### Arguments
+    /// * `from` - The Address redeeming tokens
+    /// * `amount` - The amount of tokens to redeem
+    ///
+    /// ### Panics
+    /// If the oracle has no price for the token or a collateral, or the price is too old
+    /// If the token does not trade below the peg threshold
+    /// If the reserves can't cover the redemption
+    fn redeem(e: Env, from: Address, amount: i128) -> Vec<(Address, i128)>;
+}
+
+#[contractimpl]
+impl Redemption for RedemptionContract {
+    fn initialize(e: Env, admin: Address, token: Address, oracle: Address, config: RedemptionConfig) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, RedemptionError::AlreadyInitializedError);
+        }
+        require_valid_config(&e, &config);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_oracle(&e, &oracle);
+        storage::set_config(&e, &config);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_config(e: Env, config: RedemptionConfig) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_config(&e, &config);
+
+        storage::set_config(&e, &config);
+
+        RedemptionEvents::set_config(&e, config);
+    }
+
+    fn get_config(e: Env) -> RedemptionConfig {
+        storage::extend_instance(&e);
+        storage::get_config(&e)
+    }
+
+    fn set_collateral(e: Env, collateral: Vec<Address>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_collateral(&e, &collateral);
+
+        RedemptionEvents::set_collateral(&e, collateral);
+    }
+
+    fn get_collateral(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_collateral(&e)
+    }
+
+    fn withdraw(e: Env, token: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if amount < 0 {
+            panic_with_error!(&e, RedemptionError::NegativeAmountError);
+        }
+
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &to, &amount);
+
+        RedemptionEvents::withdraw(&e, token, to, amount);
+    }
+
+    fn get_fee(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        let config = storage::get_config(&e);
+        let base_rate = decay_base_rate(&e, &config, &storage::get_base_rate(&e));
+        (config.base_fee + base_rate).min(config.max_fee)
+    }
+
+    fn redeem(e: Env, from: Address, amount: i128) -> Vec<(Address, i128)> {
+        storage::extend_instance(&e);
+        from.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&e, RedemptionError::NegativeAmountError);
+        }
+
+        let config = storage::get_config(&e);
+        let token = storage::get_token(&e);
+        let token_client = TokenClient::new(&e, &token);
+        let oracle_client = PriceFeedClient::new(&e, &storage::get_oracle(&e));
+        let oracle_scalar = 10i128.pow(oracle_client.decimals());
+        let peg_price = math::apply_pct(&e, oracle_scalar, SCALAR_7 - config.peg_threshold as i128);
+        if fetch_price(&e, &oracle_client, &config, &token) >= peg_price {
+            panic_with_error!(&e, RedemptionError::PegNotBroken);
+        }
+
+        // value the reserves in token units, riskiest collateral first
+        let token_scalar = 10i128.pow(token_client.decimals());
+        let mut reserves: Vec<(Address, i128, i128)> = vec![&e];
+        let mut total_value: i128 = 0;
+        for collateral in storage::get_collateral(&e).iter() {
+            let collateral_client = TokenClient::new(&e, &collateral);
+            let balance = collateral_client.balance(&e.current_contract_address());
+            if balance <= 0 {
+                continue;
+            }
+            let price = fetch_price(&e, &oracle_client, &config, &collateral);
+            // the value of one whole collateral token in token units
+            let unit_value = price * token_scalar / oracle_scalar;
+            let collateral_scalar = 10i128.pow(collateral_client.decimals());
+            total_value += balance * unit_value / collateral_scalar;
+            reserves.push_back((collateral, unit_value, collateral_scalar));
+        }
+        if total_value == 0 {
+            panic_with_error!(&e, RedemptionError::InsufficientReserves);
+        }
+
+        // raise the base rate by half the share of the reserves redeemed, then charge the fee
+        let mut base_rate = decay_base_rate(&e, &config, &storage::get_base_rate(&e)) as i128;
+        base_rate = (base_rate + math::pct_of(&e, amount, 2 * total_value)).min(SCALAR_7);
+        storage::set_base_rate(
+            &e,
+            &BaseRate {
+                rate: base_rate as u32,
+                timestamp: e.ledger().timestamp(),
+            },
+        );
+        let fee_rate = (config.base_fee as i128 + base_rate).min(config.max_fee as i128);
+        let fee = math::apply_pct(&e, amount, fee_rate);
+
+        let mut remaining = amount - fee;
+        let mut payouts: Vec<(Address, i128)> = vec![&e];
+        for (collateral, unit_value, collateral_scalar) in reserves.iter() {
+            if remaining == 0 {
+                break;
+            }
+            let collateral_client = TokenClient::new(&e, &collateral);
+            let balance = collateral_client.balance(&e.current_contract_address());
+            let payout = (remaining * collateral_scalar / unit_value).min(balance);
+            let value = if payout == balance {
+                (payout * unit_value / collateral_scalar).min(remaining)
+            } else {
+                remaining
+            };
+            collateral_client.transfer(&e.current_contract_address(), &from, &payout);
+            payouts.push_back((collateral, payout));
+            remaining -= value;
+        }
+        if remaining > 0 {
+            panic_with_error!(&e, RedemptionError::InsufficientReserves);
+        }
+
+        token_client.burn(&from, &amount);
+
+        RedemptionEvents::redeem(&e, from, amount, fee, payouts.clone());
+        payouts
+    }
+}
+
+/// Panic if a rate in the config is greater than 100% or the base fee is greater than the max fee
+fn require_valid_config(e: &Env, config: &RedemptionConfig) {
+    if config.base_fee > config.max_fee
+        || config.max_fee as i128 > SCALAR_7
+        || config.peg_threshold as i128 >= SCALAR_7
+    {
+        panic_with_error!(e, RedemptionError::InvalidConfig);
+    }
+}
+
+/// Get the base rate decayed linearly over the decay period since the last redemption
+fn decay_base_rate(e: &Env, config: &RedemptionConfig, base_rate: &BaseRate) -> u32 {
+    let elapsed = e.ledger().timestamp().saturating_sub(base_rate.timestamp);
+    if elapsed >= config.decay_period {
+        return 0;
+    }
+    (base_rate.rate as u64 * (config.decay_period - elapsed) / config.decay_period) as u32
+}
+
+/// Fetch the oracle price of a token
+///
+/// ### Panics
+/// If the oracle has no price for the token
+/// If the price is older than the maximum age of the config
+fn fetch_price(
+    e: &Env,
+    oracle_client: &PriceFeedClient,
+    config: &RedemptionConfig,
+    token: &Address,
+) -> i128 {
+    let price_data = match oracle_client.lastprice(&Asset::Stellar(token.clone())) {
+        Some(price_data) if price_data.price > 0 => price_data,
+        _ => panic_with_error!(e, RedemptionError::NoPrice),
+    };
+    if config.max_age > 0 && e.ledger().timestamp() > price_data.timestamp + config.max_age {
+        panic_with_error!(e, RedemptionError::StalePrice);
+    }
+    price_data.price
+}
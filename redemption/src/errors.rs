@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the redemption contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Redemption specific errors start at 2300.
+pub enum RedemptionError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Redemption
+    InvalidConfig = 2300,
+    PegNotBroken = 2301,
+    InsufficientReserves = 2302,
+    NoPrice = 2303,
+    StalePrice = 2304,
+}
@@ -0,0 +1,44 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::RedemptionConfig;
+
+pub struct RedemptionEvents {}
+
+impl RedemptionEvents {
+    /// Emitted when the admin updates the redemption config
+    ///
+    /// - topics - `["set_config"]`
+    /// - data - `config: RedemptionConfig`
+    pub fn set_config(e: &Env, config: RedemptionConfig) {
+        let topics = (Symbol::new(e, topics::SET_CONFIG),);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin sets the collateral tokens paid out by redemptions
+    ///
+    /// - topics - `["set_collateral"]`
+    /// - data - `collateral: Vec<Address>`
+    pub fn set_collateral(e: &Env, collateral: Vec<Address>) {
+        let topics = (Symbol::new(e, topics::SET_COLLATERAL),);
+        e.events().publish(topics, collateral);
+    }
+
+    /// Emitted when the admin withdraws collateral from the reserves
+    ///
+    /// - topics - `["withdraw", token: Address]`
+    /// - data - `[to: Address, amount: i128]`
+    pub fn withdraw(e: &Env, token: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), token);
+        e.events().publish(topics, (to, amount));
+    }
+
+    /// Emitted when tokens are redeemed for collateral
+    ///
+    /// - topics - `["redeem", from: Address]`
+    /// - data - `[amount: i128, fee: i128, payouts: Vec<(Address, i128)>]`
+    pub fn redeem(e: &Env, from: Address, amount: i128, fee: i128, payouts: Vec<(Address, i128)>) {
+        let topics = (Symbol::new(e, topics::REDEEM), from);
+        e.events().publish(topics, (amount, fee, payouts));
+    }
+}
@@ -0,0 +1,161 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const ORACLE_KEY: &str = "Oracle";
+const CONFIG_KEY: &str = "Config";
+const COLLATERAL_KEY: &str = "Collateral";
+const BASE_RATE_KEY: &str = "BaseRate";
+
+/// The redemption fee and peg settings. Rates have 7 decimals.
+#[derive(Clone)]
+#[contracttype]
+pub struct RedemptionConfig {
+    pub base_fee: u32,      // the fee charged on every redemption
+    pub max_fee: u32,       // the cap on the total fee
+    pub decay_period: u64,  // the time for the fee raised by a redemption to decay, in seconds
+    pub peg_threshold: u32, // how far below $1 the token must trade before redemptions open
+    pub max_age: u64,       // the oldest an oracle price can be, in seconds, or zero for any age
+}
+
+/// The extra redemption fee built up by past redemptions
+#[derive(Clone)]
+#[contracttype]
+pub struct BaseRate {
+    pub rate: u32,      // the extra fee at `timestamp`, with 7 decimals
+    pub timestamp: u64, // the time of the last redemption
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the redeemable token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the redeemable token
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Oracle **********/
+
+/// Fetch the Address of the price oracle
+///
+/// ### Panics
+/// If the oracle does not exist
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the price oracle
+///
+/// ### Arguments
+/// * `oracle` - The Address for the oracle
+pub fn set_oracle(e: &Env, oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ORACLE_KEY), oracle);
+}
+
+/********** Config **********/
+
+/// Fetch the redemption config
+///
+/// ### Panics
+/// If the config does not exist
+pub fn get_config(e: &Env) -> RedemptionConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, CONFIG_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the redemption config
+///
+/// ### Arguments
+/// * `config` - The redemption config
+pub fn set_config(e: &Env, config: &RedemptionConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, RedemptionConfig>(&Symbol::new(e, CONFIG_KEY), config);
+}
+
+/********** Collateral **********/
+
+/// Fetch the collateral tokens paid out by redemptions, riskiest first
+pub fn get_collateral(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, COLLATERAL_KEY))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Set the collateral tokens paid out by redemptions
+///
+/// ### Arguments
+/// * `collateral` - The collateral token Addresses, riskiest first
+pub fn set_collateral(e: &Env, collateral: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, COLLATERAL_KEY), collateral);
+}
+
+/********** Base Rate **********/
+
+/// Fetch the extra fee built up by past redemptions
+pub fn get_base_rate(e: &Env) -> BaseRate {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, BASE_RATE_KEY))
+        .unwrap_or(BaseRate {
+            rate: 0,
+            timestamp: 0,
+        })
+}
+
+/// Set the extra fee built up by past redemptions
+///
+/// ### Arguments
+/// * `base_rate` - The extra fee and the time it was last updated
+pub fn set_base_rate(e: &Env, base_rate: &BaseRate) {
+    e.storage()
+        .instance()
+        .set::<Symbol, BaseRate>(&Symbol::new(e, BASE_RATE_KEY), base_rate);
+}
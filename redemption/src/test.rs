@@ -0,0 +1,118 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{RedemptionClient, RedemptionContract};
+use crate::errors::RedemptionError;
+use crate::storage::RedemptionConfig;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient, MockPriceOracleWASM};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, Symbol};
+
+fn default_config() -> RedemptionConfig {
+    RedemptionConfig {
+        base_fee: 0_0050000,
+        max_fee: 0_0500000,
+        decay_period: 12 * 60 * 60,
+        peg_threshold: 0_0100000,
+        max_age: 15 * 60,
+    }
+}
+
+/// Create a redemption contract for a token with 2 collateral tokens, returning the redemption
+/// client, the oracle client and the token, riskiest collateral and safest collateral addresses
+fn create_redemption<'a>(
+    e: &Env,
+) -> (RedemptionClient<'a>, MockPriceOracleClient<'a>, Address, Address, Address) {
+    let token_admin = Address::generate(e);
+    let token = e.register_stellar_asset_contract(token_admin.clone());
+    let xlm = e.register_stellar_asset_contract(token_admin.clone());
+    let usdc = e.register_stellar_asset_contract(token_admin);
+
+    let oracle_id = e.register_contract_wasm(None, MockPriceOracleWASM);
+    let oracle_client = MockPriceOracleClient::new(e, &oracle_id);
+    oracle_client.set_data(
+        &Address::generate(e),
+        &Asset::Other(Symbol::new(e, "USD")),
+        &vec![
+            e,
+            Asset::Stellar(token.clone()),
+            Asset::Stellar(xlm.clone()),
+            Asset::Stellar(usdc.clone()),
+        ],
+        &7,
+        &300,
+    );
+
+    let redemption_id = e.register_contract(None, RedemptionContract);
+    let redemption_client = RedemptionClient::new(e, &redemption_id);
+    redemption_client.initialize(&Address::generate(e), &token, &oracle_id, &default_config());
+    redemption_client.set_collateral(&vec![e, xlm.clone(), usdc.clone()]);
+    (redemption_client, oracle_client, token, xlm, usdc)
+}
+
+#[test]
+fn test_redeem() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (redemption_client, oracle_client, token, xlm, usdc) = create_redemption(&e);
+    let user = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&user, &1000_0000000);
+    StellarAssetClient::new(&e, &xlm).mint(&redemption_client.address, &2000_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&redemption_client.address, &800_0000000);
+
+    // no redemptions at the peg
+    oracle_client.set_price_stable(&vec![&e, 0_9950000, 0_1000000, 1_0000000]);
+    let result = redemption_client.try_redeem(&user, &500_0000000);
+    assert_eq!(result.err(), Some(Ok(RedemptionError::PegNotBroken.into())));
+
+    oracle_client.set_price_stable(&vec![&e, 0_9800000, 0_1000000, 1_0000000]);
+    assert_eq!(redemption_client.get_fee(), 0_0050000);
+    let payouts = redemption_client.redeem(&user, &500_0000000);
+
+    // reserves are worth $1000, so the base rate rises by 25% and the fee is capped at 5%
+    // $475 is paid out, draining the $200 of XLM first
+    assert_eq!(
+        payouts,
+        vec![&e, (xlm.clone(), 2000_0000000), (usdc.clone(), 275_0000000)]
+    );
+    assert_eq!(TokenClient::new(&e, &token).balance(&user), 500_0000000);
+    assert_eq!(TokenClient::new(&e, &usdc).balance(&user), 275_0000000);
+    assert_eq!(redemption_client.get_fee(), 0_0500000);
+
+    // the base rate decays linearly over the decay period
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 11 * 60 * 60);
+    assert_eq!(redemption_client.get_fee(), 0_0050000 + 0_0208333);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 12 * 60 * 60);
+    assert_eq!(redemption_client.get_fee(), 0_0050000);
+
+    // the prices are now too old to redeem against
+    let result = redemption_client.try_redeem(&user, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(RedemptionError::StalePrice.into())));
+
+    // the remaining $525 of reserves can't cover a larger redemption
+    oracle_client.set_price_stable(&vec![&e, 0_9800000, 0_1000000, 1_0000000]);
+    let result = redemption_client.try_redeem(&user, &600_0000000);
+    assert_eq!(result.err(), Some(Ok(RedemptionError::InsufficientReserves.into())));
+}
+
+#[test]
+fn test_set_config_validates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (redemption_client, _, _, _, _) = create_redemption(&e);
+
+    let config = RedemptionConfig {
+        base_fee: 0_1000000,
+        ..default_config()
+    };
+    let result = redemption_client.try_set_config(&config);
+    assert_eq!(result.err(), Some(Ok(RedemptionError::InvalidConfig.into())));
+
+    let config = RedemptionConfig {
+        max_fee: 0_1000000,
+        ..default_config()
+    };
+    redemption_client.set_config(&config);
+    assert_eq!(redemption_client.get_config().max_fee, 0_1000000);
+}
@@ -0,0 +1,361 @@
+use crate::errors::ReferralError;
+use crate::events::ReferralEvents;
+use crate::storage::{self, RebateIndex, Referred, ReferrerInfo};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, Symbol};
+
+const MAX_BPS: u32 = 10000;
+const SCALAR_12: i128 = 1_000_000_000_000;
+const SECONDS_PER_YEAR: i128 = 31536000;
+
+#[contract]
+pub struct ReferralContract;
+
+#[contractclient(name = "ReferralClient")]
+pub trait Referral {
+    /// Initialize the referral registry. Rebates accrue over time on the referred deposits still
+    /// held, and are paid out of the contract's token balance, which is funded by adding the
+    /// contract to the fee distributor as a `Transfer` destination.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the token rebates are paid in
+    /// * `rebate` - The yearly rebate on referred deposits, in basis points
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the rebate is greater than 100%
+    fn initialize(e: Env, admin: Address, token: Address, rebate: u32);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the yearly rebate on referred deposits. Rebates up to now accrue at the
+    /// old rebate.
+    ///
+    /// ### Arguments
+    /// * `rebate` - The yearly rebate, in basis points
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the rebate is greater than 100%
+    fn set_rebate(e: Env, rebate: u32);
+
+    /// Get the yearly rebate on referred deposits, in basis points
+    fn get_rebate(e: Env) -> u32;
+
+    /// (Admin only) Allow or disallow a contract, like the savings contract, to record
+    /// referred deposits
+    ///
+    /// ### Arguments
+    /// * `reporter` - The Address of the contract
+    /// * `allowed` - Whether the contract can record referred deposits
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_reporter(e: Env, reporter: Address, allowed: bool);
+
+    /// Check if a contract can record referred deposits
+    ///
+    /// ### Arguments
+    /// * `reporter` - The Address of the contract
+    fn is_reporter(e: Env, reporter: Address) -> bool;
+
+    /// Register a referral code
+    ///
+    /// ### Arguments
+    /// * `referrer` - The Address accruing the rebates of the code
+    /// * `code` - The referral code
+    ///
+    /// ### Panics
+    /// If the code is already registered
+    fn register(e: Env, referrer: Address, code: Symbol);
+
+    /// Get the referrer that registered a code
+    ///
+    /// ### Arguments
+    /// * `code` - The referral code
+    ///
+    /// ### Panics
+    /// If the code is not registered
+    fn get_referrer(e: Env, code: Symbol) -> Address;
+
+    /// (Reporter only) Record a deposit carrying a referral code. The referrer of the code accrues
+    /// rebates on the deposit for as long as it is held. A depositor's earlier referred deposits
+    /// with the reporter move to the referrer of the latest code.
+    ///
+    /// ### Arguments
+    /// * `reporter` - The Address of the contract taking the deposit
+    /// * `code` - The referral code
+    /// * `depositor` - The Address depositing
+    /// * `amount` - The amount deposited
+    ///
+    /// ### Panics
+    /// If the caller is not an allowed reporter
+    /// If the code is not registered
+    /// If the depositor is the referrer of the code
+    fn record(e: Env, reporter: Address, code: Symbol, depositor: Address, amount: i128);
+
+    /// (Reporter only) Release a withdrawal from the referred deposits of a depositor, so the
+    /// referrer stops accruing rebates on it
+    ///
+    /// Returns the referred amount released
+    ///
+    /// ### Arguments
+    /// * `reporter` - The Address of the contract paying out the withdrawal
+    /// * `depositor` - The Address withdrawing
+    /// * `amount` - The amount withdrawn
+    ///
+    /// ### Panics
+    /// If the caller is not an allowed reporter
+    fn release(e: Env, reporter: Address, depositor: Address, amount: i128) -> i128;
+
+    /// Get the rebates a referrer has accrued up to now and not claimed
+    ///
+    /// ### Arguments
+    /// * `referrer` - The Address of the referrer
+    fn get_accrued(e: Env, referrer: Address) -> i128;
+
+    /// Claim accrued rebates, up to the rebates the fee distributor has paid in so far. The
+    /// rest stays accrued.
+    ///
+    /// Returns the amount claimed
+    ///
+    /// ### Arguments
+    /// * `referrer` - The Address of the referrer
+    fn claim(e: Env, referrer: Address) -> i128;
+}
+
+#[contractimpl]
+impl Referral for ReferralContract {
+    fn initialize(e: Env, admin: Address, token: Address, rebate: u32) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, ReferralError::AlreadyInitializedError);
+        }
+        require_valid_rebate(&e, rebate);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_rebate(&e, rebate);
+        storage::set_rebate_index(
+            &e,
+            &RebateIndex {
+                index: 0,
+                last_update: e.ledger().timestamp(),
+            },
+        );
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_rebate(e: Env, rebate: u32) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_rebate(&e, rebate);
+
+        accrue_index(&e);
+        storage::set_rebate(&e, rebate);
+
+        ReferralEvents::set_rebate(&e, rebate);
+    }
+
+    fn get_rebate(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_rebate(&e)
+    }
+
+    fn set_reporter(e: Env, reporter: Address, allowed: bool) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_reporter(&e, &reporter, allowed);
+
+        ReferralEvents::set_reporter(&e, reporter, allowed);
+    }
+
+    fn is_reporter(e: Env, reporter: Address) -> bool {
+        storage::extend_instance(&e);
+        storage::is_reporter(&e, &reporter)
+    }
+
+    fn register(e: Env, referrer: Address, code: Symbol) {
+        storage::extend_instance(&e);
+        referrer.require_auth();
+        if storage::get_referrer(&e, &code).is_some() {
+            panic_with_error!(&e, ReferralError::CodeTaken);
+        }
+
+        storage::set_referrer(&e, &code, &referrer);
+
+        ReferralEvents::register(&e, code, referrer);
+    }
+
+    fn get_referrer(e: Env, code: Symbol) -> Address {
+        storage::extend_instance(&e);
+        load_referrer(&e, &code)
+    }
+
+    fn record(e: Env, reporter: Address, code: Symbol, depositor: Address, amount: i128) {
+        storage::extend_instance(&e);
+        require_reporter(&e, &reporter);
+        if amount <= 0 {
+            panic_with_error!(&e, ReferralError::NegativeAmountError);
+        }
+        let referrer = load_referrer(&e, &code);
+        if referrer == depositor {
+            panic_with_error!(&e, ReferralError::SelfReferral);
+        }
+
+        let index = accrue_index(&e);
+        let mut referred = storage::get_referred(&e, &reporter, &depositor).unwrap_or(Referred {
+            referrer: referrer.clone(),
+            amount: 0,
+        });
+        if referred.referrer != referrer {
+            update_referred(&e, index, &referred.referrer, -referred.amount);
+            update_referred(&e, index, &referrer, referred.amount);
+            referred.referrer = referrer.clone();
+        }
+        referred.amount += amount;
+        update_referred(&e, index, &referrer, amount);
+        storage::set_referred(&e, &reporter, &depositor, &referred);
+
+        ReferralEvents::record(&e, code, reporter, depositor, amount);
+    }
+
+    fn release(e: Env, reporter: Address, depositor: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        require_reporter(&e, &reporter);
+        if amount <= 0 {
+            panic_with_error!(&e, ReferralError::NegativeAmountError);
+        }
+
+        let mut referred = match storage::get_referred(&e, &reporter, &depositor) {
+            Some(referred) => referred,
+            None => return 0,
+        };
+        let released = amount.min(referred.amount);
+        let index = accrue_index(&e);
+        update_referred(&e, index, &referred.referrer, -released);
+        referred.amount -= released;
+        storage::set_referred(&e, &reporter, &depositor, &referred);
+
+        ReferralEvents::release(&e, reporter, depositor, released);
+        released
+    }
+
+    fn get_accrued(e: Env, referrer: Address) -> i128 {
+        storage::extend_instance(&e);
+        let mut info = storage::get_referrer_info(&e, &referrer);
+        accrue_referrer(&e, load_index(&e), &mut info);
+        info.accrued
+    }
+
+    fn claim(e: Env, referrer: Address) -> i128 {
+        storage::extend_instance(&e);
+        referrer.require_auth();
+
+        let index = accrue_index(&e);
+        let mut info = storage::get_referrer_info(&e, &referrer);
+        accrue_referrer(&e, index, &mut info);
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        let amount = info.accrued.min(token_client.balance(&e.current_contract_address()));
+        info.accrued -= amount;
+        storage::set_referrer_info(&e, &referrer, &info);
+        if amount > 0 {
+            token_client.transfer(&e.current_contract_address(), &referrer, &amount);
+        }
+
+        ReferralEvents::claim(&e, referrer, amount);
+        amount
+    }
+}
+
+/// Load the rebate index up to now, without storing it
+fn load_index(e: &Env) -> i128 {
+    let rebate_index = storage::get_rebate_index(e);
+    let elapsed = (e.ledger().timestamp() - rebate_index.last_update) as i128;
+    let rebate = storage::get_rebate(e) as i128;
+    rebate_index.index
+        + math::mul_div_floor(e, rebate * elapsed, SCALAR_12, MAX_BPS as i128 * SECONDS_PER_YEAR)
+}
+
+/// Accrue the rebate index up to now and store it
+///
+/// Returns the rebate index, with 12 decimals
+fn accrue_index(e: &Env) -> i128 {
+    let index = load_index(e);
+    storage::set_rebate_index(
+        e,
+        &RebateIndex {
+            index,
+            last_update: e.ledger().timestamp(),
+        },
+    );
+    index
+}
+
+/// Accrue the rebates of a referrer up to a rebate index
+fn accrue_referrer(e: &Env, index: i128, info: &mut ReferrerInfo) {
+    info.accrued += math::mul_div_floor(e, info.referred, index - info.index, SCALAR_12);
+    info.index = index;
+}
+
+/// Accrue the rebates of a referrer and change the referred deposits it accrues on
+///
+/// ### Arguments
+/// * `index` - The rebate index now
+/// * `referrer` - The Address of the referrer
+/// * `change` - The change in referred deposits
+fn update_referred(e: &Env, index: i128, referrer: &Address, change: i128) {
+    let mut info = storage::get_referrer_info(e, referrer);
+    accrue_referrer(e, index, &mut info);
+    info.referred += change;
+    storage::set_referrer_info(e, referrer, &info);
+}
+
+/// Require that an address is an allowed reporter and authorized the call
+///
+/// ### Panics
+/// If the reporter did not authorize the call or is not allowed
+fn require_reporter(e: &Env, reporter: &Address) {
+    reporter.require_auth();
+    if !storage::is_reporter(e, reporter) {
+        panic_with_error!(e, ReferralError::UnauthorizedError);
+    }
+}
+
+/// Load the referrer that registered a code
+///
+/// ### Panics
+/// If the code is not registered
+fn load_referrer(e: &Env, code: &Symbol) -> Address {
+    storage::get_referrer(e, code)
+        .unwrap_or_else(|| panic_with_error!(e, ReferralError::CodeNotFound))
+}
+
+/// Require that a rebate is at most 100%
+///
+/// ### Panics
+/// If the rebate is greater than 100%
+fn require_valid_rebate(e: &Env, rebate: u32) {
+    if rebate > MAX_BPS {
+        panic_with_error!(e, ReferralError::InvalidRebate);
+    }
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the referral contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Referral specific errors start at 4400.
+pub enum ReferralError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Referral
+    CodeTaken = 4400,
+    CodeNotFound = 4401,
+    InvalidRebate = 4402,
+    SelfReferral = 4403,
+}
@@ -0,0 +1,60 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct ReferralEvents {}
+
+impl ReferralEvents {
+    /// Emitted when the admin updates the rebate
+    ///
+    /// - topics - `["set_rebate"]`
+    /// - data - `rebate: u32`
+    pub fn set_rebate(e: &Env, rebate: u32) {
+        let topics = (Symbol::new(e, topics::SET_REBATE),);
+        e.events().publish(topics, rebate);
+    }
+
+    /// Emitted when the admin allows or disallows a reporter
+    ///
+    /// - topics - `["set_reporter", reporter: Address]`
+    /// - data - `allowed: bool`
+    pub fn set_reporter(e: &Env, reporter: Address, allowed: bool) {
+        let topics = (Symbol::new(e, topics::SET_REPORTER), reporter);
+        e.events().publish(topics, allowed);
+    }
+
+    /// Emitted when an integrator registers a referral code
+    ///
+    /// - topics - `["register", code: Symbol]`
+    /// - data - `referrer: Address`
+    pub fn register(e: &Env, code: Symbol, referrer: Address) {
+        let topics = (Symbol::new(e, topics::REGISTER), code);
+        e.events().publish(topics, referrer);
+    }
+
+    /// Emitted when a reporter records a referred deposit
+    ///
+    /// - topics - `["record", code: Symbol, reporter: Address]`
+    /// - data - `[depositor: Address, amount: i128]`
+    pub fn record(e: &Env, code: Symbol, reporter: Address, depositor: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::RECORD), code, reporter);
+        e.events().publish(topics, (depositor, amount));
+    }
+
+    /// Emitted when a reporter releases a referred deposit that was withdrawn
+    ///
+    /// - topics - `["release", reporter: Address, depositor: Address]`
+    /// - data - `amount: i128`
+    pub fn release(e: &Env, reporter: Address, depositor: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::RELEASE), reporter, depositor);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a referrer claims rebates
+    ///
+    /// - topics - `["claim", referrer: Address]`
+    /// - data - `amount: i128`
+    pub fn claim(e: &Env, referrer: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::CLAIM), referrer);
+        e.events().publish(topics, amount);
+    }
+}
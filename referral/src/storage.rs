@@ -0,0 +1,280 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const REBATE_KEY: &str = "Rebate";
+const REBATE_INDEX_KEY: &str = "RebateIndex";
+
+/// The rebates accrued per referred token since the registry was created
+#[derive(Clone)]
+#[contracttype]
+pub struct RebateIndex {
+    pub index: i128,      // the rebates accrued per referred token, with 12 decimals
+    pub last_update: u64, // the time `index` was last updated
+}
+
+/// The rebates of a referrer
+#[derive(Clone)]
+#[contracttype]
+pub struct ReferrerInfo {
+    pub referred: i128, // the referred deposits still held
+    pub index: i128,    // the rebate index the referrer last accrued at
+    pub accrued: i128,  // the rebates accrued and not yet claimed
+}
+
+/// A deposit referred to a referrer, held with a reporter
+#[derive(Clone)]
+#[contracttype]
+pub struct Referred {
+    pub referrer: Address, // the referrer the deposit was referred by
+    pub amount: i128,      // the referred amount still held
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ReferralDataKey {
+    // The referrer that registered a code
+    Code(Symbol),
+    // Whether a contract can record referred deposits
+    Reporter(Address),
+    // The rebates of a referrer
+    Referrer(Address),
+    // The deposit of a depositor with a reporter, referred by a referrer
+    Referred(Address, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Settings **********/
+
+/// Fetch the Address of the token rebates are paid in
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token rebates are paid in
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the yearly rebate on referred deposits, in basis points
+///
+/// ### Panics
+/// If the rebate does not exist
+pub fn get_rebate(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REBATE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the yearly rebate on referred deposits
+///
+/// ### Arguments
+/// * `rebate` - The yearly rebate, in basis points
+pub fn set_rebate(e: &Env, rebate: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, REBATE_KEY), &rebate);
+}
+
+/// Fetch the rebate index
+///
+/// ### Panics
+/// If the rebate index does not exist
+pub fn get_rebate_index(e: &Env) -> RebateIndex {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REBATE_INDEX_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the rebate index
+///
+/// ### Arguments
+/// * `rebate_index` - The rebate index
+pub fn set_rebate_index(e: &Env, rebate_index: &RebateIndex) {
+    e.storage()
+        .instance()
+        .set::<Symbol, RebateIndex>(&Symbol::new(e, REBATE_INDEX_KEY), rebate_index);
+}
+
+/********** Reporters **********/
+
+/// Check if a contract can record referred deposits
+///
+/// ### Arguments
+/// * `reporter` - The Address of the contract
+pub fn is_reporter(e: &Env, reporter: &Address) -> bool {
+    let key = ReferralDataKey::Reporter(reporter.clone());
+    let result = e.storage().persistent().get::<ReferralDataKey, bool>(&key);
+    match result {
+        Some(allowed) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            allowed
+        }
+        None => false,
+    }
+}
+
+/// Set whether a contract can record referred deposits
+///
+/// ### Arguments
+/// * `reporter` - The Address of the contract
+/// * `allowed` - Whether the contract can record referred deposits
+pub fn set_reporter(e: &Env, reporter: &Address, allowed: bool) {
+    let key = ReferralDataKey::Reporter(reporter.clone());
+    if allowed {
+        e.storage()
+            .persistent()
+            .set::<ReferralDataKey, bool>(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/********** Referrers **********/
+
+/// Fetch the referrer that registered a code, if any
+///
+/// ### Arguments
+/// * `code` - The referral code
+pub fn get_referrer(e: &Env, code: &Symbol) -> Option<Address> {
+    let key = ReferralDataKey::Code(code.clone());
+    let result = e.storage().persistent().get::<ReferralDataKey, Address>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set the referrer that registered a code
+///
+/// ### Arguments
+/// * `code` - The referral code
+/// * `referrer` - The Address of the referrer
+pub fn set_referrer(e: &Env, code: &Symbol, referrer: &Address) {
+    let key = ReferralDataKey::Code(code.clone());
+    e.storage()
+        .persistent()
+        .set::<ReferralDataKey, Address>(&key, referrer);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the rebates of a referrer, or an empty position if it has none
+///
+/// ### Arguments
+/// * `referrer` - The Address of the referrer
+pub fn get_referrer_info(e: &Env, referrer: &Address) -> ReferrerInfo {
+    let key = ReferralDataKey::Referrer(referrer.clone());
+    let result = e.storage().persistent().get::<ReferralDataKey, ReferrerInfo>(&key);
+    match result {
+        Some(info) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            info
+        }
+        None => ReferrerInfo {
+            referred: 0,
+            index: 0,
+            accrued: 0,
+        },
+    }
+}
+
+/// Set the rebates of a referrer
+///
+/// ### Arguments
+/// * `referrer` - The Address of the referrer
+/// * `info` - The rebates of the referrer
+pub fn set_referrer_info(e: &Env, referrer: &Address, info: &ReferrerInfo) {
+    let key = ReferralDataKey::Referrer(referrer.clone());
+    e.storage()
+        .persistent()
+        .set::<ReferralDataKey, ReferrerInfo>(&key, info);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/********** Referred Deposits **********/
+
+/// Fetch the referred deposit of a depositor with a reporter, if any
+///
+/// ### Arguments
+/// * `reporter` - The Address of the contract holding the deposit
+/// * `depositor` - The Address of the depositor
+pub fn get_referred(e: &Env, reporter: &Address, depositor: &Address) -> Option<Referred> {
+    let key = ReferralDataKey::Referred(reporter.clone(), depositor.clone());
+    let result = e.storage().persistent().get::<ReferralDataKey, Referred>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set the referred deposit of a depositor with a reporter, removing it once nothing is held
+///
+/// ### Arguments
+/// * `reporter` - The Address of the contract holding the deposit
+/// * `depositor` - The Address of the depositor
+/// * `referred` - The referred deposit
+pub fn set_referred(e: &Env, reporter: &Address, depositor: &Address, referred: &Referred) {
+    let key = ReferralDataKey::Referred(reporter.clone(), depositor.clone());
+    if referred.amount > 0 {
+        e.storage()
+            .persistent()
+            .set::<ReferralDataKey, Referred>(&key, referred);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{ReferralClient, ReferralContract};
+use crate::errors::ReferralError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, Symbol};
+
+const YEAR: u64 = 31536000;
+
+#[test]
+fn test_register_record_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let referral_id = e.register_contract(None, ReferralContract);
+    let referral_client = ReferralClient::new(&e, &referral_id);
+    referral_client.initialize(&Address::generate(&e), &ousd, &10);
+    let result = referral_client.try_set_rebate(&10001);
+    assert_eq!(result.err(), Some(Ok(ReferralError::InvalidRebate.into())));
+
+    let wallet = Address::generate(&e);
+    let code = Symbol::new(&e, "wallet");
+    referral_client.register(&wallet, &code);
+    let result = referral_client.try_register(&Address::generate(&e), &code);
+    assert_eq!(result.err(), Some(Ok(ReferralError::CodeTaken.into())));
+    assert_eq!(referral_client.get_referrer(&code), wallet);
+
+    let savings = Address::generate(&e);
+    let depositor = Address::generate(&e);
+    let result = referral_client.try_record(&savings, &code, &depositor, &1000_0000000);
+    assert_eq!(result.err(), Some(Ok(ReferralError::UnauthorizedError.into())));
+    referral_client.set_reporter(&savings, &true);
+    let result = referral_client.try_record(&savings, &Symbol::new(&e, "none"), &depositor, &1);
+    assert_eq!(result.err(), Some(Ok(ReferralError::CodeNotFound.into())));
+    let result = referral_client.try_record(&savings, &code, &wallet, &1);
+    assert_eq!(result.err(), Some(Ok(ReferralError::SelfReferral.into())));
+
+    // rebates accrue on the referred deposits still held, 0.1% a year
+    referral_client.record(&savings, &code, &depositor, &1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    assert_eq!(referral_client.get_accrued(&wallet), 1_0000000);
+    assert_eq!(referral_client.release(&savings, &depositor, &600_0000000), 600_0000000);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    assert_eq!(referral_client.get_accrued(&wallet), 1_4000000);
+
+    // a deposit withdrawn straight away accrues nothing
+    referral_client.record(&savings, &code, &depositor, &100000_0000000);
+    assert_eq!(referral_client.release(&savings, &depositor, &200000_0000000), 100400_0000000);
+    assert_eq!(referral_client.release(&savings, &depositor, &1), 0);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    assert_eq!(referral_client.get_accrued(&wallet), 1_4000000);
+
+    // claims are limited to the rebates paid in by the fee distributor
+    StellarAssetClient::new(&e, &ousd).mint(&referral_id, &1_0000000);
+    assert_eq!(referral_client.claim(&wallet), 1_0000000);
+    assert_eq!(referral_client.get_accrued(&wallet), 0_4000000);
+    StellarAssetClient::new(&e, &ousd).mint(&referral_id, &5_0000000);
+    assert_eq!(referral_client.claim(&wallet), 0_4000000);
+    assert_eq!(TokenClient::new(&e, &ousd).balance(&wallet), 1_4000000);
+}
+
+#[test]
+fn test_record_moves_to_latest_referrer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let referral_id = e.register_contract(None, ReferralContract);
+    let referral_client = ReferralClient::new(&e, &referral_id);
+    referral_client.initialize(&Address::generate(&e), &ousd, &100);
+    let savings = Address::generate(&e);
+    referral_client.set_reporter(&savings, &true);
+    let wallet = Address::generate(&e);
+    let exchange = Address::generate(&e);
+    referral_client.register(&wallet, &Symbol::new(&e, "wallet"));
+    referral_client.register(&exchange, &Symbol::new(&e, "exchange"));
+    let depositor = Address::generate(&e);
+
+    referral_client.record(&savings, &Symbol::new(&e, "wallet"), &depositor, &1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    referral_client.record(&savings, &Symbol::new(&e, "exchange"), &depositor, &1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+
+    // the wallet keeps what it accrued, and the exchange accrues on the whole deposit since
+    assert_eq!(referral_client.get_accrued(&wallet), 10_0000000);
+    assert_eq!(referral_client.get_accrued(&exchange), 20_0000000);
+    referral_client.set_rebate(&0);
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    assert_eq!(referral_client.get_accrued(&exchange), 20_0000000);
+}
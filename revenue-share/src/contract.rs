@@ -0,0 +1,337 @@
+use crate::dependencies::fee_distributor::FeeDistributorClient;
+use crate::errors::RevenueShareError;
+use crate::events::RevenueShareEvents;
+use crate::storage::{self, Allowance};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::{Token, TokenClient};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, String};
+
+#[contract]
+pub struct RevenueShareContract;
+
+#[contractclient(name = "RevenueShareClient")]
+pub trait RevenueShare {
+    /// Initialize the revenue share. The contract is a SEP-41 token whose shares are claims on
+    /// its balance of a revenue token, and should be added to the fee distributor as a
+    /// `Transfer` destination.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `asset` - The Address of the revenue token backing the shares
+    /// * `fee_distributor` - The Address of the fee distributor paying the revenue
+    /// * `name` - The name of the share token
+    /// * `symbol` - The symbol of the share token
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(
+        e: Env,
+        admin: Address,
+        asset: Address,
+        fee_distributor: Address,
+        name: String,
+        symbol: String,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// Get the revenue token backing the shares
+    fn get_asset(e: Env) -> Address;
+
+    /// Get the total shares outstanding
+    fn total_supply(e: Env) -> i128;
+
+    /// Get the revenue backing the shares. This is the contract's balance of the revenue token
+    /// plus its share of the revenue accrued in the fee distributor but not yet distributed, so
+    /// deposits and redemptions can't front run a distribution.
+    fn total_assets(e: Env) -> i128;
+
+    /// Deposit revenue tokens for shares
+    ///
+    /// Returns the shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `amount` - The revenue tokens deposited
+    ///
+    /// ### Panics
+    /// If `amount` is zero or negative
+    /// If the deposit is too small to mint a share
+    fn deposit(e: Env, from: Address, amount: i128) -> i128;
+
+    /// Redeem shares for revenue tokens
+    ///
+    /// Returns the revenue tokens paid out
+    ///
+    /// ### Arguments
+    /// * `from` - The Address redeeming
+    /// * `shares` - The shares redeemed
+    ///
+    /// ### Panics
+    /// If `shares` is zero or negative, or exceeds the shares of `from`
+    /// If the contract holds too few revenue tokens until the fee distributor pays out
+    fn redeem(e: Env, from: Address, shares: i128) -> i128;
+}
+
+#[contractimpl]
+impl RevenueShare for RevenueShareContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        asset: Address,
+        fee_distributor: Address,
+        name: String,
+        symbol: String,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, RevenueShareError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_asset(&e, &asset);
+        storage::set_fee_distributor(&e, &fee_distributor);
+        storage::set_name(&e, &name);
+        storage::set_symbol(&e, &symbol);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn get_asset(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_asset(&e)
+    }
+
+    fn total_supply(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_total_supply(&e)
+    }
+
+    fn total_assets(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        calc_total_assets(&e)
+    }
+
+    fn deposit(e: Env, from: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        let total_supply = storage::get_total_supply(&e);
+        let shares = if total_supply == 0 {
+            amount
+        } else {
+            amount * total_supply / calc_total_assets(&e)
+        };
+        if shares <= 0 {
+            panic_with_error!(&e, RevenueShareError::ZeroShares);
+        }
+
+        TokenClient::new(&e, &storage::get_asset(&e)).transfer(
+            &from,
+            &e.current_contract_address(),
+            &amount,
+        );
+        mint_shares(&e, &from, shares);
+
+        RevenueShareEvents::deposit(&e, from, amount, shares);
+        shares
+    }
+
+    fn redeem(e: Env, from: Address, shares: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, shares);
+
+        let total_supply = storage::get_total_supply(&e);
+        burn_shares(&e, &from, shares);
+        let amount = shares * calc_total_assets(&e) / total_supply;
+        let asset_client = TokenClient::new(&e, &storage::get_asset(&e));
+        if asset_client.balance(&e.current_contract_address()) < amount {
+            panic_with_error!(&e, RevenueShareError::InsufficientLiquidity);
+        }
+        asset_client.transfer(&e.current_contract_address(), &from, &amount);
+
+        RevenueShareEvents::redeem(&e, from, amount, shares);
+        amount
+    }
+}
+
+#[contractimpl]
+impl Token for RevenueShareContract {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_allowance(&e, &from, &spender).amount
+    }
+
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        if amount < 0 {
+            panic_with_error!(&e, RevenueShareError::NegativeAmountError);
+        }
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            panic_with_error!(&e, RevenueShareError::InvalidExpiration);
+        }
+
+        let allowance = Allowance {
+            amount,
+            expiration_ledger,
+        };
+        storage::set_allowance(&e, &from, &spender, &allowance);
+
+        RevenueShareEvents::approve(&e, from, spender, amount, expiration_ledger);
+    }
+
+    fn balance(e: Env, id: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_balance(&e, &id)
+    }
+
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        move_shares(&e, &from, &to, amount);
+    }
+
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        require_positive(&e, amount);
+
+        spend_allowance(&e, &from, &spender, amount);
+        move_shares(&e, &from, &to, amount);
+    }
+
+    fn burn(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        burn_shares(&e, &from, amount);
+    }
+
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        require_positive(&e, amount);
+
+        spend_allowance(&e, &from, &spender, amount);
+        burn_shares(&e, &from, amount);
+    }
+
+    fn decimals(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        TokenClient::new(&e, &storage::get_asset(&e)).decimals()
+    }
+
+    fn name(e: Env) -> String {
+        storage::extend_instance(&e);
+        storage::get_name(&e)
+    }
+
+    fn symbol(e: Env) -> String {
+        storage::extend_instance(&e);
+        storage::get_symbol(&e)
+    }
+}
+
+/// Calculate the revenue backing the shares, including the contract's share of the revenue
+/// the fee distributor has not distributed yet
+fn calc_total_assets(e: &Env) -> i128 {
+    let asset_client = TokenClient::new(e, &storage::get_asset(e));
+    let held = asset_client.balance(&e.current_contract_address());
+
+    let fee_distributor = storage::get_fee_distributor(e);
+    let destinations = FeeDistributorClient::new(e, &fee_distributor).get_destinations();
+    let mut weight: i128 = 0;
+    let mut total_weight: i128 = 0;
+    for destination in destinations.iter() {
+        if destination.address == e.current_contract_address() {
+            weight += destination.weight as i128;
+        }
+        total_weight += destination.weight as i128;
+    }
+    if weight == 0 {
+        return held;
+    }
+    held + math::mul_div_floor(e, asset_client.balance(&fee_distributor), weight, total_weight)
+}
+
+/// Mint shares to an address
+fn mint_shares(e: &Env, to: &Address, amount: i128) {
+    storage::set_balance(e, to, storage::get_balance(e, to) + amount);
+    storage::set_total_supply(e, storage::get_total_supply(e) + amount);
+
+    RevenueShareEvents::mint(e, e.current_contract_address(), to.clone(), amount);
+}
+
+/// Burn shares from an address
+///
+/// ### Panics
+/// If the address holds less than `amount` shares
+fn burn_shares(e: &Env, from: &Address, amount: i128) {
+    let balance = storage::get_balance(e, from);
+    if balance < amount {
+        panic_with_error!(e, RevenueShareError::BalanceError);
+    }
+    storage::set_balance(e, from, balance - amount);
+    storage::set_total_supply(e, storage::get_total_supply(e) - amount);
+
+    RevenueShareEvents::burn(e, from.clone(), amount);
+}
+
+/// Move shares between addresses
+///
+/// ### Panics
+/// If `from` holds less than `amount` shares
+fn move_shares(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let balance = storage::get_balance(e, from);
+    if balance < amount {
+        panic_with_error!(e, RevenueShareError::BalanceError);
+    }
+    storage::set_balance(e, from, balance - amount);
+    storage::set_balance(e, to, storage::get_balance(e, to) + amount);
+
+    RevenueShareEvents::transfer(e, from.clone(), to.clone(), amount);
+}
+
+/// Spend part of a spender's allowance over a holder's shares
+///
+/// ### Panics
+/// If the allowance is less than `amount`
+fn spend_allowance(e: &Env, from: &Address, spender: &Address, amount: i128) {
+    let mut allowance = storage::get_allowance(e, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(e, RevenueShareError::AllowanceError);
+    }
+    allowance.amount -= amount;
+    storage::set_allowance(e, from, spender, &allowance);
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, RevenueShareError::NegativeAmountError);
+    }
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// How revenue is delivered to a destination, mirroring the fee distributor
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum DestinationKind {
+    Transfer,
+    Staking,
+    Insurance,
+}
+
+/// A destination of protocol revenue, mirroring the fee distributor
+#[derive(Clone)]
+#[contracttype]
+pub struct Destination {
+    pub address: Address,
+    pub kind: DestinationKind,
+    pub weight: u32,
+}
+
+/// The part of the fee distributor the revenue share uses to value undistributed revenue
+#[allow(dead_code)]
+#[contractclient(name = "FeeDistributorClient")]
+pub trait FeeDistributor {
+    fn get_destinations(e: Env) -> Vec<Destination>;
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the revenue share contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Revenue share specific errors start at 4300.
+pub enum RevenueShareError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+    AllowanceError = 9,
+    BalanceError = 10,
+
+    // Revenue Share
+    ZeroShares = 4300,
+    InsufficientLiquidity = 4301,
+    InvalidExpiration = 4302,
+}
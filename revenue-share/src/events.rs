@@ -0,0 +1,60 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct RevenueShareEvents {}
+
+impl RevenueShareEvents {
+    /// Emitted when revenue is deposited for shares
+    ///
+    /// - topics - `["deposit", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn deposit(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), from);
+        e.events().publish(topics, (amount, shares));
+    }
+
+    /// Emitted when shares are redeemed for revenue
+    ///
+    /// - topics - `["redeem", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn redeem(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::REDEEM), from);
+        e.events().publish(topics, (amount, shares));
+    }
+
+    /// Emitted when an allowance is set
+    ///
+    /// - topics - `["approve", from: Address, spender: Address]`
+    /// - data - `[amount: i128, expiration_ledger: u32]`
+    pub fn approve(e: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        let topics = (Symbol::new(e, topics::APPROVE), from, spender);
+        e.events().publish(topics, (amount, expiration_ledger));
+    }
+
+    /// Emitted when shares are transferred
+    ///
+    /// - topics - `["transfer", from: Address, to: Address]`
+    /// - data - `amount: i128`
+    pub fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::TRANSFER), from, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when shares are minted
+    ///
+    /// - topics - `["mint", admin: Address, to: Address]`
+    /// - data - `amount: i128`
+    pub fn mint(e: &Env, admin: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::MINT), admin, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when shares are burned
+    ///
+    /// - topics - `["burn", from: Address]`
+    /// - data - `amount: i128`
+    pub fn burn(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::BURN), from);
+        e.events().publish(topics, amount);
+    }
+}
@@ -0,0 +1,226 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, String, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ASSET_KEY: &str = "Asset";
+const FEE_DISTRIBUTOR_KEY: &str = "FeeDistributor";
+const NAME_KEY: &str = "Name";
+const SYMBOL_KEY: &str = "Symbol";
+const TOTAL_SUPPLY_KEY: &str = "TotalSupply";
+
+/// An allowance of shares a spender can transfer on behalf of a holder
+#[derive(Clone)]
+#[contracttype]
+pub struct Allowance {
+    pub amount: i128,
+    pub expiration_ledger: u32, // the last ledger the allowance can be spent in
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum RevenueShareDataKey {
+    // The shares held by an address
+    Balance(Address),
+    // The allowance of a spender over a holder's shares
+    Allowance(Address, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the revenue token backing the shares
+///
+/// ### Panics
+/// If the asset does not exist
+pub fn get_asset(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ASSET_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the revenue token backing the shares
+///
+/// ### Arguments
+/// * `asset` - The Address for the revenue token
+pub fn set_asset(e: &Env, asset: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ASSET_KEY), asset);
+}
+
+/// Fetch the Address of the fee distributor
+///
+/// ### Panics
+/// If the fee distributor does not exist
+pub fn get_fee_distributor(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, FEE_DISTRIBUTOR_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the fee distributor
+///
+/// ### Arguments
+/// * `fee_distributor` - The Address for the fee distributor
+pub fn set_fee_distributor(e: &Env, fee_distributor: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, FEE_DISTRIBUTOR_KEY), fee_distributor);
+}
+
+/********** Metadata **********/
+
+/// Fetch the name of the share token
+///
+/// ### Panics
+/// If the name does not exist
+pub fn get_name(e: &Env) -> String {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, NAME_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the name of the share token
+///
+/// ### Arguments
+/// * `name` - The name
+pub fn set_name(e: &Env, name: &String) {
+    e.storage()
+        .instance()
+        .set::<Symbol, String>(&Symbol::new(e, NAME_KEY), name);
+}
+
+/// Fetch the symbol of the share token
+///
+/// ### Panics
+/// If the symbol does not exist
+pub fn get_symbol(e: &Env) -> String {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SYMBOL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the symbol of the share token
+///
+/// ### Arguments
+/// * `symbol` - The symbol
+pub fn set_symbol(e: &Env, symbol: &String) {
+    e.storage()
+        .instance()
+        .set::<Symbol, String>(&Symbol::new(e, SYMBOL_KEY), symbol);
+}
+
+/********** Shares **********/
+
+/// Fetch the total shares outstanding
+pub fn get_total_supply(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_SUPPLY_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the total shares outstanding
+///
+/// ### Arguments
+/// * `amount` - The total shares
+pub fn set_total_supply(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, TOTAL_SUPPLY_KEY), &amount);
+}
+
+/// Fetch the shares held by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the holder
+pub fn get_balance(e: &Env, user: &Address) -> i128 {
+    let key = RevenueShareDataKey::Balance(user.clone());
+    let result = e.storage().persistent().get::<RevenueShareDataKey, i128>(&key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Set the shares held by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the holder
+/// * `amount` - The shares held
+pub fn set_balance(e: &Env, user: &Address, amount: i128) {
+    let key = RevenueShareDataKey::Balance(user.clone());
+    e.storage()
+        .persistent()
+        .set::<RevenueShareDataKey, i128>(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the allowance of a spender over a holder's shares. Expired allowances are zero.
+///
+/// ### Arguments
+/// * `from` - The Address of the holder
+/// * `spender` - The Address of the spender
+pub fn get_allowance(e: &Env, from: &Address, spender: &Address) -> Allowance {
+    let key = RevenueShareDataKey::Allowance(from.clone(), spender.clone());
+    let result = e.storage().temporary().get::<RevenueShareDataKey, Allowance>(&key);
+    match result {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance,
+        _ => Allowance {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+/// Set the allowance of a spender over a holder's shares. The allowance is kept in temporary
+/// storage until it expires.
+///
+/// ### Arguments
+/// * `from` - The Address of the holder
+/// * `spender` - The Address of the spender
+/// * `allowance` - The allowance
+pub fn set_allowance(e: &Env, from: &Address, spender: &Address, allowance: &Allowance) {
+    let key = RevenueShareDataKey::Allowance(from.clone(), spender.clone());
+    e.storage()
+        .temporary()
+        .set::<RevenueShareDataKey, Allowance>(&key, allowance);
+    if allowance.amount > 0 {
+        let live_for = allowance.expiration_ledger - e.ledger().sequence();
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+use crate::contract::{RevenueShareClient, RevenueShareContract};
+use crate::dependencies::fee_distributor::{Destination, DestinationKind};
+use crate::errors::RevenueShareError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Symbol, Vec};
+
+/// Holds undistributed revenue for a set of destinations, like the fee distributor
+#[contract]
+pub struct MockFeeDistributor;
+
+#[contractimpl]
+impl MockFeeDistributor {
+    pub fn set_destinations(e: Env, destinations: Vec<Destination>) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "destinations"), &destinations);
+    }
+
+    pub fn get_destinations(e: Env) -> Vec<Destination> {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "destinations"))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_deposit_and_redeem() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let fee_distributor = e.register_contract(None, MockFeeDistributor);
+    let share_id = e.register_contract(None, RevenueShareContract);
+    let share_client = RevenueShareClient::new(&e, &share_id);
+    let share_token_client = TokenClient::new(&e, &share_id);
+    let stakers = Address::generate(&e);
+    MockFeeDistributorClient::new(&e, &fee_distributor).set_destinations(&vec![
+        &e,
+        Destination {
+            address: share_id.clone(),
+            kind: DestinationKind::Transfer,
+            weight: 1,
+        },
+        Destination {
+            address: stakers.clone(),
+            kind: DestinationKind::Transfer,
+            weight: 2,
+        },
+    ]);
+    share_client.initialize(
+        &Address::generate(&e),
+        &ousd,
+        &fee_distributor,
+        &String::from_str(&e, "Orbit Revenue Share"),
+        &String::from_str(&e, "ORS"),
+    );
+    assert_eq!(share_token_client.decimals(), 7);
+    assert_eq!(share_token_client.symbol(), String::from_str(&e, "ORS"));
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&alice, &1000_0000000);
+    StellarAssetClient::new(&e, &ousd).mint(&bob, &1100_0000000);
+    assert_eq!(share_client.deposit(&alice, &1000_0000000), 1000_0000000);
+
+    // a third of the revenue accrued in the fee distributor backs the shares
+    StellarAssetClient::new(&e, &ousd).mint(&fee_distributor, &300_0000000);
+    assert_eq!(share_client.total_assets(), 1100_0000000);
+    assert_eq!(share_client.deposit(&bob, &1100_0000000), 1000_0000000);
+    assert_eq!(share_client.total_supply(), 2000_0000000);
+
+    // shares are a SEP-41 token
+    let spender = Address::generate(&e);
+    let expiration = e.ledger().sequence() + 100;
+    share_token_client.approve(&alice, &spender, &600_0000000, &expiration);
+    share_token_client.transfer_from(&spender, &alice, &bob, &500_0000000);
+    assert_eq!(share_token_client.allowance(&alice, &spender), 100_0000000);
+    let result = share_token_client.try_transfer_from(&spender, &alice, &bob, &500_0000000);
+    assert_eq!(result.err(), Some(Ok(RevenueShareError::AllowanceError.into())));
+    e.ledger().with_mut(|li| li.sequence_number = expiration + 1);
+    assert_eq!(share_token_client.allowance(&alice, &spender), 0);
+    share_token_client.transfer(&alice, &bob, &500_0000000);
+    assert_eq!(share_token_client.balance(&bob), 2000_0000000);
+
+    // redemptions are limited to the revenue paid out so far
+    let result = share_client.try_redeem(&bob, &2000_0000000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(RevenueShareError::InsufficientLiquidity.into()))
+    );
+    ousd_client.transfer(&fee_distributor, &share_id, &100_0000000);
+    ousd_client.transfer(&fee_distributor, &stakers, &200_0000000);
+    assert_eq!(share_client.total_assets(), 2200_0000000);
+    assert_eq!(share_client.redeem(&bob, &2000_0000000), 2200_0000000);
+    assert_eq!(ousd_client.balance(&bob), 2200_0000000);
+    assert_eq!(share_client.total_supply(), 0);
+}
@@ -0,0 +1,151 @@
+use crate::dependencies::pool::{Client as PoolClient, Positions, Request, RequestType};
+use crate::errors::RouterError;
+use crate::storage;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+#[contract]
+pub struct RouterContract;
+
+#[contractclient(name = "RouterClient")]
+pub trait Router {
+    /// Initialize the router. The router builds the Blend pool submissions for common user
+    /// flows, and submits them on the user's behalf. It never holds funds: the user is the
+    /// spender and recipient of every submission, so the user authorizes the pool's token
+    /// transfers along with the router call.
+    ///
+    /// ### Arguments
+    /// * `token` - The Address of the stablecoin borrowed from the pools
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, token: Address);
+
+    /// Get the Address of the stablecoin borrowed from the pools
+    fn get_token(e: Env) -> Address;
+
+    /// Supply collateral to a pool and borrow the stablecoin against it in one submission.
+    /// Either amount can be zero to skip that step.
+    ///
+    /// Returns the user's positions in the pool
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the user
+    /// * `pool` - The Address of the Blend pool
+    /// * `collateral` - The Address of the collateral asset
+    /// * `amount` - The amount of collateral to supply
+    /// * `borrow_amount` - The amount of the stablecoin to borrow
+    ///
+    /// ### Panics
+    /// If either amount is negative
+    /// If the pool rejects the submission, e.g. the user's health factor would be too low
+    fn deposit_collateral_and_borrow(
+        e: Env,
+        user: Address,
+        pool: Address,
+        collateral: Address,
+        amount: i128,
+        borrow_amount: i128,
+    ) -> Positions;
+
+    /// Repay the stablecoin borrowed from a pool and withdraw collateral in one submission.
+    /// Either amount can be zero to skip that step. Repaying more than the debt only repays
+    /// the debt.
+    ///
+    /// Returns the user's positions in the pool
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the user
+    /// * `pool` - The Address of the Blend pool
+    /// * `repay_amount` - The amount of the stablecoin to repay
+    /// * `collateral` - The Address of the collateral asset
+    /// * `amount` - The amount of collateral to withdraw
+    ///
+    /// ### Panics
+    /// If either amount is negative
+    /// If the pool rejects the submission, e.g. the user's health factor would be too low
+    fn repay_and_withdraw(
+        e: Env,
+        user: Address,
+        pool: Address,
+        repay_amount: i128,
+        collateral: Address,
+        amount: i128,
+    ) -> Positions;
+}
+
+#[contractimpl]
+impl Router for RouterContract {
+    fn initialize(e: Env, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, RouterError::AlreadyInitializedError);
+        }
+
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn get_token(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_token(&e)
+    }
+
+    fn deposit_collateral_and_borrow(
+        e: Env,
+        user: Address,
+        pool: Address,
+        collateral: Address,
+        amount: i128,
+        borrow_amount: i128,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        user.require_auth();
+
+        let token = storage::get_token(&e);
+        let mut requests = vec![&e];
+        push_request(&e, &mut requests, RequestType::SupplyCollateral, collateral, amount);
+        push_request(&e, &mut requests, RequestType::Borrow, token, borrow_amount);
+        PoolClient::new(&e, &pool).submit(&user, &user, &user, &requests)
+    }
+
+    fn repay_and_withdraw(
+        e: Env,
+        user: Address,
+        pool: Address,
+        repay_amount: i128,
+        collateral: Address,
+        amount: i128,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        user.require_auth();
+
+        let token = storage::get_token(&e);
+        let mut requests = vec![&e];
+        push_request(&e, &mut requests, RequestType::Repay, token, repay_amount);
+        push_request(&e, &mut requests, RequestType::WithdrawCollateral, collateral, amount);
+        PoolClient::new(&e, &pool).submit(&user, &user, &user, &requests)
+    }
+}
+
+/// Add a request to a submission, unless the amount is zero
+///
+/// ### Panics
+/// If the amount is negative
+fn push_request(
+    e: &Env,
+    requests: &mut Vec<Request>,
+    request_type: RequestType,
+    address: Address,
+    amount: i128,
+) {
+    if amount < 0 {
+        panic_with_error!(e, RouterError::NegativeAmountError);
+    }
+    if amount > 0 {
+        requests.push_back(Request {
+            request_type: request_type.into_u32(),
+            address,
+            amount,
+        });
+    }
+}
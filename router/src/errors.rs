@@ -0,0 +1,13 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the router. Common errors are codes that match up with the built-in contracts
+/// error reporting.
+pub enum RouterError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    NegativeAmountError = 8,
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+use crate::contract::{RouterClient, RouterContract};
+use crate::dependencies::pool::{Positions, Request, RequestType};
+use crate::errors::RouterError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, map, vec, Address, Env, Symbol, Vec};
+
+/// Records the last submission, like a Blend pool
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions {
+        from.require_auth();
+        if spender != from {
+            spender.require_auth();
+        }
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "last"), &(from, spender, to, requests));
+        Positions {
+            collateral: map![&e],
+            liabilities: map![&e],
+            supply: map![&e],
+        }
+    }
+
+    pub fn last(e: Env) -> (Address, Address, Address, Vec<Request>) {
+        e.storage().instance().get(&Symbol::new(&e, "last")).unwrap()
+    }
+}
+
+#[test]
+fn test_router_builds_submissions() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token = Address::generate(&e);
+    let collateral = Address::generate(&e);
+    let router_id = e.register_contract(None, RouterContract);
+    let router_client = RouterClient::new(&e, &router_id);
+    router_client.initialize(&token);
+    let pool = e.register_contract(None, MockPool);
+    let pool_client = MockPoolClient::new(&e, &pool);
+    let user = Address::generate(&e);
+
+    router_client.deposit_collateral_and_borrow(
+        &user,
+        &pool,
+        &collateral,
+        &1000_0000000,
+        &500_0000000,
+    );
+    let (from, spender, to, requests) = pool_client.last();
+    assert_eq!((from, spender, to.clone()), (user.clone(), user.clone(), user.clone()));
+    assert_eq!(
+        requests,
+        vec![
+            &e,
+            Request {
+                request_type: RequestType::SupplyCollateral.into_u32(),
+                address: collateral.clone(),
+                amount: 1000_0000000,
+            },
+            Request {
+                request_type: RequestType::Borrow.into_u32(),
+                address: token.clone(),
+                amount: 500_0000000,
+            },
+        ]
+    );
+
+    // zero amounts are skipped
+    router_client.repay_and_withdraw(&user, &pool, &500_0000000, &collateral, &0);
+    let (_, _, _, requests) = pool_client.last();
+    assert_eq!(
+        requests,
+        vec![
+            &e,
+            Request {
+                request_type: RequestType::Repay.into_u32(),
+                address: token,
+                amount: 500_0000000,
+            },
+        ]
+    );
+
+    let result = router_client.try_repay_and_withdraw(&user, &pool, &0, &collateral, &-1);
+    assert_eq!(result.err(), Some(Ok(RouterError::NegativeAmountError.into())));
+}
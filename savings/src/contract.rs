@@ -0,0 +1,375 @@
+use crate::dependencies::compliance::ComplianceClient;
+use crate::dependencies::referral::ReferralClient;
+use crate::errors::SavingsError;
+use crate::events::SavingsEvents;
+use crate::storage::{self, SharePrice};
+use orbit_access::Role;
+use orbit_common::math::{self, SCALAR_7};
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, Symbol,
+};
+
+const SCALAR_12: i128 = 1_000_000_000_000;
+const SECONDS_PER_YEAR: i128 = 31536000;
+
+#[contract]
+pub struct SavingsContract;
+
+#[contractclient(name = "SavingsClient")]
+pub trait Savings {
+    /// Initialize the savings contract. Interest is paid out of the contract's token balance
+    /// beyond what was deposited, which is funded by transferring treasury yield to the contract.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `token` - The Address of the token deposited
+    /// * `rate` - The yearly savings rate, with 7 decimals
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the rate is greater than 100%
+    fn initialize(e: Env, admin: Address, token: Address, rate: u32);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the savings rate. Interest up to now accrues at the old rate.
+    ///
+    /// ### Arguments
+    /// * `rate` - The yearly savings rate, with 7 decimals
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the rate is greater than 100%
+    fn set_rate(e: Env, rate: u32);
+
+    /// (Admin only) Set the referral registry deposits carrying a referral code are reported to,
+    /// and withdrawals released from. The savings contract must be an allowed reporter of the
+    /// registry. Withdrawals go through even if the registry fails to release them.
+    ///
+    /// ### Arguments
+    /// * `referral` - The Address for the referral registry
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_referral(e: Env, referral: Address);
+
+    /// (Admin only) Set the compliance registry deposits and withdrawals are checked against,
+    /// for permissioned deployments. Without a registry anyone can save.
+    ///
+    /// ### Arguments
+    /// * `compliance` - The Address for the compliance registry
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_compliance(e: Env, compliance: Address);
+
+    /// Get the yearly savings rate, with 7 decimals
+    fn get_rate(e: Env) -> u32;
+
+    /// Accrue interest up to now
+    ///
+    /// Returns the tokens a share is worth, with 12 decimals
+    fn accrue(e: Env) -> i128;
+
+    /// Get the tokens a share is worth now, with 12 decimals
+    fn get_share_price(e: Env) -> i128;
+
+    /// Deposit tokens for savings shares
+    ///
+    /// Returns the amount of shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `amount` - The amount of tokens to deposit
+    ///
+    /// ### Panics
+    /// If `from` is not allowed by the compliance registry
+    fn deposit(e: Env, from: Address, amount: i128) -> i128;
+
+    /// Deposit tokens for savings shares on behalf of an integrator. The referrer of the code
+    /// accrues rebates on the deposit until it is withdrawn.
+    ///
+    /// Returns the amount of shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing
+    /// * `amount` - The amount of tokens to deposit
+    /// * `code` - The referral code of the integrator
+    ///
+    /// ### Panics
+    /// If `from` is not allowed by the compliance registry
+    /// If the referral registry is not set
+    /// If the code is not registered
+    /// If `from` is the referrer of the code
+    fn deposit_with_code(e: Env, from: Address, amount: i128, code: Symbol) -> i128;
+
+    /// Withdraw tokens by burning savings shares
+    ///
+    /// Returns the amount of shares burned
+    ///
+    /// ### Arguments
+    /// * `from` - The Address withdrawing
+    /// * `amount` - The amount of tokens to withdraw
+    ///
+    /// ### Panics
+    /// If `from` is not allowed by the compliance registry
+    /// If `from` does not own enough shares
+    /// If the contract does not hold enough tokens to pay out the withdrawal
+    fn withdraw(e: Env, from: Address, amount: i128) -> i128;
+
+    /// Get the savings shares owned by an address
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the saver
+    fn get_shares(e: Env, user: Address) -> i128;
+
+    /// Get the tokens the savings shares of an address are worth now
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the saver
+    fn get_balance(e: Env, user: Address) -> i128;
+
+    /// Get the total savings shares
+    fn get_total_shares(e: Env) -> i128;
+
+    /// Get the tokens all savings shares are worth now
+    fn get_total_assets(e: Env) -> i128;
+}
+
+#[contractimpl]
+impl Savings for SavingsContract {
+    fn initialize(e: Env, admin: Address, token: Address, rate: u32) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, SavingsError::AlreadyInitializedError);
+        }
+        require_valid_rate(&e, rate);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_rate(&e, rate);
+        storage::set_share_price(
+            &e,
+            &SharePrice {
+                price: SCALAR_12,
+                timestamp: e.ledger().timestamp(),
+            },
+        );
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_rate(e: Env, rate: u32) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_rate(&e, rate);
+
+        accrue_share_price(&e);
+        storage::set_rate(&e, rate);
+
+        SavingsEvents::set_rate(&e, rate);
+    }
+
+    fn set_referral(e: Env, referral: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_referral(&e, &referral);
+    }
+
+    fn set_compliance(e: Env, compliance: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_compliance(&e, &compliance);
+    }
+
+    fn get_rate(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_rate(&e)
+    }
+
+    fn accrue(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        accrue_share_price(&e)
+    }
+
+    fn get_share_price(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        load_share_price(&e)
+    }
+
+    fn deposit(e: Env, from: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        require_allowed(&e, &from);
+
+        deposit_tokens(&e, &from, amount)
+    }
+
+    fn deposit_with_code(e: Env, from: Address, amount: i128, code: Symbol) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        require_allowed(&e, &from);
+
+        let referral = storage::get_referral(&e)
+            .unwrap_or_else(|| panic_with_error!(&e, SavingsError::ReferralNotSet));
+        let shares = deposit_tokens(&e, &from, amount);
+        ReferralClient::new(&e, &referral).record(
+            &e.current_contract_address(),
+            &code,
+            &from,
+            &amount,
+        );
+        shares
+    }
+
+    fn withdraw(e: Env, from: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        require_allowed(&e, &from);
+
+        let price = accrue_share_price(&e);
+        // round up so withdrawals never take more than the shares are worth
+        let shares = math::mul_div_ceil(&e, amount, SCALAR_12, price);
+        let user_shares = storage::get_shares(&e, &from);
+        if user_shares < shares {
+            panic_with_error!(&e, SavingsError::InsufficientShares);
+        }
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        if token_client.balance(&e.current_contract_address()) < amount {
+            panic_with_error!(&e, SavingsError::InsufficientFunding);
+        }
+        storage::set_shares(&e, &from, user_shares - shares);
+        storage::set_total_shares(&e, storage::get_total_shares(&e) - shares);
+        token_client.transfer(&e.current_contract_address(), &from, &amount);
+        // a failing referral registry only costs the referrer rebates, so it can't block the
+        // withdrawal
+        if let Some(referral) = storage::get_referral(&e) {
+            let _ = ReferralClient::new(&e, &referral).try_release(
+                &e.current_contract_address(),
+                &from,
+                &amount,
+            );
+        }
+
+        SavingsEvents::withdraw(&e, from, amount, shares);
+        shares
+    }
+
+    fn get_shares(e: Env, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_shares(&e, &user)
+    }
+
+    fn get_balance(e: Env, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        math::mul_div_floor(&e, storage::get_shares(&e, &user), load_share_price(&e), SCALAR_12)
+    }
+
+    fn get_total_shares(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_total_shares(&e)
+    }
+
+    fn get_total_assets(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        let total_shares = storage::get_total_shares(&e);
+        math::mul_div_floor(&e, total_shares, load_share_price(&e), SCALAR_12)
+    }
+}
+
+/// Load the tokens a share is worth now, without storing the accrued share price
+fn load_share_price(e: &Env) -> i128 {
+    let share_price = storage::get_share_price(e);
+    let elapsed = (e.ledger().timestamp() - share_price.timestamp) as i128;
+    let rate = storage::get_rate(e) as i128;
+    share_price.price
+        + math::mul_div_floor(e, share_price.price, rate * elapsed, SCALAR_7 * SECONDS_PER_YEAR)
+}
+
+/// Accrue interest up to now and store the share price
+///
+/// Returns the tokens a share is worth, with 12 decimals
+fn accrue_share_price(e: &Env) -> i128 {
+    let price = load_share_price(e);
+    storage::set_share_price(
+        e,
+        &SharePrice {
+            price,
+            timestamp: e.ledger().timestamp(),
+        },
+    );
+    price
+}
+
+/// Deposit tokens for savings shares
+///
+/// Returns the amount of shares minted
+fn deposit_tokens(e: &Env, from: &Address, amount: i128) -> i128 {
+    let price = accrue_share_price(e);
+    let shares = math::mul_div_floor(e, amount, SCALAR_12, price);
+    if shares <= 0 {
+        panic_with_error!(e, SavingsError::NegativeAmountError);
+    }
+    TokenClient::new(e, &storage::get_token(e)).transfer(
+        from,
+        &e.current_contract_address(),
+        &amount,
+    );
+    storage::set_shares(e, from, storage::get_shares(e, from) + shares);
+    storage::set_total_shares(e, storage::get_total_shares(e) + shares);
+
+    SavingsEvents::deposit(e, from.clone(), amount, shares);
+    shares
+}
+
+/// Require that an address is allowed by the compliance registry, if one is set
+///
+/// ### Panics
+/// If the address is not allowed
+fn require_allowed(e: &Env, user: &Address) {
+    if let Some(compliance) = storage::get_compliance(e) {
+        if !ComplianceClient::new(e, &compliance).is_allowed(user) {
+            panic_with_error!(e, SavingsError::NotAllowed);
+        }
+    }
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, SavingsError::NegativeAmountError);
+    }
+}
+
+/// Require that a savings rate is valid
+///
+/// ### Panics
+/// If the rate is greater than 100%
+fn require_valid_rate(e: &Env, rate: u32) {
+    if rate as i128 > SCALAR_7 {
+        panic_with_error!(e, SavingsError::InvalidRate);
+    }
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the compliance registry the savings contract checks depositors against
+#[allow(dead_code)]
+#[contractclient(name = "ComplianceClient")]
+pub trait Compliance {
+    fn is_allowed(e: Env, user: Address) -> bool;
+}
@@ -0,0 +1,2 @@
+pub mod compliance;
+pub mod referral;
@@ -0,0 +1,11 @@
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+/// The part of the referral registry the savings contract reports referred deposits and their
+/// withdrawals to
+#[allow(dead_code)]
+#[contractclient(name = "ReferralClient")]
+pub trait Referral {
+    fn record(e: Env, reporter: Address, code: Symbol, depositor: Address, amount: i128);
+
+    fn release(e: Env, reporter: Address, depositor: Address, amount: i128) -> i128;
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the savings contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Savings specific errors start at 2600.
+pub enum SavingsError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Savings
+    InsufficientShares = 2600,
+    InsufficientFunding = 2601,
+    InvalidRate = 2602,
+    ReferralNotSet = 2603,
+    NotAllowed = 2604,
+}
@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct SavingsEvents {}
+
+impl SavingsEvents {
+    /// Emitted when the admin updates the savings rate
+    ///
+    /// - topics - `["set_rate"]`
+    /// - data - `rate: u32`
+    pub fn set_rate(e: &Env, rate: u32) {
+        let topics = (Symbol::new(e, topics::SET_RATE),);
+        e.events().publish(topics, rate);
+    }
+
+    /// Emitted when tokens are deposited
+    ///
+    /// - topics - `["deposit", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn deposit(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), from);
+        e.events().publish(topics, (amount, shares));
+    }
+
+    /// Emitted when tokens are withdrawn
+    ///
+    /// - topics - `["withdraw", from: Address]`
+    /// - data - `[amount: i128, shares: i128]`
+    pub fn withdraw(e: &Env, from: Address, amount: i128, shares: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), from);
+        e.events().publish(topics, (amount, shares));
+    }
+}
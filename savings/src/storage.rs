@@ -0,0 +1,203 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const RATE_KEY: &str = "Rate";
+const SHARE_PRICE_KEY: &str = "SharePrice";
+const TOTAL_SHARES_KEY: &str = "TotalShares";
+const REFERRAL_KEY: &str = "Referral";
+const COMPLIANCE_KEY: &str = "Compliance";
+
+/// The value of a savings share
+#[derive(Clone)]
+#[contracttype]
+pub struct SharePrice {
+    pub price: i128,    // the tokens a share is worth, with 12 decimals
+    pub timestamp: u64, // the time interest was last accrued
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum SavingsDataKey {
+    // The savings shares owned by an address
+    Shares(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Referral **********/
+
+/// Fetch the Address of the referral registry, if set
+pub fn get_referral(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REFERRAL_KEY))
+}
+
+/// Set the Address of the referral registry
+///
+/// ### Arguments
+/// * `referral` - The Address for the referral registry
+pub fn set_referral(e: &Env, referral: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, REFERRAL_KEY), referral);
+}
+
+/********** Compliance **********/
+
+/// Fetch the Address of the compliance registry, if set
+pub fn get_compliance(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, COMPLIANCE_KEY))
+}
+
+/// Set the Address of the compliance registry
+///
+/// ### Arguments
+/// * `compliance` - The Address for the compliance registry
+pub fn set_compliance(e: &Env, compliance: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, COMPLIANCE_KEY), compliance);
+}
+
+/********** Savings **********/
+
+/// Fetch the Address of the token deposited
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token deposited
+///
+/// ### Arguments
+/// * `token` - The Address of the token deposited
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the yearly savings rate, with 7 decimals
+///
+/// ### Panics
+/// If the rate does not exist
+pub fn get_rate(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, RATE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the yearly savings rate
+///
+/// ### Arguments
+/// * `rate` - The yearly savings rate, with 7 decimals
+pub fn set_rate(e: &Env, rate: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, RATE_KEY), &rate);
+}
+
+/// Fetch the value of a savings share
+///
+/// ### Panics
+/// If the share price does not exist
+pub fn get_share_price(e: &Env) -> SharePrice {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SHARE_PRICE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the value of a savings share
+///
+/// ### Arguments
+/// * `share_price` - The value of a savings share
+pub fn set_share_price(e: &Env, share_price: &SharePrice) {
+    e.storage()
+        .instance()
+        .set::<Symbol, SharePrice>(&Symbol::new(e, SHARE_PRICE_KEY), share_price);
+}
+
+/// Fetch the total savings shares
+pub fn get_total_shares(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_SHARES_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the total savings shares
+///
+/// ### Arguments
+/// * `shares` - The total savings shares
+pub fn set_total_shares(e: &Env, shares: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, TOTAL_SHARES_KEY), &shares);
+}
+
+/// Fetch the savings shares owned by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the saver
+pub fn get_shares(e: &Env, user: &Address) -> i128 {
+    let key = SavingsDataKey::Shares(user.clone());
+    let result = e.storage().persistent().get::<SavingsDataKey, i128>(&key);
+    match result {
+        Some(shares) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            shares
+        }
+        None => 0,
+    }
+}
+
+/// Set the savings shares owned by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the saver
+/// * `shares` - The savings shares owned
+pub fn set_shares(e: &Env, user: &Address, shares: i128) {
+    let key = SavingsDataKey::Shares(user.clone());
+    e.storage()
+        .persistent()
+        .set::<SavingsDataKey, i128>(&key, &shares);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
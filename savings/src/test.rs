@@ -0,0 +1,177 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{SavingsClient, SavingsContract};
+use crate::errors::SavingsError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+const YEAR: u64 = 31536000;
+
+/// Records the last referred deposit and the withdrawals released, like the referral registry,
+/// and can be made to fail releases
+#[contract]
+pub struct MockReferral;
+
+#[contractimpl]
+impl MockReferral {
+    pub fn record(e: Env, reporter: Address, code: Symbol, depositor: Address, amount: i128) {
+        reporter.require_auth();
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "last"), &(code, depositor, amount));
+    }
+
+    pub fn release(e: Env, reporter: Address, _depositor: Address, amount: i128) -> i128 {
+        reporter.require_auth();
+        if e.storage().instance().has(&Symbol::new(&e, "failing")) {
+            panic!("release failed");
+        }
+        let released = Self::released(e.clone()) + amount;
+        e.storage().instance().set(&Symbol::new(&e, "released"), &released);
+        amount
+    }
+
+    pub fn set_failing(e: Env) {
+        e.storage().instance().set(&Symbol::new(&e, "failing"), &true);
+    }
+
+    pub fn last(e: Env) -> (Symbol, Address, i128) {
+        e.storage().instance().get(&Symbol::new(&e, "last")).unwrap()
+    }
+
+    pub fn released(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "released"))
+            .unwrap_or(0)
+    }
+}
+
+/// Create a savings contract paying 5% a year, returning the savings client and the token address
+fn create_savings<'a>(e: &Env) -> (SavingsClient<'a>, Address) {
+    let token = e.register_stellar_asset_contract(Address::generate(e));
+    let savings_id = e.register_contract(None, SavingsContract);
+    let savings_client = SavingsClient::new(e, &savings_id);
+    savings_client.initialize(&Address::generate(e), &token, &0_0500000);
+    (savings_client, token)
+}
+
+#[test]
+fn test_deposit_accrue_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (savings_client, token) = create_savings(&e);
+    let token_client = TokenClient::new(&e, &token);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&samwise, &1000_0000000);
+    StellarAssetClient::new(&e, &token).mint(&frodo, &1050_0000000);
+
+    assert_eq!(savings_client.deposit(&samwise, &1000_0000000), 1000_0000000);
+
+    // a year later, a share is worth 1.05 tokens
+    e.ledger().with_mut(|li| li.timestamp += YEAR);
+    assert_eq!(savings_client.get_share_price(), 1_050_000_000_000);
+    assert_eq!(savings_client.get_balance(&samwise), 1050_0000000);
+    assert_eq!(savings_client.accrue(), 1_050_000_000_000);
+
+    // interest is only paid if the contract is funded
+    let result = savings_client.try_withdraw(&samwise, &1050_0000000);
+    assert_eq!(result.err(), Some(Ok(SavingsError::InsufficientFunding.into())));
+    let result = savings_client.try_withdraw(&samwise, &1050_0000001);
+    assert_eq!(result.err(), Some(Ok(SavingsError::InsufficientShares.into())));
+    StellarAssetClient::new(&e, &token).mint(&savings_client.address, &100_0000000);
+
+    assert_eq!(savings_client.deposit(&frodo, &1050_0000000), 1000_0000000);
+    assert_eq!(savings_client.get_total_shares(), 2000_0000000);
+    assert_eq!(savings_client.get_total_assets(), 2100_0000000);
+    assert_eq!(savings_client.withdraw(&samwise, &1050_0000000), 1000_0000000);
+    assert_eq!(token_client.balance(&samwise), 1050_0000000);
+    assert_eq!(savings_client.get_shares(&samwise), 0);
+
+    // the rate change applies from now on
+    savings_client.set_rate(&0_1000000);
+    e.ledger().with_mut(|li| li.timestamp += YEAR / 2);
+    assert_eq!(savings_client.get_balance(&frodo), 1102_5000000);
+}
+
+#[test]
+fn test_set_rate_validates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (savings_client, _) = create_savings(&e);
+
+    let result = savings_client.try_set_rate(&1_0000001);
+    assert_eq!(result.err(), Some(Ok(SavingsError::InvalidRate.into())));
+    savings_client.set_rate(&1_0000000);
+    assert_eq!(savings_client.get_rate(), 1_0000000);
+}
+
+/// Allows every address but one, like the compliance registry
+#[contract]
+pub struct MockCompliance;
+
+#[contractimpl]
+impl MockCompliance {
+    pub fn deny(e: Env, user: Address) {
+        e.storage().instance().set(&Symbol::new(&e, "denied"), &user);
+    }
+
+    pub fn is_allowed(e: Env, user: Address) -> bool {
+        let denied: Option<Address> = e.storage().instance().get(&Symbol::new(&e, "denied"));
+        denied != Some(user)
+    }
+}
+
+#[test]
+fn test_deposit_with_code() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (savings_client, token) = create_savings(&e);
+    let user = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&user, &1000_0000000);
+    let code = Symbol::new(&e, "wallet");
+
+    let result = savings_client.try_deposit_with_code(&user, &1000_0000000, &code);
+    assert_eq!(result.err(), Some(Ok(SavingsError::ReferralNotSet.into())));
+
+    let referral = e.register_contract(None, MockReferral);
+    savings_client.set_referral(&referral);
+    let shares = savings_client.deposit_with_code(&user, &1000_0000000, &code);
+    assert_eq!(shares, 1000_0000000);
+    assert_eq!(savings_client.get_shares(&user), 1000_0000000);
+    let referral_client = MockReferralClient::new(&e, &referral);
+    assert_eq!(referral_client.last(), (code, user.clone(), 1000_0000000));
+
+    // withdrawals are released so the referrer stops accruing on them
+    savings_client.withdraw(&user, &400_0000000);
+    assert_eq!(referral_client.released(), 400_0000000);
+
+    // a failing registry doesn't block withdrawals
+    referral_client.set_failing();
+    savings_client.withdraw(&user, &100_0000000);
+    assert_eq!(savings_client.get_shares(&user), 500_0000000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&user), 500_0000000);
+    assert_eq!(referral_client.released(), 400_0000000);
+}
+
+#[test]
+fn test_compliance_hook() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (savings_client, token) = create_savings(&e);
+    let user = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&user, &1000_0000000);
+    savings_client.deposit(&user, &500_0000000);
+
+    let compliance = e.register_contract(None, MockCompliance);
+    savings_client.set_compliance(&compliance);
+    savings_client.deposit(&user, &250_0000000);
+    MockComplianceClient::new(&e, &compliance).deny(&user);
+    let result = savings_client.try_deposit(&user, &250_0000000);
+    assert_eq!(result.err(), Some(Ok(SavingsError::NotAllowed.into())));
+    let result = savings_client.try_withdraw(&user, &250_0000000);
+    assert_eq!(result.err(), Some(Ok(SavingsError::NotAllowed.into())));
+}
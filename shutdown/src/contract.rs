@@ -0,0 +1,285 @@
+use crate::dependencies::admin::AdminClient;
+use crate::dependencies::treasury::TreasuryClient;
+use crate::errors::ShutdownError;
+use crate::events::ShutdownEvents;
+use crate::storage::{self, Settlement};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_40_oracle::{Asset, PriceFeedClient};
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+#[contract]
+pub struct ShutdownContract;
+
+#[contractclient(name = "ShutdownClient")]
+pub trait Shutdown {
+    /// Initialize the shutdown module. The module must be granted `freeze_pool` on the admin
+    /// contract, and set as the shutdown module of the treasury.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `admin_contract` - The Address of the admin contract managing the pools
+    /// * `treasury` - The Address of the treasury minting the token
+    /// * `oracle` - The Address of the oracle pricing the collateral in USD
+    /// * `token` - The Address of the token being wound down
+    /// * `collateral` - The collateral assets backing the token
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(
+        e: Env,
+        admin: Address,
+        admin_contract: Address,
+        treasury: Address,
+        oracle: Address,
+        token: Address,
+        collateral: Vec<Address>,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Trigger the emergency shutdown. Freezes the pools so no more tokens can
+    /// be borrowed into circulation, shuts the treasury down so it mints no more and withdraws
+    /// and burns what it can of its supply, and snapshots the oracle price of each collateral
+    /// asset. The admin then sends the collateral to this contract, and winds down the yield
+    /// adapters and withdraws the treasury's supply as it is repaid, before calling `settle`.
+    /// The shutdown can't be undone.
+    ///
+    /// ### Arguments
+    /// * `pools` - The pools to freeze
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the shutdown was already triggered
+    /// If the oracle has no price for a collateral asset
+    /// If the treasury can't be shut down
+    fn shutdown(e: Env, pools: Vec<Address>);
+
+    /// Check if the emergency shutdown was triggered
+    fn is_shutdown(e: Env) -> bool;
+
+    /// Get the oracle price of a collateral asset at shutdown
+    ///
+    /// ### Arguments
+    /// * `asset` - The collateral asset
+    fn get_price(e: Env, asset: Address) -> i128;
+
+    /// (Admin only) Settle the shutdown once the collateral has been sent to this contract.
+    /// Snapshots the collateral held, values it at the prices snapshotted at shutdown, reads the
+    /// outstanding supply of the token from the treasury minting it, and opens redemptions.
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the shutdown was not triggered or was already settled
+    /// If no supply is outstanding
+    fn settle(e: Env);
+
+    /// Get the settlement terms
+    ///
+    /// ### Panics
+    /// If the shutdown was not settled
+    fn get_settlement(e: Env) -> Settlement;
+
+    /// Get the collateral paid out for redeeming tokens
+    ///
+    /// ### Arguments
+    /// * `amount` - The amount of tokens
+    ///
+    /// ### Panics
+    /// If the shutdown was not settled
+    fn get_redemption(e: Env, amount: i128) -> Vec<(Address, i128)>;
+
+    /// Burn tokens for collateral held at settlement. Each token is worth a token's value of
+    /// collateral at the prices snapshotted at shutdown, taken pro-rata from each asset, or a
+    /// pro-rata share of all the collateral if it is worth less than the supply.
+    ///
+    /// Returns the amount of each collateral asset paid out
+    ///
+    /// ### Arguments
+    /// * `from` - The Address redeeming tokens
+    /// * `amount` - The amount of tokens to redeem
+    ///
+    /// ### Panics
+    /// If the shutdown was not settled
+    /// If the redemption exceeds the supply left to redeem
+    fn redeem(e: Env, from: Address, amount: i128) -> Vec<(Address, i128)>;
+}
+
+#[contractimpl]
+impl Shutdown for ShutdownContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        admin_contract: Address,
+        treasury: Address,
+        oracle: Address,
+        token: Address,
+        collateral: Vec<Address>,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, ShutdownError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_admin_contract(&e, &admin_contract);
+        storage::set_treasury(&e, &treasury);
+        storage::set_oracle(&e, &oracle);
+        storage::set_token(&e, &token);
+        storage::set_collateral(&e, &collateral);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn shutdown(e: Env, pools: Vec<Address>) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if storage::get_shutdown(&e).is_some() {
+            panic_with_error!(&e, ShutdownError::AlreadyShutdown);
+        }
+
+        let admin_client = AdminClient::new(&e, &storage::get_admin_contract(&e));
+        for pool in pools.iter() {
+            admin_client.freeze_pool(&e.current_contract_address(), &pool);
+        }
+        let unwound = TreasuryClient::new(&e, &storage::get_treasury(&e)).shutdown();
+        let oracle = PriceFeedClient::new(&e, &storage::get_oracle(&e));
+        for asset in storage::get_collateral(&e).iter() {
+            let price = oracle
+                .lastprice(&Asset::Stellar(asset.clone()))
+                .unwrap_or_else(|| panic_with_error!(&e, ShutdownError::NoPrice));
+            storage::set_price(&e, &asset, price.price);
+        }
+        storage::set_shutdown(&e, e.ledger().timestamp());
+
+        ShutdownEvents::shutdown(&e, pools, unwound);
+    }
+
+    fn is_shutdown(e: Env) -> bool {
+        storage::extend_instance(&e);
+        storage::get_shutdown(&e).is_some()
+    }
+
+    fn get_price(e: Env, asset: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_price(&e, &asset)
+    }
+
+    fn settle(e: Env) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if storage::get_shutdown(&e).is_none() {
+            panic_with_error!(&e, ShutdownError::NotShutdown);
+        }
+        if storage::get_settlement(&e).is_some() {
+            panic_with_error!(&e, ShutdownError::AlreadySettled);
+        }
+        // the token is a Stellar asset, which doesn't report its supply, so the supply is what
+        // the treasury minting it has outstanding
+        let supply = TreasuryClient::new(&e, &storage::get_treasury(&e)).get_token_supply();
+        if supply <= 0 {
+            panic_with_error!(&e, ShutdownError::NegativeAmountError);
+        }
+
+        let oracle = PriceFeedClient::new(&e, &storage::get_oracle(&e));
+        let oracle_scalar = 10i128.pow(oracle.decimals());
+        let token_scalar = 10i128.pow(TokenClient::new(&e, &storage::get_token(&e)).decimals());
+        let mut value: i128 = 0;
+        for asset in storage::get_collateral(&e).iter() {
+            let asset_client = TokenClient::new(&e, &asset);
+            let balance = asset_client.balance(&e.current_contract_address());
+            storage::set_balance(&e, &asset, balance);
+            // the value of one whole collateral token in token units
+            let unit_value = math::mul_div_floor(
+                &e,
+                storage::get_price(&e, &asset),
+                token_scalar,
+                oracle_scalar,
+            );
+            let asset_scalar = 10i128.pow(asset_client.decimals());
+            value += math::mul_div_floor(&e, balance, unit_value, asset_scalar);
+        }
+        storage::set_settlement(
+            &e,
+            &Settlement {
+                supply,
+                value,
+                redeemed: 0,
+            },
+        );
+
+        ShutdownEvents::settle(&e, supply, value);
+    }
+
+    fn get_settlement(e: Env) -> Settlement {
+        storage::extend_instance(&e);
+        load_settlement(&e)
+    }
+
+    fn get_redemption(e: Env, amount: i128) -> Vec<(Address, i128)> {
+        storage::extend_instance(&e);
+        calc_payouts(&e, &load_settlement(&e), amount)
+    }
+
+    fn redeem(e: Env, from: Address, amount: i128) -> Vec<(Address, i128)> {
+        storage::extend_instance(&e);
+        from.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&e, ShutdownError::NegativeAmountError);
+        }
+
+        let mut settlement = load_settlement(&e);
+        if settlement.redeemed + amount > settlement.supply {
+            panic_with_error!(&e, ShutdownError::ExceedsSupply);
+        }
+        let payouts = calc_payouts(&e, &settlement, amount);
+        settlement.redeemed += amount;
+        storage::set_settlement(&e, &settlement);
+
+        TokenClient::new(&e, &storage::get_token(&e)).burn(&from, &amount);
+        for (asset, payout) in payouts.iter() {
+            if payout > 0 {
+                TokenClient::new(&e, &asset).transfer(&e.current_contract_address(), &from, &payout);
+            }
+        }
+
+        ShutdownEvents::redeem(&e, from, amount, payouts.clone());
+        payouts
+    }
+}
+
+/// Load the settlement terms
+///
+/// ### Panics
+/// If the shutdown was not settled
+fn load_settlement(e: &Env) -> Settlement {
+    storage::get_settlement(e).unwrap_or_else(|| panic_with_error!(e, ShutdownError::NotSettled))
+}
+
+/// Calculate the collateral paid out for an amount of tokens. The tokens are worth their value
+/// in collateral at the shutdown prices, or a pro-rata share of the collateral if it is worth
+/// less than the supply, so a surplus is left behind rather than paid out to early redeemers.
+fn calc_payouts(e: &Env, settlement: &Settlement, amount: i128) -> Vec<(Address, i128)> {
+    let backing = settlement.supply.max(settlement.value);
+    let mut payouts = vec![e];
+    for asset in storage::get_collateral(e).iter() {
+        let payout = math::mul_div_floor(e, storage::get_balance(e, &asset), amount, backing);
+        payouts.push_back((asset, payout));
+    }
+    payouts
+}
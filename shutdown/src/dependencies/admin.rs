@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the admin contract the shutdown module uses to freeze pools
+#[allow(dead_code)]
+#[contractclient(name = "AdminClient")]
+pub trait Admin {
+    fn freeze_pool(e: Env, caller: Address, pool: Address);
+}
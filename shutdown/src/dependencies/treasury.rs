@@ -0,0 +1,10 @@
+use soroban_sdk::{contractclient, Env};
+
+/// The part of the treasury the shutdown module uses to stop minting and unwind its supply
+#[allow(dead_code)]
+#[contractclient(name = "TreasuryClient")]
+pub trait Treasury {
+    fn shutdown(e: Env) -> i128;
+
+    fn get_token_supply(e: Env) -> i128;
+}
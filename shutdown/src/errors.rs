@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the shutdown contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Shutdown specific errors start at 3200.
+pub enum ShutdownError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Shutdown
+    AlreadyShutdown = 3200,
+    NotShutdown = 3201,
+    NotSettled = 3202,
+    AlreadySettled = 3203,
+    NoPrice = 3204,
+    ExceedsSupply = 3205,
+}
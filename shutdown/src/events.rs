@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub struct ShutdownEvents {}
+
+impl ShutdownEvents {
+    /// Emitted when the admin triggers the shutdown
+    ///
+    /// - topics - `["shutdown"]`
+    /// - data - `[pools: Vec<Address>, unwound: i128]`
+    pub fn shutdown(e: &Env, pools: Vec<Address>, unwound: i128) {
+        let topics = (Symbol::new(e, topics::SHUTDOWN),);
+        e.events().publish(topics, (pools, unwound));
+    }
+
+    /// Emitted when the admin settles the shutdown and redemptions open
+    ///
+    /// - topics - `["settle"]`
+    /// - data - `[supply: i128, value: i128]`
+    pub fn settle(e: &Env, supply: i128, value: i128) {
+        let topics = (Symbol::new(e, topics::SETTLE),);
+        e.events().publish(topics, (supply, value));
+    }
+
+    /// Emitted when tokens are redeemed for collateral
+    ///
+    /// - topics - `["redeem", from: Address]`
+    /// - data - `[amount: i128, payouts: Vec<(Address, i128)>]`
+    pub fn redeem(e: &Env, from: Address, amount: i128, payouts: Vec<(Address, i128)>) {
+        let topics = (Symbol::new(e, topics::REDEEM), from);
+        e.events().publish(topics, (amount, payouts));
+    }
+}
@@ -0,0 +1,245 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const ADMIN_CONTRACT_KEY: &str = "AdminContract";
+const TREASURY_KEY: &str = "Treasury";
+const ORACLE_KEY: &str = "Oracle";
+const TOKEN_KEY: &str = "Token";
+const COLLATERAL_KEY: &str = "Collateral";
+const SHUTDOWN_KEY: &str = "Shutdown";
+const SETTLEMENT_KEY: &str = "Settlement";
+
+/// The terms redemptions are settled on
+#[derive(Clone)]
+#[contracttype]
+pub struct Settlement {
+    pub supply: i128,   // the outstanding token supply at settlement
+    pub value: i128,    // the collateral held at settlement, valued in tokens at shutdown prices
+    pub redeemed: i128, // the tokens redeemed so far
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ShutdownDataKey {
+    // The oracle price of a collateral asset at shutdown
+    Price(Address),
+    // The balance of a collateral asset held at settlement
+    Balance(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the admin contract managing the pools
+///
+/// ### Panics
+/// If the admin contract does not exist
+pub fn get_admin_contract(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ADMIN_CONTRACT_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the admin contract managing the pools
+///
+/// ### Arguments
+/// * `admin_contract` - The Address of the admin contract
+pub fn set_admin_contract(e: &Env, admin_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_CONTRACT_KEY), admin_contract);
+}
+
+/// Fetch the Address of the treasury minting the token
+///
+/// ### Panics
+/// If the treasury does not exist
+pub fn get_treasury(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TREASURY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the treasury minting the token
+///
+/// ### Arguments
+/// * `treasury` - The Address of the treasury
+pub fn set_treasury(e: &Env, treasury: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TREASURY_KEY), treasury);
+}
+
+/// Fetch the Address of the oracle
+///
+/// ### Panics
+/// If the oracle does not exist
+pub fn get_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the oracle
+///
+/// ### Arguments
+/// * `oracle` - The Address of the oracle
+pub fn set_oracle(e: &Env, oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ORACLE_KEY), oracle);
+}
+
+/// Fetch the Address of the token being wound down
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token being wound down
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the collateral assets backing the token
+///
+/// ### Panics
+/// If the collateral does not exist
+pub fn get_collateral(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, COLLATERAL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the collateral assets backing the token
+///
+/// ### Arguments
+/// * `collateral` - The collateral asset addresses
+pub fn set_collateral(e: &Env, collateral: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, COLLATERAL_KEY), collateral);
+}
+
+/********** Shutdown **********/
+
+/// Fetch the time of the shutdown, if it was triggered
+pub fn get_shutdown(e: &Env) -> Option<u64> {
+    e.storage().instance().get(&Symbol::new(e, SHUTDOWN_KEY))
+}
+
+/// Set the time of the shutdown
+///
+/// ### Arguments
+/// * `timestamp` - The time of the shutdown
+pub fn set_shutdown(e: &Env, timestamp: u64) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u64>(&Symbol::new(e, SHUTDOWN_KEY), &timestamp);
+}
+
+/// Fetch the settlement terms, if the shutdown was settled
+pub fn get_settlement(e: &Env) -> Option<Settlement> {
+    e.storage().instance().get(&Symbol::new(e, SETTLEMENT_KEY))
+}
+
+/// Set the settlement terms
+///
+/// ### Arguments
+/// * `settlement` - The settlement terms
+pub fn set_settlement(e: &Env, settlement: &Settlement) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Settlement>(&Symbol::new(e, SETTLEMENT_KEY), settlement);
+}
+
+/// Fetch the oracle price of a collateral asset at shutdown
+///
+/// ### Arguments
+/// * `asset` - The Address of the collateral asset
+pub fn get_price(e: &Env, asset: &Address) -> i128 {
+    get_persistent_amount(e, &ShutdownDataKey::Price(asset.clone()))
+}
+
+/// Set the oracle price of a collateral asset at shutdown
+///
+/// ### Arguments
+/// * `asset` - The Address of the collateral asset
+/// * `price` - The oracle price
+pub fn set_price(e: &Env, asset: &Address, price: i128) {
+    set_persistent_amount(e, &ShutdownDataKey::Price(asset.clone()), price);
+}
+
+/// Fetch the balance of a collateral asset held at settlement
+///
+/// ### Arguments
+/// * `asset` - The Address of the collateral asset
+pub fn get_balance(e: &Env, asset: &Address) -> i128 {
+    get_persistent_amount(e, &ShutdownDataKey::Balance(asset.clone()))
+}
+
+/// Set the balance of a collateral asset held at settlement
+///
+/// ### Arguments
+/// * `asset` - The Address of the collateral asset
+/// * `balance` - The balance held
+pub fn set_balance(e: &Env, asset: &Address, balance: i128) {
+    set_persistent_amount(e, &ShutdownDataKey::Balance(asset.clone()), balance);
+}
+
+fn get_persistent_amount(e: &Env, key: &ShutdownDataKey) -> i128 {
+    let result = e.storage().persistent().get::<ShutdownDataKey, i128>(key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            amount
+        }
+        None => 0,
+    }
+}
+
+fn set_persistent_amount(e: &Env, key: &ShutdownDataKey, amount: i128) {
+    e.storage()
+        .persistent()
+        .set::<ShutdownDataKey, i128>(key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
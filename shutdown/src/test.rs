@@ -0,0 +1,182 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{ShutdownClient, ShutdownContract};
+use crate::errors::ShutdownError;
+use sep_40_oracle::testutils::{Asset, MockPriceOracleClient, MockPriceOracleWASM};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, Symbol, Vec};
+
+/// Records the pools frozen through `freeze_pool`, like the admin contract
+#[contract]
+pub struct MockAdmin;
+
+#[contractimpl]
+impl MockAdmin {
+    pub fn freeze_pool(e: Env, caller: Address, pool: Address) {
+        caller.require_auth();
+        let mut frozen = Self::frozen(e.clone());
+        frozen.push_back(pool);
+        e.storage().instance().set(&Symbol::new(&e, "frozen"), &frozen);
+    }
+
+    pub fn frozen(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "frozen"))
+            .unwrap_or(vec![&e])
+    }
+}
+
+/// Records the shutdown and reports a set supply, like the treasury
+#[contract]
+pub struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn shutdown(e: Env) -> i128 {
+        e.storage().instance().set(&Symbol::new(&e, "shutdown"), &true);
+        0
+    }
+
+    pub fn is_shutdown(e: Env) -> bool {
+        e.storage().instance().has(&Symbol::new(&e, "shutdown"))
+    }
+
+    pub fn set_token_supply(e: Env, supply: i128) {
+        e.storage().instance().set(&Symbol::new(&e, "supply"), &supply);
+    }
+
+    pub fn get_token_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "supply"))
+            .unwrap_or(0)
+    }
+}
+
+/// Deploy a shutdown module over xlm at $0.10 and usdc at $1.00, and the mocks it calls
+///
+/// Returns (shutdown, admin, treasury, ousd, xlm, usdc)
+fn create_shutdown(e: &Env) -> (Address, Address, Address, Address, Address, Address) {
+    let token_admin = Address::generate(e);
+    let ousd = e.register_stellar_asset_contract(token_admin.clone());
+    let xlm = e.register_stellar_asset_contract(token_admin.clone());
+    let usdc = e.register_stellar_asset_contract(token_admin);
+    let admin_id = e.register_contract(None, MockAdmin);
+    let treasury_id = e.register_contract(None, MockTreasury);
+
+    let oracle_id = e.register_contract_wasm(None, MockPriceOracleWASM);
+    let oracle_client = MockPriceOracleClient::new(e, &oracle_id);
+    oracle_client.set_data(
+        &Address::generate(e),
+        &Asset::Other(Symbol::new(e, "USD")),
+        &vec![e, Asset::Stellar(xlm.clone()), Asset::Stellar(usdc.clone())],
+        &7,
+        &300,
+    );
+    oracle_client.set_price_stable(&vec![e, 0_1000000, 1_0000000]);
+
+    let shutdown_id = e.register_contract(None, ShutdownContract);
+    let shutdown_client = ShutdownClient::new(e, &shutdown_id);
+    shutdown_client.initialize(
+        &Address::generate(e),
+        &admin_id,
+        &treasury_id,
+        &oracle_id,
+        &ousd,
+        &vec![e, xlm.clone(), usdc.clone()],
+    );
+    (shutdown_id, admin_id, treasury_id, ousd, xlm, usdc)
+}
+
+#[test]
+fn test_shutdown_and_redeem() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (shutdown_id, admin_id, treasury_id, ousd, xlm, usdc) = create_shutdown(&e);
+    let shutdown_client = ShutdownClient::new(&e, &shutdown_id);
+    let treasury_client = MockTreasuryClient::new(&e, &treasury_id);
+    let pool = Address::generate(&e);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&samwise, &400_0000000);
+
+    treasury_client.set_token_supply(&1000_0000000);
+    let result = shutdown_client.try_settle();
+    assert_eq!(result.err(), Some(Ok(ShutdownError::NotShutdown.into())));
+
+    shutdown_client.shutdown(&vec![&e, pool.clone()]);
+    assert!(shutdown_client.is_shutdown());
+    assert_eq!(MockAdminClient::new(&e, &admin_id).frozen(), vec![&e, pool.clone()]);
+    assert!(treasury_client.is_shutdown());
+    assert_eq!(shutdown_client.get_price(&xlm), 0_1000000);
+    let result = shutdown_client.try_shutdown(&vec![&e]);
+    assert_eq!(result.err(), Some(Ok(ShutdownError::AlreadyShutdown.into())));
+    let result = shutdown_client.try_redeem(&samwise, &1);
+    assert_eq!(result.err(), Some(Ok(ShutdownError::NotSettled.into())));
+
+    // the unwound collateral is sent to the module
+    StellarAssetClient::new(&e, &xlm).mint(&shutdown_id, &5000_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&shutdown_id, &400_0000000);
+    shutdown_client.settle();
+    // the collateral is worth $900 against a supply of 1000, so redemptions are pro-rata
+    assert_eq!(shutdown_client.get_settlement().value, 900_0000000);
+    let result = shutdown_client.try_settle();
+    assert_eq!(result.err(), Some(Ok(ShutdownError::AlreadySettled.into())));
+
+    let payouts = shutdown_client.redeem(&samwise, &400_0000000);
+    assert_eq!(
+        payouts,
+        vec![&e, (xlm.clone(), 2000_0000000), (usdc.clone(), 160_0000000)]
+    );
+    assert_eq!(TokenClient::new(&e, &ousd).balance(&samwise), 0);
+    assert_eq!(TokenClient::new(&e, &xlm).balance(&samwise), 2000_0000000);
+    assert_eq!(TokenClient::new(&e, &usdc).balance(&samwise), 160_0000000);
+    assert_eq!(shutdown_client.get_settlement().redeemed, 400_0000000);
+
+    // later redemptions get the same rate
+    assert_eq!(
+        shutdown_client.get_redemption(&600_0000000),
+        vec![&e, (xlm.clone(), 3000_0000000), (usdc.clone(), 240_0000000)]
+    );
+    let result = shutdown_client.try_redeem(&samwise, &600_0000001);
+    assert_eq!(result.err(), Some(Ok(ShutdownError::ExceedsSupply.into())));
+}
+
+#[test]
+fn test_redeem_overcollateralized() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (shutdown_id, _, treasury_id, ousd, xlm, usdc) = create_shutdown(&e);
+    let shutdown_client = ShutdownClient::new(&e, &shutdown_id);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&samwise, &400_0000000);
+    MockTreasuryClient::new(&e, &treasury_id).set_token_supply(&1000_0000000);
+
+    shutdown_client.shutdown(&vec![&e]);
+    StellarAssetClient::new(&e, &xlm).mint(&shutdown_id, &20000_0000000);
+    StellarAssetClient::new(&e, &usdc).mint(&shutdown_id, &400_0000000);
+    shutdown_client.settle();
+    assert_eq!(shutdown_client.get_settlement().value, 2400_0000000);
+
+    // each token is paid $1 of collateral at the shutdown prices, not a share of the surplus
+    let payouts = shutdown_client.redeem(&samwise, &400_0000000);
+    assert_eq!(
+        payouts,
+        vec![&e, (xlm.clone(), 3333_3333333), (usdc.clone(), 66_6666666)]
+    );
+    assert_eq!(TokenClient::new(&e, &xlm).balance(&samwise), 3333_3333333);
+    assert_eq!(TokenClient::new(&e, &usdc).balance(&samwise), 66_6666666);
+}
+
+#[test]
+fn test_settle_no_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (shutdown_id, _, _, _, _, _) = create_shutdown(&e);
+    let shutdown_client = ShutdownClient::new(&e, &shutdown_id);
+
+    shutdown_client.shutdown(&vec![&e]);
+    let result = shutdown_client.try_settle();
+    assert_eq!(result.err(), Some(Ok(ShutdownError::NegativeAmountError.into())));
+}
@@ -0,0 +1,169 @@
+use crate::dependencies::admin::AdminClient;
+use crate::dependencies::bridge_oracle::{AssetConfig, BridgeOracleClient};
+use crate::dependencies::pool::ReserveConfig;
+use crate::dependencies::treasury_factory::TreasuryFactoryClient;
+use crate::errors::StablecoinFactoryError;
+use crate::events::StablecoinFactoryEvents;
+use crate::storage::{self, Stablecoin};
+use orbit_access::Role;
+use sep_40_oracle::Asset;
+use sep_41_token::StellarAssetClient;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec,
+};
+
+#[contract]
+pub struct StablecoinFactoryContract;
+
+#[contractclient(name = "StablecoinFactoryClient")]
+pub trait StablecoinFactory {
+    /// Initialize the stablecoin factory
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `treasury_factory` - The Address of the treasury factory
+    /// * `admin_contract` - The Address of the admin contract managing the pools
+    /// * `bridge_oracle` - The Address of the bridge oracle
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(
+        e: Env,
+        admin: Address,
+        treasury_factory: Address,
+        admin_contract: Address,
+        bridge_oracle: Address,
+    );
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Launch a stablecoin for a new currency. Deploys its treasury, hands the
+    /// admin of its stellar asset contract to the treasury, queues its reserve on the pool and
+    /// sets its feeds on the bridge oracle.
+    ///
+    /// The admin must also administer the stellar asset contract, the treasury factory, the
+    /// admin contract and the bridge oracle, so the whole launch is authorized at once.
+    ///
+    /// Returns the Address of the treasury
+    ///
+    /// ### Arguments
+    /// * `currency` - The currency code of the stablecoin, like `EUR`
+    /// * `token` - The Address of the stellar asset contract of the stablecoin
+    /// * `pool` - The Address of the pool the treasury supplies the stablecoin to
+    /// * `salt` - The salt for the treasury deployment
+    /// * `reserve_config` - The reserve config of the stablecoin on the pool
+    /// * `oracle_config` - The pricing settings of the stablecoin on the bridge oracle
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If a stablecoin was already launched for the currency
+    fn launch(
+        e: Env,
+        currency: Symbol,
+        token: Address,
+        pool: Address,
+        salt: BytesN<32>,
+        reserve_config: ReserveConfig,
+        oracle_config: AssetConfig,
+    ) -> Address;
+
+    /// Get the currency codes of the launched stablecoins
+    fn get_currencies(e: Env) -> Vec<Symbol>;
+
+    /// Get the contracts of a launched stablecoin
+    ///
+    /// ### Arguments
+    /// * `currency` - The currency code of the stablecoin
+    ///
+    /// ### Panics
+    /// If no stablecoin was launched for the currency
+    fn get_stablecoin(e: Env, currency: Symbol) -> Stablecoin;
+}
+
+#[contractimpl]
+impl StablecoinFactory for StablecoinFactoryContract {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        treasury_factory: Address,
+        admin_contract: Address,
+        bridge_oracle: Address,
+    ) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, StablecoinFactoryError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_treasury_factory(&e, &treasury_factory);
+        storage::set_admin_contract(&e, &admin_contract);
+        storage::set_bridge_oracle(&e, &bridge_oracle);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn launch(
+        e: Env,
+        currency: Symbol,
+        token: Address,
+        pool: Address,
+        salt: BytesN<32>,
+        reserve_config: ReserveConfig,
+        oracle_config: AssetConfig,
+    ) -> Address {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if storage::get_stablecoin(&e, &currency).is_some() {
+            panic_with_error!(&e, StablecoinFactoryError::CurrencyExists);
+        }
+
+        let treasury = TreasuryFactoryClient::new(&e, &storage::get_treasury_factory(&e))
+            .deploy(&salt, &token, &pool);
+        StellarAssetClient::new(&e, &token).set_admin(&treasury);
+        AdminClient::new(&e, &storage::get_admin_contract(&e)).queue_set_reserve(
+            &pool,
+            &token,
+            &reserve_config,
+        );
+        BridgeOracleClient::new(&e, &storage::get_bridge_oracle(&e))
+            .set_asset_config(&Asset::Stellar(token.clone()), &oracle_config);
+
+        let stablecoin = Stablecoin {
+            token,
+            treasury: treasury.clone(),
+            pool,
+        };
+        let mut currencies = storage::get_currencies(&e);
+        currencies.push_back(currency.clone());
+        storage::set_currencies(&e, &currencies);
+        storage::set_stablecoin(&e, &currency, &stablecoin);
+
+        StablecoinFactoryEvents::launch(&e, currency, stablecoin);
+        treasury
+    }
+
+    fn get_currencies(e: Env) -> Vec<Symbol> {
+        storage::extend_instance(&e);
+        storage::get_currencies(&e)
+    }
+
+    fn get_stablecoin(e: Env, currency: Symbol) -> Stablecoin {
+        storage::extend_instance(&e);
+        storage::get_stablecoin(&e, &currency)
+            .unwrap_or_else(|| panic_with_error!(&e, StablecoinFactoryError::CurrencyNotFound))
+    }
+}
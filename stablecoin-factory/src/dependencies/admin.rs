@@ -0,0 +1,9 @@
+use crate::dependencies::pool::ReserveConfig;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the admin contract the factory uses to set up reserves
+#[allow(dead_code)]
+#[contractclient(name = "AdminClient")]
+pub trait Admin {
+    fn queue_set_reserve(e: Env, pool: Address, asset: Address, config: ReserveConfig);
+}
@@ -0,0 +1,43 @@
+use sep_40_oracle::Asset;
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+// The pricing types of the bridge oracle, mirrored field for field so they encode the same
+
+/// The interface of an upstream price feed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum SourceKind {
+    Sep40,
+    Reflector,
+}
+
+/// An upstream price feed for an asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Source {
+    pub kind: SourceKind,
+    pub oracle: Address,
+    pub asset: Asset,
+    pub decimals: u32,
+    pub weight: u32,
+    pub staleness_penalty: u32,
+}
+
+/// The pricing settings of an asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AssetConfig {
+    pub sources: Vec<Source>,
+    pub min_sources: u32,
+    pub max_age: u64,
+    pub max_deviation: u32,
+    pub heartbeat: u64,
+    pub fixed_price: i128,
+}
+
+/// The part of the bridge oracle the factory uses to price new stablecoins
+#[allow(dead_code)]
+#[contractclient(name = "BridgeOracleClient")]
+pub trait BridgeOracle {
+    fn set_asset_config(env: Env, asset: Asset, config: AssetConfig);
+}
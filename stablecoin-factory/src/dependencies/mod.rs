@@ -0,0 +1,4 @@
+pub mod admin;
+pub mod bridge_oracle;
+pub use orbit_common::pool;
+pub mod treasury_factory;
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, BytesN, Env};
+
+/// The part of the treasury factory the factory uses to deploy treasuries
+#[allow(dead_code)]
+#[contractclient(name = "TreasuryFactoryClient")]
+pub trait TreasuryFactory {
+    fn deploy(e: Env, salt: BytesN<32>, token_address: Address, blend_pool: Address) -> Address;
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the stablecoin factory contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Stablecoin factory specific errors start at 3900.
+pub enum StablecoinFactoryError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Stablecoin Factory
+    CurrencyExists = 3900,
+    CurrencyNotFound = 3901,
+}
@@ -0,0 +1,17 @@
+use orbit_events::topics;
+use soroban_sdk::{Env, Symbol};
+
+use crate::storage::Stablecoin;
+
+pub struct StablecoinFactoryEvents {}
+
+impl StablecoinFactoryEvents {
+    /// Emitted when a stablecoin is launched
+    ///
+    /// - topics - `["launch", currency: Symbol]`
+    /// - data - `stablecoin: Stablecoin`
+    pub fn launch(e: &Env, currency: Symbol, stablecoin: Stablecoin) {
+        let topics = (Symbol::new(e, topics::LAUNCH), currency);
+        e.events().publish(topics, stablecoin);
+    }
+}
@@ -0,0 +1,14 @@
+#![no_std]
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+mod storage;
+mod contract;
+mod dependencies;
+mod errors;
+mod events;
+mod test;
+
+pub use contract::*;
+pub use dependencies::bridge_oracle::{AssetConfig, Source, SourceKind};
+pub use errors::StablecoinFactoryError;
+pub use storage::Stablecoin;
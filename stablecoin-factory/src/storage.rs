@@ -0,0 +1,163 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, vec, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TREASURY_FACTORY_KEY: &str = "TreasuryFactory";
+const ADMIN_CONTRACT_KEY: &str = "AdminContract";
+const BRIDGE_ORACLE_KEY: &str = "BridgeOracle";
+const CURRENCIES_KEY: &str = "Currencies";
+
+/// The contracts of a launched stablecoin
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Stablecoin {
+    pub token: Address,    // the stellar asset contract of the stablecoin
+    pub treasury: Address, // the treasury minting the stablecoin
+    pub pool: Address,     // the pool the treasury supplies the stablecoin to
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum StablecoinFactoryDataKey {
+    // The contracts of a stablecoin by currency code
+    Stablecoin(Symbol),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** External Contracts **********/
+
+/// Fetch the treasury factory Address
+///
+/// ### Panics
+/// If the treasury factory does not exist
+pub fn get_treasury_factory(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TREASURY_FACTORY_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the treasury factory Address
+///
+/// ### Arguments
+/// * `treasury_factory` - The Address for the treasury factory
+pub fn set_treasury_factory(e: &Env, treasury_factory: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TREASURY_FACTORY_KEY), treasury_factory);
+}
+
+/// Fetch the admin contract Address
+///
+/// ### Panics
+/// If the admin contract does not exist
+pub fn get_admin_contract(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, ADMIN_CONTRACT_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the admin contract Address
+///
+/// ### Arguments
+/// * `admin_contract` - The Address for the admin contract
+pub fn set_admin_contract(e: &Env, admin_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_CONTRACT_KEY), admin_contract);
+}
+
+/// Fetch the bridge oracle Address
+///
+/// ### Panics
+/// If the bridge oracle does not exist
+pub fn get_bridge_oracle(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, BRIDGE_ORACLE_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the bridge oracle Address
+///
+/// ### Arguments
+/// * `bridge_oracle` - The Address for the bridge oracle
+pub fn set_bridge_oracle(e: &Env, bridge_oracle: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, BRIDGE_ORACLE_KEY), bridge_oracle);
+}
+
+/********** Stablecoins **********/
+
+/// Fetch the currency codes of the launched stablecoins
+pub fn get_currencies(e: &Env) -> Vec<Symbol> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, CURRENCIES_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the currency codes of the launched stablecoins
+///
+/// ### Arguments
+/// * `currencies` - The currency codes
+pub fn set_currencies(e: &Env, currencies: &Vec<Symbol>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Symbol>>(&Symbol::new(e, CURRENCIES_KEY), currencies);
+}
+
+/// Fetch the contracts of a stablecoin, if it was launched
+///
+/// ### Arguments
+/// * `currency` - The currency code of the stablecoin
+pub fn get_stablecoin(e: &Env, currency: &Symbol) -> Option<Stablecoin> {
+    let key = StablecoinFactoryDataKey::Stablecoin(currency.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StablecoinFactoryDataKey, Stablecoin>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the contracts of a stablecoin
+///
+/// ### Arguments
+/// * `currency` - The currency code of the stablecoin
+/// * `stablecoin` - The contracts of the stablecoin
+pub fn set_stablecoin(e: &Env, currency: &Symbol, stablecoin: &Stablecoin) {
+    let key = StablecoinFactoryDataKey::Stablecoin(currency.clone());
+    e.storage()
+        .persistent()
+        .set::<StablecoinFactoryDataKey, Stablecoin>(&key, stablecoin);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{StablecoinFactoryClient, StablecoinFactoryContract};
+use crate::dependencies::bridge_oracle::{AssetConfig, Source, SourceKind};
+use crate::dependencies::pool::ReserveConfig;
+use crate::errors::StablecoinFactoryError;
+use sep_40_oracle::Asset;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, Symbol};
+
+/// Deploys a placeholder treasury, like the treasury factory
+#[contract]
+pub struct MockTreasuryFactory;
+
+#[contractimpl]
+impl MockTreasuryFactory {
+    pub fn deploy(
+        e: Env,
+        _salt: BytesN<32>,
+        _token_address: Address,
+        _blend_pool: Address,
+    ) -> Address {
+        let treasury = Address::generate(&e);
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "treasury"), &treasury);
+        treasury
+    }
+}
+
+/// Records the queued reserve, like the admin contract
+#[contract]
+pub struct MockAdmin;
+
+#[contractimpl]
+impl MockAdmin {
+    pub fn queue_set_reserve(e: Env, _pool: Address, asset: Address, config: ReserveConfig) {
+        e.storage().instance().set(&asset, &config);
+    }
+
+    pub fn reserve(e: Env, asset: Address) -> Option<ReserveConfig> {
+        e.storage().instance().get(&asset)
+    }
+}
+
+/// Records the asset config, like the bridge oracle
+#[contract]
+pub struct MockBridgeOracle;
+
+#[contractimpl]
+impl MockBridgeOracle {
+    pub fn set_asset_config(e: Env, asset: Asset, config: AssetConfig) {
+        e.storage().instance().set(&asset, &config);
+    }
+
+    pub fn get_asset_config(e: Env, asset: Asset) -> Option<AssetConfig> {
+        e.storage().instance().get(&asset)
+    }
+}
+
+#[test]
+fn test_launch() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let governance = Address::generate(&e);
+    let oeur = e.register_stellar_asset_contract(governance.clone());
+    let pool = Address::generate(&e);
+    let treasury_factory = e.register_contract(None, MockTreasuryFactory);
+    let admin_id = e.register_contract(None, MockAdmin);
+    let admin_client = MockAdminClient::new(&e, &admin_id);
+    let bridge_oracle = e.register_contract(None, MockBridgeOracle);
+    let bridge_oracle_client = MockBridgeOracleClient::new(&e, &bridge_oracle);
+
+    let factory_id = e.register_contract(None, StablecoinFactoryContract);
+    let factory_client = StablecoinFactoryClient::new(&e, &factory_id);
+    factory_client.initialize(&governance, &treasury_factory, &admin_id, &bridge_oracle);
+
+    let reserve_config = ReserveConfig {
+        decimals: 7,
+        c_factor: 0,
+        l_factor: 0_9500000,
+        util: 0_8000000,
+        max_util: 0_9500000,
+        r_one: 0_0100000,
+        r_two: 0_2000000,
+        r_three: 1_0000000,
+        reactivity: 0_0000020,
+        index: 0,
+    };
+    let oracle_config = AssetConfig {
+        sources: vec![
+            &e,
+            Source {
+                kind: SourceKind::Reflector,
+                oracle: Address::generate(&e),
+                asset: Asset::Other(Symbol::new(&e, "EUR")),
+                decimals: 14,
+                weight: 1,
+                staleness_penalty: 0,
+            },
+        ],
+        min_sources: 1,
+        max_age: 900,
+        max_deviation: 0_0500000,
+        heartbeat: 3600,
+        fixed_price: 0,
+    };
+    let eur = Symbol::new(&e, "EUR");
+    let salt = BytesN::<32>::from_array(&e, &[1; 32]);
+    let treasury = factory_client.launch(
+        &eur,
+        &oeur,
+        &pool,
+        &salt,
+        &reserve_config,
+        &oracle_config,
+    );
+
+    assert_eq!(StellarAssetClient::new(&e, &oeur).admin(), treasury);
+    assert_eq!(admin_client.reserve(&oeur), Some(reserve_config.clone()));
+    assert_eq!(
+        bridge_oracle_client.get_asset_config(&Asset::Stellar(oeur.clone())),
+        Some(oracle_config.clone())
+    );
+    let stablecoin = factory_client.get_stablecoin(&eur);
+    assert_eq!(stablecoin.token, oeur);
+    assert_eq!(stablecoin.treasury, treasury);
+    assert_eq!(stablecoin.pool, pool);
+    assert_eq!(factory_client.get_currencies(), vec![&e, eur.clone()]);
+
+    let result = factory_client.try_launch(
+        &eur,
+        &oeur,
+        &pool,
+        &salt,
+        &reserve_config,
+        &oracle_config,
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(StablecoinFactoryError::CurrencyExists.into()))
+    );
+    let result = factory_client.try_get_stablecoin(&Symbol::new(&e, "GBP"));
+    assert_eq!(
+        result.err(),
+        Some(Ok(StablecoinFactoryError::CurrencyNotFound.into()))
+    );
+}
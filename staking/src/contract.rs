@@ -0,0 +1,483 @@
+use crate::errors::StakingError;
+use crate::events::StakingEvents;
+use crate::storage::{
+    self, Epoch, EpochData, EpochReward, StakeWeight, StakingConfig, Unbonding,
+};
+use orbit_access::Role;
+use orbit_common::math;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, Vec};
+
+const SCALAR_12: i128 = 1_000_000_000_000;
+/// The most unstakes an address can have unbonding at once
+const MAX_UNBONDING: u32 = 20;
+
+#[contract]
+pub struct StakingContract;
+
+#[contractclient(name = "StakingClient")]
+pub trait Staking {
+    /// Initialize the staking contract. The first epoch starts immediately.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `stake_token` - The Address of the protocol token that is staked
+    /// * `config` - The epoch and unbonding settings
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the config is invalid
+    fn initialize(e: Env, admin: Address, stake_token: Address, config: StakingConfig);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Set the epoch and unbonding settings. The new epoch length applies from the
+    /// end of the current epoch.
+    ///
+    /// ### Arguments
+    /// * `config` - The epoch and unbonding settings
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the epoch length is zero
+    fn set_config(e: Env, config: StakingConfig);
+
+    /// Get the epoch and unbonding settings
+    fn get_config(e: Env) -> StakingConfig;
+
+    /// (Admin only) Add a token stakers are rewarded in
+    ///
+    /// ### Arguments
+    /// * `token` - The reward token address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the token is already a reward token
+    fn add_reward_token(e: Env, token: Address);
+
+    /// Get the tokens stakers are rewarded in
+    fn get_reward_tokens(e: Env) -> Vec<Address>;
+
+    /// Send rewards to the stakers, like peg keeping profits, PSM fees or harvested pool
+    /// interest. Rewards are distributed at the end of the current epoch, to each staker in
+    /// proportion to the tokens they staked and how long they staked them during the epoch.
+    ///
+    /// ### Arguments
+    /// * `from` - The Address sending the rewards
+    /// * `token` - The reward token
+    /// * `amount` - The amount of rewards
+    ///
+    /// ### Panics
+    /// If the token is not a reward token
+    fn notify_reward(e: Env, from: Address, token: Address, amount: i128);
+
+    /// Get the current epoch, ending any epochs that have elapsed
+    fn get_epoch(e: Env) -> Epoch;
+
+    /// Stake tokens. Staked tokens earn a share of the rewards distributed at the end of each
+    /// epoch, weighted by the time they were staked.
+    ///
+    /// ### Arguments
+    /// * `from` - The Address staking
+    /// * `amount` - The amount of tokens to stake
+    fn stake(e: Env, from: Address, amount: i128);
+
+    /// Unstake tokens. Unstaked tokens stop earning rewards and can be withdrawn once the
+    /// unbonding period ends. Each unstake unbonds on its own, so unstaking again does not delay
+    /// tokens already unbonding.
+    ///
+    /// ### Arguments
+    /// * `from` - The Address unstaking
+    /// * `amount` - The amount of tokens to unstake
+    ///
+    /// ### Panics
+    /// If `from` has staked less than `amount`
+    /// If `from` already has the most unstakes unbonding at once
+    fn unstake(e: Env, from: Address, amount: i128);
+
+    /// Withdraw the tokens that finished unbonding
+    ///
+    /// Returns the amount of tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The Address withdrawing
+    ///
+    /// ### Panics
+    /// If no tokens finished unbonding
+    fn withdraw(e: Env, from: Address) -> i128;
+
+    /// Claim accrued rewards
+    ///
+    /// Returns the amount of each reward token claimed
+    ///
+    /// ### Arguments
+    /// * `from` - The Address claiming
+    fn claim(e: Env, from: Address) -> Vec<(Address, i128)>;
+
+    /// Get the tokens staked by an address
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the staker
+    fn get_stake(e: Env, user: Address) -> i128;
+
+    /// Get the total amount of staked tokens
+    fn get_total_staked(e: Env) -> i128;
+
+    /// Get the tokens an address is unbonding, oldest first
+    ///
+    /// ### Arguments
+    /// * `user` - The Address of the staker
+    fn get_unbonding(e: Env, user: Address) -> Vec<Unbonding>;
+}
+
+#[contractimpl]
+impl Staking for StakingContract {
+    fn initialize(e: Env, admin: Address, stake_token: Address, config: StakingConfig) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, StakingError::AlreadyInitializedError);
+        }
+        require_valid_config(&e, &config);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_stake_token(&e, &stake_token);
+        storage::set_config(&e, &config);
+        storage::set_epoch(
+            &e,
+            &Epoch {
+                number: 0,
+                start: e.ledger().timestamp(),
+            },
+        );
+        storage::set_total_weight(
+            &e,
+            &StakeWeight {
+                epoch: 0,
+                weight: 0,
+                last_update: e.ledger().timestamp(),
+            },
+        );
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_config(e: Env, config: StakingConfig) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        require_valid_config(&e, &config);
+        roll_epoch(&e);
+
+        storage::set_config(&e, &config);
+
+        StakingEvents::set_config(&e, config);
+    }
+
+    fn get_config(e: Env) -> StakingConfig {
+        storage::extend_instance(&e);
+        storage::get_config(&e)
+    }
+
+    fn add_reward_token(e: Env, token: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let mut tokens = storage::get_reward_tokens(&e);
+        if tokens.contains(&token) {
+            panic_with_error!(&e, StakingError::InvalidRewardToken);
+        }
+        tokens.push_back(token.clone());
+        storage::set_reward_tokens(&e, &tokens);
+
+        StakingEvents::add_reward_token(&e, token);
+    }
+
+    fn get_reward_tokens(e: Env) -> Vec<Address> {
+        storage::extend_instance(&e);
+        storage::get_reward_tokens(&e)
+    }
+
+    fn notify_reward(e: Env, from: Address, token: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        if !storage::get_reward_tokens(&e).contains(&token) {
+            panic_with_error!(&e, StakingError::InvalidRewardToken);
+        }
+        roll_epoch(&e);
+
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        let mut data = storage::get_reward_data(&e, &token);
+        data.pending += amount;
+        storage::set_reward_data(&e, &token, &data);
+
+        StakingEvents::notify_reward(&e, token, from, amount);
+    }
+
+    fn get_epoch(e: Env) -> Epoch {
+        storage::extend_instance(&e);
+        roll_epoch(&e)
+    }
+
+    fn stake(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        roll_epoch(&e);
+
+        let stake = storage::get_stake(&e, &from);
+        accrue_user(&e, &from, stake);
+        accrue_total_weight(&e);
+        TokenClient::new(&e, &storage::get_stake_token(&e)).transfer(
+            &from,
+            &e.current_contract_address(),
+            &amount,
+        );
+        storage::set_stake(&e, &from, stake + amount);
+        storage::set_total_staked(&e, storage::get_total_staked(&e) + amount);
+
+        StakingEvents::stake(&e, from, amount);
+    }
+
+    fn unstake(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+        roll_epoch(&e);
+
+        let stake = storage::get_stake(&e, &from);
+        if stake < amount {
+            panic_with_error!(&e, StakingError::InsufficientStake);
+        }
+        let mut unbonding = storage::get_unbonding(&e, &from);
+        if unbonding.len() >= MAX_UNBONDING {
+            panic_with_error!(&e, StakingError::UnbondingLimit);
+        }
+        accrue_user(&e, &from, stake);
+        accrue_total_weight(&e);
+        storage::set_stake(&e, &from, stake - amount);
+        storage::set_total_staked(&e, storage::get_total_staked(&e) - amount);
+
+        let unlock_time = e.ledger().timestamp() + storage::get_config(&e).unbonding_period;
+        unbonding.push_back(Unbonding {
+            amount,
+            unlock_time,
+        });
+        storage::set_unbonding(&e, &from, &unbonding);
+
+        StakingEvents::unstake(&e, from, amount, unlock_time);
+    }
+
+    fn withdraw(e: Env, from: Address) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let now = e.ledger().timestamp();
+        let mut amount = 0;
+        let mut unbonding = vec![&e];
+        for entry in storage::get_unbonding(&e, &from).iter() {
+            if entry.unlock_time <= now {
+                amount += entry.amount;
+            } else {
+                unbonding.push_back(entry);
+            }
+        }
+        if amount == 0 {
+            panic_with_error!(&e, StakingError::UnbondingNotExpired);
+        }
+
+        if unbonding.is_empty() {
+            storage::del_unbonding(&e, &from);
+        } else {
+            storage::set_unbonding(&e, &from, &unbonding);
+        }
+        TokenClient::new(&e, &storage::get_stake_token(&e)).transfer(
+            &e.current_contract_address(),
+            &from,
+            &amount,
+        );
+
+        StakingEvents::withdraw(&e, from, amount);
+        amount
+    }
+
+    fn claim(e: Env, from: Address) -> Vec<(Address, i128)> {
+        storage::extend_instance(&e);
+        from.require_auth();
+        roll_epoch(&e);
+
+        accrue_user(&e, &from, storage::get_stake(&e, &from));
+        let mut claimed = vec![&e];
+        for token in storage::get_reward_tokens(&e).iter() {
+            let mut user_data = storage::get_user_reward_data(&e, &from, &token);
+            if user_data.accrued > 0 {
+                TokenClient::new(&e, &token).transfer(
+                    &e.current_contract_address(),
+                    &from,
+                    &user_data.accrued,
+                );
+                claimed.push_back((token.clone(), user_data.accrued));
+                user_data.accrued = 0;
+                storage::set_user_reward_data(&e, &from, &token, &user_data);
+            }
+        }
+
+        StakingEvents::claim(&e, from, claimed.clone());
+        claimed
+    }
+
+    fn get_stake(e: Env, user: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_stake(&e, &user)
+    }
+
+    fn get_total_staked(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_total_staked(&e)
+    }
+
+    fn get_unbonding(e: Env, user: Address) -> Vec<Unbonding> {
+        storage::extend_instance(&e);
+        storage::get_unbonding(&e, &user)
+    }
+}
+
+/// End any elapsed epochs and distribute their rewards to the stakers by the token-seconds
+/// they staked. Epochs that elapsed without a call in between end together, as one epoch.
+/// Rewards received while nothing is staked carry over to the next epoch.
+///
+/// Returns the current epoch
+fn roll_epoch(e: &Env) -> Epoch {
+    let mut epoch = storage::get_epoch(e);
+    let epoch_length = storage::get_config(e).epoch_length;
+    let now = e.ledger().timestamp();
+    if now < epoch.start + epoch_length {
+        return epoch;
+    }
+
+    let elapsed = (now - epoch.start) / epoch_length;
+    let end = epoch.start + elapsed * epoch_length;
+    let total_staked = storage::get_total_staked(e);
+    let total_weight = storage::get_total_weight(e);
+    let weight = total_weight.weight + total_staked * (end - total_weight.last_update) as i128;
+    storage::set_epoch_data(e, epoch.number, &EpochData { end, weight });
+    if weight > 0 {
+        // a token staked for the whole epoch earns its length over the weight of the rewards
+        let duration = (end - epoch.start) as i128;
+        for token in storage::get_reward_tokens(e).iter() {
+            let mut data = storage::get_reward_data(e, &token);
+            if data.pending > 0 {
+                data.index += math::mul_div_floor(e, data.pending * SCALAR_12, duration, weight);
+                let reward = EpochReward {
+                    amount: data.pending,
+                    index: data.index,
+                };
+                storage::set_epoch_reward(e, &token, epoch.number, &reward);
+                data.pending = 0;
+                storage::set_reward_data(e, &token, &data);
+            }
+        }
+    }
+    epoch.number += elapsed as u32;
+    epoch.start = end;
+    storage::set_epoch(e, &epoch);
+    storage::set_total_weight(
+        e,
+        &StakeWeight {
+            epoch: epoch.number,
+            weight: total_staked * (now - end) as i128,
+            last_update: now,
+        },
+    );
+
+    StakingEvents::new_epoch(e, epoch.number, epoch.start, total_staked);
+    epoch
+}
+
+/// Accrue the token-seconds staked in the current epoch up to now. Call before the total
+/// staked changes, after rolling the epoch.
+fn accrue_total_weight(e: &Env) {
+    let mut total_weight = storage::get_total_weight(e);
+    let now = e.ledger().timestamp();
+    total_weight.weight += storage::get_total_staked(e) * (now - total_weight.last_update) as i128;
+    total_weight.last_update = now;
+    storage::set_total_weight(e, &total_weight);
+}
+
+/// Accrue the rewards distributed to a staker since their last update, and the token-seconds
+/// they staked in the current epoch. Call before their stake changes, after rolling the epoch.
+///
+/// A staker is paid for the epoch of their last update by their share of its weight, and for
+/// each later ended epoch, which they were staked for in full, through the reward index.
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `stake` - The tokens staked by the staker since their last update
+fn accrue_user(e: &Env, user: &Address, stake: i128) {
+    let epoch = storage::get_epoch(e);
+    let now = e.ledger().timestamp();
+    let mut user_weight = storage::get_user_weight(e, user);
+    if user_weight.epoch == epoch.number {
+        user_weight.weight += stake * (now - user_weight.last_update) as i128;
+    } else {
+        // the epoch of the last update ended, so pay out the part staked in it. Addresses that
+        // had nothing staked skip it, as the data of their epoch may be long archived.
+        let last_epoch = (stake > 0 || user_weight.weight > 0).then(|| {
+            let data = storage::get_epoch_data(e, user_weight.epoch);
+            let weight = user_weight.weight + stake * (data.end - user_weight.last_update) as i128;
+            (data.weight, weight)
+        });
+        for token in storage::get_reward_tokens(e).iter() {
+            let index = storage::get_reward_data(e, &token).index;
+            let mut user_data = storage::get_user_reward_data(e, user, &token);
+            let mut index_after = user_data.index;
+            if let Some((epoch_weight, weight)) = last_epoch {
+                if let Some(reward) = storage::get_epoch_reward(e, &token, user_weight.epoch) {
+                    user_data.accrued +=
+                        math::mul_div_floor(e, weight, reward.amount, epoch_weight);
+                    index_after = reward.index;
+                }
+            }
+            user_data.accrued += math::mul_div_floor(e, stake, index - index_after, SCALAR_12);
+            user_data.index = index;
+            storage::set_user_reward_data(e, user, &token, &user_data);
+        }
+        user_weight.epoch = epoch.number;
+        user_weight.weight = stake * (now - epoch.start) as i128;
+    }
+    user_weight.last_update = now;
+    storage::set_user_weight(e, user, &user_weight);
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, StakingError::NegativeAmountError);
+    }
+}
+
+/// Require that the epoch and unbonding settings are valid
+///
+/// ### Panics
+/// If the epoch length is zero
+fn require_valid_config(e: &Env, config: &StakingConfig) {
+    if config.epoch_length == 0 {
+        panic_with_error!(e, StakingError::InvalidConfig);
+    }
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the staking contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Staking specific errors start at 2500.
+pub enum StakingError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Staking
+    InsufficientStake = 2500,
+    UnbondingNotExpired = 2501,
+    InvalidRewardToken = 2502,
+    InvalidConfig = 2503,
+    UnbondingLimit = 2504,
+}
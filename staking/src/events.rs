@@ -0,0 +1,80 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::StakingConfig;
+
+pub struct StakingEvents {}
+
+impl StakingEvents {
+    /// Emitted when the admin updates the epoch and unbonding settings
+    ///
+    /// - topics - `["set_config"]`
+    /// - data - `config: StakingConfig`
+    pub fn set_config(e: &Env, config: StakingConfig) {
+        let topics = (Symbol::new(e, topics::SET_CONFIG),);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin adds a reward token
+    ///
+    /// - topics - `["add_reward_token"]`
+    /// - data - `token: Address`
+    pub fn add_reward_token(e: &Env, token: Address) {
+        let topics = (Symbol::new(e, topics::ADD_REWARD_TOKEN),);
+        e.events().publish(topics, token);
+    }
+
+    /// Emitted when rewards are sent to the stakers
+    ///
+    /// - topics - `["notify_reward", token: Address]`
+    /// - data - `[from: Address, amount: i128]`
+    pub fn notify_reward(e: &Env, token: Address, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::NOTIFY_REWARD), token);
+        e.events().publish(topics, (from, amount));
+    }
+
+    /// Emitted when an epoch ends and its rewards are distributed
+    ///
+    /// - topics - `["new_epoch", epoch: u32]`
+    /// - data - `[start: u64, total_staked: i128]`
+    pub fn new_epoch(e: &Env, epoch: u32, start: u64, total_staked: i128) {
+        let topics = (Symbol::new(e, topics::NEW_EPOCH), epoch);
+        e.events().publish(topics, (start, total_staked));
+    }
+
+    /// Emitted when tokens are staked
+    ///
+    /// - topics - `["stake", from: Address]`
+    /// - data - `amount: i128`
+    pub fn stake(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::STAKE), from);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when tokens are unstaked and start unbonding
+    ///
+    /// - topics - `["unstake", from: Address]`
+    /// - data - `[amount: i128, unlock_time: u64]`
+    pub fn unstake(e: &Env, from: Address, amount: i128, unlock_time: u64) {
+        let topics = (Symbol::new(e, topics::UNSTAKE), from);
+        e.events().publish(topics, (amount, unlock_time));
+    }
+
+    /// Emitted when unbonded tokens are withdrawn
+    ///
+    /// - topics - `["withdraw", from: Address]`
+    /// - data - `amount: i128`
+    pub fn withdraw(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), from);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a staker claims rewards
+    ///
+    /// - topics - `["claim", from: Address]`
+    /// - data - `claimed: Vec<(Address, i128)>`
+    pub fn claim(e: &Env, from: Address, claimed: Vec<(Address, i128)>) {
+        let topics = (Symbol::new(e, topics::CLAIM), from);
+        e.events().publish(topics, claimed);
+    }
+}
@@ -0,0 +1,516 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, vec, Address, Env, Symbol, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const STAKE_TOKEN_KEY: &str = "StakeToken";
+const CONFIG_KEY: &str = "Config";
+const EPOCH_KEY: &str = "Epoch";
+const REWARD_TOKENS_KEY: &str = "RewardTokens";
+const TOTAL_STAKED_KEY: &str = "TotalStaked";
+const TOTAL_WEIGHT_KEY: &str = "TotalWeight";
+
+/// The epoch and unbonding settings
+#[derive(Clone)]
+#[contracttype]
+pub struct StakingConfig {
+    pub epoch_length: u64,     // the length of an epoch, in seconds
+    pub unbonding_period: u64, // the time unstaked tokens stay locked before they can be withdrawn, in seconds
+}
+
+/// The current reward epoch. Rewards received during an epoch are distributed to stakers
+/// when it ends.
+#[derive(Clone)]
+#[contracttype]
+pub struct Epoch {
+    pub number: u32,
+    pub start: u64,
+}
+
+/// The time weight of staked tokens within an epoch, in token-seconds
+#[derive(Clone)]
+#[contracttype]
+pub struct StakeWeight {
+    pub epoch: u32,       // the epoch the weight was accrued in
+    pub weight: i128,     // the token-seconds staked in the epoch up to `last_update`
+    pub last_update: u64, // the time the weight was last accrued
+}
+
+/// An ended epoch, kept so stakers can accrue the rewards of the part they staked
+#[derive(Clone)]
+#[contracttype]
+pub struct EpochData {
+    pub end: u64,     // the time the epoch ended
+    pub weight: i128, // the token-seconds staked over the epoch
+}
+
+/// The distribution state of a reward token
+#[derive(Clone)]
+#[contracttype]
+pub struct RewardData {
+    pub index: i128,   // the rewards per token staked through every epoch, with 12 decimals
+    pub pending: i128, // the rewards received and not yet distributed
+}
+
+/// The rewards of a reward token distributed at the end of an epoch
+#[derive(Clone)]
+#[contracttype]
+pub struct EpochReward {
+    pub amount: i128, // the rewards distributed
+    pub index: i128,  // the reward index after the epoch
+}
+
+/// The reward state of a staker for a reward token
+#[derive(Clone)]
+#[contracttype]
+pub struct UserRewardData {
+    pub index: i128,   // the reward index the staker last accrued at
+    pub accrued: i128, // the rewards accrued and not yet claimed
+}
+
+/// Tokens unstaked together and waiting out the unbonding period
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Unbonding {
+    pub amount: i128,
+    pub unlock_time: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum StakingDataKey {
+    // An ended epoch
+    Epoch(u32),
+    // The rewards of a reward token distributed at the end of an epoch
+    EpochReward(Address, u32),
+    // The distribution state of a reward token
+    Reward(Address),
+    // The tokens staked by an address
+    Stake(Address),
+    // The reward state of a staker for a reward token
+    UserReward(Address, Address),
+    // The tokens an address is unbonding
+    Unbonding(Address),
+    // The time weight of the tokens staked by an address in its last epoch
+    UserWeight(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Settings **********/
+
+/// Fetch the Address of the staked token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_stake_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, STAKE_TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the staked token
+///
+/// ### Arguments
+/// * `token` - The Address of the staked token
+pub fn set_stake_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, STAKE_TOKEN_KEY), token);
+}
+
+/// Fetch the epoch and unbonding settings
+///
+/// ### Panics
+/// If the config does not exist
+pub fn get_config(e: &Env) -> StakingConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, CONFIG_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the epoch and unbonding settings
+///
+/// ### Arguments
+/// * `config` - The epoch and unbonding settings
+pub fn set_config(e: &Env, config: &StakingConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, StakingConfig>(&Symbol::new(e, CONFIG_KEY), config);
+}
+
+/// Fetch the reward tokens
+pub fn get_reward_tokens(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, REWARD_TOKENS_KEY))
+        .unwrap_or(vec![e])
+}
+
+/// Set the reward tokens
+///
+/// ### Arguments
+/// * `tokens` - The reward token addresses
+pub fn set_reward_tokens(e: &Env, tokens: &Vec<Address>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, REWARD_TOKENS_KEY), tokens);
+}
+
+/********** Epoch **********/
+
+/// Fetch the current epoch
+///
+/// ### Panics
+/// If the epoch does not exist
+pub fn get_epoch(e: &Env) -> Epoch {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, EPOCH_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the current epoch
+///
+/// ### Arguments
+/// * `epoch` - The current epoch
+pub fn set_epoch(e: &Env, epoch: &Epoch) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Epoch>(&Symbol::new(e, EPOCH_KEY), epoch);
+}
+
+/// Fetch an ended epoch
+///
+/// ### Arguments
+/// * `number` - The number of the epoch
+///
+/// ### Panics
+/// If the epoch has not ended
+pub fn get_epoch_data(e: &Env, number: u32) -> EpochData {
+    let key = StakingDataKey::Epoch(number);
+    let data = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, EpochData>(&key)
+        .unwrap_optimized();
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    data
+}
+
+/// Set an ended epoch
+///
+/// ### Arguments
+/// * `number` - The number of the epoch
+/// * `data` - The ended epoch
+pub fn set_epoch_data(e: &Env, number: u32, data: &EpochData) {
+    let key = StakingDataKey::Epoch(number);
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, EpochData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/********** Stakes **********/
+
+/// Fetch the total amount of staked tokens
+pub fn get_total_staked(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_STAKED_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the total amount of staked tokens
+///
+/// ### Arguments
+/// * `amount` - The total amount of staked tokens
+pub fn set_total_staked(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, TOTAL_STAKED_KEY), &amount);
+}
+
+/// Fetch the time weight of all staked tokens in the current epoch
+pub fn get_total_weight(e: &Env) -> StakeWeight {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_WEIGHT_KEY))
+        .unwrap_or(StakeWeight {
+            epoch: 0,
+            weight: 0,
+            last_update: 0,
+        })
+}
+
+/// Set the time weight of all staked tokens in the current epoch
+///
+/// ### Arguments
+/// * `weight` - The time weight
+pub fn set_total_weight(e: &Env, weight: &StakeWeight) {
+    e.storage()
+        .instance()
+        .set::<Symbol, StakeWeight>(&Symbol::new(e, TOTAL_WEIGHT_KEY), weight);
+}
+
+/// Fetch the time weight of the tokens staked by an address in its last epoch
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+pub fn get_user_weight(e: &Env, user: &Address) -> StakeWeight {
+    let key = StakingDataKey::UserWeight(user.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, StakeWeight>(&key);
+    match result {
+        Some(weight) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            weight
+        }
+        None => StakeWeight {
+            epoch: 0,
+            weight: 0,
+            last_update: 0,
+        },
+    }
+}
+
+/// Set the time weight of the tokens staked by an address in the current epoch
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `weight` - The time weight
+pub fn set_user_weight(e: &Env, user: &Address, weight: &StakeWeight) {
+    let key = StakingDataKey::UserWeight(user.clone());
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, StakeWeight>(&key, weight);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the tokens staked by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+pub fn get_stake(e: &Env, user: &Address) -> i128 {
+    let key = StakingDataKey::Stake(user.clone());
+    let result = e.storage().persistent().get::<StakingDataKey, i128>(&key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Set the tokens staked by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `amount` - The amount of tokens staked
+pub fn set_stake(e: &Env, user: &Address, amount: i128) {
+    let key = StakingDataKey::Stake(user.clone());
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, i128>(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the tokens an address is unbonding, oldest first
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+pub fn get_unbonding(e: &Env, user: &Address) -> Vec<Unbonding> {
+    let key = StakingDataKey::Unbonding(user.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, Vec<Unbonding>>(&key);
+    match result {
+        Some(unbonding) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            unbonding
+        }
+        None => vec![e],
+    }
+}
+
+/// Set the tokens an address is unbonding
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `unbonding` - The tokens unbonding, oldest first
+pub fn set_unbonding(e: &Env, user: &Address, unbonding: &Vec<Unbonding>) {
+    let key = StakingDataKey::Unbonding(user.clone());
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, Vec<Unbonding>>(&key, unbonding);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Remove the tokens an address is unbonding
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+pub fn del_unbonding(e: &Env, user: &Address) {
+    let key = StakingDataKey::Unbonding(user.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/********** Rewards **********/
+
+/// Fetch the distribution state of a reward token
+///
+/// ### Arguments
+/// * `token` - The Address of the reward token
+pub fn get_reward_data(e: &Env, token: &Address) -> RewardData {
+    let key = StakingDataKey::Reward(token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, RewardData>(&key);
+    match result {
+        Some(data) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+            data
+        }
+        None => RewardData {
+            index: 0,
+            pending: 0,
+        },
+    }
+}
+
+/// Set the distribution state of a reward token
+///
+/// ### Arguments
+/// * `token` - The Address of the reward token
+/// * `data` - The distribution state
+pub fn set_reward_data(e: &Env, token: &Address, data: &RewardData) {
+    let key = StakingDataKey::Reward(token.clone());
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, RewardData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the rewards of a reward token distributed at the end of an epoch
+///
+/// ### Arguments
+/// * `token` - The Address of the reward token
+/// * `number` - The number of the epoch
+pub fn get_epoch_reward(e: &Env, token: &Address, number: u32) -> Option<EpochReward> {
+    let key = StakingDataKey::EpochReward(token.clone(), number);
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, EpochReward>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set the rewards of a reward token distributed at the end of an epoch
+///
+/// ### Arguments
+/// * `token` - The Address of the reward token
+/// * `number` - The number of the epoch
+/// * `reward` - The rewards distributed
+pub fn set_epoch_reward(e: &Env, token: &Address, number: u32, reward: &EpochReward) {
+    let key = StakingDataKey::EpochReward(token.clone(), number);
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, EpochReward>(&key, reward);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the reward state of a staker for a reward token
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `token` - The Address of the reward token
+pub fn get_user_reward_data(e: &Env, user: &Address, token: &Address) -> UserRewardData {
+    let key = StakingDataKey::UserReward(user.clone(), token.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<StakingDataKey, UserRewardData>(&key);
+    match result {
+        Some(data) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            data
+        }
+        None => UserRewardData {
+            index: 0,
+            accrued: 0,
+        },
+    }
+}
+
+/// Set the reward state of a staker for a reward token
+///
+/// ### Arguments
+/// * `user` - The Address of the staker
+/// * `token` - The Address of the reward token
+/// * `data` - The reward state
+pub fn set_user_reward_data(e: &Env, user: &Address, token: &Address, data: &UserRewardData) {
+    let key = StakingDataKey::UserReward(user.clone(), token.clone());
+    e.storage()
+        .persistent()
+        .set::<StakingDataKey, UserRewardData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
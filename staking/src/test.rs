@@ -0,0 +1,203 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use crate::contract::{StakingClient, StakingContract};
+use crate::errors::StakingError;
+use crate::storage::{StakingConfig, Unbonding};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+/// Create a staking contract rewarding stakers in one token, returning the staking client and
+/// the stake token and reward token addresses
+fn create_staking<'a>(e: &Env) -> (StakingClient<'a>, Address, Address) {
+    let token_admin = Address::generate(e);
+    let stake_token = e.register_stellar_asset_contract(token_admin.clone());
+    let reward_token = e.register_stellar_asset_contract(token_admin);
+
+    let staking_id = e.register_contract(None, StakingContract);
+    let staking_client = StakingClient::new(e, &staking_id);
+    staking_client.initialize(
+        &Address::generate(e),
+        &stake_token,
+        &StakingConfig {
+            epoch_length: WEEK,
+            unbonding_period: 2 * WEEK,
+        },
+    );
+    staking_client.add_reward_token(&reward_token);
+    (staking_client, stake_token, reward_token)
+}
+
+#[test]
+fn test_rewards_by_epoch() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (staking_client, stake_token, reward_token) = create_staking(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    StellarAssetClient::new(&e, &stake_token).mint(&samwise, &100_0000000);
+    StellarAssetClient::new(&e, &stake_token).mint(&frodo, &300_0000000);
+    StellarAssetClient::new(&e, &reward_token).mint(&keeper, &1000_0000000);
+
+    // rewards sent while nothing is staked carry over
+    staking_client.notify_reward(&keeper, &reward_token, &200_0000000);
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(staking_client.get_epoch().number, 1);
+
+    staking_client.stake(&samwise, &100_0000000);
+    staking_client.stake(&frodo, &300_0000000);
+    staking_client.notify_reward(&keeper, &reward_token, &200_0000000);
+    assert_eq!(staking_client.get_total_staked(), 400_0000000);
+
+    // nothing is distributed until the epoch ends
+    assert_eq!(staking_client.claim(&samwise), vec![&e]);
+
+    e.ledger().with_mut(|li| li.timestamp += WEEK + 10);
+    let epoch = staking_client.get_epoch();
+    assert_eq!(epoch.number, 2);
+    assert_eq!(epoch.start, 1000 + 2 * WEEK);
+    assert_eq!(
+        staking_client.claim(&samwise),
+        vec![&e, (reward_token.clone(), 100_0000000)]
+    );
+    assert_eq!(
+        staking_client.claim(&frodo),
+        vec![&e, (reward_token.clone(), 300_0000000)]
+    );
+    assert_eq!(TokenClient::new(&e, &reward_token).balance(&frodo), 300_0000000);
+    assert_eq!(staking_client.claim(&frodo), vec![&e]);
+
+    // samwise unstakes half way through the next epoch, so earns for half of it
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 2 * WEEK + WEEK / 2);
+    staking_client.unstake(&samwise, &100_0000000);
+    staking_client.notify_reward(&keeper, &reward_token, &140_0000000);
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(
+        staking_client.claim(&samwise),
+        vec![&e, (reward_token.clone(), 20_0000000)]
+    );
+    assert_eq!(
+        staking_client.claim(&frodo),
+        vec![&e, (reward_token.clone(), 120_0000000)]
+    );
+
+    // with nothing left to accrue, later epochs pay samwise nothing
+    staking_client.notify_reward(&keeper, &reward_token, &30_0000000);
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(staking_client.claim(&samwise), vec![&e]);
+    assert_eq!(
+        staking_client.claim(&frodo),
+        vec![&e, (reward_token.clone(), 30_0000000)]
+    );
+
+    let result = staking_client.try_notify_reward(&keeper, &stake_token, &1_0000000);
+    assert_eq!(result.err(), Some(Ok(StakingError::InvalidRewardToken.into())));
+}
+
+#[test]
+fn test_rewards_by_time_staked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (staking_client, stake_token, reward_token) = create_staking(&e);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    StellarAssetClient::new(&e, &stake_token).mint(&samwise, &100_0000000);
+    StellarAssetClient::new(&e, &stake_token).mint(&frodo, &100_0000000);
+    StellarAssetClient::new(&e, &stake_token).mint(&merry, &1000_0000000);
+    StellarAssetClient::new(&e, &reward_token).mint(&keeper, &1000_0000000);
+
+    // frodo stakes for the whole epoch and samwise for its second half, so frodo's stake
+    // weighs twice as much
+    staking_client.stake(&frodo, &100_0000000);
+    e.ledger().with_mut(|li| li.timestamp += WEEK / 2);
+    staking_client.stake(&samwise, &100_0000000);
+    staking_client.notify_reward(&keeper, &reward_token, &300_0000000);
+
+    // merry stakes 10 times as much a second before the epoch ends, and earns next to nothing
+    e.ledger().with_mut(|li| li.timestamp += WEEK / 2 - 1);
+    staking_client.stake(&merry, &1000_0000000);
+    e.ledger().with_mut(|li| li.timestamp += 1);
+    assert_eq!(staking_client.get_epoch().number, 1);
+    assert_eq!(
+        staking_client.claim(&merry),
+        vec![&e, (reward_token.clone(), 0_0033068)]
+    );
+
+    // samwise accrues the half epoch and the whole next epoch at once
+    staking_client.notify_reward(&keeper, &reward_token, &120_0000000);
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(
+        staking_client.claim(&samwise),
+        vec![&e, (reward_token.clone(), 99_9988977 + 10_0000000)]
+    );
+    assert_eq!(
+        staking_client.claim(&frodo),
+        vec![&e, (reward_token.clone(), 199_9977954 + 10_0000000)]
+    );
+    assert_eq!(
+        staking_client.claim(&merry),
+        vec![&e, (reward_token.clone(), 100_0000000)]
+    );
+}
+
+#[test]
+fn test_unbonding() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (staking_client, stake_token, _) = create_staking(&e);
+    let samwise = Address::generate(&e);
+    StellarAssetClient::new(&e, &stake_token).mint(&samwise, &100_0000000);
+    staking_client.stake(&samwise, &100_0000000);
+
+    let result = staking_client.try_unstake(&samwise, &100_0000001);
+    assert_eq!(result.err(), Some(Ok(StakingError::InsufficientStake.into())));
+
+    staking_client.unstake(&samwise, &40_0000000);
+    assert_eq!(staking_client.get_stake(&samwise), 60_0000000);
+    let first = Unbonding {
+        amount: 40_0000000,
+        unlock_time: 1000 + 2 * WEEK,
+    };
+    assert_eq!(staking_client.get_unbonding(&samwise), vec![&e, first.clone()]);
+    let result = staking_client.try_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(StakingError::UnbondingNotExpired.into())));
+
+    // unstaking again unbonds on its own, without delaying the first unstake
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    staking_client.unstake(&samwise, &10_0000000);
+    let second = Unbonding {
+        amount: 10_0000000,
+        unlock_time: 1000 + 3 * WEEK,
+    };
+    assert_eq!(
+        staking_client.get_unbonding(&samwise),
+        vec![&e, first, second.clone()]
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(staking_client.withdraw(&samwise), 40_0000000);
+    assert_eq!(TokenClient::new(&e, &stake_token).balance(&samwise), 40_0000000);
+    assert_eq!(staking_client.get_unbonding(&samwise), vec![&e, second]);
+    let result = staking_client.try_withdraw(&samwise);
+    assert_eq!(result.err(), Some(Ok(StakingError::UnbondingNotExpired.into())));
+
+    e.ledger().with_mut(|li| li.timestamp += WEEK);
+    assert_eq!(staking_client.withdraw(&samwise), 10_0000000);
+    assert_eq!(TokenClient::new(&e, &stake_token).balance(&samwise), 50_0000000);
+    assert_eq!(staking_client.get_unbonding(&samwise), vec![&e]);
+
+    // an address can only have so many unstakes unbonding at once
+    for _ in 0..20 {
+        staking_client.unstake(&samwise, &1_0000000);
+    }
+    let result = staking_client.try_unstake(&samwise, &1_0000000);
+    assert_eq!(result.err(), Some(Ok(StakingError::UnbondingLimit.into())));
+}
@@ -0,0 +1,215 @@
+use crate::errors::StreamsError;
+use crate::events::StreamsEvents;
+use crate::storage::{self, Stream};
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct StreamsContract;
+
+#[contractclient(name = "StreamsClient")]
+pub trait Streams {
+    /// Initialize the streams contract. The admin funds streams that pay a recipient a fixed
+    /// amount of tokens every second, e.g. for grants and salaries, and can cancel them at any
+    /// time.
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually governance
+    /// * `token` - The Address of the token streamed
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Create a stream, funding it in full from the admin
+    ///
+    /// Returns the id of the stream
+    ///
+    /// ### Arguments
+    /// * `recipient` - The Address paid by the stream
+    /// * `rate` - The tokens streamed per second
+    /// * `start` - The time the stream starts
+    /// * `end` - The time the stream ends
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the rate is zero or negative
+    /// If the stream does not end after it starts and after now
+    fn create(e: Env, recipient: Address, rate: i128, start: u64, end: u64) -> u32;
+
+    /// Get an active stream
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the stream
+    ///
+    /// ### Panics
+    /// If the stream does not exist
+    fn get_stream(e: Env, id: u32) -> Stream;
+
+    /// Get the tokens streamed to the recipient and not withdrawn yet
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the stream
+    ///
+    /// ### Panics
+    /// If the stream does not exist
+    fn get_accrued(e: Env, id: u32) -> i128;
+
+    /// (Recipient only) Withdraw the tokens streamed so far. The stream is removed once it has
+    /// ended and been withdrawn in full.
+    ///
+    /// Returns the tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the stream
+    ///
+    /// ### Panics
+    /// If the stream does not exist
+    fn withdraw(e: Env, id: u32) -> i128;
+
+    /// (Admin only) Cancel a stream, paying the recipient the tokens streamed so far and
+    /// refunding the rest to the admin
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the stream
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the stream does not exist
+    fn cancel(e: Env, id: u32);
+}
+
+#[contractimpl]
+impl Streams for StreamsContract {
+    fn initialize(e: Env, admin: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, StreamsError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn create(e: Env, recipient: Address, rate: i128, start: u64, end: u64) -> u32 {
+        storage::extend_instance(&e);
+        let admin = orbit_access::require_role(&e, Role::Admin);
+        if rate <= 0 {
+            panic_with_error!(&e, StreamsError::NegativeAmountError);
+        }
+        if end <= start || end <= e.ledger().timestamp() {
+            panic_with_error!(&e, StreamsError::InvalidSchedule);
+        }
+
+        let deposit = rate * (end - start) as i128;
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &admin,
+            &e.current_contract_address(),
+            &deposit,
+        );
+        let id = storage::get_next_id(&e);
+        storage::set_next_id(&e, id + 1);
+        let stream = Stream {
+            recipient: recipient.clone(),
+            rate,
+            start,
+            end,
+            withdrawn: 0,
+        };
+        storage::set_stream(&e, id, &stream);
+
+        StreamsEvents::create(&e, id, recipient, rate, start, end);
+        id
+    }
+
+    fn get_stream(e: Env, id: u32) -> Stream {
+        storage::extend_instance(&e);
+        load_stream(&e, id)
+    }
+
+    fn get_accrued(e: Env, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        let stream = load_stream(&e, id);
+        calc_streamed(&e, &stream) - stream.withdrawn
+    }
+
+    fn withdraw(e: Env, id: u32) -> i128 {
+        storage::extend_instance(&e);
+        let mut stream = load_stream(&e, id);
+        stream.recipient.require_auth();
+
+        let amount = calc_streamed(&e, &stream) - stream.withdrawn;
+        stream.withdrawn += amount;
+        if e.ledger().timestamp() >= stream.end {
+            storage::del_stream(&e, id);
+        } else {
+            storage::set_stream(&e, id, &stream);
+        }
+        if amount > 0 {
+            TokenClient::new(&e, &storage::get_token(&e)).transfer(
+                &e.current_contract_address(),
+                &stream.recipient,
+                &amount,
+            );
+        }
+
+        StreamsEvents::withdraw(&e, id, stream.recipient, amount);
+        amount
+    }
+
+    fn cancel(e: Env, id: u32) {
+        storage::extend_instance(&e);
+        let admin = orbit_access::require_role(&e, Role::Admin);
+
+        let stream = load_stream(&e, id);
+        let streamed = calc_streamed(&e, &stream);
+        let paid = streamed - stream.withdrawn;
+        let refunded = stream.rate * (stream.end - stream.start) as i128 - streamed;
+        storage::del_stream(&e, id);
+        let token_client = TokenClient::new(&e, &storage::get_token(&e));
+        if paid > 0 {
+            token_client.transfer(&e.current_contract_address(), &stream.recipient, &paid);
+        }
+        if refunded > 0 {
+            token_client.transfer(&e.current_contract_address(), &admin, &refunded);
+        }
+
+        StreamsEvents::cancel(&e, id, paid, refunded);
+    }
+}
+
+/// Load an active stream
+///
+/// ### Panics
+/// If the stream does not exist
+fn load_stream(e: &Env, id: u32) -> Stream {
+    match storage::get_stream(e, id) {
+        Some(stream) => stream,
+        None => panic_with_error!(e, StreamsError::StreamNotFound),
+    }
+}
+
+/// Calculate the tokens streamed so far, withdrawn or not
+fn calc_streamed(e: &Env, stream: &Stream) -> i128 {
+    let now = e.ledger().timestamp().clamp(stream.start, stream.end);
+    stream.rate * (now - stream.start) as i128
+}
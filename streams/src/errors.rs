@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the streams contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Streams specific errors start at 4900.
+pub enum StreamsError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Streams
+    StreamNotFound = 4900,
+    InvalidSchedule = 4901,
+}
@@ -0,0 +1,33 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct StreamsEvents {}
+
+impl StreamsEvents {
+    /// Emitted when the admin funds a stream
+    ///
+    /// - topics - `["create", id: u32, recipient: Address]`
+    /// - data - `[rate: i128, start: u64, end: u64]`
+    pub fn create(e: &Env, id: u32, recipient: Address, rate: i128, start: u64, end: u64) {
+        let topics = (Symbol::new(e, topics::CREATE), id, recipient);
+        e.events().publish(topics, (rate, start, end));
+    }
+
+    /// Emitted when the recipient withdraws from a stream
+    ///
+    /// - topics - `["withdraw", id: u32, recipient: Address]`
+    /// - data - `amount: i128`
+    pub fn withdraw(e: &Env, id: u32, recipient: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), id, recipient);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when the admin cancels a stream
+    ///
+    /// - topics - `["cancel", id: u32]`
+    /// - data - `[paid: i128, refunded: i128]`
+    pub fn cancel(e: &Env, id: u32, paid: i128, refunded: i128) {
+        let topics = (Symbol::new(e, topics::CANCEL), id);
+        e.events().publish(topics, (paid, refunded));
+    }
+}
@@ -0,0 +1,128 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+const NEXT_ID_KEY: &str = "NextId";
+
+/// A payment streamed to a recipient every second between two times
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Stream {
+    pub recipient: Address,
+    pub rate: i128,      // the tokens streamed per second
+    pub start: u64,      // the time the stream starts
+    pub end: u64,        // the time the stream ends
+    pub withdrawn: i128, // the tokens the recipient has withdrawn
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum StreamsDataKey {
+    // An active stream
+    Stream(u32),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Streams **********/
+
+/// Fetch the Address of the streamed token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the streamed token
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the id of the next stream
+pub fn get_next_id(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, NEXT_ID_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the id of the next stream
+///
+/// ### Arguments
+/// * `id` - The id of the next stream
+pub fn set_next_id(e: &Env, id: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, NEXT_ID_KEY), &id);
+}
+
+/// Fetch an active stream
+///
+/// ### Arguments
+/// * `id` - The id of the stream
+pub fn get_stream(e: &Env, id: u32) -> Option<Stream> {
+    let key = StreamsDataKey::Stream(id);
+    let result = e.storage().persistent().get::<StreamsDataKey, Stream>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set an active stream
+///
+/// ### Arguments
+/// * `id` - The id of the stream
+/// * `stream` - The stream
+pub fn set_stream(e: &Env, id: u32, stream: &Stream) {
+    let key = StreamsDataKey::Stream(id);
+    e.storage()
+        .persistent()
+        .set::<StreamsDataKey, Stream>(&key, stream);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Remove a stream
+///
+/// ### Arguments
+/// * `id` - The id of the stream
+pub fn del_stream(e: &Env, id: u32) {
+    let key = StreamsDataKey::Stream(id);
+    e.storage().persistent().remove(&key);
+}
@@ -0,0 +1,51 @@
+#![cfg(test)]
+use crate::contract::{StreamsClient, StreamsContract};
+use crate::errors::StreamsError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+#[test]
+fn test_stream_withdraw_and_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let admin = Address::generate(&e);
+    let streams_id = e.register_contract(None, StreamsContract);
+    let streams_client = StreamsClient::new(&e, &streams_id);
+    streams_client.initialize(&admin, &ousd);
+    StellarAssetClient::new(&e, &ousd).mint(&admin, &3000_0000000);
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+
+    let result = streams_client.try_create(&samwise, &1_0000000, &2000, &2000);
+    assert_eq!(result.err(), Some(Ok(StreamsError::InvalidSchedule.into())));
+    let sam_stream = streams_client.create(&samwise, &1_0000000, &2000, &3000);
+    let frodo_stream = streams_client.create(&frodo, &2_0000000, &1000, &1500);
+    assert_eq!(ousd_client.balance(&admin), 1000_0000000);
+
+    // nothing is streamed before the start
+    e.ledger().with_mut(|li| li.timestamp = 1250);
+    assert_eq!(streams_client.get_accrued(&sam_stream), 0);
+    assert_eq!(streams_client.withdraw(&frodo_stream), 500_0000000);
+    assert_eq!(streams_client.get_stream(&frodo_stream).withdrawn, 500_0000000);
+
+    // an ended stream is removed once withdrawn
+    e.ledger().with_mut(|li| li.timestamp = 2500);
+    assert_eq!(streams_client.withdraw(&frodo_stream), 500_0000000);
+    assert_eq!(ousd_client.balance(&frodo), 1000_0000000);
+    let result = streams_client.try_get_stream(&frodo_stream);
+    assert_eq!(result.err(), Some(Ok(StreamsError::StreamNotFound.into())));
+
+    // cancelling pays the accrued tokens and refunds the rest
+    assert_eq!(streams_client.withdraw(&sam_stream), 500_0000000);
+    e.ledger().with_mut(|li| li.timestamp = 2600);
+    streams_client.cancel(&sam_stream);
+    assert_eq!(ousd_client.balance(&samwise), 600_0000000);
+    assert_eq!(ousd_client.balance(&admin), 1400_0000000);
+    assert_eq!(ousd_client.balance(&streams_id), 0);
+    let result = streams_client.try_withdraw(&sam_stream);
+    assert_eq!(result.err(), Some(Ok(StreamsError::StreamNotFound.into())));
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use ::admin::AdminClient;
+
+/// Deploy an uninitialized admin contract, registered natively from source
+pub fn create_admin<'a>(e: &Env) -> (Address, AdminClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::admin::AdminContract {});
+    (contract_id.clone(), AdminClient::new(e, &contract_id))
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use mock_amm::{MockAmmClient, SwapBehavior};
+pub use orbit_common::soroswap::SoroswapRouterClient;
+
+/// Deploy a mock AMM that swaps with a set behavior. Swaps fail until a behavior is set, and
+/// are paid out of the AMM's own balance.
+pub fn create_mock_amm<'a>(e: &Env) -> (Address, MockAmmClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, mock_amm::MockAmmContract);
+    (contract_id.clone(), MockAmmClient::new(e, &contract_id))
+}
@@ -31,3 +31,27 @@ pub fn assert_approx_eq_rel(a: i128, b: i128, delta: i128) {
         delta
     );
 }
+
+/// Assert a `try_` call on a contract client failed with a contract error
+///
+/// The error can be any contract error type, such as `TreasuryError::SupplyError`, or a
+/// `soroban_sdk::Error` built from a code for contracts only deployed as wasm.
+///
+/// ```ignore
+/// assert_contract_err!(treasury.try_decrease_supply(&amount), TreasuryError::SupplyError);
+/// ```
+#[macro_export]
+macro_rules! assert_contract_err {
+    ($result:expr, $error:expr) => {
+        match $result {
+            Err(Ok(error)) => {
+                let expected: soroban_sdk::Error = $error.into();
+                assert_eq!(error, expected, "the call failed with another error");
+            }
+            Err(Err(error)) => {
+                panic!("expected {:?}, but the call failed with {:?}", $error, error)
+            }
+            Ok(_) => panic!("expected {:?}, but the call succeeded", $error),
+        }
+    };
+}
@@ -0,0 +1,119 @@
+use sep_40_oracle::testutils::Asset;
+use soroban_sdk::{vec as svec, Address, Vec as SVec};
+
+use crate::pool::{AuctionData, Positions, Request, RequestType};
+use crate::test_fixture::{TestFixture, TokenIndex};
+
+/// Supply XLM collateral for a user and borrow oUSD against it
+///
+/// ### Arguments
+/// * `pool_index` - The pool to borrow from
+/// * `user` - The borrower, who must hold the XLM
+/// * `collateral` - The amount of XLM to supply as collateral
+/// * `borrow` - The amount of oUSD to borrow
+pub fn open_ousd_borrow(
+    fixture: &TestFixture,
+    pool_index: usize,
+    user: &Address,
+    collateral: i128,
+    borrow: i128,
+) -> Positions {
+    refresh_prices(fixture);
+    fixture.pools[pool_index].pool.submit(
+        user,
+        user,
+        user,
+        &svec![
+            &fixture.env,
+            Request {
+                request_type: RequestType::SupplyCollateral.into_u32(),
+                address: fixture.tokens[TokenIndex::XLM].address.clone(),
+                amount: collateral,
+            },
+            Request {
+                request_type: RequestType::Borrow.into_u32(),
+                address: fixture.tokens[TokenIndex::OUSD].address.clone(),
+                amount: borrow,
+            },
+        ],
+    )
+}
+
+/// Republish the last price of every oracle asset at the current timestamp, so the pool does
+/// not reject them as stale after a jump
+pub fn refresh_prices(fixture: &TestFixture) {
+    let mut prices = SVec::new(&fixture.env);
+    for asset in fixture.oracle.assets().iter() {
+        prices.push_back(fixture.oracle.lastprice(&asset).unwrap().price);
+    }
+    fixture.oracle.set_price_stable(&prices);
+}
+
+/// Move the XLM price, leaving the price of every other oracle asset as is
+///
+/// ### Arguments
+/// * `price` - The new XLM price, with the oracle's decimals
+pub fn set_xlm_price(fixture: &TestFixture, price: i128) {
+    let xlm = Asset::Stellar(fixture.tokens[TokenIndex::XLM].address.clone());
+    let mut prices = SVec::new(&fixture.env);
+    for asset in fixture.oracle.assets().iter() {
+        if asset == xlm {
+            prices.push_back(price);
+        } else {
+            prices.push_back(fixture.oracle.lastprice(&asset).unwrap().price);
+        }
+    }
+    fixture.oracle.set_price_stable(&prices);
+}
+
+/// Drop the XLM price until a user is underwater and create a liquidation auction for them
+///
+/// ### Arguments
+/// * `pool_index` - The pool the user borrows from
+/// * `user` - The user to liquidate
+/// * `price` - The XLM price that leaves the user underwater
+/// * `percent_liquidated` - The percent of the user's position to auction
+pub fn liquidate_by_price(
+    fixture: &TestFixture,
+    pool_index: usize,
+    user: &Address,
+    price: i128,
+    percent_liquidated: u64,
+) -> AuctionData {
+    set_xlm_price(fixture, price);
+    fixture.pools[pool_index]
+        .pool
+        .new_liquidation_auction(user, &percent_liquidated)
+}
+
+/// Let interest accrue until a user is underwater and create a liquidation auction for them
+///
+/// ### Arguments
+/// * `pool_index` - The pool the user borrows from
+/// * `user` - The user to liquidate
+/// * `step` - The seconds to jump between attempts
+/// * `max_steps` - The most attempts to make
+/// * `percent_liquidated` - The percent of the user's position to auction. A user who just went
+///   underwater can only be auctioned in small part.
+///
+/// ### Panics
+/// If the auction could not be created after `max_steps` jumps
+pub fn liquidate_by_interest(
+    fixture: &TestFixture,
+    pool_index: usize,
+    user: &Address,
+    step: u64,
+    max_steps: u32,
+    percent_liquidated: u64,
+) -> AuctionData {
+    let pool = &fixture.pools[pool_index].pool;
+    for _ in 0..max_steps {
+        // interest accrues by timestamp, and leaving the sequence alone keeps entries live
+        fixture.jump(step);
+        refresh_prices(fixture);
+        if let Ok(Ok(auction)) = pool.try_new_liquidation_auction(user, &percent_liquidated) {
+            return auction;
+        }
+    }
+    panic!("no liquidation auction could be created after {} steps", max_steps);
+}
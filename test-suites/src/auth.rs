@@ -0,0 +1,50 @@
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Val, Vec as SVec};
+
+/// Build the invocation tree a contract call is expected to be authorized with
+///
+/// ### Arguments
+/// * `contract` - The contract called
+/// * `fn_name` - The function called
+/// * `args` - The arguments of the call, as a tuple
+/// * `sub_invocations` - The calls made under it that the same address must authorize
+pub fn invocation(
+    e: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: impl IntoVal<Env, SVec<Val>>,
+    sub_invocations: &[AuthorizedInvocation],
+) -> AuthorizedInvocation {
+    AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            contract.clone(),
+            Symbol::new(e, fn_name),
+            args.into_val(e),
+        )),
+        sub_invocations: sub_invocations.to_vec(),
+    }
+}
+
+/// Assert the last call was authorized by exactly these addresses, each with exactly this
+/// invocation tree, in order
+///
+/// ### Arguments
+/// * `expected` - The addresses and the invocation tree each authorized
+pub fn assert_auths(e: &Env, expected: &[(Address, AuthorizedInvocation)]) {
+    assert_eq!(e.auths(), expected.to_vec(), "the call was authorized differently");
+}
+
+/// Assert the last call was authorized by exactly these addresses, in order, whatever they
+/// authorized
+///
+/// ### Arguments
+/// * `expected` - The addresses
+pub fn assert_authorizers(e: &Env, expected: &[Address]) {
+    let authorizers: Vec<Address> = e.auths().into_iter().map(|(address, _)| address).collect();
+    assert_eq!(authorizers, expected.to_vec(), "the call was authorized by other addresses");
+}
+
+/// Assert the last call did not require any address to authorize it
+pub fn assert_no_auths(e: &Env) {
+    assert_authorizers(e, &[]);
+}
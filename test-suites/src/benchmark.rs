@@ -0,0 +1,130 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use sep_40_oracle::testutils::Asset;
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::Address;
+
+use crate::budget::Resources;
+use crate::test_fixture::{TestFixture, TokenIndex, SCALAR_7, SCALAR_9};
+
+/// A strategy measured by a benchmark
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkRow {
+    pub name: String,
+    pub used: Resources,
+    pub profit: i128, // the profit realized, in oUSD
+}
+
+/// The results of strategies compared against each other, written out as a markdown table so
+/// a change of strategy can point at the numbers behind it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkTable {
+    pub title: String,
+    pub rows: Vec<BenchmarkRow>,
+}
+
+impl BenchmarkTable {
+    pub fn new(title: &str) -> Self {
+        BenchmarkTable {
+            title: title.to_string(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Record the resources used and the profit realized by a strategy
+    pub fn record(&mut self, name: &str, used: Resources, profit: i128) {
+        self.rows.push(BenchmarkRow {
+            name: name.to_string(),
+            used,
+            profit,
+        });
+    }
+
+    /// Get the row of a strategy
+    ///
+    /// ### Panics
+    /// If no strategy was recorded under `name`
+    pub fn row(&self, name: &str) -> &BenchmarkRow {
+        self.rows
+            .iter()
+            .find(|row| row.name == name)
+            .unwrap_or_else(|| panic!("no strategy named {}", name))
+    }
+
+    /// Render the table as markdown
+    pub fn render(&self) -> String {
+        let mut table = format!("## {}\n\n", self.title);
+        table.push_str("| strategy | cpu insns | mem bytes | reads | writes | profit |\n");
+        table.push_str("| --- | ---: | ---: | ---: | ---: | ---: |\n");
+        for row in self.rows.iter() {
+            writeln!(
+                table,
+                "| {} | {} | {} | {} | {} | {} |",
+                row.name,
+                row.used.cpu_insns,
+                row.used.mem_bytes,
+                row.used.read_entries,
+                row.used.write_entries,
+                format_amount(row.profit)
+            )
+            .unwrap();
+        }
+        table
+    }
+
+    /// Write the table to `target/benchmarks`, named after its title
+    ///
+    /// Returns the path of the table
+    pub fn write(&self) -> PathBuf {
+        let dir = match std::env::var("CARGO_TARGET_DIR") {
+            Ok(target) => PathBuf::from(target),
+            Err(_) => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target"),
+        }
+        .join("benchmarks");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.md", self.title.replace(' ', "_")));
+        std::fs::write(&path, self.render()).unwrap();
+        path
+    }
+}
+
+/// Format an amount with 7 decimals
+fn format_amount(amount: i128) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.abs();
+    format!("{}{}.{:07}", sign, amount / SCALAR_7, amount % SCALAR_7)
+}
+
+/// Value what a user holds, in their wallet and in a pool, in oUSD
+///
+/// oUSD is valued at its peg, and every other reserve at the price of the fixture's oracle.
+/// Liabilities count against the value.
+///
+/// ### Arguments
+/// * `pool_index` - The pool the user holds positions in
+/// * `user` - The user to value
+pub fn equity(fixture: &TestFixture, pool_index: usize, user: &Address) -> i128 {
+    let pool_fixture = &fixture.pools[pool_index];
+    let positions = pool_fixture.pool.get_positions(user);
+    let mut value = 0;
+    for (asset_index, reserve_index) in pool_fixture.reserves.iter() {
+        let token = &fixture.tokens[*asset_index];
+        let data = fixture.read_reserve_data(pool_index, *asset_index);
+        let b_tokens = positions.collateral.get(*reserve_index).unwrap_or(0)
+            + positions.supply.get(*reserve_index).unwrap_or(0);
+        let d_tokens = positions.liabilities.get(*reserve_index).unwrap_or(0);
+        let amount = token.balance(user)
+            + b_tokens.fixed_mul_floor(data.b_rate, SCALAR_9).unwrap()
+            - d_tokens.fixed_mul_ceil(data.d_rate, SCALAR_9).unwrap();
+        let price = match asset_index {
+            TokenIndex::OUSD => SCALAR_7,
+            _ => {
+                let asset = Asset::Stellar(token.address.clone());
+                fixture.oracle.lastprice(&asset).unwrap().price
+            }
+        };
+        value += amount.fixed_mul_floor(price, SCALAR_7).unwrap();
+    }
+    value
+}
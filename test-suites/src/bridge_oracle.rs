@@ -9,4 +9,17 @@ pub fn create_bridge_oracle<'a>(e: &Env) -> (Address, BridgeOracleClient<'a>) {
     let contract_id = Address::generate(e);
     e.register_contract_wasm(&contract_id, BRIDGE_ORACLE_WASM);
     (contract_id.clone(), BridgeOracleClient::new(e, &contract_id))
-}
\ No newline at end of file
+}
+
+/// Deploy an uninitialized bridge oracle natively from source. The deployed wasm predates
+/// price age limits and fallback feeds, so use this to test them.
+pub fn create_native_bridge_oracle<'a>(
+    e: &Env,
+) -> (Address, ::bridge_oracle::BridgeOracleClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::bridge_oracle::BridgeOracleContract {});
+    (
+        contract_id.clone(),
+        ::bridge_oracle::BridgeOracleClient::new(e, &contract_id),
+    )
+}
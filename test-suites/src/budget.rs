@@ -0,0 +1,97 @@
+use std::ops::Add;
+
+use soroban_sdk::Env;
+
+/// The resources consumed by an invocation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Resources {
+    pub cpu_insns: u64,     // the CPU instructions metered
+    pub mem_bytes: u64,     // the memory metered, in bytes
+    pub read_entries: u32,  // the ledger entries only read
+    pub write_entries: u32, // the ledger entries written
+}
+
+impl Add for Resources {
+    type Output = Resources;
+
+    fn add(self, other: Resources) -> Resources {
+        Resources {
+            cpu_insns: self.cpu_insns + other.cpu_insns,
+            mem_bytes: self.mem_bytes + other.mem_bytes,
+            read_entries: self.read_entries + other.read_entries,
+            write_entries: self.write_entries + other.write_entries,
+        }
+    }
+}
+
+/// The resource limits of a single transaction on the Stellar network
+pub const TX_LIMITS: Resources = Resources {
+    cpu_insns: 100_000_000,
+    mem_bytes: 41_943_040,
+    read_entries: 40,
+    write_entries: 25,
+};
+
+/// Run `f` and measure the resources it consumes
+///
+/// Resets the budget to unlimited before running `f`. Contracts registered natively are
+/// underestimated compared to their wasm, so measure the wasm when it matters.
+pub fn measure<T>(e: &Env, f: impl FnOnce() -> T) -> (T, Resources) {
+    e.budget().reset_unlimited();
+    e.host()
+        .with_mut_storage(|storage| {
+            storage.footprint = Default::default();
+            Ok(())
+        })
+        .unwrap();
+
+    let result = f();
+
+    let budget = e.budget();
+    let cpu_insns = budget.cpu_instruction_cost();
+    let mem_bytes = budget.memory_bytes_cost();
+    let (read_entries, write_entries) = e
+        .host()
+        .with_mut_storage(|storage| {
+            let host_budget = e.host().budget_cloned();
+            let mut reads = 0;
+            let mut writes = 0;
+            for (_, access) in storage.footprint.0.iter(&host_budget)? {
+                // the sdk does not export the access type, so match on its name
+                if format!("{:?}", access) == "ReadWrite" {
+                    writes += 1;
+                } else {
+                    reads += 1;
+                }
+            }
+            Ok((reads, writes))
+        })
+        .unwrap();
+    (
+        result,
+        Resources {
+            cpu_insns,
+            mem_bytes,
+            read_entries,
+            write_entries,
+        },
+    )
+}
+
+/// Run `f` and assert it stays within `limits`
+///
+/// ### Panics
+/// If `f` consumes more of any resource than its limit
+pub fn assert_within_limits<T>(e: &Env, limits: &Resources, f: impl FnOnce() -> T) -> T {
+    let (result, used) = measure(e, f);
+    assert!(
+        used.cpu_insns <= limits.cpu_insns
+            && used.mem_bytes <= limits.mem_bytes
+            && used.read_entries <= limits.read_entries
+            && used.write_entries <= limits.write_entries,
+        "resources used {:?} exceed the limits {:?}",
+        used,
+        limits
+    );
+    result
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use ::d3m::{D3MClient, D3MConfig};
+
+/// Deploy an uninitialized direct deposit module, registered natively from source
+pub fn create_d3m<'a>(e: &Env) -> (Address, D3MClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::d3m::D3MContract {});
+    (contract_id.clone(), D3MClient::new(e, &contract_id))
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{vec as svec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec as SVec};
+
+/// An event published by a contract
+#[derive(Clone, Debug)]
+pub struct ContractEvent {
+    pub topics: SVec<Val>,
+    pub data: Val,
+}
+
+impl ContractEvent {
+    /// The name of the event, taken from its first topic, if that is a Symbol
+    pub fn name(&self) -> Option<Symbol> {
+        let first = self.topics.first()?;
+        Symbol::try_from_val(self.topics.env(), &first).ok()
+    }
+
+    /// Decode a topic of the event
+    ///
+    /// ### Panics
+    /// If there is no topic at `index`, or it is not a `T`
+    pub fn topic<T: TryFromVal<Env, Val>>(&self, index: u32) -> T {
+        let topic = self.topics.get(index).expect("no topic at the index");
+        T::try_from_val(self.topics.env(), &topic).ok().expect("topic is not of the type")
+    }
+
+    /// Decode the data of the event, such as one of the contracts' event structs
+    ///
+    /// ### Panics
+    /// If the data is not a `T`
+    pub fn data<T: TryFromVal<Env, Val>>(&self) -> T {
+        T::try_from_val(self.topics.env(), &self.data).ok().expect("data is not of the type")
+    }
+}
+
+/// Collect the events published by a contract since the Env was created, oldest first
+///
+/// ### Arguments
+/// * `contract` - The contract that published the events
+pub fn collect_events(contract: &Address) -> Vec<ContractEvent> {
+    contract
+        .env()
+        .events()
+        .all()
+        .iter()
+        .filter(|(address, _, _)| address == contract)
+        .map(|(_, topics, data)| ContractEvent { topics, data })
+        .collect()
+}
+
+/// Collect the data of the events a contract published under a name, oldest first, decoded
+/// as `T`
+///
+/// ### Arguments
+/// * `contract` - The contract that published the events
+/// * `name` - The name of the events, their first topic
+///
+/// ### Panics
+/// If the data of a named event is not a `T`
+pub fn collect_named<T: TryFromVal<Env, Val>>(contract: &Address, name: &str) -> Vec<T> {
+    let name = Symbol::new(contract.env(), name);
+    collect_events(contract)
+        .iter()
+        .filter(|event| event.name() == Some(name.clone()))
+        .map(|event| event.data())
+        .collect()
+}
+
+/// Assert a contract published an event with exactly these topics and data
+///
+/// ### Arguments
+/// * `contract` - The contract that published the event
+/// * `topics` - The topics of the event, such as a tuple starting with its name
+/// * `data` - The data of the event
+///
+/// ### Panics
+/// If no event matches, listing the events the contract did publish
+pub fn assert_event(
+    e: &Env,
+    contract: &Address,
+    topics: impl IntoVal<Env, SVec<Val>>,
+    data: impl IntoVal<Env, Val>,
+) {
+    let expected = svec![e, (topics.into_val(e), data.into_val(e))];
+    let events = collect_events(contract);
+    let found = events
+        .iter()
+        .any(|event| svec![e, (event.topics.clone(), event.data)] == expected);
+    assert!(found, "no event matches {:?} in {:?}", expected, events);
+}
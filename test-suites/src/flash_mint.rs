@@ -0,0 +1,10 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use ::flash_mint::FlashMintClient;
+
+/// Deploy an uninitialized flash mint, registered natively from source
+pub fn create_flash_mint<'a>(e: &Env) -> (Address, FlashMintClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::flash_mint::FlashMintContract {});
+    (contract_id.clone(), FlashMintClient::new(e, &contract_id))
+}
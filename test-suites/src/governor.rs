@@ -0,0 +1,10 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use ::governor::{GovernorClient, GovernorSettings};
+
+/// Deploy an uninitialized governor, registered natively from source
+pub fn create_governor<'a>(e: &Env) -> (Address, GovernorClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::governor::GovernorContract {});
+    (contract_id.clone(), GovernorClient::new(e, &contract_id))
+}
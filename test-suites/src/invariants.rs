@@ -0,0 +1,55 @@
+use soroban_fixed_point_math::FixedPoint;
+
+use crate::test_fixture::{TestFixture, TokenIndex, SCALAR_9};
+
+/// Assert the properties that must hold across the protocol whenever no call is in progress.
+/// Call after every scripted operation in an integration test.
+///
+/// For every pool:
+/// - the treasury holds no oUSD, so nothing it minted is left un-burned or unsupplied
+/// - the treasury's position in the pool is worth at least the supply it tracks
+/// - the bridge oracle reports prices with the same decimals as its upstream oracle
+pub fn assert_invariants(fixture: &TestFixture) {
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    for (pool_index, pool_fixture) in fixture.pools.iter().enumerate() {
+        let treasury = &pool_fixture.treasury.address;
+        assert_eq!(
+            ousd.balance(treasury),
+            0,
+            "pool {}: the treasury holds oUSD at rest",
+            pool_index
+        );
+
+        let supply = fixture.read_treasury_supply(pool_index);
+        let b_tokens = match pool_fixture.reserves.get(&TokenIndex::OUSD) {
+            Some(reserve_index) => pool_fixture
+                .pool
+                .get_positions(treasury)
+                .supply
+                .get(*reserve_index)
+                .unwrap_or(0),
+            None => 0,
+        };
+        let position = if b_tokens > 0 {
+            let b_rate = fixture.read_reserve_data(pool_index, TokenIndex::OUSD).b_rate;
+            b_tokens.fixed_mul_floor(b_rate, SCALAR_9).unwrap()
+        } else {
+            0
+        };
+        // allow for rounding on each supply and withdrawal
+        assert!(
+            position + 10 >= supply,
+            "pool {}: the treasury position of {} does not back its supply of {}",
+            pool_index,
+            position,
+            supply
+        );
+
+        assert_eq!(
+            pool_fixture.oracle.decimals(),
+            fixture.oracle.decimals(),
+            "pool {}: the bridge oracle decimals differ from its upstream",
+            pool_index
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use soroban_sdk::{Address, Bytes, Env, String};
+
+/// A ledger captured from a network, such as testnet, loaded into an Env to reproduce what was
+/// observed there.
+///
+/// Capture the ledger entries of the contracts involved, e.g. Blend, Soroswap and Orbit, along
+/// with their code, into a ledger snapshot file with `stellar snapshot create`. Contracts in the
+/// snapshot run from the wasm deployed on the network. To run the current build of the treasury
+/// against the captured state instead, upgrade it with `treasury::upgrade_to_native`.
+pub struct ImportedLedger {
+    pub env: Env,
+    addresses: HashMap<&'static str, Address>,
+}
+
+impl ImportedLedger {
+    /// Load a ledger snapshot file at the ledger it was captured at, with all auths mocked and
+    /// an unlimited budget. The Env is seeded from the snapshot, so the nonces of mocked auths
+    /// don't replay ones already used in the captured ledger.
+    ///
+    /// ### Arguments
+    /// * `path` - The ledger snapshot file
+    /// * `addresses` - A name and strkey for each contract or account the test uses
+    ///
+    /// ### Panics
+    /// If the file can't be read, or a strkey is not a valid address
+    pub fn load(path: impl AsRef<Path>, addresses: &[(&'static str, &str)]) -> Self {
+        let snapshot = std::fs::read(&path).unwrap();
+        let env = Env::from_ledger_snapshot_file(path);
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        let seed = env.crypto().sha256(&Bytes::from_slice(&env, &snapshot));
+        env.host().set_base_prng_seed(seed.to_array()).unwrap();
+        let addresses = addresses
+            .iter()
+            .map(|(name, strkey)| (*name, Address::from_string(&String::from_str(&env, strkey))))
+            .collect();
+        ImportedLedger { env, addresses }
+    }
+
+    /// Get a named address
+    ///
+    /// Accounts generated in the Env can collide with ones in the snapshot, so users the test
+    /// acts as should be captured accounts, named here.
+    ///
+    /// ### Panics
+    /// If no address was named `name`
+    pub fn address(&self, name: &str) -> Address {
+        self.addresses
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| panic!("no address named {}", name))
+    }
+
+    /// Write the ledger as it is now to a snapshot file, so a reproduction can be loaded again
+    /// from the state it reached
+    pub fn save(&self, path: impl AsRef<Path>) {
+        self.env.to_ledger_snapshot_file(path);
+    }
+}
@@ -1,15 +1,32 @@
 #![allow(clippy::all)]
+pub mod admin;
+pub mod amm;
+pub mod auctions;
+pub mod auth;
 pub mod backstop;
+pub mod benchmark;
+pub mod budget;
+pub mod d3m;
 pub mod emitter;
+pub mod events;
+pub mod flash_mint;
+pub mod governor;
+pub mod invariants;
+pub mod ledger_import;
 pub mod liquidity_pool;
 pub mod oracle;
+pub mod parity;
 pub mod pool;
+pub mod pool_builder;
 pub mod pool_factory;
+pub mod scenario;
 mod setup;
-pub use setup::create_fixture_with_data;
+pub use setup::{create_fixture_with_data, create_orbit_fixture, OrbitFixture};
+pub mod snapshot;
 pub mod assertions;
 pub mod test_fixture;
+pub mod timelock;
 pub mod token;
-mod treasury;
+pub mod treasury;
 mod treasury_factory;
-mod bridge_oracle;
+pub mod bridge_oracle;
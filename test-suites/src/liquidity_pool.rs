@@ -1,5 +1,7 @@
 use soroban_sdk::{testutils::Address as _, vec, Address, Env};
 
+use crate::test_fixture::SCALAR_7;
+
 mod lp_contract {
     soroban_sdk::contractimport!(file = "../wasm/comet.wasm");
 }
@@ -46,3 +48,46 @@ pub(crate) fn create_lp_pool<'a>(
 
     (contract_address, client)
 }
+
+/// Deploy a Comet LP pool of 50% token_1 / 50% token_2 that prices token_1 in token_2 at
+/// `price`, e.g. oUSD trading at 0.97 USDC. The admin must be able to mint both tokens.
+///
+/// ### Arguments
+/// * `depth` - The balance of token_1 in the pool
+/// * `price` - The price of token_1 in token_2, with 7 decimals
+/// * `swap_fee` - The swap fee, with 7 decimals
+pub fn create_pair<'a>(
+    e: &Env,
+    admin: &Address,
+    token_1: &Address,
+    token_2: &Address,
+    depth: i128,
+    price: i128,
+    swap_fee: i128,
+) -> (Address, LPClient<'a>) {
+    let contract_address = Address::generate(e);
+    e.register_contract_wasm(&contract_address, LP_WASM);
+    let client = LPClient::new(e, &contract_address);
+
+    let balance_1 = depth;
+    let balance_2 = depth * price / SCALAR_7;
+    let token_1_client = MockTokenClient::new(e, token_1);
+    let token_2_client = MockTokenClient::new(e, token_2);
+    token_1_client.mint(&admin, &balance_1);
+    token_2_client.mint(&admin, &balance_2);
+    token_1_client.approve(&admin, &contract_address, &balance_1, &5356700);
+    token_2_client.approve(&admin, &contract_address, &balance_2, &5356700);
+
+    client.init(&Address::generate(e), &admin);
+    client.bundle_bind(
+        &vec![e, token_1.clone(), token_2.clone()],
+        &vec![e, balance_1, balance_2],
+        &vec![e, 5_0000000, 5_0000000],
+    );
+
+    client.set_swap_fee(&swap_fee, &admin);
+    client.finalize();
+    client.set_public_swap(&admin, &true);
+
+    (contract_address, client)
+}
@@ -1,5 +1,6 @@
 use soroban_sdk::{testutils::Address as _, Address, Env};
 
+use mock_scripted_oracle::{ScriptedOracleClient, ScriptedOracleContract};
 use sep_40_oracle::testutils::{MockPriceOracleClient, MockPriceOracleWASM};
 
 pub fn create_mock_oracle<'a>(e: &Env) -> (Address, MockPriceOracleClient<'a>) {
@@ -10,3 +11,13 @@ pub fn create_mock_oracle<'a>(e: &Env) -> (Address, MockPriceOracleClient<'a>) {
         MockPriceOracleClient::new(e, &contract_id),
     )
 }
+
+/// Deploy an oracle whose prices follow a script per asset. It must be initialized.
+pub fn create_scripted_oracle<'a>(e: &Env) -> (Address, ScriptedOracleClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ScriptedOracleContract);
+    (
+        contract_id.clone(),
+        ScriptedOracleClient::new(e, &contract_id),
+    )
+}
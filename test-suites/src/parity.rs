@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+/// Run a scenario with treasuries deployed from their wasm, then registered natively, and
+/// assert both runs agree. Native contracts skip the wasm boundary, so a difference points at
+/// serialization or auth that only breaks once the contract is compiled.
+///
+/// Returns the result of the wasm run
+///
+/// ### Arguments
+/// * `scenario` - Builds its fixture with the `wasm` flag it is passed, and returns what the
+///   runs should agree on. Each run has its own Env, so return amounts rather than Addresses.
+pub fn assert_parity<T: PartialEq + Debug>(scenario: impl Fn(bool) -> T) -> T {
+    let wasm = scenario(true);
+    let native = scenario(false);
+    assert_eq!(wasm, native, "the wasm and native runs of the scenario differ");
+    wasm
+}
@@ -1,23 +1,7 @@
-mod pool_contract {
-    soroban_sdk::contractimport!(file = "../wasm/pool.wasm");
-}
-
-pub use pool_contract::{Client as PoolClient, ReserveEmissionsData, PoolDataKey, ReserveEmissionsConfig, PoolConfig, ReserveData, ReserveConfig, Request, ReserveEmissionMetadata, WASM as POOL_WASM};
+use mock_pool::{MockPoolClient, MockPoolContract};
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
 
-#[derive(Clone, PartialEq)]
-#[repr(u32)]
-pub enum RequestType {
-    Supply = 0,
-    Withdraw = 1,
-    SupplyCollateral = 2,
-    WithdrawCollateral = 3,
-    Borrow = 4,
-    Repay = 5,
-    FillUserLiquidationAuction = 6,
-    FillBadDebtAuction = 7,
-    FillInterestAuction = 8,
-    DeleteLiquidationAuction = 9,
-}
+pub use orbit_common::pool::{AuctionData, Client as PoolClient, Positions, ReserveEmissionsData, PoolDataKey, ReserveEmissionsConfig, PoolConfig, ReserveData, ReserveConfig, Request, RequestType, ReserveEmissionMetadata, WASM as POOL_WASM};
 pub fn default_reserve_metadata() -> ReserveConfig {
     ReserveConfig {
         decimals: 7,
@@ -32,3 +16,12 @@ pub fn default_reserve_metadata() -> ReserveConfig {
         index: 0,
     }
 }
+
+/// Deploy a mock pool that can be set to fail, for negative-path tests of its callers
+pub fn create_mock_pool<'a>(e: &Env, reserves: &Vec<Address>) -> (Address, MockPoolClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, MockPoolContract);
+    let client = MockPoolClient::new(e, &contract_id);
+    client.initialize(reserves);
+    (contract_id, client)
+}
@@ -0,0 +1,147 @@
+use soroban_sdk::{testutils::Address as _, vec as svec, Address, Symbol};
+
+use crate::{
+    pool::{default_reserve_metadata, ReserveConfig, ReserveEmissionMetadata},
+    test_fixture::{TestFixture, TokenIndex},
+};
+
+/// Builds a fully configured pool on a TestFixture: its reserves, reserve emissions, backstop
+/// deposit and reward zone entry, so tests don't repeat the setup dance.
+///
+/// ```ignore
+/// let pool_index = PoolFixtureBuilder::new("Teapot")
+///     .with_reserve(TokenIndex::OUSD, ousd_config)
+///     .with_reserve(TokenIndex::XLM, xlm_config)
+///     .with_emissions(TokenIndex::OUSD, 0, 0_6000000)
+///     .with_backstop(&frodo, 50_000 * SCALAR_7)
+///     .build(&mut fixture);
+/// ```
+pub struct PoolFixtureBuilder {
+    name: &'static str,
+    backstop_take_rate: u32,
+    max_positions: u32,
+    oracle: Option<Address>,
+    bridge: Option<Address>,
+    reserves: Vec<(TokenIndex, ReserveConfig)>,
+    emissions: Vec<(TokenIndex, u32, u64)>,
+    backstop: Option<(Address, i128)>,
+}
+
+impl PoolFixtureBuilder {
+    /// Start a pool with a 10% backstop take rate and up to 6 positions per user
+    pub fn new(name: &'static str) -> Self {
+        PoolFixtureBuilder {
+            name,
+            backstop_take_rate: 0_1000000,
+            max_positions: 6,
+            oracle: None,
+            bridge: None,
+            reserves: vec![],
+            emissions: vec![],
+            backstop: None,
+        }
+    }
+
+    /// Set the backstop take rate, with 7 decimals
+    pub fn with_take_rate(mut self, backstop_take_rate: u32) -> Self {
+        self.backstop_take_rate = backstop_take_rate;
+        self
+    }
+
+    /// Set the most positions a user can hold in the pool
+    pub fn with_max_positions(mut self, max_positions: u32) -> Self {
+        self.max_positions = max_positions;
+        self
+    }
+
+    /// Read prices from an upstream oracle other than the fixture's mock oracle
+    pub fn with_oracle(mut self, oracle: &Address) -> Self {
+        self.oracle = Some(oracle.clone());
+        self
+    }
+
+    /// Read prices from an initialized bridge oracle, instead of deploying one over the upstream
+    /// oracle
+    pub fn with_bridge_oracle(mut self, bridge: &Address) -> Self {
+        self.bridge = Some(bridge.clone());
+        self
+    }
+
+    /// Add a reserve, in order
+    pub fn with_reserve(mut self, asset: TokenIndex, config: ReserveConfig) -> Self {
+        self.reserves.push((asset, config));
+        self
+    }
+
+    /// Add a reserve with the default reserve config
+    pub fn with_default_reserve(self, asset: TokenIndex) -> Self {
+        self.with_reserve(asset, default_reserve_metadata())
+    }
+
+    /// Direct a share of the pool's emissions to a reserve's d-tokens (0) or b-tokens (1)
+    pub fn with_emissions(mut self, asset: TokenIndex, res_type: u32, share: u64) -> Self {
+        self.emissions.push((asset, res_type, share));
+        self
+    }
+
+    /// Deposit backstop LP tokens from `from` and add the pool to the reward zone. The pool is
+    /// only activated if it has a backstop.
+    pub fn with_backstop(mut self, from: &Address, amount: i128) -> Self {
+        self.backstop = Some((from.clone(), amount));
+        self
+    }
+
+    /// Deploy and configure the pool
+    ///
+    /// Returns the index of the pool in the fixture
+    pub fn build(self, fixture: &mut TestFixture) -> usize {
+        let name = Symbol::new(&fixture.env, self.name);
+        match self.bridge {
+            Some(bridge) => fixture.create_pool_with_bridge(
+                name,
+                self.backstop_take_rate,
+                self.max_positions,
+                &bridge,
+            ),
+            None => {
+                let oracle = self.oracle.unwrap_or(fixture.oracle.address.clone());
+                let (take_rate, max_positions) = (self.backstop_take_rate, self.max_positions);
+                fixture.create_pool_with_oracle(name, take_rate, max_positions, &oracle);
+            }
+        }
+        let pool_index = fixture.pools.len() - 1;
+        for (asset, config) in self.reserves.iter() {
+            fixture.create_pool_reserve(pool_index, *asset, config);
+        }
+
+        let pool_fixture = &fixture.pools[pool_index];
+        if !self.emissions.is_empty() {
+            let mut reserve_emissions = svec![&fixture.env];
+            for (asset, res_type, share) in self.emissions.iter() {
+                reserve_emissions.push_back(ReserveEmissionMetadata {
+                    res_index: pool_fixture.reserves[asset],
+                    res_type: *res_type,
+                    share: *share,
+                });
+            }
+            pool_fixture.pool.set_emissions_config(&reserve_emissions);
+        }
+
+        if let Some((from, amount)) = self.backstop {
+            fixture
+                .backstop
+                .deposit(&from, &pool_fixture.pool.address, &amount);
+            fixture.backstop.update_tkn_val();
+            fixture
+                .backstop
+                .add_reward(&pool_fixture.pool.address, &Address::generate(&fixture.env));
+            pool_fixture.pool.set_status(&3);
+            pool_fixture.pool.update_status();
+
+            fixture.emitter.distribute();
+            fixture.backstop.gulp_emissions();
+            pool_fixture.pool.gulp_emissions();
+        }
+        pool_index
+    }
+}
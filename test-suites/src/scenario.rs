@@ -0,0 +1,204 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use soroban_sdk::{testutils::Address as _, vec as svec, Address};
+
+use crate::auctions::{refresh_prices, set_xlm_price};
+use crate::invariants::assert_invariants;
+use crate::pool::{Request, RequestType};
+use crate::test_fixture::{TestFixture, TokenIndex, SCALAR_7};
+
+/// A user action, or a move of the market, taken in one step of a scenario. Users are indexes
+/// into the scenario's users.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    SupplyCollateral { user: usize, amount: i128 },
+    WithdrawCollateral { user: usize, amount: i128 },
+    Borrow { user: usize, amount: i128 },
+    Repay { user: usize, amount: i128 },
+    // Move the XLM price, with 7 decimals
+    MovePrice { price: i128 },
+    // Auction part of a user's position, and fill it at once
+    Liquidate { user: usize, percent: u64 },
+    IncreaseSupply { amount: i128 },
+    DecreaseSupply { amount: i128 },
+    JumpLedgers { ledgers: u32 },
+}
+
+/// Generates random but reproducible actions from a seed, so a failing scenario can be run
+/// again from its seed alone
+pub struct ScenarioGenerator {
+    rng: StdRng,
+    users: usize,
+}
+
+impl ScenarioGenerator {
+    /// ### Arguments
+    /// * `seed` - The seed of the actions
+    /// * `users` - The number of users taking actions
+    pub fn new(seed: u64, users: usize) -> Self {
+        ScenarioGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            users,
+        }
+    }
+
+    /// Generate the next action
+    pub fn next_action(&mut self) -> Action {
+        let user = self.rng.gen_range(0, self.users);
+        let amount = self.rng.gen_range(1, 20_000) * SCALAR_7;
+        match self.rng.gen_range(0, 10) {
+            0 => Action::SupplyCollateral {
+                user,
+                amount: amount * 10,
+            },
+            1 => Action::WithdrawCollateral {
+                user,
+                amount: amount * 10,
+            },
+            2 | 3 => Action::Borrow { user, amount },
+            4 => Action::Repay { user, amount },
+            5 => Action::MovePrice {
+                price: self.rng.gen_range(0_0500000, 0_1500000),
+            },
+            6 => Action::Liquidate {
+                user,
+                percent: self.rng.gen_range(10, 60),
+            },
+            7 => Action::IncreaseSupply { amount },
+            8 => Action::DecreaseSupply { amount },
+            _ => Action::JumpLedgers {
+                ledgers: self.rng.gen_range(1, 720),
+            },
+        }
+    }
+
+    /// Generate the next `steps` actions
+    pub fn actions(&mut self, steps: u32) -> Vec<Action> {
+        (0..steps).map(|_| self.next_action()).collect()
+    }
+}
+
+/// Fund the users of a scenario and a liquidator that fills their auctions
+///
+/// Returns the users, followed by the liquidator
+///
+/// ### Arguments
+/// * `users` - The number of users taking actions
+pub fn create_scenario_users(fixture: &TestFixture, users: usize) -> Vec<Address> {
+    let xlm = &fixture.tokens[TokenIndex::XLM];
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    let mut addresses: Vec<Address> =
+        (0..=users).map(|_| Address::generate(&fixture.env)).collect();
+    for user in addresses.iter() {
+        xlm.mint(user, &(2_000_000 * SCALAR_7));
+        ousd.mint(user, &(50_000 * SCALAR_7));
+    }
+
+    // the liquidator backs the debt it takes on from auctions with plenty of collateral
+    let liquidator = addresses.pop().unwrap();
+    refresh_prices(fixture);
+    let collateral = 2_000_000 * SCALAR_7;
+    submit(fixture, &liquidator, RequestType::SupplyCollateral, TokenIndex::XLM, collateral);
+    addresses.push(liquidator);
+    addresses
+}
+
+/// Run actions against the first pool of a fixture, asserting the protocol invariants after
+/// each step. Actions the protocol rejects, such as a borrow beyond a user's limit, are skipped.
+///
+/// Returns if each action was carried out
+///
+/// ### Arguments
+/// * `users` - The users of the scenario followed by the liquidator, as created by
+///   `create_scenario_users`
+/// * `actions` - The actions to take, in order
+///
+/// ### Panics
+/// If an invariant does not hold after a step
+pub fn run_scenario(fixture: &TestFixture, users: &[Address], actions: &[Action]) -> Vec<bool> {
+    let pool_fixture = &fixture.pools[0];
+    let liquidator = users.last().unwrap();
+    let mut outcomes = Vec::new();
+    for (step, action) in actions.iter().enumerate() {
+        let done = match action.clone() {
+            Action::SupplyCollateral { user, amount } => {
+                let user = &users[user];
+                submit(fixture, user, RequestType::SupplyCollateral, TokenIndex::XLM, amount)
+            }
+            Action::WithdrawCollateral { user, amount } => {
+                let user = &users[user];
+                submit(fixture, user, RequestType::WithdrawCollateral, TokenIndex::XLM, amount)
+            }
+            Action::Borrow { user, amount } => {
+                submit(fixture, &users[user], RequestType::Borrow, TokenIndex::OUSD, amount)
+            }
+            Action::Repay { user, amount } => {
+                submit(fixture, &users[user], RequestType::Repay, TokenIndex::OUSD, amount)
+            }
+            Action::MovePrice { price } => {
+                set_xlm_price(fixture, price);
+                true
+            }
+            Action::Liquidate { user, percent } => {
+                let auction = pool_fixture
+                    .pool
+                    .try_new_liquidation_auction(&users[user], &percent);
+                auction.is_ok()
+                    && submit_request(
+                        fixture,
+                        liquidator,
+                        Request {
+                            request_type: RequestType::FillUserLiquidationAuction.into_u32(),
+                            address: users[user].clone(),
+                            amount: 100,
+                        },
+                    )
+            }
+            Action::IncreaseSupply { amount } => {
+                pool_fixture.treasury.try_increase_supply(&amount).is_ok()
+            }
+            Action::DecreaseSupply { amount } => {
+                pool_fixture.treasury.try_decrease_supply(&amount).is_ok()
+            }
+            Action::JumpLedgers { ledgers } => {
+                fixture.jump_ledgers(ledgers);
+                refresh_prices(fixture);
+                true
+            }
+        };
+        outcomes.push(done);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            assert_invariants(fixture);
+        }));
+        if let Err(error) = result {
+            let message = match error.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => format!("{:?}", error),
+            };
+            panic!("invariant broken at step {} after {:?}: {}", step, action, message);
+        }
+    }
+    outcomes
+}
+
+fn submit(
+    fixture: &TestFixture,
+    user: &Address,
+    request_type: RequestType,
+    token: TokenIndex,
+    amount: i128,
+) -> bool {
+    let request = Request {
+        request_type: request_type.into_u32(),
+        address: fixture.tokens[token].address.clone(),
+        amount,
+    };
+    submit_request(fixture, user, request)
+}
+
+fn submit_request(fixture: &TestFixture, user: &Address, request: Request) -> bool {
+    fixture.pools[0]
+        .pool
+        .try_submit(user, user, user, &svec![&fixture.env, request])
+        .is_ok()
+}
@@ -1,14 +1,97 @@
-use soroban_sdk::{testutils::Address as _, vec as svec, Address, Symbol, Vec as SVec, String};
+use soroban_sdk::{testutils::Address as _, vec as svec, Address, Env, Vec as SVec, String};
 
 use crate::{
-    pool::{default_reserve_metadata, RequestType, ReserveEmissionMetadata, Request},
+    admin::{create_admin, AdminClient},
+    amm::{create_mock_amm, MockAmmClient, SwapBehavior},
+    bridge_oracle::create_native_bridge_oracle,
+    flash_mint::{create_flash_mint, FlashMintClient},
+    pool::{default_reserve_metadata, RequestType, Request},
+    pool_builder::PoolFixtureBuilder,
     test_fixture::{TestFixture, TokenIndex, SCALAR_7},
 };
 
 /// Create a test fixture with a pool and a whale depositing and borrowing all assets
-pub fn create_fixture_with_data<'a>() -> TestFixture<'a> {
-    let mut fixture = TestFixture::create();
+///
+/// ### Arguments
+/// * `wasm` - If treasuries are deployed from their wasm, rather than registered natively
+pub fn create_fixture_with_data<'a>(wasm: bool) -> TestFixture<'a> {
+    let mut fixture = TestFixture::create(wasm);
+    seed_fixture(&mut fixture, None);
+    fixture
+}
+
+/// The Orbit protocol wired over the Blend fixture, with every Orbit contract registered
+/// natively from source
+pub struct OrbitFixture<'a> {
+    /// The Blend fixture, holding the tokens and the "Teapot" pool at index 0
+    pub blend: TestFixture<'a>,
+    /// The treasury of the pool, which mints oUSD into it
+    pub treasury: ::treasury::TreasuryClient<'a>,
+    /// The bridge oracle the pool reads prices through
+    pub bridge_oracle: ::bridge_oracle::BridgeOracleClient<'a>,
+    /// The admin contract, which is the admin of the pool
+    pub admin: AdminClient<'a>,
+    /// The flash minter of the treasury, which lends newly minted oUSD
+    pub flash_mint: FlashMintClient<'a>,
+    /// An AMM with oUSD and USDC liquidity, swapping along a curve around $1
+    pub amm: MockAmmClient<'a>,
+}
 
+/// Deploy and wire the Orbit protocol over a Blend fixture with data, in one call
+///
+/// Bombadil is the admin, guardian and interest rate manager of every contract. The pool reads
+/// prices through the bridge oracle and is administered by the admin contract. Its treasury has
+/// supplied 100k oUSD, and lends flash loans through the flash mint for a 5 bps fee. The AMM
+/// holds 1M each of oUSD and USDC.
+///
+/// ### Arguments
+/// * `e` - The Env to deploy to
+pub fn create_orbit_fixture<'a>(e: &Env) -> OrbitFixture<'a> {
+    let mut blend = TestFixture::create_with_env(e, false);
+    let bombadil = blend.bombadil.clone();
+    let ousd = blend.tokens[TokenIndex::OUSD].address.clone();
+    let usdc = blend.tokens[TokenIndex::USDC].address.clone();
+
+    let (bridge_id, bridge_oracle) = create_native_bridge_oracle(e);
+    bridge_oracle.initialize(&bombadil, &bombadil, &ousd, &usdc, &blend.oracle.address);
+    seed_fixture(&mut blend, Some(&bridge_id));
+    let pool_fixture = &blend.pools[0];
+    let treasury = ::treasury::TreasuryClient::new(e, &pool_fixture.treasury.address);
+
+    let (admin_id, admin) = create_admin(e);
+    admin.initialize(&bombadil, &bombadil, &bombadil);
+    pool_fixture.pool.set_admin(&admin_id);
+
+    let (flash_mint_id, flash_mint) = create_flash_mint(e);
+    flash_mint.initialize(
+        &bombadil,
+        &ousd,
+        &treasury.address,
+        &bombadil,
+        &5,
+        &(1_000_000 * SCALAR_7),
+    );
+    treasury.set_flash_minter(&flash_mint_id);
+
+    let (amm_id, amm) = create_mock_amm(e);
+    let liquidity = 1_000_000 * SCALAR_7;
+    amm.set_default(&SwapBehavior::Curve(liquidity, liquidity, 30));
+    blend.tokens[TokenIndex::OUSD].mint(&amm_id, &liquidity);
+    blend.tokens[TokenIndex::USDC].mint(&amm_id, &liquidity);
+
+    OrbitFixture {
+        blend,
+        treasury,
+        bridge_oracle,
+        admin,
+        flash_mint,
+        amm,
+    }
+}
+
+/// Create the pool of `create_fixture_with_data`, reading prices through `bridge` if set, and
+/// fund it with a whale's backstop deposit and the treasury's supply
+fn seed_fixture(fixture: &mut TestFixture, bridge: Option<&Address>) {
     // mint whale tokens
     let frodo = Address::generate(&fixture.env);
     fixture.users.push(frodo.clone());
@@ -29,52 +112,28 @@ pub fn create_fixture_with_data<'a>() -> TestFixture<'a> {
         &frodo,
     );
 
-    fixture.create_pool(Symbol::new(&fixture.env, "Teapot"), 0_1000000, 6);
-
     let mut ousd_config = default_reserve_metadata();
     ousd_config.c_factor = 0_900_0000;
     ousd_config.l_factor = 0_950_0000;
     ousd_config.util = 0_850_0000;
-    fixture.create_pool_reserve(0, TokenIndex::OUSD, &ousd_config);
 
     let mut xlm_config = default_reserve_metadata();
     xlm_config.c_factor = 0_750_0000;
     xlm_config.l_factor = 0_750_0000;
     xlm_config.util = 0_500_0000;
-    fixture.create_pool_reserve(0, TokenIndex::XLM, &xlm_config);
 
-    // enable emissions for pool
+    // create the pool with emissions, and deposit into its backstop
+    let mut builder = PoolFixtureBuilder::new("Teapot")
+        .with_reserve(TokenIndex::OUSD, ousd_config)
+        .with_reserve(TokenIndex::XLM, xlm_config)
+        .with_emissions(TokenIndex::OUSD, 0, 0_600_0000) // d_token
+        .with_emissions(TokenIndex::XLM, 1, 0_400_0000) // b_token
+        .with_backstop(&frodo, 50_000 * SCALAR_7);
+    if let Some(bridge) = bridge {
+        builder = builder.with_bridge_oracle(bridge);
+    }
+    builder.build(fixture);
     let treasury_fixture = &fixture.pools[0];
-    let reserve_emissions: soroban_sdk::Vec<ReserveEmissionMetadata> = svec![
-        &fixture.env,
-        ReserveEmissionMetadata {
-            res_index: 0, // Orbit
-            res_type: 0,  // d_token
-            share: 0_600_0000
-        },
-        ReserveEmissionMetadata {
-            res_index: 1, // XLM
-            res_type: 1,  // b_token
-            share: 0_400_0000
-        },
-    ];
-    treasury_fixture.pool.set_emissions_config(&reserve_emissions);
-
-    // deposit into backstop, add to reward zone
-    fixture
-        .backstop
-        .deposit(&frodo, &treasury_fixture.pool.address, &(50_000 * SCALAR_7));
-    fixture.backstop.update_tkn_val();
-    fixture
-        .backstop
-        .add_reward(&treasury_fixture.pool.address, &Address::generate(&fixture.env));
-    treasury_fixture.pool.set_status(&3);
-    treasury_fixture.pool.update_status();
-
-    // enable emissions
-    fixture.emitter.distribute();
-    fixture.backstop.gulp_emissions();
-    treasury_fixture.pool.gulp_emissions();
 
     fixture.jump(60);
 
@@ -87,12 +146,12 @@ pub fn create_fixture_with_data<'a>() -> TestFixture<'a> {
     // let requests: SVec<Request> = svec![
     //     &fixture.env,
     //     Request {
-    //         request_type: RequestType::SupplyCollateral as u32,
+    //         request_type: RequestType::SupplyCollateral.into_u32(),
     //         address: fixture.tokens[TokenIndex::XLM].address.clone(),
     //         amount: 100_000 * SCALAR_7,
     //     },
     //     Request {
-    //         request_type: RequestType::Borrow as u32,
+    //         request_type: RequestType::Borrow.into_u32(),
     //         address: fixture.tokens[TokenIndex::XLM].address.clone(),
     //         amount: 60_000 * SCALAR_7,
     //     },
@@ -103,12 +162,12 @@ pub fn create_fixture_with_data<'a>() -> TestFixture<'a> {
     fixture.jump(60 * 60); // 1 hr
 
     fixture.env.budget().reset_unlimited();
-    fixture
 }
 
 #[cfg(test)]
 mod tests {
 
+    use crate::amm::SoroswapRouterClient;
     use crate::test_fixture::{PoolFixture};
 
     use super::*;
@@ -117,7 +176,40 @@ mod tests {
 
     #[test]
     fn test_create_fixture_with_data_wasm() {
-        let fixture: TestFixture<'_> = create_fixture_with_data();
+        check_fixture_with_data(create_fixture_with_data(true));
+    }
+
+    #[test]
+    fn test_create_fixture_with_data_native() {
+        check_fixture_with_data(create_fixture_with_data(false));
+    }
+
+    #[test]
+    fn test_create_orbit_fixture() {
+        let e = Env::default();
+        let orbit = create_orbit_fixture(&e);
+        let fixture = &orbit.blend;
+        let bombadil = &fixture.bombadil;
+        let pool = &fixture.pools[0].pool.address;
+        let ousd = &fixture.tokens[TokenIndex::OUSD].address;
+        let usdc = &fixture.tokens[TokenIndex::USDC].address;
+
+        assert_eq!(orbit.treasury.get_token_supply(), 100_000 * SCALAR_7);
+        assert_eq!(orbit.treasury.get_blend_address(), *pool);
+        assert_eq!(fixture.pools[0].oracle.address, orbit.bridge_oracle.address);
+        assert_eq!(orbit.admin.get_owner(), *bombadil);
+        assert_eq!(orbit.flash_mint.flash_fee(&(10_000 * SCALAR_7)), 5 * SCALAR_7);
+        let path = svec![&e, ousd.clone(), usdc.clone()];
+        let router = SoroswapRouterClient::new(&e, &orbit.amm.address);
+        let quote = router.router_get_amounts_out(&SCALAR_7, &path);
+        assert!(quote.get(1).unwrap() > 0_9900000);
+
+        // the pool is administered through the admin contract
+        orbit.admin.freeze_pool(bombadil, pool);
+        assert_eq!(fixture.read_pool_config(0).status, 4);
+    }
+
+    fn check_fixture_with_data(fixture: TestFixture<'_>) {
         let frodo = fixture.users.get(0).unwrap();
         let treasury_fixture: &PoolFixture = fixture.pools.get(0).unwrap();
 
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use sep_40_oracle::testutils::MockPriceOracleClient;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::testutils::Snapshot;
+use soroban_sdk::xdr::ScAddress;
+use soroban_sdk::{Address, Env, TryFromVal};
+
+use crate::backstop::BackstopClient;
+use crate::bridge_oracle::BridgeOracleClient;
+use crate::emitter::EmitterClient;
+use crate::liquidity_pool::LPClient;
+use crate::pool::PoolClient;
+use crate::pool_factory::PoolFactoryClient;
+use crate::test_fixture::{PoolFixture, TestFixture, TokenIndex};
+use crate::treasury::TreasuryClient;
+use treasury::TreasuryContract;
+use crate::treasury_factory::TreasuryFactoryClient;
+
+/// Addresses belong to the Env that created them, so they are kept as XDR and rebuilt in the
+/// restored Env
+fn to_sc_address(address: &Address) -> ScAddress {
+    ScAddress::try_from(address).unwrap()
+}
+
+fn from_sc_address(e: &Env, address: &ScAddress) -> Address {
+    Address::try_from_val(e, address).unwrap()
+}
+
+/// The contracts deployed for a pool of a snapshotted fixture
+#[derive(Clone)]
+struct PoolSnapshot {
+    treasury: ScAddress,
+    pool: ScAddress,
+    reserves: HashMap<TokenIndex, u32>,
+    oracle: ScAddress,
+}
+
+/// The full ledger state of a TestFixture, along with the contracts needed to rebuild it.
+///
+/// Take one after an expensive setup and restore it for each test case, so every case starts
+/// from the same state without deploying and seeding the protocol again.
+#[derive(Clone)]
+pub struct FixtureSnapshot {
+    pub snapshot: Snapshot,
+    wasm: bool,
+    bombadil: ScAddress,
+    users: Vec<ScAddress>,
+    emitter: ScAddress,
+    backstop: ScAddress,
+    pool_factory: ScAddress,
+    treasury_factory: ScAddress,
+    oracle: ScAddress,
+    lp: ScAddress,
+    pools: Vec<PoolSnapshot>,
+    tokens: Vec<ScAddress>,
+}
+
+impl TestFixture<'_> {
+    /// Snapshot the ledger state of the fixture
+    pub fn snapshot(&self) -> FixtureSnapshot {
+        FixtureSnapshot {
+            snapshot: self.env.to_snapshot(),
+            wasm: self.wasm,
+            bombadil: to_sc_address(&self.bombadil),
+            users: self.users.iter().map(to_sc_address).collect(),
+            emitter: to_sc_address(&self.emitter.address),
+            backstop: to_sc_address(&self.backstop.address),
+            pool_factory: to_sc_address(&self.pool_factory.address),
+            treasury_factory: to_sc_address(&self.treasury_factory.address),
+            oracle: to_sc_address(&self.oracle.address),
+            lp: to_sc_address(&self.lp.address),
+            pools: self
+                .pools
+                .iter()
+                .map(|pool_fixture| PoolSnapshot {
+                    treasury: to_sc_address(&pool_fixture.treasury.address),
+                    pool: to_sc_address(&pool_fixture.pool.address),
+                    reserves: pool_fixture.reserves.clone(),
+                    oracle: to_sc_address(&pool_fixture.oracle.address),
+                })
+                .collect(),
+            tokens: self.tokens.iter().map(|token| to_sc_address(&token.address)).collect(),
+        }
+    }
+}
+
+impl FixtureSnapshot {
+    /// Restore the snapshot into a new TestFixture with its own Env
+    ///
+    /// Changes made to the restored fixture do not affect the snapshot, so it can be restored
+    /// again for the next test case.
+    pub fn restore<'a>(&self) -> TestFixture<'a> {
+        let e = Env::from_snapshot(self.snapshot.clone());
+        e.mock_all_auths();
+        e.budget().reset_unlimited();
+
+        let address = |address: &ScAddress| from_sc_address(&e, address);
+        // natively registered contracts are not part of the ledger, so register them again
+        if !self.wasm {
+            for pool_snapshot in self.pools.iter() {
+                e.register_contract(&address(&pool_snapshot.treasury), TreasuryContract {});
+            }
+        }
+        TestFixture {
+            wasm: self.wasm,
+            bombadil: address(&self.bombadil),
+            users: self.users.iter().map(address).collect(),
+            emitter: EmitterClient::new(&e, &address(&self.emitter)),
+            backstop: BackstopClient::new(&e, &address(&self.backstop)),
+            pool_factory: PoolFactoryClient::new(&e, &address(&self.pool_factory)),
+            treasury_factory: TreasuryFactoryClient::new(&e, &address(&self.treasury_factory)),
+            oracle: MockPriceOracleClient::new(&e, &address(&self.oracle)),
+            lp: LPClient::new(&e, &address(&self.lp)),
+            pools: self
+                .pools
+                .iter()
+                .map(|pool_snapshot| PoolFixture {
+                    treasury: TreasuryClient::new(&e, &address(&pool_snapshot.treasury)),
+                    pool: PoolClient::new(&e, &address(&pool_snapshot.pool)),
+                    reserves: pool_snapshot.reserves.clone(),
+                    oracle: BridgeOracleClient::new(&e, &address(&pool_snapshot.oracle)),
+                })
+                .collect(),
+            tokens: self
+                .tokens
+                .iter()
+                .map(|token| MockTokenClient::new(&e, &address(token)))
+                .collect(),
+            env: e,
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::backstop::create_backstop;
 use crate::emitter::create_emitter;
 use crate::liquidity_pool::{create_lp_pool, LPClient};
 use crate::oracle::create_mock_oracle;
-use crate::pool::{POOL_WASM, ReserveEmissionsData, PoolDataKey, ReserveEmissionsConfig, PoolClient, PoolConfig, ReserveConfig, ReserveData};
+use crate::pool::{POOL_WASM, ReserveEmissionsData, PoolDataKey, ReserveEmissionsConfig, PoolClient, PoolConfig, ReserveConfig, ReserveData, Request, RequestType};
 use crate::token::{create_stellar_token};
 use crate::backstop::BackstopClient;
 use crate::emitter::EmitterClient;
@@ -14,7 +14,9 @@ use sep_40_oracle::testutils::{Asset, MockPriceOracleClient};
 use sep_41_token::testutils::{MockTokenClient, MockTokenWASM};
 use soroban_sdk::testutils::{Address as _, BytesN as _, Ledger, LedgerInfo};
 use soroban_sdk::{vec as svec, Address, BytesN, Env, Map, Symbol};
-use crate::treasury::{TreasuryClient, TREASURY_WASM};
+use crate::treasury::{
+    create_treasury, initialize_treasury, read_supply, TreasuryClient, TREASURY_WASM,
+};
 use crate::bridge_oracle::{BRIDGE_ORACLE_WASM, BridgeOracleClient, create_bridge_oracle};
 use crate::treasury_factory::{create_treasury_factory, TreasuryFactoryClient, TreasuryInitMeta};
 
@@ -46,6 +48,7 @@ impl<'a> Index<TokenIndex> for Vec<MockTokenClient<'a>> {
 
 pub struct TestFixture<'a> {
     pub env: Env,
+    pub wasm: bool,
     pub bombadil: Address,
     pub users: Vec<Address>,
     pub emitter: EmitterClient<'a>,
@@ -63,8 +66,21 @@ impl TestFixture<'_> {
     ///
     /// Deploys BLND (0), USDC (1), wETH (2), XLM (3), and STABLE (4) test tokens, alongside all required
     /// Blend Protocol contracts, including a BLND-USDC LP.
-    pub fn create<'a>() -> TestFixture<'a> {
-        let e = Env::default();
+    ///
+    /// ### Arguments
+    /// * `wasm` - If treasuries are deployed from their wasm, rather than registered natively
+    pub fn create<'a>(wasm: bool) -> TestFixture<'a> {
+        TestFixture::create_with_env(&Env::default(), wasm)
+    }
+
+    /// Create a new TestFixture for the Blend Protocol in an existing Env. The Env has all auths
+    /// mocked, an unlimited budget, and its ledger set to the backstop epoch.
+    ///
+    /// ### Arguments
+    /// * `e` - The Env to deploy to
+    /// * `wasm` - If treasuries are deployed from their wasm, rather than registered natively
+    pub fn create_with_env<'a>(e: &Env, wasm: bool) -> TestFixture<'a> {
+        let e = e.clone();
         e.mock_all_auths();
         e.budget().reset_unlimited();
 
@@ -157,6 +173,7 @@ impl TestFixture<'_> {
 
         let fixture = TestFixture {
             env: e,
+            wasm,
             bombadil,
             users: vec![],
             emitter: emitter_client,
@@ -178,30 +195,68 @@ impl TestFixture<'_> {
     }
 
     pub fn create_pool(&mut self, name: Symbol, backstop_take_rate: u32, max_positions: u32) {
+        let oracle = self.oracle.address.clone();
+        self.create_pool_with_oracle(name, backstop_take_rate, max_positions, &oracle);
+    }
+
+    /// Create a pool whose bridge oracle reads prices from `oracle` instead of the fixture's
+    /// mock oracle
+    pub fn create_pool_with_oracle(
+        &mut self,
+        name: Symbol,
+        backstop_take_rate: u32,
+        max_positions: u32,
+        oracle: &Address,
+    ) {
         let (bridge_id, bridge_client) = create_bridge_oracle(&self.env);
         let from = self.tokens[TokenIndex::OUSD].address.clone();
         let to = self.tokens[TokenIndex::USDC].address.clone();
-        bridge_client.initialize(&from, &to, &self.oracle.address);
+        bridge_client.initialize(&from, &to, oracle);
+        self.create_pool_with_bridge(name, backstop_take_rate, max_positions, &bridge_id);
+    }
+
+    /// Create a pool that reads prices from an initialized bridge oracle, such as one
+    /// registered natively
+    pub fn create_pool_with_bridge(
+        &mut self,
+        name: Symbol,
+        backstop_take_rate: u32,
+        max_positions: u32,
+        bridge_id: &Address,
+    ) {
         let pool_id = self.pool_factory.deploy(
             &self.bombadil,
             &name,
             &BytesN::<32>::random(&self.env),
-            &bridge_id,
+            bridge_id,
             &backstop_take_rate,
             &max_positions,
         );
         let ousd_id = &self.tokens[TokenIndex::OUSD];
-        let treasury_id = self.treasury_factory.deploy(
-            &BytesN::<32>::random(&self.env),
-            &ousd_id.address.clone(),
-            &pool_id
-        );
+        let treasury_id = if self.wasm {
+            self.treasury_factory.deploy(
+                &BytesN::<32>::random(&self.env),
+                &ousd_id.address.clone(),
+                &pool_id
+            )
+        } else {
+            let (treasury_id, _) = create_treasury(&self.env, false);
+            initialize_treasury(
+                &self.env,
+                &treasury_id,
+                false,
+                &self.bombadil,
+                &ousd_id.address,
+                &pool_id,
+            );
+            treasury_id
+        };
         ousd_id.set_admin(&treasury_id);
         self.pools.push(PoolFixture {
             pool: PoolClient::new(&self.env, &pool_id),
             treasury: TreasuryClient::new(&self.env, &treasury_id),
             reserves: HashMap::new(),
-            oracle: bridge_client,
+            oracle: BridgeOracleClient::new(&self.env, bridge_id),
         });
     }
 
@@ -245,6 +300,10 @@ impl TestFixture<'_> {
         })
     }
 
+    pub fn read_treasury_supply(&self, pool_index: usize) -> i128 {
+        read_supply(&self.env, &self.pools[pool_index].treasury.address)
+    }
+
     pub fn read_reserve_config(&self, pool_index: usize, asset_index: TokenIndex) -> ReserveConfig {
         let treasury_fixture = &self.pools[pool_index];
         let token = &self.tokens[asset_index];
@@ -325,4 +384,52 @@ impl TestFixture<'_> {
             max_entry_ttl: 9999999,
         });
     }
+
+    /// Advance the sequence by `ledgers`, and the timestamp by 5 seconds a ledger
+    ///
+    /// Entries are archived once the sequence passes their TTL, so a long jump without any
+    /// calls in between can leave contracts unusable.
+    pub fn jump_ledgers(&self, ledgers: u32) {
+        self.jump_with_sequence(ledgers as u64 * 5);
+    }
+
+    /// Advance the timestamp by `time` seconds, and the sequence by a ledger every 5 seconds
+    pub fn jump_time(&self, time: u64) {
+        self.jump_with_sequence(time);
+    }
+
+    /// Advance the timestamp and sequence like `jump_time`, then accrue interest on every
+    /// reserve of every pool
+    pub fn jump_time_and_accrue(&self, time: u64) {
+        self.jump_time(time);
+        for pool_index in 0..self.pools.len() {
+            self.accrue_interest(pool_index);
+        }
+    }
+
+    /// Accrue interest on every reserve of a pool, by having bombadil supply and withdraw a
+    /// token of each. The reserve data read back afterwards is current.
+    pub fn accrue_interest(&self, pool_index: usize) {
+        let pool_fixture = &self.pools[pool_index];
+        let mut requests = svec![&self.env];
+        for asset_index in pool_fixture.reserves.keys() {
+            let token = &self.tokens[*asset_index];
+            token.mint(&self.bombadil, &SCALAR_7);
+            requests.push_back(Request {
+                request_type: RequestType::Supply.into_u32(),
+                address: token.address.clone(),
+                amount: SCALAR_7,
+            });
+            requests.push_back(Request {
+                request_type: RequestType::Withdraw.into_u32(),
+                address: token.address.clone(),
+                amount: SCALAR_7,
+            });
+        }
+        if !requests.is_empty() {
+            pool_fixture
+                .pool
+                .submit(&self.bombadil, &self.bombadil, &self.bombadil, &requests);
+        }
+    }
 }
@@ -0,0 +1,10 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+pub use ::timelock::{TimelockClient, TimelockSettings};
+
+/// Deploy an uninitialized timelock, registered natively from source
+pub fn create_timelock<'a>(e: &Env) -> (Address, TimelockClient<'a>) {
+    let contract_id = Address::generate(e);
+    e.register_contract(&contract_id, ::timelock::TimelockContract {});
+    (contract_id.clone(), TimelockClient::new(e, &contract_id))
+}
@@ -1,7 +1,68 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal, Symbol, Val, Vec};
+
 mod treasury_contract {
     soroban_sdk::contractimport!(
         file = "../wasm/treasury.wasm"
     );
 }
 
-pub use treasury_contract::{Client as TreasuryClient, WASM as TREASURY_WASM};
\ No newline at end of file
+pub use treasury_contract::{Client as TreasuryClient, WASM as TREASURY_WASM};
+
+/// The rent bump treasuries are initialized with, the same as the treasury factory's
+pub const TTL_THRESHOLD: u32 = 172_800;
+pub const TTL_BUMP: u32 = 241_920;
+
+/// Deploy an uninitialized treasury, from its wasm or natively from source
+pub fn create_treasury<'a>(e: &Env, wasm: bool) -> (Address, TreasuryClient<'a>) {
+    let contract_id = Address::generate(e);
+    if wasm {
+        e.register_contract_wasm(&contract_id, TREASURY_WASM);
+    } else {
+        e.register_contract(&contract_id, ::treasury::TreasuryContract {});
+    }
+    (contract_id.clone(), TreasuryClient::new(e, &contract_id))
+}
+
+/// Initialize a treasury deployed by `create_treasury`. The current build also takes the rent
+/// bump, which the released wasm does not.
+pub fn initialize_treasury(
+    e: &Env,
+    treasury_id: &Address,
+    wasm: bool,
+    admin: &Address,
+    token: &Address,
+    blend_pool: &Address,
+) {
+    if wasm {
+        TreasuryClient::new(e, treasury_id).initialize(admin, token, blend_pool);
+    } else {
+        ::treasury::TreasuryClient::new(e, treasury_id).initialize(
+            admin,
+            token,
+            blend_pool,
+            &TTL_THRESHOLD,
+            &TTL_BUMP,
+        );
+    }
+}
+
+/// Replace the code of a deployed treasury with the current build, registered natively, while
+/// keeping its storage. The deployed wasm has no `upgrade` entrypoint, so this stands in for one.
+pub fn upgrade_to_native(e: &Env, treasury_id: &Address) {
+    e.register_contract(treasury_id, ::treasury::TreasuryContract {});
+}
+
+/// Read the supply a treasury tracks straight from its storage, as either the released wasm or
+/// the current build keeps it. The released wasm has no `get_token_supply`.
+pub fn read_supply(e: &Env, treasury_id: &Address) -> i128 {
+    e.as_contract(treasury_id, || {
+        let instance = e.storage().instance();
+        let token: Address = instance.get(&Symbol::new(e, "Token")).unwrap();
+        let key: Vec<Val> = vec![e, Symbol::new(e, "TokenSupply").into_val(e), token.into_val(e)];
+        e.storage()
+            .persistent()
+            .get(&key)
+            .or_else(|| instance.get(&Symbol::new(e, "TokenSupply")))
+            .unwrap_or(0)
+    })
+}
@@ -0,0 +1,174 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use admin::AdminError;
+use governor::{GovernorError, VOTE_AGAINST, VOTE_FOR};
+use soroban_sdk::{
+    testutils::Address as _, vec, Address, Env, Error, IntoVal, String, Symbol, Val, Vec,
+};
+use test_suites::{
+    admin::create_admin,
+    assert_contract_err, create_fixture_with_data,
+    governor::{create_governor, GovernorSettings},
+    pool::ReserveConfig,
+    test_fixture::{TokenIndex, SCALAR_7},
+    timelock::{create_timelock, TimelockSettings},
+    token::create_stellar_token,
+};
+use timelock::TimelockError;
+
+const DAY: u64 = 24 * 60 * 60;
+/// The code of the pool's InitNotUnlocked error, for a queued reserve change still locked
+const INIT_NOT_UNLOCKED: u32 = 1203;
+
+/// A governor call that queues `function` on the admin contract in the timelock
+fn queue_admin_call(
+    e: &Env,
+    timelock: &Address,
+    admin: &Address,
+    function: &str,
+    args: Vec<Val>,
+) -> (Address, Symbol, Vec<Val>) {
+    let args = vec![
+        e,
+        admin.into_val(e),
+        Symbol::new(e, function).into_val(e),
+        args.into_val(e),
+    ];
+    (timelock.clone(), Symbol::new(e, "queue"), args)
+}
+
+/// Reserve config changes to a live pool go through the whole governance stack. A governor
+/// proposal must reach its quorum before it queues the changes in the timelock, which owns the
+/// admin contract, which is the pool's admin. Each layer enforces its own delay, and the
+/// guardian can cancel a change at the timelock or at the pool.
+#[test]
+fn test_reserve_config_through_governance() {
+    let fixture = create_fixture_with_data(false);
+    let e = &fixture.env;
+    let pool_fixture = &fixture.pools[0];
+    let pool = &pool_fixture.pool.address;
+    let ousd = &fixture.tokens[TokenIndex::OUSD].address;
+    let xlm = &fixture.tokens[TokenIndex::XLM].address;
+    let guardian = Address::generate(e);
+    let ir_manager = Address::generate(e);
+
+    let (vote_token_id, vote_token) = create_stellar_token(e, &fixture.bombadil);
+    let (governor_id, governor_client) = create_governor(e);
+    governor_client.initialize(
+        &vote_token_id,
+        &guardian,
+        &GovernorSettings {
+            proposal_threshold: 1_000 * SCALAR_7,
+            voting_period: 3 * DAY,
+            quorum: 50_000 * SCALAR_7,
+            timelock: DAY,
+        },
+    );
+    let (timelock_id, timelock_client) = create_timelock(e);
+    timelock_client.initialize(
+        &governor_id,
+        &guardian,
+        &TimelockSettings {
+            min_delay: 2 * DAY,
+            grace_period: 7 * DAY,
+        },
+    );
+    let (admin_id, admin_client) = create_admin(e);
+    admin_client.initialize(&timelock_id, &ir_manager, &guardian);
+    pool_fixture.pool.set_admin(&admin_id);
+
+    let proposer = Address::generate(e);
+    let whale = Address::generate(e);
+    let dolphin = Address::generate(e);
+    let skeptic = Address::generate(e);
+    let minnow = Address::generate(e);
+    vote_token.mint(&minnow, &(500 * SCALAR_7));
+    vote_token.mint(&proposer, &(1_000 * SCALAR_7));
+    vote_token.mint(&whale, &(30_000 * SCALAR_7));
+    vote_token.mint(&dolphin, &(25_000 * SCALAR_7));
+    vote_token.mint(&skeptic, &(10_000 * SCALAR_7));
+
+    let mut ousd_config: ReserveConfig = fixture.read_reserve_config(0, TokenIndex::OUSD);
+    ousd_config.c_factor = 8_500_000;
+    ousd_config.max_util = 9_000_000;
+    let mut xlm_config: ReserveConfig = fixture.read_reserve_config(0, TokenIndex::XLM);
+    xlm_config.c_factor = 5_000_000;
+    let calls = vec![
+        e,
+        queue_admin_call(
+            e,
+            &timelock_id,
+            &admin_id,
+            "queue_set_reserve",
+            vec![e, pool.into_val(e), ousd.into_val(e), ousd_config.into_val(e)],
+        ),
+        queue_admin_call(
+            e,
+            &timelock_id,
+            &admin_id,
+            "queue_set_reserve",
+            vec![e, pool.into_val(e), xlm.into_val(e), xlm_config.into_val(e)],
+        ),
+    ];
+    let description = String::from_str(e, "Tighten oUSD and XLM");
+
+    // only holders over the proposal threshold can propose, and a proposal that misses the
+    // quorum can't be queued
+    let result = governor_client.try_propose(&minnow, &calls, &description);
+    assert_contract_err!(result, GovernorError::InsufficientVotingPower);
+    let failed = governor_client.propose(&proposer, &calls, &description);
+    governor_client.vote(&whale, &failed, &VOTE_FOR, &(30_000 * SCALAR_7));
+    governor_client.vote(&skeptic, &failed, &VOTE_AGAINST, &(10_000 * SCALAR_7));
+    fixture.jump(3 * DAY);
+    let result = governor_client.try_queue(&failed);
+    assert_contract_err!(result, GovernorError::ProposalNotSucceeded);
+    for voter in [&whale, &skeptic] {
+        governor_client.reclaim(voter, &failed);
+    }
+
+    // with the quorum reached, the proposal queues both changes in the timelock
+    let passed = governor_client.propose(&proposer, &calls, &description);
+    governor_client.vote(&whale, &passed, &VOTE_FOR, &(30_000 * SCALAR_7));
+    governor_client.vote(&dolphin, &passed, &VOTE_FOR, &(25_000 * SCALAR_7));
+    governor_client.vote(&skeptic, &passed, &VOTE_AGAINST, &(10_000 * SCALAR_7));
+    fixture.jump(3 * DAY);
+    governor_client.queue(&passed);
+    let result = governor_client.try_execute(&passed);
+    assert_contract_err!(result, GovernorError::TimelockNotExpired);
+    fixture.jump(DAY);
+    governor_client.execute(&passed);
+    let (ousd_op, xlm_op) = (0, 1);
+    let operation = timelock_client.get_operation(&ousd_op);
+    assert_eq!(operation.function, Symbol::new(e, "queue_set_reserve"));
+
+    // the timelock holds both changes for its delay, and the guardian cancels the XLM change
+    let result = timelock_client.try_execute(&ousd_op);
+    assert_contract_err!(result, TimelockError::TimelockNotExpired);
+    timelock_client.cancel(&guardian, &xlm_op);
+    fixture.jump(2 * DAY);
+    timelock_client.execute(&ousd_op);
+    let result = timelock_client.try_execute(&xlm_op);
+    assert_contract_err!(result, TimelockError::OperationNotQueued);
+    assert_eq!(admin_client.get_reserve_config(pool, ousd).c_factor, 8_500_000);
+    let result = admin_client.try_get_reserve_config(pool, xlm);
+    assert_contract_err!(result, AdminError::ReserveNotConfigured);
+
+    // the pool applies its own delay before the change takes effect
+    let result = pool_fixture.pool.try_set_reserve(ousd);
+    assert_contract_err!(result, Error::from_contract_error(INIT_NOT_UNLOCKED));
+    fixture.jump(7 * DAY);
+    pool_fixture.pool.set_reserve(ousd);
+    let applied: ReserveConfig = fixture.read_reserve_config(0, TokenIndex::OUSD);
+    assert_eq!((applied.c_factor, applied.max_util), (8_500_000, 9_000_000));
+    assert_eq!(fixture.read_reserve_config(0, TokenIndex::XLM).c_factor, 7_500_000);
+
+    // the interest rate manager tunes the curve directly, and the guardian can still cancel
+    // the change at the pool before it takes effect
+    let (r_one, r_two, r_three, util) = (0_0400000, 0_4000000, 1_2000000, 0_8000000);
+    admin_client.set_ir_params(&ir_manager, pool, ousd, &r_one, &r_two, &r_three, &util);
+    admin_client.cancel_set_reserve(&guardian, pool, ousd);
+    fixture.jump(7 * DAY);
+    // with nothing left queued, the pool traps
+    assert!(pool_fixture.pool.try_set_reserve(ousd).is_err());
+    assert_eq!(fixture.read_reserve_config(0, TokenIndex::OUSD).r_one, applied.r_one);
+}
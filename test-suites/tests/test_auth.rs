@@ -0,0 +1,81 @@
+#![cfg(test)]
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use test_suites::{
+    auth::{assert_authorizers, assert_auths, assert_no_auths, invocation},
+    create_fixture_with_data,
+    parity::assert_parity,
+    pool::{Request, RequestType},
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// Only the treasury admin authorizes supply changes. The treasury's own calls into the pool
+/// and token need no other address.
+#[test]
+fn test_treasury_auths() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let e = &fixture.env;
+        let treasury = &fixture.pools[0].treasury;
+
+        let amount = 1_000 * SCALAR_7;
+        treasury.increase_supply(&amount);
+        assert_auths(
+            e,
+            &[(
+                fixture.bombadil.clone(),
+                invocation(e, &treasury.address, "increase_supply", (amount,), &[]),
+            )],
+        );
+        treasury.decrease_supply(&amount);
+        assert_authorizers(e, std::slice::from_ref(&fixture.bombadil));
+
+        treasury.get_blend_address();
+        assert_no_auths(e);
+    });
+}
+
+/// A borrower authorizes the submit and, under it, the transfer of their collateral
+#[test]
+fn test_pool_submit_auths() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let e = &fixture.env;
+        let pool = &fixture.pools[0].pool;
+        let xlm = &fixture.tokens[TokenIndex::XLM];
+        let samwise = Address::generate(e);
+        xlm.mint(&samwise, &(1_000 * SCALAR_7));
+
+        let requests = vec![
+            e,
+            Request {
+                request_type: RequestType::SupplyCollateral.into_u32(),
+                address: xlm.address.clone(),
+                amount: 1_000 * SCALAR_7,
+            },
+        ];
+        pool.submit(&samwise, &samwise, &samwise, &requests);
+        let transfer = invocation(
+            e,
+            &xlm.address,
+            "transfer",
+            (samwise.clone(), pool.address.clone(), 1_000 * SCALAR_7),
+            &[],
+        );
+        let args = (samwise.clone(), samwise.clone(), samwise.clone(), requests);
+        assert_auths(
+            e,
+            &[(
+                samwise.clone(),
+                invocation(e, &pool.address, "submit", args, &[transfer]),
+            )],
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "authorized by other addresses")]
+fn test_assert_authorizers_panics() {
+    let fixture = create_fixture_with_data(true);
+    fixture.pools[0].treasury.increase_supply(&SCALAR_7);
+    assert_authorizers(&fixture.env, &[fixture.users[0].clone()]);
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use soroban_sdk::{testutils::Address as _, vec as svec, Address};
+use test_suites::{
+    auctions::{liquidate_by_price, open_ousd_borrow, refresh_prices},
+    benchmark::{equity, BenchmarkTable},
+    budget::{measure, Resources, TX_LIMITS},
+    create_fixture_with_data,
+    pool::{Request, RequestType},
+    test_fixture::{TestFixture, TokenIndex, SCALAR_7},
+};
+
+/// A fixture where two borrowers are being auctioned, and a liquidator is ready to fill
+struct Liquidation<'a> {
+    fixture: TestFixture<'a>,
+    liquidator: Address,
+    borrowers: [Address; 2],
+}
+
+/// Auction two oUSD borrowers, and let the auctions run for `ledgers`
+fn setup_liquidation<'a>(ledgers: u32) -> Liquidation<'a> {
+    let fixture = create_fixture_with_data(true);
+    let xlm = &fixture.tokens[TokenIndex::XLM];
+    let borrowers = [Address::generate(&fixture.env), Address::generate(&fixture.env)];
+    for borrower in borrowers.iter() {
+        xlm.mint(borrower, &(10_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, 0, borrower, 10_000 * SCALAR_7, 700 * SCALAR_7);
+    }
+    for borrower in borrowers.iter() {
+        liquidate_by_price(&fixture, 0, borrower, 0_0900000, 60);
+    }
+
+    // the liquidator backs the debt it takes on with collateral of its own
+    let liquidator = Address::generate(&fixture.env);
+    xlm.mint(&liquidator, &(20_000 * SCALAR_7));
+    fixture.tokens[TokenIndex::OUSD].mint(&liquidator, &(2_000 * SCALAR_7));
+    submit(&fixture, &liquidator, &[supply_collateral(&fixture, 20_000 * SCALAR_7)]);
+
+    fixture.jump_ledgers(ledgers);
+    refresh_prices(&fixture);
+    Liquidation {
+        fixture,
+        liquidator,
+        borrowers,
+    }
+}
+
+fn supply_collateral(fixture: &TestFixture, amount: i128) -> Request {
+    Request {
+        request_type: RequestType::SupplyCollateral.into_u32(),
+        address: fixture.tokens[TokenIndex::XLM].address.clone(),
+        amount,
+    }
+}
+
+fn fill(borrower: &Address) -> Request {
+    Request {
+        request_type: RequestType::FillUserLiquidationAuction.into_u32(),
+        address: borrower.clone(),
+        amount: 100,
+    }
+}
+
+fn repay(fixture: &TestFixture, amount: i128) -> Request {
+    Request {
+        request_type: RequestType::Repay.into_u32(),
+        address: fixture.tokens[TokenIndex::OUSD].address.clone(),
+        amount,
+    }
+}
+
+fn submit(fixture: &TestFixture, liquidator: &Address, requests: &[Request]) {
+    let mut batch = svec![&fixture.env];
+    for request in requests {
+        batch.push_back(request.clone());
+    }
+    fixture.pools[0].pool.submit(liquidator, liquidator, liquidator, &batch);
+}
+
+/// Run a strategy as a sequence of submits, measuring each, and record what it used in total
+/// and the profit the liquidator realized
+fn run_strategy(
+    table: &mut BenchmarkTable,
+    name: &str,
+    ledgers: u32,
+    submits: impl Fn(&Liquidation) -> Vec<Vec<Request>>,
+) {
+    let liquidation = setup_liquidation(ledgers);
+    let fixture = &liquidation.fixture;
+    let before = equity(fixture, 0, &liquidation.liquidator);
+    let mut used = Resources::default();
+    for requests in submits(&liquidation) {
+        let (_, resources) = measure(&fixture.env, || {
+            submit(fixture, &liquidation.liquidator, &requests)
+        });
+        used = used + resources;
+    }
+    let profit = equity(fixture, 0, &liquidation.liquidator) - before;
+    table.record(name, used, profit);
+}
+
+/// Compare the ways a liquidator can fill user liquidation auctions, and write the results to
+/// a table. Only the wasm is measured, as native contracts are not metered like their wasm.
+#[test]
+fn test_benchmark_liquidation_strategies() {
+    let mut table = BenchmarkTable::new("liquidation strategies");
+    run_strategy(&mut table, "single fills", 201, |liquidation| {
+        let [samwise, merry] = &liquidation.borrowers;
+        vec![vec![fill(samwise)], vec![fill(merry)]]
+    });
+    run_strategy(&mut table, "batched fills", 201, |liquidation| {
+        let [samwise, merry] = &liquidation.borrowers;
+        vec![vec![fill(samwise), fill(merry)]]
+    });
+    run_strategy(&mut table, "batched fills and repay", 201, |liquidation| {
+        let [samwise, merry] = &liquidation.borrowers;
+        let repay = repay(&liquidation.fixture, 2_000 * SCALAR_7);
+        vec![vec![fill(samwise), fill(merry), repay]]
+    });
+    run_strategy(&mut table, "early batched fills", 100, |liquidation| {
+        let [samwise, merry] = &liquidation.borrowers;
+        vec![vec![fill(samwise), fill(merry)]]
+    });
+    let path = table.write();
+    println!("{}\nwritten to {}", table.render(), path.display());
+
+    // batching shares the reads and writes of the pool between the fills
+    let single = table.row("single fills");
+    let batched = table.row("batched fills");
+    assert!(batched.used.cpu_insns < single.used.cpu_insns);
+    assert!(batched.used.read_entries + batched.used.write_entries
+        < single.used.read_entries + single.used.write_entries);
+    assert!(single.profit > 0);
+    assert_eq!(batched.profit, single.profit);
+
+    // repaying in the same submit fits in a transaction, and realizes the same profit
+    let repaid = table.row("batched fills and repay");
+    assert!(repaid.used.cpu_insns <= TX_LIMITS.cpu_insns);
+    assert!((repaid.profit - batched.profit).abs() <= 10);
+
+    // filling early, before the full lot is offered, realizes less
+    let early = table.row("early batched fills");
+    assert!(early.profit < batched.profit);
+    assert!(std::fs::read_to_string(path).unwrap().contains("| early batched fills |"));
+}
@@ -0,0 +1,46 @@
+#![cfg(test)]
+use soroban_sdk::{testutils::Address as _, Address};
+use test_suites::{
+    auctions::{open_ousd_borrow, refresh_prices},
+    budget::{assert_within_limits, measure, Resources, TX_LIMITS},
+    create_fixture_with_data,
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// The treasury and a borrow through the pool fit in a single transaction. Native contracts
+/// are not metered like their wasm, so only the wasm is measured.
+#[test]
+fn test_treasury_and_pool_within_tx_limits() {
+    let fixture = create_fixture_with_data(true);
+    let pool_fixture = &fixture.pools[0];
+
+    let (_, used) = measure(&fixture.env, || {
+        pool_fixture.treasury.increase_supply(&(10_000 * SCALAR_7))
+    });
+    assert!(used.cpu_insns > 0 && used.mem_bytes > 0);
+    assert!(used.read_entries > 0 && used.write_entries > 0);
+    assert_within_limits(&fixture.env, &TX_LIMITS, || {
+        pool_fixture.treasury.decrease_supply(&(10_000 * SCALAR_7))
+    });
+
+    let samwise = Address::generate(&fixture.env);
+    fixture.tokens[TokenIndex::XLM].mint(&samwise, &(10_000 * SCALAR_7));
+    refresh_prices(&fixture);
+    let positions = assert_within_limits(&fixture.env, &TX_LIMITS, || {
+        open_ousd_borrow(&fixture, 0, &samwise, 10_000 * SCALAR_7, 100 * SCALAR_7)
+    });
+    assert_eq!(positions.liabilities.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "exceed the limits")]
+fn test_assert_within_limits_panics() {
+    let fixture = create_fixture_with_data(true);
+    let limits = Resources {
+        write_entries: 0,
+        ..TX_LIMITS
+    };
+    assert_within_limits(&fixture.env, &limits, || {
+        fixture.pools[0].treasury.increase_supply(&SCALAR_7)
+    });
+}
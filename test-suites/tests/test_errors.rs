@@ -0,0 +1,150 @@
+#![cfg(test)]
+use admin::AdminError;
+use mock_pool::{MockPoolError, Positions, Request};
+use orbit_common::pool::{PoolError, RequestType};
+use soroban_sdk::{map, testutils::Address as _, vec, Address, Env, Symbol, Val, Vec};
+use test_suites::{
+    admin::create_admin, assert_contract_err, pool::create_mock_pool, test_fixture::SCALAR_7,
+    treasury::{create_treasury, TTL_BUMP, TTL_THRESHOLD},
+};
+use treasury::{TreasuryClient, TreasuryError};
+
+/// Raise every error the treasury can raise. InternalError and AlreadyInitializedError are
+/// declared but never raised.
+#[test]
+fn test_treasury_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let (pool_id, pool_client) = create_mock_pool(&e, &vec![&e, ousd.clone()]);
+    treasury_client.initialize(&Address::generate(&e), &ousd, &pool_id, &TTL_THRESHOLD, &TTL_BUMP);
+    treasury_client.increase_supply(&(1000 * SCALAR_7));
+
+    // only a flash minter can flash mint, and none is set
+    let result = treasury_client.try_flash_mint(&SCALAR_7);
+    assert_contract_err!(result, TreasuryError::UnauthorizedError);
+
+    // the supply can't drop below what was minted, or what the pool holds
+    let result = treasury_client.try_decrease_supply(&(1001 * SCALAR_7));
+    assert_contract_err!(result, TreasuryError::SupplyError);
+    let short = Positions {
+        liabilities: map![&e],
+        collateral: map![&e],
+        supply: map![&e, (0, 100 * SCALAR_7)],
+    };
+    pool_client.set_reported(&treasury_id, &short);
+    let result = treasury_client.try_decrease_supply(&(500 * SCALAR_7));
+    assert_contract_err!(result, TreasuryError::SupplyError);
+
+    // nor can it take more than the pool holds once borrowers have drawn it down
+    pool_client.clear_reported(&treasury_id);
+    let borrower = Address::generate(&e);
+    let borrow = Request {
+        request_type: RequestType::Borrow.into_u32(),
+        address: ousd.clone(),
+        amount: 600 * SCALAR_7,
+    };
+    pool_client.submit(&borrower, &borrower, &borrower, &vec![&e, borrow]);
+    let result = treasury_client.try_decrease_supply(&(500 * SCALAR_7));
+    assert_contract_err!(result, TreasuryError::InsufficientLiquidity);
+
+    // adapters take positive amounts, up to their cap, and return no more than they hold
+    let adapter = Address::generate(&e);
+    let result = treasury_client.try_deposit_adapter(&adapter, &0);
+    assert_contract_err!(result, TreasuryError::NegativeAmountError);
+    let result = treasury_client.try_withdraw_adapter(&adapter, &-1);
+    assert_contract_err!(result, TreasuryError::NegativeAmountError);
+    let result = treasury_client.try_deposit_adapter(&adapter, &SCALAR_7);
+    assert_contract_err!(result, TreasuryError::AdapterCapExceeded);
+    let result = treasury_client.try_withdraw_adapter(&adapter, &SCALAR_7);
+    assert_contract_err!(result, TreasuryError::SupplyError);
+
+    // a request the pool refuses fails the treasury with the error the pool's error maps to
+    pool_client.set_revert(&true);
+    let errors = [
+        (MockPoolError::SubmitFailed as u32, TreasuryError::PoolSubmitFailed),
+        (PoolError::BadRequest as u32, TreasuryError::PoolSubmitFailed),
+        (PoolError::InvalidPoolStatus as u32, TreasuryError::PoolFrozen),
+        (PoolError::StalePrice as u32, TreasuryError::PoolStalePrice),
+        (PoolError::InvalidUtilRate as u32, TreasuryError::InsufficientLiquidity),
+        (PoolError::NegativeAmountError as u32, TreasuryError::NegativeAmountError),
+        (PoolError::BalanceError as u32, TreasuryError::BalanceError),
+        (PoolError::OverflowError as u32, TreasuryError::OverflowError),
+    ];
+    for (code, error) in errors {
+        pool_client.set_revert_error(&code);
+        let result = treasury_client.try_increase_supply(&SCALAR_7);
+        assert_eq!(result.err(), Some(Ok(error.into())), "increase {}", code);
+        let result = treasury_client.try_decrease_supply(&SCALAR_7);
+        assert_eq!(result.err(), Some(Ok(error.into())), "decrease {}", code);
+    }
+
+    // a shut down treasury mints no more
+    pool_client.set_revert(&false);
+    treasury_client.set_shutdown_module(&Address::generate(&e));
+    treasury_client.shutdown();
+    let result = treasury_client.try_increase_supply(&SCALAR_7);
+    assert_contract_err!(result, TreasuryError::TreasuryShutdown);
+}
+
+/// Raise every error the admin contract can raise. InternalError is declared but never raised.
+#[test]
+fn test_admin_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (_, admin_client) = create_admin(&e);
+    let owner = Address::generate(&e);
+    let ir_manager = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let pool = Address::generate(&e);
+    let asset = Address::generate(&e);
+    admin_client.initialize(&owner, &ir_manager, &guardian);
+
+    let result = admin_client.try_initialize(&owner, &ir_manager, &guardian);
+    assert_contract_err!(result, AdminError::AlreadyInitializedError);
+    let result = admin_client.try_accept_ownership();
+    assert_contract_err!(result, AdminError::NoPendingOwner);
+
+    // governance calls need a governor, and can only go through the admin functions
+    let calls: Vec<(Symbol, Vec<Val>)> = vec![&e];
+    assert_contract_err!(admin_client.try_execute(&calls), AdminError::UnauthorizedError);
+    admin_client.set_governor(&Address::generate(&e));
+    let calls = vec![&e, (Symbol::new(&e, "set_admin"), vec![&e, pool.to_val()])];
+    assert_contract_err!(admin_client.try_execute(&calls), AdminError::InvalidCall);
+
+    // pool admin transfers must be queued, for the same admin, and wait out the timelock
+    let result = admin_client.try_cancel_set_pool_admin(&stranger, &pool);
+    assert_contract_err!(result, AdminError::UnauthorizedError);
+    let result = admin_client.try_cancel_set_pool_admin(&guardian, &pool);
+    assert_contract_err!(result, AdminError::NoQueuedPoolAdmin);
+    let result = admin_client.try_set_pool_admin(&pool, &stranger);
+    assert_contract_err!(result, AdminError::NoQueuedPoolAdmin);
+    admin_client.queue_set_pool_admin(&pool, &stranger);
+    let result = admin_client.try_set_pool_admin(&pool, &guardian);
+    assert_contract_err!(result, AdminError::NoQueuedPoolAdmin);
+    let result = admin_client.try_set_pool_admin(&pool, &stranger);
+    assert_contract_err!(result, AdminError::TimelockNotExpired);
+
+    // reserves must be configured through the admin before they can be tuned or rolled back
+    let result = admin_client.try_set_ir_params(&stranger, &pool, &asset, &1, &2, &3, &4);
+    assert_contract_err!(result, AdminError::UnauthorizedError);
+    let result = admin_client.try_set_ir_params(&ir_manager, &pool, &asset, &1, &2, &3, &4);
+    assert_contract_err!(result, AdminError::ReserveNotConfigured);
+    let result = admin_client.try_get_reserve_config(&pool, &asset);
+    assert_contract_err!(result, AdminError::ReserveNotConfigured);
+    let result = admin_client.try_rollback_reserve(&owner, &pool, &asset);
+    assert_contract_err!(result, AdminError::NoPreviousReserveConfig);
+}
+
+#[test]
+#[should_panic(expected = "the call failed with another error")]
+fn test_assert_contract_err_panics_on_other_error() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (_, admin_client) = create_admin(&e);
+    let result = admin_client.try_accept_ownership();
+    assert_contract_err!(result, AdminError::UnauthorizedError);
+}
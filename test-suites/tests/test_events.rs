@@ -0,0 +1,89 @@
+#![cfg(test)]
+use orbit_events::{topics, RoleUpdate};
+use soroban_sdk::{testutils::Address as _, Address, Symbol};
+use test_suites::{
+    auctions::open_ousd_borrow,
+    create_fixture_with_data, create_orbit_fixture,
+    events::{assert_event, collect_events, collect_named},
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// The events of a borrow and a treasury mint can be read back with their data decoded
+#[test]
+fn test_collect_and_assert_events() {
+    let fixture = create_fixture_with_data(true);
+    let pool_fixture = &fixture.pools[0];
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    let xlm = &fixture.tokens[TokenIndex::XLM];
+    let samwise = Address::generate(&fixture.env);
+    xlm.mint(&samwise, &(10_000 * SCALAR_7));
+
+    open_ousd_borrow(&fixture, 0, &samwise, 10_000 * SCALAR_7, 500 * SCALAR_7);
+    let names: Vec<Symbol> = collect_events(&pool_fixture.pool.address)
+        .iter()
+        .filter_map(|event| event.name())
+        .collect();
+    assert!(names.contains(&Symbol::new(&fixture.env, "supply_collateral")));
+    assert!(names.contains(&Symbol::new(&fixture.env, "borrow")));
+
+    // the pool reports the amount and the b or d tokens it minted for it
+    let borrows = collect_named::<(i128, i128)>(&pool_fixture.pool.address, "borrow");
+    assert_eq!(borrows.len(), 1);
+    let (amount, d_tokens) = borrows[0];
+    assert_eq!(amount, 500 * SCALAR_7);
+    assert!(d_tokens > 0 && d_tokens <= amount);
+    assert_event(
+        &fixture.env,
+        &pool_fixture.pool.address,
+        (Symbol::new(&fixture.env, "borrow"), ousd.address.clone(), samwise.clone()),
+        (amount, d_tokens),
+    );
+
+    // events build up across invocations, so the newest mint is the treasury's latest
+    pool_fixture.treasury.increase_supply(&(1_000 * SCALAR_7));
+    let mints = collect_named::<i128>(&ousd.address, "mint");
+    assert_eq!(mints.first(), Some(&(100_000 * SCALAR_7)));
+    assert_eq!(mints.last(), Some(&(1_000 * SCALAR_7)));
+}
+
+#[test]
+#[should_panic(expected = "no event matches")]
+fn test_assert_event_panics() {
+    let fixture = create_fixture_with_data(true);
+    let pool_fixture = &fixture.pools[0];
+    pool_fixture.treasury.increase_supply(&(1_000 * SCALAR_7));
+    assert_event(
+        &fixture.env,
+        &pool_fixture.pool.address,
+        (Symbol::new(&fixture.env, "supply"),),
+        1_000 * SCALAR_7,
+    );
+}
+
+/// The events of the Orbit contracts decode against the topics and data of orbit-events
+#[test]
+fn test_decode_orbit_events() {
+    let e = soroban_sdk::Env::default();
+    let orbit = create_orbit_fixture(&e);
+    let bombadil = &orbit.blend.bombadil;
+    let samwise = Address::generate(&e);
+
+    orbit.admin.propose_ownership(&samwise);
+    orbit.admin.accept_ownership();
+    let proposals = collect_named::<Address>(&orbit.admin.address, topics::PROPOSE_OWNERSHIP);
+    assert_eq!(proposals, vec![samwise.clone()]);
+    let updates = collect_named::<RoleUpdate>(&orbit.admin.address, topics::ACCEPT_OWNERSHIP);
+    assert_eq!(
+        updates,
+        [RoleUpdate {
+            old: bombadil.clone(),
+            new: samwise.clone(),
+        }]
+    );
+    assert_event(
+        &e,
+        &orbit.admin.address,
+        (Symbol::new(&e, topics::PROPOSE_OWNERSHIP), bombadil.clone()),
+        samwise,
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+use flash_mint::FlashMintError;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _, token::TokenClient, vec,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, Bytes, Env, Error, Symbol,
+};
+use test_suites::{
+    assert_contract_err,
+    flash_mint::create_flash_mint,
+    flash_mint::FlashMintClient,
+    pool::create_mock_pool,
+    test_fixture::SCALAR_7,
+    treasury::{create_treasury, TTL_BUMP, TTL_THRESHOLD},
+};
+use treasury::TreasuryClient;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug)]
+pub enum Attack {
+    Honest,
+    RepayShort,
+    Reenter,
+    Approve,
+    ReturnEarly,
+}
+
+/// A flash loan receiver that misbehaves in the way it is told to
+#[contract]
+pub struct MaliciousReceiver;
+
+#[contractimpl]
+impl MaliciousReceiver {
+    pub fn set_attack(e: Env, lender: Address, attack: Attack) {
+        e.storage().instance().set(&Symbol::new(&e, "lender"), &lender);
+        e.storage().instance().set(&Symbol::new(&e, "attack"), &attack);
+    }
+
+    pub fn on_flash_loan(
+        e: Env,
+        _initiator: Address,
+        token: Address,
+        amount: i128,
+        fee: i128,
+        data: Bytes,
+    ) {
+        let lender: Address = e.storage().instance().get(&Symbol::new(&e, "lender")).unwrap();
+        let attack: Attack = e.storage().instance().get(&Symbol::new(&e, "attack")).unwrap();
+        let this = e.current_contract_address();
+        let token_client = TokenClient::new(&e, &token);
+        match attack {
+            Attack::Honest => token_client.transfer(&this, &lender, &(amount + fee)),
+            Attack::RepayShort => token_client.transfer(&this, &lender, &(amount + fee - 1)),
+            Attack::Reenter => {
+                FlashMintClient::new(&e, &lender).flash_loan(&this, &this, &amount, &data);
+                token_client.transfer(&this, &lender, &(amount + fee));
+            }
+            Attack::Approve => {
+                let expiration = e.ledger().sequence() + 100;
+                token_client.approve(&this, &lender, &(amount + fee), &expiration);
+            }
+            Attack::ReturnEarly => (),
+        }
+    }
+}
+
+/// A receiver that doesn't pay back the loan plus the fee, in full and by transfer, reverts
+/// the flash loan. Nothing minted for the loan is left behind, and the treasury's supply is
+/// untouched.
+#[test]
+fn test_flash_loan_attacks_revert() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let ousd_client = MockTokenClient::new(&e, &ousd);
+    let (pool_id, _) = create_mock_pool(&e, &vec![&e, ousd.clone()]);
+    treasury_client.initialize(&admin, &ousd, &pool_id, &TTL_THRESHOLD, &TTL_BUMP);
+    treasury_client.increase_supply(&(1_000 * SCALAR_7));
+
+    let fee_distributor = Address::generate(&e);
+    let (flash_mint_id, flash_mint_client) = create_flash_mint(&e);
+    flash_mint_client.initialize(
+        &admin,
+        &ousd,
+        &treasury_id,
+        &fee_distributor,
+        &5,
+        &(1_000_000 * SCALAR_7),
+    );
+    treasury_client.set_flash_minter(&flash_mint_id);
+    let receiver = e.register_contract(None, MaliciousReceiver);
+    let receiver_client = MaliciousReceiverClient::new(&e, &receiver);
+    ousd_client.mint(&receiver, &(100 * SCALAR_7));
+    let data = Bytes::new(&e);
+
+    let attacks = [
+        (Attack::RepayShort, FlashMintError::RepaymentFailed.into()),
+        (Attack::Approve, FlashMintError::RepaymentFailed.into()),
+        (Attack::ReturnEarly, FlashMintError::RepaymentFailed.into()),
+        // the host refuses to call back into a contract already on the call stack
+        (
+            Attack::Reenter,
+            Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction),
+        ),
+    ];
+    for (attack, error) in attacks.iter() {
+        receiver_client.set_attack(&flash_mint_id, attack);
+        let result =
+            flash_mint_client.try_flash_loan(&receiver, &receiver, &(10_000 * SCALAR_7), &data);
+        assert_contract_err!(result, *error);
+
+        assert_eq!(ousd_client.balance(&receiver), 100 * SCALAR_7, "{:?}", attack);
+        assert_eq!(ousd_client.balance(&flash_mint_id), 0, "{:?}", attack);
+        assert_eq!(ousd_client.balance(&treasury_id), 0, "{:?}", attack);
+        assert_eq!(ousd_client.balance(&fee_distributor), 0, "{:?}", attack);
+        assert_eq!(ousd_client.balance(&pool_id), 1_000 * SCALAR_7, "{:?}", attack);
+        assert_eq!(treasury_client.get_token_supply(), 1_000 * SCALAR_7, "{:?}", attack);
+    }
+
+    // the same loan repaid in full goes through, burning the loan and paying out the fee
+    receiver_client.set_attack(&flash_mint_id, &Attack::Honest);
+    let fee = flash_mint_client.flash_loan(&receiver, &receiver, &(10_000 * SCALAR_7), &data);
+    assert_eq!(fee, 5 * SCALAR_7);
+    assert_eq!(ousd_client.balance(&receiver), 95 * SCALAR_7);
+    assert_eq!(ousd_client.balance(&flash_mint_id), 0);
+    assert_eq!(ousd_client.balance(&fee_distributor), 5 * SCALAR_7);
+    assert_eq!(treasury_client.get_token_supply(), 1_000 * SCALAR_7);
+}
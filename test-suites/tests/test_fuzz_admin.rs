@@ -0,0 +1,182 @@
+#![cfg(test)]
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, token::TokenClient, Address, Env,
+};
+use test_suites::{
+    create_orbit_fixture, invariants::assert_invariants, test_fixture::{TokenIndex, SCALAR_7},
+};
+
+const SEEDS: [u64; 3] = [1, 42, 1337];
+const STEPS: u32 = 60;
+const ADAPTERS: usize = 3;
+/// The pool status the admin freezes the pool with, which blocks supplying
+const ADMIN_FROZEN: u32 = 4;
+
+/// A yield adapter that holds what it is given
+#[contract]
+pub struct HoldingAdapter;
+
+#[contractimpl]
+impl HoldingAdapter {
+    pub fn initialize(e: Env, token: Address) {
+        e.storage().instance().set(&0_u32, &token);
+    }
+
+    pub fn deposit(e: Env, from: Address, amount: i128) {
+        let token: Address = e.storage().instance().get(&0_u32).unwrap();
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+    }
+
+    pub fn withdraw(e: Env, to: Address, amount: i128) {
+        let token: Address = e.storage().instance().get(&0_u32).unwrap();
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &to, &amount);
+    }
+
+    pub fn total_assets(e: Env) -> i128 {
+        let token: Address = e.storage().instance().get(&0_u32).unwrap();
+        TokenClient::new(&e, &token).balance(&e.current_contract_address())
+    }
+}
+
+/// What the treasury should hold after each operation
+#[derive(Clone, Debug, Default)]
+struct Model {
+    supply: i128,
+    caps: [i128; ADAPTERS],
+    deployed: [i128; ADAPTERS],
+    status: u32,
+}
+
+/// Fuzz sequences of admin operations, both valid and invalid, against the treasury and the
+/// pool it supplies. Adapters are registered by setting a cap, and removed by setting it to
+/// zero, and the admin contract pauses and resumes the pool. Each operation must succeed if
+/// and only if the model of the treasury says it is valid, and afterwards the treasury must
+/// match the model: its supply, and the cap and reserves of each adapter, with the reserves
+/// held by the adapter. Nothing may be deployed through an adapter that was never registered.
+#[test]
+fn test_fuzz_admin_operations() {
+    for seed in SEEDS {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let e = Env::default();
+        let orbit = create_orbit_fixture(&e);
+        let fixture = &orbit.blend;
+        let pool = &fixture.pools[0].pool.address;
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        let bombadil = &fixture.bombadil;
+        let mallory = Address::generate(&e);
+        // the last adapter is never registered
+        let adapters: Vec<Address> = (0..ADAPTERS)
+            .map(|_| {
+                let adapter = e.register_contract(None, HoldingAdapter);
+                HoldingAdapterClient::new(&e, &adapter).initialize(&ousd.address);
+                adapter
+            })
+            .collect();
+        let mut model = Model {
+            supply: orbit.treasury.get_token_supply(),
+            status: fixture.read_pool_config(0).status,
+            ..Model::default()
+        };
+
+        for step in 0..STEPS {
+            let context = format!("seed {} step {}", seed, step);
+            let index = rng.gen_range(0, ADAPTERS);
+            let adapter = &adapters[index];
+            let amount = rng.gen_range(-2_000, 20_000) * SCALAR_7;
+            match rng.gen_range(0, 10) {
+                // register an adapter, or change its cap
+                0 => {
+                    let index = rng.gen_range(0, ADAPTERS - 1);
+                    let cap = rng.gen_range(10_000, 100_000) * SCALAR_7;
+                    orbit.treasury.set_adapter_cap(&adapters[index], &cap);
+                    model.caps[index] = cap;
+                }
+                // remove an adapter, which winds down what it holds
+                1 => {
+                    orbit.treasury.set_adapter_cap(adapter, &0);
+                    model.caps[index] = 0;
+                }
+                2 => {
+                    let valid = amount > 0 && model.deployed[index] + amount <= model.caps[index];
+                    let result = orbit.treasury.try_deposit_adapter(adapter, &amount);
+                    assert_eq!(result.is_ok(), valid, "deposit {}: {}", amount, context);
+                    if valid {
+                        model.deployed[index] += amount;
+                    }
+                }
+                3 => {
+                    // wind down all the adapter holds, or try an arbitrary amount
+                    let amount = if rng.gen_bool(0.5) {
+                        model.deployed[index]
+                    } else {
+                        amount
+                    };
+                    let valid = amount > 0 && amount <= model.deployed[index];
+                    let result = orbit.treasury.try_withdraw_adapter(adapter, &amount);
+                    assert_eq!(result.is_ok(), valid, "withdraw {}: {}", amount, context);
+                    if valid {
+                        model.deployed[index] -= amount;
+                    }
+                }
+                4 | 5 => {
+                    let valid = amount > 0 && model.status != ADMIN_FROZEN;
+                    let result = orbit.treasury.try_increase_supply(&amount);
+                    assert_eq!(result.is_ok(), valid, "increase {}: {}", amount, context);
+                    if valid {
+                        model.supply += amount;
+                    }
+                }
+                6 | 7 => {
+                    let b_tokens = fixture.pools[0]
+                        .pool
+                        .get_positions(&orbit.treasury.address)
+                        .supply
+                        .get(0)
+                        .unwrap_or(0);
+                    let valid = amount > 0
+                        && amount <= model.supply
+                        && amount <= b_tokens
+                        && amount <= ousd.balance(pool);
+                    let result = orbit.treasury.try_decrease_supply(&amount);
+                    assert_eq!(result.is_ok(), valid, "decrease {}: {}", amount, context);
+                    if valid {
+                        model.supply -= amount;
+                    }
+                }
+                // pause or resume the pool, as the guardian or as someone without a role
+                8 => {
+                    let caller = if rng.gen_bool(0.75) { bombadil } else { &mallory };
+                    let result = orbit.admin.try_freeze_pool(caller, pool);
+                    assert_eq!(result.is_ok(), caller == bombadil, "freeze: {}", context);
+                    if caller == bombadil {
+                        model.status = ADMIN_FROZEN;
+                    }
+                }
+                _ => {
+                    let caller = if rng.gen_bool(0.75) { bombadil } else { &mallory };
+                    // the admin can't set the statuses the pool reaches on its own
+                    let status = rng.gen_range(0, 6);
+                    let valid = caller == bombadil && status != 1 && status != 5;
+                    let result = orbit.admin.try_set_pool_status(caller, pool, &status);
+                    assert_eq!(result.is_ok(), valid, "status {}: {}", status, context);
+                    if valid {
+                        model.status = status;
+                    }
+                }
+            }
+
+            assert_eq!(orbit.treasury.get_token_supply(), model.supply, "{}", context);
+            assert_eq!(fixture.read_pool_config(0).status, model.status, "{}", context);
+            for (index, adapter) in adapters.iter().enumerate() {
+                let data = orbit.treasury.get_adapter(adapter);
+                assert_eq!(data.cap, model.caps[index], "adapter {}: {}", index, context);
+                assert_eq!(data.deployed, model.deployed[index], "adapter {}: {}", index, context);
+                assert_eq!(ousd.balance(adapter), data.deployed, "adapter {}: {}", index, context);
+            }
+            let unregistered = orbit.treasury.get_adapter(&adapters[ADAPTERS - 1]);
+            assert_eq!((unregistered.cap, unregistered.deployed), (0, 0), "{}", context);
+            assert_invariants(fixture);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use sep_40_oracle::testutils::MockPriceOracleClient;
+use sep_41_token::testutils::MockTokenClient;
+use soroban_sdk::{vec, Address};
+use test_suites::{
+    create_fixture_with_data,
+    ledger_import::ImportedLedger,
+    pool::{PoolClient, Request, RequestType},
+    test_fixture::{TokenIndex, SCALAR_7},
+    treasury::upgrade_to_native,
+};
+use treasury::TreasuryClient;
+
+fn strkey(address: &Address) -> std::string::String {
+    let strkey = address.to_string();
+    let mut buf = [0u8; 56];
+    strkey.copy_into_slice(&mut buf);
+    std::str::from_utf8(&buf).unwrap().to_string()
+}
+
+/// A ledger captured from a network is loaded with its contracts and their state, and a
+/// reproduction run against it can be saved and loaded again. The capture is stood in for by
+/// the ledger of a wasm fixture.
+#[test]
+fn test_import_captured_ledger() {
+    let fixture = create_fixture_with_data(true);
+    let pool_fixture = &fixture.pools[0];
+    let ousd_index = pool_fixture.reserves[&TokenIndex::OUSD];
+    let dir = std::env::temp_dir().join("orbit-ledger-import");
+    std::fs::create_dir_all(&dir).unwrap();
+    let captured = dir.join("captured.json");
+    fixture.env.to_ledger_snapshot_file(&captured);
+    let addresses = [
+        ("pool", strkey(&pool_fixture.pool.address)),
+        ("treasury", strkey(&pool_fixture.treasury.address)),
+        ("oracle", strkey(&fixture.oracle.address)),
+        ("ousd", strkey(&fixture.tokens[TokenIndex::OUSD].address)),
+        ("xlm", strkey(&fixture.tokens[TokenIndex::XLM].address)),
+        ("frodo", strkey(&fixture.users[0])),
+    ];
+    let addresses: Vec<(&'static str, &str)> =
+        addresses.iter().map(|(name, strkey)| (*name, strkey.as_str())).collect();
+    let supplied: Vec<(u32, i128)> = pool_fixture
+        .pool
+        .get_positions(&pool_fixture.treasury.address)
+        .supply
+        .iter()
+        .collect();
+
+    // the imported ledger holds the captured positions, at the captured ledger
+    let imported = ImportedLedger::load(&captured, &addresses);
+    let e = &imported.env;
+    assert_eq!(e.ledger().timestamp(), fixture.env.ledger().timestamp());
+    assert_eq!(e.ledger().sequence(), fixture.env.ledger().sequence());
+    let pool = PoolClient::new(e, &imported.address("pool"));
+    let treasury_id = imported.address("treasury");
+    let supply = pool.get_positions(&treasury_id).supply;
+    assert_eq!(supply.iter().collect::<Vec<_>>(), supplied);
+
+    // the captured contracts run as deployed
+    let frodo = imported.address("frodo");
+    let xlm = imported.address("xlm");
+    let ousd = imported.address("ousd");
+    MockPriceOracleClient::new(e, &imported.address("oracle"))
+        .set_price_stable(&vec![e, 1_0000000, 0_1000000]);
+    let ousd_token = MockTokenClient::new(e, &ousd);
+    let balance = ousd_token.balance(&frodo);
+    MockTokenClient::new(e, &xlm).mint(&frodo, &(1_000 * SCALAR_7));
+    let requests = vec![
+        e,
+        Request {
+            request_type: RequestType::SupplyCollateral.into_u32(),
+            address: xlm.clone(),
+            amount: 1_000 * SCALAR_7,
+        },
+        Request {
+            request_type: RequestType::Borrow.into_u32(),
+            address: ousd.clone(),
+            amount: 50 * SCALAR_7,
+        },
+    ];
+    pool.submit(&frodo, &frodo, &frodo, &requests);
+    assert_eq!(ousd_token.balance(&frodo), balance + 50 * SCALAR_7);
+    let liabilities = pool.get_positions(&frodo).liabilities.get(ousd_index).unwrap();
+    assert!(liabilities > 0);
+
+    // and the current build of the treasury runs against the captured state
+    upgrade_to_native(e, &treasury_id);
+    let treasury = TreasuryClient::new(e, &treasury_id);
+    treasury.migrate();
+    let supply = treasury.get_token_supply();
+    treasury.decrease_supply(&(1_000 * SCALAR_7));
+    assert_eq!(treasury.get_token_supply(), supply - 1_000 * SCALAR_7);
+
+    // the reproduction is saved, and loads again from where it left off
+    let reproduced = dir.join("reproduced.json");
+    imported.save(&reproduced);
+    let reloaded = ImportedLedger::load(&reproduced, &addresses);
+    let pool = PoolClient::new(&reloaded.env, &reloaded.address("pool"));
+    let positions = pool.get_positions(&reloaded.address("frodo"));
+    assert_eq!(positions.liabilities.get(ousd_index), Some(liabilities));
+}
+
+#[test]
+#[should_panic(expected = "no address named router")]
+fn test_imported_ledger_unknown_address() {
+    let fixture = create_fixture_with_data(true);
+    let path = std::env::temp_dir().join("orbit-ledger-import-unknown.json");
+    fixture.env.to_ledger_snapshot_file(&path);
+    ImportedLedger::load(&path, &[]).address("router");
+}
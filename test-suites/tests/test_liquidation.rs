@@ -0,0 +1,108 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use test_suites::{
+    auctions::{liquidate_by_interest, liquidate_by_price, open_ousd_borrow},
+    create_fixture_with_data,
+    invariants::assert_invariants,
+    parity::assert_parity,
+    pool::{Request, RequestType},
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// A borrower of oUSD goes underwater when their collateral drops in price, is auctioned, and
+/// a liquidator fills the auction and repays the debt with oUSD the treasury minted
+#[test]
+fn test_liquidation_of_ousd_borrower() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let frodo = fixture.users[0].clone();
+        let samwise = Address::generate(&fixture.env);
+        let pool_fixture = &fixture.pools[0];
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        let xlm = &fixture.tokens[TokenIndex::XLM];
+
+        // samwise borrows oUSD against $1000 of XLM
+        xlm.mint(&samwise, &(10_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, 0, &samwise, 10_000 * SCALAR_7, 700 * SCALAR_7);
+        assert_eq!(ousd.balance(&samwise), 700 * SCALAR_7);
+        assert_invariants(&fixture);
+
+        // XLM drops 10%, leaving samwise underwater
+        let auction = liquidate_by_price(&fixture, 0, &samwise, 0_0900000, 60);
+        let ousd_bid = auction.bid.get(ousd.address.clone()).unwrap();
+        let xlm_lot = auction.lot.get(xlm.address.clone()).unwrap();
+        assert!(ousd_bid > 0 && xlm_lot > 0);
+
+        // frodo fills the auction once the full lot is offered, and repays the debt taken on
+        fixture.jump_ledgers(201);
+        ousd.mint(&frodo, &(1_000 * SCALAR_7));
+        pool_fixture.pool.submit(
+            &frodo,
+            &frodo,
+            &frodo,
+            &vec![
+                &fixture.env,
+                Request {
+                    request_type: RequestType::SupplyCollateral.into_u32(),
+                    address: xlm.address.clone(),
+                    amount: 10_000 * SCALAR_7,
+                },
+                Request {
+                    request_type: RequestType::FillUserLiquidationAuction.into_u32(),
+                    address: samwise.clone(),
+                    amount: 100,
+                },
+                Request {
+                    request_type: RequestType::Repay.into_u32(),
+                    address: ousd.address.clone(),
+                    amount: 1_000 * SCALAR_7,
+                },
+            ],
+        );
+        let frodo_positions = pool_fixture.pool.get_positions(&frodo);
+        assert_eq!(frodo_positions.liabilities.len(), 0);
+        let samwise_positions = pool_fixture.pool.get_positions(&samwise);
+        let ousd_index = pool_fixture.reserves[&TokenIndex::OUSD];
+        let xlm_index = pool_fixture.reserves[&TokenIndex::XLM];
+        let liability = samwise_positions.liabilities.get(ousd_index).unwrap();
+        let collateral = samwise_positions.collateral.get(xlm_index).unwrap();
+        assert!(liability < 700 * SCALAR_7);
+        assert!(collateral < 10_000 * SCALAR_7);
+
+        // the oUSD the treasury supplied can still be withdrawn and burned
+        assert_invariants(&fixture);
+        pool_fixture.treasury.decrease_supply(&(50_000 * SCALAR_7));
+        assert_invariants(&fixture);
+        (ousd_bid, xlm_lot, liability, collateral, fixture.read_treasury_supply(0))
+    });
+}
+
+/// A borrower at the edge of their borrow limit goes underwater as interest accrues on their
+/// oUSD debt, and can be auctioned without any price move
+#[test]
+fn test_liquidation_after_interest_accrual() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let samwise = Address::generate(&fixture.env);
+        let pool_fixture = &fixture.pools[0];
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        let xlm = &fixture.tokens[TokenIndex::XLM];
+
+        // samwise borrows 85% of the treasury supply, just under the limit of $85.5k
+        xlm.mint(&samwise, &(1_200_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, 0, &samwise, 1_200_000 * SCALAR_7, 85_000 * SCALAR_7);
+        let result = pool_fixture.pool.try_new_liquidation_auction(&samwise, &60);
+        assert!(result.is_err());
+
+        let start = fixture.env.ledger().timestamp();
+        let auction = liquidate_by_interest(&fixture, 0, &samwise, 24 * 60 * 60, 365, 15);
+        let elapsed = fixture.env.ledger().timestamp() - start;
+        let ousd_bid = auction.bid.get(ousd.address.clone()).unwrap();
+        let xlm_lot = auction.lot.get(xlm.address.clone()).unwrap();
+        assert!(elapsed > 0);
+        assert!(ousd_bid > 0 && xlm_lot > 0);
+        assert_invariants(&fixture);
+        (elapsed, ousd_bid, xlm_lot)
+    });
+}
@@ -0,0 +1,320 @@
+#![cfg(test)]
+use bridge_oracle::{BridgeOracleClient, BridgeOracleError};
+use sep_40_oracle::testutils::Asset;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, IntoVal, Map, Symbol, Val, Vec,
+};
+use test_suites::{
+    assert_contract_err, bridge_oracle::create_native_bridge_oracle, oracle::create_mock_oracle,
+    treasury::{create_treasury, TTL_BUMP, TTL_THRESHOLD},
+};
+use treasury::{TreasuryClient, TreasuryError};
+
+// The storage of earlier versions of each contract is written here with raw keys and values,
+// rather than with the contract's own types, so the fixtures keep matching what those versions
+// stored when the types change. A change to how the contract encodes its storage breaks these
+// tests, and must come with a new storage version and a step in `migrate`.
+
+/// Encode a struct as the contract stores it, a map of its field names to their values
+fn raw_struct(e: &Env, fields: &[(&str, Val)]) -> Map<Symbol, Val> {
+    let mut map = Map::new(e);
+    for (name, value) in fields.iter() {
+        map.set(Symbol::new(e, name), *value);
+    }
+    map
+}
+
+/// Encode a feed of an upstream sep-40 oracle with 7 decimals, which version 1 stored without a
+/// weight
+fn raw_source(e: &Env, oracle: &Address, asset: &Asset) -> Map<Symbol, Val> {
+    raw_struct(
+        e,
+        &[
+            ("kind", vec![e, Symbol::new(e, "Sep40")].into_val(e)),
+            ("oracle", oracle.into_val(e)),
+            ("asset", asset.into_val(e)),
+            ("decimals", 7_u32.into_val(e)),
+        ],
+    )
+}
+
+/// Encode a price observation
+fn raw_price(e: &Env, price: i128, timestamp: u64) -> Map<Symbol, Val> {
+    raw_struct(
+        e,
+        &[
+            ("price", price.into_val(e)),
+            ("timestamp", timestamp.into_val(e)),
+        ],
+    )
+}
+
+/// Write the storage of a bridge oracle initialized before its storage version was tracked, which
+/// only bridged one asset to another on a single upstream oracle
+fn write_version_0<'a>(
+    e: &Env,
+    upstream: &Address,
+    ousd: &Asset,
+    usdc: &Asset,
+) -> BridgeOracleClient<'a> {
+    let (bridge_id, bridge_client) = create_native_bridge_oracle(e);
+    e.as_contract(&bridge_id, || {
+        let instance = e.storage().instance();
+        instance.set(&Symbol::new(e, "FomAsset"), ousd);
+        instance.set(&Symbol::new(e, "ToAsset"), usdc);
+        instance.set(&Symbol::new(e, "Oracle"), upstream);
+    });
+    bridge_client
+}
+
+/// Write the storage of a bridge oracle at version 1, which kept each setting of an asset under
+/// its own key and its observations in a single entry. oUSD is pinned to $1, and XLM is priced
+/// by one feed with 600 second old prices at most, a fallback feed, and 30 observations.
+fn write_version_1<'a>(
+    e: &Env,
+    upstream: &Address,
+    fallback: &Address,
+    assets: &[Asset; 3],
+) -> BridgeOracleClient<'a> {
+    let [ousd, usdc, xlm] = assets;
+    let (bridge_id, bridge_client) = create_native_bridge_oracle(e);
+    e.as_contract(&bridge_id, || {
+        let instance = e.storage().instance();
+        instance.set(&Symbol::new(e, "IsInit"), &true);
+        instance.set(&Symbol::new(e, "Admin"), &Address::generate(e));
+        instance.set(&Symbol::new(e, "Guardian"), &Address::generate(e));
+        instance.set(&Symbol::new(e, "FomAsset"), ousd);
+        instance.set(&Symbol::new(e, "ToAsset"), usdc);
+        instance.set(&Symbol::new(e, "Oracle"), upstream);
+        instance.set(&Symbol::new(e, "Version"), &1_u32);
+
+        let persistent = e.storage().persistent();
+        let key = |name: &str, asset: &Asset| (Symbol::new(e, name), asset.clone());
+        persistent.set(&key("FixedPrice", ousd), &10_000_000_i128);
+        let sources = raw_struct(
+            e,
+            &[
+                ("sources", vec![e, raw_source(e, upstream, xlm)].into_val(e)),
+                ("min_sources", 1_u32.into_val(e)),
+            ],
+        );
+        persistent.set(&key("Sources", xlm), &sources);
+        persistent.set(&key("MaxAge", xlm), &600_u64);
+        let fallback = raw_struct(
+            e,
+            &[
+                ("source", raw_source(e, fallback, xlm).into_val(e)),
+                ("max_deviation", 500_000_u32.into_val(e)),
+            ],
+        );
+        persistent.set(&key("Fallback", xlm), &fallback);
+        let mut observations = Vec::new(e);
+        for i in 0..30 {
+            observations.push_back(raw_price(e, 1_000_000 + i, 100 + i as u64));
+        }
+        persistent.set(&key("Observations", xlm), &observations);
+    });
+    bridge_client
+}
+
+/// A bridge oracle initialized before its storage version was tracked has no admin, so it can't
+/// be migrated, and keeps bridging its asset through its upstream oracle
+#[test]
+fn test_migrate_rejects_version_0_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let ousd = Asset::Stellar(Address::generate(&e));
+    let usdc = Asset::Stellar(Address::generate(&e));
+    let xlm = Asset::Stellar(Address::generate(&e));
+    let (upstream_id, upstream_client) = create_mock_oracle(&e);
+    upstream_client.set_data(
+        &Address::generate(&e),
+        &Asset::Other(Symbol::new(&e, "USD")),
+        &vec![&e, usdc.clone(), xlm.clone()],
+        &7,
+        &300,
+    );
+    upstream_client.set_price(&vec![&e, 9_990_000, 1_000_000], &1000);
+
+    let bridge_client = write_version_0(&e, &upstream_id, &ousd, &usdc);
+    assert_eq!(bridge_client.version(), 0);
+    let result = bridge_client.try_migrate(&vec![&e, ousd.clone()]);
+    assert_contract_err!(result, BridgeOracleError::InvalidStorageVersion);
+    assert_eq!(bridge_client.version(), 0);
+
+    // the baseline keys still read as they did
+    assert_eq!(bridge_client.decimals(), 7);
+    assert_eq!(bridge_client.lastprice(&ousd).unwrap().price, 9_990_000);
+    assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 1_000_000);
+}
+
+#[test]
+fn test_migrate_version_1_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let ousd = Asset::Stellar(Address::generate(&e));
+    let usdc = Asset::Stellar(Address::generate(&e));
+    let xlm = Asset::Stellar(Address::generate(&e));
+    let (upstream_id, upstream_client) = create_mock_oracle(&e);
+    upstream_client.set_data(
+        &Address::generate(&e),
+        &Asset::Other(Symbol::new(&e, "USD")),
+        &vec![&e, usdc.clone(), xlm.clone()],
+        &7,
+        &300,
+    );
+    upstream_client.set_price(&vec![&e, 10_000_000, 1_000_000], &1000);
+    let (fallback_id, fallback_client) = create_mock_oracle(&e);
+    fallback_client.set_data(
+        &Address::generate(&e),
+        &Asset::Other(Symbol::new(&e, "USD")),
+        &vec![&e, xlm.clone()],
+        &7,
+        &300,
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    fallback_client.set_price(&vec![&e, 980_000], &1000);
+
+    let assets = [ousd.clone(), usdc.clone(), xlm.clone()];
+    let bridge_client = write_version_1(&e, &upstream_id, &fallback_id, &assets);
+    assert_eq!(bridge_client.version(), 1);
+    bridge_client.migrate(&vec![&e, ousd.clone(), xlm.clone()]);
+    assert_eq!(bridge_client.version(), 2);
+
+    // everything the old layout held reads back after the migration
+    assert_eq!(bridge_client.assets(), vec![&e, ousd.clone(), xlm.clone()]);
+    assert_eq!(bridge_client.lastprice(&ousd).unwrap().price, 10_000_000);
+    assert_eq!(bridge_client.lastprice(&usdc).unwrap().price, 10_000_000);
+    let config = bridge_client.get_asset_config(&xlm).unwrap();
+    assert_eq!((config.max_age, config.fixed_price), (600, 0));
+    assert_eq!((config.sources.len(), config.min_sources), (1, 1));
+    let source = config.sources.get(0).unwrap();
+    assert_eq!(
+        (source.oracle, source.weight, source.staleness_penalty),
+        (upstream_id, 1, 0)
+    );
+    assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 1_000_000);
+    let history = bridge_client.prices(&xlm, &30).unwrap();
+    assert_eq!(history.len(), 24);
+    assert_eq!(history.first().unwrap().price, 1_000_029);
+    assert_eq!(history.last().unwrap().price, 1_000_006);
+
+    // the old keys are gone
+    e.as_contract(&bridge_client.address, || {
+        let persistent = e.storage().persistent();
+        let key = |name: &str, asset: &Asset| (Symbol::new(&e, name), asset.clone());
+        assert!(!persistent.has(&key("FixedPrice", &ousd)));
+        assert!(!persistent.has(&key("Sources", &xlm)));
+        assert!(!persistent.has(&key("MaxAge", &xlm)));
+        assert!(!persistent.has(&key("Observations", &xlm)));
+    });
+
+    // the fallback feed takes over once the upstream price is too old
+    e.ledger().with_mut(|li| li.timestamp = 1900);
+    fallback_client.set_price(&vec![&e, 980_000], &1900);
+    assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 980_000);
+
+    // the migration is only run once
+    bridge_client.migrate(&vec![&e, ousd.clone(), xlm.clone()]);
+    assert_eq!(bridge_client.version(), 2);
+    assert_eq!(bridge_client.lastprice(&ousd).unwrap().price, 10_000_000);
+}
+
+/// Storage written by a later version than the contract's can't be migrated down
+#[test]
+fn test_migrate_rejects_newer_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bridge_id, bridge_client) = create_native_bridge_oracle(&e);
+    bridge_client.initialize(
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &Address::generate(&e),
+        &Address::generate(&e),
+    );
+    e.as_contract(&bridge_id, || {
+        e.storage().instance().set(&Symbol::new(&e, "Version"), &3_u32);
+    });
+    let result = bridge_client.try_migrate(&vec![&e]);
+    assert_contract_err!(result, BridgeOracleError::InvalidStorageVersion);
+    assert_eq!(bridge_client.version(), 3);
+}
+
+/// Write the storage of a treasury initialized before its storage version was tracked, which
+/// kept the pool and the supply of its token in its instance
+fn write_treasury_version_0<'a>(
+    e: &Env,
+    token: &Address,
+    blend: &Address,
+    supply: i128,
+) -> TreasuryClient<'a> {
+    let (treasury_id, _) = create_treasury(e, false);
+    e.as_contract(&treasury_id, || {
+        let instance = e.storage().instance();
+        instance.set(&Symbol::new(e, "Admin"), &Address::generate(e));
+        instance.set(&Symbol::new(e, "Token"), token);
+        instance.set(&Symbol::new(e, "Blend"), blend);
+        instance.set(&Symbol::new(e, "TokenSupply"), &supply);
+    });
+    TreasuryClient::new(e, &treasury_id)
+}
+
+#[test]
+fn test_migrate_treasury_version_0_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token = Address::generate(&e);
+    let blend = Address::generate(&e);
+    let treasury_client = write_treasury_version_0(&e, &token, &blend, 10_000_000_000);
+    assert_eq!(treasury_client.version(), 0);
+    treasury_client.migrate();
+    assert_eq!(treasury_client.version(), 1);
+
+    // the pool and the supply move out of the instance into entries keyed by token
+    e.as_contract(&treasury_client.address, || {
+        let instance = e.storage().instance();
+        assert!(!instance.has(&Symbol::new(&e, "Blend")));
+        assert!(!instance.has(&Symbol::new(&e, "TokenSupply")));
+        let persistent = e.storage().persistent();
+        let key = |name: &str| -> Vec<Val> {
+            vec![&e, Symbol::new(&e, name).into_val(&e), token.into_val(&e)]
+        };
+        assert_eq!(persistent.get::<_, Address>(&key("Blend")), Some(blend.clone()));
+        assert_eq!(persistent.get::<_, i128>(&key("TokenSupply")), Some(10_000_000_000));
+    });
+    assert_eq!(treasury_client.get_token_address(), token);
+    assert_eq!(treasury_client.get_blend_address(), blend);
+    assert_eq!(treasury_client.get_token_supply(), 10_000_000_000);
+
+    // the migration is only run once
+    treasury_client.migrate();
+    assert_eq!(treasury_client.version(), 1);
+    assert_eq!(treasury_client.get_token_supply(), 10_000_000_000);
+}
+
+/// A treasury initialized by the current build is already at the current version, and storage
+/// written by a later version can't be migrated down
+#[test]
+fn test_migrate_treasury_rejects_newer_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let token = Address::generate(&e);
+    let blend = Address::generate(&e);
+    treasury_client.initialize(&Address::generate(&e), &token, &blend, &TTL_THRESHOLD, &TTL_BUMP);
+    assert_eq!(treasury_client.version(), 1);
+    treasury_client.migrate();
+    assert_eq!(treasury_client.version(), 1);
+    assert_eq!(treasury_client.get_blend_address(), blend);
+
+    e.as_contract(&treasury_id, || {
+        e.storage().instance().set(&Symbol::new(&e, "Version"), &2_u32);
+    });
+    let result = treasury_client.try_migrate();
+    assert_contract_err!(result, TreasuryError::InvalidStorageVersion);
+    assert_eq!(treasury_client.version(), 2);
+}
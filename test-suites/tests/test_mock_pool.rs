@@ -0,0 +1,112 @@
+#![cfg(test)]
+use mock_pool::{MockPoolClient, MockPoolError, Positions};
+use soroban_sdk::{map, testutils::Address as _, token::TokenClient, vec, Address, Env, Error};
+use test_suites::{
+    parity::assert_parity,
+    pool::create_mock_pool,
+    test_fixture::SCALAR_7,
+    treasury::{create_treasury, initialize_treasury, read_supply, TreasuryClient},
+};
+
+const SUPPLY_ERROR: u32 = 2000;
+const POOL_SUBMIT_FAILED: u32 = 2004;
+
+/// Deploy a treasury that mints oUSD into a mock pool
+fn setup_treasury<'a>(
+    e: &Env,
+    wasm: bool,
+) -> (TreasuryClient<'a>, MockPoolClient<'a>, TokenClient<'a>) {
+    let (treasury_id, treasury_client) = create_treasury(e, wasm);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let (pool_id, pool_client) = create_mock_pool(e, &vec![e, ousd.clone()]);
+    initialize_treasury(e, &treasury_id, wasm, &Address::generate(e), &ousd, &pool_id);
+    (treasury_client, pool_client, TokenClient::new(e, &ousd))
+}
+
+#[test]
+fn test_treasury_submit_reverts() {
+    assert_parity(|wasm| {
+        let e = Env::default();
+        e.mock_all_auths();
+        let (treasury_client, pool_client, ousd_client) = setup_treasury(&e, wasm);
+        treasury_client.increase_supply(&(1000 * SCALAR_7));
+
+        // the mint reverts with the pool, so nothing is left in the treasury
+        pool_client.set_revert(&true);
+        let increase = treasury_client.try_increase_supply(&(500 * SCALAR_7)).err();
+        let decrease = treasury_client.try_decrease_supply(&(500 * SCALAR_7)).err();
+        assert_eq!(read_supply(&e, &treasury_client.address), 1000 * SCALAR_7);
+        assert_eq!(ousd_client.balance(&treasury_client.address), 0);
+        assert_eq!(ousd_client.balance(&pool_client.address), 1000 * SCALAR_7);
+
+        // the released treasury fails with the pool's error, where the current one maps it to
+        // its own, and an error it doesn't know to the submit failing
+        let expected = if wasm {
+            Error::from_contract_error(MockPoolError::SubmitFailed as u32)
+        } else {
+            Error::from_contract_error(POOL_SUBMIT_FAILED)
+        };
+        assert_eq!(increase, Some(Ok(expected)));
+        assert_eq!(decrease, Some(Ok(expected)));
+        (
+            read_supply(&e, &treasury_client.address),
+            ousd_client.balance(&pool_client.address),
+        )
+    });
+}
+
+#[test]
+fn test_treasury_bad_positions() {
+    assert_parity(|wasm| {
+        let e = Env::default();
+        e.mock_all_auths();
+        let (treasury_client, pool_client, ousd_client) = setup_treasury(&e, wasm);
+        treasury_client.increase_supply(&(1000 * SCALAR_7));
+
+        // the pool under-reports the treasury's supply
+        let bad = Positions {
+            liabilities: map![&e],
+            collateral: map![&e],
+            supply: map![&e, (0, 100 * SCALAR_7)],
+        };
+        pool_client.set_reported(&treasury_client.address, &bad);
+        let result = treasury_client.try_decrease_supply(&(500 * SCALAR_7));
+        assert_eq!(result.err(), Some(Ok(Error::from_contract_error(SUPPLY_ERROR))));
+
+        // the pool reports no supply at all
+        let empty = Positions {
+            liabilities: map![&e],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        pool_client.set_reported(&treasury_client.address, &empty);
+        let missing = treasury_client.try_decrease_supply(&(500 * SCALAR_7)).err();
+        assert!(missing.is_some());
+
+        pool_client.clear_reported(&treasury_client.address);
+        treasury_client.decrease_supply(&(500 * SCALAR_7));
+        assert_eq!(read_supply(&e, &treasury_client.address), 500 * SCALAR_7);
+        assert_eq!(ousd_client.balance(&pool_client.address), 500 * SCALAR_7);
+        missing
+    });
+}
+
+#[test]
+fn test_treasury_partial_withdraw() {
+    assert_parity(|wasm| {
+        let e = Env::default();
+        e.mock_all_auths();
+        let (treasury_client, pool_client, ousd_client) = setup_treasury(&e, wasm);
+        treasury_client.increase_supply(&(1000 * SCALAR_7));
+
+        // the pool returns less than the treasury burns, so the decrease reverts as a whole
+        pool_client.set_fill(&50);
+        let result = treasury_client.try_decrease_supply(&(500 * SCALAR_7)).err();
+        assert!(result.is_some());
+        assert_eq!(read_supply(&e, &treasury_client.address), 1000 * SCALAR_7);
+        assert_eq!(ousd_client.balance(&treasury_client.address), 0);
+        let supply = pool_client.get_positions(&treasury_client.address).supply;
+        assert_eq!(supply.get_unchecked(0), 1000 * SCALAR_7);
+        result
+    });
+}
@@ -0,0 +1,140 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use bridge_oracle::{BridgeOracleError, Source, SourceKind};
+use mock_scripted_oracle::{Move, ScriptPoint};
+use sep_40_oracle::{testutils::Asset, PriceData};
+use soroban_sdk::{testutils::Address as _, vec, Address, Symbol};
+use test_suites::{
+    auctions::open_ousd_borrow,
+    bridge_oracle::create_native_bridge_oracle,
+    create_fixture_with_data,
+    events::collect_named,
+    invariants::assert_invariants,
+    oracle::{create_mock_oracle, create_scripted_oracle},
+    parity::assert_parity,
+    pool::{default_reserve_metadata, Request, RequestType},
+    pool_builder::PoolFixtureBuilder,
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+const MAX_AGE: u64 = 900;
+
+fn step(timestamp: u64, price: i128) -> ScriptPoint {
+    ScriptPoint {
+        kind: Move::Step,
+        timestamp,
+        price,
+    }
+}
+
+/// The upstream oracle stops publishing XLM prices mid-scenario. Once its last price is older
+/// than the bridge oracle accepts, the pool refuses anything that needs a price, while
+/// repayments still go through. A fallback feed unfreezes the pool, and the pool keeps working
+/// on the primary feed once it publishes again.
+#[test]
+fn test_stale_upstream_freezes_pool_until_fallback() {
+    assert_parity(|wasm| {
+        let mut fixture = create_fixture_with_data(wasm);
+        let frodo = fixture.users[0].clone();
+        let now = fixture.env.ledger().timestamp();
+        let stale_at = now - now % 300 + 60 * 60;
+        let resume_at = stale_at + 4 * 60 * 60;
+
+        // XLM holds at $0.10, stops updating for four hours, then publishes again
+        let (oracle_id, oracle_client) = create_scripted_oracle(&fixture.env);
+        oracle_client.initialize(&Asset::Other(Symbol::new(&fixture.env, "USD")), &7, &300);
+        let usdc = Asset::Stellar(fixture.tokens[TokenIndex::USDC].address.clone());
+        oracle_client.set_script(&usdc, &vec![&fixture.env, step(0, 1_0000000)]);
+        let xlm = Asset::Stellar(fixture.tokens[TokenIndex::XLM].address.clone());
+        let stale = ScriptPoint {
+            kind: Move::Stale,
+            timestamp: stale_at,
+            price: 0,
+        };
+        let script = vec![&fixture.env, step(0, 0_1000000), stale, step(resume_at, 0_1000000)];
+        oracle_client.set_script(&xlm, &script);
+
+        // the pool reads prices through a bridge oracle that rejects prices older than MAX_AGE
+        let (bridge_id, bridge_client) = create_native_bridge_oracle(&fixture.env);
+        bridge_client.initialize(
+            &fixture.bombadil,
+            &fixture.bombadil,
+            &fixture.tokens[TokenIndex::OUSD].address,
+            &fixture.tokens[TokenIndex::USDC].address,
+            &oracle_id,
+        );
+        bridge_client.set_max_age(&xlm, &MAX_AGE);
+        let mut ousd_config = default_reserve_metadata();
+        ousd_config.l_factor = 9_500_000;
+        let pool_index = PoolFixtureBuilder::new("Stale")
+            .with_bridge_oracle(&bridge_id)
+            .with_reserve(TokenIndex::OUSD, ousd_config)
+            .with_default_reserve(TokenIndex::XLM)
+            .with_backstop(&frodo, 50_000 * SCALAR_7)
+            .build(&mut fixture);
+        let pool_fixture = &fixture.pools[pool_index];
+        pool_fixture.treasury.increase_supply(&(100_000 * SCALAR_7));
+
+        let samwise = Address::generate(&fixture.env);
+        let xlm_token = &fixture.tokens[TokenIndex::XLM];
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        xlm_token.mint(&samwise, &(10_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, pool_index, &samwise, 10_000 * SCALAR_7, 400 * SCALAR_7);
+        let request = |request_type: RequestType, address: &Address, amount: i128| {
+            let request = Request {
+                request_type: request_type.into_u32(),
+                address: address.clone(),
+                amount,
+            };
+            pool_fixture
+                .pool
+                .try_submit(&samwise, &samwise, &samwise, &vec![&fixture.env, request])
+        };
+
+        // within MAX_AGE of the last price the pool carries on
+        fixture.jump(stale_at - now + MAX_AGE - 300);
+        assert!(request(RequestType::Borrow, &ousd.address, 100 * SCALAR_7).is_ok());
+
+        // past MAX_AGE, anything checking the health of a position is refused
+        fixture.jump(600);
+        let result = bridge_client.try_lastprice(&xlm);
+        assert_eq!(result.err(), Some(Ok(BridgeOracleError::StalePrice.into())));
+        let borrow = request(RequestType::Borrow, &ousd.address, 100 * SCALAR_7);
+        let withdraw = request(RequestType::WithdrawCollateral, &xlm_token.address, SCALAR_7);
+        let auction = pool_fixture.pool.try_new_liquidation_auction(&samwise, &50);
+        assert!(borrow.is_err() && withdraw.is_err() && auction.is_err());
+        assert!(request(RequestType::Repay, &ousd.address, 100 * SCALAR_7).is_ok());
+        assert_invariants(&fixture);
+
+        // a fallback feed takes over while the upstream is stale
+        let (fallback_id, fallback_client) = create_mock_oracle(&fixture.env);
+        fallback_client.set_data(
+            &fixture.bombadil,
+            &Asset::Other(Symbol::new(&fixture.env, "USD")),
+            &vec![&fixture.env, xlm.clone()],
+            &7,
+            &300,
+        );
+        fallback_client.set_price_stable(&vec![&fixture.env, 0_0950000]);
+        let fallback = Source {
+            kind: SourceKind::Sep40,
+            oracle: fallback_id,
+            asset: xlm.clone(),
+            decimals: 7,
+            weight: 1,
+            staleness_penalty: 0,
+        };
+        bridge_client.set_fallback(&xlm, &fallback, &0_0500000);
+        assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 0_0950000);
+        assert!(request(RequestType::Borrow, &ousd.address, 100 * SCALAR_7).is_ok());
+        let fallbacks = collect_named::<PriceData>(&bridge_id, "fallback");
+        assert_eq!(fallbacks.last().unwrap().price, 0_0950000);
+
+        // once the upstream publishes again, its price is used over the stale fallback
+        fixture.jump(resume_at - fixture.env.ledger().timestamp());
+        assert_eq!(bridge_client.lastprice(&xlm).unwrap().price, 0_1000000);
+        assert!(request(RequestType::Borrow, &ousd.address, 100 * SCALAR_7).is_ok());
+        assert_invariants(&fixture);
+        fixture.read_treasury_supply(pool_index)
+    });
+}
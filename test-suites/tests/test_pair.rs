@@ -0,0 +1,48 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use soroban_sdk::{testutils::Address as _, Address};
+use test_suites::{
+    assertions::assert_approx_eq_abs,
+    liquidity_pool::create_pair,
+    parity::assert_parity,
+    test_fixture::{TestFixture, TokenIndex, SCALAR_7},
+};
+
+#[test]
+fn test_pair_trades_at_price() {
+    assert_parity(|wasm| {
+        let fixture = TestFixture::create(wasm);
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        let usdc = &fixture.tokens[TokenIndex::USDC];
+
+        // oUSD trading at 0.97
+        let (pair, pair_client) = create_pair(
+            &fixture.env,
+            &fixture.bombadil,
+            &ousd.address,
+            &usdc.address,
+            100_000 * SCALAR_7,
+            0_9700000,
+            0_0030000,
+        );
+        assert_eq!(ousd.balance(&pair), 100_000 * SCALAR_7);
+        assert_eq!(usdc.balance(&pair), 97_000 * SCALAR_7);
+        let price = pair_client.get_spot_price_sans_fee(&usdc.address, &ousd.address);
+        assert_approx_eq_abs(price, 0_9700000, 10);
+
+        // selling oUSD pays less than the spot price after the fee and slippage
+        let samwise = Address::generate(&fixture.env);
+        ousd.mint(&samwise, &(100 * SCALAR_7));
+        let (usdc_out, _) = pair_client.swap_exact_amount_in(
+            &ousd.address,
+            &(100 * SCALAR_7),
+            &usdc.address,
+            &0,
+            &i128::MAX,
+            &samwise,
+        );
+        assert!(usdc_out < 97 * SCALAR_7 && usdc_out > 96 * SCALAR_7);
+        assert_eq!(usdc.balance(&samwise), usdc_out);
+        usdc_out
+    });
+}
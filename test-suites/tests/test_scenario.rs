@@ -0,0 +1,33 @@
+#![cfg(test)]
+use test_suites::{
+    create_fixture_with_data,
+    parity::assert_parity,
+    scenario::{create_scenario_users, run_scenario, ScenarioGenerator},
+};
+
+const SEEDS: [u64; 3] = [7, 2024, 90210];
+const USERS: usize = 3;
+const STEPS: u32 = 60;
+
+#[test]
+fn test_scenario_generator_is_reproducible() {
+    let actions = ScenarioGenerator::new(7, USERS).actions(STEPS);
+    assert_eq!(actions, ScenarioGenerator::new(7, USERS).actions(STEPS));
+    assert_ne!(actions, ScenarioGenerator::new(8, USERS).actions(STEPS));
+}
+
+/// Run random scenarios of user actions, price moves, liquidations and treasury supply changes
+/// over many ledgers. The invariants are checked after every step, and the wasm and native
+/// treasuries must carry out the same actions.
+#[test]
+fn test_random_scenarios() {
+    for seed in SEEDS {
+        let actions = ScenarioGenerator::new(seed, USERS).actions(STEPS);
+        let outcomes = assert_parity(|wasm| {
+            let fixture = create_fixture_with_data(wasm);
+            let users = create_scenario_users(&fixture, USERS);
+            run_scenario(&fixture, &users, &actions)
+        });
+        assert!(outcomes.iter().any(|done| *done), "no action of seed {} was carried out", seed);
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use mock_scripted_oracle::{Move, ScriptPoint};
+use sep_40_oracle::testutils::Asset;
+use soroban_sdk::{testutils::Address as _, vec, Address, Symbol};
+use test_suites::{
+    auctions::open_ousd_borrow,
+    create_fixture_with_data,
+    oracle::create_scripted_oracle,
+    parity::assert_parity,
+    pool::default_reserve_metadata,
+    pool_builder::PoolFixtureBuilder,
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// A borrower goes underwater as XLM ramps down along its price script, with no price
+/// updates made by the test
+#[test]
+fn test_scripted_depeg_liquidation() {
+    assert_parity(|wasm| {
+        let mut fixture = create_fixture_with_data(wasm);
+        let frodo = fixture.users[0].clone();
+        let now = fixture.env.ledger().timestamp();
+        let start = now - now % 300 + 60 * 60;
+
+        // XLM holds at $0.10 for an hour, then ramps down to $0.05 over 30000 seconds
+        let (oracle_id, oracle_client) = create_scripted_oracle(&fixture.env);
+        oracle_client.initialize(&Asset::Other(Symbol::new(&fixture.env, "USD")), &7, &300);
+        let step = |timestamp: u64, price: i128| ScriptPoint {
+            kind: Move::Step,
+            timestamp,
+            price,
+        };
+        let usdc = Asset::Stellar(fixture.tokens[TokenIndex::USDC].address.clone());
+        oracle_client.set_script(&usdc, &vec![&fixture.env, step(0, 1_0000000)]);
+        let xlm = Asset::Stellar(fixture.tokens[TokenIndex::XLM].address.clone());
+        oracle_client.set_script(
+            &xlm,
+            &vec![
+                &fixture.env,
+                step(0, 0_1000000),
+                step(start, 0_1000000),
+                ScriptPoint {
+                    kind: Move::Ramp,
+                    timestamp: start + 30000,
+                    price: 0_0500000,
+                },
+            ],
+        );
+
+        let mut xlm_config = default_reserve_metadata();
+        xlm_config.c_factor = 7_500_000;
+        let mut ousd_config = default_reserve_metadata();
+        ousd_config.l_factor = 9_500_000;
+        let pool_index = PoolFixtureBuilder::new("Scripted")
+            .with_oracle(&oracle_id)
+            .with_reserve(TokenIndex::OUSD, ousd_config)
+            .with_reserve(TokenIndex::XLM, xlm_config)
+            .with_backstop(&frodo, 50_000 * SCALAR_7)
+            .build(&mut fixture);
+        let pool_fixture = &fixture.pools[pool_index];
+        pool_fixture.treasury.increase_supply(&(100_000 * SCALAR_7));
+
+        // samwise borrows oUSD against $1000 of XLM, and is healthy until the ramp
+        let samwise = Address::generate(&fixture.env);
+        fixture.tokens[TokenIndex::XLM].mint(&samwise, &(10_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, pool_index, &samwise, 10_000 * SCALAR_7, 700 * SCALAR_7);
+        fixture.jump(start - now);
+        let result = pool_fixture.pool.try_new_liquidation_auction(&samwise, &60);
+        assert!(result.is_err());
+
+        // 6000 seconds into the ramp, XLM is at $0.09 and samwise is underwater
+        fixture.jump(6000);
+        assert_eq!(oracle_client.lastprice(&xlm).unwrap().price, 0_0900000);
+        let auction = pool_fixture.pool.new_liquidation_auction(&samwise, &60);
+        let ousd = &fixture.tokens[TokenIndex::OUSD];
+        let ousd_bid = auction.bid.get(ousd.address.clone()).unwrap();
+        assert!(ousd_bid > 0);
+        ousd_bid
+    });
+}
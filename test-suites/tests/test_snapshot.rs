@@ -0,0 +1,64 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use test_suites::{
+    create_fixture_with_data,
+    invariants::assert_invariants,
+    parity::assert_parity,
+    pool::{Request, RequestType},
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// Each fixture restored from a snapshot starts from the snapshotted state, and changes made to
+/// one do not leak into the others
+#[test]
+fn test_snapshot_restore() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let snapshot = fixture.snapshot();
+        let supply = fixture.read_treasury_supply(0);
+        let timestamp = fixture.env.ledger().timestamp();
+
+        // the treasury of the first fork mints more oUSD into the pool
+        let fork = snapshot.restore();
+        assert_eq!(fork.env.ledger().timestamp(), timestamp);
+        assert_eq!(fork.read_treasury_supply(0), supply);
+        fork.pools[0].treasury.increase_supply(&(10_000 * SCALAR_7));
+        assert_eq!(fork.read_treasury_supply(0), supply + 10_000 * SCALAR_7);
+        assert_invariants(&fork);
+
+        // the second fork still sees the snapshotted supply, and can borrow against it
+        let fork = snapshot.restore();
+        assert_eq!(fork.read_treasury_supply(0), supply);
+        let samwise = Address::generate(&fork.env);
+        let ousd = &fork.tokens[TokenIndex::OUSD];
+        let xlm = &fork.tokens[TokenIndex::XLM];
+        fork.oracle.set_price_stable(&vec![&fork.env, 1_0000000, 0_1000000]);
+        xlm.mint(&samwise, &(1_000 * SCALAR_7));
+        fork.pools[0].pool.submit(
+            &samwise,
+            &samwise,
+            &samwise,
+            &vec![
+                &fork.env,
+                Request {
+                    request_type: RequestType::SupplyCollateral.into_u32(),
+                    address: xlm.address.clone(),
+                    amount: 1_000 * SCALAR_7,
+                },
+                Request {
+                    request_type: RequestType::Borrow.into_u32(),
+                    address: ousd.address.clone(),
+                    amount: 10 * SCALAR_7,
+                },
+            ],
+        );
+        assert_eq!(ousd.balance(&samwise), 10 * SCALAR_7);
+        assert_invariants(&fork);
+
+        // the original fixture is untouched
+        assert_eq!(fixture.read_treasury_supply(0), supply);
+        assert_invariants(&fixture);
+        supply
+    });
+}
@@ -0,0 +1,58 @@
+#![cfg(test)]
+use soroban_sdk::{testutils::Address as _, Address};
+use test_suites::{
+    auctions::open_ousd_borrow,
+    create_fixture_with_data,
+    invariants::assert_invariants,
+    parity::assert_parity,
+    test_fixture::{TokenIndex, SCALAR_7},
+};
+
+/// Ledger and time jumps move the sequence and timestamp together
+#[test]
+fn test_jumps_move_sequence_and_time() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let sequence = fixture.env.ledger().sequence();
+        let timestamp = fixture.env.ledger().timestamp();
+
+        fixture.jump_ledgers(100);
+        assert_eq!(fixture.env.ledger().sequence(), sequence + 100);
+        assert_eq!(fixture.env.ledger().timestamp(), timestamp + 500);
+
+        fixture.jump_time(60 * 60);
+        assert_eq!(fixture.env.ledger().sequence(), sequence + 100 + 720);
+        assert_eq!(fixture.env.ledger().timestamp(), timestamp + 500 + 60 * 60);
+    });
+}
+
+/// Interest only shows in the stored reserve data once the pool is poked, which
+/// `jump_time_and_accrue` does for every reserve
+#[test]
+fn test_jump_time_and_accrue() {
+    assert_parity(|wasm| {
+        let fixture = create_fixture_with_data(wasm);
+        let samwise = Address::generate(&fixture.env);
+        fixture.tokens[TokenIndex::XLM].mint(&samwise, &(100_000 * SCALAR_7));
+        open_ousd_borrow(&fixture, 0, &samwise, 100_000 * SCALAR_7, 5_000 * SCALAR_7);
+        let ousd_data = fixture.read_reserve_data(0, TokenIndex::OUSD);
+        let xlm_data = fixture.read_reserve_data(0, TokenIndex::XLM);
+
+        fixture.jump_time(7 * 24 * 60 * 60);
+        let reserve_data = fixture.read_reserve_data(0, TokenIndex::OUSD);
+        assert_eq!(reserve_data.d_rate, ousd_data.d_rate);
+        assert_eq!(reserve_data.last_time, ousd_data.last_time);
+
+        fixture.jump_time_and_accrue(7 * 24 * 60 * 60);
+        let reserve_data = fixture.read_reserve_data(0, TokenIndex::OUSD);
+        assert!(reserve_data.d_rate > ousd_data.d_rate);
+        assert!(reserve_data.b_rate > ousd_data.b_rate);
+        assert_eq!(reserve_data.last_time, fixture.env.ledger().timestamp());
+        let reserve_data = fixture.read_reserve_data(0, TokenIndex::XLM);
+        assert_eq!(reserve_data.last_time, fixture.env.ledger().timestamp());
+        assert_eq!(reserve_data.b_supply, xlm_data.b_supply);
+        assert_invariants(&fixture);
+        let reserve_data = fixture.read_reserve_data(0, TokenIndex::OUSD);
+        (reserve_data.d_rate, reserve_data.b_rate)
+    });
+}
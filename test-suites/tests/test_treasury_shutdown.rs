@@ -0,0 +1,64 @@
+#![cfg(test)]
+use mock_pool::Request;
+use orbit_common::pool::RequestType;
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env};
+use test_suites::{
+    assert_contract_err, pool::create_mock_pool, test_fixture::SCALAR_7,
+    treasury::{create_treasury, TTL_BUMP, TTL_THRESHOLD},
+};
+use treasury::{TreasuryClient, TreasuryError};
+
+#[test]
+fn test_treasury_shutdown() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let (pool_id, pool_client) = create_mock_pool(&e, &vec![&e, ousd.clone()]);
+    treasury_client.initialize(&Address::generate(&e), &ousd, &pool_id, &TTL_THRESHOLD, &TTL_BUMP);
+    treasury_client.increase_supply(&(1000 * SCALAR_7));
+    let adapter = Address::generate(&e);
+    treasury_client.set_adapter_cap(&adapter, &(100 * SCALAR_7));
+    treasury_client.set_flash_minter(&Address::generate(&e));
+
+    // borrowers hold 600 of the supply
+    let borrower = Address::generate(&e);
+    let borrow = Request {
+        request_type: RequestType::Borrow.into_u32(),
+        address: ousd.clone(),
+        amount: 600 * SCALAR_7,
+    };
+    pool_client.submit(&borrower, &borrower, &borrower, &vec![&e, borrow]);
+
+    // only the shutdown module can shut the treasury down
+    assert_contract_err!(treasury_client.try_shutdown(), TreasuryError::UnauthorizedError);
+    treasury_client.set_shutdown_module(&Address::generate(&e));
+
+    // the shutdown withdraws and burns what the pool can pay out
+    assert_eq!(treasury_client.shutdown(), 400 * SCALAR_7);
+    assert!(treasury_client.is_shutdown());
+    assert_eq!(treasury_client.get_token_supply(), 600 * SCALAR_7);
+    assert_eq!(ousd_client.balance(&pool_id), 0);
+    assert_eq!(ousd_client.balance(&treasury_id), 0);
+
+    // and nothing more can be minted
+    let result = treasury_client.try_increase_supply(&SCALAR_7);
+    assert_contract_err!(result, TreasuryError::TreasuryShutdown);
+    let result = treasury_client.try_deposit_adapter(&adapter, &SCALAR_7);
+    assert_contract_err!(result, TreasuryError::TreasuryShutdown);
+    let result = treasury_client.try_flash_mint(&SCALAR_7);
+    assert_contract_err!(result, TreasuryError::TreasuryShutdown);
+
+    // the rest is withdrawn as borrowers repay
+    let repay = Request {
+        request_type: RequestType::Repay.into_u32(),
+        address: ousd.clone(),
+        amount: 200 * SCALAR_7,
+    };
+    pool_client.submit(&borrower, &borrower, &borrower, &vec![&e, repay]);
+    treasury_client.decrease_supply(&(200 * SCALAR_7));
+    assert_eq!(treasury_client.get_token_supply(), 400 * SCALAR_7);
+    assert_eq!(ousd_client.balance(&pool_id), 0);
+}
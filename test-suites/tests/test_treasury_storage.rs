@@ -0,0 +1,98 @@
+#![cfg(test)]
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, IntoVal, Symbol, Val, Vec,
+};
+use test_suites::{
+    pool::create_mock_pool,
+    test_fixture::SCALAR_7,
+    treasury::{create_treasury, TTL_BUMP, TTL_THRESHOLD},
+};
+use treasury::{TreasuryClient, TreasuryError, TtlConfig};
+
+/// Ledgers between the reads, under the rent the treasury bumps an entry to on access but over
+/// what is left of it by the next read
+const LEDGERS: u32 = 200_000;
+
+/// Panic unless a persistent entry of a contract is live. The host only checks liveness when
+/// rent is bumped, so this bumps the entry by nothing.
+fn assert_live(e: &Env, contract: &Address, key: &Vec<Val>) {
+    e.as_contract(contract, || e.storage().persistent().extend_ttl(key, 0, 0));
+}
+
+/// The treasury keeps the pool and supply of its token in persistent entries rather than its
+/// instance, and bumps their rent each time it reads them, so they outlive their first rent
+/// for as long as the treasury is used
+#[test]
+fn test_treasury_per_token_storage() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let (pool_id, _) = create_mock_pool(&e, &vec![&e, ousd.clone()]);
+    treasury_client.initialize(&Address::generate(&e), &ousd, &pool_id, &TTL_THRESHOLD, &TTL_BUMP);
+    treasury_client.increase_supply(&(1_000 * SCALAR_7));
+
+    e.as_contract(&treasury_id, || {
+        let instance = e.storage().instance();
+        assert!(!instance.has(&Symbol::new(&e, "Blend")));
+        assert!(!instance.has(&Symbol::new(&e, "TokenSupply")));
+    });
+
+    let supply_key = vec![&e, Symbol::new(&e, "TokenSupply").into_val(&e), ousd.into_val(&e)];
+    let blend_key = vec![&e, Symbol::new(&e, "Blend").into_val(&e), ousd.into_val(&e)];
+    for _ in 0..3 {
+        e.ledger().with_mut(|li| li.sequence_number += LEDGERS);
+        assert_live(&e, &treasury_id, &supply_key);
+        assert_live(&e, &treasury_id, &blend_key);
+        assert_eq!(treasury_client.get_token_supply(), 1_000 * SCALAR_7);
+        assert_eq!(treasury_client.get_blend_address(), pool_id);
+    }
+}
+
+/// The treasury is initialized with how far it bumps its rent, the admin can change it, and
+/// anyone can bump it all the way
+#[test]
+fn test_treasury_ttl_config() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (treasury_id, _) = create_treasury(&e, false);
+    let treasury_client = TreasuryClient::new(&e, &treasury_id);
+    let ousd = e.register_stellar_asset_contract(treasury_id.clone());
+    let (pool_id, _) = create_mock_pool(&e, &vec![&e, ousd.clone()]);
+    let max_ttl = e.as_contract(&treasury_id, || e.storage().max_ttl());
+    let admin = Address::generate(&e);
+    let invalid = [(300_000, 200_000), (200_000, 200_000), (100, max_ttl + 1)];
+    for (threshold, bump) in invalid {
+        let result = treasury_client.try_initialize(&admin, &ousd, &pool_id, &threshold, &bump);
+        assert_eq!(result.err(), Some(Ok(TreasuryError::InvalidTtlConfig.into())));
+    }
+    treasury_client.initialize(&admin, &ousd, &pool_id, &TTL_THRESHOLD, &(2 * TTL_BUMP));
+    let initial = TtlConfig {
+        threshold: TTL_THRESHOLD,
+        bump: 2 * TTL_BUMP,
+    };
+    assert_eq!(treasury_client.get_ttl_config(), initial);
+
+    for (threshold, bump) in invalid {
+        let result = treasury_client.try_set_ttl_config(&threshold, &bump);
+        assert_eq!(result.err(), Some(Ok(TreasuryError::InvalidTtlConfig.into())));
+    }
+    treasury_client.set_ttl_config(&100, &(3 * LEDGERS));
+    let config = TtlConfig {
+        threshold: 100,
+        bump: 3 * LEDGERS,
+    };
+    assert_eq!(treasury_client.get_ttl_config(), config);
+
+    // the entries are past their first rent, but were bumped to the new bump
+    treasury_client.extend_ttl();
+    e.ledger().with_mut(|li| li.sequence_number += 2 * LEDGERS + LEDGERS / 2);
+    let supply_key = vec![&e, Symbol::new(&e, "TokenSupply").into_val(&e), ousd.into_val(&e)];
+    let blend_key = vec![&e, Symbol::new(&e, "Blend").into_val(&e), ousd.into_val(&e)];
+    assert_live(&e, &treasury_id, &supply_key);
+    assert_live(&e, &treasury_id, &blend_key);
+    e.as_contract(&treasury_id, || e.storage().instance().extend_ttl(0, 0));
+    assert_eq!(treasury_client.get_token_supply(), 0);
+}
@@ -0,0 +1,70 @@
+#![cfg(test)]
+use soroban_sdk::{testutils::Address as _, Address, Symbol};
+use test_suites::{
+    auctions::open_ousd_borrow,
+    auth::assert_authorizers,
+    create_fixture_with_data,
+    invariants::assert_invariants,
+    test_fixture::{TokenIndex, SCALAR_7},
+    treasury::upgrade_to_native,
+};
+use treasury::TreasuryClient as CurrentTreasuryClient;
+
+/// A treasury deployed from the released wasm and holding a supply keeps its admin, token, pool
+/// and supply once its code is replaced with the current build and its storage is migrated, and
+/// the current build can keep managing it
+#[test]
+fn test_treasury_upgrade_keeps_state() {
+    let fixture = create_fixture_with_data(true);
+    let pool_fixture = &fixture.pools[0];
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    let xlm = &fixture.tokens[TokenIndex::XLM];
+
+    // populate the released treasury and borrow part of its supply
+    pool_fixture.treasury.increase_supply(&(20_000 * SCALAR_7));
+    let samwise = Address::generate(&fixture.env);
+    xlm.mint(&samwise, &(100_000 * SCALAR_7));
+    open_ousd_borrow(&fixture, 0, &samwise, 100_000 * SCALAR_7, 5_000 * SCALAR_7);
+    let supply = fixture.read_treasury_supply(0);
+    let positions = pool_fixture.pool.get_positions(&pool_fixture.treasury.address);
+    assert_eq!(supply, 120_000 * SCALAR_7);
+
+    upgrade_to_native(&fixture.env, &pool_fixture.treasury.address);
+    let treasury = CurrentTreasuryClient::new(&fixture.env, &pool_fixture.treasury.address);
+    assert_eq!(treasury.version(), 0);
+    treasury.migrate();
+    assert_authorizers(&fixture.env, std::slice::from_ref(&fixture.bombadil));
+    assert_eq!(treasury.version(), 1);
+
+    // the migration moves the pool and the supply out of the instance into their own entries
+    let in_instance = fixture.env.as_contract(&treasury.address, || {
+        let instance = fixture.env.storage().instance();
+        instance.has(&Symbol::new(&fixture.env, "Blend"))
+            || instance.has(&Symbol::new(&fixture.env, "TokenSupply"))
+    });
+    assert!(!in_instance);
+    assert_eq!(treasury.get_token_address(), ousd.address);
+    assert_eq!(treasury.get_blend_address(), pool_fixture.pool.address);
+    assert_eq!(treasury.get_token_supply(), supply);
+    assert_eq!(pool_fixture.pool.get_positions(&treasury.address), positions);
+    assert_invariants(&fixture);
+
+    // state the released treasury never wrote reads as empty
+    let adapter = Address::generate(&fixture.env);
+    let adapter_data = treasury.get_adapter(&adapter);
+    assert_eq!((adapter_data.cap, adapter_data.deployed), (0, 0));
+
+    // the admin carries over and can still manage the supply
+    treasury.set_adapter_cap(&adapter, &(1_000 * SCALAR_7));
+    assert_authorizers(&fixture.env, std::slice::from_ref(&fixture.bombadil));
+    treasury.decrease_supply(&(20_000 * SCALAR_7));
+    assert_authorizers(&fixture.env, std::slice::from_ref(&fixture.bombadil));
+    assert_eq!(treasury.get_token_supply(), supply - 20_000 * SCALAR_7);
+    assert_eq!(fixture.read_treasury_supply(0), supply - 20_000 * SCALAR_7);
+    assert_invariants(&fixture);
+
+    // the migration is only run once
+    treasury.migrate();
+    assert_eq!(treasury.version(), 1);
+    assert_eq!(treasury.get_token_supply(), supply - 20_000 * SCALAR_7);
+}
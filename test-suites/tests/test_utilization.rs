@@ -0,0 +1,113 @@
+#![cfg(test)]
+#![allow(clippy::zero_prefixed_literal)]
+use soroban_sdk::{testutils::Address as _, vec, Address};
+use test_suites::{
+    assert_contract_err,
+    auctions::{open_ousd_borrow, refresh_prices},
+    create_fixture_with_data,
+    d3m::{create_d3m, D3MConfig},
+    invariants::assert_invariants,
+    pool::{Request, RequestType},
+    test_fixture::{TestFixture, TokenIndex, SCALAR_7},
+};
+use treasury::TreasuryError;
+
+fn repay(fixture: &TestFixture, user: &Address, amount: i128) {
+    let request = Request {
+        request_type: RequestType::Repay.into_u32(),
+        address: fixture.tokens[TokenIndex::OUSD].address.clone(),
+        amount,
+    };
+    fixture.pools[0]
+        .pool
+        .submit(user, user, user, &vec![&fixture.env, request]);
+}
+
+/// Borrowers take the pool to its max utilization of 95%. The treasury can withdraw the idle
+/// liquidity left, taking the pool to 100%, but no more, until borrowers repay.
+#[test]
+fn test_decrease_supply_at_full_utilization() {
+    let fixture = create_fixture_with_data(false);
+    let pool_fixture = &fixture.pools[0];
+    let treasury = &pool_fixture.treasury;
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    let samwise = Address::generate(&fixture.env);
+    fixture.tokens[TokenIndex::XLM].mint(&samwise, &(3_000_000 * SCALAR_7));
+    open_ousd_borrow(&fixture, 0, &samwise, 3_000_000 * SCALAR_7, 95_000 * SCALAR_7);
+    assert_eq!(ousd.balance(&pool_fixture.pool.address), 5_000 * SCALAR_7);
+
+    // more than the pool holds is refused up front, leaving the supply as it was
+    let result = treasury.try_decrease_supply(&(10_000 * SCALAR_7));
+    assert_contract_err!(result, TreasuryError::InsufficientLiquidity);
+    assert_eq!(fixture.read_treasury_supply(0), 100_000 * SCALAR_7);
+
+    // what the pool holds can be withdrawn, which leaves it fully utilized
+    treasury.decrease_supply(&(5_000 * SCALAR_7));
+    assert_eq!(ousd.balance(&pool_fixture.pool.address), 0);
+    assert_eq!(fixture.read_treasury_supply(0), 95_000 * SCALAR_7);
+    let result = treasury.try_decrease_supply(&1);
+    assert_contract_err!(result, TreasuryError::InsufficientLiquidity);
+    assert_invariants(&fixture);
+
+    // interest keeps accruing on a fully utilized pool, and nothing can be withdrawn until
+    // borrowers repay
+    fixture.jump(30 * 24 * 60 * 60);
+    refresh_prices(&fixture);
+    let result = treasury.try_decrease_supply(&SCALAR_7);
+    assert_contract_err!(result, TreasuryError::InsufficientLiquidity);
+    ousd.mint(&samwise, &(1_000 * SCALAR_7));
+    repay(&fixture, &samwise, 20_000 * SCALAR_7);
+    treasury.decrease_supply(&(20_000 * SCALAR_7));
+    assert_eq!(fixture.read_treasury_supply(0), 75_000 * SCALAR_7);
+    assert_invariants(&fixture);
+}
+
+/// The direct deposit module has its ceiling cut while the pool is highly utilized. It unwinds
+/// only the idle liquidity, taking the pool to 100% utilization, and the rest as borrowers repay.
+#[test]
+fn test_d3m_unwinds_idle_liquidity_at_full_utilization() {
+    let fixture = create_fixture_with_data(false);
+    let pool_fixture = &fixture.pools[0];
+    let pool = &pool_fixture.pool.address;
+    let ousd = &fixture.tokens[TokenIndex::OUSD];
+    let (d3m_id, d3m_client) = create_d3m(&fixture.env);
+    d3m_client.initialize(&fixture.bombadil, &ousd.address);
+    pool_fixture.treasury.set_admin(&d3m_id);
+    let config = D3MConfig {
+        treasury: pool_fixture.treasury.address.clone(),
+        ceiling: 200_000 * SCALAR_7,
+        floor: 10_000 * SCALAR_7,
+        target_util: 0_8000000,
+    };
+    d3m_client.set_pool(pool, &config);
+
+    // borrowing to 95% utilization has the module mint back down to 80%
+    let samwise = Address::generate(&fixture.env);
+    fixture.tokens[TokenIndex::XLM].mint(&samwise, &(3_000_000 * SCALAR_7));
+    open_ousd_borrow(&fixture, 0, &samwise, 3_000_000 * SCALAR_7, 95_000 * SCALAR_7);
+    assert_eq!(d3m_client.exec(pool), 18_750 * SCALAR_7);
+    assert_eq!(ousd.balance(pool), 23_750 * SCALAR_7);
+
+    // with the ceiling under what is borrowed, only the idle liquidity is unwound
+    d3m_client.set_pool(
+        pool,
+        &D3MConfig {
+            ceiling: 50_000 * SCALAR_7,
+            ..config
+        },
+    );
+    assert_eq!(d3m_client.exec(pool), -23_750 * SCALAR_7);
+    assert_eq!(ousd.balance(pool), 0);
+    assert_eq!(d3m_client.exec(pool), 0);
+    assert_eq!(fixture.read_treasury_supply(0), 95_000 * SCALAR_7);
+    assert_invariants(&fixture);
+
+    // repayments are unwound as they come in, until the pool is back at its target utilization
+    repay(&fixture, &samwise, 30_000 * SCALAR_7);
+    assert_eq!(d3m_client.exec(pool), -30_000 * SCALAR_7);
+    ousd.mint(&samwise, &(10_000 * SCALAR_7));
+    repay(&fixture, &samwise, 30_000 * SCALAR_7);
+    assert_eq!(d3m_client.exec(pool), -21_250 * SCALAR_7);
+    assert_eq!(fixture.read_treasury_supply(0), 43_750 * SCALAR_7);
+    assert_invariants(&fixture);
+}
@@ -0,0 +1,281 @@
+use crate::errors::TimelockError;
+use crate::events::TimelockEvents;
+use crate::storage::{self, Operation, OperationStatus, TimelockSettings};
+use orbit_access::Role;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, Env, Symbol, TryFromVal,
+    Val, Vec,
+};
+
+#[contract]
+pub struct TimelockContract;
+
+#[contractclient(name = "TimelockClient")]
+pub trait Timelock {
+    /// Initialize the timelock
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin that queues calls, usually the governor
+    /// * `guardian` - The Address for the guardian that can cancel queued calls
+    /// * `settings` - The delays applied by the timelock
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    /// If the grace period is zero
+    fn initialize(e: Env, admin: Address, guardian: Address, settings: TimelockSettings);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Guardian only) Set a new guardian
+    ///
+    /// ### Arguments
+    /// * `guardian` - The new guardian address
+    ///
+    /// ### Panics
+    /// If the caller is not the guardian
+    fn set_guardian(e: Env, guardian: Address);
+
+    /// Get the timelock settings. The settings can only be changed by a timelocked
+    /// `set_settings` call to the timelock itself.
+    fn get_settings(e: Env) -> TimelockSettings;
+
+    /// Get the delay applied to calls to a target contract. Per-target delays can only be changed
+    /// by a timelocked `set_delay` call to the timelock itself, and never go below the minimum
+    /// delay.
+    ///
+    /// ### Arguments
+    /// * `target` - The Address of the target contract
+    fn get_delay(e: Env, target: Address) -> u64;
+
+    /// Get an operation
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the operation
+    ///
+    /// ### Panics
+    /// If the operation does not exist
+    fn get_operation(e: Env, id: u32) -> Operation;
+
+    /// (Admin only) Queue a call to be executed once the delay of its target expires
+    ///
+    /// Returns the id of the operation
+    ///
+    /// ### Arguments
+    /// * `target` - The Address of the contract to call
+    /// * `function` - The function to call
+    /// * `args` - The arguments of the call
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the call is to the timelock itself and is not `set_settings` or `set_delay`
+    fn queue(e: Env, target: Address, function: Symbol, args: Vec<Val>) -> u32;
+
+    /// Execute a queued call, with the timelock as the invoker
+    ///
+    /// ### Arguments
+    /// * `id` - The id of the operation
+    ///
+    /// ### Panics
+    /// If the operation is not queued
+    /// If the delay has not expired, or the grace period has passed
+    fn execute(e: Env, id: u32);
+
+    /// (Admin or guardian only) Cancel a queued call
+    ///
+    /// ### Arguments
+    /// * `caller` - The Address canceling the operation
+    /// * `id` - The id of the operation
+    ///
+    /// ### Panics
+    /// If the caller is not the admin or the guardian
+    /// If the operation is not queued
+    fn cancel(e: Env, caller: Address, id: u32);
+}
+
+#[contractimpl]
+impl Timelock for TimelockContract {
+    fn initialize(e: Env, admin: Address, guardian: Address, settings: TimelockSettings) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, TimelockError::AlreadyInitializedError);
+        }
+        require_valid_settings(&e, &settings);
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+        storage::set_settings(&e, &settings);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn set_guardian(e: Env, guardian: Address) {
+        storage::extend_instance(&e);
+        let old_guardian = orbit_access::require_role(&e, Role::Guardian);
+
+        orbit_access::set_role(&e, Role::Guardian, &guardian);
+
+        TimelockEvents::set_guardian(&e, old_guardian, guardian);
+    }
+
+    fn get_settings(e: Env) -> TimelockSettings {
+        storage::extend_instance(&e);
+        storage::get_settings(&e)
+    }
+
+    fn get_delay(e: Env, target: Address) -> u64 {
+        storage::extend_instance(&e);
+        get_target_delay(&e, &target)
+    }
+
+    fn get_operation(e: Env, id: u32) -> Operation {
+        storage::extend_instance(&e);
+        load_operation(&e, id)
+    }
+
+    fn queue(e: Env, target: Address, function: Symbol, args: Vec<Val>) -> u32 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        if target == e.current_contract_address()
+            && function != Symbol::new(&e, "set_settings")
+            && function != Symbol::new(&e, "set_delay")
+        {
+            panic_with_error!(&e, TimelockError::InvalidCall);
+        }
+
+        let id = storage::get_operation_count(&e);
+        let eta = e.ledger().timestamp() + get_target_delay(&e, &target);
+        storage::set_operation(
+            &e,
+            id,
+            &Operation {
+                target: target.clone(),
+                function: function.clone(),
+                args,
+                eta,
+                status: OperationStatus::Queued,
+            },
+        );
+        storage::set_operation_count(&e, id + 1);
+
+        TimelockEvents::queue(&e, id, target, function, eta);
+        id
+    }
+
+    fn execute(e: Env, id: u32) {
+        storage::extend_instance(&e);
+
+        let mut operation = load_operation(&e, id);
+        if operation.status != OperationStatus::Queued {
+            panic_with_error!(&e, TimelockError::OperationNotQueued);
+        }
+        let now = e.ledger().timestamp();
+        if now < operation.eta {
+            panic_with_error!(&e, TimelockError::TimelockNotExpired);
+        }
+        if now > operation.eta + storage::get_settings(&e).grace_period {
+            panic_with_error!(&e, TimelockError::OperationExpired);
+        }
+
+        operation.status = OperationStatus::Executed;
+        storage::set_operation(&e, id, &operation);
+
+        if operation.target == e.current_contract_address() {
+            // the timelock can't invoke itself, so its own calls are applied directly
+            execute_self_call(&e, &operation.function, &operation.args);
+        } else {
+            e.invoke_contract::<Val>(&operation.target, &operation.function, operation.args);
+        }
+
+        TimelockEvents::execute(&e, id);
+    }
+
+    fn cancel(e: Env, caller: Address, id: u32) {
+        storage::extend_instance(&e);
+        caller.require_auth();
+
+        if !orbit_access::has_role(&e, Role::Admin, &caller)
+            && !orbit_access::has_role(&e, Role::Guardian, &caller)
+        {
+            panic_with_error!(&e, TimelockError::UnauthorizedError);
+        }
+        let mut operation = load_operation(&e, id);
+        if operation.status != OperationStatus::Queued {
+            panic_with_error!(&e, TimelockError::OperationNotQueued);
+        }
+
+        operation.status = OperationStatus::Canceled;
+        storage::set_operation(&e, id, &operation);
+
+        TimelockEvents::cancel(&e, id, caller);
+    }
+}
+
+/// Load an operation
+///
+/// ### Panics
+/// If the operation does not exist
+fn load_operation(e: &Env, id: u32) -> Operation {
+    storage::get_operation(e, id)
+        .unwrap_or_else(|| panic_with_error!(e, TimelockError::OperationNotFound))
+}
+
+/// Get the delay applied to calls to a target contract
+fn get_target_delay(e: &Env, target: &Address) -> u64 {
+    let min_delay = storage::get_settings(e).min_delay;
+    match storage::get_delay(e, target) {
+        Some(delay) => delay.max(min_delay),
+        None => min_delay,
+    }
+}
+
+/// Apply a queued call made to the timelock itself
+///
+/// ### Panics
+/// If the call is not a valid `set_settings` or `set_delay` call
+fn execute_self_call(e: &Env, function: &Symbol, args: &Vec<Val>) {
+    if *function == Symbol::new(e, "set_settings") && args.len() == 1 {
+        let settings = TimelockSettings::try_from_val(e, &args.get_unchecked(0))
+            .unwrap_or_else(|_| panic_with_error!(e, TimelockError::InvalidCall));
+        require_valid_settings(e, &settings);
+
+        storage::set_settings(e, &settings);
+
+        TimelockEvents::set_settings(e, settings);
+    } else if *function == Symbol::new(e, "set_delay") && args.len() == 2 {
+        let target = Address::try_from_val(e, &args.get_unchecked(0))
+            .unwrap_or_else(|_| panic_with_error!(e, TimelockError::InvalidCall));
+        let delay = u64::try_from_val(e, &args.get_unchecked(1))
+            .unwrap_or_else(|_| panic_with_error!(e, TimelockError::InvalidCall));
+
+        storage::set_delay(e, &target, delay);
+
+        TimelockEvents::set_delay(e, target, delay);
+    } else {
+        panic_with_error!(e, TimelockError::InvalidCall);
+    }
+}
+
+/// Require that the timelock settings are valid
+///
+/// ### Panics
+/// If the grace period is zero
+fn require_valid_settings(e: &Env, settings: &TimelockSettings) {
+    if settings.grace_period == 0 {
+        panic_with_error!(e, TimelockError::InvalidSettings);
+    }
+}
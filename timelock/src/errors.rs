@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the timelock contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Timelock specific errors start at 3400.
+pub enum TimelockError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+
+    // Timelock
+    OperationNotFound = 3400,
+    OperationNotQueued = 3401,
+    TimelockNotExpired = 3402,
+    OperationExpired = 3403,
+    InvalidCall = 3404,
+    InvalidSettings = 3405,
+}
@@ -0,0 +1,62 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::TimelockSettings;
+
+pub struct TimelockEvents {}
+
+impl TimelockEvents {
+    /// Emitted when the guardian is updated
+    ///
+    /// - topics - `["set_guardian", old_guardian: Address]`
+    /// - data - `guardian: Address`
+    pub fn set_guardian(e: &Env, old_guardian: Address, guardian: Address) {
+        let topics = (Symbol::new(e, topics::SET_GUARDIAN), old_guardian);
+        e.events().publish(topics, guardian);
+    }
+
+    /// Emitted when a timelocked call updates the settings
+    ///
+    /// - topics - `["set_settings"]`
+    /// - data - `settings: TimelockSettings`
+    pub fn set_settings(e: &Env, settings: TimelockSettings) {
+        let topics = (Symbol::new(e, topics::SET_SETTINGS),);
+        e.events().publish(topics, settings);
+    }
+
+    /// Emitted when a timelocked call updates the delay of a target contract
+    ///
+    /// - topics - `["set_delay", target: Address]`
+    /// - data - `delay: u64`
+    pub fn set_delay(e: &Env, target: Address, delay: u64) {
+        let topics = (Symbol::new(e, topics::SET_DELAY), target);
+        e.events().publish(topics, delay);
+    }
+
+    /// Emitted when a call is queued
+    ///
+    /// - topics - `["queue", id: u32, target: Address]`
+    /// - data - `[function: Symbol, eta: u64]`
+    pub fn queue(e: &Env, id: u32, target: Address, function: Symbol, eta: u64) {
+        let topics = (Symbol::new(e, topics::QUEUE), id, target);
+        e.events().publish(topics, (function, eta));
+    }
+
+    /// Emitted when a queued call is executed
+    ///
+    /// - topics - `["execute", id: u32]`
+    /// - data - `()`
+    pub fn execute(e: &Env, id: u32) {
+        let topics = (Symbol::new(e, topics::EXECUTE), id);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a queued call is canceled
+    ///
+    /// - topics - `["cancel", id: u32]`
+    /// - data - `caller: Address`
+    pub fn cancel(e: &Env, id: u32, caller: Address) {
+        let topics = (Symbol::new(e, topics::CANCEL), id);
+        e.events().publish(topics, caller);
+    }
+}
@@ -0,0 +1,171 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol, Val, Vec};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const SETTINGS_KEY: &str = "Settings";
+const OPERATION_COUNT_KEY: &str = "OpCount";
+
+/// The delays applied by the timelock
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TimelockSettings {
+    pub min_delay: u64,    // the delay applied to every call, in seconds
+    pub grace_period: u64, // the time a call can be executed after its delay expires, in seconds
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum OperationStatus {
+    Queued,   // waiting for its delay to expire
+    Executed, // executed
+    Canceled, // canceled by the admin or the guardian
+}
+
+/// A call queued on the timelock
+#[derive(Clone)]
+#[contracttype]
+pub struct Operation {
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    pub eta: u64, // the time the call can be executed
+    pub status: OperationStatus,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum TimelockDataKey {
+    // An operation by id
+    Operation(u32),
+    // The delay of calls to a target contract
+    Delay(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Settings **********/
+
+/// Fetch the timelock settings
+///
+/// ### Panics
+/// If the settings do not exist
+pub fn get_settings(e: &Env) -> TimelockSettings {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SETTINGS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the timelock settings
+///
+/// ### Arguments
+/// * `settings` - The timelock settings
+pub fn set_settings(e: &Env, settings: &TimelockSettings) {
+    e.storage()
+        .instance()
+        .set::<Symbol, TimelockSettings>(&Symbol::new(e, SETTINGS_KEY), settings);
+}
+
+/********** Delays **********/
+
+/// Fetch the delay of calls to a target contract, if one is set
+///
+/// ### Arguments
+/// * `target` - The Address of the target contract
+pub fn get_delay(e: &Env, target: &Address) -> Option<u64> {
+    let key = TimelockDataKey::Delay(target.clone());
+    let result = e.storage().persistent().get::<TimelockDataKey, u64>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set the delay of calls to a target contract
+///
+/// ### Arguments
+/// * `target` - The Address of the target contract
+/// * `delay` - The delay, in seconds
+pub fn set_delay(e: &Env, target: &Address, delay: u64) {
+    let key = TimelockDataKey::Delay(target.clone());
+    e.storage()
+        .persistent()
+        .set::<TimelockDataKey, u64>(&key, &delay);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/********** Operations **********/
+
+/// Fetch the number of operations queued, which is also the id of the next operation
+pub fn get_operation_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, OPERATION_COUNT_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the number of operations queued
+///
+/// ### Arguments
+/// * `count` - The number of operations
+pub fn set_operation_count(e: &Env, count: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, OPERATION_COUNT_KEY), &count);
+}
+
+/// Fetch an operation, if it exists
+///
+/// ### Arguments
+/// * `id` - The id of the operation
+pub fn get_operation(e: &Env, id: u32) -> Option<Operation> {
+    let key = TimelockDataKey::Operation(id);
+    let result = e
+        .storage()
+        .persistent()
+        .get::<TimelockDataKey, Operation>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    }
+    result
+}
+
+/// Set an operation
+///
+/// ### Arguments
+/// * `id` - The id of the operation
+/// * `operation` - The operation
+pub fn set_operation(e: &Env, id: u32, operation: &Operation) {
+    let key = TimelockDataKey::Operation(id);
+    e.storage()
+        .persistent()
+        .set::<TimelockDataKey, Operation>(&key, operation);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
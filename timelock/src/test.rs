@@ -0,0 +1,153 @@
+#![cfg(test)]
+use crate::contract::{TimelockClient, TimelockContract};
+use crate::errors::TimelockError;
+use crate::storage::{OperationStatus, TimelockSettings};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[contract]
+pub struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn set_value(e: Env, value: u32) {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "value"), &value);
+    }
+
+    pub fn value(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&Symbol::new(&e, "value"))
+            .unwrap_or(0)
+    }
+}
+
+/// Create a timelock, returning the timelock client, the admin and the guardian
+fn create_timelock<'a>(e: &Env) -> (TimelockClient<'a>, Address, Address) {
+    let admin = Address::generate(e);
+    let guardian = Address::generate(e);
+    let timelock_id = e.register_contract(None, TimelockContract);
+    let timelock_client = TimelockClient::new(e, &timelock_id);
+    timelock_client.initialize(
+        &admin,
+        &guardian,
+        &TimelockSettings {
+            min_delay: DAY,
+            grace_period: 7 * DAY,
+        },
+    );
+    (timelock_client, admin, guardian)
+}
+
+#[test]
+fn test_queue_and_execute() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (timelock_client, _, _) = create_timelock(&e);
+    let target = e.register_contract(None, MockTarget);
+    let target_client = MockTargetClient::new(&e, &target);
+    let set_value = Symbol::new(&e, "set_value");
+
+    let id = timelock_client.queue(&target, &set_value, &vec![&e, 7_u32.into_val(&e)]);
+    assert_eq!(id, 0);
+    assert_eq!(timelock_client.get_operation(&id).eta, 1000 + DAY);
+
+    let result = timelock_client.try_execute(&id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::TimelockNotExpired.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + DAY);
+    timelock_client.execute(&id);
+    assert_eq!(target_client.value(), 7);
+    assert_eq!(
+        timelock_client.get_operation(&id).status,
+        OperationStatus::Executed
+    );
+    let result = timelock_client.try_execute(&id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::OperationNotQueued.into()))
+    );
+
+    // raise the delay of the target through the timelock itself
+    let id = timelock_client.queue(
+        &timelock_client.address,
+        &Symbol::new(&e, "set_delay"),
+        &vec![&e, target.into_val(&e), (3 * DAY).into_val(&e)],
+    );
+    e.ledger().with_mut(|li| li.timestamp += DAY);
+    timelock_client.execute(&id);
+    assert_eq!(timelock_client.get_delay(&target), 3 * DAY);
+    assert_eq!(timelock_client.get_delay(&Address::generate(&e)), DAY);
+
+    let id = timelock_client.queue(&target, &set_value, &vec![&e, 8_u32.into_val(&e)]);
+    e.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+    let result = timelock_client.try_execute(&id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::TimelockNotExpired.into()))
+    );
+
+    // the call expires once the grace period passes
+    e.ledger().with_mut(|li| li.timestamp += 8 * DAY + 1);
+    let result = timelock_client.try_execute(&id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::OperationExpired.into()))
+    );
+    assert_eq!(target_client.value(), 7);
+}
+
+#[test]
+fn test_cancel_and_invalid_calls() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (timelock_client, admin, guardian) = create_timelock(&e);
+    let target = e.register_contract(None, MockTarget);
+    let set_value = Symbol::new(&e, "set_value");
+
+    let id = timelock_client.queue(&target, &set_value, &vec![&e, 7_u32.into_val(&e)]);
+    let result = timelock_client.try_cancel(&Address::generate(&e), &id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::UnauthorizedError.into()))
+    );
+
+    timelock_client.cancel(&guardian, &id);
+    assert_eq!(
+        timelock_client.get_operation(&id).status,
+        OperationStatus::Canceled
+    );
+    e.ledger().with_mut(|li| li.timestamp += DAY);
+    let result = timelock_client.try_execute(&id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::OperationNotQueued.into()))
+    );
+    let result = timelock_client.try_cancel(&admin, &id);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::OperationNotQueued.into()))
+    );
+
+    // only settings and delays can be changed through the timelock itself
+    let result = timelock_client.try_queue(
+        &timelock_client.address,
+        &Symbol::new(&e, "set_admin"),
+        &vec![&e, admin.into_val(&e)],
+    );
+    assert_eq!(result.err(), Some(Ok(TimelockError::InvalidCall.into())));
+
+    let result = timelock_client.try_get_operation(&5);
+    assert_eq!(
+        result.err(),
+        Some(Ok(TimelockError::OperationNotFound.into()))
+    );
+}
@@ -1,6 +1,6 @@
 use crate::{
     errors::TreasuryFactoryError,
-    storage::{self, TreasuryInitMeta},
+    storage::{self, TreasuryInitMeta, LEDGER_BUMP, LEDGER_THRESHOLD},
 };
 
 use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, vec, Address, BytesN, Env, IntoVal, Symbol, Val};
@@ -82,12 +82,14 @@ impl TreasuryFactory for TreasuryFactoryContract {
         // }
 
         
-        // Init the treasury
+        // Init the treasury, bumping its rent like the factory's
         let treasury_init_args = vec![
             &e,
             admin.into_val(&e),
             token_address.into_val(&e),
             blend_pool.into_val(&e),
+            LEDGER_THRESHOLD.into_val(&e),
+            LEDGER_BUMP.into_val(&e),
         ];
         e.invoke_contract::<Val>(&treasury_id, &Symbol::new(&e, "initialize"), treasury_init_args);
         
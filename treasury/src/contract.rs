@@ -1,9 +1,18 @@
-use crate::storage;
-use crate::dependencies::pool::{Client as PoolClient, Request};
-use sep_41_token::StellarAssetClient;
-use soroban_sdk::{contract, contractclient, contractimpl, Address, Env, IntoVal, vec, Vec, Val, Symbol, panic_with_error};
+use crate::storage::{self, AdapterData, TtlConfig};
+use crate::dependencies::pool::{Client as PoolClient, PoolError, Request, RequestType};
+use crate::dependencies::rate_limiter::RateLimiterClient;
+use crate::dependencies::yield_adapter::YieldAdapterClient;
+use sep_41_token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractclient, contractimpl, Address, Env, Error, IntoVal, vec, Vec, Val, Symbol, panic_with_error};
+use soroban_sdk::xdr::ScErrorType;
 use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
 use crate::errors::TreasuryError;
+use orbit_access::Role;
+use orbit_events::topics;
+
+/// The version of the storage layout used by this contract. Bump this and add a step to
+/// `migrate` whenever stored data changes shape.
+const STORAGE_VERSION: u32 = 1;
 
 #[contract]
 pub struct TreasuryContract;
@@ -17,8 +26,19 @@ pub trait Treasury {
     /// * `admin` - The Address for the admin
     /// * `token` - The Address for the token
     /// * `blend_pool` - The Address for the blend pool
+    /// * `ttl_threshold` - The TTL, in ledgers, under which an entry is bumped
+    /// * `ttl_bump` - The TTL, in ledgers, an entry is bumped to
     ///
-    fn initialize(e: Env, admin: Address, token: Address, blend_pool: Address);
+    /// ### Panics
+    /// If the threshold is not under the bump, or the bump is over the most the network allows
+    fn initialize(
+        e: Env,
+        admin: Address,
+        token: Address,
+        blend_pool: Address,
+        ttl_threshold: u32,
+        ttl_bump: u32,
+    );
 
     /// (Admin only) Set a new address as the admin of this pool
     ///
@@ -29,6 +49,63 @@ pub trait Treasury {
     /// If the caller is not the admin
     fn set_admin(e: Env, admin: Address);
 
+    /// (Admin only) Set the rate limiter consulted before minting supply into the pool or an
+    /// adapter. Flash mints are exempt, see `flash_mint`.
+    ///
+    /// ### Arguments
+    /// * `rate_limiter` - The Address for the rate limiter
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_rate_limiter(e: Env, rate_limiter: Address);
+
+    /// (Admin only) Set the flash minter allowed to mint tokens it burns within the same
+    /// transaction
+    ///
+    /// ### Arguments
+    /// * `flash_minter` - The Address for the flash minter
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_flash_minter(e: Env, flash_minter: Address);
+
+    /// (Admin only) Set the shutdown module allowed to shut the treasury down
+    ///
+    /// ### Arguments
+    /// * `shutdown_module` - The Address for the shutdown module
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_shutdown_module(e: Env, shutdown_module: Address);
+
+    /// (Shutdown module only) Shut the treasury down for the emergency shutdown. Minting is
+    /// blocked for good, and the treasury withdraws as much of its supply from the blend pool as
+    /// the pool holds and burns it. What is still borrowed can be withdrawn with
+    /// `decrease_supply` once it is repaid. Returns the amount withdrawn.
+    ///
+    /// ### Panics
+    /// If no shutdown module is set or the caller is not the shutdown module
+    /// If the pool reverts, as for `increase_supply`
+    fn shutdown(e: Env) -> i128;
+
+    /// Check if the treasury was shut down
+    fn is_shutdown(e: Env) -> bool;
+
+    /// (Flash minter only) Mint tokens to the flash minter for a flash loan. The flash minter
+    /// burns them before its transaction ends, so the supply is unchanged. Does not bump the rent
+    /// of the treasury, as it is called for every loan; see `extend_ttl`.
+    ///
+    /// Flash mints are not counted against the rate limiter. The tokens never outlive the
+    /// transaction, and counting them would let anyone drain the mint limit with flash loans.
+    ///
+    /// ### Arguments
+    /// * `amount` - The amount to mint
+    ///
+    /// ### Panics
+    /// If no flash minter is set or the caller is not the flash minter
+    /// If the treasury was shut down
+    fn flash_mint(e: Env, amount: i128);
+
     /// (Admin only) Increase the supply of the pool
     ///
     /// ### Arguments
@@ -36,6 +113,10 @@ pub trait Treasury {
     ///
     /// ### Panics
     /// If the caller is not the admin
+    /// If the amount exceeds the mint limit of the rate limiter
+    /// If the treasury was shut down
+    /// If the pool reverts, with `PoolFrozen` if the pool is frozen, `PoolStalePrice` if its
+    /// oracle is stale, and `PoolSubmitFailed` for an error the treasury doesn't know
     fn increase_supply(e: Env, amount: i128);
 
     /// (Admin only) Decrease the supply of the pool
@@ -46,6 +127,8 @@ pub trait Treasury {
     /// ### Panics
     /// If the caller is not the admin
     /// If the supply is less than the amount
+    /// If the pool does not hold enough idle liquidity to withdraw the amount
+    /// If the pool reverts, as for `increase_supply`
     fn decrease_supply(e: Env, amount: i128);
 
     /// Get token address
@@ -53,37 +136,192 @@ pub trait Treasury {
 
     /// Get blend address
     fn get_blend_address(e: Env) -> Address;
+
+    /// Get the supply minted by the treasury into the blend pool
+    fn get_token_supply(e: Env) -> i128;
+
+    /// (Admin only) Set the most the treasury can deploy through a yield adapter
+    ///
+    /// ### Arguments
+    /// * `adapter` - The Address of the yield adapter
+    /// * `cap` - The cap on the reserves deployed through the adapter
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_adapter_cap(e: Env, adapter: Address, cap: i128);
+
+    /// Get the cap on and the reserves deployed through a yield adapter
+    ///
+    /// ### Arguments
+    /// * `adapter` - The Address of the yield adapter
+    fn get_adapter(e: Env, adapter: Address) -> AdapterData;
+
+    /// (Admin only) Mint tokens and deploy them through a yield adapter
+    ///
+    /// ### Arguments
+    /// * `adapter` - The Address of the yield adapter
+    /// * `amount` - The amount to deploy
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the reserves deployed through the adapter would exceed its cap
+    /// If the amount exceeds the mint limit of the rate limiter
+    /// If the treasury was shut down
+    fn deposit_adapter(e: Env, adapter: Address, amount: i128);
+
+    /// (Admin only) Withdraw tokens from a yield adapter and burn them
+    ///
+    /// ### Arguments
+    /// * `adapter` - The Address of the yield adapter
+    /// * `amount` - The amount to withdraw
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the reserves deployed through the adapter are less than the amount
+    fn withdraw_adapter(e: Env, adapter: Address, amount: i128);
+
+    /// (Admin only) Move data stored by an earlier version of the contract into the current
+    /// storage layout. Call this after upgrading the contract. Does nothing if the storage is
+    /// already current.
+    ///
+    /// Version 0 kept the blend pool and the token supply in instance storage. Version 1 keeps
+    /// them in persistent storage, keyed by token.
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the storage was written by a newer version of the contract
+    fn migrate(e: Env);
+
+    /// Get the version of the storage layout
+    fn version(e: Env) -> u32;
+
+    /// (Admin only) Set how the rent of the treasury's entries is bumped. Each call bumps the
+    /// entries it touches whose TTL has fallen under `threshold` back up to `bump`. Treasuries
+    /// initialized before the config was kept default to ~10 days and ~14 days.
+    ///
+    /// ### Arguments
+    /// * `threshold` - The TTL, in ledgers, under which an entry is bumped
+    /// * `bump` - The TTL, in ledgers, an entry is bumped to
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the threshold is not under the bump, or the bump is over the most the network allows
+    fn set_ttl_config(e: Env, threshold: u32, bump: u32);
+
+    /// Get how the rent of the treasury's entries is bumped
+    fn get_ttl_config(e: Env) -> TtlConfig;
+
+    /// Bump the rent of the treasury's instance, and of the pool and supply of its token, up to
+    /// the full bump whatever their TTL. Anyone can call this, so the treasury stays live between
+    /// calls that skip bumping.
+    fn extend_ttl(e: Env);
 }
 
 #[contractimpl]
 impl Treasury for TreasuryContract {
 
-    fn initialize(e: Env, admin: Address, token: Address, blend_pool: Address) {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        token: Address,
+        blend_pool: Address,
+        ttl_threshold: u32,
+        ttl_bump: u32,
+    ) {
+        let ttl_config = TtlConfig {
+            threshold: ttl_threshold,
+            bump: ttl_bump,
+        };
+        require_valid_ttl_config(&e, &ttl_config);
+        storage::set_ttl_config(&e, &ttl_config);
         storage::extend_instance(&e);
 
-        storage::set_admin(&e, &admin);
-        storage::set_blend(&e, &blend_pool);
+        orbit_access::set_role(&e, Role::Admin, &admin);
         storage::set_token(&e, &token);
-        storage::set_token_supply(&e, &0);
+        storage::set_blend(&e, &token, &blend_pool);
+        storage::set_token_supply(&e, &token, &0);
+        storage::set_version(&e, STORAGE_VERSION);
     }
 
     fn set_admin(e: Env, new_admin: Address) {
         storage::extend_instance(&e);
-        let admin = storage::get_admin(&e);
-        admin.require_auth();
+        orbit_access::require_role(&e, Role::Admin);
         new_admin.require_auth();
 
-        storage::set_admin(&e, &new_admin);
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
         //e.events().publish(Symbol::new(e, "set_admin"), admin, new_admin);
     }
 
+    fn set_rate_limiter(e: Env, rate_limiter: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        storage::set_rate_limiter(&e, &rate_limiter);
+    }
+
+    fn set_flash_minter(e: Env, flash_minter: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        orbit_access::set_role(&e, Role::FlashMinter, &flash_minter);
+    }
+
+    fn set_shutdown_module(e: Env, shutdown_module: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        orbit_access::set_role(&e, Role::ShutdownModule, &shutdown_module);
+    }
+
+    fn shutdown(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::ShutdownModule);
+        storage::set_is_shutdown(&e);
+
+        // withdraw what the pool can pay out, as borrowers still hold the rest
+        let token = storage::get_token(&e);
+        let blend = storage::get_blend(&e, &token);
+        let supply = storage::get_token_supply(&e, &token);
+        let position = PoolClient::new(&e, &blend)
+            .get_positions(&e.current_contract_address())
+            .supply
+            .get(0)
+            .unwrap_or(0);
+        let liquidity = TokenClient::new(&e, &token).balance(&blend);
+        let amount = supply.min(position).min(liquidity);
+        if amount > 0 {
+            withdraw_supply(&e, &token, &blend, amount);
+        }
+        amount
+    }
+
+    fn is_shutdown(e: Env) -> bool {
+        storage::extend_instance(&e);
+        storage::get_is_shutdown(&e)
+    }
+
+    fn flash_mint(e: Env, amount: i128) {
+        let flash_minter = orbit_access::require_role(&e, Role::FlashMinter);
+        require_not_shutdown(&e);
+
+        let token = storage::get_token(&e);
+        StellarAssetClient::new(&e, &token).mint(&flash_minter, &amount);
+    }
+
     fn increase_supply(e: Env, amount: i128) {
         storage::extend_instance(&e);
-        let admin = storage::get_admin(&e);
-        admin.require_auth();
+        orbit_access::require_role(&e, Role::Admin);
+        require_not_shutdown(&e);
 
         let token = storage::get_token(&e);
-        let blend = storage::get_blend(&e);
+        let blend = storage::get_blend(&e, &token);
+        if let Some(rate_limiter) = storage::get_rate_limiter(&e) {
+            RateLimiterClient::new(&e, &rate_limiter).consume(
+                &e.current_contract_address(),
+                &token,
+                &amount,
+            );
+        }
         StellarAssetClient::new(&e, &token).mint(&e.current_contract_address(), &amount);
         let args: Vec<Val> = vec![
             &e,
@@ -102,34 +340,30 @@ impl Treasury for TreasuryContract {
                 sub_invocations: vec![&e],
             })
         ]);
-        PoolClient::new(&e, &blend).submit(&e.current_contract_address(), &e.current_contract_address(), &e.current_contract_address(), &vec![
-            &e,
-            Request {
-                request_type: 0_u32, // SUPPLY RequestType
-                address: token.clone(),
-                amount,
-            },
-        ]);
+        submit(&e, &blend, Request {
+            request_type: RequestType::Supply.into_u32(),
+            address: token.clone(),
+            amount,
+        });
 
-        let supply = storage::get_token_supply(&e);
+        let supply = storage::get_token_supply(&e, &token);
         let new_supply = supply + amount;
-        storage::set_token_supply(&e, &new_supply);
+        storage::set_token_supply(&e, &token, &new_supply);
 
         //e.events().publish(Symbol::new(&e, "increase_supply"), admin);
     }
 
     fn decrease_supply(e: Env, amount: i128) {
         storage::extend_instance(&e);
-        let admin = storage::get_admin(&e);
-        admin.require_auth();
+        orbit_access::require_role(&e, Role::Admin);
 
-        let supply = storage::get_token_supply(&e);
+        let token = storage::get_token(&e);
+        let supply = storage::get_token_supply(&e, &token);
         if supply < amount {
             panic_with_error!(&e, TreasuryError::SupplyError);
         }
 
-        let token = storage::get_token(&e);
-        let blend = storage::get_blend(&e);
+        let blend = storage::get_blend(&e, &token);
         let pool_client = PoolClient::new(&e, &blend);
         
         let position = pool_client.get_positions(&e.current_contract_address()).supply;
@@ -137,35 +371,238 @@ impl Treasury for TreasuryContract {
         if position_amount < amount {
             panic_with_error!(&e, TreasuryError::SupplyError);
         }
+        // near full utilization the pool can't pay out more than it holds
+        let liquidity = TokenClient::new(&e, &token).balance(&blend);
+        if liquidity < amount {
+            panic_with_error!(&e, TreasuryError::InsufficientLiquidity);
+        }
+
+        withdraw_supply(&e, &token, &blend, amount);
+
+        //e.events().publish(Symbol::new(&e, "decrease_supply"), admin);
+    }
+
+    fn get_token_address(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_token(&e)
+    }
+
+    fn get_blend_address(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_blend(&e, &storage::get_token(&e))
+    }
+
+    fn get_token_supply(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_token_supply(&e, &storage::get_token(&e))
+    }
 
-        pool_client.submit(&e.current_contract_address(), &e.current_contract_address(), &e.current_contract_address(), &vec![
+    fn set_adapter_cap(e: Env, adapter: Address, cap: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let mut data = storage::get_adapter(&e, &adapter);
+        data.cap = cap;
+        storage::set_adapter(&e, &adapter, &data);
+    }
+
+    fn get_adapter(e: Env, adapter: Address) -> AdapterData {
+        storage::extend_instance(&e);
+        storage::get_adapter(&e, &adapter)
+    }
+
+    fn deposit_adapter(e: Env, adapter: Address, amount: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if amount <= 0 {
+            panic_with_error!(&e, TreasuryError::NegativeAmountError);
+        }
+
+        let mut data = storage::get_adapter(&e, &adapter);
+        if data.deployed + amount > data.cap {
+            panic_with_error!(&e, TreasuryError::AdapterCapExceeded);
+        }
+        require_not_shutdown(&e);
+        let token = storage::get_token(&e);
+        if let Some(rate_limiter) = storage::get_rate_limiter(&e) {
+            RateLimiterClient::new(&e, &rate_limiter).consume(
+                &e.current_contract_address(),
+                &token,
+                &amount,
+            );
+        }
+        StellarAssetClient::new(&e, &token).mint(&e.current_contract_address(), &amount);
+        // the adapter pulls the tokens from the treasury
+        e.authorize_as_current_contract(vec![
             &e,
-            Request {
-                request_type: 1_u32, // WITHDRAW RequestType
-                address: token.clone(),
-                amount,
-            },
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: vec![
+                        &e,
+                        e.current_contract_address().into_val(&e),
+                        adapter.into_val(&e),
+                        amount.into_val(&e),
+                    ],
+                },
+                sub_invocations: vec![&e],
+            })
         ]);
+        YieldAdapterClient::new(&e, &adapter).deposit(&e.current_contract_address(), &amount);
+
+        data.deployed += amount;
+        storage::set_adapter(&e, &adapter, &data);
+    }
+
+    fn withdraw_adapter(e: Env, adapter: Address, amount: i128) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        if amount <= 0 {
+            panic_with_error!(&e, TreasuryError::NegativeAmountError);
+        }
+
+        let mut data = storage::get_adapter(&e, &adapter);
+        if data.deployed < amount {
+            panic_with_error!(&e, TreasuryError::SupplyError);
+        }
+        YieldAdapterClient::new(&e, &adapter).withdraw(&e.current_contract_address(), &amount);
+        let token = storage::get_token(&e);
         let burn_args: Vec<Val> = vec![
             &e,
             e.current_contract_address().into_val(&e),
             amount.into_val(&e),
         ];
         e.invoke_contract::<Val>(&token, &Symbol::new(&e, "burn"), burn_args);
-        let supply = storage::get_token_supply(&e);
-        let new_supply = supply - amount;
-        storage::set_token_supply(&e, &new_supply);
 
-        //e.events().publish(Symbol::new(&e, "decrease_supply"), admin);
+        data.deployed -= amount;
+        storage::set_adapter(&e, &adapter, &data);
     }
 
-    fn get_token_address(e: Env) -> Address {
+    fn migrate(e: Env) {
         storage::extend_instance(&e);
-        storage::get_token(&e)
+        orbit_access::require_role(&e, Role::Admin);
+
+        let version = storage::get_version(&e);
+        if version > STORAGE_VERSION {
+            panic_with_error!(&e, TreasuryError::InvalidStorageVersion);
+        }
+        if version == STORAGE_VERSION {
+            return;
+        }
+
+        // version 0 kept the blend pool and the supply of its only token in the instance
+        let token = storage::get_token(&e);
+        if let Some(blend) = storage::take_legacy_blend(&e) {
+            storage::set_blend(&e, &token, &blend);
+        }
+        if let Some(supply) = storage::take_legacy_token_supply(&e) {
+            storage::set_token_supply(&e, &token, &supply);
+        }
+        storage::set_version(&e, STORAGE_VERSION);
+
+        e.events().publish((Symbol::new(&e, topics::MIGRATE),), (version, STORAGE_VERSION));
     }
 
-    fn get_blend_address(e: Env) -> Address {
+    fn version(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        storage::get_version(&e)
+    }
+
+    fn set_ttl_config(e: Env, threshold: u32, bump: u32) {
         storage::extend_instance(&e);
-        storage::get_blend(&e)
+        orbit_access::require_role(&e, Role::Admin);
+
+        let ttl_config = TtlConfig { threshold, bump };
+        require_valid_ttl_config(&e, &ttl_config);
+        storage::set_ttl_config(&e, &ttl_config);
+    }
+
+    fn get_ttl_config(e: Env) -> TtlConfig {
+        storage::get_ttl_config(&e)
+    }
+
+    fn extend_ttl(e: Env) {
+        storage::extend_all(&e, &storage::get_token(&e));
+    }
+}
+
+/// Require that a TTL config bumps entries only once they fall under the threshold, and
+/// within the most the network allows. A threshold equal to the bump would bump every entry on
+/// every call, as `extend_ttl` does on purpose.
+///
+/// ### Panics
+/// If the threshold is not under the bump, or the bump is over the most the network allows
+fn require_valid_ttl_config(e: &Env, config: &TtlConfig) {
+    if config.threshold >= config.bump || config.bump > e.storage().max_ttl() {
+        panic_with_error!(e, TreasuryError::InvalidTtlConfig);
+    }
+}
+
+/// Require that the treasury was not shut down
+///
+/// ### Panics
+/// If the treasury was shut down
+fn require_not_shutdown(e: &Env) {
+    if storage::get_is_shutdown(e) {
+        panic_with_error!(e, TreasuryError::TreasuryShutdown);
+    }
+}
+
+/// Withdraw part of the treasury's supply from the blend pool and burn it
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+/// * `blend` - The Address of the blend pool
+/// * `amount` - The amount to withdraw, at most the supply
+///
+/// ### Panics
+/// If the pool reverts
+fn withdraw_supply(e: &Env, token: &Address, blend: &Address, amount: i128) {
+    submit(e, blend, Request {
+        request_type: RequestType::Withdraw.into_u32(),
+        address: token.clone(),
+        amount,
+    });
+    let burn_args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e),
+        amount.into_val(e),
+    ];
+    e.invoke_contract::<Val>(token, &Symbol::new(e, "burn"), burn_args);
+    let supply = storage::get_token_supply(e, token);
+    storage::set_token_supply(e, token, &(supply - amount));
+}
+
+/// Submit a request for the treasury to the blend pool
+///
+/// ### Panics
+/// If the pool reverts, with the error `pool_error` maps its error to
+fn submit(e: &Env, blend: &Address, request: Request) {
+    let this = e.current_contract_address();
+    let result = PoolClient::new(e, blend).try_submit(&this, &this, &this, &vec![e, request]);
+    match result {
+        Ok(_) => (),
+        Err(Ok(error)) => panic_with_error!(e, pool_error(error)),
+        Err(Err(_)) => panic_with_error!(e, TreasuryError::PoolSubmitFailed),
+    }
+}
+
+/// Map the error the blend pool reverted with to the error the treasury fails with. A failed
+/// call's events are rolled back with it, so the error is the only way the pool's reason reaches
+/// the caller. The common errors and the pool errors the treasury can cause are known, and
+/// anything else, including host errors, is reported as `PoolSubmitFailed`.
+fn pool_error(error: Error) -> TreasuryError {
+    if !error.is_type(ScErrorType::Contract) {
+        return TreasuryError::PoolSubmitFailed;
+    }
+    match error.get_code() {
+        code if code == PoolError::NegativeAmountError as u32 => TreasuryError::NegativeAmountError,
+        code if code == PoolError::BalanceError as u32 => TreasuryError::BalanceError,
+        code if code == PoolError::OverflowError as u32 => TreasuryError::OverflowError,
+        code if code == PoolError::InvalidPoolStatus as u32 => TreasuryError::PoolFrozen,
+        code if code == PoolError::InvalidUtilRate as u32 => TreasuryError::InsufficientLiquidity,
+        code if code == PoolError::StalePrice as u32 => TreasuryError::PoolStalePrice,
+        _ => TreasuryError::PoolSubmitFailed,
     }
 }
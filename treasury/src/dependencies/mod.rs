@@ -1 +1,3 @@
-pub mod pool;
\ No newline at end of file
+pub use orbit_common::pool;
+pub mod rate_limiter;
+pub mod yield_adapter;
@@ -1,3 +0,0 @@
-use soroban_sdk::contractimport;
-
-contractimport!(file = "../wasm/pool.wasm");
\ No newline at end of file
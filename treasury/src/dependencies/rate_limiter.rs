@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the rate limiter the treasury consults before minting
+#[allow(dead_code)]
+#[contractclient(name = "RateLimiterClient")]
+pub trait RateLimiter {
+    fn consume(e: Env, minter: Address, asset: Address, amount: i128);
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The interface of the yield adapters the treasury deploys reserves through
+#[allow(dead_code)]
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    fn deposit(e: Env, from: Address, amount: i128);
+
+    fn withdraw(e: Env, to: Address, amount: i128);
+
+    fn total_assets(e: Env) -> i128;
+}
@@ -15,5 +15,13 @@ pub enum TreasuryError {
     OverflowError = 12,
     
     SupplyError = 2000,
+    AdapterCapExceeded = 2001,
+    InsufficientLiquidity = 2002,
+    InvalidStorageVersion = 2003,
+    PoolSubmitFailed = 2004,
+    InvalidTtlConfig = 2005,
+    PoolFrozen = 2006,
+    PoolStalePrice = 2007,
+    TreasuryShutdown = 2008,
 
 }
\ No newline at end of file
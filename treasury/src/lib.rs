@@ -7,3 +7,5 @@ mod dependencies;
 mod errors;
 
 pub use contract::*;
+pub use errors::TreasuryError;
+pub use storage::{AdapterData, TtlConfig};
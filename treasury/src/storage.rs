@@ -1,42 +1,108 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val};
 use soroban_sdk::unwrap::UnwrapOptimized;
 
 pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
 pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
 
-const ADMIN_KEY: &str = "Admin";
 const BLEND_KEY: &str = "Blend";
 const TOKEN_KEY: &str = "Token";
 const TOKEN_SUPPLY_KEY: &str = "TokenSupply";
+const RATE_LIMITER_KEY: &str = "RateLimiter";
+const VERSION_KEY: &str = "Version";
+const TTL_CONFIG_KEY: &str = "TtlConfig";
+const IS_SHUTDOWN_KEY: &str = "IsShutdown";
+
+/// How the rent of the treasury's entries is bumped, in ledgers
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TtlConfig {
+    pub threshold: u32, // the TTL under which an entry is bumped
+    pub bump: u32,      // the TTL an entry is bumped to
+}
+
+/// The reserves a yield adapter can hold and holds
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[contracttype]
+pub struct AdapterData {
+    pub cap: i128,      // the most the treasury can deploy through the adapter
+    pub deployed: i128, // the tokens the treasury has deployed through the adapter
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum TreasuryDataKey {
+    // The reserves of a yield adapter
+    Adapter(Address),
+    // The blend pool a token is supplied to
+    Blend(Address),
+    // The supply of a token minted into its blend pool
+    TokenSupply(Address),
+}
 
 /// Bump the instance rent for the contract
 pub fn extend_instance(e: &Env) {
+    let config = get_ttl_config(e);
+    e.storage().instance().extend_ttl(config.threshold, config.bump);
+}
+
+/// Bump the rent of the instance and of the entries of a token up to the full bump, whatever
+/// their TTL
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+pub fn extend_all(e: &Env, token: &Address) {
+    let bump = get_ttl_config(e).bump;
+    e.storage().instance().extend_ttl(bump, bump);
+    for key in [
+        TreasuryDataKey::Blend(token.clone()),
+        TreasuryDataKey::TokenSupply(token.clone()),
+    ] {
+        e.storage().persistent().extend_ttl(&key, bump, bump);
+    }
+}
+
+/********** TTL Config **********/
+
+/// Fetch how the rent of the treasury's entries is bumped, ~10 days and ~14 days by default
+pub fn get_ttl_config(e: &Env) -> TtlConfig {
     e.storage()
         .instance()
-        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        .get(&Symbol::new(e, TTL_CONFIG_KEY))
+        .unwrap_or(TtlConfig {
+            threshold: LEDGER_THRESHOLD_SHARED,
+            bump: LEDGER_BUMP_SHARED,
+        })
 }
 
-/********** Admin **********/
-
-// Fetch the current admin Address
+/// Set how the rent of the treasury's entries is bumped
 ///
-/// ### Panics
-/// If the admin does not exist
-pub fn get_admin(e: &Env) -> Address {
+/// ### Arguments
+/// * `config` - The threshold and bump
+pub fn set_ttl_config(e: &Env, config: &TtlConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, TtlConfig>(&Symbol::new(e, TTL_CONFIG_KEY), config);
+}
+
+/********** Version **********/
+
+/// Fetch the version of the storage layout, or 0 if the treasury was initialized before the
+/// version was tracked
+pub fn get_version(e: &Env) -> u32 {
     e.storage()
         .instance()
-        .get(&Symbol::new(e, ADMIN_KEY))
-        .unwrap()
+        .get(&Symbol::new(e, VERSION_KEY))
+        .unwrap_or(0)
 }
 
-/// Set a new admin
+/// Set the version of the storage layout
 ///
 /// ### Arguments
-/// * `new_admin` - The Address for the admin
-pub fn set_admin(e: &Env, new_admin: &Address) {
+/// * `version` - The version
+pub fn set_version(e: &Env, version: u32) {
     e.storage()
         .instance()
-        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), new_admin);
+        .set::<Symbol, u32>(&Symbol::new(e, VERSION_KEY), &version);
 }
 
 /********** Token **********/
@@ -64,46 +130,137 @@ pub fn set_token(e: &Env, token: &Address) {
 
 /********** Token Supply **********/
 
-/// Fetch the current token supply
+/// Fetch the supply of a token minted into its blend pool
+///
+/// ### Arguments
+/// * `token` - The Address of the token
 ///
 /// ### Panics
 /// If the token supply does not exist
-pub fn get_token_supply(e: &Env) -> i128 {
-    e.storage()
-        .instance()
-        .get(&Symbol::new(e, TOKEN_SUPPLY_KEY))
-        .unwrap_optimized()
+pub fn get_token_supply(e: &Env, token: &Address) -> i128 {
+    get_persistent(e, &TreasuryDataKey::TokenSupply(token.clone())).unwrap_optimized()
 }
 
-/// Set the token supply
+/// Set the supply of a token minted into its blend pool
 ///
 /// ### Arguments
+/// * `token` - The Address of the token
 /// * `supply` - The new supply
-pub fn set_token_supply(e: &Env, supply: &i128) {
-    e.storage()
-        .instance()
-        .set::<Symbol, i128>(&Symbol::new(e, TOKEN_SUPPLY_KEY), supply);
+pub fn set_token_supply(e: &Env, token: &Address, supply: &i128) {
+    set_persistent(e, &TreasuryDataKey::TokenSupply(token.clone()), supply);
+}
+
+/// Remove and return the token supply a treasury of storage version 0 kept in its instance
+pub fn take_legacy_token_supply(e: &Env) -> Option<i128> {
+    take_legacy(e, TOKEN_SUPPLY_KEY)
 }
 
 /********** Blend **********/
 
-/// Fetch the current blend Address
+/// Fetch the blend pool a token is supplied to
+///
+/// ### Arguments
+/// * `token` - The Address of the token
 ///
 /// ### Panics
-/// If the blend does not exist
-pub fn get_blend(e: &Env) -> Address {
+/// If the blend pool does not exist
+pub fn get_blend(e: &Env, token: &Address) -> Address {
+    get_persistent(e, &TreasuryDataKey::Blend(token.clone())).unwrap_optimized()
+}
+
+/// Set the blend pool a token is supplied to
+///
+/// ### Arguments
+/// * `token` - The Address of the token
+/// * `blend` - The Address for the blend pool
+pub fn set_blend(e: &Env, token: &Address, blend: &Address) {
+    set_persistent(e, &TreasuryDataKey::Blend(token.clone()), blend);
+}
+
+/// Remove and return the blend pool a treasury of storage version 0 kept in its instance
+pub fn take_legacy_blend(e: &Env) -> Option<Address> {
+    take_legacy(e, BLEND_KEY)
+}
+
+/********** Rate Limiter **********/
+
+/// Fetch the rate limiter Address, if one is set
+pub fn get_rate_limiter(e: &Env) -> Option<Address> {
     e.storage()
         .instance()
-        .get(&Symbol::new(e, BLEND_KEY))
-        .unwrap_optimized()
+        .get(&Symbol::new(e, RATE_LIMITER_KEY))
 }
 
-/// Set the blend Address
+/// Set the rate limiter Address
 ///
 /// ### Arguments
-/// * `blend` - The Address for the blend pool
-pub fn set_blend(e: &Env, blend: &Address) {
+/// * `rate_limiter` - The Address for the rate limiter
+pub fn set_rate_limiter(e: &Env, rate_limiter: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, RATE_LIMITER_KEY), rate_limiter);
+}
+
+/********** Shutdown **********/
+
+/// Check if the treasury was shut down
+pub fn get_is_shutdown(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_SHUTDOWN_KEY))
+}
+
+/// Set the treasury as shut down
+pub fn set_is_shutdown(e: &Env) {
     e.storage()
         .instance()
-        .set::<Symbol, Address>(&Symbol::new(e, BLEND_KEY), blend);
+        .set::<Symbol, bool>(&Symbol::new(e, IS_SHUTDOWN_KEY), &true);
+}
+
+/********** Yield Adapters **********/
+
+/// Fetch the reserves of a yield adapter. Unknown adapters have a cap of zero.
+///
+/// ### Arguments
+/// * `adapter` - The Address of the yield adapter
+pub fn get_adapter(e: &Env, adapter: &Address) -> AdapterData {
+    get_persistent(e, &TreasuryDataKey::Adapter(adapter.clone())).unwrap_or_default()
+}
+
+/// Set the reserves of a yield adapter
+///
+/// ### Arguments
+/// * `adapter` - The Address of the yield adapter
+/// * `data` - The reserves of the adapter
+pub fn set_adapter(e: &Env, adapter: &Address, data: &AdapterData) {
+    set_persistent(e, &TreasuryDataKey::Adapter(adapter.clone()), data);
+}
+
+/********** Persistent **********/
+
+/// Fetch a persistent entry, and bump its rent if it exists
+fn get_persistent<V: TryFromVal<Env, Val>>(e: &Env, key: &TreasuryDataKey) -> Option<V> {
+    let result = e.storage().persistent().get::<TreasuryDataKey, V>(key);
+    if result.is_some() {
+        let config = get_ttl_config(e);
+        e.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.bump);
+    }
+    result
+}
+
+/// Set a persistent entry, and bump its rent
+fn set_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &TreasuryDataKey, value: &V) {
+    e.storage().persistent().set::<TreasuryDataKey, V>(key, value);
+    let config = get_ttl_config(e);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.bump);
+}
+
+/// Remove and return an entry a treasury of storage version 0 kept in its instance
+fn take_legacy<V: TryFromVal<Env, Val>>(e: &Env, key: &str) -> Option<V> {
+    let key = Symbol::new(e, key);
+    let result = e.storage().instance().get(&key);
+    e.storage().instance().remove(&key);
+    result
 }
@@ -0,0 +1,161 @@
+use crate::dependencies::vault::VaultClient;
+use crate::errors::VaultAdapterError;
+use crate::events::VaultAdapterEvents;
+use crate::storage;
+use sep_41_token::TokenClient;
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, IntoVal, Symbol,
+};
+
+#[contract]
+pub struct VaultAdapterContract;
+
+/// The yield adapter interface the treasury deploys reserves through
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    /// (Owner only) Deposit tokens into the yield source
+    ///
+    /// ### Arguments
+    /// * `from` - The Address depositing, which must be the owner
+    /// * `amount` - The amount to deposit
+    ///
+    /// ### Panics
+    /// If `from` is not the owner
+    fn deposit(e: Env, from: Address, amount: i128);
+
+    /// (Owner only) Withdraw tokens from the yield source
+    ///
+    /// ### Arguments
+    /// * `to` - The Address receiving the tokens, which must be the owner
+    /// * `amount` - The amount to withdraw
+    ///
+    /// ### Panics
+    /// If `to` is not the owner
+    /// If the yield source holds less than `amount` for the adapter
+    fn withdraw(e: Env, to: Address, amount: i128);
+
+    /// Get the tokens the adapter's position in the yield source is worth now
+    fn total_assets(e: Env) -> i128;
+}
+
+#[contractclient(name = "VaultAdapterClient")]
+pub trait VaultAdapter {
+    /// Initialize the vault adapter
+    ///
+    /// ### Arguments
+    /// * `owner` - The Address for the owner, usually a treasury
+    /// * `token` - The Address of the token deposited
+    /// * `vault` - The Address of the vault, which takes `deposit(from, amount)` and
+    ///   `withdraw(from, amount)` and values positions with `get_balance(user)`
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, owner: Address, token: Address, vault: Address);
+
+    /// Get the owner
+    fn get_owner(e: Env) -> Address;
+
+    /// Get the vault
+    fn get_vault(e: Env) -> Address;
+}
+
+#[contractimpl]
+impl VaultAdapter for VaultAdapterContract {
+    fn initialize(e: Env, owner: Address, token: Address, vault: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, VaultAdapterError::AlreadyInitializedError);
+        }
+
+        storage::set_owner(&e, &owner);
+        storage::set_token(&e, &token);
+        storage::set_vault(&e, &vault);
+        storage::set_is_init(&e);
+    }
+
+    fn get_owner(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_owner(&e)
+    }
+
+    fn get_vault(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_vault(&e)
+    }
+}
+
+#[contractimpl]
+impl YieldAdapter for VaultAdapterContract {
+    fn deposit(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        require_owner(&e, &from);
+        require_positive(&e, amount);
+
+        let token = storage::get_token(&e);
+        let vault = storage::get_vault(&e);
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        // the vault pulls the tokens from the adapter
+        e.authorize_as_current_contract(vec![
+            &e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: vec![
+                        &e,
+                        e.current_contract_address().into_val(&e),
+                        vault.into_val(&e),
+                        amount.into_val(&e),
+                    ],
+                },
+                sub_invocations: vec![&e],
+            }),
+        ]);
+        VaultClient::new(&e, &vault).deposit(&e.current_contract_address(), &amount);
+
+        VaultAdapterEvents::deposit(&e, from, amount);
+    }
+
+    fn withdraw(e: Env, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        require_owner(&e, &to);
+        require_positive(&e, amount);
+
+        let vault_client = VaultClient::new(&e, &storage::get_vault(&e));
+        vault_client.withdraw(&e.current_contract_address(), &amount);
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &e.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        VaultAdapterEvents::withdraw(&e, to, amount);
+    }
+
+    fn total_assets(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        VaultClient::new(&e, &storage::get_vault(&e)).get_balance(&e.current_contract_address())
+    }
+}
+
+/// Require that an address is the owner and authorized the call
+///
+/// ### Panics
+/// If the address is not the owner
+fn require_owner(e: &Env, user: &Address) {
+    if *user != storage::get_owner(e) {
+        panic_with_error!(e, VaultAdapterError::UnauthorizedError);
+    }
+    user.require_auth();
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, VaultAdapterError::NegativeAmountError);
+    }
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The interface of the share-based vaults the adapter deposits into, like the savings contract
+#[allow(dead_code)]
+#[contractclient(name = "VaultClient")]
+pub trait Vault {
+    fn deposit(e: Env, from: Address, amount: i128) -> i128;
+
+    fn withdraw(e: Env, from: Address, amount: i128) -> i128;
+
+    fn get_balance(e: Env, user: Address) -> i128;
+}
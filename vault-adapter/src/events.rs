@@ -0,0 +1,24 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct VaultAdapterEvents {}
+
+impl VaultAdapterEvents {
+    /// Emitted when the owner deposits into the vault
+    ///
+    /// - topics - `["deposit", from: Address]`
+    /// - data - `amount: i128`
+    pub fn deposit(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::DEPOSIT), from);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when the owner withdraws from the vault
+    ///
+    /// - topics - `["withdraw", to: Address]`
+    /// - data - `amount: i128`
+    pub fn withdraw(e: &Env, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::WITHDRAW), to);
+        e.events().publish(topics, amount);
+    }
+}
@@ -0,0 +1,95 @@
+use soroban_sdk::{unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const OWNER_KEY: &str = "Owner";
+const TOKEN_KEY: &str = "Token";
+const VAULT_KEY: &str = "Vault";
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Owner **********/
+
+/// Fetch the Address of the owner, who deposits and withdraws
+///
+/// ### Panics
+/// If the owner does not exist
+pub fn get_owner(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, OWNER_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the owner, who deposits and withdraws
+///
+/// ### Arguments
+/// * `owner` - The Address for the owner
+pub fn set_owner(e: &Env, owner: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, OWNER_KEY), owner);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the token deposited
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token deposited
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/// Fetch the Address of the vault
+///
+/// ### Panics
+/// If the vault does not exist
+pub fn get_vault(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, VAULT_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the vault
+///
+/// ### Arguments
+/// * `vault` - The Address for the vault
+pub fn set_vault(e: &Env, vault: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, VAULT_KEY), vault);
+}
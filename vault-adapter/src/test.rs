@@ -0,0 +1,72 @@
+#![cfg(test)]
+use crate::contract::{VaultAdapterClient, VaultAdapterContract, YieldAdapterClient};
+use crate::errors::VaultAdapterError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+/// Holds deposits and pays a fixed amount of yield, like the savings contract
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn set_token(e: Env, token: Address) {
+        e.storage().instance().set(&Symbol::new(&e, "token"), &token);
+    }
+
+    pub fn add_yield(e: Env, user: Address, amount: i128) {
+        let balance = Self::get_balance(e.clone(), user.clone());
+        e.storage().instance().set(&user, &(balance + amount));
+    }
+
+    pub fn deposit(e: Env, from: Address, amount: i128) -> i128 {
+        let token: Address = e.storage().instance().get(&Symbol::new(&e, "token")).unwrap();
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        Self::add_yield(e, from, amount);
+        amount
+    }
+
+    pub fn withdraw(e: Env, from: Address, amount: i128) -> i128 {
+        let token: Address = e.storage().instance().get(&Symbol::new(&e, "token")).unwrap();
+        let balance = Self::get_balance(e.clone(), from.clone());
+        assert!(balance >= amount);
+        e.storage().instance().set(&from, &(balance - amount));
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &from, &amount);
+        amount
+    }
+
+    pub fn get_balance(e: Env, user: Address) -> i128 {
+        e.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_deposit_and_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let token = e.register_stellar_asset_contract(Address::generate(&e));
+    let token_client = TokenClient::new(&e, &token);
+    let vault = e.register_contract(None, MockVault);
+    let vault_client = MockVaultClient::new(&e, &vault);
+    vault_client.set_token(&token);
+    let owner = Address::generate(&e);
+    StellarAssetClient::new(&e, &token).mint(&owner, &1000_0000000);
+    StellarAssetClient::new(&e, &token).mint(&vault, &10_0000000);
+
+    let adapter_id = e.register_contract(None, VaultAdapterContract);
+    VaultAdapterClient::new(&e, &adapter_id).initialize(&owner, &token, &vault);
+    let adapter_client = YieldAdapterClient::new(&e, &adapter_id);
+
+    let result = adapter_client.try_deposit(&Address::generate(&e), &1000_0000000);
+    assert_eq!(result.err(), Some(Ok(VaultAdapterError::UnauthorizedError.into())));
+    adapter_client.deposit(&owner, &1000_0000000);
+    assert_eq!(adapter_client.total_assets(), 1000_0000000);
+    assert_eq!(token_client.balance(&vault), 1010_0000000);
+
+    vault_client.add_yield(&adapter_id, &10_0000000);
+    assert_eq!(adapter_client.total_assets(), 1010_0000000);
+    adapter_client.withdraw(&owner, &1010_0000000);
+    assert_eq!(token_client.balance(&owner), 1010_0000000);
+    assert_eq!(adapter_client.total_assets(), 0);
+}
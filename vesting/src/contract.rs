@@ -0,0 +1,242 @@
+use crate::errors::VestingError;
+use crate::events::VestingEvents;
+use crate::storage::{self, VestingSchedule};
+use orbit_access::Role;
+use sep_41_token::TokenClient;
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env};
+
+#[contract]
+pub struct VestingContract;
+
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    /// Initialize the vesting contract
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin, usually the DAO
+    /// * `token` - The Address of the protocol token that vests
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, admin: Address, token: Address);
+
+    /// (Admin only) Set a new admin
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The new admin address
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    fn set_admin(e: Env, new_admin: Address);
+
+    /// (Admin only) Create a vesting schedule for a beneficiary, funded by the admin
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The Address the tokens vest to
+    /// * `amount` - The total tokens vesting
+    /// * `start` - The time vesting starts
+    /// * `cliff` - The time after `start` before anything vests, in seconds
+    /// * `duration` - The time after `start` when everything is vested, in seconds
+    /// * `revocable` - If the admin can revoke the unvested tokens
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the beneficiary already has a schedule
+    /// If the duration is zero or the cliff is longer than the duration
+    fn create_schedule(
+        e: Env,
+        beneficiary: Address,
+        amount: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    );
+
+    /// Get the vesting schedule of a beneficiary
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The Address of the beneficiary
+    ///
+    /// ### Panics
+    /// If the beneficiary has no schedule
+    fn get_schedule(e: Env, beneficiary: Address) -> VestingSchedule;
+
+    /// Get the vested tokens of a beneficiary that have not been claimed
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The Address of the beneficiary
+    ///
+    /// ### Panics
+    /// If the beneficiary has no schedule
+    fn get_claimable(e: Env, beneficiary: Address) -> i128;
+
+    /// Claim vested tokens
+    ///
+    /// Returns the amount of tokens claimed
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The Address of the beneficiary
+    ///
+    /// ### Panics
+    /// If the beneficiary has no schedule
+    /// If no tokens are claimable
+    fn claim(e: Env, beneficiary: Address) -> i128;
+
+    /// (Admin only) Revoke the unvested tokens of a revocable schedule. Tokens vested by now
+    /// stay claimable by the beneficiary.
+    ///
+    /// Returns the amount of unvested tokens sent to `to`
+    ///
+    /// ### Arguments
+    /// * `beneficiary` - The Address of the beneficiary
+    /// * `to` - The Address receiving the unvested tokens
+    ///
+    /// ### Panics
+    /// If the caller is not the admin
+    /// If the schedule is not revocable or already revoked
+    fn revoke(e: Env, beneficiary: Address, to: Address) -> i128;
+}
+
+#[contractimpl]
+impl Vesting for VestingContract {
+    fn initialize(e: Env, admin: Address, token: Address) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, VestingError::AlreadyInitializedError);
+        }
+
+        orbit_access::set_role(&e, Role::Admin, &admin);
+        storage::set_token(&e, &token);
+        storage::set_is_init(&e);
+    }
+
+    fn set_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+        new_admin.require_auth();
+
+        orbit_access::set_role(&e, Role::Admin, &new_admin);
+    }
+
+    fn create_schedule(
+        e: Env,
+        beneficiary: Address,
+        amount: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    ) {
+        storage::extend_instance(&e);
+        let admin = orbit_access::require_role(&e, Role::Admin);
+        if amount <= 0 {
+            panic_with_error!(&e, VestingError::NegativeAmountError);
+        }
+        if duration == 0 || cliff > duration {
+            panic_with_error!(&e, VestingError::InvalidSchedule);
+        }
+        if storage::get_schedule(&e, &beneficiary).is_some() {
+            panic_with_error!(&e, VestingError::ScheduleExists);
+        }
+
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &admin,
+            &e.current_contract_address(),
+            &amount,
+        );
+        let schedule = VestingSchedule {
+            amount,
+            claimed: 0,
+            start,
+            cliff,
+            duration,
+            revocable,
+            revoked: false,
+        };
+        storage::set_schedule(&e, &beneficiary, &schedule);
+
+        VestingEvents::create_schedule(&e, beneficiary, schedule);
+    }
+
+    fn get_schedule(e: Env, beneficiary: Address) -> VestingSchedule {
+        storage::extend_instance(&e);
+        load_schedule(&e, &beneficiary)
+    }
+
+    fn get_claimable(e: Env, beneficiary: Address) -> i128 {
+        storage::extend_instance(&e);
+        let schedule = load_schedule(&e, &beneficiary);
+        vested_amount(&e, &schedule) - schedule.claimed
+    }
+
+    fn claim(e: Env, beneficiary: Address) -> i128 {
+        storage::extend_instance(&e);
+        beneficiary.require_auth();
+
+        let mut schedule = load_schedule(&e, &beneficiary);
+        let amount = vested_amount(&e, &schedule) - schedule.claimed;
+        if amount <= 0 {
+            panic_with_error!(&e, VestingError::NothingToClaim);
+        }
+        schedule.claimed += amount;
+        storage::set_schedule(&e, &beneficiary, &schedule);
+        TokenClient::new(&e, &storage::get_token(&e)).transfer(
+            &e.current_contract_address(),
+            &beneficiary,
+            &amount,
+        );
+
+        VestingEvents::claim(&e, beneficiary, amount);
+        amount
+    }
+
+    fn revoke(e: Env, beneficiary: Address, to: Address) -> i128 {
+        storage::extend_instance(&e);
+        orbit_access::require_role(&e, Role::Admin);
+
+        let mut schedule = load_schedule(&e, &beneficiary);
+        if !schedule.revocable || schedule.revoked {
+            panic_with_error!(&e, VestingError::NotRevocable);
+        }
+        let vested = vested_amount(&e, &schedule);
+        let unvested = schedule.amount - vested;
+        schedule.amount = vested;
+        schedule.revoked = true;
+        storage::set_schedule(&e, &beneficiary, &schedule);
+        if unvested > 0 {
+            TokenClient::new(&e, &storage::get_token(&e)).transfer(
+                &e.current_contract_address(),
+                &to,
+                &unvested,
+            );
+        }
+
+        VestingEvents::revoke(&e, beneficiary, to, unvested);
+        unvested
+    }
+}
+
+/// Load the vesting schedule of a beneficiary
+///
+/// ### Panics
+/// If the beneficiary has no schedule
+fn load_schedule(e: &Env, beneficiary: &Address) -> VestingSchedule {
+    storage::get_schedule(e, beneficiary)
+        .unwrap_or_else(|| panic_with_error!(e, VestingError::ScheduleNotFound))
+}
+
+/// Calculate the tokens of a schedule vested by now
+fn vested_amount(e: &Env, schedule: &VestingSchedule) -> i128 {
+    if schedule.revoked {
+        return schedule.amount;
+    }
+    let now = e.ledger().timestamp();
+    if now < schedule.start + schedule.cliff {
+        0
+    } else if now >= schedule.start + schedule.duration {
+        schedule.amount
+    } else {
+        schedule.amount * (now - schedule.start) as i128 / schedule.duration as i128
+    }
+}
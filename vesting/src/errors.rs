@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the vesting contract. Common errors are codes that match up with the built-in
+/// contracts error reporting. Vesting specific errors start at 2900.
+pub enum VestingError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    UnauthorizedError = 4,
+    NegativeAmountError = 8,
+
+    // Vesting
+    ScheduleExists = 2900,
+    ScheduleNotFound = 2901,
+    NotRevocable = 2902,
+    InvalidSchedule = 2903,
+    NothingToClaim = 2904,
+}
@@ -0,0 +1,35 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::VestingSchedule;
+
+pub struct VestingEvents {}
+
+impl VestingEvents {
+    /// Emitted when a vesting schedule is created
+    ///
+    /// - topics - `["create_schedule", beneficiary: Address]`
+    /// - data - `schedule: VestingSchedule`
+    pub fn create_schedule(e: &Env, beneficiary: Address, schedule: VestingSchedule) {
+        let topics = (Symbol::new(e, topics::CREATE_SCHEDULE), beneficiary);
+        e.events().publish(topics, schedule);
+    }
+
+    /// Emitted when a beneficiary claims vested tokens
+    ///
+    /// - topics - `["claim", beneficiary: Address]`
+    /// - data - `amount: i128`
+    pub fn claim(e: &Env, beneficiary: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::CLAIM), beneficiary);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when the admin revokes the unvested tokens of a schedule
+    ///
+    /// - topics - `["revoke", beneficiary: Address]`
+    /// - data - `[to: Address, unvested: i128]`
+    pub fn revoke(e: &Env, beneficiary: Address, to: Address, unvested: i128) {
+        let topics = (Symbol::new(e, topics::REVOKE), beneficiary);
+        e.events().publish(topics, (to, unvested));
+    }
+}
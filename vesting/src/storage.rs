@@ -0,0 +1,109 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const TOKEN_KEY: &str = "Token";
+
+/// A vesting schedule. Nothing vests before the cliff, then tokens vest linearly from `start`
+/// until `start + duration`. A schedule with a cliff equal to its duration vests everything at
+/// the cliff.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub amount: i128,    // the total tokens vesting
+    pub claimed: i128,   // the tokens claimed so far
+    pub start: u64,      // the time vesting starts
+    pub cliff: u64,      // the time after `start` before anything vests, in seconds
+    pub duration: u64,   // the time after `start` when everything is vested, in seconds
+    pub revocable: bool, // if the admin can revoke the unvested tokens
+    pub revoked: bool,   // if the schedule was revoked, which fixes `amount` to the tokens vested at revocation
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum VestingDataKey {
+    // The vesting schedule of a beneficiary
+    Schedule(Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Token **********/
+
+/// Fetch the Address of the vesting token
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the vesting token
+///
+/// ### Arguments
+/// * `token` - The Address of the vesting token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Schedules **********/
+
+/// Fetch the vesting schedule of a beneficiary, if it exists
+///
+/// ### Arguments
+/// * `beneficiary` - The Address of the beneficiary
+pub fn get_schedule(e: &Env, beneficiary: &Address) -> Option<VestingSchedule> {
+    let key = VestingDataKey::Schedule(beneficiary.clone());
+    let result = e
+        .storage()
+        .persistent()
+        .get::<VestingDataKey, VestingSchedule>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Set the vesting schedule of a beneficiary
+///
+/// ### Arguments
+/// * `beneficiary` - The Address of the beneficiary
+/// * `schedule` - The vesting schedule
+pub fn set_schedule(e: &Env, beneficiary: &Address, schedule: &VestingSchedule) {
+    let key = VestingDataKey::Schedule(beneficiary.clone());
+    e.storage()
+        .persistent()
+        .set::<VestingDataKey, VestingSchedule>(&key, schedule);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
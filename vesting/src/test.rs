@@ -0,0 +1,86 @@
+#![cfg(test)]
+use crate::contract::{VestingClient, VestingContract};
+use crate::errors::VestingError;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+/// Create a vesting contract administered by a funded DAO treasury, returning the vesting
+/// client, the treasury and the token address
+fn create_vesting<'a>(e: &Env) -> (VestingClient<'a>, Address, Address) {
+    let token = e.register_stellar_asset_contract(Address::generate(e));
+    let treasury = Address::generate(e);
+    StellarAssetClient::new(e, &token).mint(&treasury, &10000_0000000);
+
+    let vesting_id = e.register_contract(None, VestingContract);
+    let vesting_client = VestingClient::new(e, &vesting_id);
+    vesting_client.initialize(&treasury, &token);
+    (vesting_client, treasury, token)
+}
+
+#[test]
+fn test_linear_vesting_with_cliff() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (vesting_client, treasury, token) = create_vesting(&e);
+    let samwise = Address::generate(&e);
+
+    // 1200 tokens over 12 months with a 3 month cliff
+    vesting_client.create_schedule(&samwise, &1200_0000000, &1000, &(3 * MONTH), &(12 * MONTH), &false);
+    let result = vesting_client.try_create_schedule(&samwise, &1, &1000, &0, &MONTH, &false);
+    assert_eq!(result.err(), Some(Ok(VestingError::ScheduleExists.into())));
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 3 * MONTH - 1);
+    assert_eq!(vesting_client.get_claimable(&samwise), 0);
+    let result = vesting_client.try_claim(&samwise);
+    assert_eq!(result.err(), Some(Ok(VestingError::NothingToClaim.into())));
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 3 * MONTH);
+    assert_eq!(vesting_client.claim(&samwise), 300_0000000);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 6 * MONTH);
+    assert_eq!(vesting_client.claim(&samwise), 300_0000000);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 24 * MONTH);
+    assert_eq!(vesting_client.claim(&samwise), 600_0000000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&samwise), 1200_0000000);
+    assert_eq!(vesting_client.get_schedule(&samwise).claimed, 1200_0000000);
+
+    // not revocable
+    let result = vesting_client.try_revoke(&samwise, &treasury);
+    assert_eq!(result.err(), Some(Ok(VestingError::NotRevocable.into())));
+}
+
+#[test]
+fn test_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (vesting_client, treasury, token) = create_vesting(&e);
+    let frodo = Address::generate(&e);
+    let token_client = TokenClient::new(&e, &token);
+
+    // a pure cliff schedule vests everything at once
+    let result = vesting_client.try_create_schedule(&frodo, &1000_0000000, &1000, &(7 * MONTH), &(6 * MONTH), &true);
+    assert_eq!(result.err(), Some(Ok(VestingError::InvalidSchedule.into())));
+    vesting_client.create_schedule(&frodo, &1000_0000000, &1000, &(6 * MONTH), &(6 * MONTH), &true);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 6 * MONTH - 1);
+    assert_eq!(vesting_client.get_claimable(&frodo), 0);
+    assert_eq!(vesting_client.revoke(&frodo, &treasury), 1000_0000000);
+    assert_eq!(token_client.balance(&treasury), 10000_0000000);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 6 * MONTH);
+    assert_eq!(vesting_client.get_claimable(&frodo), 0);
+
+    // tokens vested before revocation stay claimable
+    let samwise = Address::generate(&e);
+    vesting_client.create_schedule(&samwise, &1200_0000000, &1000, &0, &(12 * MONTH), &true);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 9 * MONTH);
+    assert_eq!(vesting_client.revoke(&samwise, &treasury), 300_0000000);
+    let result = vesting_client.try_revoke(&samwise, &treasury);
+    assert_eq!(result.err(), Some(Ok(VestingError::NotRevocable.into())));
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 12 * MONTH);
+    assert_eq!(vesting_client.claim(&samwise), 900_0000000);
+    assert_eq!(token_client.balance(&vesting_client.address), 0);
+}
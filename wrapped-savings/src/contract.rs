@@ -0,0 +1,295 @@
+use crate::dependencies::savings::SavingsClient;
+use crate::errors::WrappedSavingsError;
+use crate::events::WrappedSavingsEvents;
+use crate::storage::{self, Allowance};
+use sep_41_token::{Token, TokenClient};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, vec, Address, Env, IntoVal, String,
+    Symbol,
+};
+
+const SCALAR_12: i128 = 1_000_000_000_000;
+
+#[contract]
+pub struct WrappedSavingsContract;
+
+#[contractclient(name = "WrappedSavingsClient")]
+pub trait WrappedSavings {
+    /// Initialize the wrapped savings token. The contract is a SEP-41 token backed one to one by
+    /// savings shares it holds, so balances stay static while the exchange rate to the deposited
+    /// token grows with the savings rate. If the savings contract has a compliance registry, the
+    /// wrapper must be allowed by it.
+    ///
+    /// ### Arguments
+    /// * `savings` - The Address of the savings contract
+    /// * `token` - The Address of the token deposited in the savings contract
+    /// * `name` - The name of the wrapped token
+    /// * `symbol` - The symbol of the wrapped token
+    ///
+    /// ### Panics
+    /// If the contract is already initialized
+    fn initialize(e: Env, savings: Address, token: Address, name: String, symbol: String);
+
+    /// Get the Address of the savings contract
+    fn get_savings(e: Env) -> Address;
+
+    /// Get the total wrapped tokens outstanding
+    fn total_supply(e: Env) -> i128;
+
+    /// Get the tokens a wrapped token is worth now, with 12 decimals
+    fn exchange_rate(e: Env) -> i128;
+
+    /// Deposit tokens in the savings contract and wrap the shares
+    ///
+    /// Returns the wrapped tokens minted
+    ///
+    /// ### Arguments
+    /// * `from` - The Address wrapping
+    /// * `amount` - The tokens deposited
+    ///
+    /// ### Panics
+    /// If `amount` is zero or negative, or too small to mint a share
+    fn wrap(e: Env, from: Address, amount: i128) -> i128;
+
+    /// Burn wrapped tokens and withdraw what the shares are worth from the savings contract
+    ///
+    /// Returns the tokens withdrawn
+    ///
+    /// ### Arguments
+    /// * `from` - The Address unwrapping
+    /// * `wrapped` - The wrapped tokens burned
+    ///
+    /// ### Panics
+    /// If `wrapped` is zero or negative, or exceeds the wrapped tokens of `from`
+    /// If the savings contract can't pay out the withdrawal
+    fn unwrap(e: Env, from: Address, wrapped: i128) -> i128;
+}
+
+#[contractimpl]
+impl WrappedSavings for WrappedSavingsContract {
+    fn initialize(e: Env, savings: Address, token: Address, name: String, symbol: String) {
+        storage::extend_instance(&e);
+        if storage::get_is_init(&e) {
+            panic_with_error!(&e, WrappedSavingsError::AlreadyInitializedError);
+        }
+
+        storage::set_savings(&e, &savings);
+        storage::set_token(&e, &token);
+        storage::set_name(&e, &name);
+        storage::set_symbol(&e, &symbol);
+        storage::set_is_init(&e);
+    }
+
+    fn get_savings(e: Env) -> Address {
+        storage::extend_instance(&e);
+        storage::get_savings(&e)
+    }
+
+    fn total_supply(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_total_supply(&e)
+    }
+
+    fn exchange_rate(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        SavingsClient::new(&e, &storage::get_savings(&e)).get_share_price()
+    }
+
+    fn wrap(e: Env, from: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        let token = storage::get_token(&e);
+        let savings = storage::get_savings(&e);
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        // the savings contract pulls the tokens from the wrapper
+        e.authorize_as_current_contract(vec![
+            &e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: vec![
+                        &e,
+                        e.current_contract_address().into_val(&e),
+                        savings.into_val(&e),
+                        amount.into_val(&e),
+                    ],
+                },
+                sub_invocations: vec![&e],
+            }),
+        ]);
+        let wrapped =
+            SavingsClient::new(&e, &savings).deposit(&e.current_contract_address(), &amount);
+        mint_wrapped(&e, &from, wrapped);
+
+        WrappedSavingsEvents::wrap(&e, from, amount, wrapped);
+        wrapped
+    }
+
+    fn unwrap(e: Env, from: Address, wrapped: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, wrapped);
+
+        burn_wrapped(&e, &from, wrapped);
+        let savings_client = SavingsClient::new(&e, &storage::get_savings(&e));
+        // round down, so the shares burned by the savings contract never exceed those unwrapped
+        let amount = wrapped * savings_client.get_share_price() / SCALAR_12;
+        if amount > 0 {
+            savings_client.withdraw(&e.current_contract_address(), &amount);
+            TokenClient::new(&e, &storage::get_token(&e)).transfer(
+                &e.current_contract_address(),
+                &from,
+                &amount,
+            );
+        }
+
+        WrappedSavingsEvents::unwrap(&e, from, amount, wrapped);
+        amount
+    }
+}
+
+#[contractimpl]
+impl Token for WrappedSavingsContract {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_allowance(&e, &from, &spender).amount
+    }
+
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        if amount < 0 {
+            panic_with_error!(&e, WrappedSavingsError::NegativeAmountError);
+        }
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            panic_with_error!(&e, WrappedSavingsError::InvalidExpiration);
+        }
+
+        let allowance = Allowance {
+            amount,
+            expiration_ledger,
+        };
+        storage::set_allowance(&e, &from, &spender, &allowance);
+
+        WrappedSavingsEvents::approve(&e, from, spender, amount, expiration_ledger);
+    }
+
+    fn balance(e: Env, id: Address) -> i128 {
+        storage::extend_instance(&e);
+        storage::get_balance(&e, &id)
+    }
+
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        move_wrapped(&e, &from, &to, amount);
+    }
+
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        require_positive(&e, amount);
+
+        spend_allowance(&e, &from, &spender, amount);
+        move_wrapped(&e, &from, &to, amount);
+    }
+
+    fn burn(e: Env, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+        require_positive(&e, amount);
+
+        burn_wrapped(&e, &from, amount);
+    }
+
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        require_positive(&e, amount);
+
+        spend_allowance(&e, &from, &spender, amount);
+        burn_wrapped(&e, &from, amount);
+    }
+
+    fn decimals(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        TokenClient::new(&e, &storage::get_token(&e)).decimals()
+    }
+
+    fn name(e: Env) -> String {
+        storage::extend_instance(&e);
+        storage::get_name(&e)
+    }
+
+    fn symbol(e: Env) -> String {
+        storage::extend_instance(&e);
+        storage::get_symbol(&e)
+    }
+}
+
+/// Mint wrapped tokens to an address
+fn mint_wrapped(e: &Env, to: &Address, amount: i128) {
+    storage::set_balance(e, to, storage::get_balance(e, to) + amount);
+    storage::set_total_supply(e, storage::get_total_supply(e) + amount);
+
+    WrappedSavingsEvents::mint(e, e.current_contract_address(), to.clone(), amount);
+}
+
+/// Burn wrapped tokens from an address
+///
+/// ### Panics
+/// If the address holds less than `amount` wrapped tokens
+fn burn_wrapped(e: &Env, from: &Address, amount: i128) {
+    let balance = storage::get_balance(e, from);
+    if balance < amount {
+        panic_with_error!(e, WrappedSavingsError::BalanceError);
+    }
+    storage::set_balance(e, from, balance - amount);
+    storage::set_total_supply(e, storage::get_total_supply(e) - amount);
+
+    WrappedSavingsEvents::burn(e, from.clone(), amount);
+}
+
+/// Move wrapped tokens between addresses
+///
+/// ### Panics
+/// If `from` holds less than `amount` wrapped tokens
+fn move_wrapped(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let balance = storage::get_balance(e, from);
+    if balance < amount {
+        panic_with_error!(e, WrappedSavingsError::BalanceError);
+    }
+    storage::set_balance(e, from, balance - amount);
+    storage::set_balance(e, to, storage::get_balance(e, to) + amount);
+
+    WrappedSavingsEvents::transfer(e, from.clone(), to.clone(), amount);
+}
+
+/// Spend part of a spender's allowance over a holder's wrapped tokens
+///
+/// ### Panics
+/// If the allowance is less than `amount`
+fn spend_allowance(e: &Env, from: &Address, spender: &Address, amount: i128) {
+    let mut allowance = storage::get_allowance(e, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(e, WrappedSavingsError::AllowanceError);
+    }
+    allowance.amount -= amount;
+    storage::set_allowance(e, from, spender, &allowance);
+}
+
+/// Require that an amount is positive
+///
+/// ### Panics
+/// If the amount is zero or negative
+fn require_positive(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, WrappedSavingsError::NegativeAmountError);
+    }
+}
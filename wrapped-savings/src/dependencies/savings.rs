@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The part of the savings contract the wrapper uses to deposit and withdraw its shares
+#[allow(dead_code)]
+#[contractclient(name = "SavingsClient")]
+pub trait Savings {
+    fn deposit(e: Env, from: Address, amount: i128) -> i128;
+
+    fn withdraw(e: Env, from: Address, amount: i128) -> i128;
+
+    fn get_share_price(e: Env) -> i128;
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+/// Error codes for the wrapped savings token. Common errors are codes that match up with the
+/// built-in contracts error reporting. Wrapped savings specific errors start at 4800.
+pub enum WrappedSavingsError {
+    // Common Errors
+    InternalError = 1,
+    AlreadyInitializedError = 3,
+    NegativeAmountError = 8,
+    AllowanceError = 9,
+    BalanceError = 10,
+
+    // Wrapped Savings
+    InvalidExpiration = 4800,
+}
@@ -0,0 +1,60 @@
+use orbit_events::topics;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct WrappedSavingsEvents {}
+
+impl WrappedSavingsEvents {
+    /// Emitted when tokens are wrapped
+    ///
+    /// - topics - `["wrap", from: Address]`
+    /// - data - `[amount: i128, wrapped: i128]`
+    pub fn wrap(e: &Env, from: Address, amount: i128, wrapped: i128) {
+        let topics = (Symbol::new(e, topics::WRAP), from);
+        e.events().publish(topics, (amount, wrapped));
+    }
+
+    /// Emitted when tokens are unwrapped
+    ///
+    /// - topics - `["unwrap", from: Address]`
+    /// - data - `[amount: i128, wrapped: i128]`
+    pub fn unwrap(e: &Env, from: Address, amount: i128, wrapped: i128) {
+        let topics = (Symbol::new(e, topics::UNWRAP), from);
+        e.events().publish(topics, (amount, wrapped));
+    }
+
+    /// Emitted when an allowance is set
+    ///
+    /// - topics - `["approve", from: Address, spender: Address]`
+    /// - data - `[amount: i128, expiration_ledger: u32]`
+    pub fn approve(e: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        let topics = (Symbol::new(e, topics::APPROVE), from, spender);
+        e.events().publish(topics, (amount, expiration_ledger));
+    }
+
+    /// Emitted when wrapped tokens are transferred
+    ///
+    /// - topics - `["transfer", from: Address, to: Address]`
+    /// - data - `amount: i128`
+    pub fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::TRANSFER), from, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when wrapped tokens are minted
+    ///
+    /// - topics - `["mint", admin: Address, to: Address]`
+    /// - data - `amount: i128`
+    pub fn mint(e: &Env, admin: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::MINT), admin, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when wrapped tokens are burned
+    ///
+    /// - topics - `["burn", from: Address]`
+    /// - data - `amount: i128`
+    pub fn burn(e: &Env, from: Address, amount: i128) {
+        let topics = (Symbol::new(e, topics::BURN), from);
+        e.events().publish(topics, amount);
+    }
+}
@@ -0,0 +1,226 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, String, Symbol};
+
+pub(crate) const LEDGER_THRESHOLD_SHARED: u32 = 172800; // ~ 10 days
+pub(crate) const LEDGER_BUMP_SHARED: u32 = 241920; // ~ 14 days
+
+pub(crate) const LEDGER_THRESHOLD_USER: u32 = 518400; // ~ 30 days
+pub(crate) const LEDGER_BUMP_USER: u32 = 535670; // ~ 31 days
+
+const IS_INIT_KEY: &str = "IsInit";
+const SAVINGS_KEY: &str = "Savings";
+const TOKEN_KEY: &str = "Token";
+const NAME_KEY: &str = "Name";
+const SYMBOL_KEY: &str = "Symbol";
+const TOTAL_SUPPLY_KEY: &str = "TotalSupply";
+
+/// An allowance of wrapped tokens a spender can transfer on behalf of a holder
+#[derive(Clone)]
+#[contracttype]
+pub struct Allowance {
+    pub amount: i128,
+    pub expiration_ledger: u32, // the last ledger the allowance can be spent in
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum WrappedSavingsDataKey {
+    // The wrapped tokens held by an address
+    Balance(Address),
+    // The allowance of a spender over a holder's wrapped tokens
+    Allowance(Address, Address),
+}
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Check if the contract has been initialized
+pub fn get_is_init(e: &Env) -> bool {
+    e.storage().instance().has(&Symbol::new(e, IS_INIT_KEY))
+}
+
+/// Set the contract as initialized
+pub fn set_is_init(e: &Env) {
+    e.storage()
+        .instance()
+        .set::<Symbol, bool>(&Symbol::new(e, IS_INIT_KEY), &true);
+}
+
+/********** Dependencies **********/
+
+/// Fetch the Address of the savings contract
+///
+/// ### Panics
+/// If the savings contract does not exist
+pub fn get_savings(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SAVINGS_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the savings contract
+///
+/// ### Arguments
+/// * `savings` - The Address for the savings contract
+pub fn set_savings(e: &Env, savings: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, SAVINGS_KEY), savings);
+}
+
+/// Fetch the Address of the token deposited in the savings contract
+///
+/// ### Panics
+/// If the token does not exist
+pub fn get_token(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOKEN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the Address of the token deposited in the savings contract
+///
+/// ### Arguments
+/// * `token` - The Address for the token
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, TOKEN_KEY), token);
+}
+
+/********** Metadata **********/
+
+/// Fetch the name of the wrapped token
+///
+/// ### Panics
+/// If the name does not exist
+pub fn get_name(e: &Env) -> String {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, NAME_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the name of the wrapped token
+///
+/// ### Arguments
+/// * `name` - The name
+pub fn set_name(e: &Env, name: &String) {
+    e.storage()
+        .instance()
+        .set::<Symbol, String>(&Symbol::new(e, NAME_KEY), name);
+}
+
+/// Fetch the symbol of the wrapped token
+///
+/// ### Panics
+/// If the symbol does not exist
+pub fn get_symbol(e: &Env) -> String {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SYMBOL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the symbol of the wrapped token
+///
+/// ### Arguments
+/// * `symbol` - The symbol
+pub fn set_symbol(e: &Env, symbol: &String) {
+    e.storage()
+        .instance()
+        .set::<Symbol, String>(&Symbol::new(e, SYMBOL_KEY), symbol);
+}
+
+/********** Balances **********/
+
+/// Fetch the total wrapped tokens outstanding
+pub fn get_total_supply(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, TOTAL_SUPPLY_KEY))
+        .unwrap_or(0)
+}
+
+/// Set the total wrapped tokens outstanding
+///
+/// ### Arguments
+/// * `amount` - The total wrapped tokens
+pub fn set_total_supply(e: &Env, amount: i128) {
+    e.storage()
+        .instance()
+        .set::<Symbol, i128>(&Symbol::new(e, TOTAL_SUPPLY_KEY), &amount);
+}
+
+/// Fetch the wrapped tokens held by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the holder
+pub fn get_balance(e: &Env, user: &Address) -> i128 {
+    let key = WrappedSavingsDataKey::Balance(user.clone());
+    let result = e.storage().persistent().get::<WrappedSavingsDataKey, i128>(&key);
+    match result {
+        Some(amount) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Set the wrapped tokens held by an address
+///
+/// ### Arguments
+/// * `user` - The Address of the holder
+/// * `amount` - The wrapped tokens held
+pub fn set_balance(e: &Env, user: &Address, amount: i128) {
+    let key = WrappedSavingsDataKey::Balance(user.clone());
+    e.storage()
+        .persistent()
+        .set::<WrappedSavingsDataKey, i128>(&key, &amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the allowance of a spender over a holder's wrapped tokens. Expired allowances are zero.
+///
+/// ### Arguments
+/// * `from` - The Address of the holder
+/// * `spender` - The Address of the spender
+pub fn get_allowance(e: &Env, from: &Address, spender: &Address) -> Allowance {
+    let key = WrappedSavingsDataKey::Allowance(from.clone(), spender.clone());
+    let result = e.storage().temporary().get::<WrappedSavingsDataKey, Allowance>(&key);
+    match result {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance,
+        _ => Allowance {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+/// Set the allowance of a spender over a holder's wrapped tokens. The allowance is kept in
+/// temporary storage until it expires.
+///
+/// ### Arguments
+/// * `from` - The Address of the holder
+/// * `spender` - The Address of the spender
+/// * `allowance` - The allowance
+pub fn set_allowance(e: &Env, from: &Address, spender: &Address, allowance: &Allowance) {
+    let key = WrappedSavingsDataKey::Allowance(from.clone(), spender.clone());
+    e.storage()
+        .temporary()
+        .set::<WrappedSavingsDataKey, Allowance>(&key, allowance);
+    if allowance.amount > 0 {
+        let live_for = allowance.expiration_ledger - e.ledger().sequence();
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+use crate::contract::{WrappedSavingsClient, WrappedSavingsContract};
+use crate::errors::WrappedSavingsError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol};
+
+const SCALAR_12: i128 = 1_000_000_000_000;
+
+/// Issues shares at a settable price, like the savings contract
+#[contract]
+pub struct MockSavings;
+
+#[contractimpl]
+impl MockSavings {
+    pub fn set_savings(e: Env, token: Address, price: i128) {
+        e.storage().instance().set(&Symbol::new(&e, "token"), &token);
+        e.storage().instance().set(&Symbol::new(&e, "price"), &price);
+    }
+
+    pub fn get_share_price(e: Env) -> i128 {
+        e.storage().instance().get(&Symbol::new(&e, "price")).unwrap()
+    }
+
+    pub fn get_shares(e: Env, user: Address) -> i128 {
+        e.storage().instance().get(&user).unwrap_or(0)
+    }
+
+    pub fn deposit(e: Env, from: Address, amount: i128) -> i128 {
+        from.require_auth();
+        let token: Address = e.storage().instance().get(&Symbol::new(&e, "token")).unwrap();
+        TokenClient::new(&e, &token).transfer(&from, &e.current_contract_address(), &amount);
+        let shares = amount * SCALAR_12 / Self::get_share_price(e.clone());
+        let balance = Self::get_shares(e.clone(), from.clone());
+        e.storage().instance().set(&from, &(balance + shares));
+        shares
+    }
+
+    pub fn withdraw(e: Env, from: Address, amount: i128) -> i128 {
+        from.require_auth();
+        let price = Self::get_share_price(e.clone());
+        let shares = (amount * SCALAR_12 + price - 1) / price;
+        let balance = Self::get_shares(e.clone(), from.clone());
+        assert!(balance >= shares);
+        e.storage().instance().set(&from, &(balance - shares));
+        let token: Address = e.storage().instance().get(&Symbol::new(&e, "token")).unwrap();
+        TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &from, &amount);
+        shares
+    }
+}
+
+#[test]
+fn test_wrap_and_unwrap() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    let ousd = e.register_stellar_asset_contract(Address::generate(&e));
+    let ousd_client = TokenClient::new(&e, &ousd);
+    let savings = e.register_contract(None, MockSavings);
+    let savings_client = MockSavingsClient::new(&e, &savings);
+    savings_client.set_savings(&ousd, &SCALAR_12);
+    let wrapper_id = e.register_contract(None, WrappedSavingsContract);
+    let wrapper_client = WrappedSavingsClient::new(&e, &wrapper_id);
+    let wrapped_token_client = TokenClient::new(&e, &wrapper_id);
+    wrapper_client.initialize(
+        &savings,
+        &ousd,
+        &String::from_str(&e, "Wrapped Savings oUSD"),
+        &String::from_str(&e, "wsoUSD"),
+    );
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    StellarAssetClient::new(&e, &ousd).mint(&samwise, &1000_0000000);
+    StellarAssetClient::new(&e, &ousd).mint(&savings, &100_0000000);
+
+    assert_eq!(wrapper_client.wrap(&samwise, &1000_0000000), 1000_0000000);
+    assert_eq!(wrapped_token_client.balance(&samwise), 1000_0000000);
+    assert_eq!(savings_client.get_shares(&wrapper_id), 1000_0000000);
+
+    // balances stay static while the exchange rate grows
+    savings_client.set_savings(&ousd, &1_050_000_000_000);
+    assert_eq!(wrapper_client.exchange_rate(), 1_050_000_000_000);
+    wrapped_token_client.transfer(&samwise, &frodo, &400_0000000);
+    assert_eq!(wrapped_token_client.balance(&frodo), 400_0000000);
+
+    assert_eq!(wrapper_client.unwrap(&frodo, &400_0000000), 420_0000000);
+    assert_eq!(ousd_client.balance(&frodo), 420_0000000);
+    assert_eq!(wrapper_client.total_supply(), 600_0000000);
+    assert_eq!(savings_client.get_shares(&wrapper_id), 600_0000000);
+
+    let result = wrapper_client.try_unwrap(&frodo, &1);
+    assert_eq!(result.err(), Some(Ok(WrappedSavingsError::BalanceError.into())));
+    let result = wrapper_client.try_wrap(&frodo, &0);
+    assert_eq!(result.err(), Some(Ok(WrappedSavingsError::NegativeAmountError.into())));
+}